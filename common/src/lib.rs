@@ -178,6 +178,8 @@ pub trait SubnetInfo<AccountId> {
     fn get_validator_trust(netuid: NetUid) -> Vec<u16>;
     fn get_validator_permit(netuid: NetUid) -> Vec<bool>;
     fn hotkey_of_uid(netuid: NetUid, uid: u16) -> Option<AccountId>;
+    fn owner_coldkey(netuid: NetUid) -> Option<AccountId>;
+    fn owner_hotkey(netuid: NetUid) -> Option<AccountId>;
 }
 
 pub trait BalanceOps<AccountId> {
@@ -194,16 +196,36 @@ pub trait BalanceOps<AccountId> {
         netuid: NetUid,
         alpha: AlphaCurrency,
     ) -> Result<(), DispatchError>;
+    /// Fails while the unstake timelock of `coldkey` holds its stake, unless `alpha` is zero.
     fn decrease_stake(
         coldkey: &AccountId,
         hotkey: &AccountId,
         netuid: NetUid,
         alpha: AlphaCurrency,
     ) -> Result<AlphaCurrency, DispatchError>;
+    /// Fails while the unstake timelock of `coldkey` holds its stake.
+    fn ensure_unstake_unlocked(coldkey: &AccountId) -> DispatchResult;
+    /// Stakes `tao` from the balance of `coldkey` to `hotkey` on `netuid` through the pool, as
+    /// `add_stake` does. Returns the Alpha staked.
+    fn stake(
+        coldkey: &AccountId,
+        hotkey: &AccountId,
+        netuid: NetUid,
+        tao: TaoCurrency,
+    ) -> Result<AlphaCurrency, DispatchError>;
+    /// Unstakes `alpha` of `coldkey` from `hotkey` on `netuid` through the pool to its balance,
+    /// as `remove_stake` does. Returns the TAO credited.
+    fn unstake(
+        coldkey: &AccountId,
+        hotkey: &AccountId,
+        netuid: NetUid,
+        alpha: AlphaCurrency,
+    ) -> Result<TaoCurrency, DispatchError>;
     fn increase_provided_tao_reserve(netuid: NetUid, tao: TaoCurrency);
-    fn decrease_provided_tao_reserve(netuid: NetUid, tao: TaoCurrency);
+    fn decrease_provided_tao_reserve(netuid: NetUid, tao: TaoCurrency) -> DispatchResult;
     fn increase_provided_alpha_reserve(netuid: NetUid, alpha: AlphaCurrency);
     fn decrease_provided_alpha_reserve(netuid: NetUid, alpha: AlphaCurrency);
+    fn increase_network_treasury(tao: TaoCurrency);
 }
 
 pub mod time {
@@ -407,5 +407,71 @@ export const IStakingV2ABI = [
         "outputs": [],
         "stateMutability": "nonpayable",
         "type": "function"
+    },
+    {
+        "inputs": [
+            {
+                "internalType": "bytes32",
+                "name": "hotkey",
+                "type": "bytes32"
+            },
+            {
+                "internalType": "uint256",
+                "name": "origin_netuid",
+                "type": "uint256"
+            },
+            {
+                "internalType": "uint256",
+                "name": "destination_netuid",
+                "type": "uint256"
+            },
+            {
+                "internalType": "uint256",
+                "name": "amount",
+                "type": "uint256"
+            }
+        ],
+        "name": "swapStake",
+        "outputs": [],
+        "stateMutability": "nonpayable",
+        "type": "function"
+    },
+    {
+        "inputs": [
+            {
+                "internalType": "bytes32",
+                "name": "hotkey",
+                "type": "bytes32"
+            },
+            {
+                "internalType": "uint256",
+                "name": "origin_netuid",
+                "type": "uint256"
+            },
+            {
+                "internalType": "uint256",
+                "name": "destination_netuid",
+                "type": "uint256"
+            },
+            {
+                "internalType": "uint256",
+                "name": "amount",
+                "type": "uint256"
+            },
+            {
+                "internalType": "uint256",
+                "name": "limit_price",
+                "type": "uint256"
+            },
+            {
+                "internalType": "bool",
+                "name": "allow_partial",
+                "type": "bool"
+            }
+        ],
+        "name": "swapStakeLimit",
+        "outputs": [],
+        "stateMutability": "nonpayable",
+        "type": "function"
     }
 ];
\ No newline at end of file
@@ -0,0 +1,105 @@
+export const ISWAP_ADDRESS = "0x000000000000000000000000000000000000080b";
+
+export const ISwapABI = [
+    {
+        "inputs": [
+            {
+                "internalType": "bytes32",
+                "name": "hotkey",
+                "type": "bytes32"
+            },
+            {
+                "internalType": "uint16",
+                "name": "netuid",
+                "type": "uint16"
+            },
+            {
+                "internalType": "uint64",
+                "name": "tao",
+                "type": "uint64"
+            },
+            {
+                "internalType": "uint64",
+                "name": "minAlphaOut",
+                "type": "uint64"
+            }
+        ],
+        "name": "buyAlpha",
+        "outputs": [
+            {
+                "internalType": "uint64",
+                "name": "",
+                "type": "uint64"
+            }
+        ],
+        "stateMutability": "nonpayable",
+        "type": "function"
+    },
+    {
+        "inputs": [
+            {
+                "internalType": "bytes32",
+                "name": "hotkey",
+                "type": "bytes32"
+            },
+            {
+                "internalType": "uint16",
+                "name": "netuid",
+                "type": "uint16"
+            },
+            {
+                "internalType": "uint64",
+                "name": "alpha",
+                "type": "uint64"
+            },
+            {
+                "internalType": "uint64",
+                "name": "minTaoOut",
+                "type": "uint64"
+            }
+        ],
+        "name": "sellAlpha",
+        "outputs": [
+            {
+                "internalType": "uint64",
+                "name": "",
+                "type": "uint64"
+            }
+        ],
+        "stateMutability": "nonpayable",
+        "type": "function"
+    },
+    {
+        "inputs": [
+            {
+                "internalType": "bytes32",
+                "name": "hotkey",
+                "type": "bytes32"
+            },
+            {
+                "internalType": "uint16",
+                "name": "originNetuid",
+                "type": "uint16"
+            },
+            {
+                "internalType": "uint16",
+                "name": "destinationNetuid",
+                "type": "uint16"
+            },
+            {
+                "internalType": "uint64",
+                "name": "alpha",
+                "type": "uint64"
+            },
+            {
+                "internalType": "uint64",
+                "name": "minAlphaOut",
+                "type": "uint64"
+            }
+        ],
+        "name": "swapAlphaForAlpha",
+        "outputs": [],
+        "stateMutability": "nonpayable",
+        "type": "function"
+    }
+];
@@ -295,6 +295,26 @@ describe("Test Alpha Precompile", () => {
     });
 
     describe("Subnet Configuration Functions", () => {
+        it("getSubnetExists returns whether the subnet exists", async () => {
+            const lastSubnet = (await api.query.SubtensorModule.TotalNetworks.getValue()) - 1
+
+            const exists = await publicClient.readContract({
+                abi: IAlphaABI,
+                address: toViemAddress(IALPHA_ADDRESS),
+                functionName: "getSubnetExists",
+                args: [lastSubnet]
+            })
+            assert.equal(exists, true, "Registered subnet should exist");
+
+            const nonExistent = await publicClient.readContract({
+                abi: IAlphaABI,
+                address: toViemAddress(IALPHA_ADDRESS),
+                functionName: "getSubnetExists",
+                args: [9999]
+            })
+            assert.equal(nonExistent, false, "Unregistered subnet should not exist");
+        });
+
         it("getSubnetMechanism returns valid mechanism", async () => {
             const mechanism = await publicClient.readContract({
                 abi: IAlphaABI,
@@ -0,0 +1,105 @@
+import * as assert from "assert";
+import {
+  getDevnetApi,
+  getRandomSubstrateKeypair,
+  getSignerFromKeypair,
+  waitForTransactionCompletion,
+} from "../src/substrate";
+import { devnet } from "@polkadot-api/descriptors";
+import { TypedApi } from "polkadot-api";
+import { keccak256, pad, toBytes } from "viem";
+import { filter, firstValueFrom, timeout } from "rxjs";
+import { hexToU8a } from "@polkadot/util";
+import { u64 } from "scale-ts";
+import { keccak256 as ethersKeccak256 } from "ethers";
+import { convertPublicKeyToSs58 } from "../src/address-utils";
+import { tao } from "../src/balance-math";
+import {
+  addNewSubnetwork,
+  addStake,
+  forceSetBalanceToSs58Address,
+  startCall,
+} from "../src/subtensor";
+import {
+  convertToFixedSizeBinary,
+  generateRandomEthersWallet,
+} from "../src/utils";
+
+// The address the runtime emits the logs of native events from
+const NATIVE_EVENT_LOG_ADDRESS = "0x000000000000000000000000000000000000080c";
+
+describe("Test the EVM logs of native events", () => {
+  const hotkey = getRandomSubstrateKeypair();
+  const coldkey = getRandomSubstrateKeypair();
+  const evmWallet = generateRandomEthersWallet();
+
+  let api: TypedApi<typeof devnet>;
+  let netuid: number;
+
+  before(async () => {
+    api = await getDevnetApi();
+    await forceSetBalanceToSs58Address(
+      api,
+      convertPublicKeyToSs58(hotkey.publicKey),
+    );
+    await forceSetBalanceToSs58Address(
+      api,
+      convertPublicKeyToSs58(coldkey.publicKey),
+    );
+    netuid = await addNewSubnetwork(api, hotkey, coldkey);
+    await startCall(api, netuid, coldkey);
+
+    // Associate the EVM address with the hotkey
+    const blockNumber = await api.query.System.Number.getValue();
+    const blockNumberHash = hexToU8a(
+      ethersKeccak256(u64.enc(BigInt(blockNumber))),
+    );
+    const signature = await evmWallet.signMessage(
+      new Uint8Array([...hotkey.publicKey, ...blockNumberHash]),
+    );
+    const tx = api.tx.SubtensorModule.associate_evm_key({
+      netuid: netuid,
+      evm_key: convertToFixedSizeBinary(evmWallet.address, 20),
+      block_number: BigInt(blockNumber),
+      signature: convertToFixedSizeBinary(signature, 65),
+    });
+    await waitForTransactionCompletion(api, tx, getSignerFromKeypair(hotkey));
+  });
+
+  it("StakeAdded is deposited as an EVM Log event", async () => {
+    const topic = keccak256(
+      toBytes("StakeAdded(address,uint16,bytes32,uint256,uint256)"),
+    );
+    const addressTopic = pad(evmWallet.address.toLowerCase() as `0x${string}`);
+    const netuidTopic = pad(`0x${netuid.toString(16)}`);
+
+    // The log is deposited on finalize of the block of the stake
+    const logEvent = firstValueFrom(
+      api.event.EVM.Log.watch().pipe(
+        filter(
+          ({ payload }) =>
+            payload.log.address.asHex() === NATIVE_EVENT_LOG_ADDRESS &&
+            payload.log.topics[0]?.asHex() === topic &&
+            payload.log.topics[1]?.asHex() === addressTopic &&
+            payload.log.topics[2]?.asHex() === netuidTopic,
+        ),
+        timeout(60000),
+      ),
+    );
+
+    await addStake(
+      api,
+      netuid,
+      convertPublicKeyToSs58(hotkey.publicKey),
+      tao(100),
+      coldkey,
+    );
+
+    const data = (await logEvent).payload.log.data.asHex();
+    assert.equal(
+      data.slice(2, 66),
+      Buffer.from(coldkey.publicKey).toString("hex"),
+    );
+    assert.equal(BigInt(`0x${data.slice(66, 130)}`), tao(100));
+  });
+});
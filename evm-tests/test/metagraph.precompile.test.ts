@@ -143,4 +143,29 @@ describe("Test the Metagraph precompile", () => {
             assert.ok(value != undefined);
         }
     });
+
+    it("Metagraph alpha stake access via precompile contract is ok", async () => {
+        const uid = 0
+        const alphaStake = await publicClient.readContract({
+            abi: IMetagraphABI,
+            address: toViemAddress(IMETAGRAPH_ADDRESS),
+            functionName: "getAlphaStake",
+            args: [subnetId, uid]
+        })
+
+        const hotkeyOfUid = await api.query.SubtensorModule.Keys.getValue(subnetId, uid)
+        const totalHotkeyAlpha = await api.query.SubtensorModule.TotalHotkeyAlpha.getValue(hotkeyOfUid, subnetId)
+        assert.equal(alphaStake, totalHotkeyAlpha)
+    });
+
+    it("Metagraph alpha stake reverts for a uid out of range", async () => {
+        const uid_count = await api.query.SubtensorModule.SubnetworkN.getValue(subnetId)
+
+        await assert.rejects(publicClient.readContract({
+            abi: IMetagraphABI,
+            address: toViemAddress(IMETAGRAPH_ADDRESS),
+            functionName: "getAlphaStake",
+            args: [subnetId, uid_count]
+        }))
+    });
 });
\ No newline at end of file
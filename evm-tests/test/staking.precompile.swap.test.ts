@@ -0,0 +1,136 @@
+import * as assert from "assert";
+import { getDevnetApi, getRandomSubstrateKeypair } from "../src/substrate";
+import { devnet } from "@polkadot-api/descriptors";
+import { TypedApi } from "polkadot-api";
+import {
+  convertH160ToSS58,
+  convertPublicKeyToSs58,
+} from "../src/address-utils";
+import { tao } from "../src/balance-math";
+import {
+  addNewSubnetwork,
+  forceSetBalanceToEthAddress,
+  forceSetBalanceToSs58Address,
+  startCall,
+} from "../src/subtensor";
+import { ethers } from "ethers";
+import { generateRandomEthersWallet } from "../src/utils";
+import { ISTAKING_V2_ADDRESS, IStakingV2ABI } from "../src/contracts/staking";
+
+describe("Test staking precompile swap stake methods", () => {
+  const hotkey = getRandomSubstrateKeypair();
+  const coldkey = getRandomSubstrateKeypair();
+  const wallet1 = generateRandomEthersWallet();
+
+  let api: TypedApi<typeof devnet>;
+  let originNetuid: number;
+  let destinationNetuid: number;
+
+  async function getAlpha(netuid: number) {
+    return await api.query.SubtensorModule.Alpha.getValue(
+      convertPublicKeyToSs58(hotkey.publicKey),
+      convertH160ToSS58(wallet1.address),
+      netuid,
+    );
+  }
+
+  before(async () => {
+    api = await getDevnetApi();
+    await forceSetBalanceToSs58Address(
+      api,
+      convertPublicKeyToSs58(hotkey.publicKey),
+    );
+    await forceSetBalanceToSs58Address(
+      api,
+      convertPublicKeyToSs58(coldkey.publicKey),
+    );
+    await forceSetBalanceToEthAddress(api, wallet1.address);
+    originNetuid = await addNewSubnetwork(api, hotkey, coldkey);
+    await startCall(api, originNetuid, coldkey);
+    destinationNetuid = await addNewSubnetwork(api, hotkey, coldkey);
+    await startCall(api, destinationNetuid, coldkey);
+    console.log("will test in subnets: ", originNetuid, destinationNetuid);
+
+    const contract = new ethers.Contract(
+      ISTAKING_V2_ADDRESS,
+      IStakingV2ABI,
+      wallet1,
+    );
+    const tx = await contract.addStake(
+      hotkey.publicKey,
+      tao(1000),
+      originNetuid,
+    );
+    await tx.wait();
+  });
+
+  it("Staker swap stake", async () => {
+    const originAlpha = await getAlpha(originNetuid);
+    const destinationAlpha = await getAlpha(destinationNetuid);
+
+    const contract = new ethers.Contract(
+      ISTAKING_V2_ADDRESS,
+      IStakingV2ABI,
+      wallet1,
+    );
+    const tx = await contract.swapStake(
+      hotkey.publicKey,
+      originNetuid,
+      destinationNetuid,
+      originAlpha / BigInt(4),
+    );
+    await tx.wait();
+
+    assert.ok((await getAlpha(originNetuid)) < originAlpha);
+    assert.ok((await getAlpha(destinationNetuid)) > destinationAlpha);
+  });
+
+  it("Staker swap stake limit", async () => {
+    const originAlpha = await getAlpha(originNetuid);
+    const destinationAlpha = await getAlpha(destinationNetuid);
+
+    const contract = new ethers.Contract(
+      ISTAKING_V2_ADDRESS,
+      IStakingV2ABI,
+      wallet1,
+    );
+    const tx = await contract.swapStakeLimit(
+      hotkey.publicKey,
+      originNetuid,
+      destinationNetuid,
+      originAlpha / BigInt(4),
+      1,
+      false,
+    );
+    await tx.wait();
+
+    assert.ok((await getAlpha(originNetuid)) < originAlpha);
+    assert.ok((await getAlpha(destinationNetuid)) > destinationAlpha);
+  });
+
+  it("Staker swap stake limit rejects a price below the limit", async () => {
+    const originAlpha = await getAlpha(originNetuid);
+    const destinationAlpha = await getAlpha(destinationNetuid);
+
+    const contract = new ethers.Contract(
+      ISTAKING_V2_ADDRESS,
+      IStakingV2ABI,
+      wallet1,
+    );
+    // a limit of 1000 destination alpha per origin alpha is far above the price of the pools
+    await assert.rejects(async () => {
+      const tx = await contract.swapStakeLimit(
+        hotkey.publicKey,
+        originNetuid,
+        destinationNetuid,
+        originAlpha / BigInt(4),
+        tao(1000),
+        false,
+      );
+      await tx.wait();
+    });
+
+    assert.equal(await getAlpha(originNetuid), originAlpha);
+    assert.equal(await getAlpha(destinationNetuid), destinationAlpha);
+  });
+});
@@ -0,0 +1,128 @@
+import * as assert from "assert";
+import { getDevnetApi, getRandomSubstrateKeypair } from "../src/substrate";
+import { devnet } from "@polkadot-api/descriptors";
+import { TypedApi } from "polkadot-api";
+import {
+  convertH160ToSS58,
+  convertPublicKeyToSs58,
+} from "../src/address-utils";
+import { tao } from "../src/balance-math";
+import {
+  addNewSubnetwork,
+  forceSetBalanceToEthAddress,
+  forceSetBalanceToSs58Address,
+  startCall,
+} from "../src/subtensor";
+import { ethers } from "ethers";
+import { generateRandomEthersWallet } from "../src/utils";
+import { ISWAP_ADDRESS, ISwapABI } from "../src/contracts/swap";
+
+describe("Test swap precompile buy and sell methods", () => {
+  const hotkey = getRandomSubstrateKeypair();
+  const coldkey = getRandomSubstrateKeypair();
+  const wallet1 = generateRandomEthersWallet();
+
+  let api: TypedApi<typeof devnet>;
+  let netuid: number;
+
+  async function getAlpha() {
+    return await api.query.SubtensorModule.Alpha.getValue(
+      convertPublicKeyToSs58(hotkey.publicKey),
+      convertH160ToSS58(wallet1.address),
+      netuid,
+    );
+  }
+
+  before(async () => {
+    api = await getDevnetApi();
+    await forceSetBalanceToSs58Address(
+      api,
+      convertPublicKeyToSs58(hotkey.publicKey),
+    );
+    await forceSetBalanceToSs58Address(
+      api,
+      convertPublicKeyToSs58(coldkey.publicKey),
+    );
+    await forceSetBalanceToEthAddress(api, wallet1.address);
+    netuid = await addNewSubnetwork(api, hotkey, coldkey);
+    await startCall(api, netuid, coldkey);
+    console.log("will test in subnet: ", netuid);
+  });
+
+  it("Buy alpha", async () => {
+    const alpha = await getAlpha();
+    const contract = new ethers.Contract(ISWAP_ADDRESS, ISwapABI, wallet1);
+
+    const alphaOut = await contract.buyAlpha.staticCall(
+      hotkey.publicKey,
+      netuid,
+      tao(100),
+      1,
+    );
+    assert.ok(alphaOut > BigInt(0));
+
+    const tx = await contract.buyAlpha(hotkey.publicKey, netuid, tao(100), 1);
+    await tx.wait();
+
+    assert.ok((await getAlpha()) > alpha);
+  });
+
+  it("Buy alpha reverts below the minimum alpha out", async () => {
+    const alpha = await getAlpha();
+    const contract = new ethers.Contract(ISWAP_ADDRESS, ISwapABI, wallet1);
+
+    // 100 TAO buys far less than a million alpha
+    await assert.rejects(async () => {
+      const tx = await contract.buyAlpha(
+        hotkey.publicKey,
+        netuid,
+        tao(100),
+        tao(1000000),
+      );
+      await tx.wait();
+    });
+
+    assert.equal(await getAlpha(), alpha);
+  });
+
+  it("Sell alpha", async () => {
+    const alpha = await getAlpha();
+    const contract = new ethers.Contract(ISWAP_ADDRESS, ISwapABI, wallet1);
+
+    const taoOut = await contract.sellAlpha.staticCall(
+      hotkey.publicKey,
+      netuid,
+      alpha / BigInt(2),
+      1,
+    );
+    assert.ok(taoOut > BigInt(0));
+
+    const tx = await contract.sellAlpha(
+      hotkey.publicKey,
+      netuid,
+      alpha / BigInt(2),
+      1,
+    );
+    await tx.wait();
+
+    assert.ok((await getAlpha()) < alpha);
+  });
+
+  it("Sell alpha reverts below the minimum TAO out", async () => {
+    const alpha = await getAlpha();
+    const contract = new ethers.Contract(ISWAP_ADDRESS, ISwapABI, wallet1);
+
+    // the alpha left is worth far less than a million TAO
+    await assert.rejects(async () => {
+      const tx = await contract.sellAlpha(
+        hotkey.publicKey,
+        netuid,
+        alpha / BigInt(2),
+        tao(1000000),
+      );
+      await tx.wait();
+    });
+
+    assert.equal(await getAlpha(), alpha);
+  });
+});
@@ -3,22 +3,25 @@
 #![allow(clippy::arithmetic_side_effects)]
 
 extern crate alloc;
-use alloc::vec::Vec;
+use alloc::{boxed::Box, vec::Vec};
 
 #[allow(unused)]
 use crate::Pallet as AdminUtils;
 use frame_benchmarking::v1::account;
 use frame_benchmarking::v2::*;
-use frame_support::BoundedVec;
+use frame_support::{BoundedVec, traits::Get};
 use frame_system::RawOrigin;
-use pallet_subtensor::SubnetworkN;
+use pallet_subtensor::{
+    SubnetworkN, epoch::consensus::ConsensusEngineKind, utils::rate_limiting::HyperparameterGroup,
+};
+use sp_runtime::traits::Hash;
 
 use super::*;
 
 #[benchmarks]
 mod benchmarks {
     use super::*;
-    use subtensor_runtime_common::NetUid;
+    use subtensor_runtime_common::{MechId, NetUid};
 
     #[benchmark]
     fn swap_authorities(a: Linear<0, 32>) {
@@ -629,15 +632,568 @@ mod benchmarks {
     }
 
     #[benchmark]
-    fn sudo_trim_to_max_allowed_uids() {
+    fn sudo_trim_to_max_allowed_uids(n: Linear<2, 256>) {
+        let netuid = NetUid::from(1);
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            netuid, 1u16, // tempo
+        );
+        pallet_subtensor::Pallet::<T>::set_max_allowed_uids(netuid, n as u16);
+        pallet_subtensor::Pallet::<T>::set_min_allowed_uids(netuid, 1);
+        // Without immunity every uid but the one kept is trimmed
+        pallet_subtensor::Pallet::<T>::set_immunity_period(netuid, 0);
+        for idx in 0..n {
+            let hotkey: T::AccountId = account("Neuron", idx, 0);
+            pallet_subtensor::Pallet::<T>::append_neuron(netuid, &hotkey, 0);
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, netuid, 1u16 /*max_n*/);
+    }
+
+    #[benchmark]
+    fn sudo_set_churn_burn_params() {
+        // disable admin freeze window
         pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
         pallet_subtensor::Pallet::<T>::init_new_network(
             1u16.into(), /*netuid*/
-            1u16,        /*sudo_tempo*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 3u16/*threshold*/, 7200u64/*window*/)/*sudo_set_churn_burn_params*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_sliced_unstake_depth_fraction() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 655u16/*fraction*/)/*sudo_set_sliced_unstake_depth_fraction*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_subnet_features() {
+        // disable admin freeze window
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 0b111111u16/*features*/)/*sudo_set_subnet_features*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_max_scheduled_operations() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 4u32/*max*/)/*sudo_set_max_scheduled_operations*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_timelocked_weights_only() {
+        // disable admin freeze window
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, true/*enabled*/)/*sudo_set_timelocked_weights_only*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_reveal_window() {
+        // disable admin freeze window
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 20u64/*blocks*/)/*sudo_set_reveal_window*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_commit_reveal_bounds() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u64/*min_periods*/, 50u64/*max_periods*/, 20u64/*min_reveal_window*/)/*sudo_set_commit_reveal_bounds*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_liquid_alpha_bounds() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1638u16/*min_alpha*/, 60000u16/*max_alpha*/)/*sudo_set_liquid_alpha_bounds*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_consensus_engine() {
+        let netuid = NetUid::from(1);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            netuid, 1u16, // tempo
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, netuid, ConsensusEngineKind::TrustedMedian);
+    }
+
+    #[benchmark]
+    fn sudo_set_owner_hparam_group_rate_limit() {
+        let netuid = NetUid::from(1);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            netuid, 1u16, // tempo
+        );
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Root,
+            netuid,
+            HyperparameterGroup::Registration,
+            Some(1u16),
+        );
+    }
+
+    #[benchmark]
+    fn sudo_set_self_weights_excluded() {
+        // disable admin freeze window
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, true/*excluded*/)/*sudo_set_self_weights_excluded*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_weight_expiry_blocks() {
+        // disable admin freeze window
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 50_400u64/*blocks*/)/*sudo_set_weight_expiry_blocks*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_validator_permit_min_stake() {
+        // disable admin freeze window
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 1_000_000_000u64/*min_stake*/)/*sudo_set_validator_permit_min_stake*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_weights_set_rate_limit_override() {
+        // disable admin freeze window
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+        pallet_subtensor::Pallet::<T>::append_neuron(1u16.into(), &account("Alice", 0, 1), 0u64);
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 0u16/*uid*/, Some(1u64)/*limit*/)/*sudo_set_weights_set_rate_limit_override*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_adaptive_max_weight_limit() {
+        // disable admin freeze window
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 10u16/*multiple*/)/*sudo_set_adaptive_max_weight_limit*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_burn_auction_enabled() {
+        // disable admin freeze window
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, true/*enabled*/)/*sudo_set_burn_auction_enabled*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_difficulty_controller_gains() {
+        // disable admin freeze window
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 500_000u32/*kp*/, 50_000u32/*ki*/, 100_000u32/*kd*/)/*sudo_set_difficulty_controller_gains*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_free_extrinsic_min_balance() {
+        #[extrinsic_call]
+        _(RawOrigin::Root, 1_000_000u64.into());
+    }
+
+    #[benchmark]
+    fn sudo_set_max_registrations_per_coldkey_per_interval() {
+        // disable admin freeze window
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 2u16/*max_registrations*/)/*sudo_set_max_registrations_per_coldkey_per_interval*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_registration_queue_enabled() {
+        // disable admin freeze window
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, true/*enabled*/)/*sudo_set_registration_queue_enabled*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_registration_deposit() {
+        // disable admin freeze window
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 16384u16/*share*/, 10u16/*maturity_epochs*/)/*sudo_set_registration_deposit*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_miner_emission_share() {
+        // disable admin freeze window
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, Percent::from_percent(60)/*share*/)/*sudo_set_miner_emission_share*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_miner_emission_share_bounds() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, Percent::from_percent(30)/*min*/, Percent::from_percent(70)/*max*/)/*sudo_set_miner_emission_share_bounds*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_owner_cut_vesting_blocks() {
+        // disable admin freeze window
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 216000u64/*blocks*/)/*sudo_set_owner_cut_vesting_blocks*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_min_owner_cut_vesting_blocks() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 50400u64/*blocks*/)/*sudo_set_min_owner_cut_vesting_blocks*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_subnet_treasury() {
+        // disable admin freeze window
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 16384u16/*share*/, 32768u16/*approval_threshold*/)/*sudo_set_subnet_treasury*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_max_subnet_emission_share() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, Percent::from_percent(20)/*share*/)/*sudo_set_max_subnet_emission_share*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_hyperparameter_voting_period() {
+        // disable admin freeze window
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 50400u64/*blocks*/)/*sudo_set_hyperparameter_voting_period*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_network_treasury_share() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 16384u16/*share*/)/*sudo_set_network_treasury_share*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_sudo_call_delay() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 7200u64/*blocks*/)/*sudo_set_sudo_call_delay*/;
+    }
+
+    #[benchmark]
+    fn announce_sudo_call() {
+        crate::SudoCallDelay::<T>::put(7200);
+        let call = Box::new(crate::Call::<T>::sudo_set_subnet_limit { max_subnets: 64 });
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, call)/*announce_sudo_call*/;
+    }
+
+    #[benchmark]
+    fn cancel_sudo_call() {
+        let call = crate::Call::<T>::sudo_set_subnet_limit { max_subnets: 64 };
+        let call_hash = T::Hashing::hash_of(&call);
+        crate::PendingSudoCalls::<T>::insert(call_hash, 7200u64);
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, call_hash)/*cancel_sudo_call*/;
+    }
+
+    #[benchmark]
+    fn execute_sudo_call() {
+        crate::SudoCallDelay::<T>::put(7200);
+        let call = crate::Call::<T>::sudo_set_subnet_limit { max_subnets: 64 };
+        crate::PendingSudoCalls::<T>::insert(T::Hashing::hash_of(&call), 0u64);
+        let caller: T::AccountId = account("Alice", 0, 1);
+
+        #[extrinsic_call]
+		_(RawOrigin::Signed(caller), Box::new(call))/*execute_sudo_call*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_senate_hysteresis() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 3276u16/*hysteresis*/)/*sudo_set_senate_hysteresis*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_neuron_immunity_override() {
+        // disable admin freeze window
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+        pallet_subtensor::Pallet::<T>::append_neuron(1u16.into(), &account("Alice", 0, 1), 0u64);
+        // every other override the subnet allows is counted
+        for uid in 1..pallet_subtensor::MaxNeuronImmunityOverrides::<T>::get() {
+            pallet_subtensor::NeuronImmunityOverride::<T>::insert(
+                NetUid::from(1),
+                uid as u16,
+                100_000u64,
+            );
+        }
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 0u16/*uid*/, Some(100_000u64)/*immunity_period*/)/*sudo_set_neuron_immunity_override*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_tempo_seconds() {
+        // disable admin freeze window
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, Some(3_600u64)/*seconds*/)/*sudo_set_tempo_seconds*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_merkle_weights_enabled() {
+        // disable admin freeze window
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, true/*enabled*/)/*sudo_set_merkle_weights_enabled*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_adjustment_alpha() {
+        // disable admin freeze window
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, 58000u64/*adjustment_alpha*/)/*sudo_set_adjustment_alpha*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_network_pow_registration_allowed() {
+        // disable admin freeze window
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
         );
 
         #[extrinsic_call]
-		_(RawOrigin::Root, 1u16.into()/*netuid*/, 256u16/*max_n*/)/*sudo_trim_to_max_allowed_uids()*/;
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, true/*registration_allowed*/)/*sudo_set_network_pow_registration_allowed*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_subnet_owner_cut() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 11_796u16/*subnet_owner_cut*/)/*sudo_set_subnet_owner_cut*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_network_rate_limit() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 7200u64/*rate_limit*/)/*sudo_set_network_rate_limit*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_network_immunity_period() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 7200u64/*immunity_period*/)/*sudo_set_network_immunity_period*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_network_min_lock_cost() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1_000_000_000u64.into()/*lock_cost*/)/*sudo_set_network_min_lock_cost*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_subnet_limit() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 128u16/*max_subnets*/)/*sudo_set_subnet_limit*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_lock_reduction_interval() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 14_400u64/*interval*/)/*sudo_set_lock_reduction_interval*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_evm_chain_id() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 945u64/*chain_id*/)/*sudo_set_evm_chain_id*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_recycle_or_burn() {
+        // disable admin freeze window
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            1u16.into(), /*netuid*/
+            1u16,        /*tempo*/
+        );
+
+        #[extrinsic_call]
+		_(RawOrigin::Root, 1u16.into()/*netuid*/, pallet_subtensor::RecycleOrBurnEnum::Recycle/*recycle_or_burn*/)/*sudo_set_recycle_or_burn*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_subnet_owner_hotkey() {
+        let netuid = NetUid::from(1);
+        let owner: T::AccountId = account("Owner", 0, 1);
+        let hotkey: T::AccountId = account("Alice", 0, 1);
+
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            netuid, 1u16, // tempo
+        );
+        pallet_subtensor::SubnetOwner::<T>::insert(netuid, owner.clone());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), netuid, hotkey);
+    }
+
+    #[benchmark]
+    fn sudo_set_ck_burn() {
+        #[extrinsic_call]
+		_(RawOrigin::Root, 100u64/*burn*/)/*sudo_set_ck_burn*/;
+    }
+
+    #[benchmark]
+    fn sudo_set_mechanism_count() {
+        let netuid = NetUid::from(1);
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            netuid, 1u16, // tempo
+        );
+        // Reducing from the maximum count cleans up the most mechanisms
+        pallet_subtensor::MechanismCountCurrent::<T>::insert(
+            netuid,
+            pallet_subtensor::MaxMechanismCount::<T>::get(),
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, netuid, MechId::from(1u8));
+    }
+
+    #[benchmark]
+    fn sudo_set_mechanism_emission_split(m: Linear<1, 16>) {
+        let netuid = NetUid::from(1);
+        pallet_subtensor::Pallet::<T>::set_admin_freeze_window(0);
+        pallet_subtensor::Pallet::<T>::init_new_network(
+            netuid, 1u16, // tempo
+        );
+        pallet_subtensor::MechanismCountCurrent::<T>::insert(netuid, MechId::from(m as u8));
+
+        // The first mechanism takes the rounding remainder so that the split adds up to u16::MAX
+        let share = u16::MAX / m as u16;
+        let mut split = alloc::vec![share; m as usize];
+        if let Some(first) = split.first_mut() {
+            *first = u16::MAX - share * (m as u16 - 1);
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, netuid, Some(split));
     }
 
     //impl_benchmark_test_suite!(AdminUtils, crate::mock::new_test_ext(), crate::mock::Test);
@@ -9,9 +9,12 @@ pub use pallet::*;
 // - we could use a type parameter for `AuthorityId`, but there is
 //   no sense for this as GRANDPA's `AuthorityId` is not a parameter -- it's always the same
 use sp_consensus_grandpa::AuthorityList;
-use sp_runtime::{DispatchResult, RuntimeAppPublic, Vec, traits::Member};
+use sp_runtime::{DispatchResult, Percent, RuntimeAppPublic, Vec, traits::Member};
 
 mod benchmarking;
+pub mod timelock;
+pub mod weights;
+pub use weights::WeightInfo;
 
 #[cfg(test)]
 mod tests;
@@ -21,15 +24,25 @@ mod tests;
 pub mod pallet {
     use super::*;
     use frame_support::pallet_prelude::*;
-    use frame_support::traits::tokens::Balance;
-    use frame_support::{dispatch::DispatchResult, pallet_prelude::StorageMap};
+    use frame_support::traits::{UnfilteredDispatchable, tokens::Balance};
+    use frame_support::{
+        dispatch::{DispatchResult, GetDispatchInfo},
+        pallet_prelude::StorageMap,
+    };
     use frame_system::pallet_prelude::*;
     use pallet_evm_chain_id::{self, ChainId};
     use pallet_subtensor::{
         DefaultMaxAllowedUids,
-        utils::rate_limiting::{Hyperparameter, TransactionType},
+        coinbase::difficulty_controller::DifficultyControllerGains,
+        epoch::consensus::ConsensusEngineKind,
+        utils::{
+            misc::MAX_OWNER_BONDS_MOVING_AVERAGE,
+            rate_limiting::{Hyperparameter, HyperparameterGroup, TransactionType},
+        },
     };
     use sp_runtime::BoundedVec;
+    use sp_runtime::traits::Hash;
+    use sp_std::boxed::Box;
     use substrate_fixed::types::I96F32;
     use subtensor_runtime_common::{MechId, NetUid, TaoCurrency};
 
@@ -63,6 +76,9 @@ pub mod pallet {
 
         /// Unit of assets
         type Balance: Balance;
+
+        /// Weight information for the extrinsics of this pallet.
+        type WeightInfo: WeightInfo;
     }
 
     #[pallet::event]
@@ -89,6 +105,30 @@ pub mod pallet {
             /// Indicates if the Bonds Reset was enabled or disabled.
             enabled: bool,
         },
+        /// Event emitted when the delay of time-locked sudo calls is set.
+        SudoCallDelaySet {
+            /// The number of blocks between the announcement of a call and its execution.
+            blocks: u64,
+        },
+        /// Event emitted when a time-locked sudo call is announced.
+        SudoCallAnnounced {
+            /// The hash of the call.
+            call_hash: T::Hash,
+            /// The call announced.
+            call: Box<Call<T>>,
+            /// The block from which the call can be made.
+            executable_at: u64,
+        },
+        /// Event emitted when an announced sudo call is cancelled.
+        SudoCallCancelled {
+            /// The hash of the call.
+            call_hash: T::Hash,
+        },
+        /// Event emitted when an announced sudo call is made.
+        SudoCallExecuted {
+            /// The hash of the call.
+            call_hash: T::Hash,
+        },
     }
 
     // Errors inform users that something went wrong.
@@ -114,6 +154,16 @@ pub mod pallet {
         MaxAllowedUidsLessThanMinAllowedUids,
         /// The maximum allowed UIDs must be less than the default maximum allowed UIDs.
         MaxAllowedUidsGreaterThanDefaultMaxAllowedUids,
+        /// The uid is not registered on the subnet.
+        UidDoesNotExist,
+        /// The call is not time-locked.
+        SudoCallNotTimelocked,
+        /// The call has already been announced.
+        SudoCallAlreadyAnnounced,
+        /// The call has not been announced.
+        SudoCallNotAnnounced,
+        /// The delay of the announced call is not over yet.
+        SudoCallDelayNotElapsed,
     }
     /// Enum for specifying the type of precompile operation.
     #[derive(
@@ -140,6 +190,8 @@ pub mod pallet {
         PureProxy,
         /// Leasing precompile
         Leasing,
+        /// Swap precompile
+        Swap,
     }
 
     #[pallet::type_value]
@@ -159,6 +211,15 @@ pub mod pallet {
         DefaultPrecompileEnabled<T>,
     >;
 
+    #[pallet::storage]
+    /// The number of blocks between the announcement of a time-locked sudo call and its
+    /// execution, zero if sudo calls are not time-locked
+    pub type SudoCallDelay<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    #[pallet::storage]
+    /// Map call hash --> block from which the announced sudo call can be made
+    pub type PendingSudoCalls<T: Config> = StorageMap<_, Identity, T::Hash, u64, OptionQuery>;
+
     /// Dispatchable functions allows users to interact with the pallet and invoke state changes.
     #[pallet::call]
     impl<T: Config> Pallet<T> {
@@ -166,9 +227,7 @@ pub mod pallet {
         /// It is only callable by the root account.
         /// The extrinsic will call the Aura pallet to change the authorities.
         #[pallet::call_index(0)]
-        #[pallet::weight(Weight::from_parts(4_629_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(0_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::swap_authorities(new_authorities.len() as u32))]
         pub fn swap_authorities(
             origin: OriginFor<T>,
             new_authorities: BoundedVec<<T as Config>::AuthorityId, T::MaxAuthorities>,
@@ -187,9 +246,7 @@ pub mod pallet {
         /// It is only callable by the root account.
         /// The extrinsic will call the Subtensor pallet to set the default take.
         #[pallet::call_index(1)]
-        #[pallet::weight(Weight::from_parts(5_420_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(0_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_default_take())]
         pub fn sudo_set_default_take(origin: OriginFor<T>, default_take: u16) -> DispatchResult {
             ensure_root(origin)?;
             pallet_subtensor::Pallet::<T>::set_max_delegate_take(default_take);
@@ -201,12 +258,11 @@ pub mod pallet {
         /// It is only callable by the root account.
         /// The extrinsic will call the Subtensor pallet to set the transaction rate limit.
         #[pallet::call_index(2)]
-        #[pallet::weight(
-            (Weight::from_parts(5_400_000, 0)
-			    .saturating_add(T::DbWeight::get().writes(1_u64)),
+        #[pallet::weight((
+            T::WeightInfo::sudo_set_tx_rate_limit(),
             DispatchClass::Operational,
-            Pays::Yes)
-        )]
+            Pays::Yes
+        ))]
         pub fn sudo_set_tx_rate_limit(origin: OriginFor<T>, tx_rate_limit: u64) -> DispatchResult {
             ensure_root(origin)?;
             pallet_subtensor::Pallet::<T>::set_tx_rate_limit(tx_rate_limit);
@@ -218,9 +274,7 @@ pub mod pallet {
         /// It is only callable by the root account or subnet owner.
         /// The extrinsic will call the Subtensor pallet to set the serving rate limit.
         #[pallet::call_index(3)]
-        #[pallet::weight(Weight::from_parts(22_980_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(2_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_serving_rate_limit())]
         pub fn sudo_set_serving_rate_limit(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -245,9 +299,7 @@ pub mod pallet {
         /// It is only callable by the root account or subnet owner.
         /// The extrinsic will call the Subtensor pallet to set the minimum difficulty.
         #[pallet::call_index(4)]
-        #[pallet::weight(Weight::from_parts(26_390_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(3_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_min_difficulty())]
         pub fn sudo_set_min_difficulty(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -270,9 +322,7 @@ pub mod pallet {
         /// It is only callable by the root account or subnet owner.
         /// The extrinsic will call the Subtensor pallet to set the maximum difficulty.
         #[pallet::call_index(5)]
-        #[pallet::weight(Weight::from_parts(26_990_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(3_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_max_difficulty())]
         pub fn sudo_set_max_difficulty(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -304,9 +354,7 @@ pub mod pallet {
         /// It is only callable by the root account or subnet owner.
         /// The extrinsic will call the Subtensor pallet to set the weights version key.
         #[pallet::call_index(6)]
-        #[pallet::weight(Weight::from_parts(26_220_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(3_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_weights_version_key())]
         pub fn sudo_set_weights_version_key(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -340,9 +388,7 @@ pub mod pallet {
         /// It is only callable by the root account.
         /// The extrinsic will call the Subtensor pallet to set the weights set rate limit.
         #[pallet::call_index(7)]
-        #[pallet::weight(Weight::from_parts(15_060_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(1_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_weights_set_rate_limit())]
         pub fn sudo_set_weights_set_rate_limit(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -368,9 +414,7 @@ pub mod pallet {
         /// It is only callable by the root account, not changeable by the subnet owner.
         /// The extrinsic will call the Subtensor pallet to set the adjustment interval.
         #[pallet::call_index(8)]
-        #[pallet::weight(Weight::from_parts(21_320_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(1_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_adjustment_interval())]
         pub fn sudo_set_adjustment_interval(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -393,11 +437,7 @@ pub mod pallet {
         /// It is only callable by the root account or subnet owner.
         /// The extrinsic will call the Subtensor pallet to set the adjustment alpha.
         #[pallet::call_index(9)]
-        #[pallet::weight(
-            Weight::from_parts(14_000_000, 0)
-                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1))
-                .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(1))
-        )]
+        #[pallet::weight(T::WeightInfo::sudo_set_adjustment_alpha())]
         pub fn sudo_set_adjustment_alpha(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -427,9 +467,7 @@ pub mod pallet {
         /// It is only callable by the root account or subnet owner.
         /// The extrinsic will call the Subtensor pallet to set the adjustment beta.
         #[pallet::call_index(12)]
-        #[pallet::weight(Weight::from_parts(26_890_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(3_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_max_weight_limit())]
         pub fn sudo_set_max_weight_limit(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -461,9 +499,7 @@ pub mod pallet {
         /// It is only callable by the root account or subnet owner.
         /// The extrinsic will call the Subtensor pallet to set the immunity period.
         #[pallet::call_index(13)]
-        #[pallet::weight(Weight::from_parts(26_620_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(3_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_immunity_period())]
         pub fn sudo_set_immunity_period(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -495,9 +531,7 @@ pub mod pallet {
         /// It is only callable by the root account or subnet owner.
         /// The extrinsic will call the Subtensor pallet to set the minimum allowed weights.
         #[pallet::call_index(14)]
-        #[pallet::weight(Weight::from_parts(26_630_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(3_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_min_allowed_weights())]
         pub fn sudo_set_min_allowed_weights(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -529,9 +563,7 @@ pub mod pallet {
         /// It is only callable by the root account and subnet owner.
         /// The extrinsic will call the Subtensor pallet to set the maximum allowed UIDs for a subnet.
         #[pallet::call_index(15)]
-        #[pallet::weight(Weight::from_parts(32_140_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(5_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_max_allowed_uids())]
         pub fn sudo_set_max_allowed_uids(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -574,9 +606,7 @@ pub mod pallet {
         /// It is only callable by the root account or subnet owner.
         /// The extrinsic will call the Subtensor pallet to set the kappa.
         #[pallet::call_index(16)]
-        #[pallet::weight(Weight::from_parts(26_210_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(3_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_kappa())]
         pub fn sudo_set_kappa(origin: OriginFor<T>, netuid: NetUid, kappa: u16) -> DispatchResult {
             let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
                 origin,
@@ -602,9 +632,7 @@ pub mod pallet {
         /// It is only callable by the root account or subnet owner.
         /// The extrinsic will call the Subtensor pallet to set the rho.
         #[pallet::call_index(17)]
-        #[pallet::weight(Weight::from_parts(23_360_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(3_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_rho())]
         pub fn sudo_set_rho(origin: OriginFor<T>, netuid: NetUid, rho: u16) -> DispatchResult {
             let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
                 origin,
@@ -630,9 +658,7 @@ pub mod pallet {
         /// It is only callable by the root account or subnet owner.
         /// The extrinsic will call the Subtensor pallet to set the activity cutoff.
         #[pallet::call_index(18)]
-        #[pallet::weight(Weight::from_parts(28_720_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(4_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_activity_cutoff())]
         pub fn sudo_set_activity_cutoff(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -671,12 +697,10 @@ pub mod pallet {
         /// The extrinsic will call the Subtensor pallet to set the network registration allowed.
         #[pallet::call_index(19)]
         #[pallet::weight((
-			Weight::from_parts(7_343_000, 0)
-                .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(0))
-				.saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
-			DispatchClass::Operational,
-			Pays::Yes
-		))]
+            T::WeightInfo::sudo_set_network_registration_allowed(),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
         pub fn sudo_set_network_registration_allowed(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -697,10 +721,7 @@ pub mod pallet {
         /// It is only callable by the root account or subnet owner.
         /// The extrinsic will call the Subtensor pallet to set the network PoW registration allowed.
         #[pallet::call_index(20)]
-        #[pallet::weight(
-			Weight::from_parts(14_000_000, 0)
-				.saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1))
-		)]
+        #[pallet::weight(T::WeightInfo::sudo_set_network_pow_registration_allowed())]
         pub fn sudo_set_network_pow_registration_allowed(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -731,9 +752,7 @@ pub mod pallet {
         /// It is only callable by the root account.
         /// The extrinsic will call the Subtensor pallet to set the target registrations per interval.
         #[pallet::call_index(21)]
-        #[pallet::weight(Weight::from_parts(44_320_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(3_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_target_registrations_per_interval())]
         pub fn sudo_set_target_registrations_per_interval(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -759,9 +778,7 @@ pub mod pallet {
         /// It is only callable by root and subnet owner.
         /// The extrinsic will call the Subtensor pallet to set the minimum burn.
         #[pallet::call_index(22)]
-        #[pallet::weight(Weight::from_parts(29_970_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(4_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_min_burn())]
         pub fn sudo_set_min_burn(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -799,9 +816,7 @@ pub mod pallet {
         /// It is only callable by root and subnet owner.
         /// The extrinsic will call the Subtensor pallet to set the maximum burn.
         #[pallet::call_index(23)]
-        #[pallet::weight(Weight::from_parts(30_510_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(4_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_max_burn())]
         pub fn sudo_set_max_burn(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -839,9 +854,7 @@ pub mod pallet {
         /// It is only callable by the root account or subnet owner.
         /// The extrinsic will call the Subtensor pallet to set the difficulty.
         #[pallet::call_index(24)]
-        #[pallet::weight(Weight::from_parts(38_500_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(3_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_difficulty())]
         pub fn sudo_set_difficulty(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -858,18 +871,20 @@ pub mod pallet {
         }
 
         /// The extrinsic sets the maximum allowed validators for a subnet.
-        /// It is only callable by the root account.
+        /// It is only callable by the root account or subnet owner.
         /// The extrinsic will call the Subtensor pallet to set the maximum allowed validators.
         #[pallet::call_index(25)]
-        #[pallet::weight(Weight::from_parts(30_930_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(4_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_max_allowed_validators())]
         pub fn sudo_set_max_allowed_validators(
             origin: OriginFor<T>,
             netuid: NetUid,
             max_allowed_validators: u16,
         ) -> DispatchResult {
-            pallet_subtensor::Pallet::<T>::ensure_root_with_rate_limit(origin, netuid)?;
+            let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
+                origin,
+                netuid,
+                &[Hyperparameter::MaxAllowedValidators.into()],
+            )?;
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
@@ -887,6 +902,11 @@ pub mod pallet {
             log::debug!(
                 "MaxAllowedValidatorsSet( netuid: {netuid:?} max_allowed_validators: {max_allowed_validators:?} ) "
             );
+            pallet_subtensor::Pallet::<T>::record_owner_rl(
+                maybe_owner,
+                netuid,
+                &[Hyperparameter::MaxAllowedValidators.into()],
+            );
             Ok(())
         }
 
@@ -894,9 +914,7 @@ pub mod pallet {
         /// It is only callable by the root account or subnet owner.
         /// The extrinsic will call the Subtensor pallet to set the bonds moving average.
         #[pallet::call_index(26)]
-        #[pallet::weight(Weight::from_parts(26_270_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(3_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_bonds_moving_average())]
         pub fn sudo_set_bonds_moving_average(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -907,9 +925,9 @@ pub mod pallet {
                 netuid,
                 &[Hyperparameter::BondsMovingAverage.into()],
             )?;
-            if maybe_owner.is_some() {
+            if maybe_owner.owner.is_some() {
                 ensure!(
-                    bonds_moving_average <= 975000,
+                    bonds_moving_average <= MAX_OWNER_BONDS_MOVING_AVERAGE,
                     Error::<T>::BondsMovingAverageMaxReached
                 )
             }
@@ -934,9 +952,7 @@ pub mod pallet {
         /// It is only callable by the root account or subnet owner.
         /// The extrinsic will call the Subtensor pallet to set the bonds penalty.
         #[pallet::call_index(60)]
-        #[pallet::weight(Weight::from_parts(26_890_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(3_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_bonds_penalty())]
         pub fn sudo_set_bonds_penalty(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -966,9 +982,7 @@ pub mod pallet {
         /// It is only callable by the root account.
         /// The extrinsic will call the Subtensor pallet to set the maximum registrations per block.
         #[pallet::call_index(27)]
-        #[pallet::weight(Weight::from_parts(26_970_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(3_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_max_registrations_per_block())]
         pub fn sudo_set_max_registrations_per_block(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -995,11 +1009,10 @@ pub mod pallet {
         /// The extrinsic will call the Subtensor pallet to set the subnet owner cut.
         #[pallet::call_index(28)]
         #[pallet::weight((
-			Weight::from_parts(14_000_000, 0)
-				.saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
-			DispatchClass::Operational,
-			Pays::Yes
-		))]
+            T::WeightInfo::sudo_set_subnet_owner_cut(),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
         pub fn sudo_set_subnet_owner_cut(
             origin: OriginFor<T>,
             subnet_owner_cut: u16,
@@ -1015,11 +1028,10 @@ pub mod pallet {
         /// The extrinsic will call the Subtensor pallet to set the network rate limit.
         #[pallet::call_index(29)]
         #[pallet::weight((
-			Weight::from_parts(14_000_000, 0)
-				.saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
-			DispatchClass::Operational,
-			Pays::Yes
-		))]
+            T::WeightInfo::sudo_set_network_rate_limit(),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
         pub fn sudo_set_network_rate_limit(
             origin: OriginFor<T>,
             rate_limit: u64,
@@ -1034,15 +1046,15 @@ pub mod pallet {
         /// It is only callable by the root account.
         /// The extrinsic will call the Subtensor pallet to set the tempo.
         #[pallet::call_index(30)]
-        #[pallet::weight(Weight::from_parts(25_790_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(3_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_tempo())]
         pub fn sudo_set_tempo(origin: OriginFor<T>, netuid: NetUid, tempo: u16) -> DispatchResult {
             pallet_subtensor::Pallet::<T>::ensure_root_with_rate_limit(origin, netuid)?;
             ensure!(
                 pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
                 Error::<T>::SubnetDoesNotExist
             );
+            // A tempo in blocks replaces a tempo set in seconds
+            pallet_subtensor::Pallet::<T>::set_tempo_seconds(netuid, None)?;
             pallet_subtensor::Pallet::<T>::set_tempo(netuid, tempo);
             log::debug!("TempoSet( netuid: {netuid:?} tempo: {tempo:?} ) ");
             Ok(())
@@ -1053,8 +1065,7 @@ pub mod pallet {
         /// The extrinsic will call the Subtensor pallet to set the issuance for the network.
         #[pallet::call_index(33)]
         #[pallet::weight((
-            Weight::from_parts(2_875_000, 0)
-			    .saturating_add(T::DbWeight::get().writes(1_u64)),
+            T::WeightInfo::sudo_set_total_issuance(),
             DispatchClass::Operational,
             Pays::Yes
         ))]
@@ -1074,16 +1085,18 @@ pub mod pallet {
         /// The extrinsic will call the Subtensor pallet to set the immunity period for the network.
         #[pallet::call_index(35)]
         #[pallet::weight((
-			Weight::from_parts(14_000_000, 0)
-				.saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
-			DispatchClass::Operational,
-			Pays::Yes
-		))]
+            T::WeightInfo::sudo_set_network_immunity_period(),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
         pub fn sudo_set_network_immunity_period(
             origin: OriginFor<T>,
             immunity_period: u64,
         ) -> DispatchResult {
             ensure_root(origin)?;
+            Self::ensure_sudo_call_unlocked(Call::<T>::sudo_set_network_immunity_period {
+                immunity_period,
+            })?;
 
             pallet_subtensor::Pallet::<T>::set_network_immunity_period(immunity_period);
 
@@ -1097,16 +1110,18 @@ pub mod pallet {
         /// The extrinsic will call the Subtensor pallet to set the min lock cost for the network.
         #[pallet::call_index(36)]
         #[pallet::weight((
-			Weight::from_parts(14_000_000, 0)
-				.saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
-			DispatchClass::Operational,
-			Pays::Yes
-		))]
+            T::WeightInfo::sudo_set_network_min_lock_cost(),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
         pub fn sudo_set_network_min_lock_cost(
             origin: OriginFor<T>,
             lock_cost: TaoCurrency,
         ) -> DispatchResult {
             ensure_root(origin)?;
+            Self::ensure_sudo_call_unlocked(Call::<T>::sudo_set_network_min_lock_cost {
+                lock_cost,
+            })?;
 
             pallet_subtensor::Pallet::<T>::set_network_min_lock(lock_cost);
 
@@ -1120,13 +1135,13 @@ pub mod pallet {
         /// The extrinsic will call the Subtensor pallet to set the subnet limit.
         #[pallet::call_index(37)]
         #[pallet::weight((
-			Weight::from_parts(14_000_000, 0)
-				.saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
-			DispatchClass::Operational,
-			Pays::No
-		))]
+            T::WeightInfo::sudo_set_subnet_limit(),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
         pub fn sudo_set_subnet_limit(origin: OriginFor<T>, max_subnets: u16) -> DispatchResult {
             ensure_root(origin)?;
+            Self::ensure_sudo_call_unlocked(Call::<T>::sudo_set_subnet_limit { max_subnets })?;
             pallet_subtensor::Pallet::<T>::set_max_subnets(max_subnets);
             log::debug!("MaxSubnets ( max_subnets: {max_subnets:?} ) ");
             Ok(())
@@ -1137,16 +1152,18 @@ pub mod pallet {
         /// The extrinsic will call the Subtensor pallet to set the lock reduction interval.
         #[pallet::call_index(38)]
         #[pallet::weight((
-			Weight::from_parts(14_000_000, 0)
-				.saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1)),
-			DispatchClass::Operational,
-			Pays::Yes
-		))]
+            T::WeightInfo::sudo_set_lock_reduction_interval(),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
         pub fn sudo_set_lock_reduction_interval(
             origin: OriginFor<T>,
             interval: u64,
         ) -> DispatchResult {
             ensure_root(origin)?;
+            Self::ensure_sudo_call_unlocked(Call::<T>::sudo_set_lock_reduction_interval {
+                interval,
+            })?;
 
             pallet_subtensor::Pallet::<T>::set_lock_reduction_interval(interval);
 
@@ -1160,9 +1177,7 @@ pub mod pallet {
         /// The extrinsic will call the Subtensor pallet to set the recycled RAO.
         #[pallet::call_index(39)]
         #[pallet::weight((
-            Weight::from_parts(15_060_000, 4045)
-                .saturating_add(T::DbWeight::get().reads(1_u64))
-                .saturating_add(T::DbWeight::get().writes(1_u64)),
+            T::WeightInfo::sudo_set_rao_recycled(),
             DispatchClass::Operational,
             Pays::Yes
         ))]
@@ -1185,7 +1200,7 @@ pub mod pallet {
         /// The extrinsic will call the Subtensor pallet to set the weights min stake.
         #[pallet::call_index(42)]
         #[pallet::weight((
-            Weight::from_parts(5_000_000, 0).saturating_add(T::DbWeight::get().writes(1_u64)),
+            T::WeightInfo::sudo_set_stake_threshold(),
             DispatchClass::Operational,
             Pays::Yes
         ))]
@@ -1200,9 +1215,7 @@ pub mod pallet {
         /// The extrinsic will call the Subtensor pallet to set the minimum stake required for nominators.
         #[pallet::call_index(43)]
         #[pallet::weight((
-            Weight::from_parts(28_050_000, 6792)
-                .saturating_add(T::DbWeight::get().reads(4_u64))
-                .saturating_add(T::DbWeight::get().writes(1_u64)),
+            T::WeightInfo::sudo_set_nominator_min_required_stake(),
             DispatchClass::Operational,
             Pays::Yes
         ))]
@@ -1228,7 +1241,7 @@ pub mod pallet {
         /// The extrinsic will call the Subtensor pallet to set the rate limit for delegate take transactions.
         #[pallet::call_index(45)]
         #[pallet::weight((
-            Weight::from_parts(5_019_000, 0).saturating_add(T::DbWeight::get().writes(1_u64)),
+            T::WeightInfo::sudo_set_tx_delegate_take_rate_limit(),
             DispatchClass::Operational,
             Pays::Yes
         ))]
@@ -1249,7 +1262,7 @@ pub mod pallet {
         /// The extrinsic will call the Subtensor pallet to set the minimum delegate take.
         #[pallet::call_index(46)]
         #[pallet::weight((
-            Weight::from_parts(5_000_000, 0).saturating_add(T::DbWeight::get().writes(1_u64)),
+            T::WeightInfo::sudo_set_min_delegate_take(),
             DispatchClass::Operational,
             Pays::Yes
         ))]
@@ -1264,9 +1277,7 @@ pub mod pallet {
         /// It is only callable by the root account or subnet owner.
         /// The extrinsic will call the Subtensor pallet to set the value.
         #[pallet::call_index(49)]
-        #[pallet::weight(Weight::from_parts(26_730_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(3_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_commit_reveal_weights_enabled())]
         pub fn sudo_set_commit_reveal_weights_enabled(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -1304,8 +1315,7 @@ pub mod pallet {
         /// This function has a fixed weight of 0 and is classified as an operational transaction that does not incur any fees.
         #[pallet::call_index(50)]
         #[pallet::weight((
-            Weight::from_parts(18_300_000, 0)
-			    .saturating_add(T::DbWeight::get().writes(1_u64)),
+            T::WeightInfo::sudo_set_liquid_alpha_enabled(),
             DispatchClass::Normal,
             Pays::Yes
         ))]
@@ -1332,9 +1342,7 @@ pub mod pallet {
         /// Sets values for liquid alpha
         #[pallet::call_index(51)]
         #[pallet::weight((
-            Weight::from_parts(25_280_000, 4089)
-                .saturating_add(T::DbWeight::get().reads(3_u64))
-                .saturating_add(T::DbWeight::get().writes(1_u64)),
+            T::WeightInfo::sudo_set_alpha_values(),
             DispatchClass::Normal,
             Pays::Yes
         ))]
@@ -1378,8 +1386,7 @@ pub mod pallet {
         /// Weight is handled by the `#[pallet::weight]` attribute.
         #[pallet::call_index(54)]
         #[pallet::weight((
-            Weight::from_parts(5_000_000, 0)
-			    .saturating_add(T::DbWeight::get().writes(1_u64)),
+            T::WeightInfo::sudo_set_coldkey_swap_schedule_duration(),
             DispatchClass::Operational,
             Pays::Yes
         ))]
@@ -1415,8 +1422,7 @@ pub mod pallet {
         /// Weight is handled by the `#[pallet::weight]` attribute.
         #[pallet::call_index(55)]
         #[pallet::weight((
-            Weight::from_parts(5_000_000, 0)
-			    .saturating_add(T::DbWeight::get().writes(1_u64)),
+            T::WeightInfo::sudo_set_dissolve_network_schedule_duration(),
             DispatchClass::Operational,
             Pays::Yes
         ))]
@@ -1453,9 +1459,7 @@ pub mod pallet {
         /// # Weight
         /// Weight is handled by the `#[pallet::weight]` attribute.
         #[pallet::call_index(57)]
-        #[pallet::weight(Weight::from_parts(26_950_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(3_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_commit_reveal_weights_interval())]
         pub fn sudo_set_commit_reveal_weights_interval(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -1496,9 +1500,7 @@ pub mod pallet {
         /// # Weight
         /// Weight is handled by the `#[pallet::weight]` attribute.
         #[pallet::call_index(58)]
-        #[pallet::weight(Weight::from_parts(27_199_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(1_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_evm_chain_id())]
         pub fn sudo_set_evm_chain_id(origin: OriginFor<T>, chain_id: u64) -> DispatchResult {
             // Ensure the call is made by the root account
             ensure_root(origin)?;
@@ -1523,9 +1525,7 @@ pub mod pallet {
         /// No change should be signaled while any change is pending. Returns an error if a change
         /// is already pending.
         #[pallet::call_index(59)]
-        #[pallet::weight(Weight::from_parts(7_779_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(1_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::schedule_grandpa_change(next_authorities.len() as u32))]
         pub fn schedule_grandpa_change(
             origin: OriginFor<T>,
             // grandpa ID is always the same type, so we don't need to parametrize it via `Config`
@@ -1548,8 +1548,7 @@ pub mod pallet {
         /// This function has a fixed weight of 0 and is classified as an operational transaction that does not incur any fees.
         #[pallet::call_index(61)]
         #[pallet::weight((
-            Weight::from_parts(20_460_000, 0)
-			    .saturating_add(T::DbWeight::get().writes(1_u64)),
+            T::WeightInfo::sudo_set_toggle_transfer(),
             DispatchClass::Normal,
             Pays::Yes
         ))]
@@ -1584,7 +1583,11 @@ pub mod pallet {
         /// - `recycle_or_burn`: The desired behaviour of the "burn" UID(s) for the subnet.
         ///
         #[pallet::call_index(80)]
-        #[pallet::weight((1_000_000, DispatchClass::Normal, Pays::Yes))] // TODO: add proper weights
+        #[pallet::weight((
+            T::WeightInfo::sudo_set_recycle_or_burn(),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
         pub fn sudo_set_recycle_or_burn(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -1620,8 +1623,7 @@ pub mod pallet {
         /// Weight is handled by the `#[pallet::weight]` attribute.
         #[pallet::call_index(62)]
         #[pallet::weight((
-            Weight::from_parts(6_392_000, 3507)
-			    .saturating_add(T::DbWeight::get().reads(1_u64)),
+            T::WeightInfo::sudo_toggle_evm_precompile(),
             DispatchClass::Operational,
             Pays::Yes
         ))]
@@ -1654,13 +1656,13 @@ pub mod pallet {
         /// Weight is handled by the `#[pallet::weight]` attribute.
         #[pallet::call_index(63)]
         #[pallet::weight((
-            Weight::from_parts(3_000_000, 0)
-			    .saturating_add(T::DbWeight::get().writes(1_u64)),
+            T::WeightInfo::sudo_set_subnet_moving_alpha(),
             DispatchClass::Operational,
             Pays::Yes
         ))]
         pub fn sudo_set_subnet_moving_alpha(origin: OriginFor<T>, alpha: I96F32) -> DispatchResult {
             ensure_root(origin)?;
+            Self::ensure_sudo_call_unlocked(Call::<T>::sudo_set_subnet_moving_alpha { alpha })?;
             pallet_subtensor::SubnetMovingAlpha::<T>::set(alpha);
 
             log::debug!("SubnetMovingAlphaSet( alpha: {alpha:?} )");
@@ -1680,7 +1682,11 @@ pub mod pallet {
         /// # Weight
         /// Weight is handled by the `#[pallet::weight]` attribute.
         #[pallet::call_index(64)]
-        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        #[pallet::weight((
+            T::WeightInfo::sudo_set_subnet_owner_hotkey(),
+            DispatchClass::Operational,
+            Pays::No
+        ))]
         pub fn sudo_set_subnet_owner_hotkey(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -1706,8 +1712,7 @@ pub mod pallet {
         /// Weight is handled by the `#[pallet::weight]` attribute.
         #[pallet::call_index(65)]
         #[pallet::weight((
-            Weight::from_parts(3_918_000, 0)
-			    .saturating_add(T::DbWeight::get().writes(1_u64)),
+            T::WeightInfo::sudo_set_ema_price_halving_period(),
             DispatchClass::Operational,
             Pays::Yes
         ))]
@@ -1742,9 +1747,7 @@ pub mod pallet {
         /// Weight is handled by the `#[pallet::weight]` attribute.
         #[pallet::call_index(68)]
         #[pallet::weight((
-            Weight::from_parts(23_140_000, 4045)
-                .saturating_add(T::DbWeight::get().reads(3_u64))
-                .saturating_add(T::DbWeight::get().writes(1_u64)),
+            T::WeightInfo::sudo_set_alpha_sigmoid_steepness(),
             DispatchClass::Normal,
             Pays::Yes
         ))]
@@ -1792,8 +1795,7 @@ pub mod pallet {
         /// This function has a fixed weight of 0 and is classified as an operational transaction that does not incur any fees.
         #[pallet::call_index(69)]
         #[pallet::weight((
-            Weight::from_parts(20_460_000, 0)
-			    .saturating_add(T::DbWeight::get().writes(1_u64)),
+            T::WeightInfo::sudo_set_yuma3_enabled(),
             DispatchClass::Normal,
             Pays::Yes
         ))]
@@ -1830,8 +1832,7 @@ pub mod pallet {
         /// This function has a fixed weight of 0 and is classified as an operational transaction that does not incur any fees.
         #[pallet::call_index(70)]
         #[pallet::weight((
-            Weight::from_parts(22_340_000, 0)
-			    .saturating_add(T::DbWeight::get().writes(1_u64)),
+            T::WeightInfo::sudo_set_bonds_reset_enabled(),
             DispatchClass::Normal,
             Pays::Yes
         ))]
@@ -1889,9 +1890,7 @@ pub mod pallet {
         /// This function is rate-limited to one call per subnet per interval (e.g., one week).
         #[pallet::call_index(67)]
         #[pallet::weight((
-            Weight::from_parts(20_570_000, 4204)
-                .saturating_add(T::DbWeight::get().reads(2_u64))
-                .saturating_add(T::DbWeight::get().writes(2_u64)),
+            T::WeightInfo::sudo_set_sn_owner_hotkey(),
             DispatchClass::Normal,
             Pays::Yes
         ))]
@@ -1917,8 +1916,7 @@ pub mod pallet {
         /// Weight is handled by the `#[pallet::weight]` attribute.
         #[pallet::call_index(66)]
         #[pallet::weight((
-            Weight::from_parts(17_980_000, 0)
-			    .saturating_add(T::DbWeight::get().writes(1_u64)),
+            T::WeightInfo::sudo_set_subtoken_enabled(),
             DispatchClass::Operational,
             Pays::Yes
         ))]
@@ -1939,9 +1937,7 @@ pub mod pallet {
         /// Sets the commit-reveal weights version for all subnets
         #[pallet::call_index(71)]
         #[pallet::weight((
-            Weight::from_parts(7_114_000, 0)
-                .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1))
-                .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(0_u64)),
+            T::WeightInfo::sudo_set_commit_reveal_version(),
             DispatchClass::Operational,
             Pays::Yes
         ))]
@@ -1956,9 +1952,7 @@ pub mod pallet {
 
         /// Sets the number of immune owner neurons
         #[pallet::call_index(72)]
-        #[pallet::weight(Weight::from_parts(18_020_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(2_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_owner_immune_neuron_limit())]
         pub fn sudo_set_owner_immune_neuron_limit(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -1982,9 +1976,7 @@ pub mod pallet {
         /// It is only callable by the root account.
         /// The extrinsic will call the Subtensor pallet to set the childkey burn.
         #[pallet::call_index(73)]
-        #[pallet::weight(Weight::from_parts(15_650_000, 0)
-		.saturating_add(<T as frame_system::Config>::DbWeight::get().reads(1_u64))
-		.saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_ck_burn())]
         pub fn sudo_set_ck_burn(origin: OriginFor<T>, burn: u64) -> DispatchResult {
             ensure_root(origin)?;
             pallet_subtensor::Pallet::<T>::set_ck_burn(burn);
@@ -1996,11 +1988,9 @@ pub mod pallet {
         /// Only callable by root.
         #[pallet::call_index(74)]
         #[pallet::weight((
-			Weight::from_parts(5_771_000, 0)
-				.saturating_add(<T as frame_system::Config>::DbWeight::get().reads(0_u64))
-				.saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)),
-			DispatchClass::Operational
-		))]
+            T::WeightInfo::sudo_set_admin_freeze_window(),
+            DispatchClass::Operational
+        ))]
         pub fn sudo_set_admin_freeze_window(origin: OriginFor<T>, window: u16) -> DispatchResult {
             ensure_root(origin)?;
             pallet_subtensor::Pallet::<T>::set_admin_freeze_window(window);
@@ -2012,11 +2002,9 @@ pub mod pallet {
         /// Only callable by root.
         #[pallet::call_index(75)]
         #[pallet::weight((
-			Weight::from_parts(5_701_000, 0)
-				.saturating_add(<T as frame_system::Config>::DbWeight::get().reads(0_u64))
-				.saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)),
-			DispatchClass::Operational
-		))]
+            T::WeightInfo::sudo_set_owner_hparam_rate_limit(),
+            DispatchClass::Operational
+        ))]
         pub fn sudo_set_owner_hparam_rate_limit(
             origin: OriginFor<T>,
             epochs: u16,
@@ -2029,9 +2017,7 @@ pub mod pallet {
 
         /// Sets the desired number of mechanisms in a subnet
         #[pallet::call_index(76)]
-        #[pallet::weight(Weight::from_parts(15_000_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(1_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_mechanism_count())]
         pub fn sudo_set_mechanism_count(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -2055,9 +2041,9 @@ pub mod pallet {
 
         /// Sets the emission split between mechanisms in a subnet
         #[pallet::call_index(77)]
-        #[pallet::weight(Weight::from_parts(15_000_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(1_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_mechanism_emission_split(
+            maybe_split.as_ref().map_or(0, |split| split.len() as u32)
+        ))]
         pub fn sudo_set_mechanism_emission_split(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -2085,9 +2071,10 @@ pub mod pallet {
         /// the lowest emitters while preserving temporally and owner immune UIDs. The UIDs are
         /// then compressed to the left and storage is migrated to the new compressed UIDs.
         #[pallet::call_index(78)]
-        #[pallet::weight(Weight::from_parts(32_880_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(6_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        // Charged by the current size of the subnet, every uid is either removed or compressed
+        #[pallet::weight(T::WeightInfo::sudo_trim_to_max_allowed_uids(
+            pallet_subtensor::SubnetworkN::<T>::get(*netuid).into()
+        ))]
         pub fn sudo_trim_to_max_allowed_uids(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -2112,9 +2099,7 @@ pub mod pallet {
         /// The extrinsic sets the minimum allowed UIDs for a subnet.
         /// It is only callable by the root account.
         #[pallet::call_index(79)]
-        #[pallet::weight(Weight::from_parts(31_550_000, 0)
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().reads(5_u64))
-        .saturating_add(<T as frame_system::Config>::DbWeight::get().writes(1_u64)))]
+        #[pallet::weight(T::WeightInfo::sudo_set_min_allowed_uids())]
         pub fn sudo_set_min_allowed_uids(
             origin: OriginFor<T>,
             netuid: NetUid,
@@ -2142,6 +2127,1046 @@ pub mod pallet {
             );
             Ok(())
         }
+
+        /// The extrinsic sets the registration churn parameters for a subnet.
+        /// It is only callable by the root account or subnet owner.
+        /// Once a coldkey re-registers more than `threshold` times within `window` blocks of its
+        /// hotkeys being deregistered, its burn on the subnet escalates. A threshold of 0 disables
+        /// the escalation.
+        #[pallet::call_index(89)]
+        #[pallet::weight(T::WeightInfo::sudo_set_churn_burn_params())]
+        pub fn sudo_set_churn_burn_params(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            threshold: u16,
+            window: u64,
+        ) -> DispatchResult {
+            let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
+                origin,
+                netuid,
+                &[Hyperparameter::ChurnBurn.into()],
+            )?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(window > 0, Error::<T>::ValueNotInBounds);
+
+            pallet_subtensor::Pallet::<T>::set_churn_burn_params(netuid, threshold, window);
+            pallet_subtensor::Pallet::<T>::record_owner_rl(
+                maybe_owner,
+                netuid,
+                &[Hyperparameter::ChurnBurn.into()],
+            );
+            log::debug!(
+                "ChurnBurnParamsSet( netuid: {netuid:?} threshold: {threshold:?} window: {window:?} ) "
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the fraction of a subnet's alpha reserve, normalized to u16::MAX,
+        /// above which an unstake may be sliced over multiple blocks.
+        /// It is only callable by the root account.
+        #[pallet::call_index(81)]
+        #[pallet::weight((
+            T::WeightInfo::sudo_set_sliced_unstake_depth_fraction(),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
+        pub fn sudo_set_sliced_unstake_depth_fraction(
+            origin: OriginFor<T>,
+            fraction: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_sliced_unstake_depth_fraction(fraction);
+            Ok(())
+        }
+
+        /// The extrinsic sets the feature bitmask of a subnet, updating commit-reveal, liquid
+        /// alpha, transfers, user liquidity and the registration mode at once.
+        /// It is callable by the root account or the subnet owner. Only root may open or close
+        /// burned registration or disable user liquidity.
+        #[pallet::call_index(82)]
+        #[pallet::weight(T::WeightInfo::sudo_set_subnet_features())]
+        pub fn sudo_set_subnet_features(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            features: u16,
+        ) -> DispatchResult {
+            let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
+                origin,
+                netuid,
+                &[Hyperparameter::SubnetFeatures.into()],
+            )?;
+
+            pallet_subtensor::Pallet::<T>::do_set_subnet_features(
+                netuid,
+                features,
+                maybe_owner.owner.is_none(),
+            )?;
+            pallet_subtensor::Pallet::<T>::record_owner_rl(
+                maybe_owner,
+                netuid,
+                &[Hyperparameter::SubnetFeatures.into()],
+            );
+            log::debug!("SubnetFeaturesSet( netuid: {netuid:?} features: {features:?} ) ");
+            Ok(())
+        }
+
+        /// The extrinsic sets the maximum number of operations a coldkey may have scheduled at
+        /// once, counting coldkey swaps, sliced unstakes and unstake timelock decreases.
+        /// It is only callable by the root account.
+        #[pallet::call_index(83)]
+        #[pallet::weight((
+            T::WeightInfo::sudo_set_max_scheduled_operations(),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
+        pub fn sudo_set_max_scheduled_operations(origin: OriginFor<T>, max: u32) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(max > 0, Error::<T>::ValueNotInBounds);
+            pallet_subtensor::Pallet::<T>::set_max_scheduled_operations(max);
+            log::debug!("MaxScheduledOperationsSet( max: {max:?} ) ");
+            Ok(())
+        }
+
+        /// The extrinsic makes a subnet accept only timelocked weight commits, which the chain
+        /// decrypts and applies itself, or accept hash commits again.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(84)]
+        #[pallet::weight(T::WeightInfo::sudo_set_timelocked_weights_only())]
+        pub fn sudo_set_timelocked_weights_only(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            enabled: bool,
+        ) -> DispatchResult {
+            let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
+                origin,
+                netuid,
+                &[Hyperparameter::TimelockedWeightsOnly.into()],
+            )?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_timelocked_weights_only(netuid, enabled);
+            log::debug!("TimelockedWeightsOnlySet( netuid: {netuid:?} enabled: {enabled:?} ) ");
+            pallet_subtensor::Pallet::<T>::record_owner_rl(
+                maybe_owner,
+                netuid,
+                &[Hyperparameter::TimelockedWeightsOnly.into()],
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks at the start of the reveal epoch during which
+        /// weight commits on a subnet can be revealed, 0 for the whole epoch.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(85)]
+        #[pallet::weight(T::WeightInfo::sudo_set_reveal_window())]
+        pub fn sudo_set_reveal_window(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            blocks: u64,
+        ) -> DispatchResult {
+            let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
+                origin,
+                netuid,
+                &[Hyperparameter::RevealWindow.into()],
+            )?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_reveal_window(netuid, blocks)?;
+            log::debug!("RevealWindowSet( netuid: {netuid:?} blocks: {blocks:?} ) ");
+            pallet_subtensor::Pallet::<T>::record_owner_rl(
+                maybe_owner,
+                netuid,
+                &[Hyperparameter::RevealWindow.into()],
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the bounds within which subnet owners set the commit reveal period
+        /// and reveal window of their subnet.
+        /// It is only callable by the root account.
+        #[pallet::call_index(86)]
+        #[pallet::weight((
+            T::WeightInfo::sudo_set_commit_reveal_bounds(),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
+        pub fn sudo_set_commit_reveal_bounds(
+            origin: OriginFor<T>,
+            min_periods: u64,
+            max_periods: u64,
+            min_reveal_window: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_commit_reveal_bounds(
+                min_periods,
+                max_periods,
+                min_reveal_window,
+            )?;
+            log::debug!(
+                "CommitRevealBoundsSet( min_periods: {min_periods:?} max_periods: {max_periods:?} min_reveal_window: {min_reveal_window:?} ) "
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the tempo of a subnet in seconds, converted to blocks with the slot
+        /// duration of the chain so the tempo keeps its length across block time changes. `None`
+        /// keeps the current tempo in blocks.
+        /// It is only callable by the root account.
+        #[pallet::call_index(87)]
+        #[pallet::weight(T::WeightInfo::sudo_set_tempo_seconds())]
+        pub fn sudo_set_tempo_seconds(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            seconds: Option<u64>,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_root_with_rate_limit(origin, netuid)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_tempo_seconds(netuid, seconds)?;
+            log::debug!("TempoSecondsSet( netuid: {netuid:?} seconds: {seconds:?} ) ");
+            Ok(())
+        }
+
+        /// The extrinsic enables or disables merkle-committed weight uploads on a subnet.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(88)]
+        #[pallet::weight(T::WeightInfo::sudo_set_merkle_weights_enabled())]
+        pub fn sudo_set_merkle_weights_enabled(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            enabled: bool,
+        ) -> DispatchResult {
+            let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
+                origin,
+                netuid,
+                &[Hyperparameter::MerkleWeights.into()],
+            )?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_merkle_weights_enabled(netuid, enabled);
+            log::debug!("MerkleWeightsEnabledSet( netuid: {netuid:?} enabled: {enabled:?} ) ");
+            pallet_subtensor::Pallet::<T>::record_owner_rl(
+                maybe_owner,
+                netuid,
+                &[Hyperparameter::MerkleWeights.into()],
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the bounds within which subnet owners set the liquid alpha values
+        /// of their subnet.
+        /// It is only callable by the root account.
+        #[pallet::call_index(90)]
+        #[pallet::weight((
+            T::WeightInfo::sudo_set_liquid_alpha_bounds(),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
+        pub fn sudo_set_liquid_alpha_bounds(
+            origin: OriginFor<T>,
+            min_alpha: u16,
+            max_alpha: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_liquid_alpha_bounds(min_alpha, max_alpha)?;
+            log::debug!(
+                "LiquidAlphaBoundsSet( min_alpha: {min_alpha:?} max_alpha: {max_alpha:?} ) "
+            );
+            Ok(())
+        }
+
+        /// The extrinsic selects the consensus engine the epoch of a subnet computes its
+        /// consensus with.
+        /// It is only callable by the root account.
+        #[pallet::call_index(91)]
+        #[pallet::weight((
+            T::WeightInfo::sudo_set_consensus_engine(),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
+        pub fn sudo_set_consensus_engine(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            engine: ConsensusEngineKind,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                !netuid.is_root() && pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_consensus_engine(netuid, engine);
+            log::debug!("ConsensusEngineSet( netuid: {netuid:?} engine: {engine:?} ) ");
+            Ok(())
+        }
+
+        /// Sets the owner rate limit in epochs of a group of hyperparameters on a subnet, so that
+        /// owner updates in one group do not hold back the others for longer than the group
+        /// needs. `None` lets the group follow the global owner rate limit again.
+        /// Only callable by root.
+        #[pallet::call_index(92)]
+        #[pallet::weight((
+            T::WeightInfo::sudo_set_owner_hparam_group_rate_limit(),
+            DispatchClass::Operational
+        ))]
+        pub fn sudo_set_owner_hparam_group_rate_limit(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            group: HyperparameterGroup,
+            epochs: Option<u16>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_owner_hyperparam_group_rate_limit(
+                netuid, group, epochs,
+            );
+            log::debug!(
+                "OwnerHyperparamGroupRateLimitSet( netuid: {netuid:?} group: {group:?} epochs: {epochs:?} ) "
+            );
+            Ok(())
+        }
+
+        /// The extrinsic excludes the self-weight of every validator on a subnet from its
+        /// consensus, including the one of the subnet owner, or includes the owner's again.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(93)]
+        #[pallet::weight(T::WeightInfo::sudo_set_self_weights_excluded())]
+        pub fn sudo_set_self_weights_excluded(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            excluded: bool,
+        ) -> DispatchResult {
+            let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
+                origin,
+                netuid,
+                &[Hyperparameter::SelfWeightsExcluded.into()],
+            )?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_self_weights_excluded(netuid, excluded);
+            log::debug!("SelfWeightsExcludedSet( netuid: {netuid:?} excluded: {excluded:?} ) ");
+            pallet_subtensor::Pallet::<T>::record_owner_rl(
+                maybe_owner,
+                netuid,
+                &[Hyperparameter::SelfWeightsExcluded.into()],
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks after their last update that the epoch of a
+        /// subnet ignores the weights of a validator, zero to never expire them.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(94)]
+        #[pallet::weight(T::WeightInfo::sudo_set_weight_expiry_blocks())]
+        pub fn sudo_set_weight_expiry_blocks(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            blocks: u64,
+        ) -> DispatchResult {
+            let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
+                origin,
+                netuid,
+                &[Hyperparameter::WeightExpiry.into()],
+            )?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_weight_expiry_blocks(netuid, blocks);
+            log::debug!("WeightExpiryBlocksSet( netuid: {netuid:?} blocks: {blocks:?} ) ");
+            pallet_subtensor::Pallet::<T>::record_owner_rl(
+                maybe_owner,
+                netuid,
+                &[Hyperparameter::WeightExpiry.into()],
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the minimum stake a neuron of a subnet needs for a validator
+        /// permit, zero for none. Permits go to the neurons with the most stake among those
+        /// meeting the minimum, up to the maximum allowed validators.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(95)]
+        #[pallet::weight(T::WeightInfo::sudo_set_validator_permit_min_stake())]
+        pub fn sudo_set_validator_permit_min_stake(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            min_stake: u64,
+        ) -> DispatchResult {
+            let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
+                origin,
+                netuid,
+                &[Hyperparameter::ValidatorPermitMinStake.into()],
+            )?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_validator_permit_min_stake(netuid, min_stake);
+            log::debug!(
+                "ValidatorPermitMinStakeSet( netuid: {netuid:?} min_stake: {min_stake:?} ) "
+            );
+            pallet_subtensor::Pallet::<T>::record_owner_rl(
+                maybe_owner,
+                netuid,
+                &[Hyperparameter::ValidatorPermitMinStake.into()],
+            );
+            Ok(())
+        }
+
+        /// The extrinsic overrides the weights set rate limit of a uid of a subnet, or removes
+        /// the override with `None` so the uid follows the rate limit of the subnet again.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(96)]
+        #[pallet::weight(T::WeightInfo::sudo_set_weights_set_rate_limit_override())]
+        pub fn sudo_set_weights_set_rate_limit_override(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            uid: u16,
+            limit: Option<u64>,
+        ) -> DispatchResult {
+            let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
+                origin,
+                netuid,
+                &[Hyperparameter::WeightsSetRateLimitOverride.into()],
+            )?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                pallet_subtensor::Pallet::<T>::is_uid_exist_on_network(netuid, uid),
+                Error::<T>::UidDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_weights_set_rate_limit_override(netuid, uid, limit);
+            log::debug!(
+                "WeightsSetRateLimitOverrideSet( netuid: {netuid:?} uid: {uid:?} limit: {limit:?} ) "
+            );
+            pallet_subtensor::Pallet::<T>::record_owner_rl(
+                maybe_owner,
+                netuid,
+                &[Hyperparameter::WeightsSetRateLimitOverride.into()],
+            );
+            Ok(())
+        }
+
+        /// The extrinsic makes the max weight limit of a subnet adaptive: every tempo the epoch
+        /// sets it to `multiple` times the uniform weight over the active uids of the subnet.
+        /// A multiple of zero keeps the last max weight limit fixed again.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(97)]
+        #[pallet::weight(T::WeightInfo::sudo_set_adaptive_max_weight_limit())]
+        pub fn sudo_set_adaptive_max_weight_limit(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            multiple: u16,
+        ) -> DispatchResult {
+            let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
+                origin,
+                netuid,
+                &[Hyperparameter::AdaptiveMaxWeightLimit.into()],
+            )?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_adaptive_max_weight_limit(netuid, multiple);
+            log::debug!("AdaptiveMaxWeightLimitSet( netuid: {netuid:?} multiple: {multiple:?} ) ");
+            pallet_subtensor::Pallet::<T>::record_owner_rl(
+                maybe_owner,
+                netuid,
+                &[Hyperparameter::AdaptiveMaxWeightLimit.into()],
+            );
+            Ok(())
+        }
+
+        /// The extrinsic enables or disables the burn auction of a subnet. With the auction
+        /// enabled, burned registrations are bid for and settled at the end of every adjustment
+        /// interval, instead of being first come, first served. Pending bids are refunded at the
+        /// end of the interval once the auction is disabled.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(98)]
+        #[pallet::weight(T::WeightInfo::sudo_set_burn_auction_enabled())]
+        pub fn sudo_set_burn_auction_enabled(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            enabled: bool,
+        ) -> DispatchResult {
+            let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
+                origin,
+                netuid,
+                &[Hyperparameter::BurnAuction.into()],
+            )?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_burn_auction_enabled(netuid, enabled);
+            log::debug!("BurnAuctionEnabledSet( netuid: {netuid:?} enabled: {enabled:?} ) ");
+            pallet_subtensor::Pallet::<T>::record_owner_rl(
+                maybe_owner,
+                netuid,
+                &[Hyperparameter::BurnAuction.into()],
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the gains of the PID controller of the pow difficulty of a subnet,
+        /// in millionths. With gains set, the difficulty is set by the controller on every
+        /// adjustment interval instead of the step-wise adjustment. All zero gains disable it.
+        /// It is only callable by the root account.
+        #[pallet::call_index(99)]
+        #[pallet::weight(T::WeightInfo::sudo_set_difficulty_controller_gains())]
+        pub fn sudo_set_difficulty_controller_gains(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            kp: u32,
+            ki: u32,
+            kd: u32,
+        ) -> DispatchResult {
+            pallet_subtensor::Pallet::<T>::ensure_root_with_rate_limit(origin, netuid)?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            pallet_subtensor::Pallet::<T>::set_difficulty_controller_gains(
+                netuid,
+                DifficultyControllerGains { kp, ki, kd },
+            );
+            log::debug!(
+                "DifficultyControllerGainsSet( netuid: {netuid:?} kp: {kp:?} ki: {ki:?} kd: {kd:?} ) "
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the minimum balance or stake the coldkey of a hotkey needs for
+        /// the free set_weights and serve_axon extrinsics. Zero disables the check.
+        /// It is only callable by the root account.
+        #[pallet::call_index(100)]
+        #[pallet::weight((
+            T::WeightInfo::sudo_set_free_extrinsic_min_balance(),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
+        pub fn sudo_set_free_extrinsic_min_balance(
+            origin: OriginFor<T>,
+            min_balance: TaoCurrency,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_free_extrinsic_min_balance(min_balance);
+            log::debug!("FreeExtrinsicMinBalanceSet( min_balance: {min_balance:?} ) ");
+            Ok(())
+        }
+
+        /// The extrinsic sets the registrations a coldkey is allowed per adjustment interval on a
+        /// subnet, across PoW, burned and auctioned registrations.
+        /// It is only callable by the root account or subnet owner.
+        /// A maximum of 0 removes the quota.
+        #[pallet::call_index(101)]
+        #[pallet::weight(T::WeightInfo::sudo_set_max_registrations_per_coldkey_per_interval())]
+        pub fn sudo_set_max_registrations_per_coldkey_per_interval(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            max_registrations: u16,
+        ) -> DispatchResult {
+            let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
+                origin,
+                netuid,
+                &[Hyperparameter::ColdkeyRegistrationQuota.into()],
+            )?;
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_max_registrations_per_coldkey_per_interval(
+                netuid,
+                max_registrations,
+            );
+            pallet_subtensor::Pallet::<T>::record_owner_rl(
+                maybe_owner,
+                netuid,
+                &[Hyperparameter::ColdkeyRegistrationQuota.into()],
+            );
+            log::debug!(
+                "MaxRegistrationsPerColdkeyPerIntervalSet( netuid: {netuid:?} max_registrations: {max_registrations:?} ) "
+            );
+            Ok(())
+        }
+
+        /// The extrinsic enables or disables the registration queue of a subnet. With the queue
+        /// enabled, registrations on the full subnet wait in a queue with their burn escrowed, and
+        /// are admitted after the next epoch instead of pruning a neuron right away. Queued
+        /// registrations are refunded after the next epoch once the queue is disabled.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(102)]
+        #[pallet::weight(T::WeightInfo::sudo_set_registration_queue_enabled())]
+        pub fn sudo_set_registration_queue_enabled(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            enabled: bool,
+        ) -> DispatchResult {
+            let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
+                origin,
+                netuid,
+                &[Hyperparameter::RegistrationQueue.into()],
+            )?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_registration_queue_enabled(netuid, enabled);
+            log::debug!("RegistrationQueueEnabledSet( netuid: {netuid:?} enabled: {enabled:?} ) ");
+            pallet_subtensor::Pallet::<T>::record_owner_rl(
+                maybe_owner,
+                netuid,
+                &[Hyperparameter::RegistrationQueue.into()],
+            );
+            Ok(())
+        }
+
+        /// The extrinsic extends the immunity period of a uid of a subnet, e.g. for infrastructure
+        /// the subnet relies on, or removes the extension with `None`. The immunity period of the
+        /// uid must be longer than the subnet's, and at most `MaxNeuronImmunityOverrides` uids of a
+        /// subnet have one. The extension ends when the uid is deregistered.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(103)]
+        #[pallet::weight(T::WeightInfo::sudo_set_neuron_immunity_override())]
+        pub fn sudo_set_neuron_immunity_override(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            uid: u16,
+            immunity_period: Option<u64>,
+        ) -> DispatchResult {
+            let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
+                origin,
+                netuid,
+                &[Hyperparameter::NeuronImmunityOverride.into()],
+            )?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                pallet_subtensor::Pallet::<T>::is_uid_exist_on_network(netuid, uid),
+                Error::<T>::UidDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_neuron_immunity_override(
+                netuid,
+                uid,
+                immunity_period,
+            )?;
+            log::debug!(
+                "NeuronImmunityOverrideSet( netuid: {netuid:?} uid: {uid:?} immunity_period: {immunity_period:?} ) "
+            );
+            pallet_subtensor::Pallet::<T>::record_owner_rl(
+                maybe_owner,
+                netuid,
+                &[Hyperparameter::NeuronImmunityOverride.into()],
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the share of the registration burn of a subnet held as a deposit,
+        /// in `u16::MAX` parts, and the epochs after which the deposit is refunded. The deposit is
+        /// also refunded when the neuron deregisters itself, and burned when it is pruned while
+        /// inactive. A zero share disables deposits.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(104)]
+        #[pallet::weight(T::WeightInfo::sudo_set_registration_deposit())]
+        pub fn sudo_set_registration_deposit(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            share: u16,
+            maturity_epochs: u16,
+        ) -> DispatchResult {
+            let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
+                origin,
+                netuid,
+                &[Hyperparameter::RegistrationDeposit.into()],
+            )?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_registration_deposit(
+                netuid,
+                share,
+                maturity_epochs,
+            )?;
+            log::debug!(
+                "RegistrationDepositSet( netuid: {netuid:?} share: {share:?} maturity_epochs: {maturity_epochs:?} ) "
+            );
+            pallet_subtensor::Pallet::<T>::record_owner_rl(
+                maybe_owner,
+                netuid,
+                &[Hyperparameter::RegistrationDeposit.into()],
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the share of the emission of a subnet paid to miners, the rest being
+        /// paid to validators. The share must be within the bounds set by root.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(105)]
+        #[pallet::weight(T::WeightInfo::sudo_set_miner_emission_share())]
+        pub fn sudo_set_miner_emission_share(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            share: Percent,
+        ) -> DispatchResult {
+            let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
+                origin,
+                netuid,
+                &[Hyperparameter::MinerEmissionShare.into()],
+            )?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_miner_emission_share(netuid, share)?;
+            log::debug!("MinerEmissionShareSet( netuid: {netuid:?} share: {share:?} ) ");
+            pallet_subtensor::Pallet::<T>::record_owner_rl(
+                maybe_owner,
+                netuid,
+                &[Hyperparameter::MinerEmissionShare.into()],
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the bounds within which subnet owners set the miner emission share
+        /// of their subnet.
+        /// It is only callable by the root account.
+        #[pallet::call_index(106)]
+        #[pallet::weight((
+            T::WeightInfo::sudo_set_miner_emission_share_bounds(),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
+        pub fn sudo_set_miner_emission_share_bounds(
+            origin: OriginFor<T>,
+            min: Percent,
+            max: Percent,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::ensure_sudo_call_unlocked(Call::<T>::sudo_set_miner_emission_share_bounds {
+                min,
+                max,
+            })?;
+            pallet_subtensor::Pallet::<T>::set_miner_emission_share_bounds(min, max)?;
+            log::debug!("MinerEmissionShareBoundsSet( min: {min:?} max: {max:?} ) ");
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks the owner cut of a subnet vests over before
+        /// it is staked to the owner, zero for an owner cut staked right away. The vesting cannot
+        /// be made shorter than the current vesting of the subnet, nor the minimum set by root.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(107)]
+        #[pallet::weight(T::WeightInfo::sudo_set_owner_cut_vesting_blocks())]
+        pub fn sudo_set_owner_cut_vesting_blocks(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            blocks: u64,
+        ) -> DispatchResult {
+            let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
+                origin,
+                netuid,
+                &[Hyperparameter::OwnerCutVesting.into()],
+            )?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_owner_cut_vesting_blocks(netuid, blocks)?;
+            log::debug!("OwnerCutVestingBlocksSet( netuid: {netuid:?} blocks: {blocks:?} ) ");
+            pallet_subtensor::Pallet::<T>::record_owner_rl(
+                maybe_owner,
+                netuid,
+                &[Hyperparameter::OwnerCutVesting.into()],
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the minimum number of blocks the owner cut of subnets vests over.
+        /// It is only callable by the root account.
+        #[pallet::call_index(108)]
+        #[pallet::weight((
+            T::WeightInfo::sudo_set_min_owner_cut_vesting_blocks(),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
+        pub fn sudo_set_min_owner_cut_vesting_blocks(
+            origin: OriginFor<T>,
+            blocks: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_min_owner_cut_vesting_blocks(blocks)?;
+            log::debug!("MinOwnerCutVestingBlocksSet( blocks: {blocks:?} ) ");
+            Ok(())
+        }
+
+        /// The extrinsic sets the share of the owner cut of a subnet redirected into its treasury,
+        /// in `u16::MAX` parts, and the share of the alpha of the subnet, in `u16::MAX` parts,
+        /// that must approve a treasury spend. A zero threshold lets the owner spend without
+        /// approval, and the threshold cannot be lowered.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(109)]
+        #[pallet::weight(T::WeightInfo::sudo_set_subnet_treasury())]
+        pub fn sudo_set_subnet_treasury(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            share: u16,
+            approval_threshold: u16,
+        ) -> DispatchResult {
+            let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
+                origin,
+                netuid,
+                &[Hyperparameter::SubnetTreasury.into()],
+            )?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_subnet_treasury(netuid, share, approval_threshold)?;
+            log::debug!(
+                "SubnetTreasurySet( netuid: {netuid:?} share: {share:?} approval_threshold: {approval_threshold:?} ) "
+            );
+            pallet_subtensor::Pallet::<T>::record_owner_rl(
+                maybe_owner,
+                netuid,
+                &[Hyperparameter::SubnetTreasury.into()],
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the maximum share of the block emission injected into a single
+        /// subnet. The emission of a subnet above it is redistributed to the other subnets.
+        /// It is only callable by the root account.
+        #[pallet::call_index(110)]
+        #[pallet::weight((
+            T::WeightInfo::sudo_set_max_subnet_emission_share(),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
+        pub fn sudo_set_max_subnet_emission_share(
+            origin: OriginFor<T>,
+            share: Percent,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::ensure_sudo_call_unlocked(Call::<T>::sudo_set_max_subnet_emission_share {
+                share,
+            })?;
+            pallet_subtensor::Pallet::<T>::set_max_subnet_emission_share(share)?;
+            log::debug!("MaxSubnetEmissionShareSet( share: {share:?} ) ");
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks the stakeholders of a subnet vote on changes
+        /// of its sensitive hyperparameters, which the owner can then only propose. Zero lets the
+        /// owner set them directly, and the voting period cannot be made shorter.
+        /// It is only callable by the root account or subnet owner.
+        #[pallet::call_index(111)]
+        #[pallet::weight(T::WeightInfo::sudo_set_hyperparameter_voting_period())]
+        pub fn sudo_set_hyperparameter_voting_period(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            blocks: u64,
+        ) -> DispatchResult {
+            let maybe_owner = pallet_subtensor::Pallet::<T>::ensure_sn_owner_or_root_with_limits(
+                origin,
+                netuid,
+                &[Hyperparameter::HyperparameterVoting.into()],
+            )?;
+
+            ensure!(
+                pallet_subtensor::Pallet::<T>::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            pallet_subtensor::Pallet::<T>::set_hyperparameter_voting_period(netuid, blocks)?;
+            log::debug!("HyperparameterVotingPeriodSet( netuid: {netuid:?} blocks: {blocks:?} ) ");
+            pallet_subtensor::Pallet::<T>::record_owner_rl(
+                maybe_owner,
+                netuid,
+                &[Hyperparameter::HyperparameterVoting.into()],
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the share of transaction fees and slashed registration deposits
+        /// kept by the network treasury instead of being burned, in `u16::MAX` parts.
+        /// It is only callable by the root account.
+        #[pallet::call_index(112)]
+        #[pallet::weight((
+            T::WeightInfo::sudo_set_network_treasury_share(),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
+        pub fn sudo_set_network_treasury_share(origin: OriginFor<T>, share: u16) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::ensure_sudo_call_unlocked(Call::<T>::sudo_set_network_treasury_share { share })?;
+            pallet_subtensor::Pallet::<T>::set_network_treasury_share(share);
+            log::debug!("NetworkTreasuryShareSet( share: {share:?} ) ");
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks a time-locked sudo call waits between its
+        /// announcement and its execution, zero to make time-locked calls immediate.
+        /// It is only callable by the root account.
+        #[pallet::call_index(113)]
+        #[pallet::weight((
+            T::WeightInfo::sudo_set_sudo_call_delay(),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
+        pub fn sudo_set_sudo_call_delay(origin: OriginFor<T>, blocks: u64) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                blocks <= timelock::MAX_SUDO_CALL_DELAY,
+                Error::<T>::ValueNotInBounds
+            );
+            Self::ensure_sudo_call_unlocked(Call::<T>::sudo_set_sudo_call_delay { blocks })?;
+            SudoCallDelay::<T>::put(blocks);
+            Self::deposit_event(Event::SudoCallDelaySet { blocks });
+            log::debug!("SudoCallDelaySet( blocks: {blocks:?} ) ");
+            Ok(())
+        }
+
+        /// The extrinsic announces a time-locked sudo call, which can be made once the sudo call
+        /// delay is over.
+        /// It is only callable by the root account.
+        #[pallet::call_index(114)]
+        #[pallet::weight((
+            T::WeightInfo::announce_sudo_call(),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
+        pub fn announce_sudo_call(origin: OriginFor<T>, call: Box<Call<T>>) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                Self::is_sudo_call_timelocked(&call),
+                Error::<T>::SudoCallNotTimelocked
+            );
+            let call_hash = T::Hashing::hash_of(&call);
+            ensure!(
+                !PendingSudoCalls::<T>::contains_key(call_hash),
+                Error::<T>::SudoCallAlreadyAnnounced
+            );
+
+            let executable_at = pallet_subtensor::Pallet::<T>::get_current_block_as_u64()
+                .saturating_add(SudoCallDelay::<T>::get());
+            PendingSudoCalls::<T>::insert(call_hash, executable_at);
+            Self::deposit_event(Event::SudoCallAnnounced {
+                call_hash,
+                call,
+                executable_at,
+            });
+            Ok(())
+        }
+
+        /// The extrinsic cancels an announced sudo call.
+        /// It is only callable by the root account.
+        #[pallet::call_index(115)]
+        #[pallet::weight((
+            T::WeightInfo::cancel_sudo_call(),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
+        pub fn cancel_sudo_call(origin: OriginFor<T>, call_hash: T::Hash) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                PendingSudoCalls::<T>::take(call_hash).is_some(),
+                Error::<T>::SudoCallNotAnnounced
+            );
+            Self::deposit_event(Event::SudoCallCancelled { call_hash });
+            Ok(())
+        }
+
+        /// The extrinsic makes an announced sudo call with the root origin once its delay is
+        /// over.
+        /// It is callable by any signed account.
+        #[pallet::call_index(116)]
+        #[pallet::weight((
+            T::WeightInfo::execute_sudo_call()
+                .saturating_add(call.get_dispatch_info().call_weight),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn execute_sudo_call(origin: OriginFor<T>, call: Box<Call<T>>) -> DispatchResult {
+            ensure_signed(origin)?;
+            let call_hash = T::Hashing::hash_of(&call);
+            Self::ensure_sudo_call_executable(call_hash)?;
+
+            call.dispatch_bypass_filter(frame_system::RawOrigin::Root.into())
+                .map_err(|e| e.error)?;
+            // The call consumes its announcement itself unless the delay was lowered to zero.
+            Self::consume_sudo_call(call_hash);
+            Ok(())
+        }
+
+        /// The extrinsic sets the root stake a hotkey needs above the senate member with the
+        /// least root stake to replace it, in `u16::MAX` parts of the stake of the member.
+        /// It is only callable by the root account.
+        #[pallet::call_index(117)]
+        #[pallet::weight((
+            T::WeightInfo::sudo_set_senate_hysteresis(),
+            DispatchClass::Operational,
+            Pays::Yes
+        ))]
+        pub fn sudo_set_senate_hysteresis(origin: OriginFor<T>, hysteresis: u16) -> DispatchResult {
+            ensure_root(origin)?;
+            pallet_subtensor::Pallet::<T>::set_senate_hysteresis(hysteresis);
+            log::debug!("SenateHysteresisSet( hysteresis: {hysteresis:?} ) ");
+            Ok(())
+        }
     }
 }
 
@@ -152,6 +152,7 @@ parameter_types! {
     pub const HotkeySwapOnSubnetInterval: u64 = 7 * 24 * 60 * 60 / 12; // 7 days
     pub const LeaseDividendsDistributionInterval: u32 = 100; // 100 blocks
     pub const MaxImmuneUidsPercentage: Percent = Percent::from_percent(80);
+    pub const SlotDuration: u64 = 12_000;
     pub const EvmKeyAssociateRateLimit: u64 = 0;
 }
 
@@ -230,7 +231,9 @@ impl pallet_subtensor::Config for Test {
     type LeaseDividendsDistributionInterval = LeaseDividendsDistributionInterval;
     type GetCommitments = ();
     type MaxImmuneUidsPercentage = MaxImmuneUidsPercentage;
+    type SlotDuration = SlotDuration;
     type CommitmentsInterface = CommitmentsI;
+    type EvmLogInterface = ();
     type EvmKeyAssociateRateLimit = EvmKeyAssociateRateLimit;
 }
 
@@ -335,6 +338,9 @@ parameter_types! {
     pub const SwapMaxPositions: u32 = 100;
     pub const SwapMinimumLiquidity: u64 = 1_000;
     pub const SwapMinimumReserve: NonZeroU64 = NonZeroU64::new(1_000_000).unwrap();
+    pub const SwapMaxCandles: u32 = 100;
+    pub const SwapMaxPriceObservations: u32 = 100;
+    pub const SwapMaxLimitOrders: u32 = 100;
 }
 
 impl pallet_subtensor_swap::Config for Test {
@@ -345,6 +351,9 @@ impl pallet_subtensor_swap::Config for Test {
     type MaxPositions = SwapMaxPositions;
     type MinimumLiquidity = SwapMinimumLiquidity;
     type MinimumReserve = SwapMinimumReserve;
+    type MaxCandles = SwapMaxCandles;
+    type MaxPriceObservations = SwapMaxPriceObservations;
+    type MaxLimitOrders = SwapMaxLimitOrders;
     type WeightInfo = ();
 }
 
@@ -378,6 +387,7 @@ impl crate::Config for Test {
     type Aura = ();
     type Grandpa = GrandpaInterfaceImpl;
     type Balance = Balance;
+    type WeightInfo = ();
 }
 
 parameter_types! {
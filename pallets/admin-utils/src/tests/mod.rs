@@ -10,9 +10,18 @@ use pallet_subtensor::{
     TargetRegistrationsPerInterval, Tempo, WeightsVersionKeyRateLimit, *,
 };
 // use pallet_subtensor::{migrations, Event};
-use pallet_subtensor::{Event, utils::rate_limiting::TransactionType};
+use pallet_subtensor::coinbase::difficulty_controller::DifficultyControllerGains;
+use pallet_subtensor::subnets::weight_row::WeightRow;
+use pallet_subtensor::{
+    Event,
+    utils::rate_limiting::{Hyperparameter, TransactionType},
+};
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
 use sp_core::{Get, Pair, U256, ed25519};
+use sp_runtime::{
+    Percent,
+    traits::{BlakeTwo256, Hash},
+};
 use substrate_fixed::types::I96F32;
 use subtensor_runtime_common::{Currency, MechId, NetUid, TaoCurrency};
 
@@ -22,6 +31,24 @@ use mock::*;
 
 mod mock;
 
+/// Asserts the last events are `event`, deposited by the setter of the subnet hyperparameter
+/// `param`, followed by the `HyperparameterChanged` audit of the update.
+fn assert_last_event_audited(event: Event<Test>, param: Hyperparameter) {
+    let events: Vec<RuntimeEvent> = System::events()
+        .into_iter()
+        .map(|record| record.event)
+        .collect();
+    let [.., setter, audit] = events.as_slice() else {
+        panic!("the update deposits the setter and audit events");
+    };
+    assert_eq!(*setter, RuntimeEvent::SubtensorModule(event));
+    assert!(matches!(
+        audit,
+        RuntimeEvent::SubtensorModule(Event::HyperparameterChanged { param: audited, .. })
+            if *audited == param
+    ));
+}
+
 #[test]
 fn test_sudo_set_default_take() {
     new_test_ext().execute_with(|| {
@@ -476,6 +503,50 @@ fn test_sudo_set_immunity_period() {
     });
 }
 
+#[test]
+fn test_sudo_set_churn_burn_params() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        add_network(netuid, 10);
+        assert_eq!(
+            AdminUtils::sudo_set_churn_burn_params(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                3,
+                100
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_churn_burn_params(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid.next(),
+                3,
+                100
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_churn_burn_params(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                3,
+                0
+            ),
+            Err(Error::<Test>::ValueNotInBounds.into())
+        );
+        assert_eq!(ChurnBurnThreshold::<Test>::get(netuid), 0);
+        assert_ok!(AdminUtils::sudo_set_churn_burn_params(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            3,
+            100
+        ));
+        assert_eq!(ChurnBurnThreshold::<Test>::get(netuid), 3);
+        assert_eq!(ChurnBurnWindow::<Test>::get(netuid), 100);
+    });
+}
+
 #[test]
 fn test_sudo_set_min_allowed_weights() {
     new_test_ext().execute_with(|| {
@@ -861,350 +932,654 @@ fn test_sudo_set_stake_threshold() {
 }
 
 #[test]
-fn test_sudo_set_bonds_moving_average() {
+fn test_sudo_set_free_extrinsic_min_balance() {
+    new_test_ext().execute_with(|| {
+        let to_be_set = TaoCurrency::from(1_000_000);
+        let init_value = SubtensorModule::get_free_extrinsic_min_balance();
+        assert_eq!(
+            AdminUtils::sudo_set_free_extrinsic_min_balance(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            SubtensorModule::get_free_extrinsic_min_balance(),
+            init_value
+        );
+        assert_ok!(AdminUtils::sudo_set_free_extrinsic_min_balance(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_free_extrinsic_min_balance(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_max_registrations_per_coldkey_per_interval() {
     new_test_ext().execute_with(|| {
         let netuid = NetUid::from(1);
-        let to_be_set: u64 = 10;
         add_network(netuid, 10);
-        let init_value: u64 = SubtensorModule::get_bonds_moving_average(netuid.into());
         assert_eq!(
-            AdminUtils::sudo_set_bonds_moving_average(
+            AdminUtils::sudo_set_max_registrations_per_coldkey_per_interval(
                 <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
                 netuid,
-                to_be_set
+                2
             ),
             Err(DispatchError::BadOrigin)
         );
         assert_eq!(
-            AdminUtils::sudo_set_bonds_moving_average(
+            AdminUtils::sudo_set_max_registrations_per_coldkey_per_interval(
                 <<Test as Config>::RuntimeOrigin>::root(),
                 netuid.next(),
-                to_be_set
+                2
             ),
             Err(Error::<Test>::SubnetDoesNotExist.into())
         );
         assert_eq!(
-            SubtensorModule::get_bonds_moving_average(netuid.into()),
-            init_value
+            SubtensorModule::get_max_registrations_per_coldkey_per_interval(netuid),
+            0
+        );
+        assert_ok!(
+            AdminUtils::sudo_set_max_registrations_per_coldkey_per_interval(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                2
+            )
         );
-        assert_ok!(AdminUtils::sudo_set_bonds_moving_average(
-            <<Test as Config>::RuntimeOrigin>::root(),
-            netuid,
-            to_be_set
-        ));
         assert_eq!(
-            SubtensorModule::get_bonds_moving_average(netuid.into()),
-            to_be_set
+            SubtensorModule::get_max_registrations_per_coldkey_per_interval(netuid),
+            2
         );
     });
 }
 
 #[test]
-fn test_sudo_set_bonds_penalty() {
+fn test_sudo_set_registration_queue_enabled() {
     new_test_ext().execute_with(|| {
         let netuid = NetUid::from(1);
-        let to_be_set: u16 = 10;
         add_network(netuid, 10);
-        let init_value: u16 = SubtensorModule::get_bonds_penalty(netuid);
         assert_eq!(
-            AdminUtils::sudo_set_bonds_penalty(
+            AdminUtils::sudo_set_registration_queue_enabled(
                 <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
                 netuid,
-                to_be_set
+                true
             ),
             Err(DispatchError::BadOrigin)
         );
         assert_eq!(
-            AdminUtils::sudo_set_bonds_penalty(
+            AdminUtils::sudo_set_registration_queue_enabled(
                 <<Test as Config>::RuntimeOrigin>::root(),
                 netuid.next(),
-                to_be_set
+                true
             ),
             Err(Error::<Test>::SubnetDoesNotExist.into())
         );
-        assert_eq!(SubtensorModule::get_bonds_penalty(netuid), init_value);
-        assert_ok!(AdminUtils::sudo_set_bonds_penalty(
+        assert!(!SubtensorModule::get_registration_queue_enabled(netuid));
+        assert_ok!(AdminUtils::sudo_set_registration_queue_enabled(
             <<Test as Config>::RuntimeOrigin>::root(),
             netuid,
-            to_be_set
+            true
         ));
-        assert_eq!(SubtensorModule::get_bonds_penalty(netuid), to_be_set);
+        assert!(SubtensorModule::get_registration_queue_enabled(netuid));
     });
 }
 
 #[test]
-fn test_sudo_set_rao_recycled() {
+fn test_sudo_set_registration_deposit() {
     new_test_ext().execute_with(|| {
         let netuid = NetUid::from(1);
-        let to_be_set = TaoCurrency::from(10);
         add_network(netuid, 10);
-        let init_value = SubtensorModule::get_rao_recycled(netuid);
-
-        // Need to run from genesis block
-        run_to_block(1);
-
         assert_eq!(
-            AdminUtils::sudo_set_rao_recycled(
-                <<Test as Config>::RuntimeOrigin>::signed(U256::from(0)),
+            AdminUtils::sudo_set_registration_deposit(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
                 netuid,
-                to_be_set
+                16384,
+                10
             ),
             Err(DispatchError::BadOrigin)
         );
         assert_eq!(
-            AdminUtils::sudo_set_rao_recycled(
+            AdminUtils::sudo_set_registration_deposit(
                 <<Test as Config>::RuntimeOrigin>::root(),
                 netuid.next(),
-                to_be_set
+                16384,
+                10
             ),
             Err(Error::<Test>::SubnetDoesNotExist.into())
         );
-        assert_eq!(SubtensorModule::get_rao_recycled(netuid), init_value);
+        assert_eq!(
+            AdminUtils::sudo_set_registration_deposit(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                u16::MAX,
+                10
+            ),
+            Err(pallet_subtensor::Error::<Test>::RegistrationDepositShareTooHigh.into())
+        );
+        assert_eq!(SubtensorModule::get_registration_deposit_share(netuid), 0);
+        assert_ok!(AdminUtils::sudo_set_registration_deposit(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            16384,
+            10
+        ));
+        assert_eq!(
+            SubtensorModule::get_registration_deposit_share(netuid),
+            16384
+        );
+        assert_eq!(SubtensorModule::get_registration_deposit_epochs(netuid), 10);
+    });
+}
+
+#[test]
+fn test_sudo_set_miner_emission_share() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        AdminFreezeWindow::<Test>::set(0);
 
-        // Verify no events emitted matching the expected event
         assert_eq!(
-            System::events()
-                .iter()
-                .filter(|r| r.event
-                    == RuntimeEvent::SubtensorModule(Event::RAORecycledForRegistrationSet(
-                        netuid, to_be_set
-                    )))
-                .count(),
-            0
+            AdminUtils::sudo_set_miner_emission_share(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(2)),
+                netuid,
+                Percent::from_percent(60)
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_miner_emission_share(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                Percent::from_percent(70)
+            ),
+            pallet_subtensor::Error::<Test>::MinerEmissionShareOutOfBounds
+        );
+        assert_eq!(
+            SubtensorModule::get_miner_emission_share(netuid),
+            Percent::from_percent(50)
+        );
+        assert_ok!(AdminUtils::sudo_set_miner_emission_share(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            Percent::from_percent(41)
+        ));
+        assert_eq!(
+            SubtensorModule::get_miner_emission_share(netuid),
+            Percent::from_percent(41)
+        );
+        assert_eq!(
+            SubtensorModule::get_validator_emission_share(netuid),
+            Percent::from_percent(59)
         );
 
-        assert_ok!(AdminUtils::sudo_set_rao_recycled(
+        // Root widens the bounds
+        assert_eq!(
+            AdminUtils::sudo_set_miner_emission_share_bounds(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                Percent::from_percent(30),
+                Percent::from_percent(70)
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_miner_emission_share_bounds(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                Percent::from_percent(70),
+                Percent::from_percent(30)
+            ),
+            pallet_subtensor::Error::<Test>::InvalidMinerEmissionShareBounds
+        );
+        assert_ok!(AdminUtils::sudo_set_miner_emission_share_bounds(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            Percent::from_percent(30),
+            Percent::from_percent(70)
+        ));
+        assert_ok!(AdminUtils::sudo_set_miner_emission_share(
             <<Test as Config>::RuntimeOrigin>::root(),
             netuid,
-            to_be_set
+            Percent::from_percent(70)
         ));
-        assert_eq!(SubtensorModule::get_rao_recycled(netuid), to_be_set);
-
-        // Verify event emitted with correct values
         assert_eq!(
-            System::events()
-                .last()
-                .unwrap_or_else(|| panic!(
-                    "Expected there to be events: {:?}",
-                    System::events().to_vec()
-                ))
-                .event,
-            RuntimeEvent::SubtensorModule(Event::RAORecycledForRegistrationSet(netuid, to_be_set))
+            SubtensorModule::get_miner_emission_share(netuid),
+            Percent::from_percent(70)
         );
     });
 }
 
 #[test]
-fn test_sudo_set_network_lock_reduction_interval() {
+fn test_sudo_set_owner_cut_vesting_blocks() {
     new_test_ext().execute_with(|| {
         let netuid = NetUid::from(1);
-        let to_be_set: u64 = 7200;
+        let owner = U256::from(1);
         add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        AdminFreezeWindow::<Test>::set(0);
 
-        let init_value: u64 = SubtensorModule::get_lock_reduction_interval();
         assert_eq!(
-            AdminUtils::sudo_set_lock_reduction_interval(
-                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
-                to_be_set
+            AdminUtils::sudo_set_min_owner_cut_vesting_blocks(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                7200
             ),
             Err(DispatchError::BadOrigin)
         );
-        assert_eq!(SubtensorModule::get_lock_reduction_interval(), init_value);
-        assert_ok!(AdminUtils::sudo_set_lock_reduction_interval(
+        assert_ok!(AdminUtils::sudo_set_min_owner_cut_vesting_blocks(
             <<Test as Config>::RuntimeOrigin>::root(),
-            to_be_set
+            7200
         ));
-        assert_eq!(SubtensorModule::get_lock_reduction_interval(), to_be_set);
+        assert_eq!(SubtensorModule::get_owner_cut_vesting_blocks(netuid), 7200);
+
+        // The owner can only make the vesting stricter
+        assert_noop!(
+            AdminUtils::sudo_set_owner_cut_vesting_blocks(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                3600
+            ),
+            pallet_subtensor::Error::<Test>::OwnerCutVestingTooShort
+        );
+        assert_ok!(AdminUtils::sudo_set_owner_cut_vesting_blocks(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            50400
+        ));
+        assert_eq!(SubtensorModule::get_owner_cut_vesting_blocks(netuid), 50400);
+        assert_noop!(
+            AdminUtils::sudo_set_owner_cut_vesting_blocks(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                7200
+            ),
+            pallet_subtensor::Error::<Test>::OwnerCutVestingTooShort
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_owner_cut_vesting_blocks(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                u64::MAX
+            ),
+            pallet_subtensor::Error::<Test>::OwnerCutVestingTooLong
+        );
     });
 }
 
 #[test]
-fn test_sudo_set_network_pow_registration_allowed() {
+fn test_sudo_set_subnet_treasury() {
     new_test_ext().execute_with(|| {
         let netuid = NetUid::from(1);
-        let to_be_set: bool = true;
         add_network(netuid, 10);
-
-        let init_value: bool = SubtensorModule::get_network_pow_registration_allowed(netuid);
         assert_eq!(
-            AdminUtils::sudo_set_network_pow_registration_allowed(
+            AdminUtils::sudo_set_subnet_treasury(
                 <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
                 netuid,
-                to_be_set
+                16384,
+                32768
             ),
             Err(DispatchError::BadOrigin)
         );
+        assert_ok!(AdminUtils::sudo_set_subnet_treasury(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            16384,
+            32768
+        ));
+        assert_eq!(SubtensorModule::get_subnet_treasury_share(netuid), 16384);
         assert_eq!(
-            SubtensorModule::get_network_pow_registration_allowed(netuid),
-            init_value
+            SubtensorModule::get_subnet_treasury_approval_threshold(netuid),
+            32768
         );
-        assert_ok!(AdminUtils::sudo_set_network_pow_registration_allowed(
+
+        // The approval threshold cannot be lowered
+        assert_noop!(
+            AdminUtils::sudo_set_subnet_treasury(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                16384,
+                0
+            ),
+            pallet_subtensor::Error::<Test>::SubnetTreasuryApprovalLowered
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_sliced_unstake_depth_fraction() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u16 = 1310;
+        let init_value: u16 = SlicedUnstakeDepthFraction::<Test>::get();
+        assert_eq!(
+            AdminUtils::sudo_set_sliced_unstake_depth_fraction(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SlicedUnstakeDepthFraction::<Test>::get(), init_value);
+        assert_ok!(AdminUtils::sudo_set_sliced_unstake_depth_fraction(
             <<Test as Config>::RuntimeOrigin>::root(),
-            netuid,
             to_be_set
         ));
+        assert_eq!(SlicedUnstakeDepthFraction::<Test>::get(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_max_subnet_emission_share() {
+    new_test_ext().execute_with(|| {
         assert_eq!(
-            SubtensorModule::get_network_pow_registration_allowed(netuid),
-            to_be_set
+            AdminUtils::sudo_set_max_subnet_emission_share(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                Percent::from_percent(20)
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_max_subnet_emission_share(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                Percent::from_percent(0)
+            ),
+            pallet_subtensor::Error::<Test>::InvalidMaxSubnetEmissionShare
+        );
+        assert_ok!(AdminUtils::sudo_set_max_subnet_emission_share(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            Percent::from_percent(20)
+        ));
+        assert_eq!(
+            SubtensorModule::get_max_subnet_emission_share(),
+            Percent::from_percent(20)
         );
     });
 }
 
-mod sudo_set_nominator_min_required_stake {
-    use super::*;
-
-    #[test]
-    fn can_only_be_called_by_admin() {
-        new_test_ext().execute_with(|| {
-            let to_be_set = SubtensorModule::get_nominator_min_required_stake() + 5;
-            assert_eq!(
-                AdminUtils::sudo_set_nominator_min_required_stake(
-                    <<Test as Config>::RuntimeOrigin>::signed(U256::from(0)),
-                    to_be_set
-                ),
-                Err(DispatchError::BadOrigin)
-            );
-        });
-    }
-
-    #[test]
-    fn sets_a_lower_value() {
-        new_test_ext().execute_with(|| {
-            assert_ok!(AdminUtils::sudo_set_nominator_min_required_stake(
-                <<Test as Config>::RuntimeOrigin>::root(),
-                10
-            ));
-            let default_min_stake = pallet_subtensor::DefaultMinStake::<Test>::get();
-            assert_eq!(
-                SubtensorModule::get_nominator_min_required_stake(),
-                10 * default_min_stake.to_u64() / 1_000_000
-            );
+#[test]
+fn test_sudo_set_hyperparameter_voting_period() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        AdminFreezeWindow::<Test>::set(0);
+        OwnerHyperparamRateLimit::<Test>::set(0);
 
-            assert_ok!(AdminUtils::sudo_set_nominator_min_required_stake(
+        assert_ok!(AdminUtils::sudo_set_hyperparameter_voting_period(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            7200
+        ));
+        assert_eq!(
+            SubtensorModule::get_hyperparameter_voting_period(netuid),
+            7200
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_hyperparameter_voting_period(
                 <<Test as Config>::RuntimeOrigin>::root(),
-                5
-            ));
-            assert_eq!(
-                SubtensorModule::get_nominator_min_required_stake(),
-                5 * default_min_stake.to_u64() / 1_000_000
-            );
-        });
-    }
+                netuid,
+                3600
+            ),
+            pallet_subtensor::Error::<Test>::HyperparameterVotingPeriodTooShort
+        );
 
-    #[test]
-    fn sets_a_higher_value() {
-        new_test_ext().execute_with(|| {
-            let to_be_set = SubtensorModule::get_nominator_min_required_stake() + 5;
-            let default_min_stake = pallet_subtensor::DefaultMinStake::<Test>::get();
-            assert_ok!(AdminUtils::sudo_set_nominator_min_required_stake(
-                <<Test as Config>::RuntimeOrigin>::root(),
-                to_be_set
-            ));
-            assert_eq!(
-                SubtensorModule::get_nominator_min_required_stake(),
-                to_be_set * default_min_stake.to_u64() / 1_000_000
-            );
-        });
-    }
+        // The owner can no longer set the voted hyperparameters directly, root still can
+        assert_noop!(
+            AdminUtils::sudo_set_immunity_period(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                100
+            ),
+            pallet_subtensor::Error::<Test>::HyperparameterRequiresVote
+        );
+        assert_ok!(AdminUtils::sudo_set_immunity_period(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            100
+        ));
+        assert_eq!(SubtensorModule::get_immunity_period(netuid), 100);
+        assert_ok!(AdminUtils::sudo_set_kappa(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            100
+        ));
+    });
 }
 
 #[test]
-fn test_sudo_set_tx_delegate_take_rate_limit() {
+fn test_sudo_set_network_treasury_share() {
     new_test_ext().execute_with(|| {
-        let to_be_set: u64 = 10;
-        let init_value: u64 = SubtensorModule::get_tx_delegate_take_rate_limit();
         assert_eq!(
-            AdminUtils::sudo_set_tx_delegate_take_rate_limit(
+            AdminUtils::sudo_set_network_treasury_share(
                 <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
-                to_be_set
+                u16::MAX / 4
             ),
             Err(DispatchError::BadOrigin)
         );
-        assert_eq!(
-            SubtensorModule::get_tx_delegate_take_rate_limit(),
-            init_value
-        );
-        assert_ok!(AdminUtils::sudo_set_tx_delegate_take_rate_limit(
+        assert_ok!(AdminUtils::sudo_set_network_treasury_share(
             <<Test as Config>::RuntimeOrigin>::root(),
-            to_be_set
+            u16::MAX / 4
         ));
-        assert_eq!(
-            SubtensorModule::get_tx_delegate_take_rate_limit(),
-            to_be_set
+        assert_eq!(SubtensorModule::get_network_treasury_share(), u16::MAX / 4);
+    });
+}
+
+#[test]
+fn test_sudo_call_timelock() {
+    new_test_ext().execute_with(|| {
+        let root = <<Test as Config>::RuntimeOrigin>::root();
+        let subnet_limit = crate::Call::<Test>::sudo_set_subnet_limit { max_subnets: 64 };
+        let subnet_limit_hash = BlakeTwo256::hash_of(&subnet_limit);
+        assert_ok!(AdminUtils::sudo_set_sudo_call_delay(root.clone(), 100));
+
+        // Time-locked calls are only made once announced
+        assert_noop!(
+            AdminUtils::sudo_set_subnet_limit(root.clone(), 64),
+            Error::<Test>::SudoCallNotAnnounced
+        );
+        assert_noop!(
+            AdminUtils::announce_sudo_call(
+                root.clone(),
+                Box::new(crate::Call::<Test>::sudo_set_tx_rate_limit { tx_rate_limit: 1 })
+            ),
+            Error::<Test>::SudoCallNotTimelocked
+        );
+        assert_ok!(AdminUtils::announce_sudo_call(
+            root.clone(),
+            Box::new(subnet_limit.clone())
+        ));
+        assert_noop!(
+            AdminUtils::announce_sudo_call(root.clone(), Box::new(subnet_limit.clone())),
+            Error::<Test>::SudoCallAlreadyAnnounced
+        );
+
+        // Anyone makes the call once its delay is over, in the normal dispatch class
+        let execute = crate::Call::<Test>::execute_sudo_call {
+            call: Box::new(subnet_limit.clone()),
+        };
+        assert_eq!(execute.get_dispatch_info().class, DispatchClass::Normal);
+        let caller = <<Test as Config>::RuntimeOrigin>::signed(U256::from(1));
+        assert_noop!(
+            AdminUtils::execute_sudo_call(caller.clone(), Box::new(subnet_limit.clone())),
+            Error::<Test>::SudoCallDelayNotElapsed
+        );
+        run_to_block(System::block_number() + 100);
+        assert_ok!(AdminUtils::execute_sudo_call(
+            caller.clone(),
+            Box::new(subnet_limit.clone())
+        ));
+        assert_eq!(SubtensorModule::get_max_subnets(), 64);
+        assert!(!crate::PendingSudoCalls::<Test>::contains_key(
+            subnet_limit_hash
+        ));
+        assert_noop!(
+            AdminUtils::execute_sudo_call(
+                caller,
+                Box::new(crate::Call::<Test>::sudo_set_tx_rate_limit { tx_rate_limit: 1 })
+            ),
+            Error::<Test>::SudoCallNotAnnounced
         );
+
+        // Root cancels an announced call, or makes it itself once its delay is over
+        let immunity_period = crate::Call::<Test>::sudo_set_network_immunity_period {
+            immunity_period: 10,
+        };
+        let immunity_period_hash = BlakeTwo256::hash_of(&immunity_period);
+        assert_ok!(AdminUtils::announce_sudo_call(
+            root.clone(),
+            Box::new(immunity_period.clone())
+        ));
+        assert_ok!(AdminUtils::cancel_sudo_call(
+            root.clone(),
+            immunity_period_hash
+        ));
+        assert_noop!(
+            AdminUtils::cancel_sudo_call(root.clone(), immunity_period_hash),
+            Error::<Test>::SudoCallNotAnnounced
+        );
+        assert_ok!(AdminUtils::announce_sudo_call(
+            root.clone(),
+            Box::new(immunity_period)
+        ));
+        run_to_block(System::block_number() + 100);
+        assert_ok!(AdminUtils::sudo_set_network_immunity_period(root, 10));
+        assert_eq!(SubtensorModule::get_network_immunity_period(), 10);
     });
 }
 
 #[test]
-fn test_sudo_set_min_delegate_take() {
+fn test_sudo_set_senate_hysteresis() {
     new_test_ext().execute_with(|| {
-        let to_be_set = u16::MAX / 100;
-        let init_value = SubtensorModule::get_min_delegate_take();
         assert_eq!(
-            AdminUtils::sudo_set_min_delegate_take(
+            AdminUtils::sudo_set_senate_hysteresis(
                 <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
-                to_be_set
+                u16::MAX / 10
             ),
             Err(DispatchError::BadOrigin)
         );
-        assert_eq!(SubtensorModule::get_min_delegate_take(), init_value);
-        assert_ok!(AdminUtils::sudo_set_min_delegate_take(
+        assert_ok!(AdminUtils::sudo_set_senate_hysteresis(
             <<Test as Config>::RuntimeOrigin>::root(),
-            to_be_set
+            u16::MAX / 10
         ));
-        assert_eq!(SubtensorModule::get_min_delegate_take(), to_be_set);
+        assert_eq!(SubtensorModule::get_senate_hysteresis(), u16::MAX / 10);
     });
 }
 
 #[test]
-fn test_sudo_set_commit_reveal_weights_enabled() {
+fn test_sudo_set_subnet_features() {
+    use pallet_subtensor::subnets::features::*;
+
     new_test_ext().execute_with(|| {
         let netuid = NetUid::from(1);
+        let sn_owner = U256::from(7);
         add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, sn_owner);
 
-        let to_be_set: bool = false;
-        let init_value: bool = SubtensorModule::get_commit_reveal_weights_enabled(netuid);
+        let features = FEATURE_COMMIT_REVEAL
+            | FEATURE_TRANSFERS
+            | FEATURE_USER_LIQUIDITY
+            | REGISTRATION_BURN_AND_POW;
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_features(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                features | (1 << 6)
+            ),
+            Err(SubtensorError::<Test>::InvalidSubnetFeatures.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_subnet_features(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid.next(),
+                features
+            ),
+            Err(SubtensorError::<Test>::SubnetNotExists.into())
+        );
 
-        assert_ok!(AdminUtils::sudo_set_commit_reveal_weights_enabled(
+        assert_ok!(AdminUtils::sudo_set_subnet_features(
             <<Test as Config>::RuntimeOrigin>::root(),
             netuid,
-            to_be_set
+            features
+        ));
+        assert_eq!(SubtensorModule::get_subnet_features(netuid), Some(features));
+        assert!(SubtensorModule::get_commit_reveal_weights_enabled(netuid));
+        assert!(!SubtensorModule::get_liquid_alpha_enabled(netuid));
+        assert!(SubtensorModule::get_transfer_toggle(netuid));
+        assert!(pallet_subtensor_swap::EnabledUserLiquidity::<Test>::get(
+            netuid
+        ));
+        assert!(SubtensorModule::get_network_registration_allowed(netuid));
+        assert!(SubtensorModule::get_network_pow_registration_allowed(
+            netuid
         ));
 
-        assert!(init_value != to_be_set);
-        assert_eq!(
-            SubtensorModule::get_commit_reveal_weights_enabled(netuid),
-            to_be_set
-        );
+        // The owner may neither disable user liquidity nor close burned registration
+        for denied in [
+            features & !FEATURE_USER_LIQUIDITY,
+            (features & !FEATURE_REGISTRATION_MODE) | REGISTRATION_POW,
+        ] {
+            assert_eq!(
+                AdminUtils::sudo_set_subnet_features(
+                    <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+                    netuid,
+                    denied
+                ),
+                Err(DispatchError::BadOrigin)
+            );
+        }
+
+        let features = (features & !FEATURE_COMMIT_REVEAL & !FEATURE_REGISTRATION_MODE)
+            | FEATURE_LIQUID_ALPHA
+            | REGISTRATION_BURN;
+        assert_ok!(AdminUtils::sudo_set_subnet_features(
+            <<Test as Config>::RuntimeOrigin>::signed(sn_owner),
+            netuid,
+            features
+        ));
+        assert_eq!(SubtensorModule::get_subnet_features(netuid), Some(features));
+        assert!(!SubtensorModule::get_commit_reveal_weights_enabled(netuid));
+        assert!(SubtensorModule::get_liquid_alpha_enabled(netuid));
+        assert!(!SubtensorModule::get_network_pow_registration_allowed(
+            netuid
+        ));
     });
 }
 
 #[test]
-fn test_sudo_set_liquid_alpha_enabled() {
+fn test_sudo_set_max_scheduled_operations() {
     new_test_ext().execute_with(|| {
-        let netuid = NetUid::from(1);
-        let enabled: bool = true;
-        assert_eq!(!enabled, SubtensorModule::get_liquid_alpha_enabled(netuid));
-
-        assert_ok!(AdminUtils::sudo_set_liquid_alpha_enabled(
+        let to_be_set: u32 = 3;
+        let init_value: u32 = MaxScheduledOperations::<Test>::get();
+        assert_eq!(
+            AdminUtils::sudo_set_max_scheduled_operations(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_max_scheduled_operations(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                0
+            ),
+            Error::<Test>::ValueNotInBounds
+        );
+        assert_eq!(MaxScheduledOperations::<Test>::get(), init_value);
+        assert_ok!(AdminUtils::sudo_set_max_scheduled_operations(
             <<Test as Config>::RuntimeOrigin>::root(),
-            netuid,
-            enabled
+            to_be_set
         ));
-
-        assert_eq!(enabled, SubtensorModule::get_liquid_alpha_enabled(netuid));
+        assert_eq!(MaxScheduledOperations::<Test>::get(), to_be_set);
     });
 }
 
 #[test]
-fn test_sudo_set_alpha_sigmoid_steepness() {
+fn test_sudo_set_bonds_moving_average() {
     new_test_ext().execute_with(|| {
         let netuid = NetUid::from(1);
-        let to_be_set: i16 = 5000;
+        let to_be_set: u64 = 10;
         add_network(netuid, 10);
-        let init_value = SubtensorModule::get_alpha_sigmoid_steepness(netuid);
+        let init_value: u64 = SubtensorModule::get_bonds_moving_average(netuid.into());
         assert_eq!(
-            AdminUtils::sudo_set_alpha_sigmoid_steepness(
+            AdminUtils::sudo_set_bonds_moving_average(
                 <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
                 netuid,
                 to_be_set
@@ -1212,205 +1587,1283 @@ fn test_sudo_set_alpha_sigmoid_steepness() {
             Err(DispatchError::BadOrigin)
         );
         assert_eq!(
-            AdminUtils::sudo_set_alpha_sigmoid_steepness(
+            AdminUtils::sudo_set_bonds_moving_average(
                 <<Test as Config>::RuntimeOrigin>::root(),
                 netuid.next(),
                 to_be_set
             ),
             Err(Error::<Test>::SubnetDoesNotExist.into())
         );
-
-        let owner = U256::from(10);
-        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
-        assert_eq!(
-            AdminUtils::sudo_set_alpha_sigmoid_steepness(
-                <<Test as Config>::RuntimeOrigin>::signed(owner),
-                netuid,
-                -to_be_set
-            ),
-            Err(Error::<Test>::NegativeSigmoidSteepness.into())
-        );
         assert_eq!(
-            SubtensorModule::get_alpha_sigmoid_steepness(netuid),
+            SubtensorModule::get_bonds_moving_average(netuid.into()),
             init_value
         );
-        assert_ok!(AdminUtils::sudo_set_alpha_sigmoid_steepness(
+        assert_ok!(AdminUtils::sudo_set_bonds_moving_average(
             <<Test as Config>::RuntimeOrigin>::root(),
             netuid,
             to_be_set
         ));
         assert_eq!(
-            SubtensorModule::get_alpha_sigmoid_steepness(netuid),
+            SubtensorModule::get_bonds_moving_average(netuid.into()),
             to_be_set
         );
-        assert_ok!(AdminUtils::sudo_set_alpha_sigmoid_steepness(
+    });
+}
+
+#[test]
+fn test_sudo_set_bonds_penalty() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let to_be_set: u16 = 10;
+        add_network(netuid, 10);
+        let init_value: u16 = SubtensorModule::get_bonds_penalty(netuid);
+        assert_eq!(
+            AdminUtils::sudo_set_bonds_penalty(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_bonds_penalty(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid.next(),
+                to_be_set
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(SubtensorModule::get_bonds_penalty(netuid), init_value);
+        assert_ok!(AdminUtils::sudo_set_bonds_penalty(
             <<Test as Config>::RuntimeOrigin>::root(),
             netuid,
-            -to_be_set
+            to_be_set
         ));
-        assert_eq!(
-            SubtensorModule::get_alpha_sigmoid_steepness(netuid),
-            -to_be_set
-        );
+        assert_eq!(SubtensorModule::get_bonds_penalty(netuid), to_be_set);
     });
 }
 
 #[test]
-fn test_set_alpha_values_dispatch_info_ok() {
+fn test_sudo_set_rao_recycled() {
     new_test_ext().execute_with(|| {
         let netuid = NetUid::from(1);
-        let alpha_low: u16 = 1638_u16;
-        let alpha_high: u16 = u16::MAX - 10;
-        let call = RuntimeCall::AdminUtils(crate::Call::sudo_set_alpha_values {
-            netuid,
-            alpha_low,
+        let to_be_set = TaoCurrency::from(10);
+        add_network(netuid, 10);
+        let init_value = SubtensorModule::get_rao_recycled(netuid);
+
+        // Need to run from genesis block
+        run_to_block(1);
+
+        assert_eq!(
+            AdminUtils::sudo_set_rao_recycled(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(0)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_rao_recycled(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid.next(),
+                to_be_set
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(SubtensorModule::get_rao_recycled(netuid), init_value);
+
+        // Verify no events emitted matching the expected event
+        assert_eq!(
+            System::events()
+                .iter()
+                .filter(|r| r.event
+                    == RuntimeEvent::SubtensorModule(Event::RAORecycledForRegistrationSet(
+                        netuid, to_be_set
+                    )))
+                .count(),
+            0
+        );
+
+        assert_ok!(AdminUtils::sudo_set_rao_recycled(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_rao_recycled(netuid), to_be_set);
+
+        // Verify event emitted with correct values
+        assert_eq!(
+            System::events()
+                .last()
+                .unwrap_or_else(|| panic!(
+                    "Expected there to be events: {:?}",
+                    System::events().to_vec()
+                ))
+                .event,
+            RuntimeEvent::SubtensorModule(Event::RAORecycledForRegistrationSet(netuid, to_be_set))
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_network_lock_reduction_interval() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let to_be_set: u64 = 7200;
+        add_network(netuid, 10);
+
+        let init_value: u64 = SubtensorModule::get_lock_reduction_interval();
+        assert_eq!(
+            AdminUtils::sudo_set_lock_reduction_interval(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_lock_reduction_interval(), init_value);
+        assert_ok!(AdminUtils::sudo_set_lock_reduction_interval(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_lock_reduction_interval(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_network_pow_registration_allowed() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let to_be_set: bool = true;
+        add_network(netuid, 10);
+
+        let init_value: bool = SubtensorModule::get_network_pow_registration_allowed(netuid);
+        assert_eq!(
+            AdminUtils::sudo_set_network_pow_registration_allowed(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            SubtensorModule::get_network_pow_registration_allowed(netuid),
+            init_value
+        );
+        assert_ok!(AdminUtils::sudo_set_network_pow_registration_allowed(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(
+            SubtensorModule::get_network_pow_registration_allowed(netuid),
+            to_be_set
+        );
+    });
+}
+
+mod sudo_set_nominator_min_required_stake {
+    use super::*;
+
+    #[test]
+    fn can_only_be_called_by_admin() {
+        new_test_ext().execute_with(|| {
+            let to_be_set = SubtensorModule::get_nominator_min_required_stake() + 5;
+            assert_eq!(
+                AdminUtils::sudo_set_nominator_min_required_stake(
+                    <<Test as Config>::RuntimeOrigin>::signed(U256::from(0)),
+                    to_be_set
+                ),
+                Err(DispatchError::BadOrigin)
+            );
+        });
+    }
+
+    #[test]
+    fn sets_a_lower_value() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(AdminUtils::sudo_set_nominator_min_required_stake(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                10
+            ));
+            let default_min_stake = pallet_subtensor::DefaultMinStake::<Test>::get();
+            assert_eq!(
+                SubtensorModule::get_nominator_min_required_stake(),
+                10 * default_min_stake.to_u64() / 1_000_000
+            );
+
+            assert_ok!(AdminUtils::sudo_set_nominator_min_required_stake(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                5
+            ));
+            assert_eq!(
+                SubtensorModule::get_nominator_min_required_stake(),
+                5 * default_min_stake.to_u64() / 1_000_000
+            );
+        });
+    }
+
+    #[test]
+    fn sets_a_higher_value() {
+        new_test_ext().execute_with(|| {
+            let to_be_set = SubtensorModule::get_nominator_min_required_stake() + 5;
+            let default_min_stake = pallet_subtensor::DefaultMinStake::<Test>::get();
+            assert_ok!(AdminUtils::sudo_set_nominator_min_required_stake(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                to_be_set
+            ));
+            assert_eq!(
+                SubtensorModule::get_nominator_min_required_stake(),
+                to_be_set * default_min_stake.to_u64() / 1_000_000
+            );
+        });
+    }
+}
+
+#[test]
+fn test_sudo_set_tx_delegate_take_rate_limit() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 10;
+        let init_value: u64 = SubtensorModule::get_tx_delegate_take_rate_limit();
+        assert_eq!(
+            AdminUtils::sudo_set_tx_delegate_take_rate_limit(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            SubtensorModule::get_tx_delegate_take_rate_limit(),
+            init_value
+        );
+        assert_ok!(AdminUtils::sudo_set_tx_delegate_take_rate_limit(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(
+            SubtensorModule::get_tx_delegate_take_rate_limit(),
+            to_be_set
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_min_delegate_take() {
+    new_test_ext().execute_with(|| {
+        let to_be_set = u16::MAX / 100;
+        let init_value = SubtensorModule::get_min_delegate_take();
+        assert_eq!(
+            AdminUtils::sudo_set_min_delegate_take(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_min_delegate_take(), init_value);
+        assert_ok!(AdminUtils::sudo_set_min_delegate_take(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_min_delegate_take(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_commit_reveal_weights_enabled() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        add_network(netuid, 10);
+
+        let to_be_set: bool = false;
+        let init_value: bool = SubtensorModule::get_commit_reveal_weights_enabled(netuid);
+
+        assert_ok!(AdminUtils::sudo_set_commit_reveal_weights_enabled(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set
+        ));
+
+        assert!(init_value != to_be_set);
+        assert_eq!(
+            SubtensorModule::get_commit_reveal_weights_enabled(netuid),
+            to_be_set
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_timelocked_weights_only() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        add_network(netuid, 10);
+        assert!(!SubtensorModule::get_timelocked_weights_only(netuid));
+
+        assert_eq!(
+            AdminUtils::sudo_set_timelocked_weights_only(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                true
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(AdminUtils::sudo_set_timelocked_weights_only(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            true
+        ));
+        assert!(SubtensorModule::get_timelocked_weights_only(netuid));
+    });
+}
+
+#[test]
+fn test_sudo_set_merkle_weights_enabled() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        add_network(netuid, 10);
+        assert!(!SubtensorModule::get_merkle_weights_enabled(netuid));
+
+        assert_eq!(
+            AdminUtils::sudo_set_merkle_weights_enabled(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                true
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_merkle_weights_enabled(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid.next(),
+                true
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_merkle_weights_enabled(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            true
+        ));
+        assert!(SubtensorModule::get_merkle_weights_enabled(netuid));
+    });
+}
+
+#[test]
+fn test_sudo_set_liquid_alpha_enabled() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let enabled: bool = true;
+        assert_eq!(!enabled, SubtensorModule::get_liquid_alpha_enabled(netuid));
+
+        assert_ok!(AdminUtils::sudo_set_liquid_alpha_enabled(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            enabled
+        ));
+
+        assert_eq!(enabled, SubtensorModule::get_liquid_alpha_enabled(netuid));
+    });
+}
+
+#[test]
+fn test_sudo_set_alpha_sigmoid_steepness() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let to_be_set: i16 = 5000;
+        add_network(netuid, 10);
+        let init_value = SubtensorModule::get_alpha_sigmoid_steepness(netuid);
+        assert_eq!(
+            AdminUtils::sudo_set_alpha_sigmoid_steepness(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_alpha_sigmoid_steepness(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid.next(),
+                to_be_set
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+
+        let owner = U256::from(10);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        assert_eq!(
+            AdminUtils::sudo_set_alpha_sigmoid_steepness(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                -to_be_set
+            ),
+            Err(Error::<Test>::NegativeSigmoidSteepness.into())
+        );
+        assert_eq!(
+            SubtensorModule::get_alpha_sigmoid_steepness(netuid),
+            init_value
+        );
+        assert_ok!(AdminUtils::sudo_set_alpha_sigmoid_steepness(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(
+            SubtensorModule::get_alpha_sigmoid_steepness(netuid),
+            to_be_set
+        );
+        assert_ok!(AdminUtils::sudo_set_alpha_sigmoid_steepness(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            -to_be_set
+        ));
+        assert_eq!(
+            SubtensorModule::get_alpha_sigmoid_steepness(netuid),
+            -to_be_set
+        );
+    });
+}
+
+#[test]
+fn test_set_alpha_values_dispatch_info_ok() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let alpha_low: u16 = 1638_u16;
+        let alpha_high: u16 = u16::MAX - 10;
+        let call = RuntimeCall::AdminUtils(crate::Call::sudo_set_alpha_values {
+            netuid,
+            alpha_low,
             alpha_high,
         });
 
-        let dispatch_info = call.get_dispatch_info();
+        let dispatch_info = call.get_dispatch_info();
+
+        assert_eq!(dispatch_info.class, DispatchClass::Normal);
+        assert_eq!(dispatch_info.pays_fee, Pays::Yes);
+    });
+}
+
+#[test]
+fn test_sudo_get_set_alpha() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let alpha_low: u16 = 1638_u16;
+        let alpha_high: u16 = u16::MAX - 10;
+
+        let hotkey: U256 = U256::from(1);
+        let coldkey: U256 = U256::from(1 + 456);
+        let signer = <<Test as Config>::RuntimeOrigin>::signed(coldkey);
+
+        // Enable Liquid Alpha and setup
+        SubtensorModule::set_liquid_alpha_enabled(netuid, true);
+        pallet_subtensor::migrations::migrate_create_root_network::migrate_create_root_network::<
+            Test,
+        >();
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000_000_000_000);
+        assert_ok!(SubtensorModule::root_register(signer.clone(), hotkey,));
+
+        // Should fail as signer does not own the subnet
+        assert_err!(
+            AdminUtils::sudo_set_alpha_values(signer.clone(), netuid, alpha_low, alpha_high),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(SubtensorModule::register_network(signer.clone(), hotkey));
+
+        assert_ok!(AdminUtils::sudo_set_alpha_values(
+            signer.clone(),
+            netuid,
+            alpha_low,
+            alpha_high
+        ));
+        let (grabbed_alpha_low, grabbed_alpha_high): (u16, u16) =
+            SubtensorModule::get_alpha_values(netuid);
+
+        log::info!("alpha_low: {grabbed_alpha_low:?} alpha_high: {grabbed_alpha_high:?}");
+        assert_eq!(grabbed_alpha_low, alpha_low);
+        assert_eq!(grabbed_alpha_high, alpha_high);
+
+        // Convert the u16 values to decimal values
+        fn unnormalize_u16_to_float(normalized_value: u16) -> f32 {
+            const MAX_U16: u16 = 65535;
+            normalized_value as f32 / MAX_U16 as f32
+        }
+
+        let alpha_low_decimal = unnormalize_u16_to_float(alpha_low);
+        let alpha_high_decimal = unnormalize_u16_to_float(alpha_high);
+
+        let (alpha_low_32, alpha_high_32) = SubtensorModule::get_alpha_values_32(netuid);
+
+        let tolerance: f32 = 1e-6; // 0.000001
+
+        // Check if the values are equal to the sixth decimal
+        assert!(
+            (alpha_low_32.to_num::<f32>() - alpha_low_decimal).abs() < tolerance,
+            "alpha_low mismatch: {} != {}",
+            alpha_low_32.to_num::<f32>(),
+            alpha_low_decimal
+        );
+        assert!(
+            (alpha_high_32.to_num::<f32>() - alpha_high_decimal).abs() < tolerance,
+            "alpha_high mismatch: {} != {}",
+            alpha_high_32.to_num::<f32>(),
+            alpha_high_decimal
+        );
+
+        // 1. Liquid alpha disabled
+        SubtensorModule::set_liquid_alpha_enabled(netuid, false);
+        assert_err!(
+            AdminUtils::sudo_set_alpha_values(signer.clone(), netuid, alpha_low, alpha_high),
+            SubtensorError::<Test>::LiquidAlphaDisabled
+        );
+        // Correct scenario after error
+        SubtensorModule::set_liquid_alpha_enabled(netuid, true); // Re-enable for further tests
+        assert_ok!(AdminUtils::sudo_set_alpha_values(
+            signer.clone(),
+            netuid,
+            alpha_low,
+            alpha_high
+        ));
+
+        // 2. Alpha high too low
+        let alpha_high_too_low = (u16::MAX as u32 / 40) as u16 - 1; // One less than the minimum acceptable value
+        assert_err!(
+            AdminUtils::sudo_set_alpha_values(
+                signer.clone(),
+                netuid,
+                alpha_low,
+                alpha_high_too_low
+            ),
+            SubtensorError::<Test>::AlphaHighTooLow
+        );
+        // Correct scenario after error
+        assert_ok!(AdminUtils::sudo_set_alpha_values(
+            signer.clone(),
+            netuid,
+            alpha_low,
+            alpha_high
+        ));
+
+        // 3. Alpha low too low or too high
+        let alpha_low_too_low = 0_u16;
+        assert_err!(
+            AdminUtils::sudo_set_alpha_values(
+                signer.clone(),
+                netuid,
+                alpha_low_too_low,
+                alpha_high
+            ),
+            SubtensorError::<Test>::AlphaLowOutOfRange
+        );
+        // Correct scenario after error
+        assert_ok!(AdminUtils::sudo_set_alpha_values(
+            signer.clone(),
+            netuid,
+            alpha_low,
+            alpha_high
+        ));
+
+        let alpha_low_too_high = alpha_high + 1;
+        assert_err!(
+            AdminUtils::sudo_set_alpha_values(
+                signer.clone(),
+                netuid,
+                alpha_low_too_high,
+                alpha_high
+            ),
+            SubtensorError::<Test>::AlphaLowOutOfRange
+        );
+        // Correct scenario after error
+        assert_ok!(AdminUtils::sudo_set_alpha_values(
+            signer.clone(),
+            netuid,
+            alpha_low,
+            alpha_high
+        ));
+    });
+}
+
+#[test]
+fn test_sudo_set_liquid_alpha_bounds() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        AdminFreezeWindow::<Test>::set(0);
+        SubtensorModule::set_liquid_alpha_enabled(netuid, true);
+
+        assert_eq!(
+            AdminUtils::sudo_set_liquid_alpha_bounds(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                2_000,
+                60_000
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_liquid_alpha_bounds(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                60_000,
+                2_000
+            ),
+            pallet_subtensor::Error::<Test>::InvalidLiquidAlphaBounds
+        );
+        assert_ok!(AdminUtils::sudo_set_liquid_alpha_bounds(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            2_000,
+            60_000
+        ));
+
+        // The owner sets its alpha values within the new bounds
+        assert_noop!(
+            AdminUtils::sudo_set_alpha_values(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                2_000,
+                60_001
+            ),
+            pallet_subtensor::Error::<Test>::AlphaHighTooHigh
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_alpha_values(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                1_999,
+                60_000
+            ),
+            pallet_subtensor::Error::<Test>::AlphaLowOutOfRange
+        );
+        assert_ok!(AdminUtils::sudo_set_alpha_values(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            2_000,
+            60_000
+        ));
+        assert_eq!(SubtensorModule::get_alpha_values(netuid), (2_000, 60_000));
+        assert_last_event_audited(
+            Event::AlphaValuesSet(netuid, 2_000, 60_000),
+            Hyperparameter::AlphaValues,
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_consensus_engine() {
+    use pallet_subtensor::epoch::consensus::ConsensusEngineKind;
+
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, owner);
+
+        // Only root selects the consensus engine
+        assert_eq!(
+            AdminUtils::sudo_set_consensus_engine(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                ConsensusEngineKind::StakeWeightedAverage
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_consensus_engine(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                NetUid::from(2),
+                ConsensusEngineKind::StakeWeightedAverage
+            ),
+            Error::<Test>::SubnetDoesNotExist
+        );
+        assert_ok!(AdminUtils::sudo_set_consensus_engine(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            ConsensusEngineKind::StakeWeightedAverage
+        ));
+        assert_eq!(
+            SubtensorModule::get_consensus_engine(netuid),
+            ConsensusEngineKind::StakeWeightedAverage
+        );
+        System::assert_last_event(
+            Event::ConsensusEngineSet(netuid, ConsensusEngineKind::StakeWeightedAverage).into(),
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_owner_hparam_group_rate_limit() {
+    use pallet_subtensor::utils::rate_limiting::HyperparameterGroup;
 
-        assert_eq!(dispatch_info.class, DispatchClass::Normal);
-        assert_eq!(dispatch_info.pays_fee, Pays::Yes);
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_tempo(netuid, 1);
+        assert_ok!(AdminUtils::sudo_set_admin_freeze_window(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            0
+        ));
+
+        // Only root sets the limit of a group
+        assert_eq!(
+            AdminUtils::sudo_set_owner_hparam_group_rate_limit(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                HyperparameterGroup::Registration,
+                Some(0)
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_owner_hparam_group_rate_limit(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                NetUid::from(2),
+                HyperparameterGroup::Registration,
+                Some(0)
+            ),
+            Error::<Test>::SubnetDoesNotExist
+        );
+        assert_ok!(AdminUtils::sudo_set_owner_hparam_group_rate_limit(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            HyperparameterGroup::Registration,
+            Some(0)
+        ));
+        System::assert_last_event(
+            Event::OwnerHyperparamGroupRateLimitSet {
+                netuid,
+                group: HyperparameterGroup::Registration,
+                epochs: Some(0),
+            }
+            .into(),
+        );
+        assert_eq!(
+            SubtensorModule::get_owner_hyperparam_group_rate_limit(
+                netuid,
+                HyperparameterGroup::Registration
+            ),
+            0
+        );
+
+        // Registration burn is no longer held back, while consensus follows the global limit
+        let min_burn = SubtensorModule::get_min_burn(netuid);
+        assert_ok!(AdminUtils::sudo_set_min_burn(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            min_burn + 1.into()
+        ));
+        assert_ok!(AdminUtils::sudo_set_min_burn(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            min_burn + 2.into()
+        ));
+        assert_ok!(AdminUtils::sudo_set_kappa(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            11
+        ));
+        assert_noop!(
+            AdminUtils::sudo_set_kappa(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                12
+            ),
+            SubtensorError::<Test>::TxRateLimitExceeded
+        );
+
+        // Without a limit of its own the group follows the global limit again
+        assert_ok!(AdminUtils::sudo_set_owner_hparam_group_rate_limit(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            HyperparameterGroup::Registration,
+            None
+        ));
+        assert_eq!(
+            SubtensorModule::get_owner_hyperparam_group_rate_limit(
+                netuid,
+                HyperparameterGroup::Registration
+            ),
+            OwnerHyperparamRateLimit::<Test>::get()
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_min_burn(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                min_burn + 3.into()
+            ),
+            SubtensorError::<Test>::TxRateLimitExceeded
+        );
     });
 }
 
 #[test]
-fn test_sudo_get_set_alpha() {
+fn test_sudo_set_self_weights_excluded() {
     new_test_ext().execute_with(|| {
         let netuid = NetUid::from(1);
-        let alpha_low: u16 = 1638_u16;
-        let alpha_high: u16 = u16::MAX - 10;
+        let owner = U256::from(1);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_admin_freeze_window(0);
+        assert!(!SubtensorModule::get_self_weights_excluded(netuid));
 
-        let hotkey: U256 = U256::from(1);
-        let coldkey: U256 = U256::from(1 + 456);
-        let signer = <<Test as Config>::RuntimeOrigin>::signed(coldkey);
+        assert_eq!(
+            AdminUtils::sudo_set_self_weights_excluded(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(2)),
+                netuid,
+                true
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_self_weights_excluded(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid.next(),
+                true
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_self_weights_excluded(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            true
+        ));
+        assert!(SubtensorModule::get_self_weights_excluded(netuid));
+        assert_last_event_audited(
+            Event::SelfWeightsExcludedSet(netuid, true),
+            Hyperparameter::SelfWeightsExcluded,
+        );
 
-        // Enable Liquid Alpha and setup
-        SubtensorModule::set_liquid_alpha_enabled(netuid, true);
-        pallet_subtensor::migrations::migrate_create_root_network::migrate_create_root_network::<
-            Test,
-        >();
-        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000_000_000_000);
-        assert_ok!(SubtensorModule::root_register(signer.clone(), hotkey,));
+        assert_ok!(AdminUtils::sudo_set_self_weights_excluded(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            false
+        ));
+        assert!(!SubtensorModule::get_self_weights_excluded(netuid));
+    });
+}
 
-        // Should fail as signer does not own the subnet
-        assert_err!(
-            AdminUtils::sudo_set_alpha_values(signer.clone(), netuid, alpha_low, alpha_high),
-            DispatchError::BadOrigin
+#[test]
+fn test_sudo_set_weight_expiry_blocks() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_admin_freeze_window(0);
+        assert_eq!(SubtensorModule::get_weight_expiry_blocks(netuid), 0);
+
+        assert_eq!(
+            AdminUtils::sudo_set_weight_expiry_blocks(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(2)),
+                netuid,
+                100
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_weight_expiry_blocks(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid.next(),
+                100
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_weight_expiry_blocks(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            100
+        ));
+        assert_eq!(SubtensorModule::get_weight_expiry_blocks(netuid), 100);
+        assert_last_event_audited(
+            Event::WeightExpiryBlocksSet(netuid, 100),
+            Hyperparameter::WeightExpiry,
         );
+    });
+}
 
-        assert_ok!(SubtensorModule::register_network(signer.clone(), hotkey));
+#[test]
+fn test_sudo_set_validator_permit_min_stake() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_admin_freeze_window(0);
+        assert_eq!(SubtensorModule::get_validator_permit_min_stake(netuid), 0);
 
-        assert_ok!(AdminUtils::sudo_set_alpha_values(
-            signer.clone(),
+        assert_eq!(
+            AdminUtils::sudo_set_validator_permit_min_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(2)),
+                netuid,
+                1_000
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_validator_permit_min_stake(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid.next(),
+                1_000
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_validator_permit_min_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
             netuid,
-            alpha_low,
-            alpha_high
+            1_000
         ));
-        let (grabbed_alpha_low, grabbed_alpha_high): (u16, u16) =
-            SubtensorModule::get_alpha_values(netuid);
+        assert_eq!(
+            SubtensorModule::get_validator_permit_min_stake(netuid),
+            1_000
+        );
+        assert_last_event_audited(
+            Event::ValidatorPermitMinStakeSet(netuid, 1_000),
+            Hyperparameter::ValidatorPermitMinStake,
+        );
 
-        log::info!("alpha_low: {grabbed_alpha_low:?} alpha_high: {grabbed_alpha_high:?}");
-        assert_eq!(grabbed_alpha_low, alpha_low);
-        assert_eq!(grabbed_alpha_high, alpha_high);
+        // The owner also bounds the number of validators
+        assert_ok!(AdminUtils::sudo_set_max_allowed_validators(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            5
+        ));
+        assert_eq!(SubtensorModule::get_max_allowed_validators(netuid), 5);
+    });
+}
 
-        // Convert the u16 values to decimal values
-        fn unnormalize_u16_to_float(normalized_value: u16) -> f32 {
-            const MAX_U16: u16 = 65535;
-            normalized_value as f32 / MAX_U16 as f32
-        }
+#[test]
+fn test_sudo_set_weights_set_rate_limit_override() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_admin_freeze_window(0);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 100);
+        register_ok_neuron(netuid, U256::from(10), U256::from(11), 0);
 
-        let alpha_low_decimal = unnormalize_u16_to_float(alpha_low);
-        let alpha_high_decimal = unnormalize_u16_to_float(alpha_high);
+        assert_eq!(
+            AdminUtils::sudo_set_weights_set_rate_limit_override(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(2)),
+                netuid,
+                0,
+                Some(1)
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_weights_set_rate_limit_override(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                1,
+                Some(1)
+            ),
+            Err(Error::<Test>::UidDoesNotExist.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_weights_set_rate_limit_override(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            0,
+            Some(1)
+        ));
+        assert_eq!(
+            SubtensorModule::get_weights_set_rate_limit_for_uid(netuid, 0),
+            1
+        );
+        assert_last_event_audited(
+            Event::WeightsSetRateLimitOverrideSet {
+                netuid,
+                uid: 0,
+                limit: Some(1),
+            },
+            Hyperparameter::WeightsSetRateLimitOverride,
+        );
 
-        let (alpha_low_32, alpha_high_32) = SubtensorModule::get_alpha_values_32(netuid);
+        // Removing the override restores the rate limit of the subnet
+        assert_ok!(AdminUtils::sudo_set_weights_set_rate_limit_override(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            0,
+            None
+        ));
+        assert_eq!(
+            SubtensorModule::get_weights_set_rate_limit_for_uid(netuid, 0),
+            100
+        );
+    });
+}
 
-        let tolerance: f32 = 1e-6; // 0.000001
+#[test]
+fn test_sudo_set_neuron_immunity_override() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_admin_freeze_window(0);
+        SubtensorModule::set_immunity_period(netuid, 100);
+        register_ok_neuron(netuid, U256::from(10), U256::from(11), 0);
 
-        // Check if the values are equal to the sixth decimal
-        assert!(
-            (alpha_low_32.to_num::<f32>() - alpha_low_decimal).abs() < tolerance,
-            "alpha_low mismatch: {} != {}",
-            alpha_low_32.to_num::<f32>(),
-            alpha_low_decimal
+        assert_eq!(
+            AdminUtils::sudo_set_neuron_immunity_override(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(2)),
+                netuid,
+                0,
+                Some(1_000)
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_neuron_immunity_override(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                1,
+                Some(1_000)
+            ),
+            Err(Error::<Test>::UidDoesNotExist.into())
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_neuron_immunity_override(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                0,
+                Some(100)
+            ),
+            Err(pallet_subtensor::Error::<Test>::InvalidValue.into())
+        );
+        assert_ok!(AdminUtils::sudo_set_neuron_immunity_override(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            0,
+            Some(1_000)
+        ));
+        assert_eq!(
+            SubtensorModule::get_immunity_period_for_uid(netuid, 0),
+            1_000
         );
-        assert!(
-            (alpha_high_32.to_num::<f32>() - alpha_high_decimal).abs() < tolerance,
-            "alpha_high mismatch: {} != {}",
-            alpha_high_32.to_num::<f32>(),
-            alpha_high_decimal
+        assert_last_event_audited(
+            Event::NeuronImmunityOverrideSet {
+                netuid,
+                uid: 0,
+                immunity_period: Some(1_000),
+            },
+            Hyperparameter::NeuronImmunityOverride,
         );
 
-        // 1. Liquid alpha disabled
-        SubtensorModule::set_liquid_alpha_enabled(netuid, false);
-        assert_err!(
-            AdminUtils::sudo_set_alpha_values(signer.clone(), netuid, alpha_low, alpha_high),
-            SubtensorError::<Test>::LiquidAlphaDisabled
-        );
-        // Correct scenario after error
-        SubtensorModule::set_liquid_alpha_enabled(netuid, true); // Re-enable for further tests
-        assert_ok!(AdminUtils::sudo_set_alpha_values(
-            signer.clone(),
+        // Removing the override restores the immunity period of the subnet
+        assert_ok!(AdminUtils::sudo_set_neuron_immunity_override(
+            <<Test as Config>::RuntimeOrigin>::root(),
             netuid,
-            alpha_low,
-            alpha_high
+            0,
+            None
         ));
+        assert_eq!(SubtensorModule::get_immunity_period_for_uid(netuid, 0), 100);
+    });
+}
 
-        // 2. Alpha high too low
-        let alpha_high_too_low = (u16::MAX as u32 / 40) as u16 - 1; // One less than the minimum acceptable value
-        assert_err!(
-            AdminUtils::sudo_set_alpha_values(
-                signer.clone(),
+#[test]
+fn test_sudo_set_adaptive_max_weight_limit() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_admin_freeze_window(0);
+
+        assert_eq!(
+            AdminUtils::sudo_set_adaptive_max_weight_limit(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(2)),
                 netuid,
-                alpha_low,
-                alpha_high_too_low
+                10
             ),
-            SubtensorError::<Test>::AlphaHighTooLow
+            Err(DispatchError::BadOrigin)
         );
-        // Correct scenario after error
-        assert_ok!(AdminUtils::sudo_set_alpha_values(
-            signer.clone(),
+        assert_eq!(
+            AdminUtils::sudo_set_adaptive_max_weight_limit(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                NetUid::from(2),
+                10
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(SubtensorModule::get_adaptive_max_weight_limit(netuid), 0);
+        assert_ok!(AdminUtils::sudo_set_adaptive_max_weight_limit(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
             netuid,
-            alpha_low,
-            alpha_high
+            10
         ));
+        assert_eq!(SubtensorModule::get_adaptive_max_weight_limit(netuid), 10);
+        assert_last_event_audited(
+            Event::AdaptiveMaxWeightLimitSet(netuid, 10),
+            Hyperparameter::AdaptiveMaxWeightLimit,
+        );
+    });
+}
 
-        // 3. Alpha low too low or too high
-        let alpha_low_too_low = 0_u16;
-        assert_err!(
-            AdminUtils::sudo_set_alpha_values(
-                signer.clone(),
+#[test]
+fn test_sudo_set_burn_auction_enabled() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_admin_freeze_window(0);
+
+        assert_eq!(
+            AdminUtils::sudo_set_burn_auction_enabled(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(2)),
                 netuid,
-                alpha_low_too_low,
-                alpha_high
+                true
             ),
-            SubtensorError::<Test>::AlphaLowOutOfRange
+            Err(DispatchError::BadOrigin)
         );
-        // Correct scenario after error
-        assert_ok!(AdminUtils::sudo_set_alpha_values(
-            signer.clone(),
+        assert_eq!(
+            AdminUtils::sudo_set_burn_auction_enabled(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                NetUid::from(2),
+                true
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert!(!SubtensorModule::get_burn_auction_enabled(netuid));
+        assert_ok!(AdminUtils::sudo_set_burn_auction_enabled(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
             netuid,
-            alpha_low,
-            alpha_high
+            true
         ));
+        assert!(SubtensorModule::get_burn_auction_enabled(netuid));
+        assert_last_event_audited(
+            Event::BurnAuctionEnabledSet(netuid, true),
+            Hyperparameter::BurnAuction,
+        );
+    });
+}
 
-        let alpha_low_too_high = alpha_high + 1;
-        assert_err!(
-            AdminUtils::sudo_set_alpha_values(
-                signer.clone(),
+#[test]
+fn test_hyperparameter_change_audited() {
+    use codec::Encode;
+
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_admin_freeze_window(0);
+
+        let audited = || {
+            System::events()
+                .iter()
+                .filter(|r| {
+                    matches!(
+                        r.event,
+                        RuntimeEvent::SubtensorModule(Event::HyperparameterChanged { .. })
+                    )
+                })
+                .count()
+        };
+
+        // The owner update is audited with the value before and after it
+        assert_ok!(AdminUtils::sudo_set_burn_auction_enabled(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            true
+        ));
+        System::assert_last_event(
+            Event::HyperparameterChanged {
                 netuid,
-                alpha_low_too_high,
-                alpha_high
+                param: Hyperparameter::BurnAuction,
+                old: false.encode(),
+                new: true.encode(),
+            }
+            .into(),
+        );
+        assert_eq!(audited(), 1);
+
+        // An update leaving the value unchanged is not audited
+        assert_ok!(AdminUtils::sudo_set_burn_auction_enabled(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            true
+        ));
+        assert_eq!(audited(), 1);
+
+        // Root updates are audited too
+        assert_ok!(AdminUtils::sudo_set_burn_auction_enabled(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            false
+        ));
+        System::assert_last_event(
+            Event::HyperparameterChanged {
+                netuid,
+                param: Hyperparameter::BurnAuction,
+                old: true.encode(),
+                new: false.encode(),
+            }
+            .into(),
+        );
+        assert_eq!(audited(), 2);
+    });
+}
+
+#[test]
+fn test_sudo_set_difficulty_controller_gains() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        add_network(netuid, 10);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_admin_freeze_window(0);
+
+        assert_eq!(
+            AdminUtils::sudo_set_difficulty_controller_gains(
+                <<Test as Config>::RuntimeOrigin>::signed(owner),
+                netuid,
+                500_000,
+                50_000,
+                100_000
             ),
-            SubtensorError::<Test>::AlphaLowOutOfRange
+            Err(DispatchError::BadOrigin)
         );
-        // Correct scenario after error
-        assert_ok!(AdminUtils::sudo_set_alpha_values(
-            signer.clone(),
+        assert_eq!(
+            AdminUtils::sudo_set_difficulty_controller_gains(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                NetUid::from(2),
+                500_000,
+                50_000,
+                100_000
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(
+            SubtensorModule::get_difficulty_controller_gains(netuid),
+            None
+        );
+        assert_ok!(AdminUtils::sudo_set_difficulty_controller_gains(
+            <<Test as Config>::RuntimeOrigin>::root(),
             netuid,
-            alpha_low,
-            alpha_high
+            500_000,
+            50_000,
+            100_000
+        ));
+        assert_eq!(
+            SubtensorModule::get_difficulty_controller_gains(netuid),
+            Some(DifficultyControllerGains {
+                kp: 500_000,
+                ki: 50_000,
+                kd: 100_000,
+            })
+        );
+        assert_ok!(AdminUtils::sudo_set_difficulty_controller_gains(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            0,
+            0,
+            0
         ));
+        assert_eq!(
+            SubtensorModule::get_difficulty_controller_gains(netuid),
+            None
+        );
     });
 }
 
@@ -1518,6 +2971,132 @@ fn sudo_set_commit_reveal_weights_interval() {
     });
 }
 
+#[test]
+fn test_sudo_set_commit_reveal_bounds_and_reveal_window() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        add_network(netuid, 10);
+
+        assert_eq!(
+            AdminUtils::sudo_set_commit_reveal_bounds(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                2,
+                20,
+                5
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_commit_reveal_bounds(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                0,
+                20,
+                5
+            ),
+            pallet_subtensor::Error::<Test>::InvalidCommitRevealBounds
+        );
+        assert_ok!(AdminUtils::sudo_set_commit_reveal_bounds(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            2,
+            20,
+            5
+        ));
+
+        // Periods are set within the new bounds
+        assert_noop!(
+            AdminUtils::sudo_set_commit_reveal_weights_interval(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                21
+            ),
+            pallet_subtensor::Error::<Test>::RevealPeriodTooLarge
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_commit_reveal_weights_interval(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                1
+            ),
+            pallet_subtensor::Error::<Test>::RevealPeriodTooSmall
+        );
+
+        // So are reveal windows, with 0 for the whole epoch
+        assert_noop!(
+            AdminUtils::sudo_set_reveal_window(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                4
+            ),
+            pallet_subtensor::Error::<Test>::RevealWindowTooSmall
+        );
+        assert_ok!(AdminUtils::sudo_set_reveal_window(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            5
+        ));
+        assert_eq!(SubtensorModule::get_reveal_window(netuid), 5);
+        assert_ok!(AdminUtils::sudo_set_reveal_window(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            0
+        ));
+        assert_eq!(SubtensorModule::get_reveal_window(netuid), 0);
+    });
+}
+
+#[test]
+fn test_sudo_set_tempo_seconds() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        add_network(netuid, 10);
+
+        assert_eq!(
+            AdminUtils::sudo_set_tempo_seconds(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                Some(3_600)
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_tempo_seconds(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid.next(),
+                Some(3_600)
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_noop!(
+            AdminUtils::sudo_set_tempo_seconds(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                Some(0)
+            ),
+            pallet_subtensor::Error::<Test>::InvalidTempoSeconds
+        );
+
+        assert_ok!(AdminUtils::sudo_set_tempo_seconds(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            Some(3_600)
+        ));
+        assert_eq!(SubtensorModule::get_tempo(netuid), 300);
+        assert_eq!(
+            pallet_subtensor::TempoSeconds::<Test>::get(netuid),
+            Some(3_600)
+        );
+
+        // Setting the tempo in blocks drops the tempo in seconds
+        assert_ok!(AdminUtils::sudo_set_tempo(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            50
+        ));
+        assert_eq!(SubtensorModule::get_tempo(netuid), 50);
+        assert_eq!(pallet_subtensor::TempoSeconds::<Test>::get(netuid), None);
+    });
+}
+
 #[test]
 fn test_sudo_root_sets_evm_chain_id() {
     new_test_ext().execute_with(|| {
@@ -2578,7 +4157,7 @@ fn test_trim_to_max_allowed_uids() {
             for mecid in 0..mechanism_count.into() {
                 let netuid_index =
                     SubtensorModule::get_mechanism_storage_index(netuid, MechId::from(mecid));
-                Weights::<Test>::insert(netuid_index, uid, weights.clone());
+                Weights::<Test>::insert(netuid_index, uid, WeightRow::from(weights.clone()));
                 Bonds::<Test>::insert(netuid_index, uid, bonds.clone());
             }
         }
@@ -2677,7 +4256,7 @@ fn test_trim_to_max_allowed_uids() {
                 assert!(
                     Weights::<Test>::get(netuid_index, uid)
                         .iter()
-                        .all(|(target_uid, _)| *target_uid < new_max_n),
+                        .all(|(target_uid, _)| target_uid < new_max_n),
                     "Found a weight with target_uid >= new_max_n"
                 );
                 assert!(
@@ -2832,6 +4411,26 @@ fn test_trim_to_max_allowed_uids_too_many_immune() {
     });
 }
 
+#[test]
+fn test_trim_to_max_allowed_uids_weight_scales_with_subnet_size() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        add_network(netuid, 10);
+        let call = RuntimeCall::AdminUtils(crate::Call::sudo_trim_to_max_allowed_uids {
+            netuid,
+            max_n: 1,
+        });
+        let empty_weight = call.get_dispatch_info().call_weight;
+
+        SubnetworkN::<Test>::insert(netuid, 8);
+        assert_eq!(
+            call.get_dispatch_info().call_weight,
+            <() as crate::WeightInfo>::sudo_trim_to_max_allowed_uids(8)
+        );
+        assert!(call.get_dispatch_info().call_weight.all_gt(empty_weight));
+    });
+}
+
 #[test]
 fn test_sudo_set_min_allowed_uids() {
     new_test_ext().execute_with(|| {
@@ -0,0 +1,63 @@
+//! Time-locked sudo calls.
+//!
+//! Root changes network-wide economic parameters with immediate effect, which leaves stakers and
+//! subnet owners no time to react. Once root sets a sudo call delay, the time-locked calls are only
+//! made after being announced with `announce_sudo_call`: the announcement is kept on chain with
+//! the block from which the call can be made, root can cancel it until then, and afterwards the
+//! call is made either by root directly or by anyone with `execute_sudo_call`. Lowering the delay
+//! is itself time-locked, a zero delay makes the calls immediate again.
+
+use super::*;
+use frame_support::ensure;
+use sp_runtime::traits::Hash;
+
+/// The maximum number of blocks a time-locked sudo call waits for.
+pub const MAX_SUDO_CALL_DELAY: u64 = 7200 * 30;
+
+impl<T: Config> Pallet<T> {
+    /// Whether `call` is only made after being announced, while sudo calls are time-locked.
+    pub fn is_sudo_call_timelocked(call: &Call<T>) -> bool {
+        matches!(
+            call,
+            Call::sudo_set_network_immunity_period { .. }
+                | Call::sudo_set_network_min_lock_cost { .. }
+                | Call::sudo_set_subnet_limit { .. }
+                | Call::sudo_set_lock_reduction_interval { .. }
+                | Call::sudo_set_subnet_moving_alpha { .. }
+                | Call::sudo_set_miner_emission_share_bounds { .. }
+                | Call::sudo_set_max_subnet_emission_share { .. }
+                | Call::sudo_set_network_treasury_share { .. }
+                | Call::sudo_set_sudo_call_delay { .. }
+        )
+    }
+
+    /// Ensures `call`, a time-locked call, was announced and its delay is over, and consumes the
+    /// announcement. Calls are not time-locked while the delay is zero.
+    pub fn ensure_sudo_call_unlocked(call: Call<T>) -> DispatchResult {
+        if SudoCallDelay::<T>::get() == 0 {
+            return Ok(());
+        }
+        let call_hash = T::Hashing::hash_of(&call);
+        Self::ensure_sudo_call_executable(call_hash)?;
+        Self::consume_sudo_call(call_hash);
+        Ok(())
+    }
+
+    /// Ensures the call announced under `call_hash` can be made at the current block.
+    pub fn ensure_sudo_call_executable(call_hash: T::Hash) -> DispatchResult {
+        let executable_at =
+            PendingSudoCalls::<T>::get(call_hash).ok_or(Error::<T>::SudoCallNotAnnounced)?;
+        ensure!(
+            pallet_subtensor::Pallet::<T>::get_current_block_as_u64() >= executable_at,
+            Error::<T>::SudoCallDelayNotElapsed
+        );
+        Ok(())
+    }
+
+    /// Removes the announcement of the call under `call_hash` once it is made.
+    pub fn consume_sudo_call(call_hash: T::Hash) {
+        if PendingSudoCalls::<T>::take(call_hash).is_some() {
+            Self::deposit_event(Event::SudoCallExecuted { call_hash });
+        }
+    }
+}
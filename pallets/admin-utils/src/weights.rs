@@ -0,0 +1,1200 @@
+//! Weights for `pallet_admin_utils`
+//!
+//! These weights are estimates written by hand, not benchmark output: the reads and writes are
+//! counted from the storage each call touches and the execution times are rounded estimates.
+//! Every call of the pallet is benchmarked in `benchmarking.rs`. This file is to be replaced by
+//! the output of those benchmarks on reference hardware, with `scripts/benchmark_all.sh --write`,
+//! before the weights are relied on.
+//!
+//! Calls whose cost grows with the size of an argument or of the subnet take that size as a
+//! component: the authority count of `swap_authorities` and `schedule_grandpa_change`, the
+//! split length of `sudo_set_mechanism_emission_split` and the subnet size of
+//! `sudo_trim_to_max_allowed_uids`.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_admin_utils`.
+pub trait WeightInfo {
+	fn swap_authorities(a: u32, ) -> Weight;
+	fn sudo_set_default_take() -> Weight;
+	fn sudo_set_tx_rate_limit() -> Weight;
+	fn sudo_set_serving_rate_limit() -> Weight;
+	fn sudo_set_min_difficulty() -> Weight;
+	fn sudo_set_max_difficulty() -> Weight;
+	fn sudo_set_weights_version_key() -> Weight;
+	fn sudo_set_weights_set_rate_limit() -> Weight;
+	fn sudo_set_adjustment_interval() -> Weight;
+	fn sudo_set_adjustment_alpha() -> Weight;
+	fn sudo_set_max_weight_limit() -> Weight;
+	fn sudo_set_immunity_period() -> Weight;
+	fn sudo_set_min_allowed_weights() -> Weight;
+	fn sudo_set_max_allowed_uids() -> Weight;
+	fn sudo_set_kappa() -> Weight;
+	fn sudo_set_rho() -> Weight;
+	fn sudo_set_activity_cutoff() -> Weight;
+	fn sudo_set_network_registration_allowed() -> Weight;
+	fn sudo_set_network_pow_registration_allowed() -> Weight;
+	fn sudo_set_target_registrations_per_interval() -> Weight;
+	fn sudo_set_min_burn() -> Weight;
+	fn sudo_set_max_burn() -> Weight;
+	fn sudo_set_difficulty() -> Weight;
+	fn sudo_set_max_allowed_validators() -> Weight;
+	fn sudo_set_bonds_moving_average() -> Weight;
+	fn sudo_set_bonds_penalty() -> Weight;
+	fn sudo_set_max_registrations_per_block() -> Weight;
+	fn sudo_set_subnet_owner_cut() -> Weight;
+	fn sudo_set_network_rate_limit() -> Weight;
+	fn sudo_set_tempo() -> Weight;
+	fn sudo_set_total_issuance() -> Weight;
+	fn sudo_set_network_immunity_period() -> Weight;
+	fn sudo_set_network_min_lock_cost() -> Weight;
+	fn sudo_set_subnet_limit() -> Weight;
+	fn sudo_set_lock_reduction_interval() -> Weight;
+	fn sudo_set_rao_recycled() -> Weight;
+	fn sudo_set_stake_threshold() -> Weight;
+	fn sudo_set_nominator_min_required_stake() -> Weight;
+	fn sudo_set_tx_delegate_take_rate_limit() -> Weight;
+	fn sudo_set_min_delegate_take() -> Weight;
+	fn sudo_set_commit_reveal_weights_enabled() -> Weight;
+	fn sudo_set_liquid_alpha_enabled() -> Weight;
+	fn sudo_set_alpha_values() -> Weight;
+	fn sudo_set_coldkey_swap_schedule_duration() -> Weight;
+	fn sudo_set_dissolve_network_schedule_duration() -> Weight;
+	fn sudo_set_commit_reveal_weights_interval() -> Weight;
+	fn sudo_set_evm_chain_id() -> Weight;
+	fn schedule_grandpa_change(a: u32, ) -> Weight;
+	fn sudo_set_toggle_transfer() -> Weight;
+	fn sudo_set_recycle_or_burn() -> Weight;
+	fn sudo_toggle_evm_precompile() -> Weight;
+	fn sudo_set_subnet_moving_alpha() -> Weight;
+	fn sudo_set_subnet_owner_hotkey() -> Weight;
+	fn sudo_set_ema_price_halving_period() -> Weight;
+	fn sudo_set_alpha_sigmoid_steepness() -> Weight;
+	fn sudo_set_yuma3_enabled() -> Weight;
+	fn sudo_set_bonds_reset_enabled() -> Weight;
+	fn sudo_set_sn_owner_hotkey() -> Weight;
+	fn sudo_set_subtoken_enabled() -> Weight;
+	fn sudo_set_commit_reveal_version() -> Weight;
+	fn sudo_set_owner_immune_neuron_limit() -> Weight;
+	fn sudo_set_ck_burn() -> Weight;
+	fn sudo_set_admin_freeze_window() -> Weight;
+	fn sudo_set_owner_hparam_rate_limit() -> Weight;
+	fn sudo_set_mechanism_count() -> Weight;
+	fn sudo_set_mechanism_emission_split(m: u32, ) -> Weight;
+	fn sudo_trim_to_max_allowed_uids(n: u32, ) -> Weight;
+	fn sudo_set_min_allowed_uids() -> Weight;
+	fn sudo_set_churn_burn_params() -> Weight;
+	fn sudo_set_sliced_unstake_depth_fraction() -> Weight;
+	fn sudo_set_subnet_features() -> Weight;
+	fn sudo_set_max_scheduled_operations() -> Weight;
+	fn sudo_set_timelocked_weights_only() -> Weight;
+	fn sudo_set_reveal_window() -> Weight;
+	fn sudo_set_commit_reveal_bounds() -> Weight;
+	fn sudo_set_tempo_seconds() -> Weight;
+	fn sudo_set_merkle_weights_enabled() -> Weight;
+	fn sudo_set_liquid_alpha_bounds() -> Weight;
+	fn sudo_set_consensus_engine() -> Weight;
+	fn sudo_set_owner_hparam_group_rate_limit() -> Weight;
+	fn sudo_set_self_weights_excluded() -> Weight;
+	fn sudo_set_weight_expiry_blocks() -> Weight;
+	fn sudo_set_validator_permit_min_stake() -> Weight;
+	fn sudo_set_weights_set_rate_limit_override() -> Weight;
+	fn sudo_set_adaptive_max_weight_limit() -> Weight;
+	fn sudo_set_burn_auction_enabled() -> Weight;
+	fn sudo_set_difficulty_controller_gains() -> Weight;
+	fn sudo_set_free_extrinsic_min_balance() -> Weight;
+	fn sudo_set_max_registrations_per_coldkey_per_interval() -> Weight;
+	fn sudo_set_registration_queue_enabled() -> Weight;
+	fn sudo_set_neuron_immunity_override() -> Weight;
+	fn sudo_set_registration_deposit() -> Weight;
+	fn sudo_set_miner_emission_share() -> Weight;
+	fn sudo_set_miner_emission_share_bounds() -> Weight;
+	fn sudo_set_owner_cut_vesting_blocks() -> Weight;
+	fn sudo_set_min_owner_cut_vesting_blocks() -> Weight;
+	fn sudo_set_subnet_treasury() -> Weight;
+	fn sudo_set_max_subnet_emission_share() -> Weight;
+	fn sudo_set_hyperparameter_voting_period() -> Weight;
+	fn sudo_set_network_treasury_share() -> Weight;
+	fn sudo_set_sudo_call_delay() -> Weight;
+	fn announce_sudo_call() -> Weight;
+	fn cancel_sudo_call() -> Weight;
+	fn execute_sudo_call() -> Weight;
+	fn sudo_set_senate_hysteresis() -> Weight;
+}
+
+/// Weights for `pallet_admin_utils` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn swap_authorities(a: u32, ) -> Weight {
+		Weight::from_parts(4_629_000, 0)
+			.saturating_add(Weight::from_parts(220_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_default_take() -> Weight {
+		Weight::from_parts(5_420_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_tx_rate_limit() -> Weight {
+		Weight::from_parts(5_400_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_serving_rate_limit() -> Weight {
+		Weight::from_parts(22_980_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_min_difficulty() -> Weight {
+		Weight::from_parts(26_390_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_max_difficulty() -> Weight {
+		Weight::from_parts(26_990_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_weights_version_key() -> Weight {
+		Weight::from_parts(26_220_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_weights_set_rate_limit() -> Weight {
+		Weight::from_parts(15_060_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_adjustment_interval() -> Weight {
+		Weight::from_parts(21_320_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_adjustment_alpha() -> Weight {
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_max_weight_limit() -> Weight {
+		Weight::from_parts(26_890_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_immunity_period() -> Weight {
+		Weight::from_parts(26_620_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_min_allowed_weights() -> Weight {
+		Weight::from_parts(26_630_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_max_allowed_uids() -> Weight {
+		Weight::from_parts(32_140_000, 0)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_kappa() -> Weight {
+		Weight::from_parts(26_210_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_rho() -> Weight {
+		Weight::from_parts(23_360_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_activity_cutoff() -> Weight {
+		Weight::from_parts(28_720_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_network_registration_allowed() -> Weight {
+		Weight::from_parts(7_343_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_network_pow_registration_allowed() -> Weight {
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_target_registrations_per_interval() -> Weight {
+		Weight::from_parts(44_320_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_min_burn() -> Weight {
+		Weight::from_parts(29_970_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_max_burn() -> Weight {
+		Weight::from_parts(30_510_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_difficulty() -> Weight {
+		Weight::from_parts(38_500_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_max_allowed_validators() -> Weight {
+		Weight::from_parts(30_930_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_bonds_moving_average() -> Weight {
+		Weight::from_parts(26_270_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_bonds_penalty() -> Weight {
+		Weight::from_parts(26_890_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_max_registrations_per_block() -> Weight {
+		Weight::from_parts(26_970_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_subnet_owner_cut() -> Weight {
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_network_rate_limit() -> Weight {
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_tempo() -> Weight {
+		Weight::from_parts(25_790_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn sudo_set_total_issuance() -> Weight {
+		Weight::from_parts(2_875_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_network_immunity_period() -> Weight {
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_network_min_lock_cost() -> Weight {
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_subnet_limit() -> Weight {
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_lock_reduction_interval() -> Weight {
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_rao_recycled() -> Weight {
+		Weight::from_parts(15_060_000, 4045)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_stake_threshold() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_nominator_min_required_stake() -> Weight {
+		Weight::from_parts(28_050_000, 6792)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_tx_delegate_take_rate_limit() -> Weight {
+		Weight::from_parts(5_019_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_min_delegate_take() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_commit_reveal_weights_enabled() -> Weight {
+		Weight::from_parts(26_730_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_liquid_alpha_enabled() -> Weight {
+		Weight::from_parts(18_300_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_alpha_values() -> Weight {
+		Weight::from_parts(25_280_000, 4089)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_coldkey_swap_schedule_duration() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_dissolve_network_schedule_duration() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_commit_reveal_weights_interval() -> Weight {
+		Weight::from_parts(26_950_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_evm_chain_id() -> Weight {
+		Weight::from_parts(27_199_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn schedule_grandpa_change(a: u32, ) -> Weight {
+		Weight::from_parts(7_779_000, 0)
+			.saturating_add(Weight::from_parts(180_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_toggle_transfer() -> Weight {
+		Weight::from_parts(20_460_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_recycle_or_burn() -> Weight {
+		Weight::from_parts(26_730_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_toggle_evm_precompile() -> Weight {
+		Weight::from_parts(6_392_000, 3507)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(0_u64))
+	}
+	fn sudo_set_subnet_moving_alpha() -> Weight {
+		Weight::from_parts(3_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_subnet_owner_hotkey() -> Weight {
+		Weight::from_parts(15_060_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_ema_price_halving_period() -> Weight {
+		Weight::from_parts(3_918_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_alpha_sigmoid_steepness() -> Weight {
+		Weight::from_parts(23_140_000, 4045)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_yuma3_enabled() -> Weight {
+		Weight::from_parts(20_460_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_bonds_reset_enabled() -> Weight {
+		Weight::from_parts(22_340_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_sn_owner_hotkey() -> Weight {
+		Weight::from_parts(20_570_000, 4204)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn sudo_set_subtoken_enabled() -> Weight {
+		Weight::from_parts(17_980_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_commit_reveal_version() -> Weight {
+		Weight::from_parts(7_114_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_owner_immune_neuron_limit() -> Weight {
+		Weight::from_parts(18_020_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_ck_burn() -> Weight {
+		Weight::from_parts(15_650_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_admin_freeze_window() -> Weight {
+		Weight::from_parts(5_771_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_owner_hparam_rate_limit() -> Weight {
+		Weight::from_parts(5_701_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_mechanism_count() -> Weight {
+		Weight::from_parts(31_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn sudo_set_mechanism_emission_split(m: u32, ) -> Weight {
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(Weight::from_parts(25_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_trim_to_max_allowed_uids(n: u32, ) -> Weight {
+		Weight::from_parts(48_000_000, 0)
+			.saturating_add(Weight::from_parts(2_100_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+			.saturating_add(T::DbWeight::get().writes((13_u64).saturating_mul(n.into())))
+	}
+	fn sudo_set_min_allowed_uids() -> Weight {
+		Weight::from_parts(31_550_000, 0)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_churn_burn_params() -> Weight {
+		Weight::from_parts(27_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn sudo_set_sliced_unstake_depth_fraction() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_subnet_features() -> Weight {
+		Weight::from_parts(40_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	fn sudo_set_max_scheduled_operations() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_timelocked_weights_only() -> Weight {
+		Weight::from_parts(26_730_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_reveal_window() -> Weight {
+		Weight::from_parts(26_950_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_commit_reveal_bounds() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn sudo_set_tempo_seconds() -> Weight {
+		Weight::from_parts(25_790_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn sudo_set_merkle_weights_enabled() -> Weight {
+		Weight::from_parts(26_730_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_liquid_alpha_bounds() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_consensus_engine() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_owner_hparam_group_rate_limit() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_self_weights_excluded() -> Weight {
+		Weight::from_parts(26_730_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_weight_expiry_blocks() -> Weight {
+		Weight::from_parts(26_730_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_validator_permit_min_stake() -> Weight {
+		Weight::from_parts(26_730_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_weights_set_rate_limit_override() -> Weight {
+		Weight::from_parts(27_980_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_adaptive_max_weight_limit() -> Weight {
+		Weight::from_parts(26_510_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_burn_auction_enabled() -> Weight {
+		Weight::from_parts(26_220_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_difficulty_controller_gains() -> Weight {
+		Weight::from_parts(18_450_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn sudo_set_free_extrinsic_min_balance() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_max_registrations_per_coldkey_per_interval() -> Weight {
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn sudo_set_registration_queue_enabled() -> Weight {
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn sudo_set_neuron_immunity_override() -> Weight {
+		Weight::from_parts(38_500_000, 0)
+			.saturating_add(T::DbWeight::get().reads(21_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn sudo_set_registration_deposit() -> Weight {
+		Weight::from_parts(22_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn sudo_set_miner_emission_share() -> Weight {
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn sudo_set_miner_emission_share_bounds() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_owner_cut_vesting_blocks() -> Weight {
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn sudo_set_min_owner_cut_vesting_blocks() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_subnet_treasury() -> Weight {
+		Weight::from_parts(22_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn sudo_set_max_subnet_emission_share() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_hyperparameter_voting_period() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn sudo_set_network_treasury_share() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_sudo_call_delay() -> Weight {
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn announce_sudo_call() -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn cancel_sudo_call() -> Weight {
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn execute_sudo_call() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_senate_hysteresis() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(0_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	fn swap_authorities(a: u32, ) -> Weight {
+		Weight::from_parts(4_629_000, 0)
+			.saturating_add(Weight::from_parts(220_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_default_take() -> Weight {
+		Weight::from_parts(5_420_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_tx_rate_limit() -> Weight {
+		Weight::from_parts(5_400_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_serving_rate_limit() -> Weight {
+		Weight::from_parts(22_980_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_min_difficulty() -> Weight {
+		Weight::from_parts(26_390_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_max_difficulty() -> Weight {
+		Weight::from_parts(26_990_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_weights_version_key() -> Weight {
+		Weight::from_parts(26_220_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_weights_set_rate_limit() -> Weight {
+		Weight::from_parts(15_060_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_adjustment_interval() -> Weight {
+		Weight::from_parts(21_320_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_adjustment_alpha() -> Weight {
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_max_weight_limit() -> Weight {
+		Weight::from_parts(26_890_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_immunity_period() -> Weight {
+		Weight::from_parts(26_620_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_min_allowed_weights() -> Weight {
+		Weight::from_parts(26_630_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_max_allowed_uids() -> Weight {
+		Weight::from_parts(32_140_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_kappa() -> Weight {
+		Weight::from_parts(26_210_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_rho() -> Weight {
+		Weight::from_parts(23_360_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_activity_cutoff() -> Weight {
+		Weight::from_parts(28_720_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_network_registration_allowed() -> Weight {
+		Weight::from_parts(7_343_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_network_pow_registration_allowed() -> Weight {
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_target_registrations_per_interval() -> Weight {
+		Weight::from_parts(44_320_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_min_burn() -> Weight {
+		Weight::from_parts(29_970_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_max_burn() -> Weight {
+		Weight::from_parts(30_510_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_difficulty() -> Weight {
+		Weight::from_parts(38_500_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_max_allowed_validators() -> Weight {
+		Weight::from_parts(30_930_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_bonds_moving_average() -> Weight {
+		Weight::from_parts(26_270_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_bonds_penalty() -> Weight {
+		Weight::from_parts(26_890_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_max_registrations_per_block() -> Weight {
+		Weight::from_parts(26_970_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_subnet_owner_cut() -> Weight {
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_network_rate_limit() -> Weight {
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_tempo() -> Weight {
+		Weight::from_parts(25_790_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn sudo_set_total_issuance() -> Weight {
+		Weight::from_parts(2_875_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_network_immunity_period() -> Weight {
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_network_min_lock_cost() -> Weight {
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_subnet_limit() -> Weight {
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_lock_reduction_interval() -> Weight {
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_rao_recycled() -> Weight {
+		Weight::from_parts(15_060_000, 4045)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_stake_threshold() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_nominator_min_required_stake() -> Weight {
+		Weight::from_parts(28_050_000, 6792)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_tx_delegate_take_rate_limit() -> Weight {
+		Weight::from_parts(5_019_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_min_delegate_take() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_commit_reveal_weights_enabled() -> Weight {
+		Weight::from_parts(26_730_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_liquid_alpha_enabled() -> Weight {
+		Weight::from_parts(18_300_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_alpha_values() -> Weight {
+		Weight::from_parts(25_280_000, 4089)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_coldkey_swap_schedule_duration() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_dissolve_network_schedule_duration() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_commit_reveal_weights_interval() -> Weight {
+		Weight::from_parts(26_950_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_evm_chain_id() -> Weight {
+		Weight::from_parts(27_199_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn schedule_grandpa_change(a: u32, ) -> Weight {
+		Weight::from_parts(7_779_000, 0)
+			.saturating_add(Weight::from_parts(180_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_toggle_transfer() -> Weight {
+		Weight::from_parts(20_460_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_recycle_or_burn() -> Weight {
+		Weight::from_parts(26_730_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_toggle_evm_precompile() -> Weight {
+		Weight::from_parts(6_392_000, 3507)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(0_u64))
+	}
+	fn sudo_set_subnet_moving_alpha() -> Weight {
+		Weight::from_parts(3_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_subnet_owner_hotkey() -> Weight {
+		Weight::from_parts(15_060_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_ema_price_halving_period() -> Weight {
+		Weight::from_parts(3_918_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_alpha_sigmoid_steepness() -> Weight {
+		Weight::from_parts(23_140_000, 4045)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_yuma3_enabled() -> Weight {
+		Weight::from_parts(20_460_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_bonds_reset_enabled() -> Weight {
+		Weight::from_parts(22_340_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_sn_owner_hotkey() -> Weight {
+		Weight::from_parts(20_570_000, 4204)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn sudo_set_subtoken_enabled() -> Weight {
+		Weight::from_parts(17_980_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_commit_reveal_version() -> Weight {
+		Weight::from_parts(7_114_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_owner_immune_neuron_limit() -> Weight {
+		Weight::from_parts(18_020_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_ck_burn() -> Weight {
+		Weight::from_parts(15_650_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_admin_freeze_window() -> Weight {
+		Weight::from_parts(5_771_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_owner_hparam_rate_limit() -> Weight {
+		Weight::from_parts(5_701_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_mechanism_count() -> Weight {
+		Weight::from_parts(31_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn sudo_set_mechanism_emission_split(m: u32, ) -> Weight {
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(Weight::from_parts(25_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_trim_to_max_allowed_uids(n: u32, ) -> Weight {
+		Weight::from_parts(48_000_000, 0)
+			.saturating_add(Weight::from_parts(2_100_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+			.saturating_add(RocksDbWeight::get().writes((13_u64).saturating_mul(n.into())))
+	}
+	fn sudo_set_min_allowed_uids() -> Weight {
+		Weight::from_parts(31_550_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_churn_burn_params() -> Weight {
+		Weight::from_parts(27_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn sudo_set_sliced_unstake_depth_fraction() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_subnet_features() -> Weight {
+		Weight::from_parts(40_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	fn sudo_set_max_scheduled_operations() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_timelocked_weights_only() -> Weight {
+		Weight::from_parts(26_730_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_reveal_window() -> Weight {
+		Weight::from_parts(26_950_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_commit_reveal_bounds() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn sudo_set_tempo_seconds() -> Weight {
+		Weight::from_parts(25_790_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn sudo_set_merkle_weights_enabled() -> Weight {
+		Weight::from_parts(26_730_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_liquid_alpha_bounds() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_consensus_engine() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_owner_hparam_group_rate_limit() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_self_weights_excluded() -> Weight {
+		Weight::from_parts(26_730_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_weight_expiry_blocks() -> Weight {
+		Weight::from_parts(26_730_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_validator_permit_min_stake() -> Weight {
+		Weight::from_parts(26_730_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_weights_set_rate_limit_override() -> Weight {
+		Weight::from_parts(27_980_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_adaptive_max_weight_limit() -> Weight {
+		Weight::from_parts(26_510_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_burn_auction_enabled() -> Weight {
+		Weight::from_parts(26_220_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_difficulty_controller_gains() -> Weight {
+		Weight::from_parts(18_450_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn sudo_set_free_extrinsic_min_balance() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_max_registrations_per_coldkey_per_interval() -> Weight {
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn sudo_set_registration_queue_enabled() -> Weight {
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn sudo_set_neuron_immunity_override() -> Weight {
+		Weight::from_parts(38_500_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn sudo_set_registration_deposit() -> Weight {
+		Weight::from_parts(22_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn sudo_set_miner_emission_share() -> Weight {
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn sudo_set_miner_emission_share_bounds() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_owner_cut_vesting_blocks() -> Weight {
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn sudo_set_min_owner_cut_vesting_blocks() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_subnet_treasury() -> Weight {
+		Weight::from_parts(22_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn sudo_set_max_subnet_emission_share() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_hyperparameter_voting_period() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn sudo_set_network_treasury_share() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_sudo_call_delay() -> Weight {
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn announce_sudo_call() -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn cancel_sudo_call() -> Weight {
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn execute_sudo_call() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn sudo_set_senate_hysteresis() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
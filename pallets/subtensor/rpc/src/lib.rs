@@ -9,7 +9,7 @@ use jsonrpsee::{
 use sp_blockchain::HeaderBackend;
 use sp_runtime::{AccountId32, traits::Block as BlockT};
 use std::sync::Arc;
-use subtensor_runtime_common::{MechId, NetUid, TaoCurrency};
+use subtensor_runtime_common::{AlphaCurrency, MechId, NetUid, TaoCurrency};
 
 use sp_api::ProvideRuntimeApi;
 
@@ -85,6 +85,8 @@ pub trait SubtensorCustomApi<BlockHash> {
     fn get_subnet_state(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
     #[method(name = "subnetInfo_getLockCost")]
     fn get_network_lock_cost(&self, at: Option<BlockHash>) -> RpcResult<TaoCurrency>;
+    #[method(name = "subnetInfo_getLockCostAt")]
+    fn get_lock_cost_at(&self, block: u64, at: Option<BlockHash>) -> RpcResult<TaoCurrency>;
     #[method(name = "subnetInfo_getSelectiveMetagraph")]
     fn get_selective_metagraph(
         &self,
@@ -109,6 +111,34 @@ pub trait SubtensorCustomApi<BlockHash> {
     ) -> RpcResult<Vec<u8>>;
     #[method(name = "subnetInfo_getSubnetToPrune")]
     fn get_subnet_to_prune(&self, at: Option<BlockHash>) -> RpcResult<Option<NetUid>>;
+    #[method(name = "subnetInfo_estimateValidatorYield")]
+    fn estimate_validator_yield(
+        &self,
+        netuid: NetUid,
+        hotkey: AccountId32,
+        stake_amount: AlphaCurrency,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getStagedHyperparameters")]
+    fn get_staged_hyperparameters(
+        &self,
+        netuid: NetUid,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getReservedAccounts")]
+    fn get_reserved_accounts(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getSubnetFeatures")]
+    fn get_subnet_features(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Option<u16>>;
+    #[method(name = "subnetInfo_getSubnetClock")]
+    fn get_subnet_clock(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getSubnetTokenAccounting")]
+    fn get_subnet_token_accounting(
+        &self,
+        netuid: NetUid,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_simulateEpoch")]
+    fn simulate_epoch(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
 }
 
 pub struct SubtensorCustom<C, P> {
@@ -464,6 +494,19 @@ where
         })
     }
 
+    fn get_lock_cost_at(
+        &self,
+        block: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<TaoCurrency> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_lock_cost_at(at, block).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get subnet lock cost: {e:?}")).into()
+        })
+    }
+
     fn get_selective_metagraph(
         &self,
         netuid: NetUid,
@@ -531,4 +574,113 @@ where
             }
         }
     }
+
+    fn estimate_validator_yield(
+        &self,
+        netuid: NetUid,
+        hotkey: AccountId32,
+        stake_amount: AlphaCurrency,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.estimate_validator_yield(at, netuid, hotkey, stake_amount) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to estimate validator yield: {e:?}"
+            ))
+            .into()),
+        }
+    }
+
+    fn get_staged_hyperparameters(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_staged_hyperparameters(at, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get staged hyperparameters: {e:?}"
+            ))
+            .into()),
+        }
+    }
+
+    fn get_reserved_accounts(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_reserved_accounts(at) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get reserved accounts: {e:?}")).into())
+            }
+        }
+    }
+
+    fn get_subnet_features(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<u16>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_subnet_features(at, netuid) {
+            Ok(result) => Ok(result),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get subnet features: {e:?}")).into())
+            }
+        }
+    }
+
+    fn get_subnet_clock(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_subnet_clock(at, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!("Unable to get subnet clock: {e:?}")).into()),
+        }
+    }
+
+    fn get_subnet_token_accounting(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_subnet_token_accounting(at, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!(
+                "Unable to get subnet token accounting: {e:?}"
+            ))
+            .into()),
+        }
+    }
+
+    fn simulate_epoch(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.simulate_epoch(at, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!("Unable to simulate epoch: {e:?}")).into()),
+        }
+    }
 }
@@ -2,15 +2,30 @@
 extern crate alloc;
 use alloc::vec::Vec;
 use codec::Compact;
+use pallet_subtensor::coinbase::emission_telemetry::EmissionTelemetry;
 use pallet_subtensor::rpc_info::{
     delegate_info::DelegateInfo,
+    delegate_performance::DelegatePerformance,
     dynamic_info::DynamicInfo,
+    epoch_simulation::EpochSimulation,
+    issuance_schedule::IssuanceSchedule,
+    metadata_compat::PalletVariantsInfo,
     metagraph::{Metagraph, SelectiveMetagraph},
+    metagraph_page::MetagraphPage,
     neuron_info::{NeuronInfo, NeuronInfoLite},
     show_subnet::SubnetState,
     stake_info::StakeInfo,
     subnet_info::{SubnetHyperparams, SubnetHyperparamsV2, SubnetInfo, SubnetInfov2},
+    validator_yield::ValidatorYieldEstimate,
 };
+use pallet_subtensor::staking::stake_origin::StakeOriginInfo;
+use pallet_subtensor::subnets::attestations::SubnetAttestation;
+use pallet_subtensor::subnets::clock::SubnetClock;
+use pallet_subtensor::subnets::staged_hyperparams::StagedHyperparameterBundle;
+use pallet_subtensor::subnets::token_accounting::SubnetTokenAccounting;
+use pallet_subtensor::swap::key_rotation_log::KeyRotationRecord;
+use pallet_subtensor::utils::reserved_accounts::ReservedAccountKind;
+use pallet_subtensor::utils::scheduled_operations::ScheduledOperation;
 use sp_runtime::AccountId32;
 use subtensor_runtime_common::{AlphaCurrency, MechId, NetUid, TaoCurrency};
 
@@ -21,6 +36,7 @@ sp_api::decl_runtime_apis! {
         fn get_delegates() -> Vec<DelegateInfo<AccountId32>>;
         fn get_delegate( delegate_account: AccountId32 ) -> Option<DelegateInfo<AccountId32>>;
         fn get_delegated( delegatee_account: AccountId32 ) -> Vec<(DelegateInfo<AccountId32>, (Compact<NetUid>, Compact<AlphaCurrency>))>;
+        fn get_delegates_performance() -> Vec<DelegatePerformance<AccountId32>>;
     }
 
     pub trait NeuronInfoRuntimeApi {
@@ -28,6 +44,7 @@ sp_api::decl_runtime_apis! {
         fn get_neuron(netuid: NetUid, uid: u16) -> Option<NeuronInfo<AccountId32>>;
         fn get_neurons_lite(netuid: NetUid) -> Vec<NeuronInfoLite<AccountId32>>;
         fn get_neuron_lite(netuid: NetUid, uid: u16) -> Option<NeuronInfoLite<AccountId32>>;
+        fn get_neuron_lifetime_emissions(netuid: NetUid) -> Vec<Compact<AlphaCurrency>>;
     }
 
     pub trait SubnetInfoRuntimeApi {
@@ -48,6 +65,17 @@ sp_api::decl_runtime_apis! {
         fn get_coldkey_auto_stake_hotkey(coldkey: AccountId32, netuid: NetUid) -> Option<AccountId32>;
         fn get_selective_mechagraph(netuid: NetUid, subid: MechId, metagraph_indexes: Vec<u16>) -> Option<SelectiveMetagraph<AccountId32>>;
         fn get_subnet_to_prune() -> Option<NetUid>;
+        fn estimate_validator_yield(netuid: NetUid, hotkey: AccountId32, stake_amount: AlphaCurrency) -> Option<ValidatorYieldEstimate<AccountId32>>;
+        fn get_staged_hyperparameters(netuid: NetUid) -> Option<StagedHyperparameterBundle>;
+        fn get_reserved_accounts() -> Vec<(AccountId32, ReservedAccountKind)>;
+        fn get_subnet_features(netuid: NetUid) -> Option<u16>;
+        fn get_subnet_clock(netuid: NetUid) -> Option<SubnetClock>;
+        fn get_subnet_token_accounting(netuid: NetUid) -> Option<SubnetTokenAccounting>;
+        fn get_subnet_attestations(netuid: NetUid) -> Vec<SubnetAttestation>;
+        fn simulate_epoch(netuid: NetUid) -> Option<EpochSimulation>;
+        fn get_emission_telemetry() -> Vec<EmissionTelemetry>;
+        fn get_metagraph_page(netuid: NetUid, start_uid: u16, page_size: u16) -> Option<MetagraphPage<AccountId32>>;
+        fn get_weights(netuid: NetUid, mecid: MechId) -> Vec<(u16, Vec<(u16, u16)>)>;
     }
 
     pub trait StakeInfoRuntimeApi {
@@ -55,9 +83,25 @@ sp_api::decl_runtime_apis! {
         fn get_stake_info_for_coldkeys( coldkey_accounts: Vec<AccountId32> ) -> Vec<(AccountId32, Vec<StakeInfo<AccountId32>>)>;
         fn get_stake_info_for_hotkey_coldkey_netuid( hotkey_account: AccountId32, coldkey_account: AccountId32, netuid: NetUid ) -> Option<StakeInfo<AccountId32>>;
         fn get_stake_fee( origin: Option<(AccountId32, NetUid)>, origin_coldkey_account: AccountId32, destination: Option<(AccountId32, NetUid)>, destination_coldkey_account: AccountId32, amount: u64 ) -> u64;
+        fn get_scheduled_operations( coldkey_account: AccountId32 ) -> Vec<ScheduledOperation<AccountId32>>;
+        fn get_key_rotation_log( account: AccountId32 ) -> Vec<KeyRotationRecord<AccountId32>>;
+        fn get_stake_origins( netuid: NetUid ) -> Vec<StakeOriginInfo<AccountId32>>;
+    }
+
+    pub trait MetadataCompatRuntimeApi {
+        fn get_pallet_variants() -> Vec<PalletVariantsInfo>;
+    }
+
+    pub trait EpochFixtureRuntimeApi {
+        fn get_epoch_fixture(netuid: NetUid) -> Option<Vec<u8>>;
     }
 
     pub trait SubnetRegistrationRuntimeApi {
         fn get_network_registration_cost() -> TaoCurrency;
+        fn get_lock_cost_at(block: u64) -> TaoCurrency;
+    }
+
+    pub trait IssuanceRuntimeApi {
+        fn get_issuance_schedule() -> IssuanceSchedule;
     }
 }
@@ -3,8 +3,10 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use crate::Pallet as Subtensor;
+use crate::subnets::bootstrap::MAX_BOOTSTRAP_NEURONS;
+use crate::subnets::weight_row::WeightRow;
 use crate::*;
-use codec::Compact;
+use codec::{Compact, Encode};
 use frame_benchmarking::v2::*;
 use frame_support::{StorageDoubleMap, assert_ok};
 use frame_system::{RawOrigin, pallet_prelude::BlockNumberFor};
@@ -15,7 +17,7 @@ use sp_runtime::{
     traits::{BlakeTwo256, Hash},
 };
 use sp_std::vec;
-use subtensor_runtime_common::{AlphaCurrency, NetUid, TaoCurrency};
+use subtensor_runtime_common::{AlphaCurrency, MechId, NetUid, NetUidStorageIndex, TaoCurrency};
 
 #[frame_benchmarking::v2::benchmarks]
 mod pallet_benchmarks {
@@ -379,6 +381,59 @@ mod pallet_benchmarks {
         _(RawOrigin::Signed(old_coldkey.clone()), new_coldkey.clone());
     }
 
+    #[benchmark]
+    fn cancel_scheduled_coldkey_swap() {
+        let old_coldkey: T::AccountId = account("old_cold", 0, 1);
+        let new_coldkey: T::AccountId = account("new_cold", 1, 2);
+        let amount: u64 = 100_000_000_000_000;
+        Subtensor::<T>::add_balance_to_coldkey_account(&old_coldkey, amount);
+        assert_ok!(Subtensor::<T>::schedule_swap_coldkey(
+            RawOrigin::Signed(old_coldkey.clone()).into(),
+            new_coldkey
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(old_coldkey));
+    }
+
+    #[benchmark]
+    fn set_coldkey_guardians() {
+        let coldkey: T::AccountId = account("Cold", 0, 1);
+        let guardians: Vec<T::AccountId> = (0..10).map(|i| account("guardian", i, 1)).collect();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(coldkey), guardians, 10);
+    }
+
+    #[benchmark]
+    fn approve_coldkey_swap() {
+        let old_coldkey: T::AccountId = account("old_cold", 0, 1);
+        let new_coldkey: T::AccountId = account("new_cold", 1, 2);
+        let guardians: Vec<T::AccountId> = (0..10).map(|i| account("guardian", i, 1)).collect();
+        let amount: u64 = 100_000_000_000_000;
+        Subtensor::<T>::add_balance_to_coldkey_account(&old_coldkey, amount);
+        assert_ok!(Subtensor::<T>::set_coldkey_guardians(
+            RawOrigin::Signed(old_coldkey.clone()).into(),
+            guardians.clone(),
+            10
+        ));
+        assert_ok!(Subtensor::<T>::schedule_swap_coldkey(
+            RawOrigin::Signed(old_coldkey.clone()).into(),
+            new_coldkey.clone()
+        ));
+        for guardian in guardians.iter().skip(1) {
+            assert_ok!(Subtensor::<T>::approve_coldkey_swap(
+                RawOrigin::Signed(guardian.clone()).into(),
+                old_coldkey.clone(),
+                new_coldkey.clone()
+            ));
+        }
+        let guardian = guardians.first().cloned().expect("guardians are not empty");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(guardian), old_coldkey, new_coldkey);
+    }
+
     #[benchmark]
     fn sudo_set_tx_childkey_take_rate_limit() {
         let new_rate_limit: u64 = 100;
@@ -1577,4 +1632,209 @@ mod pallet_benchmarks {
         #[extrinsic_call]
         _(RawOrigin::Signed(coldkey.clone()), netuid, hotkey.clone());
     }
+
+    #[benchmark]
+    fn settle_weight_commits() {
+        let tempo: u16 = 0;
+        let netuid = NetUid::from(1);
+        let num_commits: usize = 10;
+
+        let hotkey: T::AccountId = account("hot", 0, 1);
+        let coldkey: T::AccountId = account("cold", 0, 2);
+
+        Subtensor::<T>::init_new_network(netuid, tempo);
+        Subtensor::<T>::set_network_registration_allowed(netuid, true);
+        Subtensor::<T>::set_network_pow_registration_allowed(netuid, true);
+        Subtensor::<T>::set_commit_reveal_weights_enabled(netuid, true);
+        Subtensor::<T>::set_weights_set_rate_limit(netuid, 0);
+
+        let block_number: u64 = Subtensor::<T>::get_current_block_as_u64();
+        let (nonce, work) =
+            Subtensor::<T>::create_work_for_block_number(netuid, block_number, 3, &hotkey);
+        assert_ok!(Subtensor::<T>::register(
+            RawOrigin::Signed(hotkey.clone()).into(),
+            netuid,
+            block_number,
+            nonce,
+            work,
+            hotkey.clone(),
+            coldkey.clone()
+        ));
+        Subtensor::<T>::set_validator_permit_for_uid(netuid, 0, true);
+
+        let mut reveals = Vec::new();
+        for i in 0..num_commits {
+            let uids = vec![0u16];
+            let values = vec![i as u16];
+            let salt = vec![i as u16];
+            let version_key: u64 = i as u64;
+
+            let commit_hash = Subtensor::<T>::get_commit_hash(
+                &hotkey,
+                NetUidStorageIndex::from(netuid),
+                &uids,
+                &values,
+                &salt,
+                version_key,
+            );
+            assert_ok!(Subtensor::<T>::commit_weights(
+                RawOrigin::Signed(hotkey.clone()).into(),
+                netuid,
+                commit_hash
+            ));
+            reveals.push((uids, values, salt, version_key));
+        }
+
+        // Move to the reveal epoch of the commits
+        let block: BlockNumberFor<T> = (block_number + 1)
+            .try_into()
+            .ok()
+            .expect("can't convert to block number");
+        frame_system::Pallet::<T>::set_block_number(block);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(hotkey.clone()),
+            netuid,
+            MechId::MAIN,
+            reveals,
+        );
+    }
+
+    /// The epoch reading the rows in the legacy `(uid, weight)` pairs encoding for `v = 0`, and
+    /// in the versioned `WeightRow` encoding for `v = 1`, so the slope over `v` is what the
+    /// compression saves.
+    #[benchmark]
+    fn epoch_sparse_weights(v: Linear<0, 1>) {
+        let netuid = NetUid::from(1);
+        let n: u16 = 4096;
+        let validators: u16 = 64;
+
+        Subtensor::<T>::init_new_network(netuid, 1);
+        Subtensor::<T>::set_max_allowed_uids(netuid, n);
+        let block_number: u64 = Subtensor::<T>::get_current_block_as_u64();
+        for uid in 0..n {
+            let hotkey: T::AccountId = account("hot", uid.into(), 1);
+            Subtensor::<T>::append_neuron(netuid, &hotkey, block_number);
+        }
+
+        // Every validator weights 5% of the uids
+        let netuid_index = NetUidStorageIndex::from(netuid);
+        let mut legacy_size: usize = 0;
+        let mut versioned_size: usize = 0;
+        for uid in 0..validators {
+            Subtensor::<T>::set_validator_permit_for_uid(netuid, uid, true);
+            let weights: Vec<(u16, u16)> = (uid..n)
+                .step_by(20)
+                .map(|target| (target, u16::MAX))
+                .collect();
+            let row = WeightRow::from(weights.clone());
+            legacy_size += weights.encoded_size();
+            versioned_size += row.encoded_size();
+            if v == 0 {
+                frame_support::storage::unhashed::put(
+                    &Weights::<T>::hashed_key_for(netuid_index, uid),
+                    &weights,
+                );
+            } else {
+                Weights::<T>::insert(netuid_index, uid, row);
+            }
+        }
+
+        #[block]
+        {
+            Subtensor::<T>::epoch_with_mechanisms(netuid, AlphaCurrency::from(1_000_000_000));
+        }
+
+        assert!(versioned_size < legacy_size);
+    }
+
+    #[benchmark]
+    fn bootstrap_register() {
+        let netuid = NetUid::from(1);
+        let owner: T::AccountId = account("Owner", 0, 1);
+
+        Subtensor::<T>::init_new_network(netuid, 1);
+        Subtensor::<T>::set_max_allowed_uids(netuid, 4096);
+        SubnetOwner::<T>::insert(netuid, owner.clone());
+
+        let neurons: Vec<(T::AccountId, T::AccountId)> = (0..MAX_BOOTSTRAP_NEURONS)
+            .map(|i| (account("hot", i.into(), 1), account("cold", i.into(), 1)))
+            .collect();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), netuid, neurons);
+    }
+
+    #[benchmark]
+    fn deregister_uid() {
+        let netuid = NetUid::from(1);
+        let coldkey: T::AccountId = account("Cold", 0, 1);
+        let n: u16 = 256;
+
+        Subtensor::<T>::init_new_network(netuid, 1);
+        Subtensor::<T>::set_max_allowed_uids(netuid, 4096);
+        let block_number: u64 = Subtensor::<T>::get_current_block_as_u64();
+        for uid in 0..n {
+            let hotkey: T::AccountId = account("hot", uid.into(), 1);
+            Subtensor::<T>::create_account_if_non_existent(&coldkey, &hotkey);
+            Subtensor::<T>::append_neuron(netuid, &hotkey, block_number);
+        }
+
+        // Every neuron weights the first one, whose uid the last neuron takes
+        let netuid_index = NetUidStorageIndex::from(netuid);
+        for uid in 0..n {
+            let weights: Vec<(u16, u16)> = vec![(0, u16::MAX), (n.saturating_sub(1), u16::MAX)];
+            Weights::<T>::insert(netuid_index, uid, WeightRow::from(weights));
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(coldkey), netuid, 0);
+    }
+
+    #[benchmark]
+    fn offer_hotkey_lease() {
+        let netuid = NetUid::from(1);
+        let coldkey: T::AccountId = account("Cold", 0, 1);
+        let hotkey: T::AccountId = account("Hot", 0, 1);
+        let renter: T::AccountId = account("Renter", 0, 1);
+
+        Subtensor::<T>::init_new_network(netuid, 1);
+        Subtensor::<T>::create_account_if_non_existent(&coldkey, &hotkey);
+        Subtensor::<T>::append_neuron(netuid, &hotkey, 0);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(coldkey),
+            hotkey,
+            netuid,
+            renter,
+            u16::MAX / 2,
+            7200,
+        );
+    }
+
+    #[benchmark]
+    fn accept_hotkey_lease() {
+        let netuid = NetUid::from(1);
+        let coldkey: T::AccountId = account("Cold", 0, 1);
+        let hotkey: T::AccountId = account("Hot", 0, 1);
+        let renter: T::AccountId = account("Renter", 0, 1);
+        let operator: T::AccountId = account("Operator", 0, 1);
+
+        Subtensor::<T>::init_new_network(netuid, 1);
+        Subtensor::<T>::create_account_if_non_existent(&coldkey, &hotkey);
+        Subtensor::<T>::append_neuron(netuid, &hotkey, 0);
+        assert_ok!(Subtensor::<T>::offer_hotkey_lease(
+            RawOrigin::Signed(coldkey).into(),
+            hotkey.clone(),
+            netuid,
+            renter.clone(),
+            u16::MAX / 2,
+            7200
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(renter), hotkey, netuid, operator);
+    }
 }
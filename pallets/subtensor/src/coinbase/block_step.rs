@@ -5,11 +5,15 @@ use subtensor_runtime_common::{NetUid, TaoCurrency};
 
 impl<T: Config + pallet_drand::Config> Pallet<T> {
     /// Executes the necessary operations for each block.
-    pub fn block_step() -> Result<(), &'static str> {
+    ///
+    /// Returns the weight of the steps whose cost varies with queued work.
+    pub fn block_step() -> Result<Weight, &'static str> {
         let block_number: u64 = Self::get_current_block_as_u64();
         log::debug!("block_step for block: {block_number:?} ");
         // --- 1. Adjust difficulties.
         Self::adjust_registration_terms_for_networks();
+        // --- 1b. Convert tempos set in seconds again if the slot duration changed.
+        Self::sync_subnet_clocks();
         // --- 2. Get the current coinbase emission.
         let block_emission: U96F32 = U96F32::saturating_from_num(
             Self::get_block_emission()
@@ -21,8 +25,14 @@ impl<T: Config + pallet_drand::Config> Pallet<T> {
         Self::run_coinbase(block_emission);
         // --- 4. Set pending children on the epoch; but only after the coinbase has been run.
         Self::try_set_pending_children(block_number);
+        // --- 5. Execute the next slice of queued sliced unstakes.
+        let sliced_unstakes_weight = Self::execute_sliced_unstakes();
+        // --- 6. Recalculate the senate from root stake at the end of the root tempo.
+        Self::try_update_senate(block_number);
+        // --- 7. Check that the reserves of the subnets on their epoch cover their alpha.
+        Self::try_check_subnet_tao_solvency(block_number);
         // Return ok.
-        Ok(())
+        Ok(sliced_unstakes_weight)
     }
 
     fn try_set_pending_children(block_number: u64) {
@@ -34,6 +44,14 @@ impl<T: Config + pallet_drand::Config> Pallet<T> {
         }
     }
 
+    fn try_check_subnet_tao_solvency(block_number: u64) {
+        for netuid in Self::get_all_subnet_netuids() {
+            if Self::should_run_epoch(netuid, block_number) {
+                Self::check_subnet_tao_solvency(netuid);
+            }
+        }
+    }
+
     /// Adjusts the network difficulties/burns of every active network. Resetting state parameters.
     ///
     pub fn adjust_registration_terms_for_networks() {
@@ -54,6 +72,9 @@ impl<T: Config + pallet_drand::Config> Pallet<T> {
             if current_block.saturating_sub(last_adjustment_block) >= adjustment_interval as u64 {
                 log::debug!("interval reached.");
 
+                // Auctioned registrations are made before the counters are read.
+                Self::settle_registration_auction(netuid);
+
                 // --- 4. Get the current counters for this network w.r.t burn and difficulty values.
                 let current_burn = Self::get_burn(netuid);
                 let current_difficulty: u64 = Self::get_difficulty_as_u64(netuid);
@@ -66,6 +87,13 @@ impl<T: Config + pallet_drand::Config> Pallet<T> {
                 let target_registrations_this_interval: u16 =
                     Self::get_target_registrations_per_interval(netuid);
                 // --- 5. Adjust burn + pow
+                // With a difficulty controller on the subnet, it sets the pow difficulty on every interval.
+                let difficulty_controlled = Self::run_difficulty_controller(
+                    netuid,
+                    current_difficulty,
+                    registrations_this_interval,
+                    target_registrations_this_interval,
+                );
                 // There are six cases to consider. A, B, C, D, E, F
                 if registrations_this_interval > target_registrations_this_interval {
                     #[allow(clippy::comparison_chain)]
@@ -73,15 +101,17 @@ impl<T: Config + pallet_drand::Config> Pallet<T> {
                         // A. There are too many registrations this interval and most of them are pow registrations
                         // this triggers an increase in the pow difficulty.
                         // pow_difficulty ++
-                        Self::set_difficulty(
-                            netuid,
-                            Self::upgraded_difficulty(
+                        if !difficulty_controlled {
+                            Self::set_difficulty(
                                 netuid,
-                                current_difficulty,
-                                registrations_this_interval,
-                                target_registrations_this_interval,
-                            ),
-                        );
+                                Self::upgraded_difficulty(
+                                    netuid,
+                                    current_difficulty,
+                                    registrations_this_interval,
+                                    target_registrations_this_interval,
+                                ),
+                            );
+                        }
                     } else if pow_registrations_this_interval < burn_registrations_this_interval {
                         // B. There are too many registrations this interval and most of them are burn registrations
                         // this triggers an increase in the burn cost.
@@ -109,15 +139,17 @@ impl<T: Config + pallet_drand::Config> Pallet<T> {
                             ),
                         );
                         // pow_difficulty ++
-                        Self::set_difficulty(
-                            netuid,
-                            Self::upgraded_difficulty(
+                        if !difficulty_controlled {
+                            Self::set_difficulty(
                                 netuid,
-                                current_difficulty,
-                                registrations_this_interval,
-                                target_registrations_this_interval,
-                            ),
-                        );
+                                Self::upgraded_difficulty(
+                                    netuid,
+                                    current_difficulty,
+                                    registrations_this_interval,
+                                    target_registrations_this_interval,
+                                ),
+                            );
+                        }
                     }
                 } else {
                     // Not enough registrations this interval.
@@ -139,15 +171,17 @@ impl<T: Config + pallet_drand::Config> Pallet<T> {
                         // D. There are not enough registrations this interval and most of them are burn registrations
                         // this triggers a decrease in the pow difficulty
                         // pow_difficulty --
-                        Self::set_difficulty(
-                            netuid,
-                            Self::upgraded_difficulty(
+                        if !difficulty_controlled {
+                            Self::set_difficulty(
                                 netuid,
-                                current_difficulty,
-                                registrations_this_interval,
-                                target_registrations_this_interval,
-                            ),
-                        );
+                                Self::upgraded_difficulty(
+                                    netuid,
+                                    current_difficulty,
+                                    registrations_this_interval,
+                                    target_registrations_this_interval,
+                                ),
+                            );
+                        }
                     } else {
                         // E. There are not enough registrations this interval and the pow and burn registrations are equal
                         // this triggers a decrease in the burn cost and pow difficulty
@@ -162,15 +196,17 @@ impl<T: Config + pallet_drand::Config> Pallet<T> {
                             ),
                         );
                         // pow_difficulty --
-                        Self::set_difficulty(
-                            netuid,
-                            Self::upgraded_difficulty(
+                        if !difficulty_controlled {
+                            Self::set_difficulty(
                                 netuid,
-                                current_difficulty,
-                                registrations_this_interval,
-                                target_registrations_this_interval,
-                            ),
-                        );
+                                Self::upgraded_difficulty(
+                                    netuid,
+                                    current_difficulty,
+                                    registrations_this_interval,
+                                    target_registrations_this_interval,
+                                ),
+                            );
+                        }
                     }
                 }
 
@@ -179,6 +215,7 @@ impl<T: Config + pallet_drand::Config> Pallet<T> {
                 Self::set_registrations_this_interval(netuid, 0);
                 Self::set_pow_registrations_this_interval(netuid, 0);
                 Self::set_burn_registrations_this_interval(netuid, 0);
+                Self::reset_coldkey_registrations(netuid);
             } else {
                 log::debug!("interval not reached.");
             }
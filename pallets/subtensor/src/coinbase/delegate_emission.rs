@@ -0,0 +1,35 @@
+//! Trailing emission of delegates.
+//!
+//! The dividends of a hotkey are only kept for its last epoch, so ranking delegates by what they
+//! earned over a day needs an indexer. The alpha dividends of every hotkey on a subnet are summed
+//! over a trailing window of blocks instead.
+
+use super::*;
+use crate::utils::trailing_window::{roll_trailing_windows, trailing_window_sum};
+use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid};
+
+impl<T: Config> Pallet<T> {
+    /// Adds `alpha` dividends of `hotkey` on `netuid` to its trailing emission.
+    pub fn record_delegate_emission(netuid: NetUid, hotkey: &T::AccountId, alpha: AlphaCurrency) {
+        let now = Self::get_current_block_as_u64();
+        DelegateEmissionWindows::<T>::mutate(netuid, hotkey, |windows| {
+            let (start, current, previous) = roll_trailing_windows(*windows, now);
+            *windows = (start, current.saturating_add(alpha), previous);
+        });
+    }
+
+    /// The alpha dividends of `hotkey` on `netuid` over the last `TRAILING_WINDOW_BLOCKS`
+    /// blocks.
+    pub fn get_trailing_delegate_emission(netuid: NetUid, hotkey: &T::AccountId) -> AlphaCurrency {
+        let now = Self::get_current_block_as_u64();
+        let (start, current, previous) =
+            roll_trailing_windows(DelegateEmissionWindows::<T>::get(netuid, hotkey), now);
+        let emission = trailing_window_sum(
+            start,
+            u128::from(current.to_u64()),
+            u128::from(previous.to_u64()),
+            now,
+        );
+        u64::try_from(emission).unwrap_or(u64::MAX).into()
+    }
+}
@@ -0,0 +1,131 @@
+//! PID controller for the PoW registration difficulty.
+//!
+//! The step-wise adjustment scales the difficulty by the ratio of the registrations of an interval
+//! to their target, smoothed by the adjustment alpha, and only in the intervals most registrations
+//! were PoW. When demand spikes it overshoots and swings back and forth. Root can set controller
+//! gains on a subnet, whose difficulty is then set by a PID controller every adjustment interval
+//! instead.
+//!
+//! The error of an interval is the registrations above `TargetRegistrationsPerInterval`, relative
+//! to the target. The difficulty is scaled by `1 + kp * error + ki * integral + kd * derivative`,
+//! where the integral sums the errors and the derivative is the change of the error since the last
+//! interval. The integral is bounded against windup, and a single adjustment at most doubles or
+//! halves the difficulty, within the minimum and maximum difficulty of the subnet. Gains are in
+//! millionths, and setting them all to zero goes back to the step-wise adjustment.
+
+use super::*;
+use safe_math::*;
+use substrate_fixed::types::{I32F32, U110F18};
+use subtensor_runtime_common::NetUid;
+
+/// The gain of one, gains are in millionths.
+pub const DIFFICULTY_CONTROLLER_GAIN_UNIT: u32 = 1_000_000;
+
+/// Bound of the integral of the errors, in intervals of registrations off by their target.
+const MAX_INTEGRAL: i32 = 16;
+
+/// The gains of the difficulty controller of a subnet, in millionths.
+#[freeze_struct("6ac267dbb3fe5725")]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, Default, TypeInfo)]
+pub struct DifficultyControllerGains {
+    /// Gain of the error of the interval.
+    pub kp: u32,
+    /// Gain of the integral of the errors.
+    pub ki: u32,
+    /// Gain of the change of the error since the last interval.
+    pub kd: u32,
+}
+
+impl DifficultyControllerGains {
+    pub fn is_zero(&self) -> bool {
+        self.kp == 0 && self.ki == 0 && self.kd == 0
+    }
+}
+
+/// The state the difficulty controller of a subnet keeps between intervals.
+#[freeze_struct("8e20e8b6245ffc50")]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, Default, TypeInfo)]
+pub struct DifficultyControllerState {
+    /// The sum of the errors of the past intervals.
+    pub integral: I32F32,
+    /// The error of the last interval.
+    pub last_error: I32F32,
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn get_difficulty_controller_gains(netuid: NetUid) -> Option<DifficultyControllerGains> {
+        DifficultyGains::<T>::get(netuid)
+    }
+
+    /// Sets the gains of the difficulty controller of `netuid` and restarts it, all zero gains
+    /// go back to the step-wise adjustment.
+    pub fn set_difficulty_controller_gains(netuid: NetUid, gains: DifficultyControllerGains) {
+        if gains.is_zero() {
+            DifficultyGains::<T>::remove(netuid);
+        } else {
+            DifficultyGains::<T>::insert(netuid, gains);
+        }
+        DifficultyControllerStates::<T>::remove(netuid);
+        Self::deposit_event(Event::DifficultyControllerGainsSet {
+            netuid,
+            kp: gains.kp,
+            ki: gains.ki,
+            kd: gains.kd,
+        });
+    }
+
+    /// Runs the difficulty controller of `netuid` for an interval with `registrations` of
+    /// `target` and sets the difficulty. Returns `false` without a controller on the subnet.
+    pub fn run_difficulty_controller(
+        netuid: NetUid,
+        current_difficulty: u64,
+        registrations: u16,
+        target: u16,
+    ) -> bool {
+        let Some(gains) = Self::get_difficulty_controller_gains(netuid) else {
+            return false;
+        };
+        let state = DifficultyControllerStates::<T>::get(netuid);
+
+        let target = I32F32::saturating_from_num(target.max(1));
+        let error = I32F32::saturating_from_num(registrations)
+            .saturating_sub(target)
+            .safe_div(target);
+        let max_integral = I32F32::saturating_from_num(MAX_INTEGRAL);
+        let integral = state
+            .integral
+            .saturating_add(error)
+            .clamp(max_integral.saturating_neg(), max_integral);
+        let derivative = error.saturating_sub(state.last_error);
+
+        let gain = |gain: u32| {
+            I32F32::saturating_from_num(gain)
+                .safe_div(I32F32::saturating_from_num(DIFFICULTY_CONTROLLER_GAIN_UNIT))
+        };
+        let scale = I32F32::saturating_from_num(1)
+            .saturating_add(gain(gains.kp).saturating_mul(error))
+            .saturating_add(gain(gains.ki).saturating_mul(integral))
+            .saturating_add(gain(gains.kd).saturating_mul(derivative))
+            .clamp(
+                I32F32::saturating_from_num(0.5),
+                I32F32::saturating_from_num(2),
+            );
+
+        let next_value: U110F18 = U110F18::saturating_from_num(current_difficulty)
+            .saturating_mul(U110F18::saturating_from_num(scale));
+        let next_difficulty = next_value.saturating_to_num::<u64>().clamp(
+            Self::get_min_difficulty(netuid),
+            Self::get_max_difficulty(netuid).max(Self::get_min_difficulty(netuid)),
+        );
+
+        DifficultyControllerStates::<T>::insert(
+            netuid,
+            DifficultyControllerState {
+                integral,
+                last_error: error,
+            },
+        );
+        Self::set_difficulty(netuid, next_difficulty);
+        true
+    }
+}
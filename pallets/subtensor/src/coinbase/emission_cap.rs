@@ -0,0 +1,83 @@
+//! Cap on the emission share of a subnet.
+//!
+//! The block emission is injected into subnets in proportion to the moving price of their alpha,
+//! so manipulating the price of a single pool draws emission away from every other subnet. Root
+//! can cap the share of the block emission any single subnet receives: the emission of a subnet
+//! above the cap is redistributed to the other subnets in proportion to their moving prices, and
+//! again from those it brings over the cap. If all subnets reach the cap, the rest of the block
+//! emission is not injected.
+
+use super::*;
+use alloc::collections::BTreeMap;
+use safe_math::*;
+use sp_runtime::Percent;
+use substrate_fixed::types::U96F32;
+use subtensor_runtime_common::NetUid;
+
+impl<T: Config> Pallet<T> {
+    pub fn get_max_subnet_emission_share() -> Percent {
+        MaxSubnetEmissionShare::<T>::get()
+    }
+
+    /// Caps the share of the block emission injected into a single subnet to `share`.
+    pub fn set_max_subnet_emission_share(share: Percent) -> Result<(), Error<T>> {
+        ensure!(
+            share.deconstruct() > 0,
+            Error::<T>::InvalidMaxSubnetEmissionShare
+        );
+        MaxSubnetEmissionShare::<T>::put(share);
+        Self::deposit_event(Event::MaxSubnetEmissionShareSet { share });
+        Ok(())
+    }
+
+    /// Splits `block_emission` between subnets in proportion to their `moving_prices`, with no
+    /// subnet receiving more than the maximum subnet emission share.
+    pub fn get_subnet_tao_emissions(
+        block_emission: U96F32,
+        moving_prices: &BTreeMap<NetUid, U96F32>,
+    ) -> BTreeMap<NetUid, U96F32> {
+        let max_emission = block_emission
+            .saturating_mul(U96F32::saturating_from_num(
+                Self::get_max_subnet_emission_share().deconstruct(),
+            ))
+            .safe_div(U96F32::saturating_from_num(100));
+
+        // Cap the subnets over the maximum until the emission left over is within it for all.
+        let mut emissions: BTreeMap<NetUid, U96F32> = BTreeMap::new();
+        loop {
+            let capped = emissions.len();
+            let uncapped: Vec<(NetUid, U96F32)> = moving_prices
+                .iter()
+                .filter(|(netuid, _)| !emissions.contains_key(*netuid))
+                .map(|(netuid, price)| (*netuid, *price))
+                .collect();
+            let uncapped_prices = uncapped
+                .iter()
+                .fold(U96F32::saturating_from_num(0), |acc, (_, price)| {
+                    acc.saturating_add(*price)
+                });
+            let uncapped_emission = block_emission
+                .saturating_sub(max_emission.saturating_mul(U96F32::saturating_from_num(capped)));
+
+            let shares: Vec<(NetUid, U96F32)> = uncapped
+                .into_iter()
+                .map(|(netuid, price)| {
+                    let emission = uncapped_emission
+                        .saturating_mul(price)
+                        .checked_div(uncapped_prices)
+                        .unwrap_or(U96F32::saturating_from_num(0));
+                    (netuid, emission)
+                })
+                .collect();
+            for (netuid, emission) in shares.iter() {
+                if *emission > max_emission {
+                    emissions.insert(*netuid, max_emission);
+                }
+            }
+            if emissions.len() == capped {
+                emissions.extend(shares);
+                return emissions;
+            }
+        }
+    }
+}
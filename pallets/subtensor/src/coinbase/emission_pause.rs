@@ -0,0 +1,46 @@
+//! Emergency pause of the emission into a subnet.
+//!
+//! When the incentive mechanism of a subnet is found to be exploitable, root can pause the
+//! emission into it until it is fixed. A paused subnet is left out of the per-block distribution
+//! like a subnet that has not started emitting: it gets no TAO or alpha injection and no root
+//! dividends, and its moving price is not updated. Its epochs still run and drain the emission
+//! pending from before the pause, and staking and weights are unaffected.
+
+use super::*;
+use subtensor_runtime_common::NetUid;
+
+impl<T: Config> Pallet<T> {
+    pub fn is_subnet_emission_paused(netuid: NetUid) -> bool {
+        SubnetEmissionPaused::<T>::get(netuid)
+    }
+
+    /// Pauses the emission into `netuid`.
+    pub fn do_pause_subnet_emission(netuid: NetUid) -> DispatchResult {
+        ensure!(
+            !netuid.is_root() && Self::if_subnet_exist(netuid),
+            Error::<T>::SubnetNotExists
+        );
+        ensure!(
+            !Self::is_subnet_emission_paused(netuid),
+            Error::<T>::SubnetEmissionAlreadyPaused
+        );
+
+        SubnetEmissionPaused::<T>::insert(netuid, true);
+        log::debug!("SubnetEmissionPaused( netuid:{netuid:?} ) ");
+        Self::deposit_event(Event::SubnetEmissionPaused { netuid });
+        Ok(())
+    }
+
+    /// Resumes the emission into `netuid`.
+    pub fn do_resume_subnet_emission(netuid: NetUid) -> DispatchResult {
+        ensure!(
+            Self::is_subnet_emission_paused(netuid),
+            Error::<T>::SubnetEmissionNotPaused
+        );
+
+        SubnetEmissionPaused::<T>::remove(netuid);
+        log::debug!("SubnetEmissionResumed( netuid:{netuid:?} ) ");
+        Self::deposit_event(Event::SubnetEmissionResumed { netuid });
+        Ok(())
+    }
+}
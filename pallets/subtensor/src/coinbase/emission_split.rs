@@ -0,0 +1,71 @@
+//! Miner and validator emission split.
+//!
+//! The epoch emits the alpha of a subnet half as incentive to miners and half as dividends to
+//! validators. The owner of a subnet can move that split with the share of the emission paid to
+//! miners, within bounds set by root, the rest going to validators. The root alpha sold for root
+//! dividends and the alpha distributed as dividends follow the validator share, and the incentives
+//! computed by the epoch are scaled from the even split to the miner share.
+
+use super::*;
+use safe_math::*;
+use sp_runtime::Percent;
+use substrate_fixed::types::U96F32;
+use subtensor_runtime_common::{AlphaCurrency, NetUid};
+
+impl<T: Config> Pallet<T> {
+    pub fn get_miner_emission_share(netuid: NetUid) -> Percent {
+        MinerEmissionShare::<T>::get(netuid)
+    }
+
+    /// The share of the emission of `netuid` paid to validators.
+    pub fn get_validator_emission_share(netuid: NetUid) -> Percent {
+        Percent::from_percent(
+            100u8.saturating_sub(Self::get_miner_emission_share(netuid).deconstruct()),
+        )
+    }
+
+    /// Pays `share` of the emission of `netuid` to miners and the rest to validators.
+    pub fn set_miner_emission_share(netuid: NetUid, share: Percent) -> Result<(), Error<T>> {
+        let (min, max) = MinerEmissionShareBounds::<T>::get();
+        ensure!(
+            min <= share && share <= max,
+            Error::<T>::MinerEmissionShareOutOfBounds
+        );
+        MinerEmissionShare::<T>::insert(netuid, share);
+        Self::deposit_event(Event::MinerEmissionShareSet { netuid, share });
+        Ok(())
+    }
+
+    /// Sets the bounds of the miner emission share of subnets. Shares already set are unchanged.
+    pub fn set_miner_emission_share_bounds(min: Percent, max: Percent) -> DispatchResult {
+        ensure!(min <= max, Error::<T>::InvalidMinerEmissionShareBounds);
+        MinerEmissionShareBounds::<T>::put((min, max));
+        Self::deposit_event(Event::MinerEmissionShareBoundsSet { min, max });
+        Ok(())
+    }
+
+    /// The validator share of the emission of `netuid` as a fraction.
+    pub fn get_validator_emission_fraction(netuid: NetUid) -> U96F32 {
+        U96F32::saturating_from_num(Self::get_validator_emission_share(netuid).deconstruct())
+            .safe_div(U96F32::saturating_from_num(100))
+    }
+
+    /// Scales the incentives of `hotkey_emission`, computed by the epoch for an even split, to
+    /// the miner share of `netuid`.
+    pub fn scale_miner_incentives(
+        netuid: NetUid,
+        hotkey_emission: Vec<(T::AccountId, AlphaCurrency, AlphaCurrency)>,
+    ) -> Vec<(T::AccountId, AlphaCurrency, AlphaCurrency)> {
+        let share = u128::from(Self::get_miner_emission_share(netuid).deconstruct());
+        hotkey_emission
+            .into_iter()
+            .map(|(hotkey, incentive, dividend)| {
+                let scaled = u128::from(u64::from(incentive))
+                    .saturating_mul(share)
+                    .safe_div(50);
+                let scaled = u64::try_from(scaled).unwrap_or(u64::MAX);
+                (hotkey, scaled.into(), dividend)
+            })
+            .collect()
+    }
+}
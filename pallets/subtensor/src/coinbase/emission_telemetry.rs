@@ -0,0 +1,68 @@
+//! Emission telemetry.
+//!
+//! Tracking the emission of the network means replaying the per-subnet emission maps block by
+//! block, which needs an archive node. Every block, the coinbase also records a compact summary of
+//! what it injected: the TAO injected in total and the alpha in and alpha out of every subnet
+//! emitted to. Summaries are kept in a ring buffer of the last `EMISSION_TELEMETRY_LEN` blocks and
+//! exposed with `get_emission_telemetry`.
+
+use super::*;
+use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid, TaoCurrency};
+
+/// The number of blocks whose emission summary is kept.
+pub const EMISSION_TELEMETRY_LEN: u64 = 100;
+
+#[freeze_struct("699b67a9c277dd89")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct SubnetEmissionSummary {
+    /// The subnet.
+    pub netuid: NetUid,
+    /// The TAO injected into the pool of the subnet.
+    pub tao_in: TaoCurrency,
+    /// The alpha injected into the pool of the subnet.
+    pub alpha_in: AlphaCurrency,
+    /// The alpha emitted to the participants of the subnet.
+    pub alpha_out: AlphaCurrency,
+}
+
+#[freeze_struct("2a6870dcbcd0c01e")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct EmissionTelemetry {
+    /// The block of the emission.
+    pub block: u64,
+    /// The TAO injected into all subnets.
+    pub tao_injected: TaoCurrency,
+    /// The emission of every subnet emitted to.
+    pub subnets: Vec<SubnetEmissionSummary>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Records the emission `subnets` of the current block, in place of the block
+    /// `EMISSION_TELEMETRY_LEN` blocks before.
+    pub fn record_emission_telemetry(subnets: Vec<SubnetEmissionSummary>) {
+        let block = Self::get_current_block_as_u64();
+        let tao_injected = subnets.iter().fold(TaoCurrency::ZERO, |acc, summary| {
+            acc.saturating_add(summary.tao_in)
+        });
+        EmissionTelemetryLog::<T>::insert(
+            block
+                .checked_rem(EMISSION_TELEMETRY_LEN)
+                .unwrap_or_default(),
+            EmissionTelemetry {
+                block,
+                tao_injected,
+                subnets,
+            },
+        );
+    }
+
+    /// The emission summaries of the last `EMISSION_TELEMETRY_LEN` blocks, oldest first.
+    pub fn get_emission_telemetry() -> Vec<EmissionTelemetry> {
+        let oldest_block = Self::get_current_block_as_u64().saturating_sub(EMISSION_TELEMETRY_LEN);
+        let mut telemetry: Vec<EmissionTelemetry> = EmissionTelemetryLog::<T>::iter_values()
+            .filter(|summary| summary.block > oldest_block)
+            .collect();
+        telemetry.sort_by_key(|summary| summary.block);
+        telemetry
+    }
+}
@@ -1,6 +1,16 @@
 use super::*;
 pub mod block_emission;
 pub mod block_step;
+pub mod delegate_emission;
+pub mod difficulty_controller;
+pub mod emission_cap;
+pub mod emission_pause;
+pub mod emission_split;
+pub mod emission_telemetry;
+pub mod network_treasury;
+pub mod neuron_emission;
+pub mod owner_cut_vesting;
 pub mod reveal_commits;
 pub mod root;
 pub mod run_coinbase;
+pub mod senate;
@@ -0,0 +1,68 @@
+//! The network treasury.
+//!
+//! Transaction fees and slashed registration deposits are burned, and the protocol share of swap
+//! fees is paid to the subnet owner, so nothing funds work done for the network as a whole. Root
+//! can have the network treasury keep a share of the transaction fees and slashes instead of
+//! burning them, and have the TAO protocol fees of swaps paid into it. The treasury is a TAO
+//! balance held on chain and counted in the total issuance, only spent by root with
+//! `spend_network_treasury`, which the council reaches through `sudo`.
+
+use super::*;
+use safe_math::*;
+use subtensor_runtime_common::{Currency, TaoCurrency};
+
+impl<T: Config> Pallet<T> {
+    pub fn get_network_treasury() -> TaoCurrency {
+        NetworkTreasury::<T>::get()
+    }
+
+    pub fn get_network_treasury_share() -> u16 {
+        NetworkTreasuryShare::<T>::get()
+    }
+
+    /// Has the network treasury keep `share` of transaction fees and slashes, in `u16::MAX` parts.
+    pub fn set_network_treasury_share(share: u16) {
+        NetworkTreasuryShare::<T>::put(share);
+        Self::deposit_event(Event::NetworkTreasuryShareSet { share });
+    }
+
+    /// Adds `amount`, already counted in the total issuance, to the network treasury.
+    pub fn fund_network_treasury(amount: TaoCurrency) {
+        if amount.is_zero() {
+            return;
+        }
+        NetworkTreasury::<T>::mutate(|treasury| *treasury = treasury.saturating_add(amount));
+    }
+
+    /// Moves the treasury share of `amount`, about to be burned, into the network treasury, and
+    /// returns the rest to burn.
+    pub fn keep_network_treasury_share(amount: TaoCurrency) -> TaoCurrency {
+        let kept = u128::from(amount.to_u64())
+            .saturating_mul(u128::from(Self::get_network_treasury_share()))
+            .safe_div(u128::from(u16::MAX));
+        let kept = TaoCurrency::from(u64::try_from(kept).unwrap_or(u64::MAX));
+        Self::fund_network_treasury(kept);
+        amount.saturating_sub(kept)
+    }
+
+    /// Pays `amount` of the network treasury to the coldkey `beneficiary`.
+    pub fn do_spend_network_treasury(
+        beneficiary: T::AccountId,
+        amount: TaoCurrency,
+    ) -> DispatchResult {
+        NetworkTreasury::<T>::try_mutate(|treasury| -> DispatchResult {
+            ensure!(
+                !amount.is_zero() && amount <= *treasury,
+                Error::<T>::InsufficientNetworkTreasury
+            );
+            *treasury = treasury.saturating_sub(amount);
+            Ok(())
+        })?;
+        Self::add_balance_to_coldkey_account(&beneficiary, amount.into());
+        Self::deposit_event(Event::NetworkTreasurySpent {
+            beneficiary,
+            amount,
+        });
+        Ok(())
+    }
+}
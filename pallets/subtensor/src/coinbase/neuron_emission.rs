@@ -0,0 +1,33 @@
+//! Lifetime emission of neurons.
+//!
+//! The emission of a neuron is only kept for its last epoch, so what a miner earned since it
+//! registered can only be found by replaying the events of every epoch. The incentive and alpha
+//! dividends paid to the hotkey of a neuron are summed under its uid instead, from its
+//! registration until the uid is pruned or replaced.
+
+use super::*;
+use codec::Compact;
+use subtensor_runtime_common::{AlphaCurrency, NetUid};
+
+impl<T: Config> Pallet<T> {
+    /// Adds `alpha` paid to `hotkey` on `netuid` to the lifetime emission of its neuron.
+    pub fn record_neuron_emission(netuid: NetUid, hotkey: &T::AccountId, alpha: AlphaCurrency) {
+        if let Ok(uid) = Uids::<T>::try_get(netuid, hotkey) {
+            NeuronLifetimeEmission::<T>::mutate(netuid, uid, |total| {
+                *total = total.saturating_add(alpha)
+            });
+        }
+    }
+
+    /// The alpha earned by the neuron `uid` on `netuid` since its registration.
+    pub fn get_neuron_lifetime_emission(netuid: NetUid, uid: u16) -> AlphaCurrency {
+        NeuronLifetimeEmission::<T>::get(netuid, uid)
+    }
+
+    /// The alpha earned by every neuron on `netuid` since its registration, by uid.
+    pub fn get_neuron_lifetime_emissions(netuid: NetUid) -> Vec<Compact<AlphaCurrency>> {
+        (0..Self::get_subnetwork_n(netuid))
+            .map(|uid| Self::get_neuron_lifetime_emission(netuid, uid).into())
+            .collect()
+    }
+}
@@ -0,0 +1,159 @@
+//! Vesting of the subnet owner cut.
+//!
+//! The owner cut of a subnet is staked to the owner hotkey as soon as it is emitted, so an owner
+//! can sell it right away. A subnet can have its owner cut vest instead: it accrues into a vesting
+//! ledger of the owner coldkey and is staked to the owner hotkey linearly over the vesting blocks
+//! of the subnet, as the epochs of the subnet run. The alpha still locked when more owner cut
+//! accrues vests along with it, until the end of the new vesting period.
+//!
+//! Root sets the minimum vesting blocks of all subnets, and an owner can only make the vesting of
+//! its subnet stricter. Ledgers stay with the coldkey they accrued for when the subnet changes
+//! hands, and are released in full when the subnet is dissolved.
+
+use super::*;
+use safe_math::*;
+use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid};
+
+/// The maximum number of blocks the owner cut vests over.
+pub const MAX_OWNER_CUT_VESTING_BLOCKS: u64 = 7200 * 365;
+
+#[freeze_struct("a9c639962123d3f")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct OwnerCutVesting<AccountId> {
+    /// The hotkey the owner cut is staked to as it vests.
+    pub hotkey: AccountId,
+    /// The alpha not vested yet.
+    pub locked: AlphaCurrency,
+    /// The block the locked alpha vests from.
+    pub start_block: u64,
+    /// The block the locked alpha is fully vested at.
+    pub end_block: u64,
+}
+
+impl<T: Config> Pallet<T> {
+    /// The number of blocks the owner cut of `netuid` vests over, zero if it does not vest.
+    pub fn get_owner_cut_vesting_blocks(netuid: NetUid) -> u64 {
+        OwnerCutVestingBlocks::<T>::get(netuid).max(MinOwnerCutVestingBlocks::<T>::get())
+    }
+
+    /// Sets the minimum number of blocks the owner cut of subnets vests over.
+    pub fn set_min_owner_cut_vesting_blocks(blocks: u64) -> Result<(), Error<T>> {
+        ensure!(
+            blocks <= MAX_OWNER_CUT_VESTING_BLOCKS,
+            Error::<T>::OwnerCutVestingTooLong
+        );
+        MinOwnerCutVestingBlocks::<T>::put(blocks);
+        Self::deposit_event(Event::MinOwnerCutVestingBlocksSet { blocks });
+        Ok(())
+    }
+
+    /// Has the owner cut of `netuid` vest over `blocks`, which cannot be shorter than its current
+    /// vesting. Owner cut already accrued keeps its vesting.
+    pub fn set_owner_cut_vesting_blocks(netuid: NetUid, blocks: u64) -> Result<(), Error<T>> {
+        ensure!(
+            blocks >= Self::get_owner_cut_vesting_blocks(netuid),
+            Error::<T>::OwnerCutVestingTooShort
+        );
+        ensure!(
+            blocks <= MAX_OWNER_CUT_VESTING_BLOCKS,
+            Error::<T>::OwnerCutVestingTooLong
+        );
+        OwnerCutVestingBlocks::<T>::insert(netuid, blocks);
+        Self::deposit_event(Event::OwnerCutVestingBlocksSet { netuid, blocks });
+        Ok(())
+    }
+
+    /// Pays `owner_cut` to the owner of `netuid`, or locks it in the vesting ledger of the owner
+    /// if the owner cut of the subnet vests.
+    pub fn pay_or_vest_owner_cut(
+        netuid: NetUid,
+        hotkey: &T::AccountId,
+        coldkey: &T::AccountId,
+        owner_cut: AlphaCurrency,
+    ) {
+        let vesting_blocks = Self::get_owner_cut_vesting_blocks(netuid);
+        if vesting_blocks == 0 {
+            Self::pay_owner_cut(netuid, hotkey, coldkey, owner_cut);
+            return;
+        }
+        if owner_cut.is_zero() {
+            return;
+        }
+
+        let current_block = Self::get_current_block_as_u64();
+        OwnerCutVestings::<T>::mutate(netuid, coldkey, |maybe_vesting| {
+            let vesting = maybe_vesting.get_or_insert_with(|| OwnerCutVesting {
+                hotkey: hotkey.clone(),
+                locked: AlphaCurrency::ZERO,
+                start_block: current_block,
+                end_block: current_block,
+            });
+            vesting.hotkey = hotkey.clone();
+            vesting.locked = vesting.locked.saturating_add(owner_cut);
+            vesting.start_block = current_block;
+            vesting.end_block = vesting
+                .end_block
+                .max(current_block.saturating_add(vesting_blocks));
+        });
+    }
+
+    /// Pays the owner cut vested since the last release of every vesting ledger of `netuid`.
+    pub fn release_vested_owner_cuts(netuid: NetUid) {
+        let current_block = Self::get_current_block_as_u64();
+        let vestings: Vec<(T::AccountId, OwnerCutVesting<T::AccountId>)> =
+            OwnerCutVestings::<T>::iter_prefix(netuid).collect();
+        for (coldkey, mut vesting) in vestings {
+            let vested = Self::get_vested_owner_cut(&vesting, current_block);
+            vesting.locked = vesting.locked.saturating_sub(vested);
+            vesting.start_block = current_block;
+            if vesting.locked.is_zero() {
+                OwnerCutVestings::<T>::remove(netuid, &coldkey);
+            } else {
+                OwnerCutVestings::<T>::insert(netuid, &coldkey, &vesting);
+            }
+            if !vested.is_zero() {
+                Self::pay_owner_cut(netuid, &vesting.hotkey, &coldkey, vested);
+            }
+        }
+    }
+
+    /// Pays every vesting ledger of `netuid` in full.
+    pub fn release_all_owner_cut_vestings(netuid: NetUid) {
+        let vestings: Vec<(T::AccountId, OwnerCutVesting<T::AccountId>)> =
+            OwnerCutVestings::<T>::drain_prefix(netuid).collect();
+        for (coldkey, vesting) in vestings {
+            Self::pay_owner_cut(netuid, &vesting.hotkey, &coldkey, vesting.locked);
+        }
+    }
+
+    /// The alpha of `vesting` vested at `block`.
+    pub fn get_vested_owner_cut(
+        vesting: &OwnerCutVesting<T::AccountId>,
+        block: u64,
+    ) -> AlphaCurrency {
+        if block >= vesting.end_block {
+            return vesting.locked;
+        }
+        let elapsed = block.saturating_sub(vesting.start_block);
+        let duration = vesting.end_block.saturating_sub(vesting.start_block);
+        let vested = u128::from(u64::from(vesting.locked))
+            .saturating_mul(u128::from(elapsed))
+            .safe_div(u128::from(duration));
+        u64::try_from(vested).unwrap_or(u64::MAX).into()
+    }
+
+    /// Stakes `amount` of owner cut of `netuid` to `hotkey` for `coldkey`.
+    fn pay_owner_cut(
+        netuid: NetUid,
+        hotkey: &T::AccountId,
+        coldkey: &T::AccountId,
+        amount: AlphaCurrency,
+    ) {
+        let real_owner_cut =
+            Self::increase_stake_for_hotkey_and_coldkey_on_subnet(hotkey, coldkey, netuid, amount);
+        // If the subnet is leased, notify the lease logic that owner cut has been distributed.
+        if let Some(lease_id) = SubnetUidToLeaseId::<T>::get(netuid) {
+            Self::distribute_leased_network_dividends(lease_id, real_owner_cut);
+        }
+    }
+}
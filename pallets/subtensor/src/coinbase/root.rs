@@ -17,6 +17,7 @@
 
 use super::*;
 use crate::CommitmentsInterface;
+use crate::subnets::weight_row::WeightRow;
 use frame_support::{dispatch::Pays, weights::Weight};
 use safe_math::*;
 use sp_core::Get;
@@ -110,6 +111,7 @@ impl<T: Config> Pallet<T> {
             !Uids::<T>::contains_key(NetUid::ROOT, &hotkey),
             Error::<T>::HotKeyAlreadyRegisteredInSubNet
         );
+        Self::ensure_not_reserved_account(&hotkey)?;
 
         // --- 6. Create a network account for the user if it doesn't exist.
         Self::create_account_if_non_existent(&coldkey, &hotkey);
@@ -186,6 +188,7 @@ impl<T: Config> Pallet<T> {
             subnetwork_uid,
             hotkey
         );
+        Self::deposit_neuron_registered_log(NetUid::ROOT, subnetwork_uid, &hotkey);
         Self::deposit_event(Event::NeuronRegistered(
             NetUid::ROOT,
             subnetwork_uid,
@@ -374,9 +377,14 @@ impl<T: Config> Pallet<T> {
 
         // 2. --- Perform the cleanup before removing the network.
         T::SwapInterface::dissolve_all_liquidity_providers(netuid)?;
+        Self::release_all_owner_cut_vestings(netuid);
+        Self::release_subnet_treasury(netuid);
         Self::destroy_alpha_in_out_stakes(netuid)?;
         T::SwapInterface::clear_protocol_liquidity(netuid)?;
         T::CommitmentsInterface::purge_netuid(netuid);
+        Self::refund_registration_bids(netuid);
+        Self::refund_queued_registrations(netuid);
+        Self::refund_registration_deposits(netuid);
 
         // 3. --- Remove the network
         Self::remove_network(netuid);
@@ -390,8 +398,7 @@ impl<T: Config> Pallet<T> {
 
     pub fn remove_network(netuid: NetUid) {
         // --- 1. Get the owner and remove from SubnetOwner.
-        let owner_coldkey: T::AccountId = SubnetOwner::<T>::get(netuid);
-        SubnetOwner::<T>::remove(netuid);
+        let owner_coldkey: T::AccountId = Self::remove_subnet_owner(netuid);
 
         // --- 2. Remove network count.
         SubnetworkN::<T>::remove(netuid);
@@ -413,20 +420,25 @@ impl<T: Config> Pallet<T> {
         // --- 8. Iterate over stored weights and fill the matrix.
         for (uid_i, weights_i) in Weights::<T>::iter_prefix(NetUidStorageIndex::ROOT) {
             // Create a new vector to hold modified weights.
-            let mut modified_weights = weights_i.clone();
+            let mut modified_weights = weights_i.to_vec();
             for (subnet_id, weight) in modified_weights.iter_mut() {
                 // If the root network had a weight pointing to this netuid, set it to 0
                 if subnet_id == &u16::from(netuid) {
                     *weight = 0;
                 }
             }
-            Weights::<T>::insert(NetUidStorageIndex::ROOT, uid_i, modified_weights);
+            Weights::<T>::insert(
+                NetUidStorageIndex::ROOT,
+                uid_i,
+                WeightRow::from(modified_weights),
+            );
         }
 
         // --- 9. Remove various network-related parameters.
         Rank::<T>::remove(netuid);
         Trust::<T>::remove(netuid);
         Active::<T>::remove(netuid);
+        NeuronStatuses::<T>::remove(netuid);
         Emission::<T>::remove(netuid);
 
         Consensus::<T>::remove(netuid);
@@ -441,12 +453,14 @@ impl<T: Config> Pallet<T> {
 
         // --- 10. Erase network parameters.
         Tempo::<T>::remove(netuid);
+        TempoSeconds::<T>::remove(netuid);
         Kappa::<T>::remove(netuid);
         Difficulty::<T>::remove(netuid);
         MaxAllowedUids::<T>::remove(netuid);
         ImmunityPeriod::<T>::remove(netuid);
         ActivityCutoff::<T>::remove(netuid);
         MaxWeightsLimit::<T>::remove(netuid);
+        AdaptiveMaxWeightsLimit::<T>::remove(netuid);
         MinAllowedWeights::<T>::remove(netuid);
         RegistrationsThisInterval::<T>::remove(netuid);
         POWRegistrationsThisInterval::<T>::remove(netuid);
@@ -458,6 +472,7 @@ impl<T: Config> Pallet<T> {
         SubnetAlphaOutEmission::<T>::remove(netuid);
         SubnetTaoInEmission::<T>::remove(netuid);
         SubnetVolume::<T>::remove(netuid);
+        SubnetVolumeWindows::<T>::remove(netuid);
         SubnetMovingPrice::<T>::remove(netuid);
         SubnetTaoProvided::<T>::remove(netuid);
 
@@ -489,6 +504,7 @@ impl<T: Config> Pallet<T> {
         AlphaSigmoidSteepness::<T>::remove(netuid);
 
         MaxAllowedValidators::<T>::remove(netuid);
+        ValidatorPermitMinStake::<T>::remove(netuid);
         AdjustmentInterval::<T>::remove(netuid);
         BondsMovingAverage::<T>::remove(netuid);
         BondsPenalty::<T>::remove(netuid);
@@ -498,7 +514,30 @@ impl<T: Config> Pallet<T> {
         ScalingLawPower::<T>::remove(netuid);
         TargetRegistrationsPerInterval::<T>::remove(netuid);
         AdjustmentAlpha::<T>::remove(netuid);
+        DifficultyGains::<T>::remove(netuid);
+        DifficultyControllerStates::<T>::remove(netuid);
         CommitRevealWeightsEnabled::<T>::remove(netuid);
+        TimelockedWeightsOnly::<T>::remove(netuid);
+        MerkleWeightsEnabled::<T>::remove(netuid);
+        SelfWeightsExcluded::<T>::remove(netuid);
+        WeightExpiryBlocks::<T>::remove(netuid);
+        BurnAuctionEnabled::<T>::remove(netuid);
+        RegistrationQueueEnabled::<T>::remove(netuid);
+        RegistrationDepositShare::<T>::remove(netuid);
+        RegistrationDepositEpochs::<T>::remove(netuid);
+        MinerEmissionShare::<T>::remove(netuid);
+        SubnetEmissionPaused::<T>::remove(netuid);
+        OwnerCutVestingBlocks::<T>::remove(netuid);
+        SubnetTreasuryShare::<T>::remove(netuid);
+        SubnetTreasuryApprovalThreshold::<T>::remove(netuid);
+        SubnetTreasury::<T>::remove(netuid);
+        SubnetTreasurySpends::<T>::remove(netuid);
+        NextSubnetTreasurySpendId::<T>::remove(netuid);
+        HyperparameterVotingPeriod::<T>::remove(netuid);
+        HyperparameterProposals::<T>::remove(netuid);
+        let _ = HyperparameterVotes::<T>::clear_prefix(netuid, u32::MAX, None);
+        NextHyperparameterProposalId::<T>::remove(netuid);
+        SubnetAttestations::<T>::remove(netuid);
 
         Burn::<T>::remove(netuid);
         MinBurn::<T>::remove(netuid);
@@ -508,15 +547,24 @@ impl<T: Config> Pallet<T> {
         RegistrationsThisBlock::<T>::remove(netuid);
         EMAPriceHalvingBlocks::<T>::remove(netuid);
         RAORecycledForRegistration::<T>::remove(netuid);
+        RegistrationBurnedTao::<T>::remove(netuid);
+        RecycledEmission::<T>::remove(netuid);
         MaxRegistrationsPerBlock::<T>::remove(netuid);
         WeightsVersionKey::<T>::remove(netuid);
 
         // --- 17. Subtoken / feature flags.
         LiquidAlphaOn::<T>::remove(netuid);
         Yuma3On::<T>::remove(netuid);
+        SubnetConsensusEngine::<T>::remove(netuid);
         AlphaValues::<T>::remove(netuid);
         SubtokenEnabled::<T>::remove(netuid);
         ImmuneOwnerUidsLimit::<T>::remove(netuid);
+        StagedHyperparameters::<T>::remove(netuid);
+        Self::clear_churn_state(netuid);
+        Self::clear_stake_allowances(netuid);
+        Self::clear_hotkey_blacklist(netuid);
+        Self::clear_registration_access(netuid);
+        Self::clear_coldkey_registration_quota(netuid);
 
         // --- 18. Consensus aux vectors.
         StakeWeight::<T>::remove(netuid);
@@ -524,13 +572,24 @@ impl<T: Config> Pallet<T> {
 
         // --- 19. DMAPs where netuid is the FIRST key: clear by prefix.
         let _ = BlockAtRegistration::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = NeuronLifetimeEmission::<T>::clear_prefix(netuid, u32::MAX, None);
         let _ = Axons::<T>::clear_prefix(netuid, u32::MAX, None);
         let _ = NeuronCertificates::<T>::clear_prefix(netuid, u32::MAX, None);
         let _ = Prometheus::<T>::clear_prefix(netuid, u32::MAX, None);
         let _ = AlphaDividendsPerSubnet::<T>::clear_prefix(netuid, u32::MAX, None);
         let _ = TaoDividendsPerSubnet::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = DelegateEmissionWindows::<T>::clear_prefix(netuid, u32::MAX, None);
         let _ = PendingChildKeys::<T>::clear_prefix(netuid, u32::MAX, None);
         let _ = AssociatedEvmAddress::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = OwnerHyperparamGroupRateLimit::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = LastHeartbeat::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = WeightsSetRateLimitOverride::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = NeuronImmunityOverride::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = StakeOrigins::<T>::clear_prefix((netuid,), u32::MAX, None);
+        let _ = WeightSetterDelegate::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = OwnerCutVestings::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = HotkeyLeaseOffers::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = HotkeyLeases::<T>::clear_prefix(netuid, u32::MAX, None);
 
         // Commit-reveal / weights commits (all per-net prefixes):
         let mechanisms: u8 = MechanismCountCurrent::<T>::get(netuid).into();
@@ -540,12 +599,14 @@ impl<T: Config> Pallet<T> {
             Incentive::<T>::remove(netuid_index);
             let _ = WeightCommits::<T>::clear_prefix(netuid_index, u32::MAX, None);
             let _ = TimelockedWeightCommits::<T>::clear_prefix(netuid_index, u32::MAX, None);
+            let _ = MerkleWeightsUploads::<T>::clear_prefix(netuid_index, u32::MAX, None);
             let _ = CRV3WeightCommits::<T>::clear_prefix(netuid_index, u32::MAX, None);
             let _ = CRV3WeightCommitsV2::<T>::clear_prefix(netuid_index, u32::MAX, None);
             let _ = Bonds::<T>::clear_prefix(netuid_index, u32::MAX, None);
             let _ = Weights::<T>::clear_prefix(netuid_index, u32::MAX, None);
         }
         RevealPeriodEpochs::<T>::remove(netuid);
+        RevealWindow::<T>::remove(netuid);
         MechanismCountCurrent::<T>::remove(netuid);
         MechanismEmissionSplit::<T>::remove(netuid);
 
@@ -668,10 +729,15 @@ impl<T: Config> Pallet<T> {
     ///     - The lock cost for the network.
     ///
     pub fn get_network_lock_cost() -> TaoCurrency {
+        Self::get_network_lock_cost_at(Self::get_current_block_as_u64())
+    }
+
+    /// The lock cost of a subnet registered at `current_block`, if no subnet is registered
+    /// before then and the lock settings stay the same.
+    pub fn get_network_lock_cost_at(current_block: u64) -> TaoCurrency {
         let last_lock = Self::get_network_last_lock();
         let min_lock = Self::get_network_min_lock();
         let last_lock_block = Self::get_network_last_lock_block();
-        let current_block = Self::get_current_block_as_u64();
         let lock_reduction_interval = Self::get_lock_reduction_interval();
         let mult: TaoCurrency = if last_lock_block == 0 { 1 } else { 2 }.into();
 
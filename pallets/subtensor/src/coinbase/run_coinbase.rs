@@ -1,4 +1,5 @@
 use super::*;
+use crate::coinbase::emission_telemetry::SubnetEmissionSummary;
 use alloc::collections::BTreeMap;
 use safe_math::*;
 use substrate_fixed::types::U96F32;
@@ -30,24 +31,27 @@ impl<T: Config> Pallet<T> {
             .filter(|netuid| *netuid != NetUid::ROOT)
             .collect();
         log::debug!("All subnet netuids: {subnets:?}");
-        // Filter out subnets with no first emission block number.
+        // Filter out subnets with no first emission block number or with their emission paused.
         let subnets_to_emit_to: Vec<NetUid> = subnets
             .clone()
             .into_iter()
-            .filter(|netuid| FirstEmissionBlockNumber::<T>::get(*netuid).is_some())
+            .filter(|netuid| {
+                FirstEmissionBlockNumber::<T>::get(*netuid).is_some()
+                    && !Self::is_subnet_emission_paused(*netuid)
+            })
             .collect();
         log::debug!("Subnets to emit to: {subnets_to_emit_to:?}");
 
-        // --- 2. Get sum of tao reserves ( in a later version we will switch to prices. )
-        let mut acc_total_moving_prices = U96F32::saturating_from_num(0.0);
+        // --- 2. Split the block emission by moving price, capped per subnet.
+        // The moving price is an EMA of the pool price, so short-lived price pushes barely move
+        // the split. Its horizon is governed by `SubnetMovingAlpha` and `EMAPriceHalvingBlocks`.
+        let mut moving_prices: BTreeMap<NetUid, U96F32> = BTreeMap::new();
         // Only get price EMA for subnets that we emit to.
         for netuid_i in subnets_to_emit_to.iter() {
-            // Get and update the moving price of each subnet adding the total together.
-            acc_total_moving_prices =
-                acc_total_moving_prices.saturating_add(Self::get_moving_alpha_price(*netuid_i));
+            moving_prices.insert(*netuid_i, Self::get_moving_alpha_price(*netuid_i));
         }
-        let total_moving_prices = acc_total_moving_prices;
-        log::debug!("total_moving_prices: {total_moving_prices:?}");
+        let default_tao_in = Self::get_subnet_tao_emissions(block_emission, &moving_prices);
+        log::debug!("default_tao_in: {default_tao_in:?}");
 
         // --- 3. Get subnet terms (tao_in, alpha_in, and alpha_out)
         // Computation is described in detail in the dtao whitepaper.
@@ -60,14 +64,8 @@ impl<T: Config> Pallet<T> {
             // Get subnet price.
             let price_i = T::SwapInterface::current_alpha_price((*netuid_i).into());
             log::debug!("price_i: {price_i:?}");
-            // Get subnet TAO.
-            let moving_price_i: U96F32 = Self::get_moving_alpha_price(*netuid_i);
-            log::debug!("moving_price_i: {moving_price_i:?}");
             // Emission is price over total.
-            let default_tao_in_i: U96F32 = block_emission
-                .saturating_mul(moving_price_i)
-                .checked_div(total_moving_prices)
-                .unwrap_or(asfloat!(0.0));
+            let default_tao_in_i: U96F32 = *default_tao_in.get(netuid_i).unwrap_or(&asfloat!(0.0));
             log::debug!("default_tao_in_i: {default_tao_in_i:?}");
             // Get alpha_emission total
             let alpha_emission_i: U96F32 = asfloat!(
@@ -130,6 +128,7 @@ impl<T: Config> Pallet<T> {
         // --- 4. Injection.
         // Actually perform the injection of alpha_in, alpha_out and tao_in into the subnet pool.
         // This operation changes the pool liquidity each block.
+        let mut emission_summaries: Vec<SubnetEmissionSummary> = Vec::new();
         for netuid_i in subnets_to_emit_to.iter() {
             // Inject Alpha in.
             let alpha_in_i =
@@ -160,7 +159,14 @@ impl<T: Config> Pallet<T> {
             });
             // Adjust protocol liquidity based on new reserves
             T::SwapInterface::adjust_protocol_liquidity(*netuid_i, tao_in_i, alpha_in_i);
+            emission_summaries.push(SubnetEmissionSummary {
+                netuid: *netuid_i,
+                tao_in: tao_in_i,
+                alpha_in: alpha_in_i,
+                alpha_out: alpha_out_i,
+            });
         }
+        Self::record_emission_telemetry(emission_summaries);
 
         // --- 5. Compute owner cuts and remove them from alpha_out remaining.
         // Remove owner cuts here so that we can properly seperate root dividends in the next step.
@@ -208,7 +214,7 @@ impl<T: Config> Pallet<T> {
             // Get root proportion of alpha_out dividends.
             let root_alpha: U96F32 = root_proportion
                 .saturating_mul(alpha_out_i) // Total alpha emission per block remaining.
-                .saturating_mul(asfloat!(0.5)); // 50% to validators.
+                .saturating_mul(Self::get_validator_emission_fraction(*netuid_i)); // Validator share.
             // Remove root alpha from alpha_out.
             log::debug!("root_alpha: {root_alpha:?}");
             // Get pending alpha as original alpha_out - root_alpha.
@@ -223,12 +229,15 @@ impl<T: Config> Pallet<T> {
                     T::SwapInterface::min_price().into(),
                     true,
                 );
-                if let Ok(ok_result) = swap_result {
-                    let root_tao: u64 = ok_result.amount_paid_out;
-                    // Accumulate root divs for subnet.
-                    PendingRootDivs::<T>::mutate(*netuid_i, |total| {
-                        *total = total.saturating_add(root_tao.into());
-                    });
+                match swap_result {
+                    Ok(ok_result) => {
+                        let root_tao: u64 = ok_result.amount_paid_out;
+                        // Accumulate root divs for subnet.
+                        PendingRootDivs::<T>::mutate(*netuid_i, |total| {
+                            *total = total.saturating_add(root_tao.into());
+                        });
+                    }
+                    Err(e) => Self::report_rejected_tao_debit(*netuid_i, &e),
                 }
             }
             // Accumulate alpha emission in pending.
@@ -242,8 +251,12 @@ impl<T: Config> Pallet<T> {
         }
 
         // --- 7. Update moving prices after using them in the emission calculation.
-        // Only update price EMA for subnets that we emit to.
-        for netuid_i in subnets_to_emit_to.iter() {
+        // Update the price EMA of subnets with their emission paused too, so that they do not
+        // resume with a stale price.
+        for netuid_i in subnets
+            .iter()
+            .filter(|netuid| FirstEmissionBlockNumber::<T>::get(**netuid).is_some())
+        {
             // Update moving prices after using them above.
             Self::update_moving_price(*netuid_i);
         }
@@ -257,34 +270,7 @@ impl<T: Config> Pallet<T> {
             };
             // Pass on subnets that have not reached their tempo.
             if Self::should_run_epoch(netuid, current_block) {
-                // Restart counters.
-                BlocksSinceLastStep::<T>::insert(netuid, 0);
-                LastMechansimStepBlock::<T>::insert(netuid, current_block);
-
-                // Get and drain the subnet pending emission.
-                let pending_alpha = PendingEmission::<T>::get(netuid);
-                PendingEmission::<T>::insert(netuid, AlphaCurrency::ZERO);
-
-                // Get and drain the subnet pending root divs.
-                let pending_tao = PendingRootDivs::<T>::get(netuid);
-                PendingRootDivs::<T>::insert(netuid, TaoCurrency::ZERO);
-
-                // Get this amount as alpha that was swapped for pending root divs.
-                let pending_swapped = PendingAlphaSwapped::<T>::get(netuid);
-                PendingAlphaSwapped::<T>::insert(netuid, AlphaCurrency::ZERO);
-
-                // Get owner cut and drain.
-                let owner_cut = PendingOwnerCut::<T>::get(netuid);
-                PendingOwnerCut::<T>::insert(netuid, AlphaCurrency::ZERO);
-
-                // Drain pending root divs, alpha emission, and owner cut.
-                Self::drain_pending_emission(
-                    netuid,
-                    pending_alpha,
-                    pending_tao,
-                    pending_swapped,
-                    owner_cut,
-                );
+                Self::run_subnet_step(netuid, current_block);
             } else {
                 // Increment
                 BlocksSinceLastStep::<T>::mutate(netuid, |total| *total = total.saturating_add(1));
@@ -292,6 +278,43 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Runs the epoch of `netuid` and drains its pending emission through it.
+    pub fn run_subnet_step(netuid: NetUid, current_block: u64) {
+        // Restart counters.
+        BlocksSinceLastStep::<T>::insert(netuid, 0);
+        LastMechansimStepBlock::<T>::insert(netuid, current_block);
+
+        // Get and drain the subnet pending emission.
+        let pending_alpha = PendingEmission::<T>::get(netuid);
+        PendingEmission::<T>::insert(netuid, AlphaCurrency::ZERO);
+
+        // Get and drain the subnet pending root divs.
+        let pending_tao = PendingRootDivs::<T>::get(netuid);
+        PendingRootDivs::<T>::insert(netuid, TaoCurrency::ZERO);
+
+        // Get this amount as alpha that was swapped for pending root divs.
+        let pending_swapped = PendingAlphaSwapped::<T>::get(netuid);
+        PendingAlphaSwapped::<T>::insert(netuid, AlphaCurrency::ZERO);
+
+        // Get owner cut and drain.
+        let owner_cut = PendingOwnerCut::<T>::get(netuid);
+        PendingOwnerCut::<T>::insert(netuid, AlphaCurrency::ZERO);
+
+        // Drain pending root divs, alpha emission, and owner cut.
+        Self::drain_pending_emission(
+            netuid,
+            pending_alpha,
+            pending_tao,
+            pending_swapped,
+            owner_cut,
+        );
+
+        // Queued registrations take the slots of pruned neurons only once the epoch has run.
+        Self::admit_queued_registrations(netuid);
+        Self::release_matured_registration_deposits(netuid);
+        Self::expire_hotkey_leases(netuid);
+    }
+
     pub fn calculate_dividends_and_incentives(
         netuid: NetUid,
         hotkey_emission: Vec<(T::AccountId, AlphaCurrency, AlphaCurrency)>,
@@ -467,23 +490,16 @@ impl<T: Config> Pallet<T> {
         alpha_dividends: BTreeMap<T::AccountId, U96F32>,
         tao_dividends: BTreeMap<T::AccountId, U96F32>,
     ) {
-        // Distribute the owner cut.
+        // Distribute the owner cut, after the owner cut vested since the last epoch.
+        Self::release_vested_owner_cuts(netuid);
         if let Ok(owner_coldkey) = SubnetOwner::<T>::try_get(netuid) {
             if let Ok(owner_hotkey) = SubnetOwnerHotkey::<T>::try_get(netuid) {
-                // Increase stake for owner hotkey and coldkey.
+                // Increase stake for owner hotkey and coldkey, or lock it while it vests.
                 log::debug!(
                     "owner_hotkey: {owner_hotkey:?} owner_coldkey: {owner_coldkey:?}, owner_cut: {owner_cut:?}"
                 );
-                let real_owner_cut = Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
-                    &owner_hotkey,
-                    &owner_coldkey,
-                    netuid,
-                    owner_cut,
-                );
-                // If the subnet is leased, notify the lease logic that owner cut has been distributed.
-                if let Some(lease_id) = SubnetUidToLeaseId::<T>::get(netuid) {
-                    Self::distribute_leased_network_dividends(lease_id, real_owner_cut);
-                }
+                let owner_cut = Self::fund_subnet_treasury(netuid, owner_cut);
+                Self::pay_or_vest_owner_cut(netuid, &owner_hotkey, &owner_coldkey, owner_cut);
             }
         }
 
@@ -503,7 +519,7 @@ impl<T: Config> Pallet<T> {
                 match RecycleOrBurn::<T>::try_get(netuid) {
                     Ok(RecycleOrBurnEnum::Recycle) => {
                         log::debug!("recycling {incentive:?}");
-                        Self::recycle_subnet_alpha(netuid, incentive);
+                        Self::recycle_subnet_emission(netuid, incentive);
                     }
                     Ok(RecycleOrBurnEnum::Burn) | Err(_) => {
                         log::debug!("burning {incentive:?}");
@@ -534,23 +550,28 @@ impl<T: Config> Pallet<T> {
                 netuid,
                 incentive,
             );
+            Self::record_neuron_emission(netuid, &hotkey, incentive);
         }
 
         // Distribute alpha divs.
         let _ = AlphaDividendsPerSubnet::<T>::clear_prefix(netuid, u32::MAX, None);
         for (hotkey, mut alpha_divs) in alpha_dividends {
+            Self::record_delegate_emission(netuid, &hotkey, tou64!(alpha_divs).into());
+            Self::record_neuron_emission(netuid, &hotkey, tou64!(alpha_divs).into());
             // Get take prop
             let alpha_take: U96F32 =
                 Self::get_hotkey_take_float(&hotkey).saturating_mul(alpha_divs);
             // Remove take prop from alpha_divs
             alpha_divs = alpha_divs.saturating_sub(alpha_take);
-            // Give the validator their take.
+            // Give the validator their take, less the share of the renter of a leased hotkey.
             log::debug!("hotkey: {hotkey:?} alpha_take: {alpha_take:?}");
+            let owner_take =
+                Self::pay_hotkey_lease_share(netuid, &hotkey, tou64!(alpha_take).into());
             Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
                 &hotkey,
                 &Owner::<T>::get(&hotkey),
                 netuid,
-                tou64!(alpha_take).into(),
+                owner_take,
             );
             // Give all other nominators.
             log::debug!("hotkey: {hotkey:?} alpha_divs: {alpha_divs:?}");
@@ -657,14 +678,20 @@ impl<T: Config> Pallet<T> {
 
         let tao_weight = Self::get_tao_weight();
 
+        // Preview any staged hyperparameters before the live epoch mutates state.
+        Self::maybe_run_shadow_epoch(netuid, pending_alpha.saturating_add(pending_swapped));
+
         // Run the epoch.
+        let bonds_before = Self::snapshot_bonds(netuid);
         let hotkey_emission: Vec<(T::AccountId, AlphaCurrency, AlphaCurrency)> =
             Self::epoch_with_mechanisms(netuid, pending_alpha.saturating_add(pending_swapped));
+        let hotkey_emission = Self::scale_miner_incentives(netuid, hotkey_emission);
         log::debug!("hotkey_emission: {hotkey_emission:?}");
+        Self::deposit_consensus_telemetry(netuid, &bonds_before);
 
         // Compute the pending validator alpha.
         // This is the total alpha being injected,
-        // minus the the alpha for the miners, (50%)
+        // minus the the alpha for the miners (the miner emission share),
         // and minus the alpha swapped for TAO (pending_swapped).
         // Important! If the incentives are 0, then Validators get 100% of the alpha.
         let incentive_sum = hotkey_emission
@@ -675,9 +702,8 @@ impl<T: Config> Pallet<T> {
         log::debug!("incentive_sum: {incentive_sum:?}");
 
         let pending_validator_alpha = if !incentive_sum.is_zero() {
-            pending_alpha
-                .saturating_add(pending_swapped)
-                .saturating_div(2.into())
+            let pending_total = u64::from(pending_alpha.saturating_add(pending_swapped));
+            AlphaCurrency::from(Self::get_validator_emission_share(netuid).mul_floor(pending_total))
                 .saturating_sub(pending_swapped)
         } else {
             // If the incentive is 0, then Validators get 100% of the alpha.
@@ -845,7 +871,7 @@ impl<T: Config> Pallet<T> {
                 parent_emission = parent_emission.saturating_sub(child_take);
                 total_child_take = total_child_take.saturating_add(child_take);
 
-                Self::recycle_subnet_alpha(
+                Self::recycle_subnet_emission(
                     netuid,
                     AlphaCurrency::from(burn_take.saturating_to_num::<u64>()),
                 );
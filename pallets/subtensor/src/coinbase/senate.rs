@@ -0,0 +1,106 @@
+//! Senate membership from root stake.
+//!
+//! Hotkeys only joined the senate when registering on root or adjusting the senate themselves, so
+//! membership lagged the stake of the delegates. The senate is now recalculated every root tempo
+//! to the delegates registered on root with the most root stake: members no longer registered on
+//! root leave, free seats go to the hotkeys with the most stake, and a hotkey replaces the member
+//! with the least stake when its stake is above that of the member by more than the senate
+//! hysteresis, so that members are not swapped on small moves of stake.
+
+use super::*;
+use safe_math::*;
+use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid};
+
+impl<T: Config> Pallet<T> {
+    pub fn get_senate_hysteresis() -> u16 {
+        SenateHysteresis::<T>::get()
+    }
+
+    /// Has a hotkey replace a senate member only with `hysteresis` more root stake than the
+    /// member, in `u16::MAX` parts of the stake of the member.
+    pub fn set_senate_hysteresis(hysteresis: u16) {
+        SenateHysteresis::<T>::put(hysteresis);
+        Self::deposit_event(Event::SenateHysteresisSet { hysteresis });
+    }
+
+    /// Recalculates the senate at the end of the root tempo.
+    pub fn try_update_senate(block_number: u64) {
+        if Self::should_run_epoch(NetUid::ROOT, block_number) {
+            Self::update_senate();
+        }
+    }
+
+    /// Sets the senate to the delegates registered on root with the most root stake, subject to the
+    /// senate hysteresis.
+    pub fn update_senate() {
+        for member in T::SenateMembers::members() {
+            if !Uids::<T>::contains_key(NetUid::ROOT, &member)
+                && T::SenateMembers::remove_member(&member).is_ok()
+            {
+                Self::deposit_event(Event::SenateMemberRemoved { member });
+            }
+        }
+
+        // Members by ascending root stake, candidates by descending root stake.
+        let mut members: Vec<(AlphaCurrency, T::AccountId)> = T::SenateMembers::members()
+            .into_iter()
+            .map(|member| {
+                let stake = Self::get_stake_for_hotkey_on_subnet(&member, NetUid::ROOT);
+                (stake, member)
+            })
+            .collect();
+        members.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let mut candidates: Vec<(AlphaCurrency, T::AccountId)> =
+            Keys::<T>::iter_prefix(NetUid::ROOT)
+                .filter(|(_, hotkey)| {
+                    Self::hotkey_is_delegate(hotkey) && !T::SenateMembers::is_member(hotkey)
+                })
+                .map(|(_, hotkey)| {
+                    let stake = Self::get_stake_for_hotkey_on_subnet(&hotkey, NetUid::ROOT);
+                    (stake, hotkey)
+                })
+                .filter(|(stake, _)| !stake.is_zero())
+                .collect();
+        candidates.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+        let max_members = T::SenateMembers::max_members() as usize;
+        for (stake, candidate) in candidates {
+            if members.len() < max_members {
+                if T::SenateMembers::add_member(&candidate).is_ok() {
+                    Self::deposit_event(Event::SenateAdjusted {
+                        old_member: None,
+                        new_member: candidate.clone(),
+                    });
+                    members.push((stake, candidate));
+                    members.sort_by(|(a, _), (b, _)| a.cmp(b));
+                }
+                continue;
+            }
+
+            let Some((lowest_stake, lowest)) = members.first().cloned() else {
+                break;
+            };
+            // The candidates left have no more stake than this one.
+            if stake <= Self::senate_replacement_threshold(lowest_stake) {
+                break;
+            }
+            if T::SenateMembers::swap_member(&lowest, &candidate).is_ok() {
+                Self::deposit_event(Event::SenateAdjusted {
+                    old_member: Some(lowest.clone()),
+                    new_member: candidate.clone(),
+                });
+                members.retain(|(_, member)| *member != lowest);
+                members.push((stake, candidate));
+                members.sort_by(|(a, _), (b, _)| a.cmp(b));
+            }
+        }
+    }
+
+    /// The root stake a hotkey needs above to replace a member with `member_stake`.
+    fn senate_replacement_threshold(member_stake: AlphaCurrency) -> AlphaCurrency {
+        let margin = u128::from(member_stake.to_u64())
+            .saturating_mul(u128::from(Self::get_senate_hysteresis()))
+            .safe_div(u128::from(u16::MAX));
+        member_stake.saturating_add(u64::try_from(margin).unwrap_or(u64::MAX).into())
+    }
+}
@@ -0,0 +1,155 @@
+//! Consensus engines.
+//!
+//! The consensus of a subnet is the score per uid the weights of its validators agree on. The
+//! epoch clips the weights of every validator at it, so it decides the validator trust, the
+//! ranks and incentive of the miners, and which part of the weights the bonds follow. Yuma
+//! computes it as the stake-weighted median of the weights at the kappa majority, which is the
+//! default for every subnet. Root can select another engine for a subnet, which only replaces
+//! this step of the epoch.
+
+use super::*;
+use crate::epoch::math::*;
+use codec::DecodeWithMemTracking;
+use substrate_fixed::types::I32F32;
+use subtensor_runtime_common::NetUid;
+
+/// Computes the consensus of a subnet from the weights of its validators.
+pub trait ConsensusEngine {
+    /// The consensus of every column of the sparse `weights` of `n` uids, where `stake` is the
+    /// normalized active stake of the rows and `kappa` the majority ratio of the subnet.
+    fn consensus_sparse(
+        stake: &[I32F32],
+        weights: &[Vec<(u16, I32F32)>],
+        n: u16,
+        kappa: I32F32,
+    ) -> Vec<I32F32>;
+
+    /// The consensus of every column of the dense `weights`.
+    fn consensus_dense(stake: &[I32F32], weights: &[Vec<I32F32>], kappa: I32F32) -> Vec<I32F32>;
+}
+
+/// The stake-weighted median of the weights at the kappa majority.
+pub struct Yuma;
+
+impl ConsensusEngine for Yuma {
+    fn consensus_sparse(
+        stake: &[I32F32],
+        weights: &[Vec<(u16, I32F32)>],
+        n: u16,
+        kappa: I32F32,
+    ) -> Vec<I32F32> {
+        weighted_median_col_sparse(stake, weights, n, kappa)
+    }
+
+    fn consensus_dense(stake: &[I32F32], weights: &[Vec<I32F32>], kappa: I32F32) -> Vec<I32F32> {
+        weighted_median_col(stake, weights, kappa)
+    }
+}
+
+/// The stake-weighted average of the weights, which never clips the weights of a validator below
+/// the average.
+pub struct StakeWeightedAverage;
+
+impl ConsensusEngine for StakeWeightedAverage {
+    fn consensus_sparse(
+        stake: &[I32F32],
+        weights: &[Vec<(u16, I32F32)>],
+        n: u16,
+        _kappa: I32F32,
+    ) -> Vec<I32F32> {
+        matmul_sparse(weights, stake, n)
+    }
+
+    fn consensus_dense(stake: &[I32F32], weights: &[Vec<I32F32>], _kappa: I32F32) -> Vec<I32F32> {
+        matmul(weights, stake)
+    }
+}
+
+/// The median of the weights at the kappa majority, with one vote per validator with active
+/// stake regardless of its size.
+pub struct TrustedMedian;
+
+impl TrustedMedian {
+    fn votes(stake: &[I32F32]) -> Vec<I32F32> {
+        let zero = I32F32::saturating_from_num(0);
+        let one = I32F32::saturating_from_num(1);
+        stake
+            .iter()
+            .map(|stake| if *stake > zero { one } else { zero })
+            .collect()
+    }
+}
+
+impl ConsensusEngine for TrustedMedian {
+    fn consensus_sparse(
+        stake: &[I32F32],
+        weights: &[Vec<(u16, I32F32)>],
+        n: u16,
+        kappa: I32F32,
+    ) -> Vec<I32F32> {
+        weighted_median_col_sparse(&Self::votes(stake), weights, n, kappa)
+    }
+
+    fn consensus_dense(stake: &[I32F32], weights: &[Vec<I32F32>], kappa: I32F32) -> Vec<I32F32> {
+        weighted_median_col(&Self::votes(stake), weights, kappa)
+    }
+}
+
+/// The consensus engine selected for a subnet.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Default, Clone, Copy, PartialEq, Eq, Debug, TypeInfo,
+)]
+pub enum ConsensusEngineKind {
+    /// Stake-weighted median of the weights, see [`Yuma`].
+    #[default]
+    Yuma,
+    /// Stake-weighted average of the weights, see [`StakeWeightedAverage`].
+    StakeWeightedAverage,
+    /// Median of the weights with one vote per validator, see [`TrustedMedian`].
+    TrustedMedian,
+}
+
+impl ConsensusEngineKind {
+    pub fn consensus_sparse(
+        self,
+        stake: &[I32F32],
+        weights: &[Vec<(u16, I32F32)>],
+        n: u16,
+        kappa: I32F32,
+    ) -> Vec<I32F32> {
+        match self {
+            Self::Yuma => Yuma::consensus_sparse(stake, weights, n, kappa),
+            Self::StakeWeightedAverage => {
+                StakeWeightedAverage::consensus_sparse(stake, weights, n, kappa)
+            }
+            Self::TrustedMedian => TrustedMedian::consensus_sparse(stake, weights, n, kappa),
+        }
+    }
+
+    pub fn consensus_dense(
+        self,
+        stake: &[I32F32],
+        weights: &[Vec<I32F32>],
+        kappa: I32F32,
+    ) -> Vec<I32F32> {
+        match self {
+            Self::Yuma => Yuma::consensus_dense(stake, weights, kappa),
+            Self::StakeWeightedAverage => {
+                StakeWeightedAverage::consensus_dense(stake, weights, kappa)
+            }
+            Self::TrustedMedian => TrustedMedian::consensus_dense(stake, weights, kappa),
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn get_consensus_engine(netuid: NetUid) -> ConsensusEngineKind {
+        SubnetConsensusEngine::<T>::get(netuid)
+    }
+
+    /// Selects the consensus engine of `netuid`, from its next epoch on.
+    pub fn set_consensus_engine(netuid: NetUid, engine: ConsensusEngineKind) {
+        SubnetConsensusEngine::<T>::insert(netuid, engine);
+        Self::deposit_event(Event::ConsensusEngineSet(netuid, engine));
+    }
+}
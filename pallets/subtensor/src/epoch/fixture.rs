@@ -0,0 +1,198 @@
+//! Epoch fixtures.
+//!
+//! An epoch fixture is a snapshot of everything the epoch of a subnet reads: its neurons and their
+//! effective stake and heartbeats, the weights, bonds and pending commits of every mechanism, and
+//! the hyperparameters. Neurons and matrix rows are ordered by uid, so capturing the same state always
+//! gives the same SCALE encoded blob.
+//!
+//! Fixtures are exported from a node through `EpochFixtureRuntimeApi`, which only returns them
+//! when the runtime is built with the `epoch-fixtures` feature, and are replayed in the pallet
+//! tests to compare epoch changes against captured mainnet states. Stake is captured after child
+//! key inheritance, so replaying a fixture does not need the staking state behind it.
+
+use super::*;
+use crate::epoch::consensus::ConsensusEngineKind;
+use alloc::collections::BTreeMap;
+use subtensor_runtime_common::{MechId, NetUid, NetUidStorageIndex};
+
+/// Version of the fixture encoding, bumped whenever `EpochFixture` changes.
+pub const EPOCH_FIXTURE_VERSION: u8 = 6;
+
+#[freeze_struct("f335c092be12120e")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct EpochFixture<AccountId> {
+    pub version: u8,
+    pub netuid: NetUid,
+    /// Block at which the fixture was captured.
+    pub block: u64,
+    /// Uid of the subnet owner hotkey, if it is registered.
+    pub owner_uid: Option<u16>,
+    pub params: EpochFixtureParams,
+    /// Neurons by uid.
+    pub neurons: Vec<EpochFixtureNeuron<AccountId>>,
+    pub mechanisms: Vec<EpochFixtureMechanism>,
+    /// Block of the last heartbeat of each uid with one, by uid.
+    pub heartbeats: Vec<(u16, u64)>,
+}
+
+#[freeze_struct("28a4702bb153e8c9")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct EpochFixtureParams {
+    pub tempo: u16,
+    pub activity_cutoff: u16,
+    pub max_allowed_validators: u16,
+    pub kappa: u16,
+    pub rho: u16,
+    pub bonds_penalty: u16,
+    pub bonds_moving_average: u64,
+    pub liquid_alpha_enabled: bool,
+    pub alpha_values: (u16, u16),
+    pub alpha_sigmoid_steepness: i16,
+    pub yuma3_enabled: bool,
+    pub commit_reveal_weights_enabled: bool,
+    pub reveal_period: u64,
+    pub stake_threshold: u64,
+    pub tao_weight: u64,
+    pub consensus_engine: ConsensusEngineKind,
+    pub self_weights_excluded: bool,
+    pub weight_expiry_blocks: u64,
+    pub validator_permit_min_stake: u64,
+}
+
+#[freeze_struct("ec82ddf079d06d8")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct EpochFixtureNeuron<AccountId> {
+    pub hotkey: AccountId,
+    pub block_at_registration: u64,
+    pub validator_permit: bool,
+    /// Alpha stake on the subnet, after child key inheritance.
+    pub alpha_stake: u64,
+    /// Root stake, after child key inheritance on the subnet.
+    pub tao_stake: u64,
+}
+
+#[freeze_struct("7f164384bdad49bc")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct EpochFixtureMechanism {
+    pub mecid: MechId,
+    pub last_update: Vec<u64>,
+    /// Weight rows by uid.
+    pub weights: Vec<Vec<(u16, u16)>>,
+    /// Bond rows by uid.
+    pub bonds: Vec<Vec<(u16, u16)>>,
+    /// Block of the earliest unexpired weight commit of each uid with one, by uid.
+    pub commit_blocks: Vec<(u16, u64)>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Captures the epoch input state of `netuid`, or `None` if there is no such subnet.
+    pub fn get_epoch_fixture(netuid: NetUid) -> Option<EpochFixture<T::AccountId>> {
+        if netuid.is_root() || !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        let n = Self::get_subnetwork_n(netuid);
+        let validator_permit = Self::get_validator_permit(netuid);
+        let neurons = (0..n)
+            .filter_map(|uid| {
+                let hotkey = Keys::<T>::try_get(netuid, uid).ok()?;
+                Some(EpochFixtureNeuron {
+                    block_at_registration: Self::get_neuron_block_at_registration(netuid, uid),
+                    validator_permit: validator_permit.get(uid as usize).copied().unwrap_or(false),
+                    alpha_stake: Self::get_inherited_for_hotkey_on_subnet(&hotkey, netuid).into(),
+                    tao_stake: Self::get_tao_inherited_for_hotkey_on_subnet(&hotkey, netuid).into(),
+                    hotkey,
+                })
+            })
+            .collect();
+
+        let mechanisms = (0..u8::from(MechanismCountCurrent::<T>::get(netuid)))
+            .map(|mecid| {
+                let mecid = MechId::from(mecid);
+                let netuid_index = Self::get_mechanism_storage_index(netuid, mecid);
+                EpochFixtureMechanism {
+                    mecid,
+                    last_update: Self::get_last_update(netuid_index),
+                    weights: (0..n)
+                        .map(|uid| Weights::<T>::get(netuid_index, uid).to_vec())
+                        .collect(),
+                    bonds: (0..n)
+                        .map(|uid| Bonds::<T>::get(netuid_index, uid))
+                        .collect(),
+                    commit_blocks: Self::get_active_commit_blocks(netuid, netuid_index),
+                }
+            })
+            .collect();
+
+        let mut heartbeats: Vec<(u16, u64)> = LastHeartbeat::<T>::iter_prefix(netuid).collect();
+        heartbeats.sort_unstable();
+
+        Some(EpochFixture {
+            version: EPOCH_FIXTURE_VERSION,
+            netuid,
+            block: Self::get_current_block_as_u64(),
+            owner_uid: Self::get_owner_uid(netuid),
+            params: EpochFixtureParams {
+                tempo: Self::get_tempo(netuid),
+                activity_cutoff: Self::get_activity_cutoff(netuid),
+                max_allowed_validators: Self::get_max_allowed_validators(netuid),
+                kappa: Self::get_kappa(netuid),
+                rho: Self::get_rho(netuid),
+                bonds_penalty: Self::get_bonds_penalty(netuid),
+                bonds_moving_average: Self::get_bonds_moving_average(netuid),
+                liquid_alpha_enabled: Self::get_liquid_alpha_enabled(netuid),
+                alpha_values: AlphaValues::<T>::get(netuid),
+                alpha_sigmoid_steepness: AlphaSigmoidSteepness::<T>::get(netuid),
+                yuma3_enabled: Yuma3On::<T>::get(netuid),
+                commit_reveal_weights_enabled: Self::get_commit_reveal_weights_enabled(netuid),
+                reveal_period: Self::get_reveal_period(netuid),
+                stake_threshold: Self::get_stake_threshold(),
+                tao_weight: TaoWeight::<T>::get(),
+                consensus_engine: Self::get_consensus_engine(netuid),
+                self_weights_excluded: Self::get_self_weights_excluded(netuid),
+                weight_expiry_blocks: Self::get_weight_expiry_blocks(netuid),
+                validator_permit_min_stake: Self::get_validator_permit_min_stake(netuid),
+            },
+            neurons,
+            mechanisms,
+            heartbeats,
+        })
+    }
+
+    /// The SCALE encoded epoch fixture of `netuid`.
+    pub fn get_epoch_fixture_blob(netuid: NetUid) -> Option<Vec<u8>> {
+        Self::get_epoch_fixture(netuid).map(|fixture| fixture.encode())
+    }
+
+    /// The earliest unexpired weight commit block of every uid with one, as the epoch masks them.
+    fn get_active_commit_blocks(
+        netuid: NetUid,
+        netuid_index: NetUidStorageIndex,
+    ) -> Vec<(u16, u64)> {
+        let mut commit_blocks: BTreeMap<u16, u64> = BTreeMap::new();
+        let mut record = |who: &T::AccountId, commit_block: u64| {
+            if Self::is_commit_expired(netuid, commit_block) {
+                return;
+            }
+            if let Some(uid) = Uids::<T>::get(netuid, who) {
+                commit_blocks
+                    .entry(uid)
+                    .and_modify(|block| *block = (*block).min(commit_block))
+                    .or_insert(commit_block);
+            }
+        };
+
+        for (who, commits) in WeightCommits::<T>::iter_prefix(netuid_index) {
+            for (_, commit_block, _, _) in commits.iter() {
+                record(&who, *commit_block);
+            }
+        }
+        for (_, commits) in TimelockedWeightCommits::<T>::iter_prefix(netuid_index) {
+            for (who, commit_block, ..) in commits.iter() {
+                record(who, *commit_block);
+            }
+        }
+
+        commit_blocks.into_iter().collect()
+    }
+}
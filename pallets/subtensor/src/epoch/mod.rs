@@ -1,3 +1,6 @@
 use super::*;
+pub mod consensus;
+pub mod fixture;
 pub mod math;
 pub mod run_epoch;
+pub mod telemetry;
@@ -137,6 +137,7 @@ impl<T: Config> Pallet<T> {
         let new_validator_permit = extract_from_sorted_terms!(terms_sorted, new_validator_permit);
         let stake_weight = extract_from_sorted_terms!(terms_sorted, stake_weight);
 
+        Self::update_adaptive_max_weight_limit(netuid, &active);
         Active::<T>::insert(netuid, active.clone());
         Emission::<T>::insert(netuid, emission);
         Rank::<T>::insert(netuid, rank);
@@ -145,8 +146,14 @@ impl<T: Config> Pallet<T> {
         Dividends::<T>::insert(netuid, dividend);
         PruningScores::<T>::insert(netuid, pruning_score);
         ValidatorTrust::<T>::insert(netuid, validator_trust);
+        Self::deposit_validator_permit_events(
+            netuid,
+            &Self::get_validator_permit(netuid),
+            &new_validator_permit,
+        );
         ValidatorPermit::<T>::insert(netuid, new_validator_permit);
         StakeWeight::<T>::insert(netuid, stake_weight);
+        Self::update_neuron_statuses(netuid, &active);
     }
 
     /// Calculates reward consensus and returns the emissions for uids/hotkeys in a given `netuid`.
@@ -184,8 +191,8 @@ impl<T: Config> Pallet<T> {
         let last_update: Vec<u64> = Self::get_last_update(netuid_index);
         log::trace!("Last update: {:?}", &last_update);
 
-        // Inactive mask.
-        let inactive: Vec<bool> = last_update
+        // Inactive mask, heartbeats count as activity.
+        let inactive: Vec<bool> = Self::get_last_activity(netuid, &last_update)
             .iter()
             .map(|updated| updated.saturating_add(activity_cutoff) < current_block)
             .collect();
@@ -267,9 +274,9 @@ impl<T: Config> Pallet<T> {
         let max_allowed_validators: u16 = Self::get_max_allowed_validators(netuid);
         log::trace!("max_allowed_validators: {max_allowed_validators:?}");
 
-        // Get new validator permits.
+        // Get new validator permits, of the largest stakes meeting the permit threshold.
         let new_validator_permits: Vec<bool> =
-            is_topk_nonzero(&stake, max_allowed_validators as usize);
+            Self::get_new_validator_permits(netuid, &total_stake, &stake, max_allowed_validators);
         log::trace!("new_validator_permits: {new_validator_permits:?}");
 
         // ==================
@@ -303,11 +310,13 @@ impl<T: Config> Pallet<T> {
         inplace_mask_rows(&validator_forbids, &mut weights);
         log::trace!("W (permit): {:?}", &weights);
 
-        // Remove self-weight by masking diagonal; keep owner_uid self-weight.
-        if let Some(owner_uid) = owner_uid {
-            inplace_mask_diag_except_index(&mut weights, owner_uid);
-        } else {
-            inplace_mask_diag(&mut weights);
+        // Remove self-weight by masking diagonal; keep owner_uid self-weight unless the subnet
+        // excludes all self-weights.
+        match owner_uid {
+            Some(owner_uid) if !Self::get_self_weights_excluded(netuid) => {
+                inplace_mask_diag_except_index(&mut weights, owner_uid);
+            }
+            _ => inplace_mask_diag(&mut weights),
         }
 
         inplace_mask_diag(&mut weights);
@@ -317,6 +326,11 @@ impl<T: Config> Pallet<T> {
         inplace_mask_matrix(&outdated, &mut weights);
         log::trace!("W (permit+diag+outdate): {:?}", &weights);
 
+        // Mask expired weights: remove weights not updated within the weight expiry.
+        let expired: Vec<bool> = Self::get_expired_weights(netuid, &last_update, current_block);
+        inplace_mask_rows(&expired, &mut weights);
+        log::trace!("W (permit+diag+outdate+expired): {:?}", &weights);
+
         // Normalize remaining weights.
         inplace_row_normalize(&mut weights);
         log::trace!("W (mask+norm): {:?}", &weights);
@@ -330,8 +344,9 @@ impl<T: Config> Pallet<T> {
 
         // Consensus majority ratio, e.g. 51%.
         let kappa: I32F32 = Self::get_float_kappa(netuid);
-        // Calculate consensus as stake-weighted median of weights.
-        let consensus: Vec<I32F32> = weighted_median_col(&active_stake, &weights, kappa);
+        // Calculate consensus with the engine of the subnet, Yuma by default.
+        let consensus: Vec<I32F32> =
+            Self::get_consensus_engine(netuid).consensus_dense(&active_stake, &weights, kappa);
         // Clip weights at majority consensus.
         let mut clipped_weights: Vec<Vec<I32F32>> = weights.clone();
         inplace_col_clip(&mut clipped_weights, &consensus);
@@ -541,6 +556,7 @@ impl<T: Config> Pallet<T> {
         Dividends::<T>::insert(netuid, cloned_dividends);
         PruningScores::<T>::insert(netuid, cloned_pruning_scores);
         ValidatorTrust::<T>::insert(netuid, cloned_validator_trust);
+        Self::deposit_validator_permit_events(netuid, &validator_permits, &new_validator_permits);
         ValidatorPermit::<T>::insert(netuid, new_validator_permits.clone());
 
         new_validator_permits
@@ -632,8 +648,8 @@ impl<T: Config> Pallet<T> {
         let last_update: Vec<u64> = Self::get_last_update(netuid_index);
         log::trace!("Last update: {:?}", &last_update);
 
-        // Inactive mask.
-        let inactive: Vec<bool> = last_update
+        // Inactive mask, heartbeats count as activity.
+        let inactive: Vec<bool> = Self::get_last_activity(netuid, &last_update)
             .iter()
             .map(|updated| updated.saturating_add(activity_cutoff) < current_block)
             .collect();
@@ -688,9 +704,9 @@ impl<T: Config> Pallet<T> {
         let max_allowed_validators: u16 = Self::get_max_allowed_validators(netuid);
         log::trace!("max_allowed_validators: {max_allowed_validators:?}");
 
-        // Get new validator permits.
+        // Get new validator permits, of the largest stakes meeting the permit threshold.
         let new_validator_permits: Vec<bool> =
-            is_topk_nonzero(&stake, max_allowed_validators as usize);
+            Self::get_new_validator_permits(netuid, &total_stake, &stake, max_allowed_validators);
         log::trace!("new_validator_permits: {new_validator_permits:?}");
 
         // ==================
@@ -723,12 +739,14 @@ impl<T: Config> Pallet<T> {
         weights = mask_rows_sparse(&validator_forbids, &weights);
         log::trace!("Weights (permit): {:?}", &weights);
 
-        // Remove self-weight by masking diagonal; keep owner_uid self-weight.
-        if let Some(owner_uid) = owner_uid {
-            weights = mask_diag_sparse_except_index(&weights, owner_uid);
-        } else {
-            weights = mask_diag_sparse(&weights);
-        }
+        // Remove self-weight by masking diagonal; keep owner_uid self-weight unless the subnet
+        // excludes all self-weights.
+        weights = match owner_uid {
+            Some(owner_uid) if !Self::get_self_weights_excluded(netuid) => {
+                mask_diag_sparse_except_index(&weights, owner_uid)
+            }
+            _ => mask_diag_sparse(&weights),
+        };
         log::trace!("Weights (permit+diag): {:?}", &weights);
 
         // Remove weights referring to deregistered neurons.
@@ -740,6 +758,11 @@ impl<T: Config> Pallet<T> {
         );
         log::trace!("Weights (permit+diag+outdate): {:?}", &weights);
 
+        // Remove weights not updated within the weight expiry.
+        let expired: Vec<bool> = Self::get_expired_weights(netuid, &last_update, current_block);
+        weights = mask_rows_sparse(&expired, &weights);
+        log::trace!("Weights (permit+diag+outdate+expired): {:?}", &weights);
+
         if Self::get_commit_reveal_weights_enabled(netuid) {
             let mut commit_blocks: Vec<u64> = vec![u64::MAX; n as usize]; // MAX ⇒ “no active commit”
 
@@ -796,8 +819,9 @@ impl<T: Config> Pallet<T> {
 
         // Consensus majority ratio, e.g. 51%.
         let kappa: I32F32 = Self::get_float_kappa(netuid);
-        // Calculate consensus as stake-weighted median of weights.
-        let consensus: Vec<I32F32> = weighted_median_col_sparse(&active_stake, &weights, n, kappa);
+        // Calculate consensus with the engine of the subnet, Yuma by default.
+        let consensus: Vec<I32F32> =
+            Self::get_consensus_engine(netuid).consensus_sparse(&active_stake, &weights, n, kappa);
         log::trace!("Consensus: {:?}", &consensus);
 
         // Clip weights at majority consensus.
@@ -1104,6 +1128,82 @@ impl<T: Config> Pallet<T> {
             .safe_div(I32F32::saturating_from_num(u16::MAX))
     }
 
+    /// Returns the new validator permits of `netuid`: the `max_allowed_validators` largest
+    /// non-zero stakes, among the neurons with at least `ValidatorPermitMinStake` stake.
+    pub fn get_new_validator_permits(
+        netuid: NetUid,
+        total_stake: &[I64F64],
+        stake: &[I32F32],
+        max_allowed_validators: u16,
+    ) -> Vec<bool> {
+        let min_stake = Self::get_validator_permit_min_stake(netuid);
+        let eligible_stake: Vec<I32F32> = stake
+            .iter()
+            .zip(total_stake)
+            .map(|(&s, &total)| {
+                if fixed64_to_u64(total) < min_stake {
+                    return I32F32::from(0);
+                }
+                s
+            })
+            .collect();
+        is_topk_nonzero(&eligible_stake, max_allowed_validators as usize)
+    }
+
+    /// Deposits an event for every uid of `netuid` whose validator permit changed from
+    /// `old_permits` to `new_permits`.
+    pub fn deposit_validator_permit_events(
+        netuid: NetUid,
+        old_permits: &[bool],
+        new_permits: &[bool],
+    ) {
+        for (uid, &new_permit) in new_permits.iter().enumerate() {
+            let old_permit = old_permits.get(uid).copied().unwrap_or_default();
+            let uid = uid as u16;
+            if new_permit && !old_permit {
+                Self::deposit_event(Event::ValidatorPermitGranted { netuid, uid });
+            } else if !new_permit && old_permit {
+                Self::deposit_event(Event::ValidatorPermitRevoked { netuid, uid });
+            }
+        }
+    }
+
+    /// Scales the max weight limit of `netuid` with its number of `active` uids, if the subnet
+    /// has an adaptive max weight limit. The limit is the adaptive multiple of the uniform weight
+    /// over the active uids, and there is no limit without active uids.
+    pub fn update_adaptive_max_weight_limit(netuid: NetUid, active: &[bool]) {
+        let multiple = Self::get_adaptive_max_weight_limit(netuid);
+        if multiple == 0 {
+            return;
+        }
+
+        let active_n = active.iter().filter(|&&is_active| is_active).count() as u64;
+        let max_weight_limit = u64::from(multiple)
+            .saturating_mul(u64::from(u16::MAX))
+            .checked_div(active_n)
+            .unwrap_or(u64::MAX)
+            .min(u64::from(u16::MAX)) as u16;
+        if max_weight_limit != Self::get_max_weight_limit(netuid) {
+            Self::set_max_weight_limit(netuid, max_weight_limit);
+        }
+    }
+
+    /// Which uids of `netuid` last updated their weights in `last_update` longer than the weight
+    /// expiry of the subnet before `current_block`. Weights never expire with an expiry of zero.
+    pub fn get_expired_weights(
+        netuid: NetUid,
+        last_update: &[u64],
+        current_block: u64,
+    ) -> Vec<bool> {
+        let weight_expiry = Self::get_weight_expiry_blocks(netuid);
+        last_update
+            .iter()
+            .map(|updated| {
+                weight_expiry > 0 && updated.saturating_add(weight_expiry) < current_block
+            })
+            .collect()
+    }
+
     pub fn get_block_at_registration(netuid: NetUid) -> Vec<u64> {
         let n = Self::get_subnetwork_n(netuid);
         let block_at_registration: Vec<u64> = (0..n)
@@ -1128,7 +1228,7 @@ impl<T: Config> Pallet<T> {
         {
             for (uid_j, weight_ij) in weights_i.iter().filter(|(uid_j, _)| *uid_j < n as u16) {
                 if let Some(row) = weights.get_mut(uid_i as usize) {
-                    row.push((*uid_j, I32F32::saturating_from_num(*weight_ij)));
+                    row.push((uid_j, I32F32::saturating_from_num(weight_ij)));
                 } else {
                     log::error!("math error: uid_i {uid_i:?} is filtered to be less than n");
                 }
@@ -1145,10 +1245,7 @@ impl<T: Config> Pallet<T> {
         for (uid_i, weights_vec) in
             Weights::<T>::iter_prefix(netuid_index).filter(|(uid_i, _)| *uid_i < n as u16)
         {
-            for (uid_j, weight_ij) in weights_vec
-                .into_iter()
-                .filter(|(uid_j, _)| *uid_j < n as u16)
-            {
+            for (uid_j, weight_ij) in weights_vec.iter().filter(|(uid_j, _)| *uid_j < n as u16) {
                 if let Some(cell) = weights
                     .get_mut(uid_i as usize)
                     .and_then(|row| row.get_mut(uid_j as usize))
@@ -1560,18 +1657,7 @@ impl<T: Config> Pallet<T> {
             Error::<T>::LiquidAlphaDisabled
         );
 
-        let max_u16: u32 = u16::MAX as u32; // 65535
-        let min_alpha_low: u16 = (max_u16.safe_div(40)) as u16; // 1638
-        let min_alpha_high: u16 = min_alpha_low;
-
-        ensure!(alpha_high >= min_alpha_high, Error::<T>::AlphaHighTooLow);
-
-        ensure!(
-            alpha_low >= min_alpha_low && alpha_low <= alpha_high,
-            Error::<T>::AlphaLowOutOfRange
-        );
-
-        AlphaValues::<T>::insert(netuid, (alpha_low, alpha_high));
+        Self::set_alpha_values(netuid, alpha_low, alpha_high)?;
 
         log::debug!(
             "AlphaValuesSet( netuid: {netuid:?}, AlphaLow: {alpha_low:?}, AlphaHigh: {alpha_high:?} ) ",
@@ -1612,4 +1698,27 @@ impl<T: Config> Pallet<T> {
 
         Ok(())
     }
+
+    /// Clears the bonds of every mechanism of `netuid`, e.g. after a redesign of its incentive
+    /// mechanism left historical bonds that do not fit the new scoring.
+    pub fn do_reset_subnet_bonds(origin: T::RuntimeOrigin, netuid: NetUid) -> DispatchResult {
+        let maybe_owner = Self::ensure_sn_owner_or_root_with_limits(
+            origin,
+            netuid,
+            &[TransactionType::BondsReset],
+        )?;
+        ensure!(
+            !netuid.is_root() && Self::if_subnet_exist(netuid),
+            Error::<T>::SubnetNotExists
+        );
+
+        for mecid in 0..u8::from(MechanismCountCurrent::<T>::get(netuid)) {
+            let netuid_index = Self::get_mechanism_storage_index(netuid, MechId::from(mecid));
+            let _ = Bonds::<T>::clear_prefix(netuid_index, u32::MAX, None);
+        }
+
+        Self::record_owner_rl(maybe_owner, netuid, &[TransactionType::BondsReset]);
+        Self::deposit_event(Event::SubnetBondsReset { netuid });
+        Ok(())
+    }
 }
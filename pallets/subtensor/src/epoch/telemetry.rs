@@ -0,0 +1,147 @@
+//! Consensus telemetry.
+//!
+//! After every epoch of a subnet, a compact summary of the health of its consensus is deposited
+//! as a header digest item. Monitoring systems can follow these items from block headers alone
+//! and alert on consensus degradation without syncing state.
+//!
+//! The digest item is a `DigestItem::Other` holding `CONSENSUS_TELEMETRY_DIGEST_ID` followed by
+//! the SCALE encoded `ConsensusTelemetry`. Metrics are computed on the main mechanism.
+
+use super::*;
+use alloc::collections::BTreeMap;
+use safe_math::*;
+use sp_runtime::DigestItem;
+use substrate_fixed::types::{I64F64, U64F64};
+use subtensor_runtime_common::{NetUid, NetUidStorageIndex};
+
+/// Prefix identifying consensus telemetry digest items.
+pub const CONSENSUS_TELEMETRY_DIGEST_ID: [u8; 4] = *b"sctl";
+
+/// Bonds of a subnet by validator uid.
+pub type BondsSnapshot = BTreeMap<u16, BTreeMap<u16, u16>>;
+
+#[freeze_struct("f653e93c50e6759")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct ConsensusTelemetry {
+    /// The subnet the epoch was run on.
+    pub netuid: NetUid,
+    /// Block at which the epoch was run.
+    pub block: u64,
+    /// Share of validator stake that set weights within the activity cutoff, normalized to
+    /// u16::MAX.
+    pub weights_stake_share: u16,
+    /// Mean number of blocks since validators with weights last set them.
+    pub mean_weight_age: u64,
+    /// Number of neurons with a validator permit.
+    pub validators: u16,
+    /// Number of neurons without a validator permit.
+    pub miners: u16,
+    /// Share of bond mass that changed during the epoch, normalized to u16::MAX.
+    pub bond_turnover: u16,
+}
+
+impl<T: Config> Pallet<T> {
+    /// The bonds of the main mechanism of `netuid`.
+    pub fn snapshot_bonds(netuid: NetUid) -> BondsSnapshot {
+        Bonds::<T>::iter_prefix(NetUidStorageIndex::from(netuid))
+            .map(|(uid, row)| (uid, row.into_iter().collect()))
+            .collect()
+    }
+
+    /// Deposits the consensus telemetry digest item of the epoch just run on `netuid`.
+    pub fn deposit_consensus_telemetry(netuid: NetUid, bonds_before: &BondsSnapshot) {
+        let telemetry = Self::get_consensus_telemetry(netuid, bonds_before);
+        log::debug!("consensus telemetry: {telemetry:?}");
+        frame_system::Pallet::<T>::deposit_log(DigestItem::Other(
+            (CONSENSUS_TELEMETRY_DIGEST_ID, telemetry).encode(),
+        ));
+    }
+
+    pub fn get_consensus_telemetry(
+        netuid: NetUid,
+        bonds_before: &BondsSnapshot,
+    ) -> ConsensusTelemetry {
+        let netuid_index = NetUidStorageIndex::from(netuid);
+        let current_block = Self::get_current_block_as_u64();
+        let activity_cutoff = u64::from(Self::get_activity_cutoff(netuid));
+        let n = Self::get_subnetwork_n(netuid);
+        let validator_permit = Self::get_validator_permit(netuid);
+        let last_update = Self::get_last_update(netuid_index);
+        let (stake, _, _) = Self::get_stake_weights_for_network(netuid);
+
+        let mut validators: u16 = 0;
+        let mut validator_stake = I64F64::saturating_from_num(0);
+        let mut weights_stake = I64F64::saturating_from_num(0);
+        let mut weight_age_sum: u64 = 0;
+        let mut weight_setters: u64 = 0;
+        for uid in 0..n {
+            if !validator_permit.get(uid as usize).copied().unwrap_or(false) {
+                continue;
+            }
+            validators = validators.saturating_add(1);
+            let uid_stake = stake
+                .get(uid as usize)
+                .copied()
+                .unwrap_or(I64F64::saturating_from_num(0));
+            validator_stake = validator_stake.saturating_add(uid_stake);
+
+            if Weights::<T>::get(netuid_index, uid).is_empty() {
+                continue;
+            }
+            let age = current_block
+                .saturating_sub(last_update.get(uid as usize).copied().unwrap_or_default());
+            weight_age_sum = weight_age_sum.saturating_add(age);
+            weight_setters = weight_setters.saturating_add(1);
+            if age <= activity_cutoff {
+                weights_stake = weights_stake.saturating_add(uid_stake);
+            }
+        }
+
+        let weights_stake_share = weights_stake
+            .safe_div(validator_stake)
+            .saturating_mul(I64F64::saturating_from_num(u16::MAX))
+            .saturating_to_num::<u16>();
+
+        ConsensusTelemetry {
+            netuid,
+            block: current_block,
+            weights_stake_share,
+            mean_weight_age: weight_age_sum
+                .checked_div(weight_setters)
+                .unwrap_or_default(),
+            validators,
+            miners: n.saturating_sub(validators),
+            bond_turnover: Self::get_bond_turnover(bonds_before, &Self::snapshot_bonds(netuid)),
+        }
+    }
+
+    /// The changed bond mass between two snapshots relative to their total bond mass, normalized
+    /// to u16::MAX.
+    fn get_bond_turnover(before: &BondsSnapshot, after: &BondsSnapshot) -> u16 {
+        let mut changed: u64 = 0;
+        let mut total: u64 = 0;
+        let empty = BTreeMap::new();
+        for uid in before
+            .keys()
+            .chain(after.keys().filter(|uid| !before.contains_key(uid)))
+        {
+            let row_before = before.get(uid).unwrap_or(&empty);
+            let row_after = after.get(uid).unwrap_or(&empty);
+            for target in row_before.keys().chain(
+                row_after
+                    .keys()
+                    .filter(|target| !row_before.contains_key(target)),
+            ) {
+                let bond_before = u64::from(row_before.get(target).copied().unwrap_or_default());
+                let bond_after = u64::from(row_after.get(target).copied().unwrap_or_default());
+                changed = changed.saturating_add(bond_before.abs_diff(bond_after));
+                total = total.saturating_add(bond_before.saturating_add(bond_after));
+            }
+        }
+
+        U64F64::saturating_from_num(changed)
+            .safe_div(U64F64::saturating_from_num(total))
+            .saturating_mul(U64F64::saturating_from_num(u16::MAX))
+            .saturating_to_num::<u16>()
+    }
+}
@@ -42,7 +42,7 @@ pub mod staking;
 pub mod subnets;
 pub mod swap;
 pub mod utils;
-use crate::utils::rate_limiting::{Hyperparameter, TransactionType};
+use crate::utils::rate_limiting::{Hyperparameter, HyperparameterGroup, TransactionType};
 use macros::{config, dispatches, errors, events, genesis, hooks};
 
 #[cfg(test)]
@@ -65,21 +65,48 @@ pub const MAX_CRV3_COMMIT_SIZE_BYTES: u32 = 5000;
 #[frame_support::pallet]
 pub mod pallet {
     use crate::RateLimitKey;
+    use crate::coinbase::difficulty_controller::{
+        DifficultyControllerGains, DifficultyControllerState,
+    };
+    use crate::coinbase::emission_telemetry::EmissionTelemetry;
+    use crate::coinbase::owner_cut_vesting::OwnerCutVesting;
+    use crate::epoch::consensus::ConsensusEngineKind;
     use crate::migrations;
+    use crate::staking::dissolution_payout::DissolutionPayout;
+    use crate::staking::sliced_unstake::{SlicedUnstake, SlicedUnstakeId};
+    use crate::staking::stake_origin::StakeOriginTag;
+    use crate::subnets::attestations::{MAX_SUBNET_ATTESTATIONS, SubnetAttestation};
+    use crate::subnets::hotkey_lease::{HotkeyLease, HotkeyLeaseOffer};
+    use crate::subnets::hyperparameter_vote::{HyperparameterChange, HyperparameterProposal};
     use crate::subnets::leasing::{LeaseId, SubnetLeaseOf};
+    use crate::subnets::merkle_weights::MerkleWeightsUpload;
+    use crate::subnets::neuron_status::NeuronStatus;
+    use crate::subnets::registration_access::{RegistrationAccess, RegistrationAccount};
+    use crate::subnets::registration_auction::RegistrationBid;
+    use crate::subnets::registration_deposit::RegistrationDeposit;
+    use crate::subnets::registration_queue::QueuedRegistration;
+    use crate::subnets::staged_hyperparams::StagedHyperparameterBundle;
+    use crate::subnets::treasury::SubnetTreasurySpend;
+    use crate::subnets::weight_row::WeightRow;
+    use crate::swap::coldkey_guardians::ColdkeyGuardianSet;
+    use crate::swap::key_rotation_log::KeyRotationRecord;
+    use crate::utils::evm_logs::MAX_EVM_LOGS_PER_BLOCK;
+    use crate::utils::reserved_accounts::ReservedAccountKind;
     use frame_support::Twox64Concat;
     use frame_support::{
         BoundedVec,
         dispatch::GetDispatchInfo,
         pallet_prelude::{DispatchResult, StorageMap, ValueQuery, *},
         traits::{
-            OriginTrait, QueryPreimage, StorePreimage, UnfilteredDispatchable, tokens::fungible,
+            OriginTrait, QueryPreimage, StorePreimage, UnfilteredDispatchable,
+            schedule::v3::Anon as ScheduleAnon, tokens::fungible,
         },
     };
     use frame_system::pallet_prelude::*;
     use pallet_drand::types::RoundNumber;
     use runtime_common::prod_or_fast;
-    use sp_core::{ConstU32, H160, H256};
+    use sp_core::{ConstU32, H160, H256, sr25519};
+    use sp_runtime::Percent;
     use sp_runtime::traits::{Dispatchable, TrailingZeroInput};
     use sp_std::collections::vec_deque::VecDeque;
     use sp_std::vec;
@@ -113,6 +140,8 @@ pub mod pallet {
     pub const MIN_COMMIT_REVEAL_PEROIDS: u64 = 1;
     /// Maximum commit reveal periods
     pub const MAX_COMMIT_REVEAL_PEROIDS: u64 = 100;
+    /// Maximum number of unrevealed weight commits of a hotkey on a mechanism
+    pub const MAX_PENDING_WEIGHT_COMMITS: usize = 10;
 
     #[pallet::pallet]
     #[pallet::without_storage_info]
@@ -128,6 +157,13 @@ pub mod pallet {
     /// local one
     pub type LocalCallOf<T> = <T as Config>::RuntimeCall;
 
+    /// Address of a task scheduled with the scheduler of the pallet.
+    pub type ScheduledTaskAddressOf<T> = <<T as Config>::Scheduler as ScheduleAnon<
+        BlockNumberFor<T>,
+        LocalCallOf<T>,
+        PalletsOriginOf<T>,
+    >>::Address;
+
     /// Data structure for Axon information.
     #[crate::freeze_struct("3545cfb0cac4c1f5")]
     #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
@@ -717,11 +753,26 @@ pub mod pallet {
         0
     }
     #[pallet::type_value]
+    /// Default minimum coldkey balance or stake for free extrinsics.
+    pub fn DefaultFreeExtrinsicMinBalance<T: Config>() -> TaoCurrency {
+        TaoCurrency::ZERO
+    }
+    #[pallet::type_value]
     /// Default Reveal Period Epochs
     pub fn DefaultRevealPeriodEpochs<T: Config>() -> u64 {
         1
     }
     #[pallet::type_value]
+    /// Default bounds of the commit reveal period.
+    pub fn DefaultCommitRevealPeriodBounds<T: Config>() -> (u64, u64) {
+        (MIN_COMMIT_REVEAL_PEROIDS, MAX_COMMIT_REVEAL_PEROIDS)
+    }
+    #[pallet::type_value]
+    /// Default minimum reveal window.
+    pub fn DefaultMinRevealWindow<T: Config>() -> u64 {
+        10
+    }
+    #[pallet::type_value]
     /// Value definition for vector of u16.
     pub fn EmptyU16Vec<T: Config>() -> Vec<u16> {
         vec![]
@@ -743,8 +794,8 @@ pub mod pallet {
     }
     #[pallet::type_value]
     /// Value definition for weights with vector of (u16, u16).
-    pub fn DefaultWeights<T: Config>() -> Vec<(u16, u16)> {
-        vec![]
+    pub fn DefaultWeights<T: Config>() -> WeightRow {
+        WeightRow::default()
     }
     #[pallet::type_value]
     /// Default value for key with type T::AccountId derived from trailing zeroes.
@@ -796,6 +847,11 @@ pub mod pallet {
         T::InitialSenateRequiredStakePercentage::get()
     }
     #[pallet::type_value]
+    /// Default senate hysteresis, 5% of the stake of the member replaced.
+    pub fn DefaultSenateHysteresis<T: Config>() -> u16 {
+        u16::MAX / 20
+    }
+    #[pallet::type_value]
     /// -- ITEM (switches liquid alpha on)
     pub fn DefaultLiquidAlpha<T: Config>() -> bool {
         false
@@ -811,6 +867,11 @@ pub mod pallet {
         (45875, 58982)
     }
     #[pallet::type_value]
+    /// Default bounds of the liquid alpha values, (min alpha_low, max alpha_high).
+    pub fn DefaultLiquidAlphaBounds<T: Config>() -> (u16, u16) {
+        (u16::MAX / 40, u16::MAX)
+    }
+    #[pallet::type_value]
     /// Default value for coldkey swap schedule duration
     pub fn DefaultColdkeySwapScheduleDuration<T: Config>() -> BlockNumberFor<T> {
         T::InitialColdkeySwapScheduleDuration::get()
@@ -922,6 +983,12 @@ pub mod pallet {
     pub type OwnerHyperparamRateLimit<T: Config> =
         StorageValue<_, u16, ValueQuery, DefaultOwnerHyperparamRateLimit<T>>;
 
+    #[pallet::storage]
+    /// --- DMAP ( netuid, group ) --> Number of epochs used to rate limit subnet owner updates of
+    /// the hyperparameters of the group, instead of `OwnerHyperparamRateLimit`
+    pub type OwnerHyperparamGroupRateLimit<T: Config> =
+        StorageDoubleMap<_, Identity, NetUid, Identity, HyperparameterGroup, u16, OptionQuery>;
+
     #[pallet::storage]
     pub type ColdkeySwapScheduleDuration<T: Config> =
         StorageValue<_, BlockNumberFor<T>, ValueQuery, DefaultColdkeySwapScheduleDuration<T>>;
@@ -938,6 +1005,11 @@ pub mod pallet {
     pub type SenateRequiredStakePercentage<T> =
         StorageValue<_, u64, ValueQuery, DefaultSenateRequiredStakePercentage<T>>;
 
+    #[pallet::storage]
+    /// --- ITEM ( senate_hysteresis ) -- root stake a hotkey needs above a senate member to
+    /// replace it, in `u16::MAX` parts of the stake of the member.
+    pub type SenateHysteresis<T> = StorageValue<_, u16, ValueQuery, DefaultSenateHysteresis<T>>;
+
     #[pallet::storage]
     /// --- DMap ( netuid, coldkey ) --> blocknumber | last hotkey swap on network.
     pub type LastHotkeySwapOnNetuid<T: Config> = StorageDoubleMap<
@@ -1062,6 +1134,16 @@ pub mod pallet {
         ValueQuery,
         DefaultZeroTao<T>,
     >;
+    #[pallet::storage] // --- DMAP ( netuid, hotkey ) --> (window_start, current, previous) | Alpha dividends of this hotkey summed over the current and previous emission windows.
+    pub type DelegateEmissionWindows<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Blake2_128Concat,
+        T::AccountId,
+        (u64, AlphaCurrency, AlphaCurrency),
+        ValueQuery,
+    >;
 
     /// ==================
     /// ==== Coinbase ====
@@ -1101,6 +1183,10 @@ pub mod pallet {
     pub type TotalIssuance<T> = StorageValue<_, TaoCurrency, ValueQuery, DefaultTotalIssuance<T>>;
     #[pallet::storage] // --- ITEM ( total_stake )
     pub type TotalStake<T> = StorageValue<_, TaoCurrency, ValueQuery>;
+    #[pallet::storage] // --- ITEM ( network_treasury ) -- TAO held by the network treasury.
+    pub type NetworkTreasury<T> = StorageValue<_, TaoCurrency, ValueQuery>;
+    #[pallet::storage] // --- ITEM ( network_treasury_share ) -- share of fees and slashes kept.
+    pub type NetworkTreasuryShare<T> = StorageValue<_, u16, ValueQuery>;
     #[pallet::storage] // --- ITEM ( moving_alpha ) -- subnet moving alpha.
     pub type SubnetMovingAlpha<T> = StorageValue<_, I96F32, ValueQuery, DefaultMovingAlpha<T>>;
     #[pallet::storage] // --- MAP ( netuid ) --> moving_price | The subnet moving price.
@@ -1109,6 +1195,9 @@ pub mod pallet {
     #[pallet::storage] // --- MAP ( netuid ) --> total_volume | The total amount of TAO bought and sold since the start of the network.
     pub type SubnetVolume<T: Config> =
         StorageMap<_, Identity, NetUid, u128, ValueQuery, DefaultZeroU128<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> (window_start, current, previous) | TAO bought and sold summed over the current and previous trailing windows.
+    pub type SubnetVolumeWindows<T: Config> =
+        StorageMap<_, Identity, NetUid, (u64, u128, u128), ValueQuery>;
     #[pallet::storage] // --- MAP ( netuid ) --> tao_in_subnet | Returns the amount of TAO in the subnet.
     pub type SubnetTAO<T: Config> =
         StorageMap<_, Identity, NetUid, TaoCurrency, ValueQuery, DefaultZeroTao<T>>;
@@ -1171,6 +1260,32 @@ pub mod pallet {
         DefaultColdkeySwapScheduled<T>,
     >;
 
+    #[pallet::storage] // --- MAP ( cold ) --> task_address | Maps coldkey to the scheduler task of its swap.
+    pub type ColdkeySwapScheduledTask<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ScheduledTaskAddressOf<T>, OptionQuery>;
+
+    #[pallet::storage] // --- MAP ( cold ) --> guardian_set | Maps coldkey to the guardians that must approve its swaps.
+    pub type ColdkeyGuardians<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        ColdkeyGuardianSet<T::AccountId>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage] // --- MAP ( cold ) --> Vec<guardian> | Guardians that approved the scheduled swap of a coldkey.
+    pub type ColdkeySwapApprovals<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<T::AccountId>, ValueQuery>;
+
+    #[pallet::storage] // --- MAP ( account ) --> Vec<record> | The last key rotations involving an account.
+    pub type KeyRotationLog<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Vec<KeyRotationRecord<T::AccountId>>,
+        ValueQuery,
+    >;
+
     #[pallet::storage] // --- DMAP ( hot, netuid ) --> alpha | Returns the total amount of alpha a hotkey owns.
     pub type TotalHotkeyAlpha<T: Config> = StorageDoubleMap<
         _,
@@ -1290,6 +1405,10 @@ pub mod pallet {
     /// =================
     #[pallet::storage] // --- MAP ( netuid ) --> tempo
     pub type Tempo<T> = StorageMap<_, Identity, NetUid, u16, ValueQuery, DefaultTempo<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> target tempo in seconds, converted into Tempo
+    pub type TempoSeconds<T> = StorageMap<_, Identity, NetUid, u64, OptionQuery>;
+    #[pallet::storage] // --- ITEM ( slot duration in milliseconds TempoSeconds were converted with )
+    pub type TempoSlotDuration<T> = StorageValue<_, u64, ValueQuery>;
 
     /// ============================
     /// ==== Subnet Parameters =====
@@ -1298,6 +1417,22 @@ pub mod pallet {
     #[pallet::storage]
     pub type FirstEmissionBlockNumber<T: Config> =
         StorageMap<_, Identity, NetUid, u64, OptionQuery>;
+    /// --- MAP ( netuid ) --> whether root has paused the emission into the subnet
+    #[pallet::storage]
+    pub type SubnetEmissionPaused<T: Config> = StorageMap<_, Identity, NetUid, bool, ValueQuery>;
+    /// --- MAP ( block % EMISSION_TELEMETRY_LEN ) --> emission summary of the block
+    #[pallet::storage]
+    pub type EmissionTelemetryLog<T: Config> =
+        StorageMap<_, Identity, u64, EmissionTelemetry, OptionQuery>;
+    #[pallet::type_value]
+    /// Default maximum share of the block emission of a subnet, no cap.
+    pub fn DefaultMaxSubnetEmissionShare<T: Config>() -> Percent {
+        Percent::from_percent(100)
+    }
+    /// --- ITEM ( share ) --> maximum share of the block emission injected into a single subnet
+    #[pallet::storage]
+    pub type MaxSubnetEmissionShare<T: Config> =
+        StorageValue<_, Percent, ValueQuery, DefaultMaxSubnetEmissionShare<T>>;
     /// --- MAP ( netuid ) --> subnet mechanism
     #[pallet::storage]
     pub type SubnetMechanism<T: Config> =
@@ -1362,6 +1497,10 @@ pub mod pallet {
     pub type SubnetOwner<T: Config> =
         StorageMap<_, Identity, NetUid, T::AccountId, ValueQuery, DefaultSubnetOwner<T>>;
     #[pallet::storage]
+    /// --- MAP ( coldkey ) --> Vec<netuid> | Subnets owned by the coldkey, kept in sync with SubnetOwner
+    pub type SubnetsOwnedBy<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<NetUid>, ValueQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> subnet_owner_hotkey
     pub type SubnetOwnerHotkey<T: Config> =
         StorageMap<_, Identity, NetUid, T::AccountId, ValueQuery, DefaultSubnetOwner<T>>;
@@ -1407,6 +1546,15 @@ pub mod pallet {
     /// --- MAP ( netuid ) --> immunity_period
     pub type ImmunityPeriod<T> =
         StorageMap<_, Identity, NetUid, u16, ValueQuery, DefaultImmunityPeriod<T>>;
+    #[pallet::type_value]
+    /// Maximum number of uids of a subnet with an immunity override.
+    pub fn MaxNeuronImmunityOverrides<T: Config>() -> u32 {
+        16
+    }
+    /// --- DMAP ( netuid, uid ) --> immunity period of the uid, extending the subnet's
+    #[pallet::storage]
+    pub type NeuronImmunityOverride<T> =
+        StorageDoubleMap<_, Identity, NetUid, Identity, u16, u64, OptionQuery>;
     #[pallet::storage]
     /// --- MAP ( netuid ) --> activity_cutoff
     pub type ActivityCutoff<T> =
@@ -1416,6 +1564,10 @@ pub mod pallet {
     pub type MaxWeightsLimit<T> =
         StorageMap<_, Identity, NetUid, u16, ValueQuery, DefaultMaxWeightsLimit<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> multiple of the uniform weight over the active uids that the epoch
+    /// sets the max_weight_limit to, zero for a fixed max_weight_limit
+    pub type AdaptiveMaxWeightsLimit<T> = StorageMap<_, Identity, NetUid, u16, ValueQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> weights_version_key
     pub type WeightsVersionKey<T> =
         StorageMap<_, Identity, NetUid, u64, ValueQuery, DefaultWeightsVersionKey<T>>;
@@ -1428,6 +1580,9 @@ pub mod pallet {
     pub type MaxAllowedValidators<T> =
         StorageMap<_, Identity, NetUid, u16, ValueQuery, DefaultMaxAllowedValidators<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> minimum stake a neuron needs for a validator permit, zero for none
+    pub type ValidatorPermitMinStake<T> = StorageMap<_, Identity, NetUid, u64, ValueQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> adjustment_interval
     pub type AdjustmentInterval<T> =
         StorageMap<_, Identity, NetUid, u16, ValueQuery, DefaultAdjustmentInterval<T>>;
@@ -1447,6 +1602,10 @@ pub mod pallet {
     #[pallet::storage]
     pub type WeightsSetRateLimit<T> =
         StorageMap<_, Identity, NetUid, u64, ValueQuery, DefaultWeightsSetRateLimit<T>>;
+    /// --- DMAP ( netuid, uid ) --> weights_set_rate_limit of the uid, overriding the subnet's
+    #[pallet::storage]
+    pub type WeightsSetRateLimitOverride<T> =
+        StorageDoubleMap<_, Identity, NetUid, Identity, u16, u64, OptionQuery>;
     #[pallet::storage]
     /// --- MAP ( netuid ) --> validator_prune_len
     pub type ValidatorPruneLen<T> =
@@ -1464,10 +1623,31 @@ pub mod pallet {
     pub type AdjustmentAlpha<T: Config> =
         StorageMap<_, Identity, NetUid, u64, ValueQuery, DefaultAdjustmentAlpha<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> gains of the PID controller of the difficulty, if enabled
+    pub type DifficultyGains<T: Config> =
+        StorageMap<_, Identity, NetUid, DifficultyControllerGains, OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> state of the PID controller of the difficulty
+    pub type DifficultyControllerStates<T: Config> =
+        StorageMap<_, Identity, NetUid, DifficultyControllerState, ValueQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> commit reveal v2 weights are enabled
     pub type CommitRevealWeightsEnabled<T> =
         StorageMap<_, Identity, NetUid, bool, ValueQuery, DefaultCommitRevealWeightsEnabled<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> only timelocked weight commits, revealed by the chain, are accepted
+    pub type TimelockedWeightsOnly<T> = StorageMap<_, Identity, NetUid, bool, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> weights can be set by merkle root commits and sparse entries
+    pub type MerkleWeightsEnabled<T> = StorageMap<_, Identity, NetUid, bool, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> the epoch masks the self-weight of every validator, the owner included
+    pub type SelfWeightsExcluded<T> = StorageMap<_, Identity, NetUid, bool, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> number of blocks after their last update that the epoch ignores the
+    /// weights of a uid, zero to never expire them
+    pub type WeightExpiryBlocks<T> = StorageMap<_, Identity, NetUid, u64, ValueQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> Burn
     pub type Burn<T> = StorageMap<_, Identity, NetUid, TaoCurrency, ValueQuery, DefaultBurn<T>>;
     #[pallet::storage]
@@ -1512,6 +1692,14 @@ pub mod pallet {
         DefaultRAORecycledForRegistration<T>,
     >;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> TAO burned by the registrations on the subnet.
+    pub type RegistrationBurnedTao<T> =
+        StorageMap<_, Identity, NetUid, TaoCurrency, ValueQuery, DefaultZeroTao<T>>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> alpha emission recycled on the subnet.
+    pub type RecycledEmission<T> =
+        StorageMap<_, Identity, NetUid, AlphaCurrency, ValueQuery, DefaultZeroAlpha<T>>;
+    #[pallet::storage]
     /// --- ITEM ( tx_rate_limit )
     pub type TxRateLimit<T> = StorageValue<_, u64, ValueQuery, DefaultTxRateLimit<T>>;
     #[pallet::storage]
@@ -1531,10 +1719,18 @@ pub mod pallet {
     pub type Yuma3On<T> =
         StorageMap<_, Blake2_128Concat, NetUid, bool, ValueQuery, DefaultYuma3<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> consensus engine of the epoch
+    pub type SubnetConsensusEngine<T> =
+        StorageMap<_, Identity, NetUid, ConsensusEngineKind, ValueQuery>;
+    #[pallet::storage]
     ///  MAP ( netuid ) --> (alpha_low, alpha_high)
     pub type AlphaValues<T> =
         StorageMap<_, Identity, NetUid, (u16, u16), ValueQuery, DefaultAlphaValues<T>>;
     #[pallet::storage]
+    /// --- ITEM ( min_alpha, max_alpha ) --> Bounds of the liquid alpha values set by subnet owners
+    pub type LiquidAlphaBounds<T> =
+        StorageValue<_, (u16, u16), ValueQuery, DefaultLiquidAlphaBounds<T>>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> If subtoken trading enabled
     pub type SubtokenEnabled<T> =
         StorageMap<_, Identity, NetUid, bool, ValueQuery, DefaultFalse<T>>;
@@ -1590,6 +1786,10 @@ pub mod pallet {
     pub type Active<T: Config> =
         StorageMap<_, Identity, NetUid, Vec<bool>, ValueQuery, EmptyBoolVec<T>>;
     #[pallet::storage]
+    /// --- MAP ( netuid ) --> neuron_statuses
+    pub type NeuronStatuses<T: Config> =
+        StorageMap<_, Identity, NetUid, Vec<NeuronStatus>, ValueQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> rank
     pub type Rank<T: Config> =
         StorageMap<_, Identity, NetUid, Vec<u16>, ValueQuery, EmptyU16Vec<T>>;
@@ -1617,6 +1817,10 @@ pub mod pallet {
     pub type LastUpdate<T: Config> =
         StorageMap<_, Identity, NetUidStorageIndex, Vec<u64>, ValueQuery, EmptyU64Vec<T>>;
     #[pallet::storage]
+    /// --- DMAP ( netuid, uid ) --> block of the last heartbeat of the validator
+    pub type LastHeartbeat<T: Config> =
+        StorageDoubleMap<_, Identity, NetUid, Identity, u16, u64, OptionQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid ) --> validator_trust
     pub type ValidatorTrust<T: Config> =
         StorageMap<_, Identity, NetUid, Vec<u16>, ValueQuery, EmptyU16Vec<T>>;
@@ -1629,14 +1833,14 @@ pub mod pallet {
     pub type ValidatorPermit<T: Config> =
         StorageMap<_, Identity, NetUid, Vec<bool>, ValueQuery, EmptyBoolVec<T>>;
     #[pallet::storage]
-    /// --- DMAP ( netuid, uid ) --> weights
+    /// --- DMAP ( netuid, uid ) --> compressed weights
     pub type Weights<T: Config> = StorageDoubleMap<
         _,
         Identity,
         NetUidStorageIndex,
         Identity,
         u16,
-        Vec<(u16, u16)>,
+        WeightRow,
         ValueQuery,
         DefaultWeights<T>,
     >;
@@ -1665,6 +1869,10 @@ pub mod pallet {
         DefaultBlockAtRegistration<T>,
     >;
     #[pallet::storage]
+    /// --- DMAP ( netuid, uid ) --> alpha earned by the neuron since its registration
+    pub type NeuronLifetimeEmission<T: Config> =
+        StorageDoubleMap<_, Identity, NetUid, Identity, u16, AlphaCurrency, ValueQuery>;
+    #[pallet::storage]
     /// --- MAP ( netuid, hotkey ) --> axon_info
     pub type Axons<T: Config> = StorageDoubleMap<
         _,
@@ -1751,6 +1959,10 @@ pub mod pallet {
     /// ITEM( weights_min_stake )
     pub type StakeThreshold<T> = StorageValue<_, u64, ValueQuery, DefaultStakeThreshold<T>>;
     #[pallet::storage]
+    /// ITEM( free_extrinsic_min_balance ) | The balance or stake the coldkey of a hotkey needs for free extrinsics.
+    pub type FreeExtrinsicMinBalance<T> =
+        StorageValue<_, TaoCurrency, ValueQuery, DefaultFreeExtrinsicMinBalance<T>>;
+    #[pallet::storage]
     /// --- MAP (netuid, who) --> VecDeque<(hash, commit_block, first_reveal_block, last_reveal_block)> | Stores a queue of commits for an account on a given netuid.
     pub type WeightCommits<T: Config> = StorageDoubleMap<
         _,
@@ -1762,6 +1974,17 @@ pub mod pallet {
         OptionQuery,
     >;
     #[pallet::storage]
+    /// --- MAP (netuid, who) --> merkle weights commit of the account and the entries submitted so far
+    pub type MerkleWeightsUploads<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        NetUidStorageIndex,
+        Twox64Concat,
+        T::AccountId,
+        MerkleWeightsUpload,
+        OptionQuery,
+    >;
+    #[pallet::storage]
     /// MAP (netuid, epoch) → VecDeque<(who, commit_block, ciphertext, reveal_round)>
     /// Stores a queue of weight commits for an account on a given subnet.
     pub type TimelockedWeightCommits<T: Config> = StorageDoubleMap<
@@ -1815,6 +2038,17 @@ pub mod pallet {
     /// --- Map (netuid) --> Number of epochs allowed for commit reveal periods
     pub type RevealPeriodEpochs<T: Config> =
         StorageMap<_, Twox64Concat, NetUid, u64, ValueQuery, DefaultRevealPeriodEpochs<T>>;
+    #[pallet::storage]
+    /// --- ITEM ( min_periods, max_periods ) --> Bounds of the commit reveal periods set by subnet owners
+    pub type CommitRevealPeriodBounds<T: Config> =
+        StorageValue<_, (u64, u64), ValueQuery, DefaultCommitRevealPeriodBounds<T>>;
+    #[pallet::storage]
+    /// --- Map (netuid) --> Number of blocks at the start of the reveal epoch during which commits can be revealed, 0 for the whole epoch
+    pub type RevealWindow<T: Config> = StorageMap<_, Twox64Concat, NetUid, u64, ValueQuery>;
+    #[pallet::storage]
+    /// --- ITEM ( min_blocks ) --> Minimum reveal window subnet owners can set
+    pub type MinRevealWindow<T: Config> =
+        StorageValue<_, u64, ValueQuery, DefaultMinRevealWindow<T>>;
 
     #[pallet::storage]
     /// --- Map (coldkey, hotkey) --> u64 the last block at which stake was added/removed.
@@ -1850,6 +2084,15 @@ pub mod pallet {
     pub type AssociatedEvmAddress<T: Config> =
         StorageDoubleMap<_, Twox64Concat, NetUid, Twox64Concat, u16, (H160, u64), OptionQuery>;
 
+    #[pallet::storage]
+    /// --- ITEM ( (topics, data) ) | The EVM logs of the native events of the block, handed to
+    /// `EvmLogInterface` on finalize.
+    pub type PendingEvmLogs<T: Config> = StorageValue<
+        _,
+        BoundedVec<(Vec<H256>, Vec<u8>), ConstU32<MAX_EVM_LOGS_PER_BLOCK>>,
+        ValueQuery,
+    >;
+
     /// ========================
     /// ==== Subnet Leasing ====
     /// ========================
@@ -1919,12 +2162,402 @@ pub mod pallet {
     pub type MechanismEmissionSplit<T: Config> =
         StorageMap<_, Twox64Concat, NetUid, Vec<u16>, OptionQuery>;
 
+    /// ================================
+    /// ==== Staged Hyperparameters ====
+    /// ================================
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> hyperparameter bundle staged by the subnet owner
+    pub type StagedHyperparameters<T: Config> =
+        StorageMap<_, Identity, NetUid, StagedHyperparameterBundle, OptionQuery>;
+
+    /// ===========================
+    /// ==== Reserved Accounts ====
+    /// ===========================
+    #[pallet::storage]
+    /// --- MAP ( account ) --> kind of protocol account it is reserved for
+    pub type ReservedAccounts<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ReservedAccountKind, OptionQuery>;
+
+    /// ===========================
+    /// ==== Unstake Timelocks ====
+    /// ===========================
+    #[pallet::storage]
+    /// --- MAP ( coldkey ) --> number of blocks an unlock request of the coldkey takes to mature
+    pub type UnstakeTimelock<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( coldkey ) --> ( lowered timelock, block from which it applies )
+    pub type PendingUnstakeTimelock<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (u64, u64), OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( coldkey ) --> block from which the coldkey is unlocked
+    pub type UnstakeUnlockedAt<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, OptionQuery>;
+
+    /// =========================
+    /// ==== Sliced Unstakes ====
+    /// =========================
+    #[pallet::type_value]
+    /// Default fraction of the alpha reserve above which an unstake may be sliced, about 1%.
+    pub fn DefaultSlicedUnstakeDepthFraction<T: Config>() -> u16 {
+        655
+    }
+    #[pallet::storage]
+    /// ITEM( fraction of the alpha reserve, normalized to u16::MAX, above which an unstake may be sliced )
+    pub type SlicedUnstakeDepthFraction<T: Config> =
+        StorageValue<_, u16, ValueQuery, DefaultSlicedUnstakeDepthFraction<T>>;
+    #[pallet::storage]
+    /// --- MAP ( id ) --> queued sliced unstake
+    pub type SlicedUnstakes<T: Config> =
+        StorageMap<_, Twox64Concat, SlicedUnstakeId, SlicedUnstake<T::AccountId>, OptionQuery>;
+    #[pallet::storage]
+    /// ITEM( id of the next sliced unstake )
+    pub type NextSlicedUnstakeId<T: Config> = StorageValue<_, SlicedUnstakeId, ValueQuery>;
+
+    /// ============================
+    /// ==== Registration Churn ====
+    /// ============================
+    #[pallet::type_value]
+    /// Default window over which re-registrations of a coldkey count as churn.
+    pub fn DefaultChurnBurnWindow<T: Config>() -> u64 {
+        7200
+    }
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> number of re-registrations within the window tolerated before the burn escalates, 0 disables
+    pub type ChurnBurnThreshold<T: Config> = StorageMap<_, Identity, NetUid, u16, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> churn window in blocks, also the decay period of one escalation step
+    pub type ChurnBurnWindow<T: Config> =
+        StorageMap<_, Identity, NetUid, u64, ValueQuery, DefaultChurnBurnWindow<T>>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, coldkey ) --> block at which a hotkey of the coldkey was last deregistered
+    pub type ColdkeyLastDeregistration<T: Config> =
+        StorageDoubleMap<_, Identity, NetUid, Blake2_128Concat, T::AccountId, u64, OptionQuery>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, coldkey ) --> ( window start, re-registrations within the window )
+    pub type ColdkeyChurn<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Blake2_128Concat,
+        T::AccountId,
+        (u64, u16),
+        ValueQuery,
+    >;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, coldkey ) --> ( burn escalation exponent, block of the last escalation )
+    pub type ChurnBurnEscalation<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Blake2_128Concat,
+        T::AccountId,
+        (u8, u64),
+        OptionQuery,
+    >;
+
+    /// ====================================
+    /// ==== Coldkey Registration Quota ====
+    /// ====================================
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> registrations of a coldkey allowed per adjustment interval, 0 disables
+    pub type MaxRegistrationsPerColdkeyPerInterval<T: Config> =
+        StorageMap<_, Identity, NetUid, u16, ValueQuery>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, coldkey ) --> registrations of the coldkey this interval
+    pub type ColdkeyRegistrationsThisInterval<T: Config> =
+        StorageDoubleMap<_, Identity, NetUid, Blake2_128Concat, T::AccountId, u16, ValueQuery>;
+
+    /// ===============================
+    /// ==== Registration Auctions ====
+    /// ===============================
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> burned registrations are auctioned every adjustment interval
+    pub type BurnAuctionEnabled<T: Config> = StorageMap<_, Identity, NetUid, bool, ValueQuery>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, hotkey ) --> escrowed bid to register the hotkey at the end of the interval
+    pub type RegistrationBids<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Blake2_128Concat,
+        T::AccountId,
+        RegistrationBid<T::AccountId>,
+        OptionQuery,
+    >;
+
+    /// ============================
+    /// ==== Registration Queue ====
+    /// ============================
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> registrations on the full subnet are queued until an epoch prunes a slot
+    pub type RegistrationQueueEnabled<T: Config> =
+        StorageMap<_, Identity, NetUid, bool, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> registrations waiting for a slot, oldest first
+    pub type RegistrationQueue<T: Config> =
+        StorageMap<_, Identity, NetUid, Vec<QueuedRegistration<T::AccountId>>, ValueQuery>;
+
+    /// ===============================
+    /// ==== Registration Deposits ====
+    /// ===============================
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> share of the registration burn held as a deposit, in u16::MAX parts
+    pub type RegistrationDepositShare<T: Config> = StorageMap<_, Identity, NetUid, u16, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> epochs after which the registration deposit is refunded
+    pub type RegistrationDepositEpochs<T: Config> =
+        StorageMap<_, Identity, NetUid, u16, ValueQuery>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, uid ) --> registration deposit held for the neuron
+    pub type RegistrationDeposits<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Identity,
+        u16,
+        RegistrationDeposit<T::AccountId>,
+        OptionQuery,
+    >;
+
+    /// ==========================
+    /// ==== Stake Allowances ====
+    /// ==========================
+    #[pallet::storage]
+    /// --- NMAP ( netuid, coldkey, manager ) --> TAO the manager may stake on behalf of the coldkey
+    pub type StakeAllowance<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Identity, NetUid>,               // subnet
+            NMapKey<Blake2_128Concat, T::AccountId>, // cold
+            NMapKey<Blake2_128Concat, T::AccountId>, // manager
+        ),
+        TaoCurrency,
+        ValueQuery,
+    >;
+
+    /// ===========================
+    /// ==== Hotkey Blacklists ====
+    /// ===========================
+    #[pallet::storage]
+    /// --- DMAP ( netuid, hotkey ) --> block at which the blacklisting of the hotkey expires
+    pub type HotkeyBlacklist<T: Config> =
+        StorageDoubleMap<_, Identity, NetUid, Blake2_128Concat, T::AccountId, u64, OptionQuery>;
+
+    /// ===================================
+    /// ==== Registration Access Lists ====
+    /// ===================================
+    #[pallet::storage]
+    /// --- DMAP ( netuid, hotkey or coldkey ) --> whether it is allowed or denied registrations
+    pub type RegistrationAccessList<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Blake2_128Concat,
+        RegistrationAccount<T::AccountId>,
+        RegistrationAccess,
+        OptionQuery,
+    >;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> number of allowed entries of the registration access list
+    pub type RegistrationAllowlistSize<T: Config> =
+        StorageMap<_, Identity, NetUid, u16, ValueQuery>;
+
+    /// ==============================
+    /// ==== Scheduled Operations ====
+    /// ==============================
+    #[pallet::type_value]
+    /// Default maximum number of operations a coldkey may have scheduled at once.
+    pub fn DefaultMaxScheduledOperations<T: Config>() -> u32 {
+        8
+    }
+    #[pallet::storage]
+    /// ITEM( maximum number of operations a coldkey may have scheduled at once )
+    pub type MaxScheduledOperations<T: Config> =
+        StorageValue<_, u32, ValueQuery, DefaultMaxScheduledOperations<T>>;
+
+    /// ===========================
+    /// ==== Stake Origin Tags ====
+    /// ===========================
+    #[pallet::storage]
+    /// --- NMAP ( netuid, hot, cold ) --> origin of the stake of the position
+    pub type StakeOrigins<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Identity, NetUid>,               // subnet
+            NMapKey<Blake2_128Concat, T::AccountId>, // hot
+            NMapKey<Blake2_128Concat, T::AccountId>, // cold
+        ),
+        StakeOriginTag,
+        OptionQuery,
+    >;
+    #[pallet::storage]
+    /// --- MAP ( cold ) --> whether the coldkey is attested by root as belonging to an institution
+    pub type InstitutionalColdkeys<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+    /// =======================================
+    /// ==== Dissolution Payout Preference ====
+    /// =======================================
+    #[pallet::storage]
+    /// --- MAP ( cold ) --> where the dissolution payouts of the coldkey are credited
+    pub type DissolutionPayoutPreference<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, DissolutionPayout<T::AccountId>, ValueQuery>;
+
+    /// =================================
+    /// ==== Weight Setter Delegates ====
+    /// =================================
+    #[pallet::storage]
+    /// --- DMAP ( netuid, operator ) --> validator hotkey the operator sets weights for
+    pub type WeightSetterDelegate<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Blake2_128Concat,
+        T::AccountId,
+        T::AccountId,
+        OptionQuery,
+    >;
+
+    /// =======================
+    /// ==== Hotkey Leases ====
+    /// =======================
+    #[pallet::storage]
+    /// --- DMAP ( netuid, hotkey ) --> lease offered by the owner of the hotkey
+    pub type HotkeyLeaseOffers<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Blake2_128Concat,
+        T::AccountId,
+        HotkeyLeaseOffer<T::AccountId>,
+        OptionQuery,
+    >;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, hotkey ) --> lease of the validation rights of the hotkey
+    pub type HotkeyLeases<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Blake2_128Concat,
+        T::AccountId,
+        HotkeyLease<T::AccountId>,
+        OptionQuery,
+    >;
+
+    /// ========================
+    /// ==== Emission Split ====
+    /// ========================
+    #[pallet::type_value]
+    /// Default share of the subnet emission paid to miners.
+    pub fn DefaultMinerEmissionShare<T: Config>() -> Percent {
+        Percent::from_percent(50)
+    }
+    #[pallet::type_value]
+    /// Default bounds of the miner emission share, (min, max).
+    pub fn DefaultMinerEmissionShareBounds<T: Config>() -> (Percent, Percent) {
+        (Percent::from_percent(40), Percent::from_percent(60))
+    }
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> share of the subnet emission paid to miners, the rest to validators
+    pub type MinerEmissionShare<T: Config> =
+        StorageMap<_, Identity, NetUid, Percent, ValueQuery, DefaultMinerEmissionShare<T>>;
+    #[pallet::storage]
+    /// --- ITEM ( min, max ) --> bounds of the miner emission share set by subnet owners
+    pub type MinerEmissionShareBounds<T: Config> =
+        StorageValue<_, (Percent, Percent), ValueQuery, DefaultMinerEmissionShareBounds<T>>;
+
+    /// ===========================
+    /// ==== Owner Cut Vesting ====
+    /// ===========================
+    #[pallet::storage]
+    /// --- ITEM ( blocks ) --> minimum number of blocks the owner cut of subnets vests over
+    pub type MinOwnerCutVestingBlocks<T: Config> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> number of blocks the owner cut of the subnet vests over
+    pub type OwnerCutVestingBlocks<T: Config> = StorageMap<_, Identity, NetUid, u64, ValueQuery>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, coldkey ) --> owner cut of the subnet vesting for the coldkey
+    pub type OwnerCutVestings<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        NetUid,
+        Blake2_128Concat,
+        T::AccountId,
+        OwnerCutVesting<T::AccountId>,
+        OptionQuery,
+    >;
+
+    /// ===========================
+    /// ==== Subnet Treasuries ====
+    /// ===========================
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> share of the owner cut redirected into the treasury, in u16::MAX parts
+    pub type SubnetTreasuryShare<T: Config> = StorageMap<_, Identity, NetUid, u16, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> share of the subnet alpha approving a treasury spend, in u16::MAX parts
+    pub type SubnetTreasuryApprovalThreshold<T: Config> =
+        StorageMap<_, Identity, NetUid, u16, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> alpha held in the treasury of the subnet
+    pub type SubnetTreasury<T: Config> = StorageMap<_, Identity, NetUid, AlphaCurrency, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> treasury spend pending approval
+    pub type SubnetTreasurySpends<T: Config> =
+        StorageMap<_, Identity, NetUid, SubnetTreasurySpend<T::AccountId>, OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> id of the next treasury spend proposed
+    pub type NextSubnetTreasurySpendId<T: Config> =
+        StorageMap<_, Identity, NetUid, u32, ValueQuery>;
+
+    /// ===============================
+    /// ==== Hyperparameter Voting ====
+    /// ===============================
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> number of blocks sensitive hyperparameter changes are voted on
+    pub type HyperparameterVotingPeriod<T: Config> =
+        StorageMap<_, Identity, NetUid, u64, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> hyperparameter change pending the vote of the stakeholders
+    pub type HyperparameterProposals<T: Config> =
+        StorageMap<_, Identity, NetUid, HyperparameterProposal, OptionQuery>;
+    #[pallet::storage]
+    /// --- DMAP ( netuid, hotkey ) --> whether the hotkey voted for the hyperparameter change pending
+    pub type HyperparameterVotes<T: Config> =
+        StorageDoubleMap<_, Identity, NetUid, Blake2_128Concat, T::AccountId, bool, OptionQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> id of the next hyperparameter change proposed
+    pub type NextHyperparameterProposalId<T: Config> =
+        StorageMap<_, Identity, NetUid, u32, ValueQuery>;
+
+    /// ==============================
+    /// ==== Subnet Attestations =====
+    /// ==============================
+    #[pallet::storage]
+    /// --- MAP ( oracle ) --> whether the oracle is authorized by root to attest subnet facts
+    pub type SubnetAttestationOracles<T: Config> =
+        StorageMap<_, Blake2_128Concat, sr25519::Public, bool, ValueQuery>;
+    #[pallet::storage]
+    /// --- MAP ( netuid ) --> last attestations of the subnet, oldest first
+    pub type SubnetAttestations<T: Config> = StorageMap<
+        _,
+        Identity,
+        NetUid,
+        BoundedVec<SubnetAttestation, ConstU32<MAX_SUBNET_ATTESTATIONS>>,
+        ValueQuery,
+    >;
+
     /// ==================
     /// ==== Genesis =====
     /// ==================
     #[pallet::storage] // --- Storage for migration run status
     pub type HasMigrationRun<T: Config> = StorageMap<_, Identity, Vec<u8>, bool, ValueQuery>;
 
+    #[pallet::storage]
+    /// --- MAP ( migration_name ) --> raw storage key | The key a stepped migration resumes from.
+    pub type SteppedMigrationCursor<T: Config> =
+        StorageMap<_, Identity, Vec<u8>, Vec<u8>, OptionQuery>;
+
     #[pallet::type_value]
     /// Default value for pending childkey cooldown (settable by root, default 0)
     pub fn DefaultPendingChildKeyCooldown<T: Config>() -> u64 {
@@ -1953,6 +2586,21 @@ pub mod pallet {
         }
     }
 
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        /// Only subnet attestations signed by an authorized oracle are accepted unsigned.
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            match call {
+                Call::submit_subnet_attestation { payload, signature } => {
+                    Self::validate_subnet_attestation(payload, signature)
+                }
+                _ => InvalidTransaction::Call.into(),
+            }
+        }
+    }
+
     // ---- Subtensor helper functions.
     impl<T: Config> Pallet<T> {
         /// Is the caller allowed to set weights
@@ -1962,6 +2610,18 @@ pub mod pallet {
             total_stake >= Self::get_stake_threshold()
         }
 
+        /// Helper function to check the coldkey of a hotkey holds the minimum balance or stake
+        /// for free extrinsics, so throwaway keys can not flood the transaction pool.
+        pub fn check_free_extrinsic_min_balance(hotkey: &T::AccountId) -> bool {
+            let min_balance = Self::get_free_extrinsic_min_balance();
+            if min_balance.is_zero() {
+                return true;
+            }
+            let coldkey = Self::get_owning_coldkey_for_hotkey(hotkey);
+            TaoCurrency::from(Self::get_coldkey_balance(&coldkey)) >= min_balance
+                || Self::get_total_stake_for_coldkey(&coldkey) >= min_balance
+        }
+
         /// Helper function to check if register is allowed
         pub fn checked_allowed_register(netuid: NetUid) -> bool {
             if netuid.is_root() {
@@ -2179,6 +2839,14 @@ impl<T: Config + pallet_balances::Config<Balance = u64>>
     fn hotkey_of_uid(netuid: NetUid, uid: u16) -> Option<T::AccountId> {
         Keys::<T>::try_get(netuid, uid).ok()
     }
+
+    fn owner_coldkey(netuid: NetUid) -> Option<T::AccountId> {
+        SubnetOwner::<T>::try_get(netuid).ok()
+    }
+
+    fn owner_hotkey(netuid: NetUid) -> Option<T::AccountId> {
+        SubnetOwnerHotkey::<T>::try_get(netuid).ok()
+    }
 }
 
 impl<T: Config + pallet_balances::Config<Balance = u64>>
@@ -2238,6 +2906,9 @@ impl<T: Config + pallet_balances::Config<Balance = u64>>
             Self::hotkey_account_exists(hotkey),
             Error::<T>::HotKeyAccountNotExists
         );
+        if !alpha.is_zero() {
+            Self::ensure_unstake_unlocked(coldkey)?;
+        }
 
         // Decrese alpha out counter
         SubnetAlphaOut::<T>::mutate(netuid, |total| {
@@ -2249,12 +2920,61 @@ impl<T: Config + pallet_balances::Config<Balance = u64>>
         ))
     }
 
+    fn ensure_unstake_unlocked(coldkey: &T::AccountId) -> DispatchResult {
+        Ok(Self::ensure_unstake_unlocked(coldkey)?)
+    }
+
+    fn stake(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        netuid: NetUid,
+        tao: TaoCurrency,
+    ) -> Result<AlphaCurrency, DispatchError> {
+        Self::ensure_subtoken_enabled(netuid)?;
+        Self::validate_add_stake(coldkey, hotkey, netuid, tao, tao, false)?;
+
+        let tao_staked = Self::remove_balance_from_coldkey_account(coldkey, tao.into())?;
+        Self::stake_into_subnet(
+            hotkey,
+            coldkey,
+            netuid,
+            tao_staked,
+            T::SwapInterface::max_price().into(),
+            true,
+            false,
+        )
+    }
+
+    fn unstake(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        netuid: NetUid,
+        alpha: AlphaCurrency,
+    ) -> Result<TaoCurrency, DispatchError> {
+        Self::ensure_subtoken_enabled(netuid)?;
+        Self::ensure_unstake_unlocked(coldkey)?;
+        Self::validate_remove_stake(coldkey, hotkey, netuid, alpha, alpha, false)?;
+
+        let tao_unstaked = Self::unstake_from_subnet(
+            hotkey,
+            coldkey,
+            netuid,
+            alpha,
+            T::SwapInterface::min_price().into(),
+            false,
+        )?;
+        Self::add_balance_to_coldkey_account(coldkey, tao_unstaked.into());
+        Self::clear_small_nomination_if_required(hotkey, coldkey, netuid);
+
+        Ok(tao_unstaked)
+    }
+
     fn increase_provided_tao_reserve(netuid: NetUid, tao: TaoCurrency) {
         Self::increase_provided_tao_reserve(netuid, tao);
     }
 
-    fn decrease_provided_tao_reserve(netuid: NetUid, tao: TaoCurrency) {
-        Self::decrease_provided_tao_reserve(netuid, tao);
+    fn decrease_provided_tao_reserve(netuid: NetUid, tao: TaoCurrency) -> DispatchResult {
+        Self::decrease_provided_tao_reserve(netuid, tao)
     }
 
     fn increase_provided_alpha_reserve(netuid: NetUid, alpha: AlphaCurrency) {
@@ -2264,6 +2984,10 @@ impl<T: Config + pallet_balances::Config<Balance = u64>>
     fn decrease_provided_alpha_reserve(netuid: NetUid, alpha: AlphaCurrency) {
         Self::decrease_provided_alpha_reserve(netuid, alpha);
     }
+
+    fn increase_network_treasury(tao: TaoCurrency) {
+        Self::fund_network_treasury(tao);
+    }
 }
 
 /// Enum that defines types of rate limited operations for
@@ -2304,3 +3028,15 @@ impl<T> ProxyInterface<T> for () {
 pub trait CommitmentsInterface {
     fn purge_netuid(netuid: NetUid);
 }
+
+/// The runtime implements this to deposit the logs bridged from the native events of a block, as
+/// `(topics, data)`, as EVM log events.
+pub trait EvmLogInterface {
+    fn deposit_logs(
+        logs: sp_std::vec::Vec<(sp_std::vec::Vec<sp_core::H256>, sp_std::vec::Vec<u8>)>,
+    );
+}
+
+impl EvmLogInterface for () {
+    fn deposit_logs(_: sp_std::vec::Vec<(sp_std::vec::Vec<sp_core::H256>, sp_std::vec::Vec<u8>)>) {}
+}
@@ -7,6 +7,7 @@ use frame_support::pallet_macros::pallet_section;
 mod config {
 
     use crate::CommitmentsInterface;
+    use crate::EvmLogInterface;
     use pallet_commitments::GetCommitments;
     use subtensor_swap_interface::SwapHandler;
 
@@ -63,6 +64,9 @@ mod config {
         ///  Interface to clean commitments on network dissolution.
         type CommitmentsInterface: CommitmentsInterface;
 
+        /// Interface to deposit the EVM logs of native events.
+        type EvmLogInterface: EvmLogInterface;
+
         /// Rate limit for associating an EVM key.
         type EvmKeyAssociateRateLimit: Get<u64>;
 
@@ -261,5 +265,8 @@ mod config {
         /// Maximum percentage of immune UIDs.
         #[pallet::constant]
         type MaxImmuneUidsPercentage: Get<Percent>;
+        /// Slot duration of the chain in milliseconds, used to convert tempos set in seconds.
+        #[pallet::constant]
+        type SlotDuration: Get<u64>;
     }
 }
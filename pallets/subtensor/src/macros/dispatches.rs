@@ -5,7 +5,14 @@ use frame_support::pallet_macros::pallet_section;
 /// This can later be imported into the pallet using [`import_section`].
 #[pallet_section]
 mod dispatches {
+    use crate::staking::dissolution_payout::DissolutionPayout;
+    use crate::subnets::attestations::SubnetAttestationPayload;
+    use crate::subnets::hyperparameter_vote::{HyperparameterChange, MAX_HYPERPARAMETER_VOTERS};
     use crate::subnets::leasing::SubnetLeasingWeightInfo;
+    use crate::subnets::merkle_weights::MerkleWeightEntry;
+    use crate::subnets::registration_access::{RegistrationAccess, RegistrationAccount};
+    use crate::subnets::staged_hyperparams::StagedHyperparameter;
+    use crate::subnets::weights::WeightsReveal;
     use frame_support::traits::schedule::DispatchTime;
     use frame_support::traits::schedule::v3::Anon as ScheduleAnon;
     use frame_system::pallet_prelude::BlockNumberFor;
@@ -751,7 +758,8 @@ mod dispatches {
         #[pallet::call_index(3)]
         #[pallet::weight((Weight::from_parts(196_800_000, 0)
 		.saturating_add(T::DbWeight::get().reads(19))
-		.saturating_add(T::DbWeight::get().writes(10)), DispatchClass::Normal, Pays::Yes))]
+		.saturating_add(T::DbWeight::get().writes(10))
+		.saturating_add(T::SwapInterface::price_floor_defense_weight()), DispatchClass::Normal, Pays::Yes))]
         pub fn remove_stake(
             origin: OriginFor<T>,
             hotkey: T::AccountId,
@@ -1086,8 +1094,8 @@ mod dispatches {
         /// Weight is calculated based on the number of database reads and writes.
         #[pallet::call_index(71)]
         #[pallet::weight((Weight::from_parts(161_700_000, 0)
-        .saturating_add(T::DbWeight::get().reads(16_u64))
-        .saturating_add(T::DbWeight::get().writes(9)), DispatchClass::Operational, Pays::Yes))]
+        .saturating_add(T::DbWeight::get().reads(18_u64))
+        .saturating_add(T::DbWeight::get().writes(11)), DispatchClass::Operational, Pays::Yes))]
         pub fn swap_coldkey(
             origin: OriginFor<T>,
             old_coldkey: T::AccountId,
@@ -1449,7 +1457,7 @@ mod dispatches {
         #[pallet::call_index(73)]
         #[pallet::weight((Weight::from_parts(37_830_000, 0)
 		.saturating_add(T::DbWeight::get().reads(4))
-		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::Yes))]
+		.saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Normal, Pays::Yes))]
         pub fn schedule_swap_coldkey(
             origin: OriginFor<T>,
             new_coldkey: T::AccountId,
@@ -1457,6 +1465,9 @@ mod dispatches {
             let who = ensure_signed(origin)?;
             let current_block = <frame_system::Pallet<T>>::block_number();
 
+            Self::ensure_not_reserved_account(&new_coldkey)?;
+            Self::ensure_unstake_unlocked(&who)?;
+
             // If the coldkey has a scheduled swap, check if we can reschedule it
             if ColdkeySwapScheduled::<T>::contains_key(&who) {
                 let (scheduled_block, _scheduled_coldkey) = ColdkeySwapScheduled::<T>::get(&who);
@@ -1464,6 +1475,7 @@ mod dispatches {
                 let redo_when = scheduled_block.saturating_add(reschedule_duration);
                 ensure!(redo_when <= current_block, Error::<T>::SwapAlreadyScheduled);
             }
+            Self::ensure_can_schedule_operation(&who)?;
 
             // Calculate the swap cost and ensure sufficient balance
             let swap_cost = Self::get_key_swap_cost();
@@ -1485,7 +1497,7 @@ mod dispatches {
             let bound_call = <T as Config>::Preimages::bound(LocalCallOf::<T>::from(call.clone()))
                 .map_err(|_| Error::<T>::FailedToSchedule)?;
 
-            T::Scheduler::schedule(
+            let task = T::Scheduler::schedule(
                 DispatchTime::At(when),
                 None,
                 63,
@@ -1495,6 +1507,8 @@ mod dispatches {
             .map_err(|_| Error::<T>::FailedToSchedule)?;
 
             ColdkeySwapScheduled::<T>::insert(&who, (when, new_coldkey.clone()));
+            ColdkeySwapScheduledTask::<T>::insert(&who, task);
+            ColdkeySwapApprovals::<T>::remove(&who);
             // Emit the SwapScheduled event
             Self::deposit_event(Event::ColdkeySwapScheduled {
                 old_coldkey: who.clone(),
@@ -1639,7 +1653,8 @@ mod dispatches {
         #[pallet::call_index(83)]
         #[pallet::weight((Weight::from_parts(28_830_000, 0)
         .saturating_add(T::DbWeight::get().reads(6))
-        .saturating_add(T::DbWeight::get().writes(0)), DispatchClass::Normal, Pays::Yes))]
+        .saturating_add(T::DbWeight::get().writes(0))
+        .saturating_add(T::SwapInterface::price_floor_defense_weight()), DispatchClass::Normal, Pays::Yes))]
         pub fn unstake_all(origin: OriginFor<T>, hotkey: T::AccountId) -> DispatchResult {
             Self::do_unstake_all(origin, hotkey)
         }
@@ -1672,7 +1687,8 @@ mod dispatches {
         #[pallet::call_index(84)]
         #[pallet::weight((Weight::from_parts(358_500_000, 0)
         .saturating_add(T::DbWeight::get().reads(38_u64))
-        .saturating_add(T::DbWeight::get().writes(21_u64)), DispatchClass::Operational, Pays::Yes))]
+        .saturating_add(T::DbWeight::get().writes(21_u64))
+        .saturating_add(T::SwapInterface::price_floor_defense_weight()), DispatchClass::Operational, Pays::Yes))]
         pub fn unstake_all_alpha(origin: OriginFor<T>, hotkey: T::AccountId) -> DispatchResult {
             Self::do_unstake_all_alpha(origin, hotkey)
         }
@@ -1701,7 +1717,8 @@ mod dispatches {
         #[pallet::call_index(85)]
         #[pallet::weight((Weight::from_parts(164_300_000, 0)
         .saturating_add(T::DbWeight::get().reads(15_u64))
-        .saturating_add(T::DbWeight::get().writes(7_u64)), DispatchClass::Normal, Pays::Yes))]
+        .saturating_add(T::DbWeight::get().writes(7_u64))
+        .saturating_add(T::SwapInterface::price_floor_defense_weight()), DispatchClass::Normal, Pays::Yes))]
         pub fn move_stake(
             origin: T::RuntimeOrigin,
             origin_hotkey: T::AccountId,
@@ -1744,7 +1761,8 @@ mod dispatches {
         #[pallet::call_index(86)]
         #[pallet::weight((Weight::from_parts(160_300_000, 0)
         .saturating_add(T::DbWeight::get().reads(13_u64))
-        .saturating_add(T::DbWeight::get().writes(6_u64)), DispatchClass::Normal, Pays::Yes))]
+        .saturating_add(T::DbWeight::get().writes(6_u64))
+        .saturating_add(T::SwapInterface::price_floor_defense_weight()), DispatchClass::Normal, Pays::Yes))]
         pub fn transfer_stake(
             origin: T::RuntimeOrigin,
             destination_coldkey: T::AccountId,
@@ -1786,7 +1804,8 @@ mod dispatches {
         #[pallet::weight((
             Weight::from_parts(351_300_000, 0)
             .saturating_add(T::DbWeight::get().reads(37_u64))
-            .saturating_add(T::DbWeight::get().writes(22_u64)),
+            .saturating_add(T::DbWeight::get().writes(22_u64))
+            .saturating_add(T::SwapInterface::price_floor_defense_weight()),
             DispatchClass::Normal,
             Pays::Yes
         ))]
@@ -1915,7 +1934,8 @@ mod dispatches {
         #[pallet::call_index(89)]
         #[pallet::weight((Weight::from_parts(377_400_000, 0)
 		.saturating_add(T::DbWeight::get().reads(30_u64))
-		.saturating_add(T::DbWeight::get().writes(14)), DispatchClass::Normal, Pays::Yes))]
+		.saturating_add(T::DbWeight::get().writes(14))
+		.saturating_add(T::SwapInterface::price_floor_defense_weight()), DispatchClass::Normal, Pays::Yes))]
         pub fn remove_stake_limit(
             origin: OriginFor<T>,
             hotkey: T::AccountId,
@@ -1959,7 +1979,8 @@ mod dispatches {
         #[pallet::weight((
             Weight::from_parts(411_500_000, 0)
             .saturating_add(T::DbWeight::get().reads(37_u64))
-            .saturating_add(T::DbWeight::get().writes(22_u64)),
+            .saturating_add(T::DbWeight::get().writes(22_u64))
+            .saturating_add(T::SwapInterface::price_floor_defense_weight()),
             DispatchClass::Normal,
             Pays::Yes
         ))]
@@ -2137,7 +2158,8 @@ mod dispatches {
         #[pallet::call_index(103)]
         #[pallet::weight((Weight::from_parts(395_300_000, 10142)
 			.saturating_add(T::DbWeight::get().reads(30_u64))
-			.saturating_add(T::DbWeight::get().writes(14_u64)), DispatchClass::Normal, Pays::Yes))]
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+			.saturating_add(T::SwapInterface::price_floor_defense_weight()), DispatchClass::Normal, Pays::Yes))]
         pub fn remove_stake_full_limit(
             origin: T::RuntimeOrigin,
             hotkey: T::AccountId,
@@ -2397,5 +2419,1459 @@ mod dispatches {
             ensure_root(origin)?;
             Self::do_dissolve_network(netuid)
         }
+
+        /// Stages a bundle of hyperparameter changes for a subnet.
+        ///
+        /// The bundle is evaluated in a shadow epoch on the next epoch of the subnet, without
+        /// affecting payouts. Staging replaces any bundle already staged for the subnet.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The subnet owner or root.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet to stage the changes for.
+        ///
+        /// * `changes` (Vec<StagedHyperparameter>):
+        ///     - The hyperparameter changes, applied in order on commit.
+        #[pallet::call_index(121)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn stage_hyperparameters(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            changes: Vec<StagedHyperparameter>,
+        ) -> DispatchResult {
+            Self::do_stage_hyperparameters(origin, netuid, changes)
+        }
+
+        /// Applies the staged hyperparameter bundle of a subnet.
+        ///
+        /// The bundle must have been evaluated in a shadow epoch. Every change is applied or
+        /// none is, and owner rate limits apply per hyperparameter.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The subnet owner or root.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet whose staged bundle is committed.
+        #[pallet::call_index(122)]
+        #[pallet::weight((Weight::from_parts(40_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(20))
+		.saturating_add(T::DbWeight::get().writes(20)), DispatchClass::Normal, Pays::Yes))]
+        pub fn commit_staged_hyperparameters(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+        ) -> DispatchResult {
+            Self::do_commit_staged_hyperparameters(origin, netuid)
+        }
+
+        /// Discards the staged hyperparameter bundle of a subnet.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The subnet owner or root.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet whose staged bundle is discarded.
+        #[pallet::call_index(123)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn rollback_staged_hyperparameters(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+        ) -> DispatchResult {
+            Self::do_rollback_staged_hyperparameters(origin, netuid)
+        }
+
+        /// Adds an account to the registry of protocol-reserved accounts, or removes it.
+        ///
+        /// Reserved accounts cannot be registered as neuron hotkeys, own subnets or be used as
+        /// key swap destinations. Root only.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - Must be root.
+        ///
+        /// * `account` (T::AccountId):
+        ///     - The account to reserve or release.
+        ///
+        /// * `kind` (Option<ReservedAccountKind>):
+        ///     - What the account is reserved for, or `None` to release it.
+        #[pallet::call_index(124)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+        pub fn set_reserved_account(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            kind: Option<ReservedAccountKind>,
+        ) -> DispatchResult {
+            Self::do_set_reserved_account(origin, account, kind)
+        }
+
+        /// Sets the unstake timelock of the calling coldkey.
+        ///
+        /// While a timelock is set, the coldkey must request an unlock and wait for the timelock to
+        /// elapse before it can unstake, transfer stake or schedule a coldkey swap. Raising the
+        /// timelock applies immediately and re-locks the coldkey, lowering it applies once the
+        /// current timelock has elapsed.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the coldkey.
+        ///
+        /// * `blocks` (u64):
+        ///     - The timelock in blocks, 0 to remove it.
+        #[pallet::call_index(125)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_unstake_timelock(origin: OriginFor<T>, blocks: u64) -> DispatchResult {
+            Self::do_set_unstake_timelock(origin, blocks)
+        }
+
+        /// Requests an unlock of the calling coldkey, which takes effect once its unstake
+        /// timelock has elapsed.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the coldkey.
+        #[pallet::call_index(126)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn request_unstake_unlock(origin: OriginFor<T>) -> DispatchResult {
+            Self::do_request_unstake_unlock(origin)
+        }
+
+        /// Queues an unstake that is executed in slices over multiple blocks.
+        ///
+        /// The unstake must exceed the slicing threshold of the subnet, a fraction of its alpha
+        /// reserve. One slice is sold per block with the limit price, until the amount is
+        /// unstaked or `max_duration` blocks have passed. Unsold alpha stays staked.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the coldkey.
+        ///
+        /// * `hotkey` (T::AccountId):
+        ///     - The hotkey to remove stake from.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet to remove stake from.
+        ///
+        /// * `amount_unstaked` (AlphaCurrency):
+        ///     - The alpha to unstake.
+        ///
+        /// * `limit_price` (TaoCurrency):
+        ///     - The lowest price at which a slice is sold.
+        ///
+        /// * `max_duration` (u64):
+        ///     - The number of blocks over which the unstake is executed.
+        #[pallet::call_index(127)]
+        #[pallet::weight((Weight::from_parts(30_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(9))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::Yes))]
+        pub fn remove_stake_sliced(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: NetUid,
+            amount_unstaked: AlphaCurrency,
+            limit_price: TaoCurrency,
+            max_duration: u64,
+        ) -> DispatchResult {
+            Self::do_remove_stake_sliced(
+                origin,
+                hotkey,
+                netuid,
+                amount_unstaked,
+                limit_price,
+                max_duration,
+            )
+        }
+
+        /// Cancels a queued sliced unstake of the calling coldkey.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the coldkey.
+        ///
+        /// * `id` (u64):
+        ///     - The id of the sliced unstake.
+        #[pallet::call_index(128)]
+        #[pallet::weight((Weight::from_parts(12_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn cancel_sliced_unstake(origin: OriginFor<T>, id: u64) -> DispatchResult {
+            Self::do_cancel_sliced_unstake(origin, id)
+        }
+
+        /// Approves a manager to stake up to `amount` of the calling coldkey's free balance on a
+        /// subnet, replacing any previous allowance. The manager can never unstake or transfer
+        /// the stake.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the coldkey.
+        ///
+        /// * `manager` (T::AccountId):
+        ///     - The account allowed to stake on behalf of the coldkey.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet the allowance applies to.
+        ///
+        /// * `amount` (TaoCurrency):
+        ///     - The TAO the manager may stake, 0 to remove the allowance.
+        #[pallet::call_index(129)]
+        #[pallet::weight((Weight::from_parts(12_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn approve_stake_allowance(
+            origin: OriginFor<T>,
+            manager: T::AccountId,
+            netuid: NetUid,
+            amount: TaoCurrency,
+        ) -> DispatchResult {
+            Self::do_approve_stake_allowance(origin, manager, netuid, amount)
+        }
+
+        /// Revokes the stake allowance of a manager on a subnet.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the coldkey.
+        ///
+        /// * `manager` (T::AccountId):
+        ///     - The manager account.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet the allowance applies to.
+        #[pallet::call_index(130)]
+        #[pallet::weight((Weight::from_parts(12_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn revoke_stake_allowance(
+            origin: OriginFor<T>,
+            manager: T::AccountId,
+            netuid: NetUid,
+        ) -> DispatchResult {
+            Self::do_revoke_stake_allowance(origin, manager, netuid)
+        }
+
+        /// Stakes the free balance of a coldkey on its behalf, consuming the stake allowance the
+        /// coldkey approved for the calling manager.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the manager.
+        ///
+        /// * `coldkey` (T::AccountId):
+        ///     - The coldkey that owns the balance and receives the stake.
+        ///
+        /// * `hotkey` (T::AccountId):
+        ///     - The hotkey to stake to.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet to stake on.
+        ///
+        /// * `amount_staked` (TaoCurrency):
+        ///     - The TAO to stake.
+        ///
+        /// * `limit_price` (TaoCurrency):
+        ///     - The highest price at which the stake is bought.
+        #[pallet::call_index(131)]
+        #[pallet::weight((Weight::from_parts(402_900_000, 0)
+		.saturating_add(T::DbWeight::get().reads(27))
+		.saturating_add(T::DbWeight::get().writes(16)), DispatchClass::Normal, Pays::Yes))]
+        pub fn add_stake_with_allowance(
+            origin: OriginFor<T>,
+            coldkey: T::AccountId,
+            hotkey: T::AccountId,
+            netuid: NetUid,
+            amount_staked: TaoCurrency,
+            limit_price: TaoCurrency,
+        ) -> DispatchResult {
+            Self::do_add_stake_with_allowance(
+                origin,
+                coldkey,
+                hotkey,
+                netuid,
+                amount_staked,
+                limit_price,
+            )
+        }
+
+        /// Blacklists a hotkey from registering and from setting, committing or revealing weights
+        /// on a subnet for `duration` blocks, renewing an existing entry. The blacklist is
+        /// bounded and expired entries are pruned first.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the subnet owner.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet.
+        ///
+        /// * `hotkey` (T::AccountId):
+        ///     - The hotkey to blacklist.
+        ///
+        /// * `duration` (u64):
+        ///     - The number of blocks the hotkey is blacklisted for.
+        #[pallet::call_index(132)]
+        #[pallet::weight((Weight::from_parts(40_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(259))
+		.saturating_add(T::DbWeight::get().writes(257)), DispatchClass::Normal, Pays::Yes))]
+        pub fn blacklist_hotkey(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            hotkey: T::AccountId,
+            duration: u64,
+        ) -> DispatchResult {
+            Self::do_blacklist_hotkey(origin, netuid, hotkey, duration)
+        }
+
+        /// Strikes a hotkey from the blacklist of a subnet. Governance strikes entries on appeal
+        /// of the blacklisted hotkey through the root origin, the subnet owner may lift their own
+        /// entries.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - Root or the signature of the subnet owner.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet.
+        ///
+        /// * `hotkey` (T::AccountId):
+        ///     - The blacklisted hotkey.
+        #[pallet::call_index(133)]
+        #[pallet::weight((Weight::from_parts(12_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn strike_blacklisted_hotkey(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            hotkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_strike_blacklisted_hotkey(origin, netuid, hotkey)
+        }
+
+        /// Reveals several pending weight commits of the caller on a mechanism at once, e.g. to
+        /// recover after downtime. Reveals of commits whose reveal window has passed drop those
+        /// commits instead of failing the call, the others set weights in commit order.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the revealing hotkey.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet.
+        ///
+        /// * `mecid` (MechId):
+        ///     - The mechanism the weights were committed on.
+        ///
+        /// * `reveals` (Vec<WeightsReveal>):
+        ///     - The uids, values, salt and version key of each commit.
+        ///
+        /// # Raises:
+        /// * `InvalidNumberOfReveals`:
+        ///   - No reveals, or more reveals than a hotkey can have pending commits.
+        ///
+        /// * `InvalidRevealCommitHashNotMatch`:
+        ///   - A reveal does not match any pending commit.
+        ///
+        /// * `RevealTooEarly`:
+        ///   - A commit is not revealable yet.
+        #[pallet::call_index(134)]
+        #[pallet::weight((Weight::from_parts(30_000_000, 0)
+		.saturating_add(Weight::from_parts(92_000_000, 0).saturating_mul(reveals.len() as u64))
+		.saturating_add(T::DbWeight::get().reads((reveals.len() as u64).saturating_mul(15).saturating_add(3)))
+		.saturating_add(T::DbWeight::get().writes((reveals.len() as u64).saturating_add(1))), DispatchClass::Normal, Pays::Yes))]
+        pub fn settle_weight_commits(
+            origin: T::RuntimeOrigin,
+            netuid: NetUid,
+            mecid: MechId,
+            reveals: Vec<WeightsReveal>,
+        ) -> DispatchResult {
+            Self::do_settle_weight_commits(origin, netuid, mecid, reveals)
+        }
+
+        /// Attests a coldkey as belonging to an institution, or revokes the attestation.
+        ///
+        /// Positions the coldkey stakes while attested are tagged as institutional stake. Root
+        /// only.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - Must be root.
+        ///
+        /// * `coldkey` (T::AccountId):
+        ///     - The coldkey to attest.
+        ///
+        /// * `attested` (bool):
+        ///     - Whether the coldkey is attested.
+        #[pallet::call_index(135)]
+        #[pallet::weight((Weight::from_parts(10_000_000, 0)
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+        pub fn set_institutional_attestation(
+            origin: OriginFor<T>,
+            coldkey: T::AccountId,
+            attested: bool,
+        ) -> DispatchResult {
+            Self::do_set_institutional_attestation(origin, coldkey, attested)
+        }
+
+        /// Authorizes an operator hotkey to set and commit weights for a validator hotkey on a
+        /// subnet.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the coldkey owning the validator hotkey.
+        ///
+        /// * `hotkey` (T::AccountId):
+        ///     - The validator hotkey.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet the operator sets weights on.
+        ///
+        /// * `operator` (T::AccountId):
+        ///     - The operator hotkey, which must not be registered on the subnet.
+        ///
+        /// # Errors:
+        /// * `NonAssociatedColdKey`:
+        ///   - The coldkey does not own the validator hotkey.
+        ///
+        /// * `InvalidWeightSetterDelegate`:
+        ///   - The operator is the validator hotkey, is registered on the subnet or operates for
+        ///     another hotkey.
+        #[pallet::call_index(136)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_weight_setter_delegate(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: NetUid,
+            operator: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_set_weight_setter_delegate(origin, hotkey, netuid, operator)
+        }
+
+        /// Revokes the authorization of an operator hotkey to set weights on a subnet.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the coldkey owning the validator hotkey, or of the operator.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet the operator sets weights on.
+        ///
+        /// * `operator` (T::AccountId):
+        ///     - The operator hotkey.
+        ///
+        /// # Errors:
+        /// * `WeightSetterDelegateNotFound`:
+        ///   - The account is not an operator on the subnet.
+        #[pallet::call_index(137)]
+        #[pallet::weight((Weight::from_parts(10_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn remove_weight_setter_delegate(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            operator: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_remove_weight_setter_delegate(origin, netuid, operator)
+        }
+
+        /// Commits the merkle root of the weights of a hotkey on a subnet with merkle weights.
+        ///
+        /// The non-zero entries of the weights are then submitted with `submit_merkle_weights`.
+        /// A new commit replaces the upload in progress.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the hotkey, or of its weight setter delegate.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet.
+        ///
+        /// * `mecid` (MechId):
+        ///     - The mechanism the weights are set on.
+        ///
+        /// * `root` (H256):
+        ///     - The merkle root of the weights of every uid of the subnet.
+        ///
+        /// * `entries` (u16):
+        ///     - The number of non-zero entries of the weights.
+        ///
+        /// * `version_key` (u64):
+        ///     - The network version key the weights are set with.
+        ///
+        /// # Raises:
+        /// * `MerkleWeightsDisabled`:
+        ///   - Merkle weights are not enabled on the subnet.
+        ///
+        /// * `CommitRevealEnabled`:
+        ///   - Commit-reveal is enabled on the subnet.
+        ///
+        /// * `InvalidMerkleWeightsEntries`:
+        ///   - The number of entries is zero or larger than the subnet.
+        ///
+        /// * `SettingWeightsTooFast`:
+        ///   - The hotkey set weights within the weights set rate limit.
+        #[pallet::call_index(138)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(8))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn commit_merkle_weights(
+            origin: T::RuntimeOrigin,
+            netuid: NetUid,
+            mecid: MechId,
+            root: H256,
+            entries: u16,
+            version_key: u64,
+        ) -> DispatchResult {
+            Self::do_commit_merkle_weights(origin, netuid, mecid, root, entries, version_key)
+        }
+
+        /// Submits non-zero entries of merkle committed weights with their proofs.
+        ///
+        /// Once all committed entries are in, the weights are set as with `set_weights`.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the hotkey, or of its weight setter delegate.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet.
+        ///
+        /// * `mecid` (MechId):
+        ///     - The mechanism the weights were committed on.
+        ///
+        /// * `entries` (Vec<MerkleWeightEntry>):
+        ///     - The uid, weight and proof of each entry.
+        ///
+        /// # Raises:
+        /// * `NoMerkleWeightsCommit`:
+        ///   - The hotkey has no merkle weights commit on the mechanism.
+        ///
+        /// * `InvalidMerkleWeightsEntries`:
+        ///   - No entries, or more entries than were committed.
+        ///
+        /// * `InvalidMerkleWeightProof`:
+        ///   - An entry is zero or does not match the committed root.
+        ///
+        /// * `DuplicateUids`:
+        ///   - An entry was already submitted.
+        #[pallet::call_index(139)]
+        #[pallet::weight((Weight::from_parts(25_000_000, 0)
+		.saturating_add(Weight::from_parts(200_000, 0).saturating_mul(entries.len() as u64))
+		.saturating_add(T::DbWeight::get().reads(15))
+		.saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Normal, Pays::Yes))]
+        pub fn submit_merkle_weights(
+            origin: T::RuntimeOrigin,
+            netuid: NetUid,
+            mecid: MechId,
+            entries: Vec<MerkleWeightEntry>,
+        ) -> DispatchResult {
+            Self::do_submit_merkle_weights(origin, netuid, mecid, entries)
+        }
+
+        /// Authorizes an oracle to attest lifecycle facts about subnets, or revokes it. Root
+        /// only.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - Must be root.
+        ///
+        /// * `oracle` (sr25519::Public):
+        ///     - The key the oracle signs attestations with.
+        ///
+        /// * `authorized` (bool):
+        ///     - Whether the oracle is authorized.
+        #[pallet::call_index(140)]
+        #[pallet::weight((Weight::from_parts(10_000_000, 0)
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+        pub fn set_subnet_attestation_oracle(
+            origin: OriginFor<T>,
+            oracle: sr25519::Public,
+            authorized: bool,
+        ) -> DispatchResult {
+            Self::do_set_subnet_attestation_oracle(origin, oracle, authorized)
+        }
+
+        /// Records a fact about a subnet attested by an authorized oracle.
+        ///
+        /// The transaction is unsigned, it is only valid with the signature of an authorized
+        /// oracle over the payload.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - Must be none.
+        ///
+        /// * `payload` (SubnetAttestationPayload):
+        ///     - The subnet, the oracle, the attested fact and the block it is signed at.
+        ///
+        /// * `signature` (sr25519::Signature):
+        ///     - The signature of the oracle over the SCALE encoded payload.
+        ///
+        /// # Raises:
+        /// * `SubnetAttestationOracleNotAuthorized`:
+        ///   - The oracle is not authorized.
+        ///
+        /// * `InvalidSubnetAttestationSignature`:
+        ///   - The signature does not match the payload and oracle.
+        ///
+        /// * `StaleSubnetAttestation`:
+        ///   - The payload is signed too long ago, or at a future block.
+        ///
+        /// * `DuplicateSubnetAttestation`:
+        ///   - The oracle has already attested the fact about the subnet.
+        #[pallet::call_index(141)]
+        #[pallet::weight((Weight::from_parts(50_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+        pub fn submit_subnet_attestation(
+            origin: OriginFor<T>,
+            payload: SubnetAttestationPayload,
+            signature: sr25519::Signature,
+        ) -> DispatchResult {
+            Self::do_submit_subnet_attestation(origin, payload, signature)
+        }
+
+        /// Clears the bonds of every mechanism of a subnet, for subnets whose incentive mechanism
+        /// was redesigned and whose historical bonds would skew the new scoring. Subnet owners are
+        /// rate limited like hyperparameter updates.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - Root or the signature of the subnet owner.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet.
+        ///
+        /// # Event:
+        /// * SubnetBondsReset;
+        ///     - On successfully clearing the bonds.
+        #[pallet::call_index(142)]
+        #[pallet::weight((Weight::from_parts(30_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(6))
+		.saturating_add(T::DbWeight::get().writes(513)), DispatchClass::Normal, Pays::Yes))]
+        pub fn reset_bonds(origin: OriginFor<T>, netuid: NetUid) -> DispatchResult {
+            Self::do_reset_subnet_bonds(origin, netuid)
+        }
+
+        /// Signals that a validator is active on a commit-reveal subnet, where its weights only
+        /// land at reveals, so that the activity cutoff does not mark it inactive in between.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the validator hotkey, which must hold a validator permit.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet.
+        ///
+        /// # Raises:
+        /// * `CommitRevealDisabled`:
+        ///     - Commit-reveal is disabled on the subnet.
+        ///
+        /// * `NeuronNoValidatorPermit`:
+        ///     - The hotkey does not hold a validator permit on the subnet.
+        ///
+        /// * `HeartbeatTooFast`:
+        ///     - The hotkey has already sent a heartbeat on the subnet this tempo.
+        #[pallet::call_index(143)]
+        #[pallet::weight((Weight::from_parts(15_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(6))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn heartbeat(origin: OriginFor<T>, netuid: NetUid) -> DispatchResult {
+            Self::do_heartbeat(origin, netuid)
+        }
+
+        /// Mints TAO to the caller coldkey, without the proof of work of `faucet`.
+        /// It is only available when the dev-mode feature is enabled.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the coldkey to fund.
+        ///
+        /// * `amount` (TaoCurrency):
+        ///     - The amount to mint, at most `DEV_FAUCET_MAX_AMOUNT`.
+        ///
+        /// # Raises:
+        /// * `DevModeDisabled`:
+        ///     - The runtime is not built with the dev-mode feature.
+        ///
+        /// * `DevFaucetAmountTooHigh`:
+        ///     - The amount is above `DEV_FAUCET_MAX_AMOUNT`.
+        ///
+        #[pallet::call_index(144)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::No))]
+        pub fn dev_faucet(origin: OriginFor<T>, amount: TaoCurrency) -> DispatchResult {
+            Self::do_dev_faucet(origin, amount)
+        }
+
+        /// Lets any number of neurons register on a subnet in every block, at its minimum burn
+        /// and difficulty. It is only available when the dev-mode feature is enabled.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The subnet owner or root.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet.
+        ///
+        /// # Raises:
+        /// * `DevModeDisabled`:
+        ///     - The runtime is not built with the dev-mode feature.
+        ///
+        #[pallet::call_index(145)]
+        #[pallet::weight((Weight::from_parts(25_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(10)), DispatchClass::Normal, Pays::Yes))]
+        pub fn dev_enable_instant_registration(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+        ) -> DispatchResult {
+            Self::do_dev_enable_instant_registration(origin, netuid)
+        }
+
+        /// Sets every rate limit of the chain, and those of a subnet, to zero.
+        /// It is only available when the dev-mode feature is enabled.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - Root.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet.
+        ///
+        /// # Raises:
+        /// * `DevModeDisabled`:
+        ///     - The runtime is not built with the dev-mode feature.
+        ///
+        #[pallet::call_index(146)]
+        #[pallet::weight((Weight::from_parts(25_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(T::DbWeight::get().writes(9)), DispatchClass::Operational, Pays::Yes))]
+        pub fn dev_disable_rate_limits(origin: OriginFor<T>, netuid: NetUid) -> DispatchResult {
+            Self::do_dev_disable_rate_limits(origin, netuid)
+        }
+
+        /// Runs the epoch of a subnet in the current block, as if its tempo had elapsed, and
+        /// drains its pending emission. It is only available when the dev-mode feature is enabled.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The subnet owner or root.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet.
+        ///
+        /// # Raises:
+        /// * `DevModeDisabled`:
+        ///     - The runtime is not built with the dev-mode feature.
+        ///
+        #[pallet::call_index(147)]
+        #[pallet::weight((Weight::from_parts(500_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2_000))
+		.saturating_add(T::DbWeight::get().writes(2_000)), DispatchClass::Normal, Pays::Yes))]
+        pub fn dev_advance_tempo(origin: OriginFor<T>, netuid: NetUid) -> DispatchResult {
+            Self::do_dev_advance_tempo(origin, netuid)
+        }
+
+        /// Sets no weights for the calling hotkey on every mechanism of a subnet, for validators
+        /// that have nothing to score this epoch. The empty weights count as a weights update,
+        /// so the activity cutoff does not mark the validator inactive.
+        ///
+        /// On commit-reveal subnets validators send a `heartbeat` instead.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the hotkey.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet.
+        ///
+        /// # Raises:
+        /// * `CommitRevealEnabled`:
+        ///     - Commit-reveal is enabled on the subnet.
+        ///
+        /// * `HotKeyNotRegisteredInSubNet`:
+        ///     - The hotkey is not registered on the subnet.
+        ///
+        /// * `SettingWeightsTooFast`:
+        ///     - The hotkey set weights within the weights set rate limit.
+        #[pallet::call_index(148)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(10))
+		.saturating_add(T::DbWeight::get().writes(4)), DispatchClass::Normal, Pays::No))]
+        pub fn set_no_weights(origin: OriginFor<T>, netuid: NetUid) -> DispatchResult {
+            Self::do_set_no_weights(origin, netuid)
+        }
+
+        /// Bids to register a hotkey on a subnet that auctions its burned registrations. The bid
+        /// is escrowed from the coldkey and, at the end of the adjustment interval, burned to
+        /// register the hotkey if it is among the highest bids, or refunded otherwise.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the coldkey of the hotkey.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet.
+        ///
+        /// * `hotkey` (T::AccountId):
+        ///     - The hotkey to register.
+        ///
+        /// * `amount` (TaoCurrency):
+        ///     - The TAO bid, at least the burn of the subnet. Replaces a lower bid of the hotkey.
+        ///
+        /// # Raises:
+        /// * `BurnAuctionDisabled`:
+        ///     - Burned registrations are not auctioned on the subnet.
+        ///
+        /// * `RegistrationBidTooLow`:
+        ///     - The bid is below the burn, the previous bid of the hotkey, or the lowest bid
+        ///       when the bids of the subnet are full.
+        ///
+        /// * `HotKeyAlreadyRegisteredInSubNet`:
+        ///     - The hotkey is already registered on the subnet.
+        #[pallet::call_index(149)]
+        #[pallet::weight((Weight::from_parts(60_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(80))
+		.saturating_add(T::DbWeight::get().writes(6)), DispatchClass::Normal, Pays::Yes))]
+        pub fn bid_burned_register(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            hotkey: T::AccountId,
+            amount: TaoCurrency,
+        ) -> DispatchResult {
+            Self::do_bid_burned_registration(origin, netuid, hotkey, amount)
+        }
+
+        /// Puts a hotkey or coldkey on the registration allowlist or denylist of a subnet, or
+        /// removes it from them. While the allowlist is not empty only its hotkeys and coldkeys
+        /// can register, and denied hotkeys and coldkeys can never register.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the subnet owner.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet.
+        ///
+        /// * `account` (RegistrationAccount):
+        ///     - The hotkey or coldkey.
+        ///
+        /// * `access` (Option<RegistrationAccess>):
+        ///     - Allowed or denied, `None` to remove the entry.
+        #[pallet::call_index(150)]
+        #[pallet::weight((Weight::from_parts(30_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(259))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_registration_access(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            account: RegistrationAccount<T::AccountId>,
+            access: Option<RegistrationAccess>,
+        ) -> DispatchResult {
+            Self::do_set_registration_access(origin, netuid, account, access)
+        }
+
+        /// Registers the initial neurons of a subnet in one call. Only the subnet owner can
+        /// bootstrap a subnet, before its first emission and while it has less than
+        /// `MAX_BOOTSTRAP_NEURONS` neurons. Bootstrapped neurons do not burn.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the subnet owner.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet.
+        ///
+        /// * `neurons` (Vec<(T::AccountId, T::AccountId)>):
+        ///     - The hotkey and coldkey of every neuron.
+        ///
+        /// # Raises:
+        /// * `SubnetBootstrapClosed`:
+        ///     - The subnet has started emitting or has too many neurons.
+        ///
+        /// * `TooManyBootstrapNeurons`:
+        ///     - The neurons would take the subnet above `MAX_BOOTSTRAP_NEURONS` or its max
+        ///       allowed uids.
+        ///
+        /// * `HotKeyAlreadyRegisteredInSubNet`:
+        ///     - A hotkey is already registered on the subnet.
+        ///
+        /// * `NonAssociatedColdKey`:
+        ///     - A hotkey is owned by another coldkey.
+        #[pallet::call_index(151)]
+        #[pallet::weight((Weight::from_parts(20_000_000, 0)
+		.saturating_add(Weight::from_parts(45_000_000, 0).saturating_mul(neurons.len() as u64))
+		.saturating_add(T::DbWeight::get().reads((neurons.len() as u64).saturating_mul(12).saturating_add(5)))
+		.saturating_add(T::DbWeight::get().writes((neurons.len() as u64).saturating_mul(20))), DispatchClass::Normal, Pays::Yes))]
+        pub fn bootstrap_register(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            neurons: Vec<(T::AccountId, T::AccountId)>,
+        ) -> DispatchResult {
+            Self::do_bootstrap_register(origin, netuid, neurons)
+        }
+
+        /// Sets where the dissolution payouts of the signing coldkey are credited, to its free
+        /// balance or staked into root on a hotkey. A payout that cannot be staked is credited
+        /// to the free balance.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the coldkey.
+        ///
+        /// * `payout` (DissolutionPayout):
+        ///     - `Balance`, or `RootStake` with the hotkey to stake on.
+        ///
+        /// # Raises:
+        /// * `HotKeyAccountNotExists`:
+        ///     - The hotkey to stake on does not exist.
+        #[pallet::call_index(152)]
+        #[pallet::weight((Weight::from_parts(10_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_dissolution_payout(
+            origin: OriginFor<T>,
+            payout: DissolutionPayout<T::AccountId>,
+        ) -> DispatchResult {
+            Self::do_set_dissolution_payout(origin, payout)
+        }
+
+        /// Deregisters a neuron from a subnet for the coldkey owning its hotkey, and refunds its
+        /// registration deposit. The last neuron of the subnet takes its uid.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the coldkey owning the hotkey of the neuron.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet.
+        ///
+        /// * `uid` (u16):
+        ///     - The uid of the neuron.
+        ///
+        /// # Raises:
+        /// * `HotKeyNotRegisteredInSubNet`:
+        ///     - There is no neuron under the uid.
+        ///
+        /// * `NonAssociatedColdKey`:
+        ///     - The hotkey of the neuron is owned by another coldkey.
+        ///
+        /// * `CannotDeregisterOwnerHotkey`:
+        ///     - The neuron is the hotkey of the subnet owner.
+        #[pallet::call_index(153)]
+        #[pallet::weight((Weight::from_parts(90_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(40))
+		.saturating_add(T::DbWeight::get().writes(40)), DispatchClass::Normal, Pays::Yes))]
+        pub fn deregister_uid(origin: OriginFor<T>, netuid: NetUid, uid: u16) -> DispatchResult {
+            Self::do_deregister_uid(origin, netuid, uid)
+        }
+
+        /// Swaps the hotkey of a neuron on a single subnet, e.g. to rotate a compromised key on
+        /// one subnet without touching the others. This is `swap_hotkey` with `Some(netuid)`: the
+        /// uid, the stake on the subnet, the weights and bonds and the child keys move to the new
+        /// hotkey, which must not be registered on any subnet. The old hotkey keeps its
+        /// registrations and stake on the other subnets.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the coldkey owning the old hotkey.
+        ///
+        /// * `old_hotkey` (T::AccountId):
+        ///     - The hotkey to swap.
+        ///
+        /// * `new_hotkey` (T::AccountId):
+        ///     - The hotkey taking its place on the subnet.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet.
+        ///
+        /// # Raises:
+        /// * `NonAssociatedColdKey`:
+        ///     - The coldkey does not own the old hotkey.
+        ///
+        /// * `HotKeyAlreadyRegisteredInSubNet`:
+        ///     - The new hotkey is registered on a subnet.
+        ///
+        /// * `HotKeySwapOnSubnetIntervalNotPassed`:
+        ///     - The coldkey swapped a hotkey on the subnet too recently.
+        ///
+        /// * `NotEnoughBalanceToPaySwapHotKey`:
+        ///     - The coldkey cannot pay the swap cost.
+        #[pallet::call_index(154)]
+        #[pallet::weight((Weight::from_parts(275_300_000, 0)
+		.saturating_add(T::DbWeight::get().reads(47))
+		.saturating_add(T::DbWeight::get().writes(37)), DispatchClass::Normal, Pays::No))]
+        pub fn swap_hotkey_on_subnet(
+            origin: OriginFor<T>,
+            old_hotkey: T::AccountId,
+            new_hotkey: T::AccountId,
+            netuid: NetUid,
+        ) -> DispatchResultWithPostInfo {
+            Self::swap_hotkey(origin, old_hotkey, new_hotkey, Some(netuid))
+        }
+
+        /// Cancels the coldkey swap scheduled by the signing coldkey, before its execution block.
+        /// The swap cost is only charged when the swap executes, so nothing is refunded.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the coldkey that scheduled the swap.
+        ///
+        /// # Raises:
+        /// * `NoColdkeySwapScheduled`:
+        ///     - The coldkey has no swap scheduled.
+        ///
+        /// * `ColdkeySwapNotCancellable`:
+        ///     - The execution block of the swap has been reached, or the swap was scheduled
+        ///       before swaps could be cancelled.
+        #[pallet::call_index(155)]
+        #[pallet::weight((Weight::from_parts(31_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::Yes))]
+        pub fn cancel_scheduled_coldkey_swap(origin: OriginFor<T>) -> DispatchResult {
+            Self::do_cancel_scheduled_coldkey_swap(origin)
+        }
+
+        /// Registers the guardians of the signing coldkey. Its scheduled swaps then only execute
+        /// if `threshold` of the guardians approve them with `approve_coldkey_swap` before the
+        /// execution block. The guardians cannot be changed once set, they are dropped when the
+        /// coldkey is swapped.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the coldkey.
+        ///
+        /// * `guardians` (Vec<T::AccountId>):
+        ///     - The guardian accounts, at most `MAX_COLDKEY_GUARDIANS`.
+        ///
+        /// * `threshold` (u16):
+        ///     - The number of guardians that must approve a swap.
+        ///
+        /// # Raises:
+        /// * `ColdkeyGuardiansAlreadySet`:
+        ///     - The coldkey already has guardians.
+        ///
+        /// * `SwapAlreadyScheduled`:
+        ///     - The coldkey has a swap scheduled.
+        ///
+        /// * `InvalidColdkeyGuardians`:
+        ///     - The threshold is zero or above the number of guardians, there are too many
+        ///       guardians, a guardian is repeated or the coldkey is its own guardian.
+        #[pallet::call_index(156)]
+        #[pallet::weight((Weight::from_parts(24_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_coldkey_guardians(
+            origin: OriginFor<T>,
+            guardians: Vec<T::AccountId>,
+            threshold: u16,
+        ) -> DispatchResult {
+            Self::do_set_coldkey_guardians(origin, guardians, threshold)
+        }
+
+        /// Approves, as a guardian of `coldkey`, its scheduled swap to `new_coldkey`. The swap
+        /// executes only if enough guardians approve it before its execution block.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the guardian.
+        ///
+        /// * `coldkey` (T::AccountId):
+        ///     - The coldkey whose swap is approved.
+        ///
+        /// * `new_coldkey` (T::AccountId):
+        ///     - The coldkey the swap is scheduled to.
+        ///
+        /// # Raises:
+        /// * `NotColdkeyGuardian`:
+        ///     - The signer is not a guardian of the coldkey.
+        ///
+        /// * `NoColdkeySwapScheduled`:
+        ///     - The coldkey has no swap scheduled.
+        ///
+        /// * `ColdkeySwapTargetMismatch`:
+        ///     - The swap is scheduled to another coldkey.
+        ///
+        /// * `ColdkeySwapApprovalClosed`:
+        ///     - The execution block of the swap has been reached.
+        ///
+        /// * `ColdkeySwapAlreadyApproved`:
+        ///     - The guardian already approved the swap.
+        #[pallet::call_index(157)]
+        #[pallet::weight((Weight::from_parts(26_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn approve_coldkey_swap(
+            origin: OriginFor<T>,
+            coldkey: T::AccountId,
+            new_coldkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_approve_coldkey_swap(origin, coldkey, new_coldkey)
+        }
+
+        /// Offers to lease the validation rights of a hotkey on a subnet to a renter coldkey for
+        /// a number of blocks, for a share of the take of the hotkey on the subnet. The renter
+        /// starts the lease with `accept_hotkey_lease`.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the coldkey owning the hotkey.
+        ///
+        /// * `hotkey` (T::AccountId):
+        ///     - The hotkey to lease, registered on the subnet.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet.
+        ///
+        /// * `renter` (T::AccountId):
+        ///     - The coldkey the lease is offered to.
+        ///
+        /// * `renter_share` (u16):
+        ///     - The share of the take of the hotkey paid to the renter, in u16::MAX parts.
+        ///
+        /// * `duration` (u64):
+        ///     - The number of blocks the lease runs for once accepted.
+        ///
+        /// # Raises:
+        /// * `NonAssociatedColdKey`:
+        ///     - The coldkey does not own the hotkey.
+        ///
+        /// * `HotKeyNotRegisteredInSubNet`:
+        ///     - The hotkey is not registered on the subnet.
+        ///
+        /// * `HotkeyLeased`:
+        ///     - The hotkey is already leased on the subnet.
+        ///
+        /// * `InvalidHotkeyLease`:
+        ///     - The subnet is root, the duration is zero or the renter is the owner.
+        #[pallet::call_index(158)]
+        #[pallet::weight((Weight::from_parts(25_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(5))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn offer_hotkey_lease(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: NetUid,
+            renter: T::AccountId,
+            renter_share: u16,
+            duration: u64,
+        ) -> DispatchResult {
+            Self::do_offer_hotkey_lease(origin, hotkey, netuid, renter, renter_share, duration)
+        }
+
+        /// Accepts the lease offered for a hotkey on a subnet. Until the lease expires, the
+        /// operator is the only account setting weights for the hotkey on the subnet, and the
+        /// renter is paid its share of the take of the hotkey.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the renter coldkey.
+        ///
+        /// * `hotkey` (T::AccountId):
+        ///     - The leased hotkey.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet.
+        ///
+        /// * `operator` (T::AccountId):
+        ///     - The hotkey of the renter setting weights for the leased hotkey.
+        ///
+        /// # Raises:
+        /// * `NoHotkeyLeaseOffer`:
+        ///     - No lease of the hotkey is offered to the renter on the subnet.
+        ///
+        /// * `HotkeyLeased`:
+        ///     - The hotkey is already leased on the subnet.
+        ///
+        /// * `InvalidWeightSetterDelegate`:
+        ///     - The operator is the hotkey, is registered on the subnet or already operates a
+        ///       hotkey on it.
+        #[pallet::call_index(159)]
+        #[pallet::weight((Weight::from_parts(27_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(3)), DispatchClass::Normal, Pays::Yes))]
+        pub fn accept_hotkey_lease(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: NetUid,
+            operator: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_accept_hotkey_lease(origin, hotkey, netuid, operator)
+        }
+
+        /// Schedules the children of a hotkey on several subnets in one call, each with the
+        /// checks and the rate limit of `set_children` on its subnet. The call fails without
+        /// scheduling anything if any subnet of the batch fails.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the coldkey owning the hotkey.
+        ///
+        /// * `hotkey` (T::AccountId):
+        ///     - The parent hotkey.
+        ///
+        /// * `children_batch` (Vec<(NetUid, Vec<(u64, T::AccountId)>)>):
+        ///     - The children and proportions of the hotkey on each subnet, at most
+        ///       `MAX_CHILDREN_BATCH_LEN` subnets.
+        ///
+        /// # Raises:
+        /// * `InvalidChildrenBatch`:
+        ///     - The batch is empty, too large or sets a subnet twice.
+        ///
+        /// * `TxRateLimitExceeded`:
+        ///     - The children of the hotkey were set on a subnet of the batch too recently.
+        ///
+        /// * Any other error of `set_children` on a subnet of the batch.
+        #[pallet::call_index(160)]
+        #[pallet::weight((Weight::from_parts(10_000_000, 0)
+		.saturating_add(Weight::from_parts(119_000_000, 0).saturating_mul(children_batch.len() as u64))
+		.saturating_add(T::DbWeight::get().reads((children_batch.len() as u64).saturating_mul(6)))
+		.saturating_add(T::DbWeight::get().writes((children_batch.len() as u64).saturating_mul(31))), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_children_batch(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            children_batch: Vec<(NetUid, Vec<(u64, T::AccountId)>)>,
+        ) -> DispatchResult {
+            Self::do_schedule_children_batch(origin, hotkey, children_batch)
+        }
+
+        /// Pauses the emission into a subnet, as an emergency brake when its incentive mechanism
+        /// is found to be exploitable. The subnet is left out of the per-block distribution until
+        /// resumed, staking and weights are unaffected.
+        /// The caller must be root
+        #[pallet::call_index(161)]
+        #[pallet::weight((Weight::from_parts(10_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+        pub fn pause_subnet_emission(origin: OriginFor<T>, netuid: NetUid) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::do_pause_subnet_emission(netuid)
+        }
+
+        /// Resumes the emission into a subnet paused with `pause_subnet_emission`.
+        /// The caller must be root
+        #[pallet::call_index(162)]
+        #[pallet::weight((Weight::from_parts(10_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+        pub fn resume_subnet_emission(origin: OriginFor<T>, netuid: NetUid) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::do_resume_subnet_emission(netuid)
+        }
+
+        /// Proposes a spend of alpha from the treasury of a subnet. The spend is made right away
+        /// if the subnet has no treasury approval threshold, and waits for the approval of its
+        /// stakeholders otherwise, replacing the spend pending.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the subnet owner.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet.
+        ///
+        /// * `hotkey` (T::AccountId):
+        ///     - The hotkey the alpha is staked to.
+        ///
+        /// * `beneficiary` (T::AccountId):
+        ///     - The coldkey the alpha is staked for.
+        ///
+        /// * `amount` (AlphaCurrency):
+        ///     - The alpha spent.
+        ///
+        /// # Raises:
+        /// * `HotKeyAccountNotExists`:
+        ///     - The hotkey does not exist.
+        ///
+        /// * `InsufficientSubnetTreasury`:
+        ///     - The amount is zero or above the treasury of the subnet.
+        #[pallet::call_index(163)]
+        #[pallet::weight((Weight::from_parts(45_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(10))
+		.saturating_add(T::DbWeight::get().writes(8)), DispatchClass::Normal, Pays::Yes))]
+        pub fn spend_subnet_treasury(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            hotkey: T::AccountId,
+            beneficiary: T::AccountId,
+            amount: AlphaCurrency,
+        ) -> DispatchResult {
+            Self::do_spend_subnet_treasury(origin, netuid, hotkey, beneficiary, amount)
+        }
+
+        /// Approves the treasury spend pending on a subnet with the stake of the signing hotkey
+        /// on the subnet. The spend is made once the hotkeys that approved it hold the treasury
+        /// approval threshold of the alpha of the subnet.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the approving hotkey.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet.
+        ///
+        /// * `id` (u32):
+        ///     - The id of the spend pending.
+        ///
+        /// # Raises:
+        /// * `NoSubnetTreasurySpend`:
+        ///     - The subnet has no spend pending with this id.
+        ///
+        /// * `SubnetTreasurySpendAlreadyApproved`:
+        ///     - The hotkey has already approved the spend.
+        ///
+        /// * `NotEnoughStakeToApprove`:
+        ///     - The hotkey has no stake on the subnet.
+        #[pallet::call_index(164)]
+        #[pallet::weight((Weight::from_parts(45_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(10))
+		.saturating_add(T::DbWeight::get().writes(8)), DispatchClass::Normal, Pays::Yes))]
+        pub fn approve_subnet_treasury_spend(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            id: u32,
+        ) -> DispatchResult {
+            Self::do_approve_subnet_treasury_spend(origin, netuid, id)
+        }
+
+        /// Proposes a change of a sensitive hyperparameter of a subnet to the vote of its
+        /// stakeholders, who vote on it for the hyperparameter voting period of the subnet.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the subnet owner.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet.
+        ///
+        /// * `change` (HyperparameterChange):
+        ///     - The hyperparameter change proposed.
+        ///
+        /// # Raises:
+        /// * `HyperparameterVotingDisabled`:
+        ///     - The subnet does not vote on hyperparameter changes.
+        ///
+        /// * `HyperparameterProposalPending`:
+        ///     - The subnet already has a change pending.
+        #[pallet::call_index(165)]
+        #[pallet::weight((Weight::from_parts(25_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(6))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::Yes))]
+        pub fn propose_hyperparameter_change(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            change: HyperparameterChange,
+        ) -> DispatchResult {
+            Self::do_propose_hyperparameter_change(origin, netuid, change)
+        }
+
+        /// Votes for or against the hyperparameter change pending on a subnet with the stake of
+        /// the signing hotkey on the subnet.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - The signature of the voting hotkey.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet.
+        ///
+        /// * `id` (u32):
+        ///     - The id of the change pending.
+        ///
+        /// * `aye` (bool):
+        ///     - Whether the hotkey votes for the change.
+        ///
+        /// # Raises:
+        /// * `NoHyperparameterProposal`:
+        ///     - The subnet has no change pending with this id.
+        ///
+        /// * `HyperparameterVotingClosed`:
+        ///     - The voting period of the change is over.
+        ///
+        /// * `NotEnoughStakeToVote`:
+        ///     - The hotkey does not have the stake to set weights on the subnet.
+        ///
+        /// * `TooManyHyperparameterVoters`:
+        ///     - `MAX_HYPERPARAMETER_VOTERS` hotkeys already voted on the change.
+        #[pallet::call_index(166)]
+        #[pallet::weight((Weight::from_parts(25_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(6))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::Yes))]
+        pub fn vote_hyperparameter_change(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            id: u32,
+            aye: bool,
+        ) -> DispatchResult {
+            Self::do_vote_hyperparameter_change(origin, netuid, id, aye)
+        }
+
+        /// Closes the vote on the hyperparameter change pending on a subnet once its voting
+        /// period is over, and makes the change if the alpha of the hotkeys that voted for it is
+        /// above the alpha of those that voted against it.
+        ///
+        /// # Args:
+        /// * `origin` - (<T as frame_system::Config>::Origin):
+        ///     - Any signature.
+        ///
+        /// * `netuid` (NetUid):
+        ///     - The subnet.
+        ///
+        /// * `id` (u32):
+        ///     - The id of the change pending.
+        ///
+        /// # Raises:
+        /// * `NoHyperparameterProposal`:
+        ///     - The subnet has no change pending with this id.
+        ///
+        /// * `HyperparameterVotingOpen`:
+        ///     - The voting period of the change is not over yet.
+        #[pallet::call_index(167)]
+        #[pallet::weight((
+            Pallet::<T>::enact_hyperparameter_change_weight(MAX_HYPERPARAMETER_VOTERS),
+            DispatchClass::Normal,
+            Pays::Yes
+        ))]
+        pub fn enact_hyperparameter_change(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            id: u32,
+        ) -> DispatchResultWithPostInfo {
+            Self::do_enact_hyperparameter_change(origin, netuid, id)
+        }
+
+        /// Pays TAO out of the network treasury, funded by the share of transaction fees and
+        /// slashes it keeps and by the swap protocol fees paid into it.
+        /// The caller must be root, which the council reaches through `sudo`
+        ///
+        /// # Raises:
+        /// * `InsufficientNetworkTreasury`:
+        ///     - The amount is zero or above the network treasury.
+        #[pallet::call_index(168)]
+        #[pallet::weight((Weight::from_parts(25_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Operational, Pays::No))]
+        pub fn spend_network_treasury(
+            origin: OriginFor<T>,
+            beneficiary: T::AccountId,
+            amount: TaoCurrency,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::do_spend_network_treasury(beneficiary, amount)
+        }
     }
 }
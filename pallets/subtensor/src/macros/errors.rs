@@ -262,5 +262,198 @@ mod errors {
         UidMapCouldNotBeCleared,
         /// Trimming would exceed the max immune neurons percentage
         TrimmingWouldExceedMaxImmunePercentage,
+        /// No hyperparameter bundle is staged for the subnet.
+        NoStagedHyperparameters,
+        /// The staged hyperparameter bundle has not been evaluated in a shadow epoch yet.
+        StagedHyperparametersNotEvaluated,
+        /// The account is reserved for the protocol and cannot be used here.
+        AccountIsReserved,
+        /// The account is not in the reserved account registry.
+        AccountNotReserved,
+        /// The account is already used as a hotkey or subnet owner and cannot be reserved.
+        ReservedAccountInUse,
+        /// The coldkey has an unstake timelock and is not unlocked.
+        UnstakeTimelocked,
+        /// The unstake timelock exceeds the maximum.
+        UnstakeTimelockTooLong,
+        /// The coldkey has no unstake timelock.
+        UnstakeTimelockNotSet,
+        /// The unstake is not larger than the slicing threshold of the subnet.
+        UnstakeBelowSlicingThreshold,
+        /// The duration of the sliced unstake is zero or exceeds the maximum.
+        InvalidSlicedUnstakeDuration,
+        /// Too many sliced unstakes are queued.
+        TooManySlicedUnstakes,
+        /// The sliced unstake does not exist or belongs to another coldkey.
+        SlicedUnstakeNotFound,
+        /// The manager has no stake allowance from the coldkey on the subnet.
+        StakeAllowanceNotFound,
+        /// The amount is zero or exceeds the stake allowance of the manager.
+        InsufficientStakeAllowance,
+        /// The subnet feature bitmask has undefined bits set.
+        InvalidSubnetFeatures,
+        /// The hotkey is blacklisted on the subnet.
+        HotkeyBlacklisted,
+        /// The hotkey is not blacklisted on the subnet.
+        HotkeyNotBlacklisted,
+        /// The subnet has too many blacklisted hotkeys.
+        TooManyBlacklistedHotkeys,
+        /// The blacklist duration is zero or exceeds the maximum.
+        InvalidBlacklistDuration,
+        /// The hotkey or coldkey is denied registrations on the subnet.
+        RegistrationDenied,
+        /// Neither the hotkey nor the coldkey is on the registration allowlist of the subnet.
+        RegistrationNotAllowlisted,
+        /// The subnet has too many registration access list entries.
+        TooManyRegistrationAccessEntries,
+        /// The account is not on the registration access list of the subnet.
+        RegistrationAccessEntryNotFound,
+        /// The coldkey has too many scheduled operations.
+        TooManyScheduledOperations,
+        /// Only timelocked weight commits are accepted on the subnet.
+        TimelockedWeightsRequired,
+        /// The reveal window is shorter than the minimum reveal window.
+        RevealWindowTooSmall,
+        /// The commit reveal bounds are invalid.
+        InvalidCommitRevealBounds,
+        /// The reveal window of the commit has closed.
+        RevealWindowClosed,
+        /// No reveals, or more reveals than a hotkey can have pending commits.
+        InvalidNumberOfReveals,
+        /// The tempo in seconds is zero.
+        InvalidTempoSeconds,
+        /// The operator is the hotkey itself, is registered on the subnet or already operates for
+        /// another hotkey.
+        InvalidWeightSetterDelegate,
+        /// The account is not a weight setter delegate on the subnet.
+        WeightSetterDelegateNotFound,
+        /// Merkle weights are not enabled on the subnet.
+        MerkleWeightsDisabled,
+        /// No merkle weights commit of the hotkey was found.
+        NoMerkleWeightsCommit,
+        /// The number of merkle weight entries is invalid.
+        InvalidMerkleWeightsEntries,
+        /// A merkle weight entry is zero or does not match the committed root.
+        InvalidMerkleWeightProof,
+        /// The oracle is not authorized to attest subnet facts.
+        SubnetAttestationOracleNotAuthorized,
+        /// The attestation is not signed by its oracle.
+        InvalidSubnetAttestationSignature,
+        /// The attestation was signed too long ago, or at a future block.
+        StaleSubnetAttestation,
+        /// The oracle has already attested this fact about the subnet.
+        DuplicateSubnetAttestation,
+        /// The validator has already sent a heartbeat on the subnet this tempo.
+        HeartbeatTooFast,
+        /// Alpha high is above the maximum liquid alpha.
+        AlphaHighTooHigh,
+        /// The liquid alpha bounds are invalid.
+        InvalidLiquidAlphaBounds,
+        /// The runtime is not built with the developer extrinsics.
+        DevModeDisabled,
+        /// The developer faucet amount is above its maximum.
+        DevFaucetAmountTooHigh,
+        /// The TAO reserve of the subnet does not cover the debit.
+        SubnetTaoInsolvent,
+        /// Burned registrations are not auctioned on the subnet.
+        BurnAuctionDisabled,
+        /// Burned registrations are auctioned on the subnet, bid for the registration instead.
+        RegistrationByAuctionOnly,
+        /// The bid is below the burn, the previous bid of the hotkey or the lowest bid.
+        RegistrationBidTooLow,
+        /// The subnet has started emitting or has too many neurons to be bootstrapped.
+        SubnetBootstrapClosed,
+        /// The neurons would take the subnet above its bootstrap or max allowed uids.
+        TooManyBootstrapNeurons,
+        /// The coldkey has used its registrations on the subnet this interval.
+        TooManyColdkeyRegistrationsThisInterval,
+        /// The hotkey is already waiting in the registration queue of the subnet.
+        HotKeyAlreadyQueued,
+        /// The registration queue of the subnet is full.
+        RegistrationQueueFull,
+        /// The subnet has the maximum number of uids with an immunity override.
+        TooManyNeuronImmunityOverrides,
+        /// The registration deposit share is above the maximum.
+        RegistrationDepositShareTooHigh,
+        /// The hotkey of the subnet owner cannot be deregistered.
+        CannotDeregisterOwnerHotkey,
+        /// The coldkey has no swap scheduled.
+        NoColdkeySwapScheduled,
+        /// The scheduled coldkey swap is due, or was scheduled before swaps could be cancelled.
+        ColdkeySwapNotCancellable,
+        /// The coldkey already has guardians.
+        ColdkeyGuardiansAlreadySet,
+        /// The guardians or the approval threshold of the coldkey are invalid.
+        InvalidColdkeyGuardians,
+        /// The signer is not a guardian of the coldkey.
+        NotColdkeyGuardian,
+        /// The coldkey swap is scheduled to another coldkey.
+        ColdkeySwapTargetMismatch,
+        /// The execution block of the coldkey swap has been reached.
+        ColdkeySwapApprovalClosed,
+        /// The guardian already approved the coldkey swap.
+        ColdkeySwapAlreadyApproved,
+        /// The coldkey swap has not been approved by enough guardians.
+        ColdkeySwapNotApproved,
+        /// The hotkey is leased to another operator on the subnet.
+        HotkeyLeased,
+        /// The hotkey lease is on the root subnet, has no duration or is offered to the owner.
+        InvalidHotkeyLease,
+        /// The hotkey has no lease offered to the renter on the subnet.
+        NoHotkeyLeaseOffer,
+        /// The children batch is empty, too large or sets a subnet twice.
+        InvalidChildrenBatch,
+        /// The miner emission share is outside the bounds set by root.
+        MinerEmissionShareOutOfBounds,
+        /// The minimum miner emission share is above the maximum.
+        InvalidMinerEmissionShareBounds,
+        /// The emission into the subnet is already paused.
+        SubnetEmissionAlreadyPaused,
+        /// The emission into the subnet is not paused.
+        SubnetEmissionNotPaused,
+        /// The owner cut vesting is shorter than the current vesting of the subnet.
+        OwnerCutVestingTooShort,
+        /// The owner cut vesting is longer than `MAX_OWNER_CUT_VESTING_BLOCKS`.
+        OwnerCutVestingTooLong,
+        /// The treasury approval threshold of a subnet cannot be lowered.
+        SubnetTreasuryApprovalLowered,
+        /// The treasury of the subnet does not hold the alpha spent.
+        InsufficientSubnetTreasury,
+        /// The subnet has no treasury spend pending with this id.
+        NoSubnetTreasurySpend,
+        /// The hotkey has already approved the treasury spend.
+        SubnetTreasurySpendAlreadyApproved,
+        /// The hotkey has no stake on the subnet to approve a treasury spend with.
+        NotEnoughStakeToApprove,
+        /// The maximum subnet emission share cannot be zero.
+        InvalidMaxSubnetEmissionShare,
+        /// The hyperparameter voting period is shorter than the current voting period of the subnet.
+        HyperparameterVotingPeriodTooShort,
+        /// The hyperparameter voting period is longer than `MAX_HYPERPARAMETER_VOTING_PERIOD`.
+        HyperparameterVotingPeriodTooLong,
+        /// The hyperparameter is only changed by a vote of the stakeholders of the subnet.
+        HyperparameterRequiresVote,
+        /// The subnet does not vote on hyperparameter changes.
+        HyperparameterVotingDisabled,
+        /// The subnet already has a hyperparameter change pending.
+        HyperparameterProposalPending,
+        /// The subnet has no hyperparameter change pending with this id.
+        NoHyperparameterProposal,
+        /// The voting period of the hyperparameter change is over.
+        HyperparameterVotingClosed,
+        /// The voting period of the hyperparameter change is not over yet.
+        HyperparameterVotingOpen,
+        /// The hotkey has already cast this vote on the hyperparameter change.
+        HyperparameterAlreadyVoted,
+        /// The hotkey does not have the stake to set weights on the subnet to vote with.
+        NotEnoughStakeToVote,
+        /// The hyperparameter change has been voted on by `MAX_HYPERPARAMETER_VOTERS` hotkeys.
+        TooManyHyperparameterVoters,
+        /// The network treasury does not hold enough TAO for the spend.
+        InsufficientNetworkTreasury,
+        /// The bonds moving average is above `MAX_OWNER_BONDS_MOVING_AVERAGE` for a subnet owner.
+        BondsMovingAverageMaxReached,
+        /// Only root sets a negative alpha sigmoid steepness.
+        NegativeSigmoidSteepness,
     }
 }
@@ -4,6 +4,11 @@ use frame_support::pallet_macros::pallet_section;
 /// This can later be imported into the pallet using [`import_section`].
 #[pallet_section]
 mod events {
+    use crate::epoch::consensus::ConsensusEngineKind;
+    use crate::staking::dissolution_payout::DissolutionPayout;
+    use crate::subnets::attestations::SubnetAttestationKind;
+    use crate::subnets::neuron_status::NeuronStatus;
+    use crate::subnets::registration_access::{RegistrationAccess, RegistrationAccount};
     use codec::Compact;
 
     #[pallet::event]
@@ -52,6 +57,8 @@ mod events {
         MaxAllowedUidsSet(NetUid, u16),
         /// the max weight limit has been set for a subnetwork.
         MaxWeightLimitSet(NetUid, u16),
+        /// the max weight limit of a subnetwork has been made adaptive, or fixed again.
+        AdaptiveMaxWeightLimitSet(NetUid, u16),
         /// the difficulty has been set for a subnet.
         DifficultySet(NetUid, u64),
         /// the adjustment interval is set for a subnet.
@@ -76,8 +83,26 @@ mod events {
         ScalingLawPowerSet(NetUid, u16),
         /// weights set rate limit has been set for a subnet.
         WeightsSetRateLimitSet(NetUid, u64),
+        /// the weights set rate limit of a uid has been overridden, or the override removed.
+        WeightsSetRateLimitOverrideSet {
+            /// The subnet of the uid.
+            netuid: NetUid,
+            /// The uid the rate limit applies to.
+            uid: u16,
+            /// The rate limit of the uid, `None` for the rate limit of the subnet.
+            limit: Option<u64>,
+        },
         /// immunity period is set for a subnet.
         ImmunityPeriodSet(NetUid, u16),
+        /// the immunity period of a uid has been extended, or the extension removed.
+        NeuronImmunityOverrideSet {
+            /// The subnet of the uid.
+            netuid: NetUid,
+            /// The uid the immunity period applies to.
+            uid: u16,
+            /// The immunity period of the uid, `None` for the immunity period of the subnet.
+            immunity_period: Option<u64>,
+        },
         /// bonds moving average is set for a subnet.
         BondsMovingAverageSet(NetUid, u64),
         /// bonds penalty is set for a subnet.
@@ -136,10 +161,23 @@ mod events {
         RAORecycledForRegistrationSet(NetUid, TaoCurrency),
         /// min stake is set for validators to set weights.
         StakeThresholdSet(u64),
+        /// the minimum coldkey balance or stake for free extrinsics is set.
+        FreeExtrinsicMinBalanceSet(TaoCurrency),
         /// setting the minimum required stake amount for senate registration.
         SenateRequiredStakePercentSet(u64),
         /// setting the adjustment alpha on a subnet.
         AdjustmentAlphaSet(NetUid, u64),
+        /// the gains of the difficulty controller of a subnet are set, all zero disables it.
+        DifficultyControllerGainsSet {
+            /// The subnet.
+            netuid: NetUid,
+            /// Gain of the error, in millionths.
+            kp: u32,
+            /// Gain of the integral, in millionths.
+            ki: u32,
+            /// Gain of the derivative, in millionths.
+            kd: u32,
+        },
         /// the faucet it called on the test net.
         Faucet(T::AccountId, u64),
         /// the subnet owner cut is set.
@@ -456,5 +494,811 @@ mod events {
             /// The account ID of the hotkey.
             hotkey: T::AccountId,
         },
+
+        /// A hyperparameter bundle has been staged for a subnet.
+        HyperparametersStaged {
+            /// The network identifier.
+            netuid: NetUid,
+            /// Number of staged changes.
+            count: u32,
+        },
+
+        /// The staged hyperparameter bundle of a subnet has been evaluated in a shadow epoch.
+        StagedHyperparametersEvaluated {
+            /// The network identifier.
+            netuid: NetUid,
+        },
+
+        /// The staged hyperparameter bundle of a subnet has been applied.
+        StagedHyperparametersCommitted {
+            /// The network identifier.
+            netuid: NetUid,
+        },
+
+        /// The staged hyperparameter bundle of a subnet has been discarded.
+        StagedHyperparametersRolledBack {
+            /// The network identifier.
+            netuid: NetUid,
+        },
+
+        /// An account has been reserved for the protocol.
+        ReservedAccountAdded {
+            /// The reserved account.
+            account: T::AccountId,
+            /// What the account is reserved for.
+            kind: ReservedAccountKind,
+        },
+
+        /// An account is no longer reserved for the protocol.
+        ReservedAccountRemoved {
+            /// The released account.
+            account: T::AccountId,
+        },
+
+        /// A coldkey has set or raised its unstake timelock.
+        UnstakeTimelockSet {
+            /// The coldkey.
+            coldkey: T::AccountId,
+            /// The timelock in blocks.
+            blocks: u64,
+        },
+
+        /// A coldkey has scheduled a decrease of its unstake timelock.
+        UnstakeTimelockDecreaseScheduled {
+            /// The coldkey.
+            coldkey: T::AccountId,
+            /// The lowered timelock in blocks.
+            blocks: u64,
+            /// Block from which the lowered timelock applies.
+            applies_at: u64,
+        },
+
+        /// A coldkey has requested to be unlocked for unstaking.
+        UnstakeUnlockRequested {
+            /// The coldkey.
+            coldkey: T::AccountId,
+            /// Block from which the coldkey is unlocked.
+            unlocked_at: u64,
+        },
+
+        /// A sliced unstake has been queued.
+        SlicedUnstakeScheduled {
+            /// The id of the sliced unstake.
+            id: u64,
+            /// The coldkey unstaking.
+            coldkey: T::AccountId,
+            /// The hotkey the stake is removed from.
+            hotkey: T::AccountId,
+            /// The network identifier.
+            netuid: NetUid,
+            /// The alpha to unstake.
+            alpha: AlphaCurrency,
+            /// The alpha unstaked per block.
+            slice: AlphaCurrency,
+            /// The last block at which a slice is executed.
+            expires_at: u64,
+        },
+
+        /// A sliced unstake has completed, expired or been cancelled.
+        SlicedUnstakeFinished {
+            /// The id of the sliced unstake.
+            id: u64,
+            /// The alpha left unsold.
+            remaining: AlphaCurrency,
+        },
+
+        /// The pool depth fraction above which unstakes may be sliced has been set.
+        SlicedUnstakeDepthFractionSet(u16),
+
+        /// The registration churn parameters of a subnet have been set.
+        ChurnBurnParamsSet {
+            /// The network identifier.
+            netuid: NetUid,
+            /// Re-registrations within the window tolerated before the burn escalates.
+            threshold: u16,
+            /// The churn window in blocks.
+            window: u64,
+        },
+
+        /// The burn of a coldkey on a subnet has escalated due to registration churn.
+        ChurnBurnEscalated {
+            /// The network identifier.
+            netuid: NetUid,
+            /// The churning coldkey.
+            coldkey: T::AccountId,
+            /// The multiplier now applied to the coldkey's burn.
+            multiplier: u64,
+        },
+
+        /// A coldkey has approved a manager to stake on its behalf on a subnet.
+        StakeAllowanceApproved {
+            /// The coldkey.
+            coldkey: T::AccountId,
+            /// The manager account.
+            manager: T::AccountId,
+            /// The network identifier.
+            netuid: NetUid,
+            /// The TAO the manager may stake.
+            amount: TaoCurrency,
+        },
+
+        /// A coldkey has revoked the stake allowance of a manager on a subnet.
+        StakeAllowanceRevoked {
+            /// The coldkey.
+            coldkey: T::AccountId,
+            /// The manager account.
+            manager: T::AccountId,
+            /// The network identifier.
+            netuid: NetUid,
+        },
+
+        /// A manager has staked on behalf of a coldkey using its stake allowance.
+        StakeAllowanceUsed {
+            /// The coldkey.
+            coldkey: T::AccountId,
+            /// The manager account.
+            manager: T::AccountId,
+            /// The network identifier.
+            netuid: NetUid,
+            /// The TAO staked.
+            amount: TaoCurrency,
+            /// The allowance left.
+            remaining: TaoCurrency,
+        },
+
+        /// The feature bitmask of a subnet has been set.
+        SubnetFeaturesSet {
+            /// The network identifier.
+            netuid: NetUid,
+            /// The feature bitmask.
+            features: u16,
+        },
+
+        /// A subnet owner has blacklisted a hotkey on their subnet.
+        HotkeyBlacklisted {
+            /// The network identifier.
+            netuid: NetUid,
+            /// The blacklisted hotkey.
+            hotkey: T::AccountId,
+            /// Block at which the blacklisting expires.
+            expires_at: u64,
+        },
+
+        /// A hotkey has been struck from the blacklist of a subnet.
+        BlacklistedHotkeyStruck {
+            /// The network identifier.
+            netuid: NetUid,
+            /// The hotkey.
+            hotkey: T::AccountId,
+        },
+
+        /// A subnet owner has set the registration access of a hotkey or coldkey.
+        RegistrationAccessSet {
+            /// The network identifier.
+            netuid: NetUid,
+            /// The hotkey or coldkey.
+            account: RegistrationAccount<T::AccountId>,
+            /// Allowed or denied, `None` if removed from the access list.
+            access: Option<RegistrationAccess>,
+        },
+
+        /// A coldkey has set where its dissolution payouts are credited.
+        DissolutionPayoutSet {
+            /// The coldkey.
+            coldkey: T::AccountId,
+            /// The free balance, or root stake on a hotkey.
+            payout: DissolutionPayout<T::AccountId>,
+        },
+
+        /// The maximum number of operations a coldkey may have scheduled at once has been set.
+        MaxScheduledOperationsSet(u32),
+
+        /// Only timelocked weight commits are accepted on a subnet, or hash commits are accepted
+        /// again.
+        /// - **netuid**: The network identifier.
+        /// - **enabled**: Whether only timelocked weight commits are accepted.
+        TimelockedWeightsOnlySet(NetUid, bool),
+
+        /// The reveal window of a subnet has been set.
+        /// - **netuid**: The network identifier.
+        /// - **blocks**: The number of blocks at the start of the reveal epoch during which
+        ///   commits can be revealed, 0 for the whole epoch.
+        RevealWindowSet(NetUid, u64),
+
+        /// The bounds within which subnet owners set their commit reveal settings have been set.
+        CommitRevealBoundsSet {
+            /// Minimum commit reveal periods.
+            min_periods: u64,
+            /// Maximum commit reveal periods.
+            max_periods: u64,
+            /// Minimum reveal window, in blocks.
+            min_reveal_window: u64,
+        },
+
+        /// The consensus engine of a subnet has been selected.
+        /// - **netuid**: The network identifier.
+        /// - **engine**: The consensus engine.
+        ConsensusEngineSet(NetUid, ConsensusEngineKind),
+
+        /// The owner rate limit of a group of hyperparameters on a subnet has been set.
+        OwnerHyperparamGroupRateLimitSet {
+            /// The subnet.
+            netuid: NetUid,
+            /// The group of hyperparameters.
+            group: HyperparameterGroup,
+            /// Number of tempos, `None` to follow the global owner rate limit.
+            epochs: Option<u16>,
+        },
+
+        /// A hyperparameter of a subnet has been changed by its owner, root or a vote of its
+        /// stakeholders.
+        HyperparameterChanged {
+            /// The subnet.
+            netuid: NetUid,
+            /// The hyperparameter.
+            param: Hyperparameter,
+            /// The SCALE encoded value before the change.
+            old: Vec<u8>,
+            /// The SCALE encoded value after the change.
+            new: Vec<u8>,
+        },
+
+        /// Liquid alpha has been enabled or disabled on a subnet.
+        /// - **netuid**: The network identifier.
+        /// - **enabled**: Whether liquid alpha is enabled.
+        LiquidAlphaEnabledSet(NetUid, bool),
+
+        /// The liquid alpha values of a subnet have been set.
+        /// - **netuid**: The network identifier.
+        /// - **alpha_low**: The lower bound of the bonds moving average alpha.
+        /// - **alpha_high**: The upper bound of the bonds moving average alpha.
+        AlphaValuesSet(NetUid, u16, u16),
+
+        /// The bounds within which subnet owners set their liquid alpha values have been set.
+        LiquidAlphaBoundsSet {
+            /// Minimum alpha low and alpha high.
+            min_alpha: u16,
+            /// Maximum alpha high.
+            max_alpha: u16,
+        },
+
+        /// Pending weight commits of a hotkey have been settled at once.
+        WeightCommitsSettled {
+            /// The revealing hotkey.
+            hotkey: T::AccountId,
+            /// The mechanism the weights were committed on.
+            netuid: NetUidStorageIndex,
+            /// Hashes of the commits whose weights were set.
+            revealed_hashes: Vec<H256>,
+            /// Hashes of the revealed commits that had expired and were dropped.
+            expired_hashes: Vec<H256>,
+        },
+
+        /// The tempo of a subnet has been set in seconds.
+        /// - **netuid**: The network identifier.
+        /// - **seconds**: The target tempo in seconds, `None` if the tempo is set in blocks again.
+        TempoSecondsSet(NetUid, Option<u64>),
+
+        /// A coldkey has been attested as belonging to an institution, or the attestation has
+        /// been revoked.
+        InstitutionalAttestationSet {
+            /// The attested coldkey.
+            coldkey: T::AccountId,
+            /// Whether the coldkey is attested.
+            attested: bool,
+        },
+
+        /// An operator hotkey has been authorized to set weights for a validator hotkey.
+        WeightSetterDelegateSet {
+            /// The subnet the operator sets weights on.
+            netuid: NetUid,
+            /// The validator hotkey.
+            hotkey: T::AccountId,
+            /// The operator hotkey.
+            operator: T::AccountId,
+        },
+
+        /// The authorization of an operator hotkey to set weights has been revoked.
+        WeightSetterDelegateRemoved {
+            /// The subnet the operator set weights on.
+            netuid: NetUid,
+            /// The validator hotkey.
+            hotkey: T::AccountId,
+            /// The operator hotkey.
+            operator: T::AccountId,
+        },
+
+        /// Merkle weights have been enabled or disabled on a subnet.
+        /// - **netuid**: The network identifier.
+        /// - **enabled**: Whether weights can be set by merkle root commits.
+        MerkleWeightsEnabledSet(NetUid, bool),
+
+        /// Self-weights have been excluded from the consensus of a subnet, or included again.
+        /// - **netuid**: The network identifier.
+        /// - **excluded**: Whether the epoch masks the self-weight of the subnet owner as well.
+        SelfWeightsExcludedSet(NetUid, bool),
+
+        /// The weight expiry of a subnet has been set.
+        /// - **netuid**: The network identifier.
+        /// - **blocks**: Blocks after their last update that weights are ignored, zero for never.
+        WeightExpiryBlocksSet(NetUid, u64),
+
+        /// The minimum stake for a validator permit on a subnet has been set.
+        /// - **netuid**: The network identifier.
+        /// - **min_stake**: The stake a neuron needs for a permit, zero for none.
+        ValidatorPermitMinStakeSet(NetUid, u64),
+
+        /// A neuron has been granted a validator permit by the epoch.
+        ValidatorPermitGranted {
+            /// The subnet of the neuron.
+            netuid: NetUid,
+            /// The uid of the neuron.
+            uid: u16,
+        },
+
+        /// A neuron has lost its validator permit in the epoch.
+        ValidatorPermitRevoked {
+            /// The subnet of the neuron.
+            netuid: NetUid,
+            /// The uid of the neuron.
+            uid: u16,
+        },
+
+        /// The merkle root of the weights of a hotkey has been committed.
+        MerkleWeightsCommitted {
+            /// The hotkey the weights are set for.
+            hotkey: T::AccountId,
+            /// The mechanism the weights are set on.
+            netuid: NetUidStorageIndex,
+            /// The merkle root of the weights.
+            root: H256,
+            /// The number of non-zero entries of the weights.
+            entries: u16,
+        },
+
+        /// Entries of merkle committed weights have been submitted, with more to come.
+        MerkleWeightsReceived {
+            /// The hotkey the weights are set for.
+            hotkey: T::AccountId,
+            /// The mechanism the weights are set on.
+            netuid: NetUidStorageIndex,
+            /// The number of entries left to submit.
+            remaining: u16,
+        },
+
+        /// An oracle has been authorized to attest subnet facts, or revoked.
+        SubnetAttestationOracleSet {
+            /// The sr25519 key of the oracle.
+            oracle: sr25519::Public,
+            /// Whether the oracle is authorized.
+            authorized: bool,
+        },
+
+        /// An oracle has attested a fact about a subnet.
+        SubnetAttested {
+            /// The attested subnet.
+            netuid: NetUid,
+            /// The sr25519 key of the oracle.
+            oracle: sr25519::Public,
+            /// The attested fact.
+            kind: SubnetAttestationKind,
+        },
+
+        /// The bonds of every mechanism of a subnet have been cleared.
+        SubnetBondsReset {
+            /// The subnet.
+            netuid: NetUid,
+        },
+
+        /// A validator has signalled activity on a subnet without setting weights.
+        ValidatorHeartbeat {
+            /// The subnet.
+            netuid: NetUid,
+            /// The validator hotkey.
+            hotkey: T::AccountId,
+        },
+
+        /// Neurons can register on a subnet in every block, at its minimum burn and difficulty.
+        DevInstantRegistrationEnabled {
+            /// The subnet.
+            netuid: NetUid,
+        },
+
+        /// The rate limits of the chain and of a subnet have been set to zero.
+        DevRateLimitsDisabled {
+            /// The subnet.
+            netuid: NetUid,
+        },
+
+        /// The epoch of a subnet has been run ahead of its tempo.
+        DevTempoAdvanced {
+            /// The subnet.
+            netuid: NetUid,
+        },
+
+        /// The TAO reserve of a subnet does not cover the value of the alpha outstanding on it.
+        SubnetTaoInsolvencyDetected {
+            /// The subnet.
+            netuid: NetUid,
+            /// The TAO reserve of the subnet.
+            reserve: TaoCurrency,
+            /// The TAO the pool pays for all the outstanding alpha.
+            alpha_value: TaoCurrency,
+        },
+
+        /// A debit of the TAO reserve of a subnet made by a block hook was rejected as the
+        /// reserve could not cover it, and rolled back.
+        SubnetTaoDebitRejected {
+            /// The subnet.
+            netuid: NetUid,
+        },
+
+        /// Burned registrations of a subnet are auctioned, or first come, first served again.
+        BurnAuctionEnabledSet(NetUid, bool),
+
+        /// A bid to register a hotkey has been escrowed until the end of the interval.
+        RegistrationBidPlaced {
+            /// The subnet.
+            netuid: NetUid,
+            /// The coldkey the bid is escrowed from.
+            coldkey: T::AccountId,
+            /// The hotkey to register.
+            hotkey: T::AccountId,
+            /// The TAO bid.
+            amount: TaoCurrency,
+        },
+
+        /// A registration bid has been outbid, replaced or lost the auction, and was refunded.
+        RegistrationBidRefunded {
+            /// The subnet.
+            netuid: NetUid,
+            /// The coldkey refunded.
+            coldkey: T::AccountId,
+            /// The hotkey of the bid.
+            hotkey: T::AccountId,
+            /// The TAO refunded.
+            amount: TaoCurrency,
+        },
+
+        /// The lifecycle status of a neuron has changed.
+        NeuronStatusChanged {
+            /// The subnet.
+            netuid: NetUid,
+            /// The uid of the neuron.
+            uid: u16,
+            /// The hotkey of the neuron.
+            hotkey: T::AccountId,
+            /// The previous status.
+            from: NeuronStatus,
+            /// The new status.
+            to: NeuronStatus,
+        },
+
+        /// The registrations a coldkey is allowed per interval on a subnet have been set.
+        MaxRegistrationsPerColdkeyPerIntervalSet {
+            /// The subnet.
+            netuid: NetUid,
+            /// The registrations allowed, 0 for no quota.
+            max_registrations: u16,
+        },
+
+        /// Registrations on a full subnet are queued for a pruned slot, or prune right away again.
+        RegistrationQueueEnabledSet(NetUid, bool),
+
+        /// A registration on a full subnet has been queued, with its burn escrowed until admission.
+        RegistrationQueued {
+            /// The subnet.
+            netuid: NetUid,
+            /// The coldkey the burn is escrowed from.
+            coldkey: T::AccountId,
+            /// The hotkey to register.
+            hotkey: T::AccountId,
+            /// The TAO escrowed, zero for PoW registrations.
+            escrow: TaoCurrency,
+            /// The position of the registration in the queue, from 1.
+            position: u32,
+        },
+
+        /// A queued registration has timed out or could not be admitted, and was refunded.
+        QueuedRegistrationRefunded {
+            /// The subnet.
+            netuid: NetUid,
+            /// The coldkey refunded.
+            coldkey: T::AccountId,
+            /// The hotkey of the registration.
+            hotkey: T::AccountId,
+            /// The TAO refunded.
+            amount: TaoCurrency,
+        },
+
+        /// The registration deposit of a subnet has been set.
+        RegistrationDepositSet {
+            /// The subnet.
+            netuid: NetUid,
+            /// The share of the burn held as a deposit, in `u16::MAX` parts.
+            share: u16,
+            /// The epochs after which the deposit is refunded.
+            maturity_epochs: u16,
+        },
+
+        /// The registration deposit of a neuron has been refunded to its coldkey.
+        RegistrationDepositRefunded {
+            /// The subnet.
+            netuid: NetUid,
+            /// The uid of the neuron.
+            uid: u16,
+            /// The coldkey refunded.
+            coldkey: T::AccountId,
+            /// The TAO refunded.
+            amount: TaoCurrency,
+        },
+
+        /// The registration deposit of a neuron pruned while inactive has been burned.
+        RegistrationDepositSlashed {
+            /// The subnet.
+            netuid: NetUid,
+            /// The uid of the neuron.
+            uid: u16,
+            /// The coldkey of the deposit.
+            coldkey: T::AccountId,
+            /// The TAO burned.
+            amount: TaoCurrency,
+        },
+
+        /// A neuron has deregistered from a subnet.
+        NeuronDeregistered {
+            /// The subnet.
+            netuid: NetUid,
+            /// The uid the neuron had.
+            uid: u16,
+            /// The hotkey of the neuron.
+            hotkey: T::AccountId,
+        },
+
+        /// A scheduled coldkey swap has been cancelled.
+        ColdkeySwapCancelled {
+            /// The account ID of the old coldkey
+            old_coldkey: T::AccountId,
+            /// The account ID of the new coldkey the swap was scheduled to
+            new_coldkey: T::AccountId,
+        },
+
+        /// The guardians of a coldkey have been set.
+        ColdkeyGuardiansSet {
+            /// The coldkey.
+            coldkey: T::AccountId,
+            /// The guardian accounts.
+            guardians: Vec<T::AccountId>,
+            /// The number of guardians that must approve a swap.
+            threshold: u16,
+        },
+
+        /// A guardian has approved the scheduled swap of a coldkey.
+        ColdkeySwapApproved {
+            /// The coldkey being swapped.
+            coldkey: T::AccountId,
+            /// The coldkey the swap is scheduled to.
+            new_coldkey: T::AccountId,
+            /// The approving guardian.
+            guardian: T::AccountId,
+            /// The number of approvals so far.
+            approvals: u16,
+        },
+
+        /// The owner of a hotkey has offered to lease it on a subnet.
+        HotkeyLeaseOffered {
+            /// The subnet.
+            netuid: NetUid,
+            /// The leased hotkey.
+            hotkey: T::AccountId,
+            /// The coldkey the lease is offered to.
+            renter: T::AccountId,
+            /// The share of the take of the hotkey paid to the renter, in u16::MAX parts.
+            renter_share: u16,
+            /// The number of blocks the lease runs for.
+            duration: u64,
+        },
+
+        /// A hotkey lease has been accepted by its renter.
+        HotkeyLeaseStarted {
+            /// The subnet.
+            netuid: NetUid,
+            /// The leased hotkey.
+            hotkey: T::AccountId,
+            /// The coldkey renting the hotkey.
+            renter: T::AccountId,
+            /// The operator setting weights for the hotkey.
+            operator: T::AccountId,
+            /// The block after which the lease expires.
+            end_block: u64,
+        },
+
+        /// A hotkey lease has expired.
+        HotkeyLeaseExpired {
+            /// The subnet.
+            netuid: NetUid,
+            /// The leased hotkey.
+            hotkey: T::AccountId,
+            /// The coldkey that rented the hotkey.
+            renter: T::AccountId,
+        },
+
+        /// The share of the emission of a subnet paid to miners has been set.
+        MinerEmissionShareSet {
+            /// The subnet.
+            netuid: NetUid,
+            /// The share paid to miners, the rest is paid to validators.
+            share: Percent,
+        },
+
+        /// The bounds within which subnet owners set their miner emission share have been set.
+        MinerEmissionShareBoundsSet {
+            /// Minimum miner emission share.
+            min: Percent,
+            /// Maximum miner emission share.
+            max: Percent,
+        },
+
+        /// Root has paused the emission into a subnet.
+        SubnetEmissionPaused {
+            /// The subnet.
+            netuid: NetUid,
+        },
+
+        /// Root has resumed the emission into a subnet.
+        SubnetEmissionResumed {
+            /// The subnet.
+            netuid: NetUid,
+        },
+
+        /// The minimum number of blocks the owner cut of subnets vests over has been set.
+        MinOwnerCutVestingBlocksSet {
+            /// The minimum vesting blocks.
+            blocks: u64,
+        },
+
+        /// The number of blocks the owner cut of a subnet vests over has been set.
+        OwnerCutVestingBlocksSet {
+            /// The subnet.
+            netuid: NetUid,
+            /// The vesting blocks.
+            blocks: u64,
+        },
+
+        /// The treasury of a subnet has been configured.
+        SubnetTreasurySet {
+            /// The subnet.
+            netuid: NetUid,
+            /// The share of the owner cut redirected into the treasury, in `u16::MAX` parts.
+            share: u16,
+            /// The share of the subnet alpha approving a spend, in `u16::MAX` parts.
+            approval_threshold: u16,
+        },
+
+        /// The owner of a subnet has proposed a treasury spend.
+        SubnetTreasurySpendProposed {
+            /// The subnet.
+            netuid: NetUid,
+            /// The id of the spend.
+            id: u32,
+            /// The hotkey the alpha is staked to.
+            hotkey: T::AccountId,
+            /// The coldkey the alpha is staked for.
+            beneficiary: T::AccountId,
+            /// The alpha spent.
+            amount: AlphaCurrency,
+        },
+
+        /// A hotkey has approved a treasury spend.
+        SubnetTreasurySpendApproved {
+            /// The subnet.
+            netuid: NetUid,
+            /// The id of the spend.
+            id: u32,
+            /// The approving hotkey.
+            hotkey: T::AccountId,
+        },
+
+        /// A treasury spend has been made.
+        SubnetTreasurySpent {
+            /// The subnet.
+            netuid: NetUid,
+            /// The id of the spend.
+            id: u32,
+            /// The hotkey the alpha is staked to.
+            hotkey: T::AccountId,
+            /// The coldkey the alpha is staked for.
+            beneficiary: T::AccountId,
+            /// The alpha spent.
+            amount: AlphaCurrency,
+        },
+
+        /// The maximum share of the block emission injected into a single subnet has been set.
+        MaxSubnetEmissionShareSet {
+            /// Maximum subnet emission share.
+            share: Percent,
+        },
+
+        /// The voting period of the sensitive hyperparameters of a subnet has been set.
+        HyperparameterVotingPeriodSet {
+            /// The subnet.
+            netuid: NetUid,
+            /// The number of blocks changes are voted on.
+            blocks: u64,
+        },
+
+        /// A hyperparameter change has been proposed to the vote of the stakeholders of a subnet.
+        HyperparameterChangeProposed {
+            /// The subnet.
+            netuid: NetUid,
+            /// The id of the proposal.
+            id: u32,
+            /// The change proposed.
+            change: HyperparameterChange,
+            /// The last block the change is voted on.
+            end_block: u64,
+        },
+
+        /// A hotkey has voted on a hyperparameter change.
+        HyperparameterChangeVoted {
+            /// The subnet.
+            netuid: NetUid,
+            /// The id of the proposal.
+            id: u32,
+            /// The voting hotkey.
+            hotkey: T::AccountId,
+            /// Whether the hotkey voted for the change.
+            aye: bool,
+        },
+
+        /// A hyperparameter change has been approved by the stakeholders of a subnet and made.
+        HyperparameterChangeEnacted {
+            /// The subnet.
+            netuid: NetUid,
+            /// The id of the proposal.
+            id: u32,
+            /// The alpha of the hotkeys that voted for the change.
+            ayes: AlphaCurrency,
+            /// The alpha of the hotkeys that voted against the change.
+            nays: AlphaCurrency,
+        },
+
+        /// A hyperparameter change has been rejected by the stakeholders of a subnet.
+        HyperparameterChangeRejected {
+            /// The subnet.
+            netuid: NetUid,
+            /// The id of the proposal.
+            id: u32,
+            /// The alpha of the hotkeys that voted for the change.
+            ayes: AlphaCurrency,
+            /// The alpha of the hotkeys that voted against the change.
+            nays: AlphaCurrency,
+        },
+
+        /// The share of transaction fees and slashes kept by the network treasury has been set.
+        NetworkTreasuryShareSet {
+            /// The share, in `u16::MAX` parts.
+            share: u16,
+        },
+
+        /// TAO has been spent from the network treasury.
+        NetworkTreasurySpent {
+            /// The coldkey the TAO is paid to.
+            beneficiary: T::AccountId,
+            /// The TAO spent.
+            amount: TaoCurrency,
+        },
+
+        /// A senate member no longer registered on root has left the senate.
+        SenateMemberRemoved {
+            /// The hotkey of the member.
+            member: T::AccountId,
+        },
+
+        /// The senate hysteresis has been set.
+        SenateHysteresisSet {
+            /// The root stake a hotkey needs above a member to replace it, in `u16::MAX` parts.
+            hysteresis: u16,
+        },
     }
 }
@@ -62,7 +62,7 @@ mod genesis {
             MaxWeightsLimit::<T>::insert(netuid, u16::MAX);
             Tempo::<T>::insert(netuid, 100);
             NetworkRegistrationAllowed::<T>::insert(netuid, true);
-            SubnetOwner::<T>::insert(netuid, hotkey.clone());
+            Pallet::<T>::set_subnet_owner(netuid, &hotkey);
             SubnetLocked::<T>::insert(netuid, TaoCurrency::from(1));
             LargestLocked::<T>::insert(netuid, 1);
             Alpha::<T>::insert(
@@ -106,6 +106,9 @@ mod genesis {
             Uids::<T>::insert(netuid, hotkey.clone(), 0); // Make uid - hotkey association.
             BlockAtRegistration::<T>::insert(netuid, 0, block_number); // Fill block at registration.
             IsNetworkMember::<T>::insert(hotkey.clone(), netuid, true); // Fill network is member.
+            NeuronStatuses::<T>::mutate(netuid, |v| {
+                v.push(Pallet::<T>::get_registered_neuron_status(netuid, 0))
+            });
             TokenSymbol::<T>::insert(netuid, Pallet::<T>::get_symbol_for_subnet(netuid));
         }
     }
@@ -16,16 +16,19 @@ mod hooks {
         // 		- The number of the block we are initializing.
         fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
             let hotkey_swap_clean_up_weight = Self::clean_up_hotkey_swap_records(block_number);
+            let stepped_migrations_weight = migrations::stepped::run_stepped_migrations::<T>();
 
             let block_step_result = Self::block_step();
             match block_step_result {
-                Ok(_) => {
+                Ok(block_step_weight) => {
                     // --- If the block step was successful, return the weight.
                     log::debug!("Successfully ran block step.");
                     Weight::from_parts(110_634_229_000_u64, 0)
                         .saturating_add(T::DbWeight::get().reads(8304_u64))
                         .saturating_add(T::DbWeight::get().writes(110_u64))
                         .saturating_add(hotkey_swap_clean_up_weight)
+                        .saturating_add(stepped_migrations_weight)
+                        .saturating_add(block_step_weight)
                 }
                 Err(e) => {
                     // --- If the block step was unsuccessful, return the weight anyway.
@@ -34,6 +37,7 @@ mod hooks {
                         .saturating_add(T::DbWeight::get().reads(8304_u64))
                         .saturating_add(T::DbWeight::get().writes(110_u64))
                         .saturating_add(hotkey_swap_clean_up_weight)
+                        .saturating_add(stepped_migrations_weight)
                 }
             }
         }
@@ -47,6 +51,11 @@ mod hooks {
             for _ in StakingOperationRateLimiter::<T>::drain() {
                 // Clear all entries each block
             }
+
+            let evm_logs = PendingEvmLogs::<T>::take();
+            if !evm_logs.is_empty() {
+                T::EvmLogInterface::deposit_logs(evm_logs.into_inner());
+            }
         }
 
         fn on_runtime_upgrade() -> frame_support::weights::Weight {
@@ -151,7 +160,11 @@ mod hooks {
                 // Migrate subnet burn cost to 2500
                 .saturating_add(migrations::migrate_network_lock_cost_2500::migrate_network_lock_cost_2500::<T>())
                 // Migrate AutoStakeDestinationColdkeys
-                .saturating_add(migrations::migrate_auto_stake_destination::migrate_auto_stake_destination::<T>());
+                .saturating_add(migrations::migrate_auto_stake_destination::migrate_auto_stake_destination::<T>())
+                // Backfill the coldkey -> owned subnets index
+                .saturating_add(migrations::migrate_subnets_owned_by::migrate_subnets_owned_by::<T>())
+                // Backfill the neuron lifecycle statuses
+                .saturating_add(migrations::migrate_neuron_statuses::migrate_neuron_statuses::<T>());
             weight
         }
 
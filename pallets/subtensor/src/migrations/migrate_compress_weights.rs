@@ -0,0 +1,53 @@
+use super::*;
+use crate::migrations::stepped::SteppedMigration;
+use crate::subnets::weight_row::{WEIGHT_ROW_V1, WeightRow};
+use frame_support::{
+    storage::{StoragePrefixedMap, unhashed},
+    traits::Get,
+    weights::{Weight, WeightMeter},
+};
+use sp_std::marker::PhantomData;
+
+/// Rewrites the weight rows stored as `(uid, weight)` pairs into compressed `WeightRow`s, as many
+/// rows per step as the meter allows. Legacy rows decode until then, so the epoch and the weight
+/// extrinsics keep working while it runs.
+pub struct CompressWeights<T>(PhantomData<T>);
+
+impl<T: Config> CompressWeights<T> {
+    /// The weight of reading a row of `len` encoded bytes and writing it back, the row taking
+    /// its length in the proof.
+    pub fn row_weight(len: usize) -> Weight {
+        T::DbWeight::get()
+            .reads_writes(1, 1)
+            .saturating_add(Weight::from_parts(0, len as u64))
+    }
+}
+
+impl<T: Config> SteppedMigration<T> for CompressWeights<T> {
+    const NAME: &'static [u8] = b"migrate_compress_weights";
+
+    fn step(cursor: Option<Vec<u8>>, meter: &mut WeightMeter) -> Option<Vec<u8>> {
+        let mut cursor = cursor.unwrap_or_else(|| Weights::<T>::final_prefix().to_vec());
+        let mut keys = Weights::<T>::iter_keys_from(cursor.clone());
+        while meter.can_consume(Self::row_weight(0)) {
+            let Some((netuid_index, uid)) = keys.next() else {
+                return None;
+            };
+            let key = Weights::<T>::hashed_key_for(netuid_index, uid);
+            let raw = unhashed::get_raw(&key).unwrap_or_default();
+            // A row too large for the rest of the meter is left for the next step
+            if meter.try_consume(Self::row_weight(raw.len())).is_err() {
+                break;
+            }
+            let legacy_row = match raw.first() {
+                Some(&WEIGHT_ROW_V1) => None,
+                _ => WeightRow::decode(&mut raw.as_slice()).ok(),
+            };
+            if let Some(row) = legacy_row {
+                unhashed::put(&key, &row);
+            }
+            cursor = key;
+        }
+        Some(cursor)
+    }
+}
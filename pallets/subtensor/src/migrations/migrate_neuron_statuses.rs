@@ -0,0 +1,49 @@
+use super::*;
+use frame_support::{traits::Get, weights::Weight};
+use log;
+use scale_info::prelude::string::String;
+
+/// Backfills `NeuronStatuses` for every subnet from the activity of its last epoch.
+pub fn migrate_neuron_statuses<T: Config>() -> Weight {
+    let migration_name = b"migrate_neuron_statuses".to_vec();
+    let mut weight = T::DbWeight::get().reads(1);
+
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            target: "runtime",
+            "Migration '{}' already run - skipping.",
+            String::from_utf8_lossy(&migration_name)
+        );
+        return weight;
+    }
+
+    log::info!(
+        target: "runtime",
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    let netuids = Pallet::<T>::get_all_subnet_netuids();
+    for netuid in netuids.iter() {
+        // No events are deposited, as the subnets have no statuses yet
+        let active = Active::<T>::get(netuid);
+        let n = active.len() as u64;
+        Pallet::<T>::update_neuron_statuses(*netuid, &active);
+        // Pruning scores, registration blocks and the owner hotkeys of every uid are read
+        weight = weight.saturating_add(
+            T::DbWeight::get().reads_writes(n.saturating_mul(3).saturating_add(5), 1),
+        );
+    }
+
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        target: "runtime",
+        "Migration '{}' completed, backfilled the neuron statuses of {} subnets.",
+        String::from_utf8_lossy(&migration_name),
+        netuids.len()
+    );
+
+    weight
+}
@@ -0,0 +1,55 @@
+use super::*;
+use alloc::collections::BTreeMap;
+use frame_support::{traits::Get, weights::Weight};
+use log;
+use scale_info::prelude::string::String;
+
+/// Backfills the `SubnetsOwnedBy` index from `SubnetOwner`.
+pub fn migrate_subnets_owned_by<T: Config>() -> Weight {
+    let migration_name = b"migrate_subnets_owned_by".to_vec();
+    let mut weight = T::DbWeight::get().reads(1);
+
+    if HasMigrationRun::<T>::get(&migration_name) {
+        log::info!(
+            target: "runtime",
+            "Migration '{}' already run - skipping.",
+            String::from_utf8_lossy(&migration_name)
+        );
+        return weight;
+    }
+
+    log::info!(
+        target: "runtime",
+        "Running migration '{}'",
+        String::from_utf8_lossy(&migration_name)
+    );
+
+    // Clear any partial index, so the backfill lists every subnet exactly once
+    let removal_results = SubnetsOwnedBy::<T>::clear(u32::MAX, None);
+    weight = weight.saturating_add(T::DbWeight::get().writes(removal_results.unique as u64));
+
+    let mut owners: BTreeMap<T::AccountId, Vec<NetUid>> = BTreeMap::new();
+    for (netuid, owner) in SubnetOwner::<T>::iter() {
+        weight = weight.saturating_add(T::DbWeight::get().reads(1));
+        owners.entry(owner).or_default().push(netuid);
+    }
+
+    let indexed = owners.len();
+    for (owner, mut netuids) in owners {
+        netuids.sort();
+        SubnetsOwnedBy::<T>::insert(owner, netuids);
+        weight = weight.saturating_add(T::DbWeight::get().writes(1));
+    }
+
+    HasMigrationRun::<T>::insert(&migration_name, true);
+    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+    log::info!(
+        target: "runtime",
+        "Migration '{}' completed, indexed the subnets of {} owners.",
+        String::from_utf8_lossy(&migration_name),
+        indexed
+    );
+
+    weight
+}
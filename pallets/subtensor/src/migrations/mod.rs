@@ -9,6 +9,7 @@ pub mod migrate_chain_identity;
 pub mod migrate_coldkey_swap_scheduled;
 pub mod migrate_commit_reveal_settings;
 pub mod migrate_commit_reveal_v2;
+pub mod migrate_compress_weights;
 pub mod migrate_create_root_network;
 pub mod migrate_crv3_commits_add_block;
 pub mod migrate_crv3_v2_to_timelocked;
@@ -23,6 +24,7 @@ pub mod migrate_init_total_issuance;
 pub mod migrate_network_immunity_period;
 pub mod migrate_network_lock_cost_2500;
 pub mod migrate_network_lock_reduction_interval;
+pub mod migrate_neuron_statuses;
 pub mod migrate_orphaned_storage_items;
 pub mod migrate_populate_owned_hotkeys;
 pub mod migrate_rao;
@@ -47,11 +49,13 @@ pub mod migrate_subnet_limit_to_default;
 pub mod migrate_subnet_locked;
 pub mod migrate_subnet_symbols;
 pub mod migrate_subnet_volume;
+pub mod migrate_subnets_owned_by;
 pub mod migrate_to_v1_separate_emission;
 pub mod migrate_to_v2_fixed_total_stake;
 pub mod migrate_total_issuance;
 pub mod migrate_transfer_ownership_to_foundation;
 pub mod migrate_upgrade_revealed_commitments;
+pub mod stepped;
 
 pub(crate) fn migrate_storage<T: Config>(
     migration_name: &'static str,
@@ -0,0 +1,123 @@
+//! Multi-block migrations.
+//!
+//! The migrations of `on_runtime_upgrade` migrate every entry in the block of the upgrade, which
+//! stalls block production once the storage they rewrite, such as the `Alpha` map, has the size of
+//! the mainnet state. A stepped migration migrates entries from `on_initialize` instead, for as
+//! long as the weight budget of the block lasts, and keeps the raw storage key it stopped at in
+//! `SteppedMigrationCursor` to resume from in the next block. Once it has no entries left it is
+//! recorded in `HasMigrationRun` like the other migrations, and the next stepped migration starts.
+//!
+//! The pallet keeps running while a stepped migration is in progress, so the code reading the
+//! storage it migrates must handle entries of both the old and the new format until it is done.
+
+use super::*;
+use crate::migrations::migrate_compress_weights::CompressWeights;
+use frame_support::{
+    traits::Get,
+    weights::{Weight, WeightMeter},
+};
+use log;
+use scale_info::prelude::string::String;
+use sp_runtime::Perbill;
+
+/// The share of the maximum block weight the stepped migrations may use in a block.
+pub const STEPPED_MIGRATION_BLOCK_SHARE: Perbill = Perbill::from_percent(10);
+
+/// A migration made over several blocks.
+pub trait SteppedMigration<T: Config> {
+    /// The name of the migration, under which it is recorded in `HasMigrationRun`.
+    const NAME: &'static [u8];
+
+    /// Migrates entries for as long as `meter` has the weight to migrate one more, starting after
+    /// the raw storage key `cursor` or from the first entry if there is none. Returns the raw
+    /// storage key to resume from in the next step, and `None` once every entry is migrated, so a
+    /// step that cannot afford any entry must still return a key, such as the storage prefix.
+    fn step(cursor: Option<Vec<u8>>, meter: &mut WeightMeter) -> Option<Vec<u8>>;
+}
+
+/// The stepped migrations, stepped one after the other. New migrations are appended.
+pub type Migrations<T> = (CompressWeights<T>,);
+
+/// Steps the stepped migrations not run yet within the weight budget of the block, and returns
+/// the weight used. The budget is `STEPPED_MIGRATION_BLOCK_SHARE` of the maximum block weight, or
+/// the weight the block has left if that is less.
+pub fn run_stepped_migrations<T: Config>() -> Weight {
+    let max_block = T::BlockWeights::get().max_block;
+    let remaining = max_block.saturating_sub(frame_system::Pallet::<T>::block_weight().total());
+    let limit = (STEPPED_MIGRATION_BLOCK_SHARE * max_block).min(remaining);
+    let mut meter = WeightMeter::with_limit(limit);
+    <Migrations<T> as SteppedMigrations<T>>::step(&mut meter);
+    meter.consumed()
+}
+
+/// A sequence of stepped migrations.
+pub trait SteppedMigrations<T: Config> {
+    /// Steps the first migration not run yet within `meter`, and returns whether one is still
+    /// pending, in which case the later migrations wait for it.
+    fn step(meter: &mut WeightMeter) -> bool;
+}
+
+impl<T: Config> SteppedMigrations<T> for () {
+    fn step(_meter: &mut WeightMeter) -> bool {
+        false
+    }
+}
+
+macro_rules! impl_stepped_migrations {
+    ($($migration:ident),+) => {
+        impl<T: Config, $($migration: SteppedMigration<T>),+> SteppedMigrations<T>
+            for ($($migration,)+)
+        {
+            fn step(meter: &mut WeightMeter) -> bool {
+                $(step_migration::<T, $migration>(meter))||+
+            }
+        }
+    };
+}
+
+impl_stepped_migrations!(A);
+impl_stepped_migrations!(A, B);
+impl_stepped_migrations!(A, B, C);
+impl_stepped_migrations!(A, B, C, D);
+
+/// Steps `M` within `meter` unless it has run, and returns whether it is still pending. A
+/// migration whose status cannot be read within `meter` counts as pending.
+pub fn step_migration<T: Config, M: SteppedMigration<T>>(meter: &mut WeightMeter) -> bool {
+    let db_weight = T::DbWeight::get();
+    if meter.try_consume(db_weight.reads(1)).is_err() {
+        return true;
+    }
+    if HasMigrationRun::<T>::get(M::NAME) {
+        return false;
+    }
+
+    // Reading the cursor, and writing it or the status back
+    if meter.try_consume(db_weight.reads_writes(1, 2)).is_err() {
+        return true;
+    }
+    let cursor = SteppedMigrationCursor::<T>::get(M::NAME);
+    if cursor.is_none() {
+        log::info!(
+            target: "runtime",
+            "Running stepped migration '{}'",
+            String::from_utf8_lossy(M::NAME)
+        );
+    }
+
+    match M::step(cursor, meter) {
+        Some(cursor) => {
+            SteppedMigrationCursor::<T>::insert(M::NAME, cursor);
+            true
+        }
+        None => {
+            SteppedMigrationCursor::<T>::remove(M::NAME);
+            HasMigrationRun::<T>::insert(M::NAME, true);
+            log::info!(
+                target: "runtime",
+                "Stepped migration '{}' completed.",
+                String::from_utf8_lossy(M::NAME)
+            );
+            false
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use super::*;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use subtensor_macros::freeze_struct;
+use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid, TaoCurrency};
+
+#[freeze_struct("e3ba2f8dbb1dd158")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct DelegatePerformance<AccountId: TypeInfo + Encode + Decode> {
+    pub delegate: AccountId,
+    pub take: Compact<u16>,
+    pub root_stake: Compact<TaoCurrency>, // stake on root
+    pub subnets: Vec<SubnetPerformance>,  // per subnet the delegate has stake or dividends on
+}
+
+#[freeze_struct("9c6402da3a2c1d66")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct SubnetPerformance {
+    pub netuid: Compact<NetUid>,
+    pub stake: Compact<AlphaCurrency>,
+    pub trailing_emission: Compact<AlphaCurrency>, // alpha dividends over the last day
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the stake, take and, per subnet, the stake and the alpha dividends over the
+    /// trailing `TRAILING_WINDOW_BLOCKS` blocks of every delegate.
+    pub fn get_delegates_performance() -> Vec<DelegatePerformance<T::AccountId>> {
+        let netuids = Self::get_all_subnet_netuids();
+        Delegates::<T>::iter()
+            .map(|(delegate, take)| {
+                let subnets = netuids
+                    .iter()
+                    .filter(|netuid| !netuid.is_root())
+                    .filter_map(|netuid| {
+                        let stake = Self::get_stake_for_hotkey_on_subnet(&delegate, *netuid);
+                        let trailing_emission =
+                            Self::get_trailing_delegate_emission(*netuid, &delegate);
+                        (!stake.is_zero() || !trailing_emission.is_zero()).then(|| {
+                            SubnetPerformance {
+                                netuid: (*netuid).into(),
+                                stake: stake.into(),
+                                trailing_emission: trailing_emission.into(),
+                            }
+                        })
+                    })
+                    .collect();
+                let root_stake = Self::get_stake_for_hotkey_on_subnet(&delegate, NetUid::ROOT);
+                DelegatePerformance {
+                    delegate,
+                    take: take.into(),
+                    root_stake: TaoCurrency::from(u64::from(root_stake)).into(),
+                    subnets,
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,86 @@
+use super::*;
+extern crate alloc;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use frame_support::storage::{TransactionOutcome, transactional};
+use substrate_fixed::types::U96F32;
+use subtensor_macros::freeze_struct;
+use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid, NetUidStorageIndex};
+
+#[freeze_struct("62d7737c9e7b2d88")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct EpochSimulation {
+    pub netuid: Compact<NetUid>,
+    pub epoch_emission: Compact<AlphaCurrency>,
+    pub incentive: Vec<Compact<u16>>,
+    pub dividends: Vec<Compact<u16>>,
+    pub miner_emission: Vec<Compact<AlphaCurrency>>,
+    pub validator_emission: Vec<Compact<AlphaCurrency>>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Runs the next epoch of `netuid` against the current weights, bonds and stake, and returns
+    /// the incentive, dividends and alpha emission it would give every uid.
+    ///
+    /// The epoch is simulated inside a storage transaction that is always rolled back, with the
+    /// projected epoch emission of `get_projected_epoch_emission`. Incentive and dividends are
+    /// aggregated over the mechanisms of the subnet as the epoch does.
+    pub fn simulate_epoch(netuid: NetUid) -> Option<EpochSimulation> {
+        if netuid.is_root() || !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+        let epoch_emission = Self::get_projected_epoch_emission(netuid);
+        let n = Self::get_subnetwork_n(netuid) as usize;
+
+        transactional::with_transaction(|| {
+            let hotkey_emission: Vec<(T::AccountId, AlphaCurrency, AlphaCurrency)> =
+                Self::epoch_with_mechanisms(netuid, epoch_emission);
+
+            let mut miner_emission = vec![Compact(AlphaCurrency::ZERO); n];
+            let mut validator_emission = vec![Compact(AlphaCurrency::ZERO); n];
+            for (hotkey, incentive, dividends) in hotkey_emission {
+                let Some(uid) = Uids::<T>::get(netuid, &hotkey) else {
+                    continue;
+                };
+                if let Some(emission) = miner_emission.get_mut(uid as usize) {
+                    *emission = incentive.into();
+                }
+                if let Some(emission) = validator_emission.get_mut(uid as usize) {
+                    *emission = dividends.into();
+                }
+            }
+
+            let simulation = EpochSimulation {
+                netuid: netuid.into(),
+                epoch_emission: epoch_emission.into(),
+                incentive: Incentive::<T>::get(NetUidStorageIndex::from(netuid))
+                    .into_iter()
+                    .map(Compact::from)
+                    .collect(),
+                dividends: Dividends::<T>::get(netuid)
+                    .into_iter()
+                    .map(Compact::from)
+                    .collect(),
+                miner_emission,
+                validator_emission,
+            };
+            TransactionOutcome::Rollback(Ok::<_, DispatchError>(simulation))
+        })
+        .ok()
+    }
+
+    /// The alpha the next epoch of `netuid` is projected to distribute: the current per-block
+    /// alpha out emission over one tempo, net of the owner cut.
+    pub fn get_projected_epoch_emission(netuid: NetUid) -> AlphaCurrency {
+        let blocks_per_epoch: u64 = u64::from(Self::get_tempo(netuid)).saturating_add(1);
+        let alpha_out_per_epoch: U96F32 =
+            U96F32::saturating_from_num(SubnetAlphaOutEmission::<T>::get(netuid).to_u64())
+                .saturating_mul(U96F32::saturating_from_num(blocks_per_epoch));
+        let owner_cut: U96F32 =
+            alpha_out_per_epoch.saturating_mul(Self::get_float_subnet_owner_cut());
+        alpha_out_per_epoch
+            .saturating_sub(owner_cut)
+            .saturating_to_num::<u64>()
+            .into()
+    }
+}
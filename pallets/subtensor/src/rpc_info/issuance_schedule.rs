@@ -0,0 +1,65 @@
+use super::*;
+extern crate alloc;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use subtensor_macros::freeze_struct;
+use subtensor_runtime_common::{Currency, TaoCurrency};
+
+#[freeze_struct("5a4f84d5dbea53ae")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct IssuanceSchedule {
+    pub block: Compact<u64>,
+    pub block_emission: Compact<TaoCurrency>,
+    pub total_issuance: Compact<TaoCurrency>,
+    pub total_supply: Compact<TaoCurrency>,
+    pub remaining_supply: Compact<TaoCurrency>,
+    pub next_halving_issuance: Option<Compact<TaoCurrency>>,
+    pub next_halving_block: Option<Compact<u64>>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the block issuance schedule of the network at the current block.
+    ///
+    /// The block emission halves every time the total issuance crosses another half of the supply
+    /// left before the cap, that is at `total_supply - total_supply / 2^n`. The next halving block
+    /// is projected from the current block emission, so it moves when issuance is recycled or
+    /// burned. There is no next halving once the cap is reached.
+    pub fn get_issuance_schedule() -> IssuanceSchedule {
+        let block = Self::get_current_block_as_u64();
+        let total_issuance = Self::get_total_issuance();
+        let total_supply = TotalSupply::<T>::get();
+        let block_emission = Self::get_block_emission().unwrap_or(TaoCurrency::ZERO);
+        let remaining_supply = total_supply.saturating_sub(total_issuance.to_u64());
+
+        let next_halving_issuance = Self::get_next_halving_issuance(total_issuance.to_u64());
+        let next_halving_block = next_halving_issuance
+            .filter(|_| !block_emission.is_zero())
+            .map(|issuance| {
+                let blocks = issuance
+                    .saturating_sub(total_issuance.to_u64())
+                    .div_ceil(block_emission.to_u64());
+                block.saturating_add(blocks).into()
+            });
+
+        IssuanceSchedule {
+            block: block.into(),
+            block_emission: block_emission.into(),
+            total_issuance: total_issuance.into(),
+            total_supply: TaoCurrency::from(total_supply).into(),
+            remaining_supply: TaoCurrency::from(remaining_supply).into(),
+            next_halving_issuance: next_halving_issuance
+                .map(|issuance| TaoCurrency::from(issuance).into()),
+            next_halving_block,
+        }
+    }
+
+    /// The total issuance the block emission halves at next, if it is below the total supply.
+    pub fn get_next_halving_issuance(issuance: u64) -> Option<u64> {
+        let total_supply = TotalSupply::<T>::get();
+        (1..u64::BITS)
+            .map(|halvings| {
+                total_supply.saturating_sub(total_supply.checked_shr(halvings).unwrap_or_default())
+            })
+            .find(|threshold| *threshold > issuance && *threshold < total_supply)
+    }
+}
@@ -0,0 +1,41 @@
+extern crate alloc;
+use alloc::vec::Vec;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use scale_info::TypeInfo;
+use subtensor_macros::freeze_struct;
+
+/// A single field of an event or error variant, as seen by SCALE decoders.
+#[freeze_struct("79937ccfda18b1d")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct VariantFieldInfo {
+    /// Field name, `None` for tuple-style variants.
+    pub name: Option<Vec<u8>>,
+    /// Type name as written in the pallet source, e.g. `T::AccountId` or `NetUid`.
+    pub type_name: Option<Vec<u8>>,
+    /// Id of the field type in the runtime metadata type registry.
+    pub type_id: Compact<u32>,
+}
+
+/// An event or error variant together with its encoded index.
+#[freeze_struct("c99340da7b1e3bdc")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct VariantInfo {
+    pub index: u8,
+    pub name: Vec<u8>,
+    pub fields: Vec<VariantFieldInfo>,
+}
+
+/// All event and error variants of one pallet in the runtime.
+///
+/// `sdk_stable` marks pallets whose events and errors are decoded by the SDK; reordering or
+/// changing the fields of their variants is a breaking change for downstream clients.
+#[freeze_struct("f7a732e175889402")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct PalletVariantsInfo {
+    pub pallet_index: u8,
+    pub pallet_name: Vec<u8>,
+    pub sdk_stable: bool,
+    pub events: Vec<VariantInfo>,
+    pub errors: Vec<VariantInfo>,
+}
@@ -0,0 +1,115 @@
+use super::*;
+use crate::epoch::math::fixed64_to_u64;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use subtensor_macros::freeze_struct;
+use subtensor_runtime_common::{AlphaCurrency, NetUid, NetUidStorageIndex, TaoCurrency};
+
+/// The maximum number of UIDs returned in a single metagraph page.
+pub const MAX_METAGRAPH_PAGE_SIZE: u16 = 256;
+
+#[freeze_struct("10f3aefd8d93b402")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct MetagraphPage<AccountId: TypeInfo + Encode + Decode> {
+    pub netuid: Compact<NetUid>,
+    pub block: Compact<u64>,
+    pub num_uids: Compact<u16>,  // UIDs on the subnet, to page through
+    pub start_uid: Compact<u16>, // UID of the first entry of the page
+
+    // Per UID of the page, from `start_uid`.
+    pub hotkeys: Vec<AccountId>,
+    pub coldkeys: Vec<AccountId>,
+    pub axons: Vec<AxonInfo>,
+    pub active: Vec<bool>,
+    pub validator_permit: Vec<bool>,
+    pub emission: Vec<Compact<AlphaCurrency>>,
+    pub dividends: Vec<Compact<u16>>,
+    pub incentives: Vec<Compact<u16>>,
+    pub consensus: Vec<Compact<u16>>,
+    pub trust: Vec<Compact<u16>>,
+    pub rank: Vec<Compact<u16>>,
+    pub alpha_stake: Vec<Compact<AlphaCurrency>>,
+    pub tao_stake: Vec<Compact<TaoCurrency>>,
+    pub total_stake: Vec<Compact<TaoCurrency>>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the metagraph of `netuid` for at most `page_size` UIDs from `start_uid`, capped to
+    /// `MAX_METAGRAPH_PAGE_SIZE`, so that clients sync large subnets page by page. The page is
+    /// empty past the last UID of the subnet.
+    pub fn get_metagraph_page(
+        netuid: NetUid,
+        start_uid: u16,
+        page_size: u16,
+    ) -> Option<MetagraphPage<T::AccountId>> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        let num_uids = Self::get_subnetwork_n(netuid);
+        let end_uid = start_uid
+            .saturating_add(page_size.min(MAX_METAGRAPH_PAGE_SIZE))
+            .min(num_uids);
+        let (skip, take) = (
+            usize::from(start_uid),
+            usize::from(end_uid.saturating_sub(start_uid)),
+        );
+
+        let mut hotkeys: Vec<T::AccountId> = vec![];
+        let mut coldkeys: Vec<T::AccountId> = vec![];
+        let mut axons: Vec<AxonInfo> = vec![];
+        let mut alpha_stake: Vec<Compact<AlphaCurrency>> = vec![];
+        let mut tao_stake: Vec<Compact<TaoCurrency>> = vec![];
+        let mut total_stake: Vec<Compact<TaoCurrency>> = vec![];
+        for uid in start_uid..end_uid {
+            let hotkey = Keys::<T>::get(netuid, uid);
+            let (total, alpha, tao) = Self::get_stake_weights_for_hotkey_on_subnet(&hotkey, netuid);
+            coldkeys.push(Owner::<T>::get(&hotkey));
+            axons.push(Self::get_axon_info(netuid, &hotkey));
+            alpha_stake.push(AlphaCurrency::from(fixed64_to_u64(alpha)).into());
+            tao_stake.push(TaoCurrency::from(fixed64_to_u64(tao)).into());
+            total_stake.push(TaoCurrency::from(fixed64_to_u64(total)).into());
+            hotkeys.push(hotkey);
+        }
+
+        Some(MetagraphPage {
+            netuid: netuid.into(),
+            block: Self::get_current_block_as_u64().into(),
+            num_uids: num_uids.into(),
+            start_uid: start_uid.into(),
+            hotkeys,
+            coldkeys,
+            axons,
+            active: page(Active::<T>::get(netuid), skip, take),
+            validator_permit: page(ValidatorPermit::<T>::get(netuid), skip, take),
+            emission: compact_page(Emission::<T>::get(netuid), skip, take),
+            dividends: compact_page(Dividends::<T>::get(netuid), skip, take),
+            incentives: compact_page(
+                Incentive::<T>::get(NetUidStorageIndex::from(netuid)),
+                skip,
+                take,
+            ),
+            consensus: compact_page(Consensus::<T>::get(netuid), skip, take),
+            trust: compact_page(Trust::<T>::get(netuid), skip, take),
+            rank: compact_page(Rank::<T>::get(netuid), skip, take),
+            alpha_stake,
+            tao_stake,
+            total_stake,
+        })
+    }
+}
+
+/// The `take` values of a per UID vector from the UID `skip`.
+fn page<V>(values: Vec<V>, skip: usize, take: usize) -> Vec<V> {
+    values.into_iter().skip(skip).take(take).collect()
+}
+
+fn compact_page<V>(values: Vec<V>, skip: usize, take: usize) -> Vec<Compact<V>>
+where
+    Compact<V>: From<V>,
+{
+    page(values, skip, take)
+        .into_iter()
+        .map(Compact::from)
+        .collect()
+}
@@ -1,8 +1,14 @@
 use super::*;
 pub mod delegate_info;
+pub mod delegate_performance;
 pub mod dynamic_info;
+pub mod epoch_simulation;
+pub mod issuance_schedule;
+pub mod metadata_compat;
 pub mod metagraph;
+pub mod metagraph_page;
 pub mod neuron_info;
 pub mod show_subnet;
 pub mod stake_info;
 pub mod subnet_info;
+pub mod validator_yield;
@@ -1,10 +1,11 @@
 use super::*;
 use frame_support::pallet_prelude::{Decode, Encode};
 extern crate alloc;
+use crate::subnets::neuron_status::NeuronStatus;
 use codec::Compact;
 use subtensor_runtime_common::{AlphaCurrency, NetUid, NetUidStorageIndex};
 
-#[freeze_struct("9e5a291e7e71482d")]
+#[freeze_struct("e2d06378b8078bc2")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
 pub struct NeuronInfo<AccountId: TypeInfo + Encode + Decode> {
     hotkey: AccountId,
@@ -27,9 +28,10 @@ pub struct NeuronInfo<AccountId: TypeInfo + Encode + Decode> {
     weights: Vec<(Compact<u16>, Compact<u16>)>, // Vec of (uid, weight)
     bonds: Vec<(Compact<u16>, Compact<u16>)>,   // Vec of (uid, bond)
     pruning_score: Compact<u16>,
+    status: NeuronStatus,
 }
 
-#[freeze_struct("b9fdff7fc6e023c7")]
+#[freeze_struct("ae221d0571581cee")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
 pub struct NeuronInfoLite<AccountId: TypeInfo + Encode + Decode> {
     hotkey: AccountId,
@@ -51,6 +53,7 @@ pub struct NeuronInfoLite<AccountId: TypeInfo + Encode + Decode> {
     validator_permit: bool,
     // has no weights or bonds
     pruning_score: Compact<u16>,
+    status: NeuronStatus,
 }
 
 impl<T: Config> Pallet<T> {
@@ -97,7 +100,7 @@ impl<T: Config> Pallet<T> {
         let validator_permit = Self::get_validator_permit_for_uid(netuid, uid);
 
         let weights = Weights::<T>::get(NetUidStorageIndex::from(netuid), uid)
-            .into_iter()
+            .iter()
             .filter_map(|(i, w)| {
                 if w > 0 {
                     Some((i.into(), w.into()))
@@ -142,6 +145,7 @@ impl<T: Config> Pallet<T> {
             weights,
             bonds,
             pruning_score: pruning_score.into(),
+            status: Self::get_neuron_status_for_uid(netuid, uid),
         };
 
         Some(neuron)
@@ -206,6 +210,7 @@ impl<T: Config> Pallet<T> {
             last_update: last_update.into(),
             validator_permit,
             pruning_score: pruning_score.into(),
+            status: Self::get_neuron_status_for_uid(netuid, uid),
         };
 
         Some(neuron)
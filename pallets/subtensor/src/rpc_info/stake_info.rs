@@ -2,12 +2,13 @@ extern crate alloc;
 
 use codec::Compact;
 use frame_support::pallet_prelude::{Decode, Encode};
+use substrate_fixed::types::U96F32;
 use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid, TaoCurrency};
-use subtensor_swap_interface::SwapHandler;
+use subtensor_swap_interface::{OrderType, SwapHandler};
 
 use super::*;
 
-#[freeze_struct("28269be895d7b5ba")]
+#[freeze_struct("2d308993be1ae7a2")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
 pub struct StakeInfo<AccountId: TypeInfo + Encode + Decode> {
     hotkey: AccountId,
@@ -19,6 +20,9 @@ pub struct StakeInfo<AccountId: TypeInfo + Encode + Decode> {
     tao_emission: Compact<TaoCurrency>,
     drain: Compact<u64>,
     is_registered: bool,
+    tao_value: Compact<TaoCurrency>, // stake at the current alpha price
+    exit_fee: Compact<TaoCurrency>,  // swap fee of unstaking the whole stake
+    exit_slippage: Compact<TaoCurrency>, // price impact of unstaking the whole stake
 }
 
 impl<T: Config> Pallet<T> {
@@ -46,6 +50,8 @@ impl<T: Config> Pallet<T> {
                     let tao_emission = TaoDividendsPerSubnet::<T>::get(*netuid_i, &hotkey_i);
                     let is_registered: bool =
                         Self::is_hotkey_registered_on_network(*netuid_i, hotkey_i);
+                    let (tao_value, exit_fee, exit_slippage) =
+                        Self::get_stake_valuation(*netuid_i, alpha);
                     stake_info_for_coldkey.push(StakeInfo {
                         hotkey: hotkey_i.clone(),
                         coldkey: coldkey_i.clone(),
@@ -56,6 +62,9 @@ impl<T: Config> Pallet<T> {
                         tao_emission: tao_emission.into(),
                         drain: 0.into(),
                         is_registered,
+                        tao_value: tao_value.into(),
+                        exit_fee: exit_fee.into(),
+                        exit_slippage: exit_slippage.into(),
                     });
                 }
             }
@@ -103,6 +112,7 @@ impl<T: Config> Pallet<T> {
         let emission = AlphaDividendsPerSubnet::<T>::get(netuid, &hotkey_account);
         let tao_emission = TaoDividendsPerSubnet::<T>::get(netuid, &hotkey_account);
         let is_registered: bool = Self::is_hotkey_registered_on_network(netuid, &hotkey_account);
+        let (tao_value, exit_fee, exit_slippage) = Self::get_stake_valuation(netuid, alpha);
 
         Some(StakeInfo {
             hotkey: hotkey_account,
@@ -114,9 +124,41 @@ impl<T: Config> Pallet<T> {
             tao_emission: tao_emission.into(),
             drain: 0.into(),
             is_registered,
+            tao_value: tao_value.into(),
+            exit_fee: exit_fee.into(),
+            exit_slippage: exit_slippage.into(),
         })
     }
 
+    /// Values `alpha` staked on `netuid` in TAO: its value at the current alpha price, and the
+    /// swap fee and price impact of unstaking all of it at once, so that the TAO received on
+    /// exit is the value less both. Without the liquidity to unstake it, nothing is received.
+    pub fn get_stake_valuation(
+        netuid: NetUid,
+        alpha: AlphaCurrency,
+    ) -> (TaoCurrency, TaoCurrency, TaoCurrency) {
+        let price = T::SwapInterface::current_alpha_price(netuid);
+        let to_tao = |amount: u64| {
+            TaoCurrency::from(
+                U96F32::saturating_from_num(amount)
+                    .saturating_mul(price)
+                    .saturating_to_num::<u64>(),
+            )
+        };
+        let tao_value = to_tao(alpha.to_u64());
+        match T::SwapInterface::sim_swap(netuid, OrderType::Sell, alpha.to_u64()) {
+            Ok(res) => {
+                // The fee of a sell is paid in alpha.
+                let exit_fee = to_tao(res.fee_paid);
+                let exit_slippage = tao_value
+                    .saturating_sub(exit_fee)
+                    .saturating_sub(res.amount_paid_out.into());
+                (tao_value, exit_fee, exit_slippage)
+            }
+            Err(_) => (tao_value, TaoCurrency::ZERO, tao_value),
+        }
+    }
+
     pub fn get_stake_fee(
         origin: Option<(T::AccountId, NetUid)>,
         _origin_coldkey_account: T::AccountId,
@@ -5,6 +5,7 @@ extern crate alloc;
 use codec::Compact;
 use substrate_fixed::types::I32F32;
 use subtensor_runtime_common::{NetUid, TaoCurrency};
+use subtensor_swap_interface::SwapHandler;
 
 #[freeze_struct("edd6bd3273dfea76")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
@@ -29,7 +30,7 @@ pub struct SubnetInfo<AccountId: TypeInfo + Encode + Decode> {
     owner: AccountId,
 }
 
-#[freeze_struct("e5f66b14b33331c3")]
+#[freeze_struct("8c062a98b592eddd")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
 pub struct SubnetInfov2<AccountId: TypeInfo + Encode + Decode> {
     netuid: Compact<NetUid>,
@@ -51,6 +52,8 @@ pub struct SubnetInfov2<AccountId: TypeInfo + Encode + Decode> {
     burn: Compact<TaoCurrency>,
     owner: AccountId,
     identity: Option<SubnetIdentityV3>,
+    locked_liquidity: Compact<u64>,
+    liquidity_locked_until: Compact<u64>,
 }
 
 #[freeze_struct("24f0815487879ed3")]
@@ -85,7 +88,7 @@ pub struct SubnetHyperparams {
     liquid_alpha_enabled: bool,
 }
 
-#[freeze_struct("2153c3f3bb01ef66")]
+#[freeze_struct("f3b082c231c0ab2b")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
 pub struct SubnetHyperparamsV2 {
     rho: Compact<u16>,
@@ -121,6 +124,8 @@ pub struct SubnetHyperparamsV2 {
     transfers_enabled: bool,
     bonds_reset_enabled: bool,
     user_liquidity_enabled: bool,
+    adaptive_max_weights_limit: Compact<u16>,
+    burn_auction_enabled: bool,
 }
 
 impl<T: Config> Pallet<T> {
@@ -211,6 +216,7 @@ impl<T: Config> Pallet<T> {
         let tempo = Self::get_tempo(netuid);
         let burn = Compact::from(Self::get_burn(netuid));
         let identity: Option<SubnetIdentityV3> = SubnetIdentitiesV3::<T>::get(netuid);
+        let (locked_liquidity, liquidity_locked_until) = T::SwapInterface::locked_liquidity(netuid);
 
         // DEPRECATED
         let network_connect: Vec<[u16; 2]> = Vec::<[u16; 2]>::new();
@@ -238,6 +244,8 @@ impl<T: Config> Pallet<T> {
             burn,
             owner: Self::get_subnet_owner(netuid),
             identity,
+            locked_liquidity: locked_liquidity.into(),
+            liquidity_locked_until: liquidity_locked_until.into(),
         })
     }
 
@@ -366,6 +374,8 @@ impl<T: Config> Pallet<T> {
         let transfers_enabled = Self::get_transfer_toggle(netuid);
         let bonds_reset = Self::get_bonds_reset(netuid);
         let user_liquidity_enabled: bool = Self::is_user_liquidity_enabled(netuid);
+        let adaptive_max_weights_limit = Self::get_adaptive_max_weight_limit(netuid);
+        let burn_auction_enabled = Self::get_burn_auction_enabled(netuid);
 
         Some(SubnetHyperparamsV2 {
             rho: rho.into(),
@@ -401,6 +411,8 @@ impl<T: Config> Pallet<T> {
             transfers_enabled,
             bonds_reset_enabled: bonds_reset,
             user_liquidity_enabled,
+            adaptive_max_weights_limit: adaptive_max_weights_limit.into(),
+            burn_auction_enabled,
         })
     }
 
@@ -0,0 +1,88 @@
+use super::*;
+extern crate alloc;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use frame_support::storage::{TransactionOutcome, transactional};
+use subtensor_macros::freeze_struct;
+use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid, TaoCurrency};
+
+#[freeze_struct("4beca8c89b928570")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct ValidatorYieldEstimate<AccountId: TypeInfo + Encode + Decode> {
+    pub netuid: Compact<NetUid>,
+    pub hotkey: AccountId,
+    pub stake: Compact<AlphaCurrency>,
+    pub blocks_per_epoch: Compact<u64>,
+    pub epoch_emission: Compact<AlphaCurrency>,
+    pub dividends: Compact<AlphaCurrency>,
+    pub validator_permit: bool,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Estimates the alpha dividends `hotkey` would earn over the next epoch of `netuid` if it
+    /// held `stake_amount` alpha, using the current weights, bonds and consensus state.
+    ///
+    /// The epoch is simulated inside a storage transaction that is always rolled back. The
+    /// projected epoch emission is the current per-block alpha out emission over one tempo,
+    /// net of the owner cut. TAO dividends paid to root stake are not included.
+    pub fn estimate_validator_yield(
+        netuid: NetUid,
+        hotkey: T::AccountId,
+        stake_amount: AlphaCurrency,
+    ) -> Option<ValidatorYieldEstimate<T::AccountId>> {
+        if netuid.is_root() || !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+        let uid = Self::get_uid_for_net_and_hotkey(netuid, &hotkey).ok()?;
+
+        let blocks_per_epoch: u64 = u64::from(Self::get_tempo(netuid)).saturating_add(1);
+        let epoch_emission = Self::get_projected_epoch_emission(netuid);
+
+        transactional::with_transaction(|| {
+            // Pretend the hotkey holds the hypothetical stake for this epoch only.
+            TotalHotkeyAlpha::<T>::insert(&hotkey, netuid, stake_amount);
+
+            let hotkey_emission: Vec<(T::AccountId, AlphaCurrency, AlphaCurrency)> =
+                Self::epoch_with_mechanisms(netuid, epoch_emission);
+
+            // Mirror `drain_pending_emission`: validators get half of the emission unless
+            // there is no incentive, in which case they get all of it.
+            let incentive_sum = hotkey_emission
+                .iter()
+                .fold(AlphaCurrency::default(), |acc, (_, incentive, _)| {
+                    acc.saturating_add(*incentive)
+                });
+            let pending_validator_alpha = if !incentive_sum.is_zero() {
+                epoch_emission.saturating_div(2.into())
+            } else {
+                epoch_emission
+            };
+
+            let (_, (alpha_dividends, _)) = Self::calculate_dividend_and_incentive_distribution(
+                netuid,
+                TaoCurrency::ZERO,
+                pending_validator_alpha,
+                hotkey_emission,
+                Self::get_tao_weight(),
+            );
+            let dividends: AlphaCurrency = alpha_dividends
+                .get(&hotkey)
+                .map(|d| d.saturating_to_num::<u64>())
+                .unwrap_or_default()
+                .into();
+
+            let estimate = ValidatorYieldEstimate {
+                netuid: netuid.into(),
+                hotkey: hotkey.clone(),
+                stake: stake_amount.into(),
+                blocks_per_epoch: blocks_per_epoch.into(),
+                epoch_emission: epoch_emission.into(),
+                dividends: dividends.into(),
+                validator_permit: Self::get_validator_permit_for_uid(netuid, uid),
+            };
+
+            TransactionOutcome::Rollback(Ok::<_, DispatchError>(estimate))
+        })
+        .ok()
+    }
+}
@@ -0,0 +1,113 @@
+//! Stake allowances.
+//!
+//! A coldkey can approve a manager account to stake up to an amount of its free balance on a
+//! subnet, similar to an ERC-20 allowance. The manager stakes on behalf of the coldkey with
+//! `add_stake_with_allowance`, which consumes the allowance. The stake belongs to the coldkey and
+//! an allowance never permits unstaking or transferring, so the funds cannot leave the coldkey.
+
+use super::*;
+use subtensor_runtime_common::{NetUid, TaoCurrency};
+
+impl<T: Config> Pallet<T> {
+    pub fn do_approve_stake_allowance(
+        origin: T::RuntimeOrigin,
+        manager: T::AccountId,
+        netuid: NetUid,
+        amount: TaoCurrency,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
+
+        if amount.is_zero() {
+            StakeAllowance::<T>::remove((netuid, &coldkey, &manager));
+        } else {
+            StakeAllowance::<T>::insert((netuid, &coldkey, &manager), amount);
+        }
+
+        Self::deposit_event(Event::StakeAllowanceApproved {
+            coldkey,
+            manager,
+            netuid,
+            amount,
+        });
+        Ok(())
+    }
+
+    pub fn do_revoke_stake_allowance(
+        origin: T::RuntimeOrigin,
+        manager: T::AccountId,
+        netuid: NetUid,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        ensure!(
+            StakeAllowance::<T>::contains_key((netuid, &coldkey, &manager)),
+            Error::<T>::StakeAllowanceNotFound
+        );
+
+        StakeAllowance::<T>::remove((netuid, &coldkey, &manager));
+        Self::deposit_event(Event::StakeAllowanceRevoked {
+            coldkey,
+            manager,
+            netuid,
+        });
+        Ok(())
+    }
+
+    /// Stakes `amount` of the free balance of `coldkey` to `hotkey` on `netuid`, consuming the
+    /// allowance of the calling manager.
+    pub fn do_add_stake_with_allowance(
+        origin: T::RuntimeOrigin,
+        coldkey: T::AccountId,
+        hotkey: T::AccountId,
+        netuid: NetUid,
+        amount: TaoCurrency,
+        limit_price: TaoCurrency,
+    ) -> DispatchResult {
+        let manager = ensure_signed(origin)?;
+
+        let allowance = StakeAllowance::<T>::get((netuid, &coldkey, &manager));
+        ensure!(
+            !amount.is_zero() && amount <= allowance,
+            Error::<T>::InsufficientStakeAllowance
+        );
+
+        let max_amount: TaoCurrency = Self::get_max_amount_add(netuid, limit_price)?.into();
+        Self::validate_add_stake(&coldkey, &hotkey, netuid, amount, max_amount, false)?;
+
+        if Self::get_owning_coldkey_for_hotkey(&hotkey) != coldkey {
+            Self::maybe_become_delegate(&hotkey);
+        }
+
+        let remaining = allowance.saturating_sub(amount);
+        if remaining.is_zero() {
+            StakeAllowance::<T>::remove((netuid, &coldkey, &manager));
+        } else {
+            StakeAllowance::<T>::insert((netuid, &coldkey, &manager), remaining);
+        }
+
+        let tao_staked = Self::remove_balance_from_coldkey_account(&coldkey, amount.into())?;
+        Self::stake_into_subnet(
+            &hotkey,
+            &coldkey,
+            netuid,
+            tao_staked,
+            limit_price,
+            true,
+            false,
+        )?;
+
+        Self::deposit_event(Event::StakeAllowanceUsed {
+            coldkey,
+            manager,
+            netuid,
+            amount,
+            remaining,
+        });
+        Ok(())
+    }
+
+    /// Removes every stake allowance on `netuid`.
+    pub fn clear_stake_allowances(netuid: NetUid) {
+        let _ = StakeAllowance::<T>::clear_prefix((netuid,), u32::MAX, None);
+    }
+}
@@ -0,0 +1,90 @@
+//! Dissolution payout preferences.
+//!
+//! When a subnet is dissolved or pruned, its stakers are paid their share of the TAO pot of the
+//! subnet, by default to the free balance of their coldkey. A coldkey can instead have its
+//! payouts restaked into root on a hotkey of its choice, so long-term stakers are not cashed out
+//! and do not have to stake again themselves. A payout that cannot be restaked, e.g. because the
+//! hotkey no longer exists, is credited to the free balance.
+
+use super::*;
+use frame_support::storage::{TransactionOutcome, transactional};
+use subtensor_runtime_common::{NetUid, TaoCurrency};
+use subtensor_swap_interface::SwapHandler;
+
+/// Where the dissolution payouts of a coldkey are credited.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    Debug,
+    Default,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum DissolutionPayout<AccountId> {
+    /// To the free balance of the coldkey.
+    #[default]
+    Balance,
+    /// Staked into root on the hotkey.
+    RootStake(AccountId),
+}
+
+impl<T: Config> Pallet<T> {
+    /// Sets where the dissolution payouts of the signing coldkey are credited.
+    pub fn do_set_dissolution_payout(
+        origin: T::RuntimeOrigin,
+        payout: DissolutionPayout<T::AccountId>,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        match &payout {
+            DissolutionPayout::Balance => DissolutionPayoutPreference::<T>::remove(&coldkey),
+            DissolutionPayout::RootStake(hotkey) => {
+                ensure!(
+                    Self::hotkey_account_exists(hotkey),
+                    Error::<T>::HotKeyAccountNotExists
+                );
+                DissolutionPayoutPreference::<T>::insert(&coldkey, payout.clone());
+            }
+        }
+
+        Self::deposit_event(Event::DissolutionPayoutSet { coldkey, payout });
+        Ok(())
+    }
+
+    /// Credits the dissolution payout `tao` of `coldkey` as it prefers.
+    pub fn credit_dissolution_payout(coldkey: &T::AccountId, tao: TaoCurrency) {
+        if let DissolutionPayout::RootStake(hotkey) = DissolutionPayoutPreference::<T>::get(coldkey)
+        {
+            let result: Result<(), DispatchError> = transactional::with_transaction(|| {
+                if !Self::hotkey_account_exists(&hotkey) {
+                    return TransactionOutcome::Rollback(Err(
+                        Error::<T>::HotKeyAccountNotExists.into()
+                    ));
+                }
+                match Self::stake_into_subnet(
+                    &hotkey,
+                    coldkey,
+                    NetUid::ROOT,
+                    tao,
+                    T::SwapInterface::max_price().into(),
+                    false,
+                    false,
+                ) {
+                    Ok(_) => TransactionOutcome::Commit(Ok(())),
+                    Err(e) => TransactionOutcome::Rollback(Err(e)),
+                }
+            });
+            match result {
+                Ok(()) => return,
+                Err(e) => log::debug!(
+                    "credit_dissolution_payout( coldkey:{coldkey:?} hotkey:{hotkey:?} ) restake failed: {e:?}"
+                ),
+            }
+        }
+        Self::add_balance_to_coldkey_account(coldkey, tao.into());
+    }
+}
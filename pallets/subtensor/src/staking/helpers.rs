@@ -206,16 +206,21 @@ impl<T: Config> Pallet<T> {
                     false,
                 );
 
-                if let Ok(cleared_stake) = maybe_cleared_stake {
-                    // Add the stake to the coldkey account.
-                    Self::add_balance_to_coldkey_account(coldkey, cleared_stake.into());
-                } else {
-                    // Just clear small alpha
-                    let alpha =
-                        Self::get_stake_for_hotkey_and_coldkey_on_subnet(hotkey, coldkey, netuid);
-                    Self::decrease_stake_for_hotkey_and_coldkey_on_subnet(
-                        hotkey, coldkey, netuid, alpha,
-                    );
+                match maybe_cleared_stake {
+                    Ok(cleared_stake) => {
+                        // Add the stake to the coldkey account.
+                        Self::add_balance_to_coldkey_account(coldkey, cleared_stake.into());
+                    }
+                    Err(e) => {
+                        Self::report_rejected_tao_debit(netuid, &e);
+                        // Just clear small alpha
+                        let alpha = Self::get_stake_for_hotkey_and_coldkey_on_subnet(
+                            hotkey, coldkey, netuid,
+                        );
+                        Self::decrease_stake_for_hotkey_and_coldkey_on_subnet(
+                            hotkey, coldkey, netuid, alpha,
+                        );
+                    }
                 }
             }
         }
@@ -1,11 +1,17 @@
 use super::*;
 pub mod account;
 pub mod add_stake;
+pub mod allowance;
 pub mod decrease_take;
+pub mod dissolution_payout;
 pub mod helpers;
 pub mod increase_take;
 pub mod move_stake;
 pub mod recycle_alpha;
 pub mod remove_stake;
 pub mod set_children;
+pub mod sliced_unstake;
+pub mod solvency;
+pub mod stake_origin;
 pub mod stake_utils;
+pub mod unstake_timelock;
@@ -349,6 +349,16 @@ impl<T: Config> Pallet<T> {
             max_amount
         };
 
+        // Stake moved between positions of the same coldkey keeps its origin
+        if origin_coldkey == destination_coldkey {
+            Self::inherit_stake_origin(
+                StakeOrigins::<T>::get((origin_netuid, origin_hotkey, origin_coldkey)),
+                destination_hotkey,
+                destination_coldkey,
+                destination_netuid,
+            );
+        }
+
         if origin_netuid != destination_netuid {
             // Any way to charge fees that works
             let drop_fee_origin = origin_netuid == NetUid::ROOT;
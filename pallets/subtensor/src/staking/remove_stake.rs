@@ -1,7 +1,6 @@
 use subtensor_swap_interface::{OrderType, SwapHandler};
 
 use super::*;
-use substrate_fixed::types::U96F32;
 use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid, TaoCurrency};
 
 impl<T: Config> Pallet<T> {
@@ -50,6 +49,7 @@ impl<T: Config> Pallet<T> {
         );
 
         Self::ensure_subtoken_enabled(netuid)?;
+        Self::ensure_unstake_unlocked(&coldkey)?;
 
         // 1.1. Cap the alpha_unstaked at available Alpha because user might be paying transaxtion fees
         // in Alpha and their total is already reduced by now.
@@ -128,6 +128,8 @@ impl<T: Config> Pallet<T> {
         let coldkey = ensure_signed(origin)?;
         log::debug!("do_unstake_all( origin:{coldkey:?} hotkey:{hotkey:?} )");
 
+        Self::ensure_unstake_unlocked(&coldkey)?;
+
         // 2. Ensure that the hotkey account exists this is only possible through registration.
         ensure!(
             Self::hotkey_account_exists(&hotkey),
@@ -218,6 +220,8 @@ impl<T: Config> Pallet<T> {
         let coldkey = ensure_signed(origin)?;
         log::debug!("do_unstake_all( origin:{coldkey:?} hotkey:{hotkey:?} )");
 
+        Self::ensure_unstake_unlocked(&coldkey)?;
+
         // 2. Ensure that the hotkey account exists this is only possible through registration.
         ensure!(
             Self::hotkey_account_exists(&hotkey),
@@ -344,6 +348,8 @@ impl<T: Config> Pallet<T> {
             "do_remove_stake( origin:{coldkey:?} hotkey:{hotkey:?}, netuid: {netuid:?}, alpha_unstaked:{alpha_unstaked:?} )"
         );
 
+        Self::ensure_unstake_unlocked(&coldkey)?;
+
         // 2. Calculate the maximum amount that can be executed with price limit
         let max_amount = Self::get_max_amount_remove(netuid, limit_price)?;
         let mut possible_alpha = alpha_unstaked;
@@ -445,166 +451,47 @@ impl<T: Config> Pallet<T> {
         // 1) Ensure the subnet exists.
         ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
 
-        // 2) Owner / lock cost.
-        let owner_coldkey: T::AccountId = SubnetOwner::<T>::get(netuid);
-        let lock_cost: TaoCurrency = Self::get_subnet_locked_balance(netuid);
-
-        // Determine if this subnet is eligible for a lock refund (legacy).
-        let reg_at: u64 = NetworkRegisteredAt::<T>::get(netuid);
-        let start_block: u64 = NetworkRegistrationStartBlock::<T>::get();
-        let should_refund_owner: bool = reg_at < start_block;
-
-        // 3) Compute owner's received emission in TAO at current price (ONLY if we may refund).
-        //    Emission::<T> is Vec<AlphaCurrency>. We:
-        //      - sum emitted α,
-        //      - apply owner fraction to get owner α,
-        //      - price that α using a *simulated* AMM swap.
-        let mut owner_emission_tao: TaoCurrency = TaoCurrency::ZERO;
-        if should_refund_owner && !lock_cost.is_zero() {
-            let total_emitted_alpha_u128: u128 =
-                Emission::<T>::get(netuid)
-                    .into_iter()
-                    .fold(0u128, |acc, e_alpha| {
-                        let e_u64: u64 = Into::<u64>::into(e_alpha);
-                        acc.saturating_add(e_u64 as u128)
-                    });
-
-            if total_emitted_alpha_u128 > 0 {
-                let owner_fraction: U96F32 = Self::get_float_subnet_owner_cut();
-                let owner_alpha_u64: u64 = U96F32::from_num(total_emitted_alpha_u128)
-                    .saturating_mul(owner_fraction)
-                    .floor()
-                    .saturating_to_num::<u64>();
-
-                owner_emission_tao = if owner_alpha_u64 > 0 {
-                    match T::SwapInterface::sim_swap(
-                        netuid.into(),
-                        OrderType::Sell,
-                        owner_alpha_u64,
-                    ) {
-                        Ok(sim) => TaoCurrency::from(sim.amount_paid_out),
-                        Err(e) => {
-                            log::debug!(
-                                "destroy_alpha_in_out_stakes: sim_swap owner α→τ failed (netuid={netuid:?}, alpha={owner_alpha_u64}, err={e:?}); falling back to price multiply.",
-                            );
-                            let cur_price: U96F32 =
-                                T::SwapInterface::current_alpha_price(netuid.into());
-                            let val_u64: u64 = U96F32::from_num(owner_alpha_u64)
-                                .saturating_mul(cur_price)
-                                .floor()
-                                .saturating_to_num::<u64>();
-                            TaoCurrency::from(val_u64)
-                        }
-                    }
-                } else {
-                    TaoCurrency::ZERO
-                };
-            }
-        }
-
-        // 4) Enumerate all α entries on this subnet to build distribution weights and cleanup lists.
-        //    - collect keys to remove,
-        //    - per (hot,cold) α VALUE (not shares) with fallback to raw share if pool uninitialized,
-        //    - track hotkeys to clear pool totals.
-        let mut keys_to_remove: Vec<(T::AccountId, T::AccountId)> = Vec::new();
-        let mut hotkeys_seen: Vec<T::AccountId> = Vec::new();
-        let mut stakers: Vec<(T::AccountId, T::AccountId, u128)> = Vec::new();
-        let mut total_alpha_value_u128: u128 = 0;
-
-        for ((hot, cold, this_netuid), share_u64f64) in Alpha::<T>::iter() {
-            if this_netuid != netuid {
-                continue;
-            }
-
-            keys_to_remove.push((hot.clone(), cold.clone()));
-            if !hotkeys_seen.contains(&hot) {
-                hotkeys_seen.push(hot.clone());
-            }
-
-            // Primary: actual α value via share pool.
-            let pool = Self::get_alpha_share_pool(hot.clone(), netuid);
-            let actual_val_u64 = pool.try_get_value(&cold).unwrap_or(0);
-
-            // Fallback: if pool uninitialized, treat raw Alpha share as value.
-            let val_u64 = if actual_val_u64 == 0 {
-                share_u64f64.saturating_to_num::<u64>()
-            } else {
-                actual_val_u64
-            };
-
-            if val_u64 > 0 {
-                let val_u128 = val_u64 as u128;
-                total_alpha_value_u128 = total_alpha_value_u128.saturating_add(val_u128);
-                stakers.push((hot, cold, val_u128));
-            }
-        }
+        // 2) Compute the settlement: the pro‑rata payouts of the pot and the owner refund.
+        let settlement = Self::get_dissolution_settlement(netuid);
 
-        // 5) Determine the TAO pot and pre-adjust accounting to avoid double counting.
-        let pot_tao: TaoCurrency = SubnetTAO::<T>::get(netuid);
-        let pot_u64: u64 = pot_tao.into();
+        // The payouts can never exceed the pot they are carved from.
+        let payout: TaoCurrency = settlement
+            .payouts
+            .iter()
+            .fold(TaoCurrency::ZERO, |total, (_, share)| {
+                total.saturating_add(*share)
+            });
+        Self::ensure_tao_debit_covered(netuid, settlement.pot, payout)?;
 
-        if pot_u64 > 0 {
+        // 3) Remove the TAO pot from the accounting to avoid double counting.
+        if !settlement.pot.is_zero() {
             SubnetTAO::<T>::remove(netuid);
-            TotalStake::<T>::mutate(|total| *total = total.saturating_sub(pot_tao));
+            TotalStake::<T>::mutate(|total| *total = total.saturating_sub(settlement.pot));
         }
 
-        // 6) Pro‑rata distribution of the pot by α value (largest‑remainder),
-        //    **credited directly to each staker's COLDKEY free balance**.
-        if pot_u64 > 0 && total_alpha_value_u128 > 0 && !stakers.is_empty() {
-            struct Portion<A, C> {
-                _hot: A,
-                cold: C,
-                share: u64, // TAO to credit to coldkey balance
-                rem: u128,  // remainder for largest‑remainder method
-            }
-
-            let pot_u128: u128 = pot_u64 as u128;
-            let mut portions: Vec<Portion<_, _>> = Vec::with_capacity(stakers.len());
-            let mut distributed: u128 = 0;
-
-            for (hot, cold, alpha_val) in &stakers {
-                let prod: u128 = pot_u128.saturating_mul(*alpha_val);
-                let share_u128: u128 = prod.checked_div(total_alpha_value_u128).unwrap_or_default();
-                let share_u64: u64 = share_u128.min(u128::from(u64::MAX)) as u64;
-                distributed = distributed.saturating_add(u128::from(share_u64));
-
-                let rem: u128 = prod.checked_rem(total_alpha_value_u128).unwrap_or_default();
-                portions.push(Portion {
-                    _hot: hot.clone(),
-                    cold: cold.clone(),
-                    share: share_u64,
-                    rem,
-                });
-            }
-
-            let leftover: u128 = pot_u128.saturating_sub(distributed);
-            if leftover > 0 {
-                portions.sort_by(|a, b| b.rem.cmp(&a.rem));
-                let give: usize = core::cmp::min(leftover, portions.len() as u128) as usize;
-                for p in portions.iter_mut().take(give) {
-                    p.share = p.share.saturating_add(1);
-                }
-            }
-
-            // Credit each share directly to coldkey free balance.
-            for p in portions {
-                if p.share > 0 {
-                    Self::add_balance_to_coldkey_account(&p.cold, p.share);
-                }
+        // 4) Credit each share to the staker's COLDKEY free balance, or restake it into root.
+        Self::deposit_dissolve_payout_logs(&settlement);
+        for (cold, share) in settlement.payouts.iter() {
+            if !share.is_zero() {
+                Self::credit_dissolution_payout(cold, *share);
             }
         }
 
-        // 7) Destroy all α-in/α-out state for this subnet.
-        // 7.a) Remove every (hot, cold, netuid) α entry.
-        for (hot, cold) in keys_to_remove {
-            Alpha::<T>::remove((hot, cold, netuid));
+        // 5) Destroy all α-in/α-out state for this subnet.
+        // 5.a) Remove every (hot, cold, netuid) α entry.
+        let mut hotkeys_seen: Vec<T::AccountId> = Vec::new();
+        for (hot, cold) in settlement.stakes {
+            Alpha::<T>::remove((&hot, cold, netuid));
+            if !hotkeys_seen.contains(&hot) {
+                hotkeys_seen.push(hot);
+            }
         }
-        // 7.b) Clear share‑pool totals for each hotkey on this subnet.
+        // 5.b) Clear share‑pool totals for each hotkey on this subnet.
         for hot in hotkeys_seen {
             TotalHotkeyAlpha::<T>::remove(&hot, netuid);
             TotalHotkeyShares::<T>::remove(&hot, netuid);
         }
-        // 7.c) Remove α‑in/α‑out counters (fully destroyed).
+        // 5.c) Remove α‑in/α‑out counters (fully destroyed).
         SubnetAlphaIn::<T>::remove(netuid);
         SubnetAlphaInProvided::<T>::remove(netuid);
         SubnetAlphaOut::<T>::remove(netuid);
@@ -612,18 +499,12 @@ impl<T: Config> Pallet<T> {
         // Clear the locked balance on the subnet.
         Self::set_subnet_locked_balance(netuid, TaoCurrency::ZERO);
 
-        // 8) Finalize lock handling:
-        //    - Legacy subnets (registered before NetworkRegistrationStartBlock) receive:
-        //        refund = max(0, lock_cost(τ) − owner_received_emission_in_τ).
-        //    - New subnets: no refund.
-        let refund: TaoCurrency = if should_refund_owner {
-            lock_cost.saturating_sub(owner_emission_tao)
-        } else {
-            TaoCurrency::ZERO
-        };
-
-        if !refund.is_zero() {
-            Self::add_balance_to_coldkey_account(&owner_coldkey, refund.to_u64());
+        // 6) Finalize lock handling: refund the owner of a legacy subnet.
+        if !settlement.owner_refund.is_zero() {
+            Self::add_balance_to_coldkey_account(
+                &settlement.owner,
+                settlement.owner_refund.to_u64(),
+            );
         }
 
         Ok(())
@@ -1,7 +1,11 @@
 use super::*;
+use crate::swap::key_rotation_log::KeyRotation;
 
 use subtensor_runtime_common::NetUid;
 
+/// The maximum number of subnets the children of a hotkey are set on in one batch.
+pub const MAX_CHILDREN_BATCH_LEN: usize = 64;
+
 impl<T: Config> Pallet<T> {
     /// ---- The implementation for the extrinsic do_set_child_singular: Sets a single child.
     /// This function allows a coldkey to set children keys.
@@ -135,6 +139,37 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Schedules the children of `hotkey` on each subnet of `batch`, with the checks and the
+    /// rate limit of `do_schedule_children` applied to every subnet. Either every subnet of the
+    /// batch is scheduled or none is.
+    ///
+    /// # Errors:
+    /// * `InvalidChildrenBatch`:
+    ///     - The batch is empty, sets more than `MAX_CHILDREN_BATCH_LEN` subnets or sets a
+    ///       subnet twice.
+    /// * Any error of `do_schedule_children` on a subnet of the batch.
+    ///
+    pub fn do_schedule_children_batch(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        batch: Vec<(NetUid, Vec<(u64, T::AccountId)>)>,
+    ) -> DispatchResult {
+        let mut netuids: Vec<NetUid> = batch.iter().map(|(netuid, _)| *netuid).collect();
+        netuids.sort();
+        netuids.dedup();
+        ensure!(
+            !batch.is_empty()
+                && batch.len() <= MAX_CHILDREN_BATCH_LEN
+                && netuids.len() == batch.len(),
+            Error::<T>::InvalidChildrenBatch
+        );
+
+        for (netuid, children) in batch {
+            Self::do_schedule_children(origin.clone(), hotkey.clone(), netuid, children)?;
+        }
+        Ok(())
+    }
+
     /// This function executes setting children keys when called during hotkey draining.
     ///
     /// * `netuid` (u16):
@@ -218,6 +253,14 @@ impl<T: Config> Pallet<T> {
                         netuid,
                         children.clone(),
                     ));
+                    Self::record_key_rotation(
+                        &[&hotkey],
+                        KeyRotation::ChildrenSet {
+                            hotkey: hotkey.clone(),
+                            netuid,
+                            children: children.clone(),
+                        },
+                    );
 
                     // Remove pending children
                     PendingChildKeys::<T>::remove(netuid, hotkey);
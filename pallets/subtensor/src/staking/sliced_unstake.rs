@@ -0,0 +1,236 @@
+//! Sliced unstakes.
+//!
+//! Selling a large position into a subnet pool in one swap moves the price far against the seller.
+//! An unstake whose size exceeds a fraction of the pool depth can instead be queued as a sliced
+//! unstake, which is executed in slices once per block over at most `max_duration` blocks. Each
+//! slice is sold with the caller's limit price and partially filled if the price falls below it.
+//! Whatever has not been sold when the order expires stays staked.
+//!
+//! The alpha of a queued order is not locked. A slice only sells what the coldkey still has staked
+//! at the time, and is skipped while the coldkey is under an unstake timelock.
+
+use super::*;
+use frame_support::storage::{TransactionOutcome, transactional};
+use safe_math::*;
+use substrate_fixed::types::U96F32;
+use subtensor_runtime_common::{AlphaCurrency, NetUid, TaoCurrency};
+use subtensor_swap_interface::SwapHandler;
+
+pub type SlicedUnstakeId = u64;
+
+/// Maximum number of sliced unstakes queued at any time.
+pub const MAX_SLICED_UNSTAKES: usize = 64;
+
+/// Maximum duration of a sliced unstake, about one day.
+pub const MAX_SLICED_UNSTAKE_DURATION: u64 = 7200;
+
+#[freeze_struct("8393a9e67084e82d")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SlicedUnstake<AccountId> {
+    /// The coldkey unstaking.
+    pub coldkey: AccountId,
+    /// The hotkey the stake is removed from.
+    pub hotkey: AccountId,
+    /// The subnet the stake is removed from.
+    pub netuid: NetUid,
+    /// The alpha left to unstake.
+    pub remaining: AlphaCurrency,
+    /// The alpha unstaked per block.
+    pub slice: AlphaCurrency,
+    /// The lowest price at which a slice is sold.
+    pub limit_price: TaoCurrency,
+    /// The last block at which a slice is executed.
+    pub expires_at: u64,
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn do_remove_stake_sliced(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        netuid: NetUid,
+        alpha_unstaked: AlphaCurrency,
+        limit_price: TaoCurrency,
+        max_duration: u64,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        Self::ensure_unstake_unlocked(&coldkey)?;
+
+        ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
+        ensure!(
+            Self::hotkey_account_exists(&hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+        ensure!(
+            (1..=MAX_SLICED_UNSTAKE_DURATION).contains(&max_duration),
+            Error::<T>::InvalidSlicedUnstakeDuration
+        );
+        ensure!(
+            Self::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid)
+                >= alpha_unstaked,
+            Error::<T>::NotEnoughStakeToWithdraw
+        );
+
+        let threshold = Self::get_sliced_unstake_threshold(netuid);
+        ensure!(
+            alpha_unstaked > threshold,
+            Error::<T>::UnstakeBelowSlicingThreshold
+        );
+        ensure!(
+            SlicedUnstakes::<T>::iter_keys().count() < MAX_SLICED_UNSTAKES,
+            Error::<T>::TooManySlicedUnstakes
+        );
+        Self::ensure_can_schedule_operation(&coldkey)?;
+
+        // Slices are as large as the threshold allows, but large enough to finish in time.
+        let slice = threshold.max(alpha_unstaked.to_u64().div_ceil(max_duration).into());
+        let expires_at = Self::get_current_block_as_u64().saturating_add(max_duration);
+
+        let id = NextSlicedUnstakeId::<T>::get();
+        NextSlicedUnstakeId::<T>::put(id.saturating_add(1));
+        SlicedUnstakes::<T>::insert(
+            id,
+            SlicedUnstake {
+                coldkey: coldkey.clone(),
+                hotkey: hotkey.clone(),
+                netuid,
+                remaining: alpha_unstaked,
+                slice,
+                limit_price,
+                expires_at,
+            },
+        );
+
+        Self::deposit_event(Event::SlicedUnstakeScheduled {
+            id,
+            coldkey,
+            hotkey,
+            netuid,
+            alpha: alpha_unstaked,
+            slice,
+            expires_at,
+        });
+        Ok(())
+    }
+
+    pub fn do_cancel_sliced_unstake(
+        origin: T::RuntimeOrigin,
+        id: SlicedUnstakeId,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        let order = SlicedUnstakes::<T>::get(id).ok_or(Error::<T>::SlicedUnstakeNotFound)?;
+        ensure!(order.coldkey == coldkey, Error::<T>::SlicedUnstakeNotFound);
+
+        SlicedUnstakes::<T>::remove(id);
+        Self::deposit_event(Event::SlicedUnstakeFinished {
+            id,
+            remaining: order.remaining,
+        });
+        Ok(())
+    }
+
+    /// The unstake size on `netuid` above which an unstake may be sliced.
+    pub fn get_sliced_unstake_threshold(netuid: NetUid) -> AlphaCurrency {
+        let depth = U96F32::saturating_from_num(SubnetAlphaIn::<T>::get(netuid).to_u64());
+        let fraction = U96F32::saturating_from_num(SlicedUnstakeDepthFraction::<T>::get())
+            .safe_div(U96F32::saturating_from_num(u16::MAX));
+        depth
+            .saturating_mul(fraction)
+            .saturating_to_num::<u64>()
+            .into()
+    }
+
+    pub fn set_sliced_unstake_depth_fraction(fraction: u16) {
+        SlicedUnstakeDepthFraction::<T>::put(fraction);
+        Self::deposit_event(Event::SlicedUnstakeDepthFractionSet(fraction));
+    }
+
+    /// Executes one slice of every queued sliced unstake and removes finished orders.
+    ///
+    /// Returns the weight consumed, which is charged to the block.
+    pub fn execute_sliced_unstakes() -> Weight {
+        let current_block = Self::get_current_block_as_u64();
+        let orders: Vec<_> = SlicedUnstakes::<T>::iter().collect();
+        let mut weight = T::DbWeight::get().reads(orders.len() as u64);
+        for (id, mut order) in orders {
+            // The unlock and stake checks and the order update
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(3, 1));
+            if current_block <= order.expires_at
+                && Self::ensure_unstake_unlocked(&order.coldkey).is_ok()
+            {
+                weight.saturating_accrue(Self::unstake_slice_weight());
+                let sold = Self::execute_unstake_slice(&order);
+                order.remaining = order.remaining.saturating_sub(sold);
+            }
+
+            let staked = Self::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &order.hotkey,
+                &order.coldkey,
+                order.netuid,
+            );
+            if current_block >= order.expires_at || order.remaining.is_zero() || staked.is_zero() {
+                SlicedUnstakes::<T>::remove(id);
+                Self::deposit_event(Event::SlicedUnstakeFinished {
+                    id,
+                    remaining: order.remaining,
+                });
+            } else {
+                SlicedUnstakes::<T>::insert(id, order);
+            }
+        }
+
+        weight
+    }
+
+    /// The weight of selling one slice, that of a `remove_stake`.
+    fn unstake_slice_weight() -> Weight {
+        Weight::from_parts(196_800_000, 0)
+            .saturating_add(T::DbWeight::get().reads(19))
+            .saturating_add(T::DbWeight::get().writes(10))
+            .saturating_add(T::SwapInterface::price_floor_defense_weight())
+    }
+
+    /// Sells one slice of `order`, returning the alpha sold.
+    fn execute_unstake_slice(order: &SlicedUnstake<T::AccountId>) -> AlphaCurrency {
+        let staked = Self::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &order.hotkey,
+            &order.coldkey,
+            order.netuid,
+        );
+        let alpha = order.slice.min(order.remaining).min(staked);
+        if alpha.is_zero() {
+            return AlphaCurrency::ZERO;
+        }
+
+        let result = transactional::with_transaction(|| {
+            let result = Self::unstake_from_subnet(
+                &order.hotkey,
+                &order.coldkey,
+                order.netuid,
+                alpha,
+                order.limit_price,
+                false,
+            );
+            match result {
+                Ok(tao) => TransactionOutcome::Commit(Ok(tao)),
+                Err(e) => TransactionOutcome::Rollback(Err(e)),
+            }
+        });
+        let tao = match result {
+            Ok(tao) => tao,
+            Err(e) => {
+                // The price is below the limit or the reserve short, retry in the next block
+                Self::report_rejected_tao_debit(order.netuid, &e);
+                return AlphaCurrency::ZERO;
+            }
+        };
+
+        Self::add_balance_to_coldkey_account(&order.coldkey, tao.into());
+        let sold = staked.saturating_sub(Self::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &order.hotkey,
+            &order.coldkey,
+            order.netuid,
+        ));
+        Self::clear_small_nomination_if_required(&order.hotkey, &order.coldkey, order.netuid);
+        sold
+    }
+}
@@ -0,0 +1,114 @@
+//! SubnetTAO solvency.
+//!
+//! The TAO reserve of a subnet, `SubnetTAO` and `SubnetTaoProvided`, backs the alpha outstanding
+//! on it: the reserve must cover what the pool pays, from its current tick, for all of
+//! `SubnetAlphaOut`. Every path that pays TAO out of the reserve, unstake swaps, liquidity
+//! removals and the payout of a dissolved subnet, checks the debit here instead of saturating it
+//! at zero, so a debit that would leave the outstanding alpha uncovered fails with
+//! `SubnetTaoInsolvent` rather than silently minting the difference.
+//!
+//! An alpha sale is also bounded by the value of the sold alpha at the price of the pool before
+//! the sale, as selling only ever moves the price down.
+//!
+//! A failed debit is rolled back along with its events. A rejected debit is logged where it is
+//! checked, and the block hooks that go on past a failed debit, the sale of root emission, sliced
+//! unstakes, lease dividends and the clearing of small nominations, deposit
+//! `SubnetTaoDebitRejected` outside of the rolled back transaction. The invariant is also checked
+//! on the epoch of every subnet, which deposits `SubnetTaoInsolvencyDetected` when it does not
+//! hold.
+
+use super::*;
+use substrate_fixed::types::U96F32;
+use subtensor_runtime_common::{NetUid, TaoCurrency};
+use subtensor_swap_interface::{OrderType, SwapHandler, SwapResult};
+
+impl<T: Config> Pallet<T> {
+    /// The TAO reserve of `netuid`, provided by the protocol and by liquidity providers.
+    pub fn get_subnet_tao_reserve(netuid: NetUid) -> TaoCurrency {
+        SubnetTAO::<T>::get(netuid).saturating_add(SubnetTaoProvided::<T>::get(netuid))
+    }
+
+    /// The TAO the pool of `netuid` pays, from its current tick, for all the alpha outstanding on
+    /// the subnet, `None` if the sale cannot be simulated.
+    pub fn get_outstanding_alpha_value(netuid: NetUid) -> Option<TaoCurrency> {
+        let alpha_out = SubnetAlphaOut::<T>::get(netuid);
+        if alpha_out.is_zero() {
+            return Some(TaoCurrency::ZERO);
+        }
+
+        T::SwapInterface::sim_swap(netuid.into(), OrderType::Sell, alpha_out.into())
+            .ok()
+            .map(|swap_result| swap_result.amount_paid_out.into())
+    }
+
+    /// Checks that a debit of `debit` leaves the TAO reserve of `netuid` covering the value of the
+    /// alpha outstanding on it. Only the reserve is checked when the value cannot be simulated.
+    pub fn ensure_subnet_tao_solvent(netuid: NetUid, debit: TaoCurrency) -> Result<(), Error<T>> {
+        let backing = Self::get_outstanding_alpha_value(netuid).unwrap_or_default();
+        let available = Self::get_subnet_tao_reserve(netuid).saturating_sub(backing);
+
+        Self::ensure_tao_debit_covered(netuid, available, debit)
+    }
+
+    /// Deposits `SubnetTaoInsolvencyDetected` if the TAO reserve of `netuid` does not cover the
+    /// value of the alpha outstanding on it.
+    pub fn check_subnet_tao_solvency(netuid: NetUid) {
+        let Some(alpha_value) = Self::get_outstanding_alpha_value(netuid) else {
+            return;
+        };
+        let reserve = Self::get_subnet_tao_reserve(netuid);
+        if reserve < alpha_value {
+            log::error!(
+                "SubnetTAO insolvency on netuid {netuid:?}: reserve {reserve:?} below the value of outstanding alpha {alpha_value:?}"
+            );
+            Self::deposit_event(Event::SubnetTaoInsolvencyDetected {
+                netuid,
+                reserve,
+                alpha_value,
+            });
+        }
+    }
+
+    /// Deposits `SubnetTaoDebitRejected` if `error`, returned by a debit of the TAO reserve of
+    /// `netuid` that was rolled back, is the rejection of the solvency check.
+    pub fn report_rejected_tao_debit(netuid: NetUid, error: &DispatchError) {
+        if *error == Error::<T>::SubnetTaoInsolvent.into() {
+            Self::deposit_event(Event::SubnetTaoDebitRejected { netuid });
+        }
+    }
+
+    /// Checks that the sale of alpha in `swap_result` on `netuid`, at `price` before the sale,
+    /// pays out no more than the sold alpha is worth and than the reserve holds.
+    pub fn ensure_alpha_sale_solvent(
+        netuid: NetUid,
+        price: U96F32,
+        swap_result: &SwapResult,
+    ) -> Result<(), Error<T>> {
+        let alpha_sold = swap_result
+            .amount_paid_in
+            .saturating_add(swap_result.fee_paid);
+        let alpha_value: u64 = U96F32::saturating_from_num(alpha_sold)
+            .saturating_mul(price)
+            .ceil()
+            .saturating_to_num();
+        let available = Self::get_subnet_tao_reserve(netuid).min(alpha_value.into());
+        let debit = swap_result.tao_reserve_delta.unsigned_abs().into();
+
+        Self::ensure_tao_debit_covered(netuid, available, debit)
+    }
+
+    /// Checks that `available` TAO of `netuid` covers a debit of `debit`.
+    pub fn ensure_tao_debit_covered(
+        netuid: NetUid,
+        available: TaoCurrency,
+        debit: TaoCurrency,
+    ) -> Result<(), Error<T>> {
+        if debit > available {
+            log::error!(
+                "SubnetTAO insolvency on netuid {netuid:?}: debit {debit:?} exceeds available {available:?}"
+            );
+            return Err(Error::<T>::SubnetTaoInsolvent);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+//! Stake origin tags.
+//!
+//! A stake position, the stake of a coldkey on a hotkey in a subnet, is tagged with where the
+//! stake comes from when it is first staked: the coldkey staking to its own hotkey, a delegation,
+//! or a coldkey attested by root as belonging to an institution. Moves and swaps of stake between
+//! positions of the same coldkey carry the tag of the origin position, so restaking cannot launder
+//! the origin of stake, while stake transferred to another coldkey is tagged for its new owner.
+//!
+//! Tags are optional, positions staked before tagging or only credited by emissions have none.
+//! Compliance-gated subnets read them through `get_stake_origins` to weight or restrict the
+//! influence of stake by origin.
+
+use super::*;
+use frame_system::ensure_root;
+use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid};
+
+/// Where the stake of a position comes from.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo)]
+pub enum StakeOriginTag {
+    /// Stake of the coldkey owning the hotkey.
+    SelfStake,
+    /// Stake delegated to a hotkey of another coldkey.
+    Delegated,
+    /// Stake of a coldkey attested by root as belonging to an institution.
+    InstitutionalAttested,
+}
+
+/// A tagged stake position on a subnet.
+#[freeze_struct("ccc220044ae33be2")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct StakeOriginInfo<AccountId> {
+    pub hotkey: AccountId,
+    pub coldkey: AccountId,
+    pub origin: StakeOriginTag,
+    /// Current alpha stake of the position.
+    pub stake: AlphaCurrency,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Attests `coldkey` as belonging to an institution, or revokes the attestation.
+    ///
+    /// Only positions staked after the attestation are tagged as institutional.
+    pub fn do_set_institutional_attestation(
+        origin: T::RuntimeOrigin,
+        coldkey: T::AccountId,
+        attested: bool,
+    ) -> DispatchResult {
+        ensure_root(origin)?;
+
+        if attested {
+            InstitutionalColdkeys::<T>::insert(&coldkey, true);
+        } else {
+            InstitutionalColdkeys::<T>::remove(&coldkey);
+        }
+        Self::deposit_event(Event::InstitutionalAttestationSet { coldkey, attested });
+        Ok(())
+    }
+
+    /// Tags the position of `coldkey` on `hotkey` with the origin of its stake, unless it is
+    /// tagged already.
+    pub fn tag_stake_origin(hotkey: &T::AccountId, coldkey: &T::AccountId, netuid: NetUid) {
+        if StakeOrigins::<T>::contains_key((netuid, hotkey, coldkey)) {
+            return;
+        }
+
+        let tag = if InstitutionalColdkeys::<T>::get(coldkey) {
+            StakeOriginTag::InstitutionalAttested
+        } else if Self::get_owning_coldkey_for_hotkey(hotkey) == *coldkey {
+            StakeOriginTag::SelfStake
+        } else {
+            StakeOriginTag::Delegated
+        };
+        StakeOrigins::<T>::insert((netuid, hotkey, coldkey), tag);
+    }
+
+    /// Gives the position of `coldkey` on `hotkey` the tag of the position its stake comes from,
+    /// unless it is tagged already.
+    pub fn inherit_stake_origin(
+        tag: Option<StakeOriginTag>,
+        hotkey: &T::AccountId,
+        coldkey: &T::AccountId,
+        netuid: NetUid,
+    ) {
+        if let Some(tag) = tag {
+            if !StakeOrigins::<T>::contains_key((netuid, hotkey, coldkey)) {
+                StakeOrigins::<T>::insert((netuid, hotkey, coldkey), tag);
+            }
+        }
+    }
+
+    /// Drops the tag of the position of `coldkey` on `hotkey` once it has no stake left.
+    pub fn clear_stake_origin_if_unstaked(
+        hotkey: &T::AccountId,
+        coldkey: &T::AccountId,
+        netuid: NetUid,
+    ) {
+        if Self::get_stake_for_hotkey_and_coldkey_on_subnet(hotkey, coldkey, netuid).is_zero() {
+            StakeOrigins::<T>::remove((netuid, hotkey, coldkey));
+        }
+    }
+
+    /// The tagged positions with stake on `netuid`.
+    pub fn get_stake_origins(netuid: NetUid) -> Vec<StakeOriginInfo<T::AccountId>> {
+        StakeOrigins::<T>::iter_prefix((netuid,))
+            .filter_map(|((hotkey, coldkey), origin)| {
+                let stake =
+                    Self::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid);
+                (!stake.is_zero()).then_some(StakeOriginInfo {
+                    hotkey,
+                    coldkey,
+                    origin,
+                    stake,
+                })
+            })
+            .collect()
+    }
+}
@@ -1,4 +1,6 @@
 use super::*;
+use crate::utils::trailing_window::{roll_trailing_windows, trailing_window_sum};
+use frame_support::storage::{TransactionOutcome, transactional};
 use safe_math::*;
 use share_pool::{SharePool, SharePoolDataOperations};
 use sp_std::ops::Neg;
@@ -571,6 +573,24 @@ impl<T: Config> Pallet<T> {
         actual_alpha.neg().max(0).unsigned_abs().into()
     }
 
+    /// Adds `tao` bought or sold on `netuid` to its total and trailing volume.
+    pub fn increase_subnet_volume(netuid: NetUid, tao: u128) {
+        SubnetVolume::<T>::mutate(netuid, |total| *total = total.saturating_add(tao));
+        let now = Self::get_current_block_as_u64();
+        SubnetVolumeWindows::<T>::mutate(netuid, |windows| {
+            let (start, current, previous) = roll_trailing_windows(*windows, now);
+            *windows = (start, current.saturating_add(tao), previous);
+        });
+    }
+
+    /// The TAO bought and sold on `netuid` over the last `TRAILING_WINDOW_BLOCKS` blocks.
+    pub fn get_trailing_subnet_volume(netuid: NetUid) -> u128 {
+        let now = Self::get_current_block_as_u64();
+        let (start, current, previous) =
+            roll_trailing_windows(SubnetVolumeWindows::<T>::get(netuid), now);
+        trailing_window_sum(start, current, previous, now)
+    }
+
     /// Swaps TAO for the alpha token on the subnet.
     ///
     /// Updates TaoIn, AlphaIn, and AlphaOut
@@ -625,45 +645,62 @@ impl<T: Config> Pallet<T> {
         TotalStake::<T>::mutate(|total| *total = total.saturating_add(tao));
 
         // Increase total subnet TAO volume.
-        SubnetVolume::<T>::mutate(netuid, |total| {
-            *total = total.saturating_add(tao.to_u64() as u128);
-        });
+        Self::increase_subnet_volume(netuid, tao.to_u64() as u128);
 
         Ok(swap_result)
     }
 
     /// Swaps a subnet's Alpha token for TAO.
     ///
-    /// Updates TaoIn, AlphaIn, and AlphaOut
+    /// Updates TaoIn, AlphaIn, and AlphaOut, rolling the swap back if the reserve cannot pay it
     pub fn swap_alpha_for_tao(
         netuid: NetUid,
         alpha: AlphaCurrency,
         price_limit: TaoCurrency,
         drop_fees: bool,
+    ) -> Result<SwapResult, DispatchError> {
+        transactional::with_transaction(|| {
+            match Self::do_swap_alpha_for_tao(netuid, alpha, price_limit, drop_fees) {
+                Ok(swap_result) => TransactionOutcome::Commit(Ok(swap_result)),
+                Err(e) => TransactionOutcome::Rollback(Err(e)),
+            }
+        })
+    }
+
+    fn do_swap_alpha_for_tao(
+        netuid: NetUid,
+        alpha: AlphaCurrency,
+        price_limit: TaoCurrency,
+        drop_fees: bool,
     ) -> Result<SwapResult, DispatchError> {
         // Step 1: Get the mechanism type for the subnet (0 for Stable, 1 for Dynamic)
         let mechanism_id: u16 = SubnetMechanism::<T>::get(netuid);
         // Step 2: Swap alpha and attain tao
         let swap_result = if mechanism_id == 1 {
-            T::SwapInterface::swap(
+            let price = T::SwapInterface::current_alpha_price(netuid.into());
+            let swap_result = T::SwapInterface::swap(
                 netuid.into(),
                 OrderType::Sell,
                 alpha.into(),
                 price_limit.into(),
                 drop_fees,
                 false,
-            )?
+            )?;
+            Self::ensure_alpha_sale_solvent(netuid, price, &swap_result)?;
+            swap_result
         } else {
             let abs_delta: u64 = alpha.into();
 
             // Step 3.b.1: Stable mechanism, just return the value 1:1
-            SwapResult {
+            let swap_result = SwapResult {
                 amount_paid_in: alpha.into(),
                 amount_paid_out: alpha.into(),
                 fee_paid: 0,
                 tao_reserve_delta: (abs_delta as i64).neg(),
                 alpha_reserve_delta: abs_delta as i64,
-            }
+            };
+            Self::ensure_alpha_sale_solvent(netuid, U96F32::saturating_from_num(1), &swap_result)?;
+            swap_result
         };
 
         // Increase only the protocol Alpha reserve. We only use the sum of
@@ -688,7 +725,7 @@ impl<T: Config> Pallet<T> {
                 .try_into()
                 .unwrap_or(0)
                 .into(),
-        );
+        )?;
 
         // Reduce total TAO reserves.
         TotalStake::<T>::mutate(|total| {
@@ -696,9 +733,7 @@ impl<T: Config> Pallet<T> {
         });
 
         // Increase total subnet TAO volume.
-        SubnetVolume::<T>::mutate(netuid, |total| {
-            *total = total.saturating_add(swap_result.amount_paid_out.into())
-        });
+        Self::increase_subnet_volume(netuid, swap_result.amount_paid_out.into());
 
         // Return the tao received.
         Ok(swap_result)
@@ -733,6 +768,7 @@ impl<T: Config> Pallet<T> {
         if !refund.is_zero() {
             Self::increase_stake_for_hotkey_and_coldkey_on_subnet(hotkey, coldkey, netuid, refund);
         }
+        Self::clear_stake_origin_if_unstaked(hotkey, coldkey, netuid);
 
         // Step 3: Update StakingHotkeys if the hotkey's total alpha, across all subnets, is zero
         // TODO const: fix.
@@ -763,6 +799,13 @@ impl<T: Config> Pallet<T> {
             netuid,
             swap_result.fee_paid
         );
+        Self::deposit_stake_removed_log(
+            coldkey,
+            hotkey,
+            netuid,
+            swap_result.amount_paid_out.into(),
+            actual_alpha_decrease,
+        );
 
         Ok(swap_result.amount_paid_out.into())
     }
@@ -812,6 +855,7 @@ impl<T: Config> Pallet<T> {
             staking_hotkeys.push(hotkey.clone());
             StakingHotkeys::<T>::insert(coldkey, staking_hotkeys.clone());
         }
+        Self::tag_stake_origin(hotkey, coldkey, netuid);
 
         LastColdkeyHotkeyStakeBlock::<T>::insert(coldkey, hotkey, Self::get_current_block_as_u64());
 
@@ -838,6 +882,13 @@ impl<T: Config> Pallet<T> {
             netuid,
             swap_result.fee_paid,
         );
+        Self::deposit_stake_added_log(
+            coldkey,
+            hotkey,
+            netuid,
+            tao,
+            swap_result.amount_paid_out.into(),
+        );
 
         Ok(swap_result.amount_paid_out.into())
     }
@@ -885,6 +936,9 @@ impl<T: Config> Pallet<T> {
             Error::<T>::AmountTooLow
         );
 
+        Self::tag_stake_origin(destination_hotkey, destination_coldkey, netuid);
+        Self::clear_stake_origin_if_unstaked(origin_hotkey, origin_coldkey, netuid);
+
         // Step 3: Update StakingHotkeys if the hotkey's total alpha, across all subnets, is zero
         // TODO: fix.
         // if Self::get_stake(hotkey, coldkey) == 0 {
@@ -916,6 +970,20 @@ impl<T: Config> Pallet<T> {
             netuid,
             0_u64, // 0 fee
         ));
+        Self::deposit_stake_removed_log(
+            origin_coldkey,
+            origin_hotkey,
+            netuid,
+            tao_equivalent,
+            actual_alpha_decrease,
+        );
+        Self::deposit_stake_added_log(
+            destination_coldkey,
+            destination_hotkey,
+            netuid,
+            tao_equivalent,
+            actual_alpha_moved,
+        );
 
         Ok(tao_equivalent)
     }
@@ -1112,6 +1180,11 @@ impl<T: Config> Pallet<T> {
             ensure!(origin_netuid != destination_netuid, Error::<T>::SameNetuid);
         }
 
+        // Stake leaving the coldkey is subject to its unstake timelock
+        if origin_coldkey != destination_coldkey {
+            Self::ensure_unstake_unlocked(origin_coldkey)?;
+        }
+
         Self::ensure_stake_operation_limit_not_exceeded(
             origin_hotkey,
             origin_coldkey,
@@ -1210,7 +1283,9 @@ impl<T: Config> Pallet<T> {
         });
     }
 
-    pub fn decrease_provided_tao_reserve(netuid: NetUid, tao: TaoCurrency) {
+    pub fn decrease_provided_tao_reserve(netuid: NetUid, tao: TaoCurrency) -> DispatchResult {
+        Self::ensure_subnet_tao_solvent(netuid, tao)?;
+
         // First, decrease SubnetTaoProvided, then deduct the rest from SubnetTAO
         let subnet_tao = SubnetTAO::<T>::get(netuid);
         let subnet_tao_provided = SubnetTaoProvided::<T>::get(netuid);
@@ -1222,6 +1297,8 @@ impl<T: Config> Pallet<T> {
             SubnetTaoProvided::<T>::set(netuid, TaoCurrency::ZERO);
             SubnetTAO::<T>::set(netuid, subnet_tao.saturating_sub(carry_over));
         }
+
+        Ok(())
     }
 
     pub fn increase_provided_alpha_reserve(netuid: NetUid, alpha: AlphaCurrency) {
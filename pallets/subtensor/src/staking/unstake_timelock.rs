@@ -0,0 +1,95 @@
+//! Self-imposed unstake timelocks.
+//!
+//! A coldkey may opt into a timelock of a number of blocks. While a timelock is set, the coldkey
+//! can only unstake, transfer stake to another coldkey or schedule a coldkey swap after it has
+//! requested an unlock and the timelock has elapsed since that request. A compromised coldkey
+//! therefore cannot drain its stake instantly.
+//!
+//! Raising the timelock applies immediately and re-locks the coldkey. Lowering it only applies
+//! once the current timelock has elapsed.
+
+use super::*;
+
+/// Maximum unstake timelock a coldkey can set, about 30 days.
+pub const MAX_UNSTAKE_TIMELOCK: u64 = 216_000;
+
+impl<T: Config> Pallet<T> {
+    pub fn do_set_unstake_timelock(origin: T::RuntimeOrigin, blocks: u64) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        ensure!(
+            blocks <= MAX_UNSTAKE_TIMELOCK,
+            Error::<T>::UnstakeTimelockTooLong
+        );
+
+        let current = Self::get_unstake_timelock(&coldkey);
+        if blocks >= current {
+            if blocks == 0 {
+                UnstakeTimelock::<T>::remove(&coldkey);
+            } else {
+                UnstakeTimelock::<T>::insert(&coldkey, blocks);
+            }
+            PendingUnstakeTimelock::<T>::remove(&coldkey);
+            UnstakeUnlockedAt::<T>::remove(&coldkey);
+
+            Self::deposit_event(Event::UnstakeTimelockSet { coldkey, blocks });
+        } else {
+            // Replacing a pending decrease does not take up another slot
+            let replaces_pending = PendingUnstakeTimelock::<T>::get(&coldkey)
+                .is_some_and(|(_, applies_at)| applies_at > Self::get_current_block_as_u64());
+            if !replaces_pending {
+                Self::ensure_can_schedule_operation(&coldkey)?;
+            }
+
+            // Persist a matured decrease before scheduling the next one
+            UnstakeTimelock::<T>::insert(&coldkey, current);
+            let applies_at = Self::get_current_block_as_u64().saturating_add(current);
+            PendingUnstakeTimelock::<T>::insert(&coldkey, (blocks, applies_at));
+
+            Self::deposit_event(Event::UnstakeTimelockDecreaseScheduled {
+                coldkey,
+                blocks,
+                applies_at,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn do_request_unstake_unlock(origin: T::RuntimeOrigin) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        let timelock = Self::get_unstake_timelock(&coldkey);
+        ensure!(timelock > 0, Error::<T>::UnstakeTimelockNotSet);
+
+        let unlocked_at = Self::get_current_block_as_u64().saturating_add(timelock);
+        UnstakeUnlockedAt::<T>::insert(&coldkey, unlocked_at);
+
+        Self::deposit_event(Event::UnstakeUnlockRequested {
+            coldkey,
+            unlocked_at,
+        });
+        Ok(())
+    }
+
+    /// The unstake timelock in effect for `coldkey`, taking a matured decrease into account.
+    pub fn get_unstake_timelock(coldkey: &T::AccountId) -> u64 {
+        match PendingUnstakeTimelock::<T>::get(coldkey) {
+            Some((blocks, applies_at)) if Self::get_current_block_as_u64() >= applies_at => blocks,
+            _ => UnstakeTimelock::<T>::get(coldkey),
+        }
+    }
+
+    /// Fails if `coldkey` has a timelock and its unlock has not been requested or has not matured.
+    pub fn ensure_unstake_unlocked(coldkey: &T::AccountId) -> Result<(), Error<T>> {
+        if Self::get_unstake_timelock(coldkey) == 0 {
+            return Ok(());
+        }
+
+        let now = Self::get_current_block_as_u64();
+        ensure!(
+            UnstakeUnlockedAt::<T>::get(coldkey).is_some_and(|unlocked_at| now >= unlocked_at),
+            Error::<T>::UnstakeTimelocked
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+//! Subnet lifecycle attestations.
+//!
+//! Oracles authorized by root are off-chain services that attest operational facts about a
+//! subnet, such as the version of a mechanism that was deployed or the hash of an external audit
+//! report, so that mechanisms and governance can reference them on chain. An oracle signs the
+//! SCALE encoded `SubnetAttestationPayload` with its sr25519 key and submits it in an unsigned
+//! `submit_subnet_attestation` transaction, which is only valid with the signature of an
+//! authorized oracle over a payload of the last `MAX_SUBNET_ATTESTATION_AGE` blocks.
+//!
+//! Every subnet keeps its last `MAX_SUBNET_ATTESTATIONS` attestations, the oldest one is dropped
+//! when a new one comes in.
+
+use super::*;
+use codec::DecodeWithMemTracking;
+use frame_system::{ensure_none, ensure_root};
+use sp_core::{
+    H256,
+    sr25519::{Public, Signature},
+};
+use sp_runtime::transaction_validity::{TransactionValidity, ValidTransaction};
+use subtensor_runtime_common::{MechId, NetUid};
+
+/// Number of attestations kept per subnet.
+pub const MAX_SUBNET_ATTESTATIONS: u32 = 32;
+
+/// Number of blocks after the block it is signed at that an attestation can be submitted.
+pub const MAX_SUBNET_ATTESTATION_AGE: u64 = 64;
+
+/// The fact an oracle attests about a subnet.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum SubnetAttestationKind {
+    /// A mechanism of the subnet runs the given version.
+    MechanismDeployed { mecid: MechId, version: u64 },
+    /// The subnet was audited externally, by the hash of the audit report.
+    ExternalAudit { report: H256 },
+    /// Any other fact, by a topic agreed off chain and the hash of its content.
+    Other { topic: u16, hash: H256 },
+}
+
+/// The payload an oracle signs to attest a fact about a subnet.
+#[freeze_struct("f40466f5e31a74a5")]
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct SubnetAttestationPayload {
+    pub netuid: NetUid,
+    pub oracle: Public,
+    pub kind: SubnetAttestationKind,
+    /// Block the payload is signed at.
+    pub block_number: u64,
+}
+
+/// An attestation recorded on a subnet.
+#[freeze_struct("879d0552840e541e")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct SubnetAttestation {
+    pub oracle: Public,
+    pub kind: SubnetAttestationKind,
+    /// Block the attestation was recorded at.
+    pub block_number: u64,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Authorizes `oracle` to attest facts about subnets, or revokes it.
+    pub fn do_set_subnet_attestation_oracle(
+        origin: T::RuntimeOrigin,
+        oracle: Public,
+        authorized: bool,
+    ) -> DispatchResult {
+        ensure_root(origin)?;
+
+        if authorized {
+            SubnetAttestationOracles::<T>::insert(oracle, true);
+        } else {
+            SubnetAttestationOracles::<T>::remove(oracle);
+        }
+        Self::deposit_event(Event::SubnetAttestationOracleSet { oracle, authorized });
+        Ok(())
+    }
+
+    /// Records the attestation of `payload` on its subnet.
+    pub fn do_submit_subnet_attestation(
+        origin: T::RuntimeOrigin,
+        payload: SubnetAttestationPayload,
+        signature: Signature,
+    ) -> DispatchResult {
+        ensure_none(origin)?;
+        Self::check_subnet_attestation(&payload, &signature)?;
+
+        let netuid = payload.netuid;
+        let attestation = SubnetAttestation {
+            oracle: payload.oracle,
+            kind: payload.kind,
+            block_number: Self::get_current_block_as_u64(),
+        };
+        SubnetAttestations::<T>::mutate(netuid, |attestations| {
+            if attestations.is_full() {
+                attestations.remove(0);
+            }
+            let _ = attestations.try_push(attestation.clone());
+        });
+        Self::deposit_event(Event::SubnetAttested {
+            netuid,
+            oracle: attestation.oracle,
+            kind: attestation.kind,
+        });
+        Ok(())
+    }
+
+    /// Checks that `payload` is signed by an authorized oracle, recent, and not attested yet.
+    pub fn check_subnet_attestation(
+        payload: &SubnetAttestationPayload,
+        signature: &Signature,
+    ) -> Result<(), Error<T>> {
+        ensure!(
+            SubnetAttestationOracles::<T>::get(payload.oracle),
+            Error::<T>::SubnetAttestationOracleNotAuthorized
+        );
+        ensure!(
+            sp_io::crypto::sr25519_verify(signature, &payload.encode(), &payload.oracle),
+            Error::<T>::InvalidSubnetAttestationSignature
+        );
+        let current_block = Self::get_current_block_as_u64();
+        ensure!(
+            payload.block_number <= current_block
+                && current_block.saturating_sub(payload.block_number) <= MAX_SUBNET_ATTESTATION_AGE,
+            Error::<T>::StaleSubnetAttestation
+        );
+        ensure!(
+            Self::if_subnet_exist(payload.netuid),
+            Error::<T>::SubnetNotExists
+        );
+        ensure!(
+            !SubnetAttestations::<T>::get(payload.netuid)
+                .iter()
+                .any(|attestation| {
+                    attestation.oracle == payload.oracle && attestation.kind == payload.kind
+                }),
+            Error::<T>::DuplicateSubnetAttestation
+        );
+        Ok(())
+    }
+
+    /// Transaction pool validity of an unsigned `submit_subnet_attestation`.
+    pub fn validate_subnet_attestation(
+        payload: &SubnetAttestationPayload,
+        signature: &Signature,
+    ) -> TransactionValidity {
+        if let Err(error) = Self::check_subnet_attestation(payload, signature) {
+            return match error {
+                Error::<T>::SubnetAttestationOracleNotAuthorized => InvalidTransaction::BadSigner,
+                Error::<T>::InvalidSubnetAttestationSignature => InvalidTransaction::BadProof,
+                Error::<T>::StaleSubnetAttestation => InvalidTransaction::Stale,
+                _ => InvalidTransaction::Call,
+            }
+            .into();
+        }
+
+        ValidTransaction::with_tag_prefix("SubnetAttestation")
+            .and_provides(payload.encode())
+            .longevity(MAX_SUBNET_ATTESTATION_AGE)
+            .propagate(true)
+            .build()
+    }
+
+    /// The attestations recorded on `netuid`, oldest first.
+    pub fn get_subnet_attestations(netuid: NetUid) -> Vec<SubnetAttestation> {
+        SubnetAttestations::<T>::get(netuid).into_inner()
+    }
+}
@@ -0,0 +1,88 @@
+//! Subnet hotkey blacklists.
+//!
+//! A subnet owner can blacklist a hotkey from registering and from setting, committing or
+//! revealing weights on their subnet until an expiry block. The blacklist of a subnet is bounded
+//! and expired entries are pruned whenever the owner adds one. A blacklisted hotkey can appeal to
+//! governance, which strikes the entry through the root origin. The owner can lift an entry the
+//! same way.
+
+use super::*;
+
+/// Maximum number of hotkeys blacklisted on a subnet at any time.
+pub const MAX_BLACKLISTED_HOTKEYS: usize = 256;
+
+/// Maximum duration of a blacklisting, about 30 days.
+pub const MAX_HOTKEY_BLACKLIST_DURATION: u64 = 216_000;
+
+impl<T: Config> Pallet<T> {
+    pub fn do_blacklist_hotkey(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        hotkey: T::AccountId,
+        duration: u64,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner(origin, netuid)?;
+        ensure!(
+            (1..=MAX_HOTKEY_BLACKLIST_DURATION).contains(&duration),
+            Error::<T>::InvalidBlacklistDuration
+        );
+
+        let current_block = Self::get_current_block_as_u64();
+        let expired: Vec<T::AccountId> = HotkeyBlacklist::<T>::iter_prefix(netuid)
+            .filter(|(_, expires_at)| *expires_at <= current_block)
+            .map(|(hotkey, _)| hotkey)
+            .collect();
+        for expired_hotkey in expired {
+            HotkeyBlacklist::<T>::remove(netuid, expired_hotkey);
+        }
+        ensure!(
+            HotkeyBlacklist::<T>::contains_key(netuid, &hotkey)
+                || HotkeyBlacklist::<T>::iter_key_prefix(netuid).count() < MAX_BLACKLISTED_HOTKEYS,
+            Error::<T>::TooManyBlacklistedHotkeys
+        );
+
+        let expires_at = current_block.saturating_add(duration);
+        HotkeyBlacklist::<T>::insert(netuid, &hotkey, expires_at);
+        Self::deposit_event(Event::HotkeyBlacklisted {
+            netuid,
+            hotkey,
+            expires_at,
+        });
+        Ok(())
+    }
+
+    /// Removes `hotkey` from the blacklist of `netuid`, on appeal to governance or by the owner.
+    pub fn do_strike_blacklisted_hotkey(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        hotkey: T::AccountId,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner_or_root(origin, netuid)?;
+        ensure!(
+            HotkeyBlacklist::<T>::contains_key(netuid, &hotkey),
+            Error::<T>::HotkeyNotBlacklisted
+        );
+
+        HotkeyBlacklist::<T>::remove(netuid, &hotkey);
+        Self::deposit_event(Event::BlacklistedHotkeyStruck { netuid, hotkey });
+        Ok(())
+    }
+
+    pub fn is_hotkey_blacklisted(netuid: NetUid, hotkey: &T::AccountId) -> bool {
+        HotkeyBlacklist::<T>::get(netuid, hotkey)
+            .is_some_and(|expires_at| Self::get_current_block_as_u64() < expires_at)
+    }
+
+    pub fn ensure_hotkey_not_blacklisted(netuid: NetUid, hotkey: &T::AccountId) -> DispatchResult {
+        ensure!(
+            !Self::is_hotkey_blacklisted(netuid, hotkey),
+            Error::<T>::HotkeyBlacklisted
+        );
+        Ok(())
+    }
+
+    /// Removes the hotkey blacklist of `netuid`.
+    pub fn clear_hotkey_blacklist(netuid: NetUid) {
+        let _ = HotkeyBlacklist::<T>::clear_prefix(netuid, u32::MAX, None);
+    }
+}
@@ -0,0 +1,69 @@
+//! Batch registration of the initial neurons of a subnet.
+//!
+//! A new subnet needs a set of neurons before its first epoch means anything, and seeding it
+//! through `burned_register` takes many rounds against the registrations per block and interval
+//! limits. Until the subnet starts emitting, its owner can register hotkey and coldkey pairs in
+//! one call instead, up to `MAX_BOOTSTRAP_NEURONS` neurons on the subnet. Bootstrapped neurons do
+//! not burn and do not count against the registration limits.
+
+use super::*;
+use subtensor_runtime_common::NetUid;
+
+/// The number of neurons up to which the owner of a subnet can bootstrap it.
+pub const MAX_BOOTSTRAP_NEURONS: u16 = 64;
+
+impl<T: Config> Pallet<T> {
+    /// Registers the `(hotkey, coldkey)` pairs of `neurons` on `netuid`, for its owner before its
+    /// first emission.
+    pub fn do_bootstrap_register(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        neurons: Vec<(T::AccountId, T::AccountId)>,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner(origin, netuid)?;
+        ensure!(
+            !netuid.is_root(),
+            Error::<T>::RegistrationNotPermittedOnRootSubnet
+        );
+        ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
+
+        // The subnet can only be bootstrapped before it starts emitting and while small.
+        let subnetwork_n = Self::get_subnetwork_n(netuid);
+        ensure!(
+            FirstEmissionBlockNumber::<T>::get(netuid).is_none()
+                && subnetwork_n < MAX_BOOTSTRAP_NEURONS,
+            Error::<T>::SubnetBootstrapClosed
+        );
+        let bootstrapped_n = u16::try_from(neurons.len())
+            .ok()
+            .and_then(|len| subnetwork_n.checked_add(len))
+            .ok_or(Error::<T>::TooManyBootstrapNeurons)?;
+        ensure!(
+            bootstrapped_n <= MAX_BOOTSTRAP_NEURONS
+                && bootstrapped_n <= Self::get_max_allowed_uids(netuid),
+            Error::<T>::TooManyBootstrapNeurons
+        );
+
+        for (hotkey, coldkey) in neurons {
+            ensure!(
+                !Uids::<T>::contains_key(netuid, &hotkey),
+                Error::<T>::HotKeyAlreadyRegisteredInSubNet
+            );
+            Self::ensure_not_reserved_account(&hotkey)?;
+            Self::create_account_if_non_existent(&coldkey, &hotkey);
+            ensure!(
+                Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+                Error::<T>::NonAssociatedColdKey
+            );
+
+            let neuron_uid = Self::register_neuron(netuid, &hotkey);
+            log::debug!(
+                "NeuronRegistered( netuid:{netuid:?} uid:{neuron_uid:?} hotkey:{hotkey:?} ) "
+            );
+            Self::deposit_neuron_registered_log(netuid, neuron_uid, &hotkey);
+            Self::deposit_event(Event::NeuronRegistered(netuid, neuron_uid, hotkey));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+//! Registration churn control.
+//!
+//! Deregistering a hotkey and re-registering a fresh one lets a coldkey cycle UIDs and farm
+//! immunity periods. When a coldkey burn-registers on a subnet within the churn window of one of
+//! its hotkeys being deregistered there, the re-registration counts as churn. Once the churn
+//! within a window exceeds the subnet's threshold, the burn that coldkey pays on the subnet
+//! doubles. Each further churned registration doubles it again, and every elapsed window without
+//! escalation halves it back.
+
+use super::*;
+
+/// Maximum burn escalation exponent, a 256x burn.
+pub const MAX_CHURN_BURN_EXPONENT: u8 = 8;
+
+impl<T: Config> Pallet<T> {
+    /// The burn `coldkey` pays to register on `netuid`, including churn escalation.
+    pub fn get_burn_for_coldkey(netuid: NetUid, coldkey: &T::AccountId) -> TaoCurrency {
+        let burn = Self::get_burn(netuid);
+        let exponent = Self::get_churn_burn_exponent(netuid, coldkey);
+        if exponent == 0 {
+            return burn;
+        }
+        u64::from(burn)
+            .saturating_mul(Self::churn_burn_multiplier(exponent))
+            .into()
+    }
+
+    /// The burn escalation exponent of `coldkey` on `netuid` after decay.
+    pub fn get_churn_burn_exponent(netuid: NetUid, coldkey: &T::AccountId) -> u8 {
+        let Some((exponent, escalated_at)) = ChurnBurnEscalation::<T>::get(netuid, coldkey) else {
+            return 0;
+        };
+        let window = ChurnBurnWindow::<T>::get(netuid).max(1);
+        let elapsed = Self::get_current_block_as_u64().saturating_sub(escalated_at);
+        let decay = elapsed.checked_div(window).unwrap_or_default();
+        exponent.saturating_sub(u8::try_from(decay).unwrap_or(u8::MAX))
+    }
+
+    fn churn_burn_multiplier(exponent: u8) -> u64 {
+        2_u64.saturating_pow(exponent.into())
+    }
+
+    /// Remembers that a hotkey of `hotkey`'s owner has been deregistered from `netuid`.
+    pub fn record_churn_deregistration(netuid: NetUid, hotkey: &T::AccountId) {
+        if ChurnBurnThreshold::<T>::get(netuid) == 0 {
+            return;
+        }
+        if let Ok(coldkey) = Owner::<T>::try_get(hotkey) {
+            ColdkeyLastDeregistration::<T>::insert(
+                netuid,
+                coldkey,
+                Self::get_current_block_as_u64(),
+            );
+        }
+    }
+
+    /// Counts a burned registration of `coldkey` on `netuid` towards its churn and escalates the
+    /// burn once the churn exceeds the threshold.
+    pub fn record_churn_registration(netuid: NetUid, coldkey: &T::AccountId) {
+        let threshold = ChurnBurnThreshold::<T>::get(netuid);
+        if threshold == 0 {
+            return;
+        }
+        let Some(deregistered_at) = ColdkeyLastDeregistration::<T>::get(netuid, coldkey) else {
+            return;
+        };
+        let current_block = Self::get_current_block_as_u64();
+        let window = ChurnBurnWindow::<T>::get(netuid);
+        if current_block.saturating_sub(deregistered_at) > window {
+            ColdkeyLastDeregistration::<T>::remove(netuid, coldkey);
+            ColdkeyChurn::<T>::remove(netuid, coldkey);
+            return;
+        }
+
+        let (mut window_start, mut count) = ColdkeyChurn::<T>::get(netuid, coldkey);
+        if current_block.saturating_sub(window_start) > window {
+            window_start = current_block;
+            count = 0;
+        }
+        count = count.saturating_add(1);
+        ColdkeyChurn::<T>::insert(netuid, coldkey, (window_start, count));
+
+        if count > threshold {
+            let exponent = Self::get_churn_burn_exponent(netuid, coldkey)
+                .saturating_add(1)
+                .min(MAX_CHURN_BURN_EXPONENT);
+            ChurnBurnEscalation::<T>::insert(netuid, coldkey, (exponent, current_block));
+
+            Self::deposit_event(Event::ChurnBurnEscalated {
+                netuid,
+                coldkey: coldkey.clone(),
+                multiplier: Self::churn_burn_multiplier(exponent),
+            });
+        }
+    }
+
+    pub fn set_churn_burn_params(netuid: NetUid, threshold: u16, window: u64) {
+        ChurnBurnThreshold::<T>::insert(netuid, threshold);
+        ChurnBurnWindow::<T>::insert(netuid, window);
+        Self::deposit_event(Event::ChurnBurnParamsSet {
+            netuid,
+            threshold,
+            window,
+        });
+    }
+
+    /// Removes the churn state of every coldkey on `netuid`.
+    pub fn clear_churn_state(netuid: NetUid) {
+        ChurnBurnThreshold::<T>::remove(netuid);
+        ChurnBurnWindow::<T>::remove(netuid);
+        let _ = ColdkeyLastDeregistration::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = ColdkeyChurn::<T>::clear_prefix(netuid, u32::MAX, None);
+        let _ = ChurnBurnEscalation::<T>::clear_prefix(netuid, u32::MAX, None);
+    }
+}
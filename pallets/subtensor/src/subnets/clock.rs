@@ -0,0 +1,84 @@
+//! Subnet clocks.
+//!
+//! The tempo of a subnet is counted in blocks, so a change of the block time changes how often
+//! the subnet runs its epoch and pays out. A subnet can instead have its tempo set in seconds,
+//! which is converted to blocks with the slot duration of the chain and written to `Tempo`, where
+//! the coinbase and the epoch read it. The conversion is redone as soon as the slot duration
+//! changes, so these subnets keep their cadence across block time changes.
+
+use super::*;
+use subtensor_runtime_common::NetUid;
+
+/// The tempo of a subnet in blocks and in seconds.
+#[freeze_struct("afff9e8fde3643a7")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct SubnetClock {
+    /// Tempo in blocks, as the epoch runs it.
+    pub tempo_blocks: u16,
+    /// Tempo in seconds at the current slot duration.
+    pub tempo_seconds: u64,
+    /// Target tempo in seconds, `None` if the tempo is set in blocks.
+    pub target_tempo_seconds: Option<u64>,
+    /// Slot duration of the chain in milliseconds.
+    pub slot_duration: u64,
+}
+
+impl<T: Config> Pallet<T> {
+    /// The number of blocks closest to `seconds` at the current slot duration, at least one.
+    pub fn tempo_seconds_to_blocks(seconds: u64) -> u16 {
+        let slot_duration = T::SlotDuration::get().max(1);
+        let blocks = seconds
+            .saturating_mul(1_000)
+            .saturating_add(slot_duration.saturating_div(2))
+            .saturating_div(slot_duration);
+        u16::try_from(blocks.max(1)).unwrap_or(u16::MAX)
+    }
+
+    /// The duration of `blocks` in seconds at the current slot duration.
+    pub fn tempo_blocks_to_seconds(blocks: u16) -> u64 {
+        u64::from(blocks)
+            .saturating_mul(T::SlotDuration::get())
+            .saturating_div(1_000)
+    }
+
+    /// Sets the tempo of `netuid` in seconds, or leaves it in blocks with `None`.
+    pub fn set_tempo_seconds(netuid: NetUid, seconds: Option<u64>) -> Result<(), Error<T>> {
+        match seconds {
+            Some(seconds) => {
+                ensure!(seconds > 0, Error::<T>::InvalidTempoSeconds);
+                TempoSeconds::<T>::insert(netuid, seconds);
+                Self::set_tempo(netuid, Self::tempo_seconds_to_blocks(seconds));
+            }
+            None => TempoSeconds::<T>::remove(netuid),
+        }
+        Self::deposit_event(Event::TempoSecondsSet(netuid, seconds));
+        Ok(())
+    }
+
+    /// Converts the tempos set in seconds again once the slot duration has changed.
+    pub fn sync_subnet_clocks() {
+        let slot_duration = T::SlotDuration::get();
+        if TempoSlotDuration::<T>::get() == slot_duration {
+            return;
+        }
+        for (netuid, seconds) in TempoSeconds::<T>::iter() {
+            Self::set_tempo(netuid, Self::tempo_seconds_to_blocks(seconds));
+        }
+        TempoSlotDuration::<T>::put(slot_duration);
+    }
+
+    /// The clock of `netuid`, `None` if the subnet does not exist.
+    pub fn get_subnet_clock(netuid: NetUid) -> Option<SubnetClock> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        let tempo_blocks = Self::get_tempo(netuid);
+        Some(SubnetClock {
+            tempo_blocks,
+            tempo_seconds: Self::tempo_blocks_to_seconds(tempo_blocks),
+            target_tempo_seconds: TempoSeconds::<T>::get(netuid),
+            slot_duration: T::SlotDuration::get(),
+        })
+    }
+}
@@ -0,0 +1,126 @@
+//! Subnet feature flags.
+//!
+//! The capabilities of a subnet are exposed as a single bitmask. The mask is composed from the
+//! underlying per-subnet toggles on every read, so it cannot drift from the values the runtime
+//! acts on, and is written through `do_set_subnet_features`, which validates the whole mask and
+//! updates every toggle at once.
+//!
+//! | Bit | Feature                                   |
+//! |-----|-------------------------------------------|
+//! | 0   | Commit-reveal of weights required         |
+//! | 1   | Liquid alpha enabled                      |
+//! | 2   | Stake transfers enabled                   |
+//! | 3   | User liquidity enabled                    |
+//! | 4-5 | Registration mode, see `REGISTRATION_*`   |
+
+use super::*;
+use subtensor_runtime_common::NetUid;
+use subtensor_swap_interface::SwapHandler;
+
+pub type SubnetFeatures = u16;
+
+pub const FEATURE_COMMIT_REVEAL: SubnetFeatures = 1 << 0;
+pub const FEATURE_LIQUID_ALPHA: SubnetFeatures = 1 << 1;
+pub const FEATURE_TRANSFERS: SubnetFeatures = 1 << 2;
+pub const FEATURE_USER_LIQUIDITY: SubnetFeatures = 1 << 3;
+
+/// Mask of the registration mode field.
+pub const FEATURE_REGISTRATION_MODE: SubnetFeatures = 0b11 << 4;
+/// Registration is closed.
+pub const REGISTRATION_CLOSED: SubnetFeatures = 0b00 << 4;
+/// Burned registration only.
+pub const REGISTRATION_BURN: SubnetFeatures = 0b01 << 4;
+/// Proof-of-work registration only.
+pub const REGISTRATION_POW: SubnetFeatures = 0b10 << 4;
+/// Burned and proof-of-work registration.
+pub const REGISTRATION_BURN_AND_POW: SubnetFeatures = 0b11 << 4;
+
+/// Every defined feature bit.
+pub const FEATURE_ALL: SubnetFeatures = FEATURE_COMMIT_REVEAL
+    | FEATURE_LIQUID_ALPHA
+    | FEATURE_TRANSFERS
+    | FEATURE_USER_LIQUIDITY
+    | FEATURE_REGISTRATION_MODE;
+
+impl<T: Config> Pallet<T> {
+    /// The feature bitmask of `netuid`, `None` if the subnet does not exist.
+    pub fn get_subnet_features(netuid: NetUid) -> Option<SubnetFeatures> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        let flags = [
+            (
+                FEATURE_COMMIT_REVEAL,
+                Self::get_commit_reveal_weights_enabled(netuid),
+            ),
+            (FEATURE_LIQUID_ALPHA, Self::get_liquid_alpha_enabled(netuid)),
+            (FEATURE_TRANSFERS, Self::get_transfer_toggle(netuid)),
+            (
+                FEATURE_USER_LIQUIDITY,
+                T::SwapInterface::is_user_liquidity_enabled(netuid),
+            ),
+            (
+                REGISTRATION_BURN,
+                Self::get_network_registration_allowed(netuid),
+            ),
+            (
+                REGISTRATION_POW,
+                Self::get_network_pow_registration_allowed(netuid),
+            ),
+        ];
+        Some(
+            flags
+                .into_iter()
+                .filter(|(_, enabled)| *enabled)
+                .fold(0, |features, (bit, _)| features | bit),
+        )
+    }
+
+    /// Sets every feature toggle of `netuid` from `features`.
+    ///
+    /// Opening or closing burned registration and disabling user liquidity are reserved to root,
+    /// as with their individual setters.
+    pub fn do_set_subnet_features(
+        netuid: NetUid,
+        features: SubnetFeatures,
+        by_root: bool,
+    ) -> DispatchResult {
+        ensure!(
+            features & !FEATURE_ALL == 0,
+            Error::<T>::InvalidSubnetFeatures
+        );
+        let current = Self::get_subnet_features(netuid).ok_or(Error::<T>::SubnetNotExists)?;
+
+        let enabled = |bit: SubnetFeatures| features & bit != 0;
+        let changed = |bit: SubnetFeatures| (features ^ current) & bit != 0;
+        ensure!(
+            by_root
+                || (!changed(REGISTRATION_BURN)
+                    && !(changed(FEATURE_USER_LIQUIDITY) && !enabled(FEATURE_USER_LIQUIDITY))),
+            DispatchError::BadOrigin
+        );
+
+        if changed(FEATURE_COMMIT_REVEAL) {
+            Self::set_commit_reveal_weights_enabled(netuid, enabled(FEATURE_COMMIT_REVEAL));
+        }
+        if changed(FEATURE_LIQUID_ALPHA) {
+            Self::set_liquid_alpha_enabled(netuid, enabled(FEATURE_LIQUID_ALPHA));
+        }
+        if changed(FEATURE_TRANSFERS) {
+            Self::toggle_transfer(netuid, enabled(FEATURE_TRANSFERS))?;
+        }
+        if changed(FEATURE_USER_LIQUIDITY) {
+            T::SwapInterface::toggle_user_liquidity(netuid, enabled(FEATURE_USER_LIQUIDITY));
+        }
+        if changed(REGISTRATION_BURN) {
+            Self::set_network_registration_allowed(netuid, enabled(REGISTRATION_BURN));
+        }
+        if changed(REGISTRATION_POW) {
+            Self::set_network_pow_registration_allowed(netuid, enabled(REGISTRATION_POW));
+        }
+
+        Self::deposit_event(Event::SubnetFeaturesSet { netuid, features });
+        Ok(())
+    }
+}
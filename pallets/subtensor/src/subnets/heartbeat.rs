@@ -0,0 +1,56 @@
+//! Validator heartbeats.
+//!
+//! The epoch marks a neuron inactive when it has not updated its weights within the activity
+//! cutoff of the subnet. On commit-reveal subnets weights only land when they are revealed, so a
+//! validator that keeps committing can still look inactive in between. A validator with a permit
+//! can send a heartbeat instead, which counts as activity for the cutoff. Heartbeats do not touch
+//! `LastUpdate`, which also decides which weights are outdated for newly registered neurons.
+//!
+//! Heartbeats are only accepted on commit-reveal subnets, at most once per tempo.
+
+use super::*;
+use subtensor_runtime_common::NetUid;
+
+impl<T: Config> Pallet<T> {
+    /// Records a heartbeat of the validator hotkey `origin` on `netuid`.
+    pub fn do_heartbeat(origin: T::RuntimeOrigin, netuid: NetUid) -> DispatchResult {
+        let hotkey = ensure_signed(origin)?;
+        ensure!(
+            !netuid.is_root() && Self::if_subnet_exist(netuid),
+            Error::<T>::SubnetNotExists
+        );
+        ensure!(
+            Self::get_commit_reveal_weights_enabled(netuid),
+            Error::<T>::CommitRevealDisabled
+        );
+        let uid = Self::get_uid_for_net_and_hotkey(netuid, &hotkey)?;
+        ensure!(
+            Self::get_validator_permit_for_uid(netuid, uid),
+            Error::<T>::NeuronNoValidatorPermit
+        );
+
+        let current_block = Self::get_current_block_as_u64();
+        if let Some(last_heartbeat) = LastHeartbeat::<T>::get(netuid, uid) {
+            ensure!(
+                current_block.saturating_sub(last_heartbeat) >= u64::from(Self::get_tempo(netuid)),
+                Error::<T>::HeartbeatTooFast
+            );
+        }
+
+        LastHeartbeat::<T>::insert(netuid, uid, current_block);
+        Self::deposit_event(Event::ValidatorHeartbeat { netuid, hotkey });
+        Ok(())
+    }
+
+    /// The block of the last activity of every uid of `netuid`, the later of its weights update
+    /// in `last_update` and its last heartbeat.
+    pub fn get_last_activity(netuid: NetUid, last_update: &[u64]) -> Vec<u64> {
+        let mut last_activity = last_update.to_vec();
+        for (uid, heartbeat) in LastHeartbeat::<T>::iter_prefix(netuid) {
+            if let Some(activity) = last_activity.get_mut(uid as usize) {
+                *activity = (*activity).max(heartbeat);
+            }
+        }
+        last_activity
+    }
+}
@@ -0,0 +1,205 @@
+//! Hotkey leasing.
+//!
+//! The owner of a validator hotkey can lease its validation rights on a subnet to a renter
+//! coldkey for a fixed number of blocks, in exchange for a share of the take of the hotkey. The
+//! owner offers the lease and the renter accepts it with an operator hotkey of its own, which
+//! becomes the weight setter delegate of the hotkey on the subnet: for the term of the lease only
+//! the operator can set and commit weights for the hotkey, and the owner can neither revoke it nor
+//! authorize another operator.
+//!
+//! While the lease runs, `renter_share` of the take of the hotkey on the alpha dividends of the
+//! subnet is staked to the hotkey for the renter coldkey, the rest goes to the owner as usual. The
+//! lease expires on its own after its end block, at the next epoch of the subnet, which removes
+//! the operator of the renter.
+
+use super::*;
+use safe_math::*;
+use subtensor_runtime_common::{AlphaCurrency, NetUid};
+
+#[freeze_struct("6b8fceea123ebf3e")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct HotkeyLeaseOffer<AccountId> {
+    /// The coldkey the lease is offered to.
+    pub renter: AccountId,
+    /// The share of the take of the hotkey paid to the renter, in `u16::MAX` parts.
+    pub renter_share: u16,
+    /// The number of blocks the lease runs for once accepted.
+    pub duration: u64,
+}
+
+#[freeze_struct("12d73abfb9ff5d1")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct HotkeyLease<AccountId> {
+    /// The coldkey renting the hotkey.
+    pub renter: AccountId,
+    /// The hotkey of the renter setting weights for the hotkey.
+    pub operator: AccountId,
+    /// The share of the take of the hotkey paid to the renter, in `u16::MAX` parts.
+    pub renter_share: u16,
+    /// The block after which the lease expires.
+    pub end_block: u64,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Offers the validation rights of `hotkey` on `netuid` to `renter` for `duration` blocks,
+    /// for `renter_share` of the take of the hotkey. A new offer replaces the previous one.
+    pub fn do_offer_hotkey_lease(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        netuid: NetUid,
+        renter: T::AccountId,
+        renter_share: u16,
+        duration: u64,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        ensure!(!netuid.is_root(), Error::<T>::InvalidHotkeyLease);
+        ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+        ensure!(
+            Self::is_hotkey_registered_on_network(netuid, &hotkey),
+            Error::<T>::HotKeyNotRegisteredInSubNet
+        );
+        ensure!(
+            Self::get_active_hotkey_lease(netuid, &hotkey).is_none(),
+            Error::<T>::HotkeyLeased
+        );
+        ensure!(
+            duration > 0 && renter != coldkey,
+            Error::<T>::InvalidHotkeyLease
+        );
+
+        HotkeyLeaseOffers::<T>::insert(
+            netuid,
+            &hotkey,
+            HotkeyLeaseOffer {
+                renter: renter.clone(),
+                renter_share,
+                duration,
+            },
+        );
+        Self::deposit_event(Event::HotkeyLeaseOffered {
+            netuid,
+            hotkey,
+            renter,
+            renter_share,
+            duration,
+        });
+        Ok(())
+    }
+
+    /// Accepts, for the renter, the lease offered for `hotkey` on `netuid`, with `operator`
+    /// setting weights for the hotkey until the lease expires.
+    pub fn do_accept_hotkey_lease(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        netuid: NetUid,
+        operator: T::AccountId,
+    ) -> DispatchResult {
+        let renter = ensure_signed(origin)?;
+        let offer = HotkeyLeaseOffers::<T>::get(netuid, &hotkey)
+            .filter(|offer| offer.renter == renter)
+            .ok_or(Error::<T>::NoHotkeyLeaseOffer)?;
+        ensure!(
+            Self::get_active_hotkey_lease(netuid, &hotkey).is_none(),
+            Error::<T>::HotkeyLeased
+        );
+        ensure!(
+            operator != hotkey
+                && !Self::is_hotkey_registered_on_network(netuid, &operator)
+                && WeightSetterDelegate::<T>::get(netuid, &operator).is_none(),
+            Error::<T>::InvalidWeightSetterDelegate
+        );
+
+        let end_block = Self::get_current_block_as_u64().saturating_add(offer.duration);
+        HotkeyLeaseOffers::<T>::remove(netuid, &hotkey);
+        WeightSetterDelegate::<T>::insert(netuid, &operator, &hotkey);
+        HotkeyLeases::<T>::insert(
+            netuid,
+            &hotkey,
+            HotkeyLease {
+                renter: renter.clone(),
+                operator: operator.clone(),
+                renter_share: offer.renter_share,
+                end_block,
+            },
+        );
+        Self::deposit_event(Event::HotkeyLeaseStarted {
+            netuid,
+            hotkey,
+            renter,
+            operator,
+            end_block,
+        });
+        Ok(())
+    }
+
+    /// The lease of `hotkey` on `netuid`, if it has not expired.
+    pub fn get_active_hotkey_lease(
+        netuid: NetUid,
+        hotkey: &T::AccountId,
+    ) -> Option<HotkeyLease<T::AccountId>> {
+        HotkeyLeases::<T>::get(netuid, hotkey)
+            .filter(|lease| lease.end_block >= Self::get_current_block_as_u64())
+    }
+
+    /// Ensures no one but the operator of the lease of `hotkey` on `netuid`, if any, acts as `who`
+    /// for the hotkey.
+    pub fn ensure_not_leased_away(
+        netuid: NetUid,
+        hotkey: &T::AccountId,
+        who: &T::AccountId,
+    ) -> Result<(), Error<T>> {
+        ensure!(
+            Self::get_active_hotkey_lease(netuid, hotkey)
+                .is_none_or(|lease| lease.operator == *who),
+            Error::<T>::HotkeyLeased
+        );
+        Ok(())
+    }
+
+    /// Stakes the share of the renter of the lease of `hotkey` on `netuid` out of the take
+    /// `alpha_take` of the hotkey, and returns the rest of the take.
+    pub fn pay_hotkey_lease_share(
+        netuid: NetUid,
+        hotkey: &T::AccountId,
+        alpha_take: AlphaCurrency,
+    ) -> AlphaCurrency {
+        let Some(lease) = Self::get_active_hotkey_lease(netuid, hotkey) else {
+            return alpha_take;
+        };
+        let renter_take = u64::from(alpha_take)
+            .saturating_mul(u64::from(lease.renter_share))
+            .safe_div(u64::from(u16::MAX));
+        let renter_take = AlphaCurrency::from(renter_take);
+        Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            hotkey,
+            &lease.renter,
+            netuid,
+            renter_take,
+        );
+        alpha_take.saturating_sub(renter_take)
+    }
+
+    /// Removes the leases of `netuid` past their end block and the operators of their renters.
+    pub fn expire_hotkey_leases(netuid: NetUid) {
+        let current_block = Self::get_current_block_as_u64();
+        let expired: Vec<(T::AccountId, HotkeyLease<T::AccountId>)> =
+            HotkeyLeases::<T>::iter_prefix(netuid)
+                .filter(|(_, lease)| lease.end_block < current_block)
+                .collect();
+        for (hotkey, lease) in expired {
+            HotkeyLeases::<T>::remove(netuid, &hotkey);
+            if WeightSetterDelegate::<T>::get(netuid, &lease.operator).as_ref() == Some(&hotkey) {
+                WeightSetterDelegate::<T>::remove(netuid, &lease.operator);
+            }
+            Self::deposit_event(Event::HotkeyLeaseExpired {
+                netuid,
+                hotkey,
+                renter: lease.renter,
+            });
+        }
+    }
+}
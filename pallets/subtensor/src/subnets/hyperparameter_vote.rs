@@ -0,0 +1,337 @@
+//! Stakeholder voting on subnet hyperparameters.
+//!
+//! The owner of a subnet sets its hyperparameters unilaterally. A subnet can opt into voting with a
+//! voting period: the sensitive hyperparameters of the subnet, its tempo, its registration
+//! settings and the use of its owner cut, can then no longer be set by the owner directly. The
+//! owner proposes a change instead, which hotkeys with the stake to set weights on the subnet vote
+//! on until the end of the voting period, and which anyone can enact afterwards. The change is
+//! made if the alpha staked to the hotkeys that voted for it, counted at enactment, is above the
+//! alpha of those that voted against it. Root still sets every hyperparameter directly.
+//!
+//! A subnet has at most one change pending at a time, voted on by at most
+//! `MAX_HYPERPARAMETER_VOTERS` hotkeys, whose votes are stored per hotkey and tallied and cleared
+//! at enactment. The voting period of a subnet can only be lengthened, so that stakeholders can
+//! rely on it.
+
+use super::*;
+use crate::coinbase::owner_cut_vesting::MAX_OWNER_CUT_VESTING_BLOCKS;
+use codec::DecodeWithMemTracking;
+use sp_runtime::Percent;
+use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid};
+
+/// The maximum number of blocks a hyperparameter change is voted on.
+pub const MAX_HYPERPARAMETER_VOTING_PERIOD: u64 = 7200 * 30;
+
+/// The maximum number of hotkeys that vote on a hyperparameter change.
+pub const MAX_HYPERPARAMETER_VOTERS: u32 = 256;
+
+/// A change of a sensitive hyperparameter of a subnet, voted on by its stakeholders.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum HyperparameterChange {
+    Tempo(u16),
+    ImmunityPeriod(u16),
+    MaxAllowedUids(u16),
+    PowRegistrationAllowed(bool),
+    MinerEmissionShare(Percent),
+    OwnerCutVesting(u64),
+    SubnetTreasury(u16, u16),
+}
+
+impl HyperparameterChange {
+    /// The hyperparameter this change sets.
+    pub fn hyperparameter(&self) -> Hyperparameter {
+        match self {
+            Self::Tempo(_) => Hyperparameter::Tempo,
+            Self::ImmunityPeriod(_) => Hyperparameter::ImmunityPeriod,
+            Self::MaxAllowedUids(_) => Hyperparameter::MaxAllowedUids,
+            Self::PowRegistrationAllowed(_) => Hyperparameter::PowRegistrationAllowed,
+            Self::MinerEmissionShare(_) => Hyperparameter::MinerEmissionShare,
+            Self::OwnerCutVesting(_) => Hyperparameter::OwnerCutVesting,
+            Self::SubnetTreasury(_, _) => Hyperparameter::SubnetTreasury,
+        }
+    }
+}
+
+#[freeze_struct("5ad2b437e9e3384c")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct HyperparameterProposal {
+    /// The id of the proposal, unique on the subnet.
+    pub id: u32,
+    /// The change proposed.
+    pub change: HyperparameterChange,
+    /// The last block the change is voted on.
+    pub end_block: u64,
+    /// The number of hotkeys that voted on the change, in `HyperparameterVotes`.
+    pub voters: u32,
+}
+
+impl<T: Config> Pallet<T> {
+    /// The number of blocks changes of the sensitive hyperparameters of `netuid` are voted on,
+    /// zero if the subnet does not vote on them.
+    pub fn get_hyperparameter_voting_period(netuid: NetUid) -> u64 {
+        HyperparameterVotingPeriod::<T>::get(netuid)
+    }
+
+    /// Has changes of the sensitive hyperparameters of `netuid` voted on for `blocks`, which
+    /// cannot be shorter than the current voting period of the subnet.
+    pub fn set_hyperparameter_voting_period(netuid: NetUid, blocks: u64) -> Result<(), Error<T>> {
+        ensure!(
+            blocks >= Self::get_hyperparameter_voting_period(netuid),
+            Error::<T>::HyperparameterVotingPeriodTooShort
+        );
+        ensure!(
+            blocks <= MAX_HYPERPARAMETER_VOTING_PERIOD,
+            Error::<T>::HyperparameterVotingPeriodTooLong
+        );
+        HyperparameterVotingPeriod::<T>::insert(netuid, blocks);
+        Self::deposit_event(Event::HyperparameterVotingPeriodSet { netuid, blocks });
+        Ok(())
+    }
+
+    /// Whether `hyperparameter` of `netuid` is only changed by a vote of its stakeholders.
+    pub fn is_hyperparameter_voted(netuid: NetUid, hyperparameter: &Hyperparameter) -> bool {
+        Self::get_hyperparameter_voting_period(netuid) > 0
+            && matches!(
+                hyperparameter,
+                Hyperparameter::Tempo
+                    | Hyperparameter::ImmunityPeriod
+                    | Hyperparameter::MaxAllowedUids
+                    | Hyperparameter::PowRegistrationAllowed
+                    | Hyperparameter::MinerEmissionShare
+                    | Hyperparameter::OwnerCutVesting
+                    | Hyperparameter::SubnetTreasury
+            )
+    }
+
+    /// Proposes, for the owner of `netuid`, `change` to the vote of the stakeholders of the
+    /// subnet.
+    pub fn do_propose_hyperparameter_change(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        change: HyperparameterChange,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner(origin, netuid)?;
+        let voting_period = Self::get_hyperparameter_voting_period(netuid);
+        ensure!(voting_period > 0, Error::<T>::HyperparameterVotingDisabled);
+        ensure!(
+            !HyperparameterProposals::<T>::contains_key(netuid),
+            Error::<T>::HyperparameterProposalPending
+        );
+        Self::validate_hyperparameter_change(netuid, &change)?;
+
+        let id = NextHyperparameterProposalId::<T>::get(netuid);
+        NextHyperparameterProposalId::<T>::insert(netuid, id.saturating_add(1));
+        let end_block = Self::get_current_block_as_u64().saturating_add(voting_period);
+        HyperparameterProposals::<T>::insert(
+            netuid,
+            HyperparameterProposal {
+                id,
+                change: change.clone(),
+                end_block,
+                voters: 0,
+            },
+        );
+        Self::deposit_event(Event::HyperparameterChangeProposed {
+            netuid,
+            id,
+            change,
+            end_block,
+        });
+        Ok(())
+    }
+
+    /// Votes, for the signing hotkey, for or against the change `id` pending on `netuid`. A
+    /// hotkey can change its vote until the end of the voting period.
+    ///
+    /// Only hotkeys with the stake to set weights on the subnet vote, so that a dust stake does
+    /// not buy one of the `MAX_HYPERPARAMETER_VOTERS` votes.
+    pub fn do_vote_hyperparameter_change(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        id: u32,
+        aye: bool,
+    ) -> DispatchResult {
+        let hotkey = ensure_signed(origin)?;
+        let mut proposal = HyperparameterProposals::<T>::get(netuid)
+            .filter(|proposal| proposal.id == id)
+            .ok_or(Error::<T>::NoHyperparameterProposal)?;
+        ensure!(
+            Self::get_current_block_as_u64() <= proposal.end_block,
+            Error::<T>::HyperparameterVotingClosed
+        );
+        ensure!(
+            !Self::get_stake_for_hotkey_on_subnet(&hotkey, netuid).is_zero()
+                && Self::check_weights_min_stake(&hotkey, netuid),
+            Error::<T>::NotEnoughStakeToVote
+        );
+
+        let previous_vote = HyperparameterVotes::<T>::get(netuid, &hotkey);
+        ensure!(
+            previous_vote != Some(aye),
+            Error::<T>::HyperparameterAlreadyVoted
+        );
+        if previous_vote.is_none() {
+            ensure!(
+                proposal.voters < MAX_HYPERPARAMETER_VOTERS,
+                Error::<T>::TooManyHyperparameterVoters
+            );
+            proposal.voters = proposal.voters.saturating_add(1);
+            HyperparameterProposals::<T>::insert(netuid, proposal);
+        }
+        HyperparameterVotes::<T>::insert(netuid, &hotkey, aye);
+
+        Self::deposit_event(Event::HyperparameterChangeVoted {
+            netuid,
+            id,
+            hotkey,
+            aye,
+        });
+        Ok(())
+    }
+
+    /// The weight of enacting a change `voters` hotkeys voted on: reading and clearing the vote
+    /// and reading the alpha of every voter, on top of closing the vote and making the change.
+    pub fn enact_hyperparameter_change_weight(voters: u32) -> Weight {
+        Weight::from_parts(45_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(10))
+            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(
+                T::DbWeight::get()
+                    .reads_writes(2, 1)
+                    .saturating_mul(voters.into()),
+            )
+    }
+
+    /// Closes the vote on the change `id` pending on `netuid` once its voting period is over,
+    /// and makes the change if the alpha of the hotkeys that voted for it is above the alpha of
+    /// those that voted against it.
+    pub fn do_enact_hyperparameter_change(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        id: u32,
+    ) -> DispatchResultWithPostInfo {
+        ensure_signed(origin)?;
+        let proposal = HyperparameterProposals::<T>::get(netuid)
+            .filter(|proposal| proposal.id == id)
+            .ok_or(Error::<T>::NoHyperparameterProposal)?;
+        ensure!(
+            Self::get_current_block_as_u64() > proposal.end_block,
+            Error::<T>::HyperparameterVotingOpen
+        );
+
+        let mut ayes = AlphaCurrency::ZERO;
+        let mut nays = AlphaCurrency::ZERO;
+        for (hotkey, aye) in HyperparameterVotes::<T>::drain_prefix(netuid) {
+            let alpha = Self::get_stake_for_hotkey_on_subnet(&hotkey, netuid);
+            if aye {
+                ayes = ayes.saturating_add(alpha);
+            } else {
+                nays = nays.saturating_add(alpha);
+            }
+        }
+        HyperparameterProposals::<T>::remove(netuid);
+
+        // The change is validated again, the state it was validated against may have moved.
+        if ayes > nays && Self::validate_hyperparameter_change(netuid, &proposal.change).is_ok() {
+            Self::apply_hyperparameter_change(netuid, &proposal.change)?;
+            Self::deposit_event(Event::HyperparameterChangeEnacted {
+                netuid,
+                id,
+                ayes,
+                nays,
+            });
+        } else {
+            Self::deposit_event(Event::HyperparameterChangeRejected {
+                netuid,
+                id,
+                ayes,
+                nays,
+            });
+        }
+        Ok(Some(Self::enact_hyperparameter_change_weight(proposal.voters)).into())
+    }
+
+    pub fn get_hyperparameter_proposal(netuid: NetUid) -> Option<HyperparameterProposal> {
+        HyperparameterProposals::<T>::get(netuid)
+    }
+
+    fn validate_hyperparameter_change(
+        netuid: NetUid,
+        change: &HyperparameterChange,
+    ) -> DispatchResult {
+        match change {
+            HyperparameterChange::Tempo(tempo) => {
+                ensure!(*tempo > 0, Error::<T>::InvalidValue);
+            }
+            HyperparameterChange::MaxAllowedUids(max_allowed_uids) => {
+                ensure!(
+                    *max_allowed_uids >= Self::get_min_allowed_uids(netuid)
+                        && *max_allowed_uids >= Self::get_subnetwork_n(netuid)
+                        && *max_allowed_uids <= DefaultMaxAllowedUids::<T>::get(),
+                    Error::<T>::InvalidValue
+                );
+            }
+            HyperparameterChange::MinerEmissionShare(share) => {
+                let (min, max) = MinerEmissionShareBounds::<T>::get();
+                ensure!(
+                    min <= *share && *share <= max,
+                    Error::<T>::MinerEmissionShareOutOfBounds
+                );
+            }
+            HyperparameterChange::OwnerCutVesting(blocks) => {
+                ensure!(
+                    *blocks >= Self::get_owner_cut_vesting_blocks(netuid),
+                    Error::<T>::OwnerCutVestingTooShort
+                );
+                ensure!(
+                    *blocks <= MAX_OWNER_CUT_VESTING_BLOCKS,
+                    Error::<T>::OwnerCutVestingTooLong
+                );
+            }
+            HyperparameterChange::SubnetTreasury(_, approval_threshold) => {
+                ensure!(
+                    *approval_threshold >= Self::get_subnet_treasury_approval_threshold(netuid),
+                    Error::<T>::SubnetTreasuryApprovalLowered
+                );
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Makes `change` on `netuid`, depositing `HyperparameterChanged` as owner and root updates
+    /// do.
+    fn apply_hyperparameter_change(
+        netuid: NetUid,
+        change: &HyperparameterChange,
+    ) -> DispatchResult {
+        let before = Self::snapshot_hyperparameters(netuid, &[change.hyperparameter().into()]);
+        match change {
+            HyperparameterChange::Tempo(tempo) => {
+                // A tempo in blocks replaces a tempo set in seconds
+                Self::set_tempo_seconds(netuid, None)?;
+                Self::set_tempo(netuid, *tempo)
+            }
+            HyperparameterChange::ImmunityPeriod(immunity_period) => {
+                Self::set_immunity_period(netuid, *immunity_period)
+            }
+            HyperparameterChange::MaxAllowedUids(max_allowed_uids) => {
+                Self::set_max_allowed_uids(netuid, *max_allowed_uids)
+            }
+            HyperparameterChange::PowRegistrationAllowed(allowed) => {
+                Self::set_network_pow_registration_allowed(netuid, *allowed)
+            }
+            HyperparameterChange::MinerEmissionShare(share) => {
+                Self::set_miner_emission_share(netuid, *share)?
+            }
+            HyperparameterChange::OwnerCutVesting(blocks) => {
+                Self::set_owner_cut_vesting_blocks(netuid, *blocks)?
+            }
+            HyperparameterChange::SubnetTreasury(share, approval_threshold) => {
+                Self::set_subnet_treasury(netuid, *share, *approval_threshold)?
+            }
+        }
+        Self::deposit_hyperparameter_changes(netuid, before);
+        Ok(())
+    }
+}
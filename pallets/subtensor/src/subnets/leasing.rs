@@ -214,7 +214,7 @@ impl<T: Config> Pallet<T> {
             Self::coldkey_owns_hotkey(&lease.beneficiary, &hotkey),
             Error::<T>::BeneficiaryDoesNotOwnHotkey
         );
-        SubnetOwner::<T>::insert(lease.netuid, lease.beneficiary.clone());
+        Self::set_subnet_owner(lease.netuid, &lease.beneficiary);
         Self::set_subnet_owner_hotkey(lease.netuid, &hotkey);
 
         // Stop tracking the lease coldkey and hotkey
@@ -316,6 +316,7 @@ impl<T: Config> Pallet<T> {
             Ok(tao_unstaked) => tao_unstaked,
             Err(err) => {
                 log::debug!("Couldn't distributing dividends for lease {lease_id}: {err:?}");
+                Self::report_rejected_tao_debit(lease.netuid, &err);
                 AccumulatedLeaseDividends::<T>::set(lease_id, total_contributors_cut_alpha);
                 return;
             }
@@ -364,9 +365,7 @@ impl<T: Config> Pallet<T> {
     }
 
     fn find_lease_netuid(lease_coldkey: &T::AccountId) -> Option<NetUid> {
-        SubnetOwner::<T>::iter()
-            .find(|(_, coldkey)| coldkey == lease_coldkey)
-            .map(|(netuid, _)| netuid)
+        SubnetsOwnedBy::<T>::get(lease_coldkey).first().copied()
     }
 
     // Get the crowdloan being finalized from the crowdloan pallet when the call is executed,
@@ -0,0 +1,230 @@
+//! Merkle-committed sparse weights.
+//!
+//! On subnets with thousands of uids, weight vectors are the largest part of the extrinsics and
+//! proof of validity of a block. With merkle weights enabled, a validator commits the merkle root
+//! of its full weight vector and the number of its non-zero entries, then submits the non-zero
+//! entries with their proofs, in as many extrinsics as it takes. Every submission is checked
+//! against the root on its own, and once all entries are in, the weights are set as with
+//! `set_weights`, into the sparse rows the epoch reads.
+//!
+//! The tree has one leaf per uid of the subnet at commit time, zero weights included, padded with
+//! zero hashes to a power of two. A leaf is the `BlakeTwo256` hash of the SCALE encoded
+//! `(uid, weight)` pair, an inner node the hash of its `(left, right)` children, and a proof lists
+//! the sibling hashes from the leaf up. `merkle_weights_root` and `merkle_weights_proof` build
+//! them.
+//!
+//! Submitted entries are public right away, so merkle weights are only accepted on subnets without
+//! commit-reveal.
+
+use super::*;
+use frame_support::{BoundedVec, traits::ConstU32};
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+use subtensor_runtime_common::{MechId, NetUid};
+
+/// A non-zero weight entry submitted against a merkle weights commit: the uid, the weight and the
+/// proof of the leaf.
+pub type MerkleWeightEntry = (u16, u16, Vec<H256>);
+
+/// Most entries an upload can hold, one per uid of the subnet.
+pub const MAX_MERKLE_WEIGHT_ENTRIES: u32 = u16::MAX as u32;
+
+/// A merkle weights commit of a validator and the entries submitted for it so far.
+#[freeze_struct("ad7b038f03085703")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct MerkleWeightsUpload {
+    pub root: H256,
+    /// Number of leaves of the tree, the number of uids of the subnet at commit.
+    pub leaves: u16,
+    /// Number of non-zero entries of the committed weights.
+    pub entries: u16,
+    pub version_key: u64,
+    /// Entries submitted so far, sorted by uid.
+    pub received: BoundedVec<(u16, u16), ConstU32<MAX_MERKLE_WEIGHT_ENTRIES>>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Commits the merkle root of the weights of the calling hotkey, replacing any upload in
+    /// progress.
+    pub fn do_commit_merkle_weights(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        mecid: MechId,
+        root: H256,
+        entries: u16,
+        version_key: u64,
+    ) -> DispatchResult {
+        let hotkey = Self::ensure_weight_setter(origin, netuid)?;
+
+        ensure!(!netuid.is_root(), Error::<T>::CanNotSetRootNetworkWeights);
+        Self::ensure_mechanism_exists(netuid, mecid)?;
+        ensure!(
+            Self::get_merkle_weights_enabled(netuid),
+            Error::<T>::MerkleWeightsDisabled
+        );
+        ensure!(
+            !Self::get_commit_reveal_weights_enabled(netuid),
+            Error::<T>::CommitRevealEnabled
+        );
+        ensure!(
+            Self::is_hotkey_registered_on_network(netuid, &hotkey),
+            Error::<T>::HotKeyNotRegisteredInSubNet
+        );
+        let leaves = Self::get_subnetwork_n(netuid);
+        ensure!(
+            entries > 0 && entries <= leaves,
+            Error::<T>::InvalidMerkleWeightsEntries
+        );
+
+        // The upload ends in setting the weights, so commits are held to the same rate limit.
+        let netuid_index = Self::get_mechanism_storage_index(netuid, mecid);
+        let neuron_uid = Self::get_uid_for_net_and_hotkey(netuid, &hotkey)?;
+        ensure!(
+            Self::check_rate_limit(netuid_index, neuron_uid, Self::get_current_block_as_u64()),
+            Error::<T>::SettingWeightsTooFast
+        );
+
+        MerkleWeightsUploads::<T>::insert(
+            netuid_index,
+            &hotkey,
+            MerkleWeightsUpload {
+                root,
+                leaves,
+                entries,
+                version_key,
+                received: BoundedVec::new(),
+            },
+        );
+        Self::deposit_event(Event::MerkleWeightsCommitted {
+            hotkey,
+            netuid: netuid_index,
+            root,
+            entries,
+        });
+        Ok(())
+    }
+
+    /// Submits non-zero entries of the committed weights of the calling hotkey, and sets the
+    /// weights once all of them are in.
+    pub fn do_submit_merkle_weights(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        mecid: MechId,
+        entries: Vec<MerkleWeightEntry>,
+    ) -> DispatchResult {
+        let hotkey = Self::ensure_weight_setter(origin.clone(), netuid)?;
+        let netuid_index = Self::get_mechanism_storage_index(netuid, mecid);
+
+        let mut upload = MerkleWeightsUploads::<T>::get(netuid_index, &hotkey)
+            .ok_or(Error::<T>::NoMerkleWeightsCommit)?;
+        ensure!(
+            !entries.is_empty()
+                && upload.received.len().saturating_add(entries.len()) <= upload.entries as usize,
+            Error::<T>::InvalidMerkleWeightsEntries
+        );
+
+        for (uid, weight, proof) in entries {
+            ensure!(
+                weight > 0
+                    && Self::verify_merkle_weight(upload.root, upload.leaves, uid, weight, &proof),
+                Error::<T>::InvalidMerkleWeightProof
+            );
+            match upload.received.binary_search_by_key(&uid, |(uid, _)| *uid) {
+                Ok(_) => return Err(Error::<T>::DuplicateUids.into()),
+                Err(position) => upload
+                    .received
+                    .try_insert(position, (uid, weight))
+                    .map_err(|_| Error::<T>::InvalidMerkleWeightsEntries)?,
+            }
+        }
+
+        let remaining = upload.entries.saturating_sub(upload.received.len() as u16);
+        if remaining > 0 {
+            MerkleWeightsUploads::<T>::insert(netuid_index, &hotkey, upload);
+            Self::deposit_event(Event::MerkleWeightsReceived {
+                hotkey,
+                netuid: netuid_index,
+                remaining,
+            });
+            return Ok(());
+        }
+
+        MerkleWeightsUploads::<T>::remove(netuid_index, &hotkey);
+        let (uids, values) = upload.received.into_iter().unzip();
+        Self::do_set_mechanism_weights(origin, netuid, mecid, uids, values, upload.version_key)
+    }
+
+    /// The leaf of the weight of `uid`.
+    pub fn merkle_weights_leaf(uid: u16, weight: u16) -> H256 {
+        BlakeTwo256::hash_of(&(uid, weight))
+    }
+
+    /// The merkle root of `weights`, the weights of every uid by uid.
+    pub fn merkle_weights_root(weights: &[u16]) -> H256 {
+        let mut level = Self::merkle_weights_leaves(weights);
+        while level.len() > 1 {
+            level = Self::merkle_weights_parents(&level);
+        }
+        level.first().copied().unwrap_or_default()
+    }
+
+    /// The merkle proof of the weight of `uid` in `weights`.
+    pub fn merkle_weights_proof(weights: &[u16], uid: u16) -> Vec<H256> {
+        let mut level = Self::merkle_weights_leaves(weights);
+        let mut index = uid as usize;
+        let mut proof = Vec::new();
+        while level.len() > 1 {
+            proof.push(level.get(index ^ 1).copied().unwrap_or_default());
+            level = Self::merkle_weights_parents(&level);
+            index = index.saturating_div(2);
+        }
+        proof
+    }
+
+    /// Whether `proof` proves the weight of `uid` in a tree of `leaves` leaves with `root`.
+    pub fn verify_merkle_weight(
+        root: H256,
+        leaves: u16,
+        uid: u16,
+        weight: u16,
+        proof: &[H256],
+    ) -> bool {
+        let depth = u32::from(leaves).next_power_of_two().trailing_zeros() as usize;
+        if uid >= leaves || proof.len() != depth {
+            return false;
+        }
+
+        let mut node = Self::merkle_weights_leaf(uid, weight);
+        let mut index = uid;
+        for sibling in proof {
+            node = if index & 1 == 0 {
+                BlakeTwo256::hash_of(&(node, *sibling))
+            } else {
+                BlakeTwo256::hash_of(&(*sibling, node))
+            };
+            index = index.saturating_div(2);
+        }
+        node == root
+    }
+
+    fn merkle_weights_leaves(weights: &[u16]) -> Vec<H256> {
+        (0..weights.len().next_power_of_two())
+            .map(|uid| {
+                weights
+                    .get(uid)
+                    .map(|weight| Self::merkle_weights_leaf(uid as u16, *weight))
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    fn merkle_weights_parents(level: &[H256]) -> Vec<H256> {
+        level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => BlakeTwo256::hash_of(&(*left, *right)),
+                _ => H256::zero(),
+            })
+            .collect()
+    }
+}
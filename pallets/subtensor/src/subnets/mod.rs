@@ -1,9 +1,31 @@
 use super::*;
+pub mod attestations;
+pub mod blacklist;
+pub mod bootstrap;
+pub mod churn;
+pub mod clock;
+pub mod features;
+pub mod heartbeat;
+pub mod hotkey_lease;
+pub mod hyperparameter_vote;
 pub mod leasing;
 pub mod mechanism;
+pub mod merkle_weights;
+pub mod neuron_status;
 pub mod registration;
+pub mod registration_access;
+pub mod registration_auction;
+pub mod registration_deposit;
+pub mod registration_queue;
+pub mod registration_quota;
 pub mod serving;
+pub mod settlement;
+pub mod staged_hyperparams;
 pub mod subnet;
 pub mod symbols;
+pub mod token_accounting;
+pub mod treasury;
 pub mod uids;
+pub mod weight_row;
+pub mod weight_setter;
 pub mod weights;
@@ -0,0 +1,113 @@
+//! Neuron lifecycle.
+//!
+//! Every uid of a subnet has a status, kept in `NeuronStatuses` next to the other per-uid vectors.
+//! A registered neuron starts out `Immune`, or `Active` without an immunity period. Every epoch
+//! recomputes the statuses: neurons within their immunity period are `Immune`, the others are
+//! `Active` or `Inactive` by the activity cutoff, and on a full subnet the neuron the next
+//! registration would replace is `PendingPrune`. A neuron that is replaced, trimmed or deregisters
+//! is `Pruned`, which only shows in the `NeuronStatusChanged` event as its uid is reused or removed
+//! at once.
+
+use super::*;
+use subtensor_runtime_common::NetUid;
+
+/// The status of a neuron in its lifecycle.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Default, Clone, Copy, PartialEq, Eq, Debug, TypeInfo,
+)]
+pub enum NeuronStatus {
+    /// Within its immunity period, it is only pruned if every other neuron is immune.
+    #[default]
+    Immune,
+    /// Set weights or sent a heartbeat within the activity cutoff.
+    Active,
+    /// Has not set weights within the activity cutoff.
+    Inactive,
+    /// Replaced by the next registration on the subnet.
+    PendingPrune,
+    /// Deregistered from the subnet.
+    Pruned,
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn get_neuron_status_for_uid(netuid: NetUid, uid: u16) -> NeuronStatus {
+        NeuronStatuses::<T>::get(netuid)
+            .get(uid as usize)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// The status of a neuron just registered to `uid` of `netuid`.
+    pub fn get_registered_neuron_status(netuid: NetUid, uid: u16) -> NeuronStatus {
+        if Self::get_neuron_is_immune(netuid, uid) {
+            NeuronStatus::Immune
+        } else {
+            NeuronStatus::Active
+        }
+    }
+
+    /// Moves the neuron `hotkey` under `uid` of `netuid` to `status`, with an event if it changed.
+    pub fn set_neuron_status_for_uid(
+        netuid: NetUid,
+        uid: u16,
+        hotkey: &T::AccountId,
+        status: NeuronStatus,
+    ) {
+        let mut statuses = NeuronStatuses::<T>::get(netuid);
+        let Some(old_status) = statuses.get_mut(uid as usize) else {
+            return;
+        };
+        if *old_status == status {
+            return;
+        }
+        Self::deposit_event(Event::NeuronStatusChanged {
+            netuid,
+            uid,
+            hotkey: hotkey.clone(),
+            from: *old_status,
+            to: status,
+        });
+        *old_status = status;
+        NeuronStatuses::<T>::insert(netuid, statuses);
+    }
+
+    /// Recomputes the status of every uid of `netuid` from its `active` mask of the epoch, its
+    /// immunity and the pruning order, and deposits an event for every status that changed.
+    pub fn update_neuron_statuses(netuid: NetUid, active: &[bool]) {
+        let n = Self::get_subnetwork_n(netuid);
+        let pending_prune = if n >= Self::get_max_allowed_uids(netuid) {
+            Self::find_neuron_to_prune(netuid)
+        } else {
+            None
+        };
+
+        let old_statuses = NeuronStatuses::<T>::get(netuid);
+        let statuses: Vec<NeuronStatus> = (0..n)
+            .map(|uid| {
+                if pending_prune == Some(uid) {
+                    NeuronStatus::PendingPrune
+                } else if Self::get_neuron_is_immune(netuid, uid) {
+                    NeuronStatus::Immune
+                } else if active.get(uid as usize).copied().unwrap_or_default() {
+                    NeuronStatus::Active
+                } else {
+                    NeuronStatus::Inactive
+                }
+            })
+            .collect();
+
+        for (uid, (old_status, status)) in old_statuses.iter().zip(statuses.iter()).enumerate() {
+            if old_status != status {
+                let uid = uid as u16;
+                Self::deposit_event(Event::NeuronStatusChanged {
+                    netuid,
+                    uid,
+                    hotkey: Keys::<T>::get(netuid, uid),
+                    from: *old_status,
+                    to: *status,
+                });
+            }
+        }
+        NeuronStatuses::<T>::insert(netuid, statuses);
+    }
+}
@@ -2,7 +2,7 @@ use super::*;
 use sp_core::{H256, U256};
 use sp_io::hashing::{keccak_256, sha2_256};
 use sp_runtime::Saturating;
-use subtensor_runtime_common::{Currency, NetUid};
+use subtensor_runtime_common::{Currency, NetUid, TaoCurrency};
 use subtensor_swap_interface::SwapHandler;
 use system::pallet_prelude::BlockNumberFor;
 
@@ -61,6 +61,9 @@ impl<T: Config> Pallet<T> {
     /// * 'TooManyRegistrationsThisBlock':
     ///     - This registration exceeds the total allowed on this network this block.
     ///
+    /// * 'TooManyColdkeyRegistrationsThisInterval':
+    ///     - The coldkey has used its registrations on this network this interval.
+    ///
     /// * 'HotKeyAlreadyRegisteredInSubNet':
     ///     - The hotkey is already registered on this network.
     ///
@@ -85,6 +88,12 @@ impl<T: Config> Pallet<T> {
             Self::get_network_registration_allowed(netuid),
             Error::<T>::SubNetRegistrationDisabled
         );
+        ensure!(
+            !Self::get_burn_auction_enabled(netuid),
+            Error::<T>::RegistrationByAuctionOnly
+        );
+        Self::ensure_hotkey_not_blacklisted(netuid, &hotkey)?;
+        Self::ensure_registration_permitted(netuid, &hotkey, &coldkey)?;
 
         // --- 4. Ensure we are not exceeding the max allowed registrations per block.
         ensure!(
@@ -99,15 +108,17 @@ impl<T: Config> Pallet<T> {
                 < Self::get_target_registrations_per_interval(netuid).saturating_mul(3),
             Error::<T>::TooManyRegistrationsThisInterval
         );
+        Self::ensure_coldkey_registration_quota(netuid, &coldkey)?;
 
         // --- 4. Ensure that the key is not already registered.
         ensure!(
             !Uids::<T>::contains_key(netuid, &hotkey),
             Error::<T>::HotKeyAlreadyRegisteredInSubNet
         );
+        Self::ensure_not_reserved_account(&hotkey)?;
 
         // --- 7. Ensure the callers coldkey has enough stake to perform the transaction.
-        let registration_cost = Self::get_burn(netuid);
+        let registration_cost = Self::get_burn_for_coldkey(netuid, &coldkey);
         ensure!(
             Self::can_remove_balance_from_coldkey_account(&coldkey, registration_cost.into()),
             Error::<T>::NotEnoughBalanceToStake
@@ -128,11 +139,24 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NoNeuronIdAvailable
         );
 
-        // --- 10. Ensure the remove operation from the coldkey is a success.
+        // --- 10. A full subnet with a registration queue escrows the burn until a slot is pruned.
+        if Self::registration_queue_active(netuid) {
+            Self::queue_registration(netuid, &hotkey, &coldkey, registration_cost)?;
+            BurnRegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
+            RegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
+            RegistrationsThisBlock::<T>::mutate(netuid, |val| val.saturating_inc());
+            Self::record_churn_registration(netuid, &coldkey);
+            Self::record_coldkey_registration(netuid, &coldkey);
+            return Ok(());
+        }
+
+        // --- 11. Ensure the remove operation from the coldkey is a success.
         let actual_burn_amount =
             Self::remove_balance_from_coldkey_account(&coldkey, registration_cost.into())?;
+        let (actual_burn_amount, deposit) =
+            Self::split_registration_burn(netuid, actual_burn_amount);
 
-        // Tokens are swapped and then burned.
+        // Tokens are swapped and then burned, the deposit is held for the neuron.
         let burned_alpha = Self::swap_tao_for_alpha(
             netuid,
             actual_burn_amount,
@@ -146,15 +170,20 @@ impl<T: Config> Pallet<T> {
 
         // Actually perform the registration.
         let neuron_uid: u16 = Self::register_neuron(netuid, &hotkey);
+        Self::hold_registration_deposit(netuid, neuron_uid, &coldkey, deposit);
 
         // --- 14. Record the registration and increment block and interval counters.
         BurnRegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
         RegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
         RegistrationsThisBlock::<T>::mutate(netuid, |val| val.saturating_inc());
-        Self::increase_rao_recycled(netuid, Self::get_burn(netuid).into());
+        Self::increase_rao_recycled(netuid, registration_cost);
+        Self::record_registration_burn(netuid, actual_burn_amount);
+        Self::record_churn_registration(netuid, &coldkey);
+        Self::record_coldkey_registration(netuid, &coldkey);
 
         // --- 15. Deposit successful event.
         log::debug!("NeuronRegistered( netuid:{netuid:?} uid:{neuron_uid:?} hotkey:{hotkey:?}  ) ");
+        Self::deposit_neuron_registered_log(netuid, neuron_uid, &hotkey);
         Self::deposit_event(Event::NeuronRegistered(netuid, neuron_uid, hotkey));
 
         // --- 16. Ok and done.
@@ -196,6 +225,9 @@ impl<T: Config> Pallet<T> {
     /// *'TooManyRegistrationsThisBlock':
     ///     - This registration exceeds the total allowed on this network this block.
     ///
+    /// *'TooManyColdkeyRegistrationsThisInterval':
+    ///     - The coldkey has used its registrations on this network this interval.
+    ///
     /// *'HotKeyAlreadyRegisteredInSubNet':
     ///     - The hotkey is already registered on this network.
     ///
@@ -240,6 +272,8 @@ impl<T: Config> Pallet<T> {
             Self::get_network_pow_registration_allowed(netuid),
             Error::<T>::SubNetRegistrationDisabled
         );
+        Self::ensure_hotkey_not_blacklisted(netuid, &hotkey)?;
+        Self::ensure_registration_permitted(netuid, &hotkey, &coldkey)?;
 
         // --- 4. Ensure we are not exceeding the max allowed registrations per block.
         ensure!(
@@ -254,12 +288,14 @@ impl<T: Config> Pallet<T> {
                 < Self::get_target_registrations_per_interval(netuid).saturating_mul(3),
             Error::<T>::TooManyRegistrationsThisInterval
         );
+        Self::ensure_coldkey_registration_quota(netuid, &coldkey)?;
 
         // --- 6. Ensure that the key is not already registered.
         ensure!(
             !Uids::<T>::contains_key(netuid, &hotkey),
             Error::<T>::HotKeyAlreadyRegisteredInSubNet
         );
+        Self::ensure_not_reserved_account(&hotkey)?;
 
         // --- 7. Ensure the passed block number is valid, not in the future or too old.
         // Work must have been done within 3 blocks (stops long range attacks).
@@ -307,6 +343,16 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NoNeuronIdAvailable
         );
 
+        // A full subnet with a registration queue admits the registration once a slot is pruned.
+        if Self::registration_queue_active(netuid) {
+            Self::queue_registration(netuid, &hotkey, &coldkey, TaoCurrency::ZERO)?;
+            POWRegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
+            RegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
+            RegistrationsThisBlock::<T>::mutate(netuid, |val| val.saturating_inc());
+            Self::record_coldkey_registration(netuid, &coldkey);
+            return Ok(());
+        }
+
         // Actually perform the registration.
         let neuron_uid: u16 = Self::register_neuron(netuid, &hotkey);
 
@@ -314,9 +360,11 @@ impl<T: Config> Pallet<T> {
         POWRegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
         RegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
         RegistrationsThisBlock::<T>::mutate(netuid, |val| val.saturating_inc());
+        Self::record_coldkey_registration(netuid, &coldkey);
 
         // --- 13. Deposit successful event.
         log::debug!("NeuronRegistered( netuid:{netuid:?} uid:{neuron_uid:?} hotkey:{hotkey:?}  ) ");
+        Self::deposit_neuron_registered_log(netuid, neuron_uid, &hotkey);
         Self::deposit_event(Event::NeuronRegistered(netuid, neuron_uid, hotkey));
 
         // --- 14. Ok and done.
@@ -445,6 +493,18 @@ impl<T: Config> Pallet<T> {
     /// the lowest pruning score, the immune neuron registered earliest is pruned.
     /// Ties for earliest registration are broken by the neuron with the lowest uid.
     pub fn get_neuron_to_prune(netuid: NetUid) -> u16 {
+        if Self::get_subnetwork_n(netuid) == 0 {
+            return 0; // If there are no neurons in this network.
+        }
+
+        let uid_to_prune = Self::find_neuron_to_prune(netuid).unwrap_or_default();
+        Self::set_pruning_score_for_uid(netuid, uid_to_prune, u16::MAX);
+        uid_to_prune
+    }
+
+    /// The neuron `get_neuron_to_prune` prunes next, without marking it as pruned. `None` if the
+    /// subnet only has the immortal hotkeys of its owner.
+    pub fn find_neuron_to_prune(netuid: NetUid) -> Option<u16> {
         let mut min_score: u16 = u16::MAX;
         let mut min_score_in_immunity: u16 = u16::MAX;
         let mut earliest_registration: u64 = u64::MAX;
@@ -456,11 +516,9 @@ impl<T: Config> Pallet<T> {
         // where all non-immune neurons have pruning score u16::MAX
         // This may be unlikely in practice.
         let mut found_non_immune = false;
+        let mut found_immune = false;
 
         let neurons_n = Self::get_subnetwork_n(netuid);
-        if neurons_n == 0 {
-            return 0; // If there are no neurons in this network.
-        }
 
         // Get the list of immortal (top-k by registration time of owner owned) keys
         let subnet_owner_coldkey = SubnetOwner::<T>::get(netuid);
@@ -479,6 +537,7 @@ impl<T: Config> Pallet<T> {
             let is_immune = Self::get_neuron_is_immune(netuid, neuron_uid);
 
             if is_immune {
+                found_immune = true;
                 // if the immune neuron has a lower pruning score than the minimum for immune neurons,
                 // or, if the pruning scores are equal and the immune neuron was registered earlier than the current minimum for immune neurons,
                 // then update the minimum pruning score and the uid to prune for immune neurons
@@ -506,11 +565,11 @@ impl<T: Config> Pallet<T> {
         }
 
         if found_non_immune {
-            Self::set_pruning_score_for_uid(netuid, uid_to_prune, u16::MAX);
-            uid_to_prune
+            Some(uid_to_prune)
+        } else if found_immune {
+            Some(uid_to_prune_in_immunity)
         } else {
-            Self::set_pruning_score_for_uid(netuid, uid_to_prune_in_immunity, u16::MAX);
-            uid_to_prune_in_immunity
+            None
         }
     }
 
@@ -0,0 +1,123 @@
+//! Subnet registration allowlists and denylists.
+//!
+//! A subnet owner can deny hotkeys or coldkeys from registering on their subnet, and can restrict
+//! registrations to an allowlist of hotkeys or coldkeys, e.g. for a closed beta. Every entry is
+//! either on the allowlist or on the denylist of the subnet. A denied hotkey or coldkey cannot
+//! register, and while the allowlist is not empty a registration needs its hotkey or its coldkey
+//! on it. Both lists together are bounded by `MAX_REGISTRATION_ACCESS_ENTRIES`.
+
+use super::*;
+use subtensor_runtime_common::NetUid;
+
+/// Maximum number of entries on the allowlist and the denylist of a subnet together.
+pub const MAX_REGISTRATION_ACCESS_ENTRIES: usize = 256;
+
+/// A hotkey or coldkey on the registration access list of a subnet.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub enum RegistrationAccount<AccountId> {
+    /// Applies to registrations of the hotkey.
+    Hotkey(AccountId),
+    /// Applies to registrations paid or signed for by the coldkey.
+    Coldkey(AccountId),
+}
+
+/// Whether an account of the registration access list may register.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum RegistrationAccess {
+    /// On the allowlist.
+    Allowed,
+    /// On the denylist.
+    Denied,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Puts `account` on the allowlist or the denylist of `netuid`, or removes it from both when
+    /// `access` is `None`.
+    pub fn do_set_registration_access(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        account: RegistrationAccount<T::AccountId>,
+        access: Option<RegistrationAccess>,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner(origin, netuid)?;
+        ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
+
+        let previous = RegistrationAccessList::<T>::get(netuid, &account);
+        match access {
+            Some(access) => {
+                ensure!(
+                    previous.is_some()
+                        || RegistrationAccessList::<T>::iter_key_prefix(netuid).count()
+                            < MAX_REGISTRATION_ACCESS_ENTRIES,
+                    Error::<T>::TooManyRegistrationAccessEntries
+                );
+                RegistrationAccessList::<T>::insert(netuid, &account, access);
+            }
+            None => {
+                ensure!(
+                    previous.is_some(),
+                    Error::<T>::RegistrationAccessEntryNotFound
+                );
+                RegistrationAccessList::<T>::remove(netuid, &account);
+            }
+        }
+
+        let was_allowed = previous == Some(RegistrationAccess::Allowed);
+        let is_allowed = access == Some(RegistrationAccess::Allowed);
+        if is_allowed && !was_allowed {
+            RegistrationAllowlistSize::<T>::mutate(netuid, |size| size.saturating_inc());
+        } else if was_allowed && !is_allowed {
+            RegistrationAllowlistSize::<T>::mutate(netuid, |size| size.saturating_dec());
+        }
+
+        Self::deposit_event(Event::RegistrationAccessSet {
+            netuid,
+            account,
+            access,
+        });
+        Ok(())
+    }
+
+    /// Ensures `hotkey` may register on `netuid` for `coldkey` by its access lists.
+    pub fn ensure_registration_permitted(
+        netuid: NetUid,
+        hotkey: &T::AccountId,
+        coldkey: &T::AccountId,
+    ) -> DispatchResult {
+        let hotkey_access =
+            RegistrationAccessList::<T>::get(netuid, RegistrationAccount::Hotkey(hotkey.clone()));
+        let coldkey_access =
+            RegistrationAccessList::<T>::get(netuid, RegistrationAccount::Coldkey(coldkey.clone()));
+        let accesses = [hotkey_access, coldkey_access];
+
+        ensure!(
+            !accesses.contains(&Some(RegistrationAccess::Denied)),
+            Error::<T>::RegistrationDenied
+        );
+        ensure!(
+            RegistrationAllowlistSize::<T>::get(netuid) == 0
+                || accesses.contains(&Some(RegistrationAccess::Allowed)),
+            Error::<T>::RegistrationNotAllowlisted
+        );
+        Ok(())
+    }
+
+    /// Removes the registration access lists of `netuid`.
+    pub fn clear_registration_access(netuid: NetUid) {
+        let _ = RegistrationAccessList::<T>::clear_prefix(netuid, u32::MAX, None);
+        RegistrationAllowlistSize::<T>::remove(netuid);
+    }
+}
@@ -0,0 +1,225 @@
+//! Burn registration auctions.
+//!
+//! Burned registrations are first come, first served, so when registrations are scarce the
+//! fastest transactions win them. A subnet can instead run a burn auction: coldkeys bid TAO to
+//! register a hotkey during the adjustment interval, at least the burn of the subnet, and the bids
+//! are escrowed. When the interval ends the highest bids, up to the target registrations per
+//! interval, are burned to register their hotkeys, and every other bid is refunded. All bids of an
+//! interval settle together, so the order in which they were placed only breaks ties.
+//!
+//! A bid can be raised until the interval ends. Once `MAX_REGISTRATION_BIDS` bids are placed on a
+//! subnet, a new bid must beat the lowest one, which is refunded.
+
+use super::*;
+use frame_support::storage::{TransactionOutcome, transactional};
+use subtensor_runtime_common::{NetUid, TaoCurrency};
+use subtensor_swap_interface::SwapHandler;
+
+/// Maximum number of registration bids placed on a subnet at any time.
+pub const MAX_REGISTRATION_BIDS: usize = 64;
+
+#[freeze_struct("d59c0a4b87eaf2b3")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct RegistrationBid<AccountId> {
+    /// The coldkey the bid is escrowed from.
+    pub coldkey: AccountId,
+    /// The TAO burned if the bid wins.
+    pub amount: TaoCurrency,
+    /// The block the bid was placed at.
+    pub placed_at: u64,
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn do_bid_burned_registration(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        hotkey: T::AccountId,
+        amount: TaoCurrency,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        ensure!(
+            !netuid.is_root(),
+            Error::<T>::RegistrationNotPermittedOnRootSubnet
+        );
+        ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
+        ensure!(
+            Self::get_burn_auction_enabled(netuid),
+            Error::<T>::BurnAuctionDisabled
+        );
+        ensure!(
+            Self::get_network_registration_allowed(netuid),
+            Error::<T>::SubNetRegistrationDisabled
+        );
+        Self::ensure_hotkey_not_blacklisted(netuid, &hotkey)?;
+        Self::ensure_registration_permitted(netuid, &hotkey, &coldkey)?;
+        ensure!(
+            !Uids::<T>::contains_key(netuid, &hotkey),
+            Error::<T>::HotKeyAlreadyRegisteredInSubNet
+        );
+        Self::ensure_not_reserved_account(&hotkey)?;
+        ensure!(
+            Self::get_max_allowed_uids(netuid) != 0,
+            Error::<T>::NoNeuronIdAvailable
+        );
+        ensure!(
+            amount >= Self::get_burn(netuid),
+            Error::<T>::RegistrationBidTooLow
+        );
+
+        // The previous bid of the hotkey is replaced by a higher one
+        if let Some(previous) = RegistrationBids::<T>::get(netuid, &hotkey) {
+            ensure!(amount > previous.amount, Error::<T>::RegistrationBidTooLow);
+            Self::refund_registration_bid(netuid, &hotkey, previous);
+        } else if RegistrationBids::<T>::iter_prefix(netuid).count() >= MAX_REGISTRATION_BIDS {
+            let (lowest_hotkey, lowest) = RegistrationBids::<T>::iter_prefix(netuid)
+                .min_by(|(_, a), (_, b)| {
+                    a.amount.cmp(&b.amount).then(b.placed_at.cmp(&a.placed_at))
+                })
+                .ok_or(Error::<T>::RegistrationBidTooLow)?;
+            ensure!(amount > lowest.amount, Error::<T>::RegistrationBidTooLow);
+            RegistrationBids::<T>::remove(netuid, &lowest_hotkey);
+            Self::refund_registration_bid(netuid, &lowest_hotkey, lowest);
+        }
+
+        Self::create_account_if_non_existent(&coldkey, &hotkey);
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+        ensure!(
+            Self::can_remove_balance_from_coldkey_account(&coldkey, amount.into()),
+            Error::<T>::NotEnoughBalanceToStake
+        );
+        let escrowed = Self::remove_balance_from_coldkey_account(&coldkey, amount.into())?;
+
+        RegistrationBids::<T>::insert(
+            netuid,
+            &hotkey,
+            RegistrationBid {
+                coldkey: coldkey.clone(),
+                amount: escrowed,
+                placed_at: Self::get_current_block_as_u64(),
+            },
+        );
+        Self::deposit_event(Event::RegistrationBidPlaced {
+            netuid,
+            coldkey,
+            hotkey,
+            amount: escrowed,
+        });
+        Ok(())
+    }
+
+    /// Registers the hotkeys of the highest registration bids of `netuid`, up to its target
+    /// registrations per interval, and refunds the other bids. Every bid is refunded if the burn
+    /// auction has been disabled since.
+    pub fn settle_registration_auction(netuid: NetUid) {
+        let mut bids: Vec<(T::AccountId, RegistrationBid<T::AccountId>)> =
+            RegistrationBids::<T>::drain_prefix(netuid).collect();
+        if bids.is_empty() {
+            return;
+        }
+        bids.sort_by(|(_, a), (_, b)| b.amount.cmp(&a.amount).then(a.placed_at.cmp(&b.placed_at)));
+
+        let mut slots: u16 = if Self::get_burn_auction_enabled(netuid) {
+            Self::get_target_registrations_per_interval(netuid)
+        } else {
+            0
+        };
+        for (hotkey, bid) in bids {
+            if slots > 0 {
+                let result = transactional::with_transaction(|| {
+                    match Self::register_winning_bid(netuid, &hotkey, &bid) {
+                        Ok(()) => TransactionOutcome::Commit(Ok(())),
+                        Err(e) => TransactionOutcome::Rollback(Err(e)),
+                    }
+                });
+                match result {
+                    Ok(()) => {
+                        slots = slots.saturating_sub(1);
+                        continue;
+                    }
+                    Err(e) => log::debug!(
+                        "settle_registration_auction( netuid:{netuid:?} hotkey:{hotkey:?} ) failed: {e:?}"
+                    ),
+                }
+            }
+            Self::refund_registration_bid(netuid, &hotkey, bid);
+        }
+    }
+
+    /// Refunds every registration bid placed on `netuid`.
+    pub fn refund_registration_bids(netuid: NetUid) {
+        for (hotkey, bid) in RegistrationBids::<T>::drain_prefix(netuid) {
+            Self::refund_registration_bid(netuid, &hotkey, bid);
+        }
+    }
+
+    fn refund_registration_bid(
+        netuid: NetUid,
+        hotkey: &T::AccountId,
+        bid: RegistrationBid<T::AccountId>,
+    ) {
+        Self::add_balance_to_coldkey_account(&bid.coldkey, bid.amount.into());
+        Self::deposit_event(Event::RegistrationBidRefunded {
+            netuid,
+            coldkey: bid.coldkey,
+            hotkey: hotkey.clone(),
+            amount: bid.amount,
+        });
+    }
+
+    /// Burns a winning registration bid and registers its hotkey, like a burned registration.
+    fn register_winning_bid(
+        netuid: NetUid,
+        hotkey: &T::AccountId,
+        bid: &RegistrationBid<T::AccountId>,
+    ) -> DispatchResult {
+        ensure!(
+            Self::get_network_registration_allowed(netuid),
+            Error::<T>::SubNetRegistrationDisabled
+        );
+        Self::ensure_hotkey_not_blacklisted(netuid, hotkey)?;
+        Self::ensure_registration_permitted(netuid, hotkey, &bid.coldkey)?;
+        Self::ensure_coldkey_registration_quota(netuid, &bid.coldkey)?;
+        ensure!(
+            !Uids::<T>::contains_key(netuid, hotkey),
+            Error::<T>::HotKeyAlreadyRegisteredInSubNet
+        );
+        ensure!(
+            Self::coldkey_owns_hotkey(&bid.coldkey, hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+        ensure!(
+            Self::get_max_allowed_uids(netuid) != 0,
+            Error::<T>::NoNeuronIdAvailable
+        );
+
+        // Tokens are swapped and then burned.
+        let burned_alpha = Self::swap_tao_for_alpha(
+            netuid,
+            bid.amount,
+            T::SwapInterface::max_price().into(),
+            false,
+        )?
+        .amount_paid_out;
+        SubnetAlphaOut::<T>::mutate(netuid, |total| {
+            *total = total.saturating_sub(burned_alpha.into())
+        });
+
+        let neuron_uid: u16 = Self::register_neuron(netuid, hotkey);
+
+        BurnRegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
+        RegistrationsThisInterval::<T>::mutate(netuid, |val| val.saturating_inc());
+        Self::increase_rao_recycled(netuid, bid.amount);
+        Self::record_registration_burn(netuid, bid.amount);
+        Self::record_churn_registration(netuid, &bid.coldkey);
+        Self::record_coldkey_registration(netuid, &bid.coldkey);
+
+        log::debug!("NeuronRegistered( netuid:{netuid:?} uid:{neuron_uid:?} hotkey:{hotkey:?} ) ");
+        Self::deposit_neuron_registered_log(netuid, neuron_uid, hotkey);
+        Self::deposit_event(Event::NeuronRegistered(netuid, neuron_uid, hotkey.clone()));
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+//! Refundable registration deposits.
+//!
+//! A burned registration is a sunk cost, so a miner that registers, idles and gets pruned pays the
+//! same as one that keeps serving the subnet. A subnet can hold a share of the burn as a deposit
+//! instead. The deposit is refunded to the coldkey once the neuron has been registered for the
+//! maturity epochs of the subnet, or when it leaves the subnet on its own with `deregister_uid`,
+//! and burned when the neuron is pruned while inactive. A neuron pruned while active, trimmed
+//! away or on a dissolved subnet is refunded.
+//!
+//! Deposits are taken from burned registrations, queued ones included, and are held by uid. At
+//! most `MAX_REGISTRATION_DEPOSIT_SHARE` of the burn is held, so a registration always burns.
+
+use super::*;
+use safe_math::*;
+use subtensor_runtime_common::{Currency, NetUid, TaoCurrency};
+
+/// The maximum share of the registration burn held as a deposit, in `u16::MAX` parts.
+pub const MAX_REGISTRATION_DEPOSIT_SHARE: u16 = u16::MAX / 2;
+
+#[freeze_struct("d5d2dfa29ad46d26")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct RegistrationDeposit<AccountId> {
+    /// The coldkey the deposit is refunded to.
+    pub coldkey: AccountId,
+    /// The TAO held.
+    pub amount: TaoCurrency,
+    /// The block from which the deposit is refunded.
+    pub matures_at: u64,
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn get_registration_deposit_share(netuid: NetUid) -> u16 {
+        RegistrationDepositShare::<T>::get(netuid)
+    }
+
+    pub fn get_registration_deposit_epochs(netuid: NetUid) -> u16 {
+        RegistrationDepositEpochs::<T>::get(netuid)
+    }
+
+    /// Holds `share` of the registration burn of `netuid` as a deposit refunded after
+    /// `maturity_epochs`, a zero share disables deposits. Deposits already held are unchanged.
+    pub fn set_registration_deposit(
+        netuid: NetUid,
+        share: u16,
+        maturity_epochs: u16,
+    ) -> Result<(), Error<T>> {
+        ensure!(
+            share <= MAX_REGISTRATION_DEPOSIT_SHARE,
+            Error::<T>::RegistrationDepositShareTooHigh
+        );
+        RegistrationDepositShare::<T>::insert(netuid, share);
+        RegistrationDepositEpochs::<T>::insert(netuid, maturity_epochs);
+        Self::deposit_event(Event::RegistrationDepositSet {
+            netuid,
+            share,
+            maturity_epochs,
+        });
+        Ok(())
+    }
+
+    /// Splits the registration burn `amount` of `netuid` into the TAO burned and the deposit.
+    pub fn split_registration_burn(
+        netuid: NetUid,
+        amount: TaoCurrency,
+    ) -> (TaoCurrency, TaoCurrency) {
+        let share = Self::get_registration_deposit_share(netuid);
+        let deposit = u64::from(amount)
+            .saturating_mul(u64::from(share))
+            .safe_div(u64::from(u16::MAX));
+        let deposit = TaoCurrency::from(deposit);
+        (amount.saturating_sub(deposit), deposit)
+    }
+
+    /// Holds `amount`, already taken from `coldkey`, as the deposit of the neuron under `uid`.
+    pub fn hold_registration_deposit(
+        netuid: NetUid,
+        uid: u16,
+        coldkey: &T::AccountId,
+        amount: TaoCurrency,
+    ) {
+        if amount.is_zero() {
+            return;
+        }
+        let blocks_per_epoch = u64::from(Self::get_tempo(netuid)).saturating_add(1);
+        let matures_at = Self::get_current_block_as_u64().saturating_add(
+            u64::from(Self::get_registration_deposit_epochs(netuid))
+                .saturating_mul(blocks_per_epoch),
+        );
+        RegistrationDeposits::<T>::insert(
+            netuid,
+            uid,
+            RegistrationDeposit {
+                coldkey: coldkey.clone(),
+                amount,
+                matures_at,
+            },
+        );
+    }
+
+    /// Refunds the deposits of `netuid` that have matured.
+    pub fn release_matured_registration_deposits(netuid: NetUid) {
+        let current_block = Self::get_current_block_as_u64();
+        let matured: Vec<u16> = RegistrationDeposits::<T>::iter_prefix(netuid)
+            .filter(|(_, deposit)| deposit.matures_at <= current_block)
+            .map(|(uid, _)| uid)
+            .collect();
+        for uid in matured {
+            Self::refund_registration_deposit(netuid, uid);
+        }
+    }
+
+    /// Settles the deposit of the neuron under `uid` as it is pruned: burned if the neuron was
+    /// inactive in the last epoch, less the share kept by the network treasury, and refunded
+    /// otherwise.
+    pub fn settle_pruned_registration_deposit(netuid: NetUid, uid: u16) {
+        if Self::get_active_for_uid(netuid, uid) {
+            Self::refund_registration_deposit(netuid, uid);
+            return;
+        }
+        let Some(deposit) = RegistrationDeposits::<T>::take(netuid, uid) else {
+            return;
+        };
+        Self::recycle_tao(Self::keep_network_treasury_share(deposit.amount));
+        Self::record_registration_burn(netuid, deposit.amount);
+        Self::deposit_event(Event::RegistrationDepositSlashed {
+            netuid,
+            uid,
+            coldkey: deposit.coldkey,
+            amount: deposit.amount,
+        });
+    }
+
+    /// Refunds the deposit of the neuron under `uid` of `netuid`, if it has one.
+    pub fn refund_registration_deposit(netuid: NetUid, uid: u16) {
+        let Some(deposit) = RegistrationDeposits::<T>::take(netuid, uid) else {
+            return;
+        };
+        Self::add_balance_to_coldkey_account(&deposit.coldkey, deposit.amount.into());
+        Self::deposit_event(Event::RegistrationDepositRefunded {
+            netuid,
+            uid,
+            coldkey: deposit.coldkey,
+            amount: deposit.amount,
+        });
+    }
+
+    /// Refunds every deposit held on `netuid`.
+    pub fn refund_registration_deposits(netuid: NetUid) {
+        let uids: Vec<u16> = RegistrationDeposits::<T>::iter_key_prefix(netuid).collect();
+        for uid in uids {
+            Self::refund_registration_deposit(netuid, uid);
+        }
+    }
+
+    /// Deregisters the neuron under `uid` of `netuid` for the coldkey owning its hotkey, and
+    /// refunds its deposit. The last neuron of the subnet takes its uid.
+    pub fn do_deregister_uid(origin: T::RuntimeOrigin, netuid: NetUid, uid: u16) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        ensure!(
+            !netuid.is_root(),
+            Error::<T>::RegistrationNotPermittedOnRootSubnet
+        );
+        ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
+        let hotkey =
+            Keys::<T>::try_get(netuid, uid).map_err(|_| Error::<T>::HotKeyNotRegisteredInSubNet)?;
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+        ensure!(
+            SubnetOwnerHotkey::<T>::get(netuid) != hotkey,
+            Error::<T>::CannotDeregisterOwnerHotkey
+        );
+
+        Self::refund_registration_deposit(netuid, uid);
+        Self::remove_neuron(netuid, uid);
+
+        log::debug!("NeuronDeregistered( netuid:{netuid:?} uid:{uid:?} hotkey:{hotkey:?} ) ");
+        Self::deposit_event(Event::NeuronDeregistered {
+            netuid,
+            uid,
+            hotkey,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,220 @@
+//! Registration waiting queue.
+//!
+//! A registration on a full subnet replaces the neuron with the lowest pruning score right away,
+//! in the middle of an epoch, so the scores the next epoch is computed from churn under it. A
+//! subnet can queue registrations instead: once `SubnetworkN` reaches `MaxAllowedUids`, burned and
+//! PoW registrations join a FIFO queue of up to `MAX_REGISTRATION_QUEUE_LEN` registrations, with
+//! their burn escrowed. After every epoch of the subnet the registrations at the head of the queue
+//! are admitted, each pruning a neuron, up to the target registrations per interval.
+//!
+//! The escrow of an admitted registration is burned, less the registration deposit of the subnet.
+//! It is refunded when the registration cannot be admitted, when it has waited
+//! `REGISTRATION_QUEUE_TIMEOUT` blocks, when the queue is disabled and when the subnet is
+//! dissolved.
+
+use super::*;
+use frame_support::storage::{TransactionOutcome, transactional};
+use subtensor_runtime_common::{Currency, NetUid, TaoCurrency};
+use subtensor_swap_interface::SwapHandler;
+
+/// Maximum number of registrations waiting in the queue of a subnet.
+pub const MAX_REGISTRATION_QUEUE_LEN: usize = 32;
+
+/// Number of blocks after which a queued registration is refunded.
+pub const REGISTRATION_QUEUE_TIMEOUT: u64 = 7200;
+
+#[freeze_struct("cdfec5eb88500c7e")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct QueuedRegistration<AccountId> {
+    /// The hotkey to register.
+    pub hotkey: AccountId,
+    /// The coldkey the burn is escrowed from.
+    pub coldkey: AccountId,
+    /// The TAO burned on admission, zero for PoW registrations.
+    pub escrow: TaoCurrency,
+    /// The block the registration was queued at.
+    pub queued_at: u64,
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn get_registration_queue_enabled(netuid: NetUid) -> bool {
+        RegistrationQueueEnabled::<T>::get(netuid)
+    }
+
+    /// Enables or disables the registration queue of `netuid`. Queued registrations of a disabled
+    /// queue are refunded after the next epoch.
+    pub fn set_registration_queue_enabled(netuid: NetUid, enabled: bool) {
+        RegistrationQueueEnabled::<T>::insert(netuid, enabled);
+        Self::deposit_event(Event::RegistrationQueueEnabledSet(netuid, enabled));
+    }
+
+    /// Whether registrations on `netuid` are queued rather than pruning a neuron right away.
+    pub fn registration_queue_active(netuid: NetUid) -> bool {
+        Self::get_registration_queue_enabled(netuid)
+            && Self::get_subnetwork_n(netuid) >= Self::get_max_allowed_uids(netuid)
+    }
+
+    /// Queues the registration of `hotkey` on `netuid`, escrowing `escrow` from `coldkey`.
+    pub fn queue_registration(
+        netuid: NetUid,
+        hotkey: &T::AccountId,
+        coldkey: &T::AccountId,
+        escrow: TaoCurrency,
+    ) -> DispatchResult {
+        let mut queue = RegistrationQueue::<T>::get(netuid);
+        ensure!(
+            !queue.iter().any(|queued| queued.hotkey == *hotkey),
+            Error::<T>::HotKeyAlreadyQueued
+        );
+        ensure!(
+            queue.len() < MAX_REGISTRATION_QUEUE_LEN,
+            Error::<T>::RegistrationQueueFull
+        );
+
+        let escrow = if escrow.is_zero() {
+            escrow
+        } else {
+            Self::remove_balance_from_coldkey_account(coldkey, escrow.into())?
+        };
+        queue.push(QueuedRegistration {
+            hotkey: hotkey.clone(),
+            coldkey: coldkey.clone(),
+            escrow,
+            queued_at: Self::get_current_block_as_u64(),
+        });
+        let position = u32::try_from(queue.len()).unwrap_or(u32::MAX);
+        RegistrationQueue::<T>::insert(netuid, queue);
+
+        Self::deposit_event(Event::RegistrationQueued {
+            netuid,
+            coldkey: coldkey.clone(),
+            hotkey: hotkey.clone(),
+            escrow,
+            position,
+        });
+        Ok(())
+    }
+
+    /// Admits the registrations at the head of the queue of `netuid`, up to its target
+    /// registrations per interval, and refunds those that timed out or cannot be admitted. Every
+    /// registration is refunded if the queue has been disabled since.
+    pub fn admit_queued_registrations(netuid: NetUid) {
+        let queue = RegistrationQueue::<T>::take(netuid);
+        if queue.is_empty() {
+            return;
+        }
+
+        let current_block = Self::get_current_block_as_u64();
+        let enabled = Self::get_registration_queue_enabled(netuid);
+        let mut slots: u16 = if enabled {
+            Self::get_target_registrations_per_interval(netuid)
+        } else {
+            0
+        };
+        let mut waiting: Vec<QueuedRegistration<T::AccountId>> = Vec::new();
+        for queued in queue {
+            if !enabled
+                || current_block.saturating_sub(queued.queued_at) >= REGISTRATION_QUEUE_TIMEOUT
+            {
+                Self::refund_queued_registration(netuid, queued);
+                continue;
+            }
+            if slots == 0 {
+                waiting.push(queued);
+                continue;
+            }
+
+            let result =
+                transactional::with_transaction(|| {
+                    match Self::admit_queued_registration(netuid, &queued) {
+                        Ok(()) => TransactionOutcome::Commit(Ok(())),
+                        Err(e) => TransactionOutcome::Rollback(Err(e)),
+                    }
+                });
+            match result {
+                Ok(()) => slots = slots.saturating_sub(1),
+                Err(e) => {
+                    log::debug!(
+                        "admit_queued_registrations( netuid:{netuid:?} hotkey:{:?} ) failed: {e:?}",
+                        queued.hotkey
+                    );
+                    Self::refund_queued_registration(netuid, queued);
+                }
+            }
+        }
+
+        if !waiting.is_empty() {
+            RegistrationQueue::<T>::insert(netuid, waiting);
+        }
+    }
+
+    /// Refunds every registration queued on `netuid`.
+    pub fn refund_queued_registrations(netuid: NetUid) {
+        for queued in RegistrationQueue::<T>::take(netuid) {
+            Self::refund_queued_registration(netuid, queued);
+        }
+    }
+
+    fn refund_queued_registration(netuid: NetUid, queued: QueuedRegistration<T::AccountId>) {
+        if !queued.escrow.is_zero() {
+            Self::add_balance_to_coldkey_account(&queued.coldkey, queued.escrow.into());
+        }
+        Self::deposit_event(Event::QueuedRegistrationRefunded {
+            netuid,
+            coldkey: queued.coldkey,
+            hotkey: queued.hotkey,
+            amount: queued.escrow,
+        });
+    }
+
+    /// Burns the escrow of a queued registration and registers its hotkey, pruning a neuron.
+    fn admit_queued_registration(
+        netuid: NetUid,
+        queued: &QueuedRegistration<T::AccountId>,
+    ) -> DispatchResult {
+        Self::ensure_hotkey_not_blacklisted(netuid, &queued.hotkey)?;
+        ensure!(
+            !Uids::<T>::contains_key(netuid, &queued.hotkey),
+            Error::<T>::HotKeyAlreadyRegisteredInSubNet
+        );
+        ensure!(
+            Self::coldkey_owns_hotkey(&queued.coldkey, &queued.hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+        ensure!(
+            Self::get_max_allowed_uids(netuid) != 0,
+            Error::<T>::NoNeuronIdAvailable
+        );
+
+        // Tokens are swapped and then burned, the deposit is held for the neuron.
+        let (burn, deposit) = Self::split_registration_burn(netuid, queued.escrow);
+        if !burn.is_zero() {
+            let burned_alpha = Self::swap_tao_for_alpha(
+                netuid,
+                burn,
+                T::SwapInterface::max_price().into(),
+                false,
+            )?
+            .amount_paid_out;
+            SubnetAlphaOut::<T>::mutate(netuid, |total| {
+                *total = total.saturating_sub(burned_alpha.into())
+            });
+            Self::increase_rao_recycled(netuid, queued.escrow);
+            Self::record_registration_burn(netuid, burn);
+        }
+
+        let neuron_uid: u16 = Self::register_neuron(netuid, &queued.hotkey);
+        Self::hold_registration_deposit(netuid, neuron_uid, &queued.coldkey, deposit);
+        log::debug!(
+            "NeuronRegistered( netuid:{netuid:?} uid:{neuron_uid:?} hotkey:{:?} ) ",
+            queued.hotkey
+        );
+        Self::deposit_neuron_registered_log(netuid, neuron_uid, &queued.hotkey);
+        Self::deposit_event(Event::NeuronRegistered(
+            netuid,
+            neuron_uid,
+            queued.hotkey.clone(),
+        ));
+        Ok(())
+    }
+}
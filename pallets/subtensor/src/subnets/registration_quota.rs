@@ -0,0 +1,57 @@
+//! Per-coldkey registration quota.
+//!
+//! The registrations of a subnet are limited per block and per adjustment interval, but not per
+//! coldkey, so a single funding coldkey can take every registration slot of an interval with
+//! fresh hotkeys. A subnet can cap the PoW, burned and auctioned registrations a coldkey makes in
+//! an interval. The counts are drained with the other registration counters of the interval.
+
+use super::*;
+use subtensor_runtime_common::NetUid;
+
+impl<T: Config> Pallet<T> {
+    pub fn get_max_registrations_per_coldkey_per_interval(netuid: NetUid) -> u16 {
+        MaxRegistrationsPerColdkeyPerInterval::<T>::get(netuid)
+    }
+
+    /// Sets the registrations a coldkey is allowed per interval on `netuid`, 0 for no quota.
+    pub fn set_max_registrations_per_coldkey_per_interval(netuid: NetUid, max_registrations: u16) {
+        MaxRegistrationsPerColdkeyPerInterval::<T>::insert(netuid, max_registrations);
+        Self::deposit_event(Event::MaxRegistrationsPerColdkeyPerIntervalSet {
+            netuid,
+            max_registrations,
+        });
+    }
+
+    /// Ensures `coldkey` has a registration left on `netuid` this interval.
+    pub fn ensure_coldkey_registration_quota(
+        netuid: NetUid,
+        coldkey: &T::AccountId,
+    ) -> DispatchResult {
+        let max_registrations = Self::get_max_registrations_per_coldkey_per_interval(netuid);
+        ensure!(
+            max_registrations == 0
+                || ColdkeyRegistrationsThisInterval::<T>::get(netuid, coldkey) < max_registrations,
+            Error::<T>::TooManyColdkeyRegistrationsThisInterval
+        );
+        Ok(())
+    }
+
+    /// Counts a registration of `coldkey` on `netuid` towards its quota of the interval.
+    pub fn record_coldkey_registration(netuid: NetUid, coldkey: &T::AccountId) {
+        if Self::get_max_registrations_per_coldkey_per_interval(netuid) == 0 {
+            return;
+        }
+        ColdkeyRegistrationsThisInterval::<T>::mutate(netuid, coldkey, |val| val.saturating_inc());
+    }
+
+    /// Drains the registration counts of the coldkeys of `netuid` at the end of an interval.
+    pub fn reset_coldkey_registrations(netuid: NetUid) {
+        let _ = ColdkeyRegistrationsThisInterval::<T>::clear_prefix(netuid, u32::MAX, None);
+    }
+
+    /// Removes the registration quota of `netuid`.
+    pub fn clear_coldkey_registration_quota(netuid: NetUid) {
+        MaxRegistrationsPerColdkeyPerInterval::<T>::remove(netuid);
+        Self::reset_coldkey_registrations(netuid);
+    }
+}
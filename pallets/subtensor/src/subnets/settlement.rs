@@ -0,0 +1,272 @@
+//! Dissolution settlements.
+//!
+//! When a subnet is dissolved its TAO pot, `SubnetTAO`, is paid out pro rata to the alpha value
+//! of every stake on it, with the rounding remainder going to the largest remainders, and legacy
+//! subnets refund their owner the lock cost less the owner emission received at the current
+//! price. `get_dissolution_settlement` computes these payouts from the state without changing it,
+//! and `destroy_alpha_in_out_stakes` pays out exactly what it computes.
+//!
+//! The settlement replayer dissolves a subnet in a transaction that is always rolled back, and
+//! compares the balance changes of the dissolution, and the root stake of payouts restaked into
+//! root, with the settlement. It runs in try-runtime checks on forked state before big subnets
+//! are dissolved, and in post-incident audits.
+
+use super::*;
+use crate::staking::dissolution_payout::DissolutionPayout;
+use alloc::collections::BTreeMap;
+use substrate_fixed::types::U96F32;
+use subtensor_runtime_common::{Currency, NetUid, TaoCurrency};
+use subtensor_swap_interface::{OrderType, SwapHandler};
+
+/// The payouts of the dissolution of a subnet.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DissolutionSettlement<AccountId> {
+    pub netuid: NetUid,
+    /// The TAO pot of the subnet, paid out to its stakers.
+    pub pot: TaoCurrency,
+    /// The share of the pot of every stake with alpha value, by coldkey, in storage order.
+    pub payouts: Vec<(AccountId, TaoCurrency)>,
+    /// Every (hotkey, coldkey) stake on the subnet, destroyed by the dissolution.
+    pub stakes: Vec<(AccountId, AccountId)>,
+    /// The owner coldkey of the subnet.
+    pub owner: AccountId,
+    /// The lock cost refunded to the owner.
+    pub owner_refund: TaoCurrency,
+}
+
+impl<AccountId: Ord + Clone> DissolutionSettlement<AccountId> {
+    /// The TAO every coldkey receives from the settlement, the owner refund included.
+    pub fn credits(&self) -> BTreeMap<AccountId, TaoCurrency> {
+        let mut credits: BTreeMap<AccountId, TaoCurrency> = BTreeMap::new();
+        let refund = (self.owner.clone(), self.owner_refund);
+        for (coldkey, tao) in self.payouts.iter().chain(core::iter::once(&refund)) {
+            let credit = credits.entry(coldkey.clone()).or_insert(TaoCurrency::ZERO);
+            *credit = credit.saturating_add(*tao);
+        }
+        credits.retain(|_, tao| !tao.is_zero());
+        credits
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Computes the settlement of the dissolution of `netuid` from the current state.
+    pub fn get_dissolution_settlement(netuid: NetUid) -> DissolutionSettlement<T::AccountId> {
+        // Owner / lock cost.
+        let owner: T::AccountId = SubnetOwner::<T>::get(netuid);
+        let lock_cost: TaoCurrency = Self::get_subnet_locked_balance(netuid);
+
+        // Determine if this subnet is eligible for a lock refund (legacy).
+        let reg_at: u64 = NetworkRegisteredAt::<T>::get(netuid);
+        let start_block: u64 = NetworkRegistrationStartBlock::<T>::get();
+        let should_refund_owner: bool = reg_at < start_block;
+
+        // Compute owner's received emission in TAO at current price (ONLY if we may refund).
+        //    Emission::<T> is Vec<AlphaCurrency>. We:
+        //      - sum emitted α,
+        //      - apply owner fraction to get owner α,
+        //      - price that α using a *simulated* AMM swap.
+        let mut owner_emission_tao: TaoCurrency = TaoCurrency::ZERO;
+        if should_refund_owner && !lock_cost.is_zero() {
+            let total_emitted_alpha_u128: u128 =
+                Emission::<T>::get(netuid)
+                    .into_iter()
+                    .fold(0u128, |acc, e_alpha| {
+                        let e_u64: u64 = Into::<u64>::into(e_alpha);
+                        acc.saturating_add(e_u64 as u128)
+                    });
+
+            if total_emitted_alpha_u128 > 0 {
+                let owner_fraction: U96F32 = Self::get_float_subnet_owner_cut();
+                let owner_alpha_u64: u64 = U96F32::from_num(total_emitted_alpha_u128)
+                    .saturating_mul(owner_fraction)
+                    .floor()
+                    .saturating_to_num::<u64>();
+
+                owner_emission_tao = if owner_alpha_u64 > 0 {
+                    match T::SwapInterface::sim_swap(
+                        netuid.into(),
+                        OrderType::Sell,
+                        owner_alpha_u64,
+                    ) {
+                        Ok(sim) => TaoCurrency::from(sim.amount_paid_out),
+                        Err(e) => {
+                            log::debug!(
+                                "get_dissolution_settlement: sim_swap owner α→τ failed (netuid={netuid:?}, alpha={owner_alpha_u64}, err={e:?}); falling back to price multiply.",
+                            );
+                            let cur_price: U96F32 =
+                                T::SwapInterface::current_alpha_price(netuid.into());
+                            let val_u64: u64 = U96F32::from_num(owner_alpha_u64)
+                                .saturating_mul(cur_price)
+                                .floor()
+                                .saturating_to_num::<u64>();
+                            TaoCurrency::from(val_u64)
+                        }
+                    }
+                } else {
+                    TaoCurrency::ZERO
+                };
+            }
+        }
+
+        // Legacy subnets (registered before NetworkRegistrationStartBlock) receive:
+        //   refund = max(0, lock_cost(τ) − owner_received_emission_in_τ).
+        // New subnets: no refund.
+        let owner_refund: TaoCurrency = if should_refund_owner {
+            lock_cost.saturating_sub(owner_emission_tao)
+        } else {
+            TaoCurrency::ZERO
+        };
+
+        // Enumerate all α entries on this subnet to build distribution weights.
+        //    - per (hot,cold) α VALUE (not shares) with fallback to raw share if pool uninitialized.
+        let mut stakes: Vec<(T::AccountId, T::AccountId)> = Vec::new();
+        let mut stakers: Vec<(T::AccountId, u128)> = Vec::new();
+        let mut total_alpha_value_u128: u128 = 0;
+
+        for ((hot, cold, this_netuid), share_u64f64) in Alpha::<T>::iter() {
+            if this_netuid != netuid {
+                continue;
+            }
+
+            // Primary: actual α value via share pool.
+            let pool = Self::get_alpha_share_pool(hot.clone(), netuid);
+            let actual_val_u64 = pool.try_get_value(&cold).unwrap_or(0);
+
+            // Fallback: if pool uninitialized, treat raw Alpha share as value.
+            let val_u64 = if actual_val_u64 == 0 {
+                share_u64f64.saturating_to_num::<u64>()
+            } else {
+                actual_val_u64
+            };
+
+            if val_u64 > 0 {
+                let val_u128 = val_u64 as u128;
+                total_alpha_value_u128 = total_alpha_value_u128.saturating_add(val_u128);
+                stakers.push((cold.clone(), val_u128));
+            }
+            stakes.push((hot, cold));
+        }
+
+        // Pro‑rata distribution of the pot by α value (largest‑remainder).
+        let pot: TaoCurrency = SubnetTAO::<T>::get(netuid);
+        let pot_u128: u128 = pot.to_u64() as u128;
+        let mut payouts: Vec<(T::AccountId, TaoCurrency)> = Vec::new();
+
+        if pot_u128 > 0 && total_alpha_value_u128 > 0 && !stakers.is_empty() {
+            let mut portions: Vec<(T::AccountId, u64, u128)> = Vec::with_capacity(stakers.len());
+            let mut distributed: u128 = 0;
+
+            for (cold, alpha_val) in stakers {
+                let prod: u128 = pot_u128.saturating_mul(alpha_val);
+                let share_u128: u128 = prod.checked_div(total_alpha_value_u128).unwrap_or_default();
+                let share_u64: u64 = share_u128.min(u128::from(u64::MAX)) as u64;
+                distributed = distributed.saturating_add(u128::from(share_u64));
+
+                let rem: u128 = prod.checked_rem(total_alpha_value_u128).unwrap_or_default();
+                portions.push((cold, share_u64, rem));
+            }
+
+            let leftover: u128 = pot_u128.saturating_sub(distributed);
+            if leftover > 0 {
+                let mut by_remainder: Vec<usize> = (0..portions.len()).collect();
+                by_remainder.sort_by(|a, b| {
+                    let rem = |i: &usize| portions.get(*i).map(|(_, _, rem)| *rem);
+                    rem(b).cmp(&rem(a))
+                });
+                let give: usize = core::cmp::min(leftover, portions.len() as u128) as usize;
+                for i in by_remainder.into_iter().take(give) {
+                    if let Some((_, share, _)) = portions.get_mut(i) {
+                        *share = share.saturating_add(1);
+                    }
+                }
+            }
+
+            payouts = portions
+                .into_iter()
+                .map(|(cold, share, _)| (cold, TaoCurrency::from(share)))
+                .collect();
+        }
+
+        DissolutionSettlement {
+            netuid,
+            pot,
+            payouts,
+            stakes,
+            owner,
+            owner_refund,
+        }
+    }
+
+    /// Replays the settlement of the dissolution of `netuid` at `block` and checks the balance
+    /// changes of the dissolution against it. The state is left as it was, and the settlement
+    /// is returned if every balance change matches.
+    #[cfg(any(feature = "try-runtime", test))]
+    pub fn replay_dissolution_settlement(
+        netuid: NetUid,
+        block: frame_system::pallet_prelude::BlockNumberFor<T>,
+    ) -> Result<DissolutionSettlement<T::AccountId>, sp_runtime::TryRuntimeError> {
+        use frame_support::storage::{TransactionOutcome, transactional};
+
+        transactional::with_transaction(|| {
+            let result: Result<_, sp_runtime::TryRuntimeError> = (|| {
+                ensure!(
+                    Self::if_subnet_exist(netuid) && !netuid.is_root(),
+                    "subnet to replay does not exist"
+                );
+                frame_system::Pallet::<T>::set_block_number(block);
+
+                // Liquidity providers are paid out before the pot is settled
+                T::SwapInterface::dissolve_all_liquidity_providers(netuid)
+                    .map_err(|_| "liquidity providers could not be dissolved")?;
+
+                // Payouts restaked into root are credited to the root stake of the coldkey.
+                let holdings = |coldkey: &T::AccountId| -> (u64, u64) {
+                    let restaked = match DissolutionPayoutPreference::<T>::get(coldkey) {
+                        DissolutionPayout::RootStake(hotkey) => {
+                            Self::get_stake_for_hotkey_and_coldkey_on_subnet(
+                                &hotkey,
+                                coldkey,
+                                NetUid::ROOT,
+                            )
+                            .to_u64()
+                        }
+                        DissolutionPayout::Balance => 0,
+                    };
+                    (Self::get_coldkey_balance(coldkey), restaked)
+                };
+
+                let settlement = Self::get_dissolution_settlement(netuid);
+                let credits = settlement.credits();
+                let holdings_before: BTreeMap<T::AccountId, (u64, u64)> = credits
+                    .keys()
+                    .map(|coldkey| (coldkey.clone(), holdings(coldkey)))
+                    .collect();
+
+                Self::destroy_alpha_in_out_stakes(netuid)
+                    .map_err(|_| "dissolution settlement failed")?;
+
+                for (coldkey, credit) in credits.iter() {
+                    let (balance_before, restaked_before) =
+                        holdings_before.get(coldkey).copied().unwrap_or_default();
+                    let (balance, restaked) = holdings(coldkey);
+                    let restaked = restaked.saturating_sub(restaked_before);
+                    let received = balance
+                        .saturating_sub(balance_before)
+                        .saturating_add(restaked);
+                    // The share pool of the root stake may round the restaked payout down a rao.
+                    let rounding = u64::from(restaked > 0);
+                    if received > credit.to_u64()
+                        || received.saturating_add(rounding) < credit.to_u64()
+                    {
+                        log::error!(
+                            "Dissolution settlement of netuid {netuid:?}: {coldkey:?} received {received} instead of {credit:?}"
+                        );
+                        return Err("dissolution payout does not match its settlement".into());
+                    }
+                }
+                Ok(settlement)
+            })();
+            TransactionOutcome::Rollback(result)
+        })
+    }
+}
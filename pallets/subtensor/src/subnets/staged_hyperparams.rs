@@ -0,0 +1,279 @@
+//! Two-phase hyperparameter updates for subnets.
+//!
+//! The subnet owner stages a bundle of epoch hyperparameter changes with
+//! `stage_hyperparameters`. On the next epoch of the subnet, the bundle is shadow-applied: the
+//! epoch is run once more with the staged values inside a storage transaction that is rolled
+//! back, and only the resulting consensus vectors are kept as a preview. Payouts are always
+//! computed with the live hyperparameters.
+//!
+//! Once a preview exists, the owner either commits the bundle, which applies every change
+//! atomically, or rolls it back, which discards it without touching the live values.
+
+use super::*;
+use crate::utils::misc::MAX_OWNER_BONDS_MOVING_AVERAGE;
+use codec::DecodeWithMemTracking;
+use frame_support::storage::{TransactionOutcome, transactional};
+use subtensor_runtime_common::{AlphaCurrency, NetUid, NetUidStorageIndex};
+
+/// Maximum number of changes in a staged hyperparameter bundle.
+pub const MAX_STAGED_HYPERPARAMETERS: u32 = 16;
+
+/// A single hyperparameter change that can be staged for a subnet.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum StagedHyperparameter {
+    Kappa(u16),
+    Rho(u16),
+    ActivityCutoff(u16),
+    BondsMovingAverage(u64),
+    BondsPenalty(u16),
+    LiquidAlphaEnabled(bool),
+    AlphaValues(u16, u16),
+    AlphaSigmoidSteepness(i16),
+    Yuma3Enabled(bool),
+}
+
+impl StagedHyperparameter {
+    /// The owner rate-limit key this change is accounted under.
+    pub fn hyperparameter(&self) -> Hyperparameter {
+        match self {
+            Self::Kappa(_) => Hyperparameter::Kappa,
+            Self::Rho(_) => Hyperparameter::Rho,
+            Self::ActivityCutoff(_) => Hyperparameter::ActivityCutoff,
+            Self::BondsMovingAverage(_) => Hyperparameter::BondsMovingAverage,
+            Self::BondsPenalty(_) => Hyperparameter::BondsPenalty,
+            Self::LiquidAlphaEnabled(_) => Hyperparameter::LiquidAlphaEnabled,
+            Self::AlphaValues(_, _) => Hyperparameter::AlphaValues,
+            Self::AlphaSigmoidSteepness(_) => Hyperparameter::AlphaSigmoidSteepness,
+            Self::Yuma3Enabled(_) => Hyperparameter::Yuma3Enabled,
+        }
+    }
+}
+
+/// Consensus vectors of the shadow epoch run with a staged bundle applied.
+#[freeze_struct("364d532089a4aaeb")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct StagedHyperparametersPreview {
+    /// Block at which the shadow epoch was run.
+    pub evaluated_at: u64,
+    /// UID-indexed combined emission.
+    pub emission: Vec<AlphaCurrency>,
+    /// UID-indexed incentive of the main mechanism.
+    pub incentive: Vec<u16>,
+    /// UID-indexed dividends.
+    pub dividends: Vec<u16>,
+    /// UID-indexed consensus.
+    pub consensus: Vec<u16>,
+    /// UID-indexed validator trust.
+    pub validator_trust: Vec<u16>,
+    /// UID-indexed validator permits.
+    pub validator_permit: Vec<bool>,
+}
+
+/// A bundle of hyperparameter changes staged by the subnet owner.
+#[freeze_struct("b563a4e022f5de1d")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct StagedHyperparameterBundle {
+    /// The staged changes, applied in order on commit.
+    pub changes: Vec<StagedHyperparameter>,
+    /// Block at which the bundle was staged.
+    pub staged_at: u64,
+    /// Shadow epoch results, available after the next epoch of the subnet.
+    pub preview: Option<StagedHyperparametersPreview>,
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn do_stage_hyperparameters(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        changes: Vec<StagedHyperparameter>,
+    ) -> DispatchResult {
+        let maybe_owner = Self::ensure_subnet_owner_or_root(origin, netuid)?;
+        ensure!(
+            !netuid.is_root() && Self::if_subnet_exist(netuid),
+            Error::<T>::SubnetNotExists
+        );
+        ensure!(
+            !changes.is_empty() && changes.len() <= MAX_STAGED_HYPERPARAMETERS as usize,
+            Error::<T>::InvalidValue
+        );
+        for change in changes.iter() {
+            Self::validate_staged_hyperparameter(change, maybe_owner.is_none())?;
+        }
+
+        let count = changes.len() as u32;
+        StagedHyperparameters::<T>::insert(
+            netuid,
+            StagedHyperparameterBundle {
+                changes,
+                staged_at: Self::get_current_block_as_u64(),
+                preview: None,
+            },
+        );
+
+        Self::deposit_event(Event::HyperparametersStaged { netuid, count });
+        Ok(())
+    }
+
+    pub fn do_commit_staged_hyperparameters(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+    ) -> DispatchResult {
+        let bundle =
+            StagedHyperparameters::<T>::get(netuid).ok_or(Error::<T>::NoStagedHyperparameters)?;
+        let limits: Vec<TransactionType> = bundle
+            .changes
+            .iter()
+            .map(|change| change.hyperparameter().into())
+            .collect();
+
+        let maybe_owner = Self::ensure_sn_owner_or_root_with_limits(origin, netuid, &limits)?;
+        ensure!(
+            bundle.preview.is_some(),
+            Error::<T>::StagedHyperparametersNotEvaluated
+        );
+
+        // Values were validated when staged, but e.g. `MinActivityCutoff` may have moved since.
+        let is_root = maybe_owner.owner.is_none();
+        for change in bundle.changes.iter() {
+            Self::validate_staged_hyperparameter(change, is_root)?;
+            Self::apply_staged_hyperparameter(netuid, change);
+        }
+        StagedHyperparameters::<T>::remove(netuid);
+        Self::record_owner_rl(maybe_owner, netuid, &limits);
+
+        Self::deposit_event(Event::StagedHyperparametersCommitted { netuid });
+        Ok(())
+    }
+
+    pub fn do_rollback_staged_hyperparameters(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner_or_root(origin, netuid)?;
+        ensure!(
+            StagedHyperparameters::<T>::contains_key(netuid),
+            Error::<T>::NoStagedHyperparameters
+        );
+
+        StagedHyperparameters::<T>::remove(netuid);
+
+        Self::deposit_event(Event::StagedHyperparametersRolledBack { netuid });
+        Ok(())
+    }
+
+    /// An estimate of the weight of an epoch of a subnet of `n` uids: reading the stake, weight
+    /// and bond rows of every uid, and the matrix arithmetic over them.
+    pub fn shadow_epoch_weight(n: u16) -> Weight {
+        let n = u64::from(n);
+        Weight::from_parts(n.saturating_mul(n).saturating_mul(10_000), 0)
+            .saturating_add(T::DbWeight::get().reads(n.saturating_mul(5).saturating_add(16)))
+    }
+
+    /// Runs the epoch of `netuid` with its staged bundle applied and records the result as the
+    /// bundle preview. All state changes made by the shadow epoch are rolled back, and its
+    /// weight is registered on top of the weight of the block step.
+    ///
+    /// Does nothing if no bundle is staged or the bundle already has a preview.
+    pub fn maybe_run_shadow_epoch(netuid: NetUid, rao_emission: AlphaCurrency) {
+        let Some(mut bundle) = StagedHyperparameters::<T>::get(netuid) else {
+            return;
+        };
+        if bundle.preview.is_some() {
+            return;
+        }
+        frame_system::Pallet::<T>::register_extra_weight_unchecked(
+            Self::shadow_epoch_weight(Self::get_subnetwork_n(netuid))
+                .saturating_add(T::DbWeight::get().writes(1)),
+            DispatchClass::Mandatory,
+        );
+
+        let preview = transactional::with_transaction(|| {
+            for change in bundle.changes.iter() {
+                Self::apply_staged_hyperparameter(netuid, change);
+            }
+            let _ = Self::epoch_with_mechanisms(netuid, rao_emission);
+
+            let preview = StagedHyperparametersPreview {
+                evaluated_at: Self::get_current_block_as_u64(),
+                emission: Emission::<T>::get(netuid),
+                incentive: Incentive::<T>::get(NetUidStorageIndex::from(netuid)),
+                dividends: Dividends::<T>::get(netuid),
+                consensus: Consensus::<T>::get(netuid),
+                validator_trust: ValidatorTrust::<T>::get(netuid),
+                validator_permit: ValidatorPermit::<T>::get(netuid),
+            };
+            TransactionOutcome::Rollback(Ok::<_, DispatchError>(preview))
+        });
+
+        if let Ok(preview) = preview {
+            bundle.preview = Some(preview);
+            StagedHyperparameters::<T>::insert(netuid, bundle);
+            Self::deposit_event(Event::StagedHyperparametersEvaluated { netuid });
+        }
+    }
+
+    pub fn get_staged_hyperparameters(netuid: NetUid) -> Option<StagedHyperparameterBundle> {
+        StagedHyperparameters::<T>::get(netuid)
+    }
+
+    /// Checks `change` as the setter of the hyperparameter checks it for root, if `is_root`, or
+    /// for the subnet owner.
+    fn validate_staged_hyperparameter(
+        change: &StagedHyperparameter,
+        is_root: bool,
+    ) -> DispatchResult {
+        match change {
+            StagedHyperparameter::ActivityCutoff(activity_cutoff) => {
+                ensure!(
+                    *activity_cutoff >= MinActivityCutoff::<T>::get(),
+                    Error::<T>::ActivityCutoffTooLow
+                );
+            }
+            StagedHyperparameter::AlphaValues(alpha_low, alpha_high) => {
+                Self::ensure_alpha_values_in_bounds(*alpha_low, *alpha_high)?;
+            }
+            StagedHyperparameter::BondsMovingAverage(bonds_moving_average) => {
+                ensure!(
+                    is_root || *bonds_moving_average <= MAX_OWNER_BONDS_MOVING_AVERAGE,
+                    Error::<T>::BondsMovingAverageMaxReached
+                );
+            }
+            StagedHyperparameter::AlphaSigmoidSteepness(steepness) => {
+                ensure!(
+                    is_root || *steepness >= 0,
+                    Error::<T>::NegativeSigmoidSteepness
+                );
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn apply_staged_hyperparameter(netuid: NetUid, change: &StagedHyperparameter) {
+        match change {
+            StagedHyperparameter::Kappa(kappa) => Self::set_kappa(netuid, *kappa),
+            StagedHyperparameter::Rho(rho) => Self::set_rho(netuid, *rho),
+            StagedHyperparameter::ActivityCutoff(activity_cutoff) => {
+                Self::set_activity_cutoff(netuid, *activity_cutoff)
+            }
+            StagedHyperparameter::BondsMovingAverage(bonds_moving_average) => {
+                Self::set_bonds_moving_average(netuid, *bonds_moving_average)
+            }
+            StagedHyperparameter::BondsPenalty(bonds_penalty) => {
+                Self::set_bonds_penalty(netuid, *bonds_penalty)
+            }
+            StagedHyperparameter::LiquidAlphaEnabled(enabled) => {
+                Self::set_liquid_alpha_enabled(netuid, *enabled)
+            }
+            StagedHyperparameter::AlphaValues(alpha_low, alpha_high) => {
+                AlphaValues::<T>::insert(netuid, (*alpha_low, *alpha_high))
+            }
+            StagedHyperparameter::AlphaSigmoidSteepness(steepness) => {
+                Self::set_alpha_sigmoid_steepness(netuid, *steepness)
+            }
+            StagedHyperparameter::Yuma3Enabled(enabled) => {
+                Self::set_yuma3_enabled(netuid, *enabled)
+            }
+        }
+    }
+}
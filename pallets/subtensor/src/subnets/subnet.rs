@@ -124,6 +124,8 @@ impl<T: Config> Pallet<T> {
             !Self::hotkey_account_exists(hotkey) || Self::coldkey_owns_hotkey(&coldkey, hotkey),
             Error::<T>::NonAssociatedColdKey
         );
+        Self::ensure_not_reserved_account(&coldkey)?;
+        Self::ensure_not_reserved_account(hotkey)?;
 
         // --- 3. Ensure the mechanism is Dynamic.
         ensure!(mechid == 1, Error::<T>::MechanismDoesNotExist);
@@ -216,7 +218,7 @@ impl<T: Config> Pallet<T> {
         // Core pool + ownership
         SubnetTAO::<T>::insert(netuid_to_register, pool_initial_tao);
         SubnetAlphaIn::<T>::insert(netuid_to_register, pool_initial_alpha);
-        SubnetOwner::<T>::insert(netuid_to_register, coldkey.clone());
+        Self::set_subnet_owner(netuid_to_register, &coldkey);
         SubnetOwnerHotkey::<T>::insert(netuid_to_register, hotkey.clone());
         SubnetLocked::<T>::insert(netuid_to_register, actual_tao_lock_amount);
         SubnetTaoProvided::<T>::insert(netuid_to_register, TaoCurrency::ZERO);
@@ -418,6 +420,9 @@ impl<T: Config> Pallet<T> {
         // Ensure that the subnet exists.
         ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
 
+        // Ensure the hotkey is not a protocol account.
+        Self::ensure_not_reserved_account(hotkey)?;
+
         // Rate limit: 1 call per week
         ensure!(
             TransactionType::SetSNOwnerHotkey.passes_rate_limit_on_subnet::<T>(
@@ -0,0 +1,45 @@
+//! Accounting of the tokens a subnet takes out of circulation.
+//!
+//! `RAORecycledForRegistration` is seeded with the lock of the subnet and can be set by root, so
+//! it does not tell how much TAO the registrations of a subnet actually burned. The pallet keeps
+//! running totals instead of the TAO burned by registrations and of the alpha emission recycled
+//! on each subnet, from the owner incentive of a recycling subnet and the childkey burn take.
+//! Both are cleared when the subnet is dissolved.
+
+use super::*;
+use subtensor_runtime_common::{AlphaCurrency, NetUid, TaoCurrency};
+
+/// The tokens a subnet took out of circulation since it was registered.
+#[freeze_struct("1bbd4f937f69e504")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct SubnetTokenAccounting {
+    /// The TAO burned by registrations on the subnet.
+    pub registration_burned_tao: TaoCurrency,
+    /// The alpha emission recycled on the subnet.
+    pub recycled_emission: AlphaCurrency,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Adds `tao` burned by a registration on `netuid` to its total.
+    pub fn record_registration_burn(netuid: NetUid, tao: TaoCurrency) {
+        RegistrationBurnedTao::<T>::mutate(netuid, |total| *total = total.saturating_add(tao));
+    }
+
+    /// Recycles `amount` of the alpha emission of `netuid` and adds it to its total.
+    pub fn recycle_subnet_emission(netuid: NetUid, amount: AlphaCurrency) {
+        Self::recycle_subnet_alpha(netuid, amount);
+        RecycledEmission::<T>::mutate(netuid, |total| *total = total.saturating_add(amount));
+    }
+
+    /// The token accounting of `netuid`, `None` if the subnet does not exist.
+    pub fn get_subnet_token_accounting(netuid: NetUid) -> Option<SubnetTokenAccounting> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        Some(SubnetTokenAccounting {
+            registration_burned_tao: RegistrationBurnedTao::<T>::get(netuid),
+            recycled_emission: RecycledEmission::<T>::get(netuid),
+        })
+    }
+}
@@ -0,0 +1,210 @@
+//! Subnet treasuries.
+//!
+//! Subnets fund bounties and infrastructure out of the owner cut, which is paid to the owner like
+//! any other stake and leaves no trace of how it is spent. The owner of a subnet can redirect a
+//! share of its owner cut into the treasury of the subnet instead, an on-chain alpha balance only
+//! spent with `spend_subnet_treasury`.
+//!
+//! The owner proposes a spend of alpha from the treasury, staked to a hotkey for a beneficiary
+//! coldkey. If the subnet has an approval threshold, the spend waits for hotkeys holding at least
+//! that share of the alpha of the subnet to approve it, otherwise it is made right away. A subnet
+//! has at most one spend pending at a time, a new proposal replaces it. The approval threshold of a
+//! subnet can only be raised, so that stakeholders can rely on it.
+//!
+//! The owner cut of leased subnets is owed to the contributors of the lease and is never
+//! redirected. The treasury of a dissolved subnet is staked to its owner before the dissolution.
+
+use super::*;
+use safe_math::*;
+use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid};
+
+#[freeze_struct("c793b656f2ef3df5")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SubnetTreasurySpend<AccountId> {
+    /// The id of the spend, unique on the subnet.
+    pub id: u32,
+    /// The hotkey the alpha is staked to.
+    pub hotkey: AccountId,
+    /// The coldkey the alpha is staked for.
+    pub beneficiary: AccountId,
+    /// The alpha spent.
+    pub amount: AlphaCurrency,
+    /// The hotkeys that approved the spend.
+    pub approvals: Vec<AccountId>,
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn get_subnet_treasury_share(netuid: NetUid) -> u16 {
+        SubnetTreasuryShare::<T>::get(netuid)
+    }
+
+    pub fn get_subnet_treasury_approval_threshold(netuid: NetUid) -> u16 {
+        SubnetTreasuryApprovalThreshold::<T>::get(netuid)
+    }
+
+    /// Redirects `share` of the owner cut of `netuid`, in `u16::MAX` parts, into its treasury,
+    /// whose spends must be approved by hotkeys holding `approval_threshold` of the alpha of the
+    /// subnet, in `u16::MAX` parts. The approval threshold cannot be lowered.
+    pub fn set_subnet_treasury(
+        netuid: NetUid,
+        share: u16,
+        approval_threshold: u16,
+    ) -> Result<(), Error<T>> {
+        ensure!(
+            approval_threshold >= Self::get_subnet_treasury_approval_threshold(netuid),
+            Error::<T>::SubnetTreasuryApprovalLowered
+        );
+        SubnetTreasuryShare::<T>::insert(netuid, share);
+        SubnetTreasuryApprovalThreshold::<T>::insert(netuid, approval_threshold);
+        Self::deposit_event(Event::SubnetTreasurySet {
+            netuid,
+            share,
+            approval_threshold,
+        });
+        Ok(())
+    }
+
+    /// Moves the treasury share of `netuid` out of `owner_cut` into its treasury, and returns the
+    /// rest of the owner cut.
+    pub fn fund_subnet_treasury(netuid: NetUid, owner_cut: AlphaCurrency) -> AlphaCurrency {
+        if SubnetUidToLeaseId::<T>::contains_key(netuid) {
+            return owner_cut;
+        }
+        let funds = u64::from(owner_cut)
+            .saturating_mul(u64::from(Self::get_subnet_treasury_share(netuid)))
+            .safe_div(u64::from(u16::MAX));
+        let funds = AlphaCurrency::from(funds);
+        if !funds.is_zero() {
+            SubnetTreasury::<T>::mutate(netuid, |treasury| {
+                *treasury = treasury.saturating_add(funds)
+            });
+        }
+        owner_cut.saturating_sub(funds)
+    }
+
+    /// Proposes, for the owner of `netuid`, to stake `amount` of the treasury of the subnet to
+    /// `hotkey` for `beneficiary`. The spend is made right away if the subnet has no approval
+    /// threshold, and replaces the spend pending otherwise.
+    pub fn do_spend_subnet_treasury(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        hotkey: T::AccountId,
+        beneficiary: T::AccountId,
+        amount: AlphaCurrency,
+    ) -> DispatchResult {
+        Self::ensure_subnet_owner(origin, netuid)?;
+        ensure!(
+            Self::hotkey_account_exists(&hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+        ensure!(
+            !amount.is_zero() && amount <= SubnetTreasury::<T>::get(netuid),
+            Error::<T>::InsufficientSubnetTreasury
+        );
+
+        let id = NextSubnetTreasurySpendId::<T>::get(netuid);
+        NextSubnetTreasurySpendId::<T>::insert(netuid, id.saturating_add(1));
+        let spend = SubnetTreasurySpend {
+            id,
+            hotkey,
+            beneficiary,
+            amount,
+            approvals: Vec::new(),
+        };
+        Self::deposit_event(Event::SubnetTreasurySpendProposed {
+            netuid,
+            id,
+            hotkey: spend.hotkey.clone(),
+            beneficiary: spend.beneficiary.clone(),
+            amount,
+        });
+        Self::try_execute_subnet_treasury_spend(netuid, spend)
+    }
+
+    /// Approves, for the signing hotkey, the spend `id` pending on the treasury of `netuid`.
+    pub fn do_approve_subnet_treasury_spend(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        id: u32,
+    ) -> DispatchResult {
+        let hotkey = ensure_signed(origin)?;
+        let mut spend = SubnetTreasurySpends::<T>::get(netuid)
+            .filter(|spend| spend.id == id)
+            .ok_or(Error::<T>::NoSubnetTreasurySpend)?;
+        ensure!(
+            !spend.approvals.contains(&hotkey),
+            Error::<T>::SubnetTreasurySpendAlreadyApproved
+        );
+        ensure!(
+            !Self::get_stake_for_hotkey_on_subnet(&hotkey, netuid).is_zero(),
+            Error::<T>::NotEnoughStakeToApprove
+        );
+
+        spend.approvals.push(hotkey.clone());
+        Self::deposit_event(Event::SubnetTreasurySpendApproved { netuid, id, hotkey });
+        Self::try_execute_subnet_treasury_spend(netuid, spend)
+    }
+
+    /// Stakes the treasury of `netuid` to its owner.
+    pub fn release_subnet_treasury(netuid: NetUid) {
+        let treasury = SubnetTreasury::<T>::take(netuid);
+        SubnetTreasurySpends::<T>::remove(netuid);
+        if treasury.is_zero() {
+            return;
+        }
+        if let (Ok(coldkey), Ok(hotkey)) = (
+            SubnetOwner::<T>::try_get(netuid),
+            SubnetOwnerHotkey::<T>::try_get(netuid),
+        ) {
+            Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey, &coldkey, netuid, treasury,
+            );
+        }
+    }
+
+    /// Makes `spend` if the hotkeys that approved it hold the approval threshold of the alpha of
+    /// `netuid`, and leaves it pending otherwise.
+    fn try_execute_subnet_treasury_spend(
+        netuid: NetUid,
+        spend: SubnetTreasurySpend<T::AccountId>,
+    ) -> DispatchResult {
+        let threshold = Self::get_subnet_treasury_approval_threshold(netuid);
+        let required = u128::from(u64::from(SubnetAlphaOut::<T>::get(netuid)))
+            .saturating_mul(u128::from(threshold))
+            .safe_div(u128::from(u16::MAX));
+        let approved = spend
+            .approvals
+            .iter()
+            .fold(AlphaCurrency::ZERO, |acc, hotkey| {
+                acc.saturating_add(Self::get_stake_for_hotkey_on_subnet(hotkey, netuid))
+            });
+        if u128::from(u64::from(approved)) < required {
+            SubnetTreasurySpends::<T>::insert(netuid, spend);
+            return Ok(());
+        }
+
+        SubnetTreasury::<T>::try_mutate(netuid, |treasury| -> DispatchResult {
+            ensure!(
+                spend.amount <= *treasury,
+                Error::<T>::InsufficientSubnetTreasury
+            );
+            *treasury = treasury.saturating_sub(spend.amount);
+            Ok(())
+        })?;
+        SubnetTreasurySpends::<T>::remove(netuid);
+        Self::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &spend.hotkey,
+            &spend.beneficiary,
+            netuid,
+            spend.amount,
+        );
+        Self::deposit_event(Event::SubnetTreasurySpent {
+            netuid,
+            id: spend.id,
+            hotkey: spend.hotkey,
+            beneficiary: spend.beneficiary,
+            amount: spend.amount,
+        });
+        Ok(())
+    }
+}
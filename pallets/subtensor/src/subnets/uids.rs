@@ -1,4 +1,6 @@
 use super::*;
+use crate::subnets::neuron_status::NeuronStatus;
+use crate::subnets::weight_row::WeightRow;
 use frame_support::storage::IterableStorageDoubleMap;
 use sp_runtime::Percent;
 use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
@@ -36,17 +38,22 @@ impl<T: Config> Pallet<T> {
             // Set weights FOR the neuron_uid to 0
             let all_uids: Vec<u16> = Weights::<T>::iter_key_prefix(netuid_index).collect();
             for uid in all_uids {
-                Weights::<T>::mutate(netuid_index, uid, |weight_vec: &mut Vec<(u16, u16)>| {
+                Weights::<T>::mutate(netuid_index, uid, |row: &mut WeightRow| {
+                    let mut weight_vec = row.to_vec();
                     for (weight_uid, w) in weight_vec.iter_mut() {
                         if *weight_uid == neuron_uid {
                             *w = 0;
                         }
                     }
+                    *row = weight_vec.into();
                 });
             }
         }
         Dividends::<T>::mutate(netuid, |v| Self::set_element_at(v, neuron_index, 0));
         StakeWeight::<T>::mutate(netuid, |v| Self::set_element_at(v, neuron_index, 0));
+        LastHeartbeat::<T>::remove(netuid, neuron_uid);
+        WeightsSetRateLimitOverride::<T>::remove(netuid, neuron_uid);
+        NeuronImmunityOverride::<T>::remove(netuid, neuron_uid);
     }
 
     /// Replace the neuron under this uid.
@@ -75,6 +82,9 @@ impl<T: Config> Pallet<T> {
         }
 
         // 2. Remove previous set memberships.
+        Self::settle_pruned_registration_deposit(netuid, uid_to_replace);
+        Self::set_neuron_status_for_uid(netuid, uid_to_replace, &old_hotkey, NeuronStatus::Pruned);
+        Self::record_churn_deregistration(netuid, &old_hotkey);
         Uids::<T>::remove(netuid, old_hotkey.clone());
         AssociatedEvmAddress::<T>::remove(netuid, uid_to_replace);
         IsNetworkMember::<T>::remove(old_hotkey.clone(), netuid);
@@ -86,6 +96,7 @@ impl<T: Config> Pallet<T> {
         Keys::<T>::insert(netuid, uid_to_replace, new_hotkey.clone()); // Make hotkey - uid association.
         Uids::<T>::insert(netuid, new_hotkey.clone(), uid_to_replace); // Make uid - hotkey association.
         BlockAtRegistration::<T>::insert(netuid, uid_to_replace, block_number); // Fill block at registration.
+        NeuronLifetimeEmission::<T>::remove(netuid, uid_to_replace); // Restart the lifetime emission.
         IsNetworkMember::<T>::insert(new_hotkey.clone(), netuid, true); // Fill network is member.
 
         // 4. Clear neuron certificates
@@ -96,6 +107,14 @@ impl<T: Config> Pallet<T> {
 
         // 5a. reset axon info for the new uid.
         Axons::<T>::remove(netuid, old_hotkey);
+
+        // 6. Start the lifecycle of the new neuron.
+        Self::set_neuron_status_for_uid(
+            netuid,
+            uid_to_replace,
+            new_hotkey,
+            Self::get_registered_neuron_status(netuid, uid_to_replace),
+        );
     }
 
     /// Appends the uid to the network.
@@ -130,6 +149,126 @@ impl<T: Config> Pallet<T> {
         Uids::<T>::insert(netuid, new_hotkey.clone(), next_uid); // Make uid - hotkey association.
         BlockAtRegistration::<T>::insert(netuid, next_uid, block_number); // Fill block at registration.
         IsNetworkMember::<T>::insert(new_hotkey.clone(), netuid, true); // Fill network is member.
+
+        // 5. Start the lifecycle of the new neuron.
+        let status = Self::get_registered_neuron_status(netuid, next_uid);
+        NeuronStatuses::<T>::mutate(netuid, |v| v.push(status));
+    }
+
+    /// Removes the neuron under `uid` from `netuid`. The last neuron of the subnet is moved to
+    /// `uid`, so the uids stay consecutive, and the weights and bonds are remapped accordingly.
+    pub fn remove_neuron(netuid: NetUid, uid: u16) {
+        let n = Self::get_subnetwork_n(netuid);
+        if uid >= n {
+            return;
+        }
+        let last_uid = n.saturating_sub(1);
+        let mechanisms_count = MechanismCountCurrent::<T>::get(netuid).into();
+        log::debug!("remove_neuron( netuid: {netuid:?} | uid: {uid:?} | last_uid: {last_uid:?} ) ");
+
+        // 1. Remove hotkey related storage items.
+        if let Ok(hotkey) = Keys::<T>::try_get(netuid, uid) {
+            Self::set_neuron_status_for_uid(netuid, uid, &hotkey, NeuronStatus::Pruned);
+            Self::record_churn_deregistration(netuid, &hotkey);
+            Uids::<T>::remove(netuid, &hotkey);
+            IsNetworkMember::<T>::remove(&hotkey, netuid);
+            LastHotkeyEmissionOnNetuid::<T>::remove(&hotkey, netuid);
+            AlphaDividendsPerSubnet::<T>::remove(netuid, &hotkey);
+            DelegateEmissionWindows::<T>::remove(netuid, &hotkey);
+            TaoDividendsPerSubnet::<T>::remove(netuid, &hotkey);
+            Axons::<T>::remove(netuid, &hotkey);
+            NeuronCertificates::<T>::remove(netuid, &hotkey);
+            Prometheus::<T>::remove(netuid, &hotkey);
+        }
+
+        // 2. Remove uid related storage items.
+        #[allow(unknown_lints)]
+        Keys::<T>::remove(netuid, uid);
+        BlockAtRegistration::<T>::remove(netuid, uid);
+        NeuronLifetimeEmission::<T>::remove(netuid, uid);
+        AssociatedEvmAddress::<T>::remove(netuid, uid);
+        LastHeartbeat::<T>::remove(netuid, uid);
+        WeightsSetRateLimitOverride::<T>::remove(netuid, uid);
+        NeuronImmunityOverride::<T>::remove(netuid, uid);
+        RegistrationDeposits::<T>::remove(netuid, uid);
+
+        // 3. Move the last neuron to the uid in the per-uid vectors.
+        let index: usize = uid.into();
+        Emission::<T>::mutate(netuid, |v| Self::swap_remove_at(v, index));
+        Rank::<T>::mutate(netuid, |v| Self::swap_remove_at(v, index));
+        Trust::<T>::mutate(netuid, |v| Self::swap_remove_at(v, index));
+        Active::<T>::mutate(netuid, |v| Self::swap_remove_at(v, index));
+        NeuronStatuses::<T>::mutate(netuid, |v| Self::swap_remove_at(v, index));
+        Consensus::<T>::mutate(netuid, |v| Self::swap_remove_at(v, index));
+        Dividends::<T>::mutate(netuid, |v| Self::swap_remove_at(v, index));
+        PruningScores::<T>::mutate(netuid, |v| Self::swap_remove_at(v, index));
+        ValidatorTrust::<T>::mutate(netuid, |v| Self::swap_remove_at(v, index));
+        ValidatorPermit::<T>::mutate(netuid, |v| Self::swap_remove_at(v, index));
+        StakeWeight::<T>::mutate(netuid, |v| Self::swap_remove_at(v, index));
+
+        // 4. Move the last neuron to the uid in the per-uid maps.
+        if uid != last_uid {
+            Keys::<T>::swap(netuid, last_uid, netuid, uid);
+            AssociatedEvmAddress::<T>::swap(netuid, last_uid, netuid, uid);
+            LastHeartbeat::<T>::swap(netuid, last_uid, netuid, uid);
+            WeightsSetRateLimitOverride::<T>::swap(netuid, last_uid, netuid, uid);
+            NeuronImmunityOverride::<T>::swap(netuid, last_uid, netuid, uid);
+            BlockAtRegistration::<T>::swap(netuid, last_uid, netuid, uid);
+            NeuronLifetimeEmission::<T>::swap(netuid, last_uid, netuid, uid);
+            RegistrationDeposits::<T>::swap(netuid, last_uid, netuid, uid);
+            if let Ok(moved_hotkey) = Keys::<T>::try_get(netuid, uid) {
+                Uids::<T>::insert(netuid, moved_hotkey, uid);
+            }
+        }
+
+        // 5. Drop the connections to the uid and point those to the last neuron at the uid.
+        let remap = |target_uid: &mut u16| {
+            if *target_uid == uid {
+                return false;
+            }
+            if *target_uid == last_uid {
+                *target_uid = uid;
+            }
+            true
+        };
+        for mecid in 0..mechanisms_count {
+            let netuid_index = Self::get_mechanism_storage_index(netuid, mecid.into());
+            Incentive::<T>::mutate(netuid_index, |v| Self::swap_remove_at(v, index));
+            LastUpdate::<T>::mutate(netuid_index, |v| Self::swap_remove_at(v, index));
+
+            Weights::<T>::remove(netuid_index, uid);
+            Bonds::<T>::remove(netuid_index, uid);
+            if uid != last_uid {
+                Weights::<T>::swap(netuid_index, last_uid, netuid_index, uid);
+                Bonds::<T>::swap(netuid_index, last_uid, netuid_index, uid);
+            }
+
+            let rows: Vec<u16> = Weights::<T>::iter_key_prefix(netuid_index).collect();
+            for row_uid in rows {
+                Weights::<T>::mutate(netuid_index, row_uid, |row| {
+                    let mut weights = row.to_vec();
+                    weights.retain_mut(|(target_uid, _weight)| remap(target_uid));
+                    *row = weights.into();
+                });
+            }
+            let rows: Vec<u16> = Bonds::<T>::iter_key_prefix(netuid_index).collect();
+            for row_uid in rows {
+                Bonds::<T>::mutate(netuid_index, row_uid, |bonds| {
+                    bonds.retain_mut(|(target_uid, _bond)| remap(target_uid));
+                    bonds.sort_by_key(|(target_uid, _bond)| *target_uid);
+                });
+            }
+        }
+
+        // 6. Shrink the subnet by the removed uid.
+        SubnetworkN::<T>::insert(netuid, last_uid);
+    }
+
+    /// Removes the element at the given position, if it exists, moving the last element to it.
+    pub fn swap_remove_at<N>(vec: &mut Vec<N>, position: usize) {
+        if position < vec.len() {
+            vec.swap_remove(position);
+        }
     }
 
     pub fn trim_to_max_allowed_uids(netuid: NetUid, max_n: u16) -> DispatchResult {
@@ -196,10 +335,17 @@ impl<T: Config> Pallet<T> {
 
                     // Remove hotkey related storage items if hotkey exists
                     if let Ok(hotkey) = Keys::<T>::try_get(netuid, neuron_uid) {
+                        Self::set_neuron_status_for_uid(
+                            netuid,
+                            neuron_uid,
+                            &hotkey,
+                            NeuronStatus::Pruned,
+                        );
                         Uids::<T>::remove(netuid, &hotkey);
                         IsNetworkMember::<T>::remove(&hotkey, netuid);
                         LastHotkeyEmissionOnNetuid::<T>::remove(&hotkey, netuid);
                         AlphaDividendsPerSubnet::<T>::remove(netuid, &hotkey);
+                        DelegateEmissionWindows::<T>::remove(netuid, &hotkey);
                         TaoDividendsPerSubnet::<T>::remove(netuid, &hotkey);
                         Axons::<T>::remove(netuid, &hotkey);
                         NeuronCertificates::<T>::remove(netuid, &hotkey);
@@ -207,10 +353,15 @@ impl<T: Config> Pallet<T> {
                     }
 
                     // Remove all storage items associated with this uid
+                    Self::refund_registration_deposit(netuid, neuron_uid);
                     #[allow(unknown_lints)]
                     Keys::<T>::remove(netuid, neuron_uid);
                     BlockAtRegistration::<T>::remove(netuid, neuron_uid);
+                    NeuronLifetimeEmission::<T>::remove(netuid, neuron_uid);
                     AssociatedEvmAddress::<T>::remove(netuid, neuron_uid);
+                    LastHeartbeat::<T>::remove(netuid, neuron_uid);
+                    WeightsSetRateLimitOverride::<T>::remove(netuid, neuron_uid);
+                    NeuronImmunityOverride::<T>::remove(netuid, neuron_uid);
                     for mecid in 0..mechanisms_count {
                         let netuid_index = Self::get_mechanism_storage_index(netuid, mecid.into());
                         Weights::<T>::remove(netuid_index, neuron_uid);
@@ -236,6 +387,7 @@ impl<T: Config> Pallet<T> {
             let ranks = Rank::<T>::get(netuid);
             let trust = Trust::<T>::get(netuid);
             let active = Active::<T>::get(netuid);
+            let statuses = NeuronStatuses::<T>::get(netuid);
             let consensus = Consensus::<T>::get(netuid);
             let dividends = Dividends::<T>::get(netuid);
             let pruning_scores = PruningScores::<T>::get(netuid);
@@ -249,6 +401,7 @@ impl<T: Config> Pallet<T> {
             let mut trimmed_ranks = Vec::with_capacity(len);
             let mut trimmed_trust = Vec::with_capacity(len);
             let mut trimmed_active = Vec::with_capacity(len);
+            let mut trimmed_statuses = Vec::with_capacity(len);
             let mut trimmed_consensus = Vec::with_capacity(len);
             let mut trimmed_dividends = Vec::with_capacity(len);
             let mut trimmed_pruning_scores = Vec::with_capacity(len);
@@ -261,6 +414,7 @@ impl<T: Config> Pallet<T> {
                 trimmed_ranks.push(ranks.get(uid).cloned().unwrap_or_default());
                 trimmed_trust.push(trust.get(uid).cloned().unwrap_or_default());
                 trimmed_active.push(active.get(uid).cloned().unwrap_or_default());
+                trimmed_statuses.push(statuses.get(uid).cloned().unwrap_or_default());
                 trimmed_consensus.push(consensus.get(uid).cloned().unwrap_or_default());
                 trimmed_dividends.push(dividends.get(uid).cloned().unwrap_or_default());
                 trimmed_pruning_scores.push(pruning_scores.get(uid).cloned().unwrap_or_default());
@@ -274,6 +428,7 @@ impl<T: Config> Pallet<T> {
             Rank::<T>::insert(netuid, trimmed_ranks);
             Trust::<T>::insert(netuid, trimmed_trust);
             Active::<T>::insert(netuid, trimmed_active);
+            NeuronStatuses::<T>::insert(netuid, trimmed_statuses);
             Consensus::<T>::insert(netuid, trimmed_consensus);
             Dividends::<T>::insert(netuid, trimmed_dividends);
             PruningScores::<T>::insert(netuid, trimmed_pruning_scores);
@@ -318,14 +473,25 @@ impl<T: Config> Pallet<T> {
                 // Swap uid specific storage items to new compressed positions
                 Keys::<T>::swap(netuid, old_neuron_uid, netuid, new_neuron_uid);
                 AssociatedEvmAddress::<T>::swap(netuid, old_neuron_uid, netuid, new_neuron_uid);
+                LastHeartbeat::<T>::swap(netuid, old_neuron_uid, netuid, new_neuron_uid);
+                WeightsSetRateLimitOverride::<T>::swap(
+                    netuid,
+                    old_neuron_uid,
+                    netuid,
+                    new_neuron_uid,
+                );
+                NeuronImmunityOverride::<T>::swap(netuid, old_neuron_uid, netuid, new_neuron_uid);
                 BlockAtRegistration::<T>::swap(netuid, old_neuron_uid, netuid, new_neuron_uid);
+                NeuronLifetimeEmission::<T>::swap(netuid, old_neuron_uid, netuid, new_neuron_uid);
+                RegistrationDeposits::<T>::swap(netuid, old_neuron_uid, netuid, new_neuron_uid);
 
                 for mecid in 0..mechanisms_count {
                     let netuid_index = Self::get_mechanism_storage_index(netuid, mecid.into());
 
                     // Swap to new position and remap all target uids
                     Weights::<T>::swap(netuid_index, old_neuron_uid, netuid_index, new_neuron_uid);
-                    Weights::<T>::mutate(netuid_index, new_neuron_uid, |weights| {
+                    Weights::<T>::mutate(netuid_index, new_neuron_uid, |row| {
+                        let mut weights = row.to_vec();
                         weights.retain_mut(|(target_uid, _weight)| {
                             if let Some(new_target_uid) =
                                 old_to_new_uid.get(&(*target_uid as usize))
@@ -335,7 +501,8 @@ impl<T: Config> Pallet<T> {
                             } else {
                                 false
                             }
-                        })
+                        });
+                        *row = weights.into();
                     });
 
                     // Swap to new position and remap all target uids
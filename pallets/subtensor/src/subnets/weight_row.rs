@@ -0,0 +1,164 @@
+//! Compressed weight rows.
+//!
+//! Validators on big subnets weight a small share of the uids, so most of a weight row is zero
+//! weights that are not stored at all. The rows that are stored list `(uid, weight)` pairs, where
+//! the uids take as many bytes as the weights. A `WeightRow` keeps its pairs sorted by uid and
+//! stores every uid as the span of zero weights since the previous one instead, which is a single
+//! compact byte for spans of up to 63 uids, and decompresses to the pairs when read.
+//!
+//! A compressed row is encoded as the version byte `WEIGHT_ROW_V1` followed by its spans. Rows
+//! stored before the compression are a bare `Vec<(u16, u16)>`, whose compact length never starts
+//! with that byte, so they still decode, to the same pairs, until `CompressWeights` has rewritten
+//! them. Clients read the rows as pairs through `get_weight_rows` whatever their encoding.
+
+use super::*;
+use codec::{Compact, EncodeLike, Input, Output};
+use scale_info::{Path, Type, build::Fields};
+use subtensor_runtime_common::{MechId, NetUid};
+
+/// The first byte of a compressed row. A legacy row starts with its length as a `Compact<u32>`,
+/// for which `0x07` is not a valid first byte.
+pub const WEIGHT_ROW_V1: u8 = 0x07;
+
+/// A row of the weight matrix, the weights set by a neuron, as the explicit number of entries
+/// followed by the run of zero weights before every entry and its weight.
+#[freeze_struct("3d1cc73f5fc2dff7")]
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct WeightRow {
+    spans: Vec<(Compact<u16>, u16)>,
+}
+
+impl WeightRow {
+    /// The `(uid, weight)` pairs of the row, sorted by uid.
+    pub fn iter(&self) -> impl Iterator<Item = (u16, u16)> + '_ {
+        let mut next_uid: u16 = 0;
+        self.spans.iter().map(move |(Compact(zeros), weight)| {
+            let uid = next_uid.saturating_add(*zeros);
+            next_uid = uid.saturating_add(1);
+            (uid, *weight)
+        })
+    }
+
+    pub fn to_vec(&self) -> Vec<(u16, u16)> {
+        self.iter().collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+}
+
+impl Encode for WeightRow {
+    fn size_hint(&self) -> usize {
+        self.spans.size_hint().saturating_add(1)
+    }
+
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        dest.push_byte(WEIGHT_ROW_V1);
+        self.spans.encode_to(dest);
+    }
+}
+
+impl EncodeLike for WeightRow {}
+
+impl Decode for WeightRow {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, codec::Error> {
+        let first = input.read_byte()?;
+        if first == WEIGHT_ROW_V1 {
+            let spans = Vec::<(Compact<u16>, u16)>::decode(input)?;
+            return Ok(Self { spans });
+        }
+
+        // A legacy row, whose length starts with the byte read
+        let weights = Vec::<(u16, u16)>::decode(&mut Prepended {
+            first: Some(first),
+            input,
+        })?;
+        Ok(Self::from(weights))
+    }
+}
+
+impl TypeInfo for WeightRow {
+    type Identity = Self;
+    fn type_info() -> Type {
+        Type::builder()
+            .path(Path::new("WeightRow", module_path!()))
+            .composite(
+                Fields::named()
+                    .field(|f| f.ty::<u8>().name("version").type_name("u8"))
+                    .field(|f| {
+                        f.ty::<Vec<(Compact<u16>, u16)>>()
+                            .name("spans")
+                            .type_name("Vec<(Compact<u16>, u16)>")
+                    }),
+            )
+    }
+}
+
+/// An input with a byte already read from it put back in front.
+struct Prepended<'a, I: Input> {
+    first: Option<u8>,
+    input: &'a mut I,
+}
+
+impl<I: Input> Input for Prepended<'_, I> {
+    fn remaining_len(&mut self) -> Result<Option<usize>, codec::Error> {
+        let first = usize::from(self.first.is_some());
+        Ok(self
+            .input
+            .remaining_len()?
+            .map(|len| len.saturating_add(first)))
+    }
+
+    fn read(&mut self, into: &mut [u8]) -> Result<(), codec::Error> {
+        match (self.first, into.split_first_mut()) {
+            (Some(first), Some((head, tail))) => {
+                *head = first;
+                self.first = None;
+                self.input.read(tail)
+            }
+            _ => self.input.read(into),
+        }
+    }
+}
+
+impl From<Vec<(u16, u16)>> for WeightRow {
+    /// Compresses `(uid, weight)` pairs, keeping the first weight of a uid listed twice.
+    fn from(mut weights: Vec<(u16, u16)>) -> Self {
+        weights.sort_by_key(|(uid, _)| *uid);
+        weights.dedup_by_key(|(uid, _)| *uid);
+
+        let mut next_uid: u16 = 0;
+        let spans = weights
+            .into_iter()
+            .map(|(uid, weight)| {
+                let zeros = uid.saturating_sub(next_uid);
+                next_uid = uid.saturating_add(1);
+                (Compact(zeros), weight)
+            })
+            .collect();
+        Self { spans }
+    }
+}
+
+impl From<WeightRow> for Vec<(u16, u16)> {
+    fn from(row: WeightRow) -> Self {
+        row.to_vec()
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// The weight rows of the mechanism `mecid` of `netuid` as `(uid, weight)` pairs, by uid.
+    pub fn get_weight_rows(netuid: NetUid, mecid: MechId) -> Vec<(u16, Vec<(u16, u16)>)> {
+        let netuid_index = Self::get_mechanism_storage_index(netuid, mecid);
+        let mut rows: Vec<(u16, Vec<(u16, u16)>)> = Weights::<T>::iter_prefix(netuid_index)
+            .map(|(uid, row)| (uid, row.to_vec()))
+            .collect();
+        rows.sort_by_key(|(uid, _)| *uid);
+        rows
+    }
+}
@@ -0,0 +1,105 @@
+//! Weight setter delegation.
+//!
+//! The owner of a validator hotkey can authorize an operator hotkey to set and commit weights for
+//! the validator on a subnet, so the key signing weights on a machine does not have to be the
+//! staking identity. The operator signs the weight calls itself and they apply to the validator
+//! hotkey, hash commits included: the operator commits the hash computed with the validator
+//! hotkey. The owner of the validator hotkey can revoke an operator at any time, and an operator
+//! can resign.
+//!
+//! An operator cannot be registered on the subnet it operates for, and an account registered on a
+//! subnet always sets weights for itself.
+//!
+//! While a hotkey is leased on a subnet, only the operator of the renter sets weights for it and
+//! the owner cannot change its operators.
+
+use super::*;
+use subtensor_runtime_common::NetUid;
+
+impl<T: Config> Pallet<T> {
+    /// Authorizes `operator` to set weights for `hotkey` on `netuid`.
+    pub fn do_set_weight_setter_delegate(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        netuid: NetUid,
+        operator: T::AccountId,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+
+        ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+        ensure!(
+            Self::get_active_hotkey_lease(netuid, &hotkey).is_none(),
+            Error::<T>::HotkeyLeased
+        );
+        ensure!(
+            operator != hotkey
+                && !Self::is_hotkey_registered_on_network(netuid, &operator)
+                && WeightSetterDelegate::<T>::get(netuid, &operator)
+                    .is_none_or(|delegator| delegator == hotkey),
+            Error::<T>::InvalidWeightSetterDelegate
+        );
+
+        WeightSetterDelegate::<T>::insert(netuid, &operator, &hotkey);
+        Self::deposit_event(Event::WeightSetterDelegateSet {
+            netuid,
+            hotkey,
+            operator,
+        });
+        Ok(())
+    }
+
+    /// Revokes the authorization of `operator` on `netuid`, either by the owner of the hotkey it
+    /// sets weights for or by the operator itself.
+    pub fn do_remove_weight_setter_delegate(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        operator: T::AccountId,
+    ) -> DispatchResult {
+        let who = ensure_signed(origin)?;
+
+        let hotkey = WeightSetterDelegate::<T>::get(netuid, &operator)
+            .ok_or(Error::<T>::WeightSetterDelegateNotFound)?;
+        ensure!(
+            who == operator || Self::coldkey_owns_hotkey(&who, &hotkey),
+            Error::<T>::NonAssociatedColdKey
+        );
+        ensure!(
+            who == operator
+                || Self::get_active_hotkey_lease(netuid, &hotkey)
+                    .is_none_or(|lease| lease.operator != operator),
+            Error::<T>::HotkeyLeased
+        );
+
+        WeightSetterDelegate::<T>::remove(netuid, &operator);
+        Self::deposit_event(Event::WeightSetterDelegateRemoved {
+            netuid,
+            hotkey,
+            operator,
+        });
+        Ok(())
+    }
+
+    /// The hotkey `who` sets weights for on `netuid`: the validator it operates for, or itself.
+    pub fn get_weight_setter_hotkey(netuid: NetUid, who: &T::AccountId) -> T::AccountId {
+        if Self::is_hotkey_registered_on_network(netuid, who) {
+            return who.clone();
+        }
+        WeightSetterDelegate::<T>::get(netuid, who).unwrap_or_else(|| who.clone())
+    }
+
+    /// Ensures `origin` is signed and returns the hotkey it sets weights for on `netuid`, unless
+    /// the hotkey is leased to another operator.
+    pub fn ensure_weight_setter(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+    ) -> Result<T::AccountId, DispatchError> {
+        let who = ensure_signed(origin)?;
+        let hotkey = Self::get_weight_setter_hotkey(netuid, &who);
+        Self::ensure_not_leased_away(netuid, &hotkey, &who)?;
+        Ok(hotkey)
+    }
+}
@@ -1,6 +1,7 @@
 use super::*;
 use crate::epoch::math::*;
-use crate::{Error, MAX_COMMIT_REVEAL_PEROIDS, MIN_COMMIT_REVEAL_PEROIDS};
+use crate::subnets::weight_row::WeightRow;
+use crate::{Error, MAX_PENDING_WEIGHT_COMMITS, MIN_COMMIT_REVEAL_PEROIDS};
 use codec::Compact;
 use frame_support::dispatch::DispatchResult;
 use safe_math::*;
@@ -12,6 +13,9 @@ use sp_runtime::{
 use sp_std::{collections::vec_deque::VecDeque, vec};
 use subtensor_runtime_common::{MechId, NetUid, NetUidStorageIndex};
 
+/// The uids, values, salt and version key of a weight commit.
+pub type WeightsReveal = (Vec<u16>, Vec<u16>, Vec<u16>, u64);
+
 impl<T: Config> Pallet<T> {
     /// ---- The implementation for committing weight hashes.
     ///
@@ -70,8 +74,8 @@ impl<T: Config> Pallet<T> {
         // Calculate subnet storage index
         let netuid_index = Self::get_mechanism_storage_index(netuid, mecid);
 
-        // 1. Verify the caller's signature (hotkey).
-        let who = ensure_signed(origin)?;
+        // 1. Verify the caller's signature (hotkey or its operator).
+        let who = Self::ensure_weight_setter(origin, netuid)?;
 
         log::debug!("do_commit_weights(hotkey: {who:?}, netuid: {netuid:?})");
 
@@ -81,11 +85,18 @@ impl<T: Config> Pallet<T> {
             Error::<T>::CommitRevealDisabled
         );
 
+        // Hash commits need a reveal transaction, timelocked commits are revealed by the chain
+        ensure!(
+            !Self::get_timelocked_weights_only(netuid),
+            Error::<T>::TimelockedWeightsRequired
+        );
+
         // 3. Ensure the hotkey is registered on the network.
         ensure!(
             Self::is_hotkey_registered_on_network(netuid, &who),
             Error::<T>::HotKeyNotRegisteredInSubNet
         );
+        Self::ensure_hotkey_not_blacklisted(netuid, &who)?;
 
         // 4. Check that the commit rate does not exceed the allowed frequency.
         let commit_block = Self::get_current_block_as_u64();
@@ -114,7 +125,10 @@ impl<T: Config> Pallet<T> {
             }
 
             // 8. Verify that the number of unrevealed commits is within the allowed limit.
-            ensure!(commits.len() < 10, Error::<T>::TooManyUnrevealedCommits);
+            ensure!(
+                commits.len() < MAX_PENDING_WEIGHT_COMMITS,
+                Error::<T>::TooManyUnrevealedCommits
+            );
 
             // 9. Append the new commit with calculated reveal blocks.
             commits.push_back((
@@ -306,8 +320,8 @@ impl<T: Config> Pallet<T> {
         // Calculate netuid storage index
         let netuid_index = Self::get_mechanism_storage_index(netuid, mecid);
 
-        // 1. Verify the caller's signature (hotkey).
-        let who = ensure_signed(origin)?;
+        // 1. Verify the caller's signature (hotkey or its operator).
+        let who = Self::ensure_weight_setter(origin, netuid)?;
 
         log::debug!("do_commit_v3_weights(hotkey: {who:?}, netuid: {netuid:?})");
 
@@ -328,6 +342,7 @@ impl<T: Config> Pallet<T> {
             Self::is_hotkey_registered_on_network(netuid, &who),
             Error::<T>::HotKeyNotRegisteredInSubNet
         );
+        Self::ensure_hotkey_not_blacklisted(netuid, &who)?;
 
         // 5. Check that the commit rate does not exceed the allowed frequency.
         let commit_block = Self::get_current_block_as_u64();
@@ -415,6 +430,9 @@ impl<T: Config> Pallet<T> {
     /// * `RevealTooEarly`:
     ///   - Attempting to reveal weights outside the valid reveal period.
     ///
+    /// * `RevealWindowClosed`:
+    ///   - Attempting to reveal weights after the reveal window of the subnet.
+    ///
     /// * `InvalidRevealCommitHashNotMatch`:
     ///   - The revealed hash does not match any committed hash.
     pub fn do_reveal_weights(
@@ -460,8 +478,8 @@ impl<T: Config> Pallet<T> {
         // Calculate netuid storage index
         let netuid_index = Self::get_mechanism_storage_index(netuid, mecid);
 
-        // --- 1. Check the caller's signature (hotkey).
-        let who = ensure_signed(origin.clone())?;
+        // --- 1. Check the caller's signature (hotkey or its operator).
+        let who = Self::ensure_weight_setter(origin.clone(), netuid)?;
 
         log::debug!("do_reveal_weights( hotkey:{who:?} netuid:{netuid:?})");
 
@@ -470,6 +488,7 @@ impl<T: Config> Pallet<T> {
             Self::get_commit_reveal_weights_enabled(netuid),
             Error::<T>::CommitRevealDisabled
         );
+        Self::ensure_hotkey_not_blacklisted(netuid, &who)?;
 
         // --- 3. Mutate the WeightCommits to retrieve existing commits for the user.
         WeightCommits::<T>::try_mutate_exists(
@@ -517,6 +536,10 @@ impl<T: Config> Pallet<T> {
                         .ok_or(Error::<T>::NoWeightsCommitFound)?;
 
                     // --- 9. Ensure the commit is ready to be revealed in the current block range.
+                    ensure!(
+                        !Self::is_reveal_window_closed(netuid, *commit_block),
+                        Error::<T>::RevealWindowClosed
+                    );
                     ensure!(
                         Self::is_reveal_block_range(netuid, *commit_block),
                         Error::<T>::RevealTooEarly
@@ -597,6 +620,9 @@ impl<T: Config> Pallet<T> {
     /// * `RevealTooEarly`:
     ///   - Attempting to reveal weights outside the valid reveal period.
     ///
+    /// * `RevealWindowClosed`:
+    ///   - Attempting to reveal weights after the reveal window of the subnet.
+    ///
     /// * `InvalidRevealCommitHashNotMatch`:
     ///   - The revealed hash does not match any committed hash.
     ///
@@ -622,8 +648,8 @@ impl<T: Config> Pallet<T> {
             Error::<T>::InputLengthsUnequal
         );
 
-        // --- 2. Check the caller's signature (hotkey).
-        let who = ensure_signed(origin.clone())?;
+        // --- 2. Check the caller's signature (hotkey or its operator).
+        let who = Self::ensure_weight_setter(origin.clone(), netuid)?;
 
         log::debug!("do_batch_reveal_weights( hotkey:{who:?} netuid:{netuid:?})");
 
@@ -632,6 +658,7 @@ impl<T: Config> Pallet<T> {
             Self::get_commit_reveal_weights_enabled(netuid),
             Error::<T>::CommitRevealDisabled
         );
+        Self::ensure_hotkey_not_blacklisted(netuid, &who)?;
 
         // --- 4. Mutate the WeightCommits to retrieve existing commits for the user.
         WeightCommits::<T>::try_mutate_exists(
@@ -699,6 +726,10 @@ impl<T: Config> Pallet<T> {
                         .ok_or(Error::<T>::NoWeightsCommitFound)?;
 
                     // --- 7d. Check if the commit is within the reveal window.
+                    ensure!(
+                        !Self::is_reveal_window_closed(netuid, commit.1),
+                        Error::<T>::RevealWindowClosed
+                    );
                     ensure!(
                         Self::is_reveal_block_range(netuid, commit.1),
                         Error::<T>::RevealTooEarly
@@ -745,6 +776,121 @@ impl<T: Config> Pallet<T> {
         )
     }
 
+    /// ---- The implementation for the extrinsic settle_weight_commits: reveals several pending
+    /// weight commits of a hotkey on a mechanism at once, e.g. after the validator was down for
+    /// a few epochs.
+    ///
+    /// Unlike `batch_reveal_weights`, reveals of commits whose reveal window has passed do not
+    /// fail the batch: those commits are dropped. The other commits must be in their reveal
+    /// window and set weights in commit order, so the most recent commit wins. Any other expired
+    /// commit of the hotkey is dropped as well.
+    ///
+    /// # Args:
+    /// * `origin` (`<T as frame_system::Config>::RuntimeOrigin`):
+    ///   - The signature of the revealing hotkey.
+    ///
+    /// * `netuid` (`NetUid`):
+    ///   - The network uid.
+    ///
+    /// * `mecid` (`MechId`):
+    ///   - The mechanism the weights were committed on.
+    ///
+    /// * `reveals` (`Vec<WeightsReveal>`):
+    ///   - The uids, values, salt and version key of each commit.
+    ///
+    /// # Raises:
+    /// * `InvalidNumberOfReveals`:
+    ///   - No reveals, or more reveals than a hotkey can have pending commits.
+    ///
+    /// * `CommitRevealDisabled`:
+    ///   - Attempting to reveal weights when the commit-reveal mechanism is disabled.
+    ///
+    /// * `NoWeightsCommitFound`:
+    ///   - The hotkey has no pending commits.
+    ///
+    /// * `InvalidRevealCommitHashNotMatch`:
+    ///   - A reveal does not match any pending commit.
+    ///
+    /// * `RevealTooEarly`:
+    ///   - A commit is not revealable yet.
+    pub fn do_settle_weight_commits(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+        mecid: MechId,
+        reveals: Vec<WeightsReveal>,
+    ) -> DispatchResult {
+        let netuid_index = Self::get_mechanism_storage_index(netuid, mecid);
+        let who = Self::ensure_weight_setter(origin.clone(), netuid)?;
+        log::debug!("do_settle_weight_commits( hotkey:{who:?} netuid:{netuid:?} mecid:{mecid:?})");
+
+        ensure!(
+            !reveals.is_empty() && reveals.len() <= MAX_PENDING_WEIGHT_COMMITS,
+            Error::<T>::InvalidNumberOfReveals
+        );
+        ensure!(
+            Self::get_commit_reveal_weights_enabled(netuid),
+            Error::<T>::CommitRevealDisabled
+        );
+        Self::ensure_hotkey_not_blacklisted(netuid, &who)?;
+
+        let mut commits =
+            WeightCommits::<T>::get(netuid_index, &who).ok_or(Error::<T>::NoWeightsCommitFound)?;
+
+        // Match every reveal to its commit before setting any weights.
+        let mut revealable = Vec::with_capacity(reveals.len());
+        let mut expired_hashes = Vec::new();
+        for (uids, values, salt, version_key) in reveals {
+            let hash =
+                Self::get_commit_hash(&who, netuid_index, &uids, &values, &salt, version_key);
+            let (_, commit_block, _, _) = commits
+                .iter()
+                .position(|(commit_hash, _, _, _)| *commit_hash == hash)
+                .and_then(|position| commits.remove(position))
+                .ok_or(Error::<T>::InvalidRevealCommitHashNotMatch)?;
+
+            if Self::is_commit_expired(netuid, commit_block)
+                || Self::is_reveal_window_closed(netuid, commit_block)
+            {
+                expired_hashes.push(hash);
+            } else {
+                ensure!(
+                    Self::is_reveal_block_range(netuid, commit_block),
+                    Error::<T>::RevealTooEarly
+                );
+                revealable.push((commit_block, hash, uids, values, version_key));
+            }
+        }
+        commits.retain(|(_, commit_block, _, _)| !Self::is_commit_expired(netuid, *commit_block));
+
+        revealable.sort_by_key(|(commit_block, ..)| *commit_block);
+        let mut revealed_hashes = Vec::with_capacity(revealable.len());
+        for (_, hash, uids, values, version_key) in revealable {
+            Self::do_set_mechanism_weights(
+                origin.clone(),
+                netuid,
+                mecid,
+                uids,
+                values,
+                version_key,
+            )?;
+            revealed_hashes.push(hash);
+        }
+
+        if commits.is_empty() {
+            WeightCommits::<T>::remove(netuid_index, &who);
+        } else {
+            WeightCommits::<T>::insert(netuid_index, &who, commits);
+        }
+
+        Self::deposit_event(Event::WeightCommitsSettled {
+            hotkey: who,
+            netuid: netuid_index,
+            revealed_hashes,
+            expired_hashes,
+        });
+        Ok(())
+    }
+
     fn internal_set_weights(
         origin: T::RuntimeOrigin,
         netuid: NetUid,
@@ -756,8 +902,9 @@ impl<T: Config> Pallet<T> {
         // Calculate subnet storage index
         let netuid_index = Self::get_mechanism_storage_index(netuid, mecid);
 
-        // --- 1. Check the caller's signature. This is the hotkey of a registered account.
-        let hotkey = ensure_signed(origin)?;
+        // --- 1. Check the caller's signature. This is the hotkey of a registered account, or
+        // its operator.
+        let hotkey = Self::ensure_weight_setter(origin, netuid)?;
         log::debug!(
             "do_set_weights( origin:{hotkey:?} netuid:{netuid:?}, uids:{uids:?}, values:{values:?})"
         );
@@ -785,6 +932,7 @@ impl<T: Config> Pallet<T> {
             Self::is_hotkey_registered_on_network(netuid, &hotkey),
             Error::<T>::HotKeyNotRegisteredInSubNet
         );
+        Self::ensure_hotkey_not_blacklisted(netuid, &hotkey)?;
 
         // --- 6. Check to see if the hotkey has enough stake to set weights.
         ensure!(
@@ -845,7 +993,7 @@ impl<T: Config> Pallet<T> {
         }
 
         // --- 17. Set weights under netuid_index (sub-subnet), uid double map entry.
-        Weights::<T>::insert(netuid_index, neuron_uid, zipped_weights);
+        Weights::<T>::insert(netuid_index, neuron_uid, WeightRow::from(zipped_weights));
 
         // --- 18. Set the activity for the weights on this network.
         if !Self::get_commit_reveal_weights_enabled(netuid) {
@@ -996,6 +1144,76 @@ impl<T: Config> Pallet<T> {
         Self::internal_set_weights(origin, netuid, mecid, uids, values, version_key)
     }
 
+    /// ---- The implementation for the extrinsic set_no_weights.
+    ///
+    /// Records an empty weight vector for the calling hotkey on every mechanism of the subnet,
+    /// which counts as a weights update for the activity cutoff.
+    ///
+    /// # Args:
+    ///  * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///    - The signature of the calling hotkey.
+    ///
+    ///  * 'netuid' (NetUid):
+    ///    - The network identifier.
+    ///
+    /// # Event:
+    ///  * WeightsSet;
+    ///    - For every mechanism of the subnet.
+    ///
+    /// # Raises:
+    ///  * 'CommitRevealEnabled':
+    ///    - Weights are set by commit-reveal on the subnet.
+    ///
+    ///  * 'HotKeyNotRegisteredInSubNet':
+    ///    - Attempting to set weights from a non registered account.
+    ///
+    ///  * 'NotEnoughStakeToSetWeights':
+    ///    - The hotkey does not have the stake to set weights.
+    ///
+    ///  * 'SettingWeightsTooFast':
+    ///    - Attempting to set weights faster than the weights_set_rate_limit.
+    ///
+    pub fn do_set_no_weights(origin: T::RuntimeOrigin, netuid: NetUid) -> DispatchResult {
+        let hotkey = Self::ensure_weight_setter(origin, netuid)?;
+        log::debug!("do_set_no_weights( origin:{hotkey:?} netuid:{netuid:?} )");
+
+        ensure!(!netuid.is_root(), Error::<T>::CanNotSetRootNetworkWeights);
+        Self::ensure_mechanism_exists(netuid, MechId::MAIN)?;
+        ensure!(
+            !Self::get_commit_reveal_weights_enabled(netuid),
+            Error::<T>::CommitRevealEnabled
+        );
+        ensure!(
+            Self::is_hotkey_registered_on_network(netuid, &hotkey),
+            Error::<T>::HotKeyNotRegisteredInSubNet
+        );
+        Self::ensure_hotkey_not_blacklisted(netuid, &hotkey)?;
+        ensure!(
+            Self::check_weights_min_stake(&hotkey, netuid),
+            Error::<T>::NotEnoughStakeToSetWeights
+        );
+
+        let neuron_uid = Self::get_uid_for_net_and_hotkey(netuid, &hotkey)?;
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let netuid_indices: Vec<NetUidStorageIndex> =
+            (0..u8::from(Self::get_current_mechanism_count(netuid)))
+                .map(|mecid| Self::get_mechanism_storage_index(netuid, MechId::from(mecid)))
+                .collect();
+        for netuid_index in netuid_indices.iter() {
+            ensure!(
+                Self::check_rate_limit(*netuid_index, neuron_uid, current_block),
+                Error::<T>::SettingWeightsTooFast
+            );
+        }
+
+        for netuid_index in netuid_indices {
+            Weights::<T>::insert(netuid_index, neuron_uid, WeightRow::default());
+            Self::set_last_update_for_uid(netuid_index, neuron_uid, current_block);
+            Self::deposit_event(Event::WeightsSet(netuid_index, neuron_uid));
+        }
+        Ok(())
+    }
+
     /// ---- The implementation for the extrinsic batch_set_weights.
     ///
     /// This call runs a batch of set weights calls, continuing on errors.
@@ -1117,7 +1335,7 @@ impl<T: Config> Pallet<T> {
                     return true;
                 } // (Storage default) Never set weights.
                 return current_block.saturating_sub(last_set_weights)
-                    >= Self::get_weights_set_rate_limit(netuid);
+                    >= Self::get_weights_set_rate_limit_for_uid(netuid, neuron_uid);
             }
         }
 
@@ -1247,8 +1465,16 @@ impl<T: Config> Pallet<T> {
         let current_epoch: u64 = Self::get_epoch_index(netuid, current_block);
         let reveal_period: u64 = Self::get_reveal_period(netuid);
 
-        // Reveal is allowed only in the exact epoch `commit_epoch + reveal_period`
+        // Reveal is allowed only in the exact epoch `commit_epoch + reveal_period`, within its
+        // reveal window
         current_epoch == commit_epoch.saturating_add(reveal_period)
+            && !Self::is_reveal_window_closed(netuid, commit_block)
+    }
+
+    /// Whether the reveal window of a commit made at `commit_block` has passed.
+    pub fn is_reveal_window_closed(netuid: NetUid, commit_block: u64) -> bool {
+        let (_, last_reveal_block) = Self::get_reveal_blocks(netuid, commit_block);
+        Self::get_current_block_as_u64() > last_reveal_block
     }
 
     pub fn get_epoch_index(netuid: NetUid, block_number: u64) -> u64 {
@@ -1281,19 +1507,24 @@ impl<T: Config> Pallet<T> {
         let first_reveal_block = reveal_epoch
             .saturating_mul(tempo_plus_one)
             .saturating_sub(netuid_plus_one);
-        let last_reveal_block = first_reveal_block.saturating_add(tempo);
+        let reveal_window = match Self::get_reveal_window(netuid) {
+            0 => tempo_plus_one,
+            window => window.min(tempo_plus_one),
+        };
+        let last_reveal_block = first_reveal_block.saturating_add(reveal_window.saturating_sub(1));
 
         (first_reveal_block, last_reveal_block)
     }
 
     pub fn set_reveal_period(netuid: NetUid, reveal_period: u64) -> DispatchResult {
+        let (min_periods, max_periods) = CommitRevealPeriodBounds::<T>::get();
         ensure!(
-            reveal_period <= MAX_COMMIT_REVEAL_PEROIDS,
+            reveal_period <= max_periods,
             Error::<T>::RevealPeriodTooLarge
         );
 
         ensure!(
-            reveal_period >= MIN_COMMIT_REVEAL_PEROIDS,
+            reveal_period >= min_periods,
             Error::<T>::RevealPeriodTooSmall
         );
 
@@ -1306,6 +1537,46 @@ impl<T: Config> Pallet<T> {
         RevealPeriodEpochs::<T>::get(netuid)
     }
 
+    /// Sets the number of blocks at the start of the reveal epoch during which commits on
+    /// `netuid` can be revealed, 0 for the whole epoch.
+    pub fn set_reveal_window(netuid: NetUid, reveal_window: u64) -> DispatchResult {
+        ensure!(
+            reveal_window == 0 || reveal_window >= MinRevealWindow::<T>::get(),
+            Error::<T>::RevealWindowTooSmall
+        );
+
+        RevealWindow::<T>::insert(netuid, reveal_window);
+
+        Self::deposit_event(Event::RevealWindowSet(netuid, reveal_window));
+        Ok(())
+    }
+    pub fn get_reveal_window(netuid: NetUid) -> u64 {
+        RevealWindow::<T>::get(netuid)
+    }
+
+    /// Sets the bounds within which subnet owners set their commit reveal periods and reveal
+    /// windows. Settings already made outside of them are kept.
+    pub fn set_commit_reveal_bounds(
+        min_periods: u64,
+        max_periods: u64,
+        min_reveal_window: u64,
+    ) -> DispatchResult {
+        ensure!(
+            MIN_COMMIT_REVEAL_PEROIDS <= min_periods && min_periods <= max_periods,
+            Error::<T>::InvalidCommitRevealBounds
+        );
+
+        CommitRevealPeriodBounds::<T>::put((min_periods, max_periods));
+        MinRevealWindow::<T>::put(min_reveal_window);
+
+        Self::deposit_event(Event::CommitRevealBoundsSet {
+            min_periods,
+            max_periods,
+            min_reveal_window,
+        });
+        Ok(())
+    }
+
     pub fn get_first_block_of_epoch(netuid: NetUid, epoch: u64) -> u64 {
         let tempo: u64 = Self::get_tempo(netuid) as u64;
         let tempo_plus_one: u64 = tempo.saturating_add(1);
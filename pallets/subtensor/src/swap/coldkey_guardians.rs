@@ -0,0 +1,129 @@
+//! Guardian approval of coldkey swaps.
+//!
+//! A scheduled coldkey swap executes after the waiting period with nothing but the signature of
+//! the coldkey, so a single leaked coldkey is enough to take an account over. A coldkey can
+//! register a set of guardian accounts and a threshold instead: its scheduled swap then only
+//! executes if at least `threshold` guardians approve it during the waiting period, otherwise the
+//! swap fails like any other failed swap and can be rescheduled.
+//!
+//! Approvals are for the swap currently scheduled and are cleared when a swap is scheduled,
+//! cancelled or executed. The guardians of a coldkey cannot be changed by the coldkey once set,
+//! as a leaked coldkey could otherwise remove them; they are dropped when the coldkey is swapped
+//! and the new coldkey registers its own.
+
+use super::*;
+
+/// The maximum number of guardians of a coldkey.
+pub const MAX_COLDKEY_GUARDIANS: usize = 10;
+
+#[freeze_struct("6459e1187780f93a")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ColdkeyGuardianSet<AccountId> {
+    /// The guardian accounts.
+    pub guardians: Vec<AccountId>,
+    /// The number of guardians that must approve a swap.
+    pub threshold: u16,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Registers the guardians of the signing coldkey, `threshold` of which must approve its
+    /// scheduled swaps.
+    pub fn do_set_coldkey_guardians(
+        origin: T::RuntimeOrigin,
+        guardians: Vec<T::AccountId>,
+        threshold: u16,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        ensure!(
+            !ColdkeyGuardians::<T>::contains_key(&coldkey),
+            Error::<T>::ColdkeyGuardiansAlreadySet
+        );
+        ensure!(
+            !ColdkeySwapScheduled::<T>::contains_key(&coldkey),
+            Error::<T>::SwapAlreadyScheduled
+        );
+
+        let n = guardians.len();
+        let mut unique = guardians.clone();
+        unique.sort();
+        unique.dedup();
+        ensure!(
+            threshold > 0
+                && usize::from(threshold) <= n
+                && n <= MAX_COLDKEY_GUARDIANS
+                && unique.len() == n
+                && !guardians.contains(&coldkey),
+            Error::<T>::InvalidColdkeyGuardians
+        );
+
+        ColdkeyGuardians::<T>::insert(
+            &coldkey,
+            ColdkeyGuardianSet {
+                guardians: guardians.clone(),
+                threshold,
+            },
+        );
+        Self::deposit_event(Event::ColdkeyGuardiansSet {
+            coldkey,
+            guardians,
+            threshold,
+        });
+        Ok(())
+    }
+
+    /// Approves, for the signing guardian, the swap of `coldkey` to `new_coldkey` scheduled for
+    /// a block not yet reached.
+    pub fn do_approve_coldkey_swap(
+        origin: T::RuntimeOrigin,
+        coldkey: T::AccountId,
+        new_coldkey: T::AccountId,
+    ) -> DispatchResult {
+        let guardian = ensure_signed(origin)?;
+        let set = ColdkeyGuardians::<T>::get(&coldkey).ok_or(Error::<T>::NotColdkeyGuardian)?;
+        ensure!(
+            set.guardians.contains(&guardian),
+            Error::<T>::NotColdkeyGuardian
+        );
+
+        let (execution_block, scheduled_coldkey) = ColdkeySwapScheduled::<T>::try_get(&coldkey)
+            .map_err(|_| Error::<T>::NoColdkeySwapScheduled)?;
+        ensure!(
+            scheduled_coldkey == new_coldkey,
+            Error::<T>::ColdkeySwapTargetMismatch
+        );
+        ensure!(
+            <frame_system::Pallet<T>>::block_number() < execution_block,
+            Error::<T>::ColdkeySwapApprovalClosed
+        );
+
+        let mut approvals = ColdkeySwapApprovals::<T>::get(&coldkey);
+        ensure!(
+            !approvals.contains(&guardian),
+            Error::<T>::ColdkeySwapAlreadyApproved
+        );
+        approvals.push(guardian.clone());
+        let approval_count = u16::try_from(approvals.len()).unwrap_or(u16::MAX);
+        ColdkeySwapApprovals::<T>::insert(&coldkey, approvals);
+
+        Self::deposit_event(Event::ColdkeySwapApproved {
+            coldkey,
+            new_coldkey,
+            guardian,
+            approvals: approval_count,
+        });
+        Ok(())
+    }
+
+    /// Ensures the swap of `coldkey` has been approved by the threshold of its guardians, if it
+    /// has any.
+    pub fn ensure_coldkey_swap_approved(coldkey: &T::AccountId) -> Result<(), Error<T>> {
+        if let Some(set) = ColdkeyGuardians::<T>::get(coldkey) {
+            ensure!(
+                ColdkeySwapApprovals::<T>::decode_len(coldkey).unwrap_or(0)
+                    >= usize::from(set.threshold),
+                Error::<T>::ColdkeySwapNotApproved
+            );
+        }
+        Ok(())
+    }
+}
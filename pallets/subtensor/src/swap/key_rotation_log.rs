@@ -0,0 +1,69 @@
+//! Key rotation audit log.
+//!
+//! Exchanges and custodians processing a withdrawal need to verify where the current keys of an
+//! account come from, which events only show to whoever indexed them. Every hotkey swap, coldkey
+//! swap and change of the children of a hotkey is therefore also recorded, with its block, in the
+//! log of each account it involves: both keys of a swap, the coldkey of a hotkey swap and the
+//! parent hotkey of a children change. The log keeps the last `MAX_KEY_ROTATION_LOG_LEN` records
+//! of an account and is exposed with `get_key_rotation_log`.
+
+use super::*;
+use subtensor_runtime_common::NetUid;
+
+/// The number of records kept in the key rotation log of an account.
+pub const MAX_KEY_ROTATION_LOG_LEN: usize = 32;
+
+/// A key rotation of an account.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum KeyRotation<AccountId> {
+    /// A swap of `old_hotkey` of `coldkey` to `new_hotkey`, on `netuid` only if set.
+    HotkeySwap {
+        coldkey: AccountId,
+        old_hotkey: AccountId,
+        new_hotkey: AccountId,
+        netuid: Option<NetUid>,
+    },
+    /// A swap of `old_coldkey` to `new_coldkey`.
+    ColdkeySwap {
+        old_coldkey: AccountId,
+        new_coldkey: AccountId,
+    },
+    /// A change of the children of `hotkey` on `netuid`.
+    ChildrenSet {
+        hotkey: AccountId,
+        netuid: NetUid,
+        children: Vec<(u64, AccountId)>,
+    },
+}
+
+#[freeze_struct("bf8f5603326df64c")]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct KeyRotationRecord<AccountId> {
+    /// The block of the rotation.
+    pub block: u64,
+    /// The rotation.
+    pub rotation: KeyRotation<AccountId>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// The key rotations of `account`, oldest first.
+    pub fn get_key_rotation_log(account: T::AccountId) -> Vec<KeyRotationRecord<T::AccountId>> {
+        KeyRotationLog::<T>::get(account)
+    }
+
+    /// Records `rotation` in the log of each of `accounts`, dropping their oldest records past
+    /// `MAX_KEY_ROTATION_LOG_LEN`.
+    pub fn record_key_rotation(accounts: &[&T::AccountId], rotation: KeyRotation<T::AccountId>) {
+        let record = KeyRotationRecord {
+            block: Self::get_current_block_as_u64(),
+            rotation,
+        };
+        for account in accounts {
+            KeyRotationLog::<T>::mutate(*account, |log| {
+                log.push(record.clone());
+                let excess = log.len().saturating_sub(MAX_KEY_ROTATION_LOG_LEN);
+                log.drain(..excess);
+            });
+        }
+    }
+}
@@ -1,3 +1,5 @@
 use super::*;
+pub mod coldkey_guardians;
+pub mod key_rotation_log;
 pub mod swap_coldkey;
 pub mod swap_hotkey;
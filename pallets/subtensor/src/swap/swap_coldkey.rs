@@ -1,4 +1,6 @@
 use super::*;
+use crate::swap::key_rotation_log::KeyRotation;
+use frame_support::traits::schedule::v3::Anon as ScheduleAnon;
 use frame_support::weights::Weight;
 use sp_core::Get;
 use substrate_fixed::types::U64F64;
@@ -37,6 +39,10 @@ impl<T: Config> Pallet<T> {
     ) -> DispatchResultWithPostInfo {
         // 2. Initialize the weight for this operation
         let mut weight: Weight = T::DbWeight::get().reads(2);
+
+        // Ensure the guardians of the old coldkey, if any, approved the swap
+        Self::ensure_coldkey_swap_approved(old_coldkey)?;
+        weight = weight.saturating_add(T::DbWeight::get().reads(2));
         // 3. Ensure the new coldkey is not associated with any hotkeys
         ensure!(
             StakingHotkeys::<T>::get(new_coldkey).is_empty(),
@@ -44,11 +50,12 @@ impl<T: Config> Pallet<T> {
         );
         weight = weight.saturating_add(T::DbWeight::get().reads(1));
 
-        // 4. Ensure the new coldkey is not a hotkey
+        // 4. Ensure the new coldkey is not a hotkey or a protocol account
         ensure!(
             !Self::hotkey_account_exists(new_coldkey),
             Error::<T>::NewColdKeyIsHotkey
         );
+        Self::ensure_not_reserved_account(new_coldkey)?;
         weight = weight.saturating_add(T::DbWeight::get().reads(1));
 
         // 5. Swap the identity if the old coldkey has one
@@ -79,6 +86,17 @@ impl<T: Config> Pallet<T> {
 
         // 11. Remove the coldkey swap scheduled record
         ColdkeySwapScheduled::<T>::remove(old_coldkey);
+        ColdkeySwapScheduledTask::<T>::remove(old_coldkey);
+        ColdkeySwapApprovals::<T>::remove(old_coldkey);
+        ColdkeyGuardians::<T>::remove(old_coldkey);
+
+        Self::record_key_rotation(
+            &[old_coldkey, new_coldkey],
+            KeyRotation::ColdkeySwap {
+                old_coldkey: old_coldkey.clone(),
+                new_coldkey: new_coldkey.clone(),
+            },
+        );
 
         // 12. Emit the ColdkeySwapped event
         Self::deposit_event(Event::ColdkeySwapped {
@@ -91,6 +109,32 @@ impl<T: Config> Pallet<T> {
         Ok(Some(weight).into())
     }
 
+    /// Cancels the coldkey swap scheduled by the signing coldkey, removing its scheduler task.
+    ///
+    /// The swap can only be cancelled before its execution block, and only if it was scheduled
+    /// with a recorded scheduler task.
+    pub fn do_cancel_scheduled_coldkey_swap(origin: T::RuntimeOrigin) -> DispatchResult {
+        let old_coldkey = ensure_signed(origin)?;
+        let (execution_block, new_coldkey) = ColdkeySwapScheduled::<T>::try_get(&old_coldkey)
+            .map_err(|_| Error::<T>::NoColdkeySwapScheduled)?;
+        ensure!(
+            <frame_system::Pallet<T>>::block_number() < execution_block,
+            Error::<T>::ColdkeySwapNotCancellable
+        );
+
+        let task = ColdkeySwapScheduledTask::<T>::take(&old_coldkey)
+            .ok_or(Error::<T>::ColdkeySwapNotCancellable)?;
+        T::Scheduler::cancel(task).map_err(|_| Error::<T>::ColdkeySwapNotCancellable)?;
+        ColdkeySwapScheduled::<T>::remove(&old_coldkey);
+        ColdkeySwapApprovals::<T>::remove(&old_coldkey);
+
+        Self::deposit_event(Event::ColdkeySwapCancelled {
+            old_coldkey,
+            new_coldkey,
+        });
+        Ok(())
+    }
+
     /// Performs the actual coldkey swap operation, transferring all associated data and balances from the old coldkey to the new coldkey.
     ///
     /// # Arguments
@@ -149,13 +193,15 @@ impl<T: Config> Pallet<T> {
 
         // 2. Swap subnet owner.
         // SubnetOwner: MAP ( netuid ) --> (coldkey) | Owner of the subnet.
-        for netuid in Self::get_all_subnet_netuids() {
-            let subnet_owner = SubnetOwner::<T>::get(netuid);
-            if subnet_owner == *old_coldkey {
-                SubnetOwner::<T>::insert(netuid, new_coldkey.clone());
-            }
-            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        // SubnetsOwnedBy: MAP ( coldkey ) --> Vec<netuid> | Subnets owned by the coldkey.
+        let owned_netuids = SubnetsOwnedBy::<T>::get(old_coldkey);
+        weight.saturating_accrue(T::DbWeight::get().reads(1));
+        for netuid in owned_netuids {
+            Self::set_subnet_owner(netuid, new_coldkey);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(3, 3));
+        }
 
+        for netuid in Self::get_all_subnet_netuids() {
             if let Some(old_auto_stake_hotkey) = AutoStakeDestination::<T>::get(old_coldkey, netuid)
             {
                 AutoStakeDestination::<T>::remove(old_coldkey, netuid);
@@ -172,6 +218,14 @@ impl<T: Config> Pallet<T> {
             }
         }
 
+        // OwnerCutVestings: DMAP ( netuid, coldkey ) --> owner cut of the subnet vesting for the coldkey.
+        for netuid in Self::get_all_subnet_netuids() {
+            if let Some(vesting) = OwnerCutVestings::<T>::take(netuid, old_coldkey) {
+                OwnerCutVestings::<T>::insert(netuid, new_coldkey, vesting);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            }
+        }
+
         // 3. Swap Stake.
         // StakingHotkeys: MAP ( coldkey ) --> Vec( hotkey )
         for hotkey in StakingHotkeys::<T>::get(old_coldkey) {
@@ -188,9 +242,16 @@ impl<T: Config> Pallet<T> {
                 );
                 // Remove the value from the old account.
                 Alpha::<T>::remove((&hotkey, old_coldkey, netuid));
+                // Carry the stake origin over.
+                Self::inherit_stake_origin(
+                    StakeOrigins::<T>::take((netuid, &hotkey, old_coldkey)),
+                    &hotkey,
+                    new_coldkey,
+                    netuid,
+                );
             }
             // Add the weight for the read and write.
-            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(4, 4));
         }
 
         // 4. Swap TotalColdkeyAlpha (DEPRECATED)
@@ -239,7 +300,15 @@ impl<T: Config> Pallet<T> {
         OwnedHotkeys::<T>::insert(new_coldkey, new_owned_hotkeys);
         weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
 
-        // 7. Transfer remaining balance.
+        // 7. Swap the dissolution payout preference.
+        // DissolutionPayoutPreference: MAP ( coldkey ) --> DissolutionPayout | Where dissolution payouts are credited.
+        if DissolutionPayoutPreference::<T>::contains_key(old_coldkey) {
+            let payout = DissolutionPayoutPreference::<T>::take(old_coldkey);
+            DissolutionPayoutPreference::<T>::insert(new_coldkey, payout);
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+
+        // 8. Transfer remaining balance.
         // Balance: MAP ( coldkey ) --> u64 | Balance of the coldkey.
         // Transfer any remaining balance from old_coldkey to new_coldkey
         let remaining_balance = Self::get_coldkey_balance(old_coldkey);
@@ -1,4 +1,6 @@
 use super::*;
+use crate::coinbase::owner_cut_vesting::OwnerCutVesting;
+use crate::swap::key_rotation_log::KeyRotation;
 use frame_support::weights::Weight;
 use sp_core::Get;
 use substrate_fixed::types::U64F64;
@@ -43,8 +45,9 @@ impl<T: Config> Pallet<T> {
         // 3. Initialize the weight for this operation
         let mut weight = T::DbWeight::get().reads(2);
 
-        // 4. Ensure the new hotkey is different from the old one
+        // 4. Ensure the new hotkey is different from the old one and not a protocol account
         ensure!(old_hotkey != new_hotkey, Error::<T>::NewHotKeyIsSameWithOld);
+        Self::ensure_not_reserved_account(new_hotkey)?;
 
         // 5. Get the current block number
         let block: u64 = Self::get_current_block_as_u64();
@@ -80,7 +83,9 @@ impl<T: Config> Pallet<T> {
 
         // 11. fork for swap hotkey on a specific subnet case after do the common check
         if let Some(netuid) = netuid {
-            return Self::swap_hotkey_on_subnet(&coldkey, old_hotkey, new_hotkey, netuid, weight);
+            return Self::do_swap_hotkey_on_subnet(
+                &coldkey, old_hotkey, new_hotkey, netuid, weight,
+            );
         };
 
         // Start to do everything for swap hotkey on all subnets case
@@ -111,6 +116,16 @@ impl<T: Config> Pallet<T> {
         Self::set_last_tx_block(&coldkey, block);
         weight.saturating_accrue(T::DbWeight::get().writes(1));
 
+        Self::record_key_rotation(
+            &[old_hotkey, new_hotkey, &coldkey],
+            KeyRotation::HotkeySwap {
+                coldkey: coldkey.clone(),
+                old_hotkey: old_hotkey.clone(),
+                new_hotkey: new_hotkey.clone(),
+                netuid: None,
+            },
+        );
+
         // 21. Emit an event for the hotkey swap
         Self::deposit_event(Event::HotkeySwapped {
             coldkey,
@@ -257,7 +272,7 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
-    fn swap_hotkey_on_subnet(
+    fn do_swap_hotkey_on_subnet(
         coldkey: &T::AccountId,
         old_hotkey: &T::AccountId,
         new_hotkey: &T::AccountId,
@@ -327,6 +342,16 @@ impl<T: Config> Pallet<T> {
         LastHotkeySwapOnNetuid::<T>::insert(netuid, coldkey, block);
         weight.saturating_accrue(T::DbWeight::get().writes(2));
 
+        Self::record_key_rotation(
+            &[old_hotkey, new_hotkey, coldkey],
+            KeyRotation::HotkeySwap {
+                coldkey: coldkey.clone(),
+                old_hotkey: old_hotkey.clone(),
+                new_hotkey: new_hotkey.clone(),
+                netuid: Some(netuid),
+            },
+        );
+
         // 11. Emit an event for the hotkey swap
         Self::deposit_event(Event::HotkeySwappedOnSubnet {
             coldkey: coldkey.clone(),
@@ -438,6 +463,42 @@ impl<T: Config> Pallet<T> {
             }
         }
 
+        // 3.6.1 Swap merkle weights uploads.
+        // MerkleWeightsUploads( netuid_index, hotkey ) --> the merkle weights commit of the hotkey.
+        if is_network_member {
+            for mecid in 0..MechanismCountCurrent::<T>::get(netuid).into() {
+                let netuid_index = Self::get_mechanism_storage_index(netuid, MechId::from(mecid));
+                if let Some(upload) = MerkleWeightsUploads::<T>::take(netuid_index, old_hotkey) {
+                    MerkleWeightsUploads::<T>::insert(netuid_index, new_hotkey, upload);
+                    weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+                }
+            }
+        }
+
+        // 3.6.2 Point the weight setter delegates of the hotkey to the new hotkey.
+        // WeightSetterDelegate( netuid, operator ) --> the validator hotkey the operator sets weights for.
+        let operators: Vec<T::AccountId> = WeightSetterDelegate::<T>::iter_prefix(netuid)
+            .filter(|(_, validator)| *validator == *old_hotkey)
+            .map(|(operator, _)| operator)
+            .collect();
+        weight.saturating_accrue(T::DbWeight::get().reads(operators.len() as u64));
+        for operator in operators {
+            WeightSetterDelegate::<T>::insert(netuid, operator, new_hotkey.clone());
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
+        }
+
+        // 3.6.3 Move the lease of the hotkey and the lease offered for it.
+        // HotkeyLeases( netuid, hotkey ) --> the lease of the validation rights of the hotkey.
+        if let Some(lease) = HotkeyLeases::<T>::take(netuid, old_hotkey) {
+            HotkeyLeases::<T>::insert(netuid, new_hotkey, lease);
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
+        }
+        if let Some(offer) = HotkeyLeaseOffers::<T>::take(netuid, old_hotkey) {
+            HotkeyLeaseOffers::<T>::insert(netuid, new_hotkey, offer);
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+
         // 3.7. Swap neuron TLS certificates.
         // NeuronCertificates( netuid, hotkey ) -> Vec<u8> -- the neuron certificate for the hotkey.
         if is_network_member {
@@ -474,6 +535,14 @@ impl<T: Config> Pallet<T> {
         }
         // }
 
+        // 4.1 Swap ChildkeyTake.
+        // ChildkeyTake( hotkey, netuid ) --> take -- the childkey take of the hotkey on the subnet.
+        if ChildkeyTake::<T>::contains_key(old_hotkey, netuid) {
+            let take = ChildkeyTake::<T>::take(old_hotkey, netuid);
+            ChildkeyTake::<T>::insert(new_hotkey, netuid, take);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        }
+
         // 5. Swap ParentKeys.
         // ParentKeys( child, netuid ) --> Vec<(proportion,parent)> -- the parent keys of the child.
         let parents: Vec<(u64, T::AccountId)> = ParentKeys::<T>::get(old_hotkey, netuid);
@@ -546,6 +615,19 @@ impl<T: Config> Pallet<T> {
             }
         }
 
+        // 7.1 Point the owner cut vesting ledgers of the subnet staking to the hotkey to the new hotkey.
+        // OwnerCutVestings( netuid, coldkey ) --> owner cut of the subnet vesting for the coldkey.
+        let vestings: Vec<(T::AccountId, OwnerCutVesting<T::AccountId>)> =
+            OwnerCutVestings::<T>::iter_prefix(netuid)
+                .filter(|(_, vesting)| vesting.hotkey == *old_hotkey)
+                .collect();
+        weight.saturating_accrue(T::DbWeight::get().reads(vestings.len() as u64));
+        for (coldkey, mut vesting) in vestings {
+            vesting.hotkey = new_hotkey.clone();
+            OwnerCutVestings::<T>::insert(netuid, coldkey, vesting);
+            weight.saturating_accrue(T::DbWeight::get().writes(1));
+        }
+
         // 8. Swap dividend records
         // 8.1 Swap TotalHotkeyAlphaLastEpoch
         let old_alpha = TotalHotkeyAlphaLastEpoch::<T>::take(old_hotkey, netuid);
@@ -579,6 +661,20 @@ impl<T: Config> Pallet<T> {
         );
         weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
 
+        // 8.4 Swap DelegateEmissionWindows, adding to the windows of the new hotkey if any
+        if let Ok(old_windows) = DelegateEmissionWindows::<T>::try_get(netuid, old_hotkey) {
+            DelegateEmissionWindows::<T>::remove(netuid, old_hotkey);
+            if DelegateEmissionWindows::<T>::contains_key(netuid, new_hotkey) {
+                DelegateEmissionWindows::<T>::mutate(netuid, new_hotkey, |windows| {
+                    windows.1 = windows.1.saturating_add(old_windows.1);
+                    windows.2 = windows.2.saturating_add(old_windows.2);
+                });
+            } else {
+                DelegateEmissionWindows::<T>::insert(netuid, new_hotkey, old_windows);
+            }
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+
         // 9. Swap Alpha
         // Alpha( hotkey, coldkey, netuid ) -> alpha
         let old_alpha_values: Vec<((T::AccountId, NetUid), U64F64)> =
@@ -595,7 +691,13 @@ impl<T: Config> Pallet<T> {
                     (new_hotkey, &coldkey, netuid),
                     alpha.saturating_add(new_alpha),
                 );
-                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+                Self::inherit_stake_origin(
+                    StakeOrigins::<T>::take((netuid, old_hotkey, &coldkey)),
+                    new_hotkey,
+                    &coldkey,
+                    netuid,
+                );
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(3, 4));
 
                 // Swap StakingHotkeys.
                 // StakingHotkeys( coldkey ) --> Vec<hotkey> -- the hotkeys that the coldkey stakes.
@@ -9,6 +9,7 @@ use substrate_fixed::types::{I64F64, I96F32, U96F32};
 use subtensor_runtime_common::{AlphaCurrency, NetUidStorageIndex, TaoCurrency};
 use subtensor_swap_interface::SwapHandler;
 
+use crate::subnets::weight_row::WeightRow;
 use crate::{utils::rate_limiting::TransactionType, *};
 use sp_core::U256;
 
@@ -2184,6 +2185,146 @@ fn test_get_stake_for_hotkey_on_subnet_multiple_networks() {
     });
 }
 
+// Test that a parent splits its stake with different child proportions on each subnet
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::children::test_children_proportions_per_subnet --exact --show-output --nocapture
+#[test]
+fn test_children_proportions_per_subnet() {
+    new_test_ext(1).execute_with(|| {
+        let netuid1 = NetUid::from(1);
+        let netuid2 = NetUid::from(2);
+        let parent = U256::from(1);
+        let child = U256::from(2);
+        let coldkey = U256::from(3);
+
+        add_network(netuid1, 1, 0);
+        add_network(netuid2, 1, 0);
+        for netuid in [netuid1, netuid2] {
+            register_ok_neuron(netuid, parent, coldkey, 0);
+            register_ok_neuron(netuid, child, coldkey, 0);
+            SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                &parent,
+                &coldkey,
+                netuid,
+                1000.into(),
+            );
+        }
+
+        // The parent delegates all of its stake on the first subnet and a quarter on the second
+        mock_set_children(&coldkey, &parent, netuid1, &[(u64::MAX, child)]);
+        mock_set_children(&coldkey, &parent, netuid2, &[(u64::MAX / 4, child)]);
+        assert_eq!(
+            ChildKeys::<Test>::get(parent, netuid1),
+            vec![(u64::MAX, child)]
+        );
+        assert_eq!(
+            ChildKeys::<Test>::get(parent, netuid2),
+            vec![(u64::MAX / 4, child)]
+        );
+
+        close(
+            SubtensorModule::get_inherited_for_hotkey_on_subnet(&parent, netuid1).into(),
+            0,
+            10,
+            "Parent stake on network 1 incorrect",
+        );
+        close(
+            SubtensorModule::get_inherited_for_hotkey_on_subnet(&child, netuid1).into(),
+            1000,
+            10,
+            "Child stake on network 1 incorrect",
+        );
+        close(
+            SubtensorModule::get_inherited_for_hotkey_on_subnet(&parent, netuid2).into(),
+            750,
+            10,
+            "Parent stake on network 2 incorrect",
+        );
+        close(
+            SubtensorModule::get_inherited_for_hotkey_on_subnet(&child, netuid2).into(),
+            250,
+            10,
+            "Child stake on network 2 incorrect",
+        );
+    });
+}
+
+// Test setting the children of a parent on several subnets in one call
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::children::test_set_children_batch --exact --show-output --nocapture
+#[test]
+fn test_set_children_batch() {
+    new_test_ext(1).execute_with(|| {
+        let netuid1 = NetUid::from(1);
+        let netuid2 = NetUid::from(2);
+        let parent = U256::from(1);
+        let child1 = U256::from(2);
+        let child2 = U256::from(3);
+        let coldkey = U256::from(4);
+
+        add_network(netuid1, 1, 0);
+        add_network(netuid2, 1, 0);
+        register_ok_neuron(netuid1, parent, coldkey, 0);
+        register_ok_neuron(netuid2, parent, coldkey, 0);
+        StakeThreshold::<Test>::put(0);
+
+        assert_noop!(
+            SubtensorModule::set_children_batch(
+                RuntimeOrigin::signed(coldkey),
+                parent,
+                vec![
+                    (netuid1, vec![(u64::MAX, child1)]),
+                    (netuid1, vec![(u64::MAX, child2)])
+                ]
+            ),
+            Error::<Test>::InvalidChildrenBatch
+        );
+
+        // A failing subnet fails the whole batch
+        assert_noop!(
+            SubtensorModule::set_children_batch(
+                RuntimeOrigin::signed(coldkey),
+                parent,
+                vec![
+                    (netuid1, vec![(u64::MAX, child1)]),
+                    (netuid2, vec![(u64::MAX, parent)])
+                ]
+            ),
+            Error::<Test>::InvalidChild
+        );
+
+        assert_ok!(SubtensorModule::set_children_batch(
+            RuntimeOrigin::signed(coldkey),
+            parent,
+            vec![
+                (netuid1, vec![(u64::MAX, child1)]),
+                (
+                    netuid2,
+                    vec![(u64::MAX / 2, child1), (u64::MAX / 2, child2)]
+                )
+            ]
+        ));
+        wait_and_set_pending_children(netuid1);
+        wait_and_set_pending_children(netuid2);
+        assert_eq!(
+            ChildKeys::<Test>::get(parent, netuid1),
+            vec![(u64::MAX, child1)]
+        );
+        assert_eq!(
+            ChildKeys::<Test>::get(parent, netuid2),
+            vec![(u64::MAX / 2, child1), (u64::MAX / 2, child2)]
+        );
+
+        // Every subnet of the batch is rate limited
+        assert_noop!(
+            SubtensorModule::set_children_batch(
+                RuntimeOrigin::signed(coldkey),
+                parent,
+                vec![(netuid2, vec![])]
+            ),
+            Error::<Test>::TxRateLimitExceeded
+        );
+    });
+}
+
 // Test that min stake is enforced for setting children
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::children::test_do_set_child_below_min_stake --exact --show-output --nocapture
 #[test]
@@ -2906,8 +3047,16 @@ fn test_childkey_take_drain() {
             ));
 
             // Setup YUMA so that it creates emissions
-            Weights::<Test>::insert(NetUidStorageIndex::from(netuid), 0, vec![(2, 0xFFFF)]);
-            Weights::<Test>::insert(NetUidStorageIndex::from(netuid), 1, vec![(2, 0xFFFF)]);
+            Weights::<Test>::insert(
+                NetUidStorageIndex::from(netuid),
+                0,
+                WeightRow::from(vec![(2, 0xFFFF)]),
+            );
+            Weights::<Test>::insert(
+                NetUidStorageIndex::from(netuid),
+                1,
+                WeightRow::from(vec![(2, 0xFFFF)]),
+            );
             BlockAtRegistration::<Test>::set(netuid, 0, 1);
             BlockAtRegistration::<Test>::set(netuid, 1, 1);
             BlockAtRegistration::<Test>::set(netuid, 2, 1);
@@ -1,6 +1,10 @@
 #![allow(unused, clippy::indexing_slicing, clippy::panic, clippy::unwrap_used)]
 use super::mock::*;
 
+use crate::coinbase::emission_telemetry::EMISSION_TELEMETRY_LEN;
+use crate::coinbase::owner_cut_vesting::OwnerCutVesting;
+use crate::epoch::telemetry::{CONSENSUS_TELEMETRY_DIGEST_ID, ConsensusTelemetry};
+use crate::subnets::weight_row::WeightRow;
 use crate::tests::mock;
 use crate::*;
 use alloc::collections::BTreeMap;
@@ -8,6 +12,7 @@ use approx::assert_abs_diff_eq;
 use frame_support::assert_ok;
 use pallet_subtensor_swap::position::PositionId;
 use sp_core::U256;
+use sp_runtime::Percent;
 use substrate_fixed::types::{I64F64, I96F32, U96F32};
 use subtensor_runtime_common::{AlphaCurrency, NetUidStorageIndex};
 use subtensor_swap_interface::SwapHandler;
@@ -332,6 +337,32 @@ fn test_update_moving_price_after_time() {
     });
 }
 
+// Test moving price keeps tracking the pool while the emission of the subnet is paused.
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_update_moving_price_while_paused --exact --show-output --nocapture
+#[test]
+fn test_update_moving_price_while_paused() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        add_network(netuid, 1, 0);
+        // Set current price to 1.0
+        SubnetTAO::<Test>::insert(netuid, TaoCurrency::from(1_000_000));
+        SubnetAlphaIn::<Test>::insert(netuid, AlphaCurrency::from(1_000_000));
+        SubnetMechanism::<Test>::insert(netuid, 1);
+        SubnetMovingAlpha::<Test>::set(I96F32::from_num(0.5));
+        SubnetMovingPrice::<Test>::insert(netuid, I96F32::from_num(0));
+        SubnetEmissionPaused::<Test>::insert(netuid, true);
+
+        // Registered long time ago
+        System::set_block_number(144_000_500);
+        FirstEmissionBlockNumber::<Test>::insert(netuid, 500);
+
+        SubtensorModule::run_coinbase(U96F32::from_num(0));
+
+        let new_price = SubnetMovingPrice::<Test>::get(netuid);
+        assert!((new_price.to_num::<f64>() - 0.5).abs() < 0.001);
+    });
+}
+
 // Test basic alpha issuance in coinbase mechanism.
 // This test verifies that:
 // - Alpha issuance is initialized to 0 for new subnets
@@ -601,6 +632,60 @@ fn test_drain_base_with_subnet() {
     });
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_consensus_telemetry_digest --exact --show-output --nocapture
+#[test]
+fn test_consensus_telemetry_digest() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let netuid_index = NetUidStorageIndex::from(netuid);
+        add_network(netuid, 1, 0);
+        for uid in 0..3 {
+            register_ok_neuron(netuid, U256::from(uid), U256::from(uid + 100), 0);
+        }
+        for uid in 0..2 {
+            SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                &U256::from(uid),
+                &U256::from(uid + 100),
+                netuid,
+                1_000_000_000.into(),
+            );
+        }
+        System::set_block_number(100);
+
+        // Two validators, one of which set weights 10 blocks ago
+        ValidatorPermit::<Test>::insert(netuid, vec![true, true, false]);
+        Weights::<Test>::insert(netuid_index, 0, WeightRow::from(vec![(2, u16::MAX)]));
+        LastUpdate::<Test>::insert(netuid_index, vec![90, 0, 0]);
+
+        // Half of the bond mass changes
+        let bonds_before = BTreeMap::from([(0, BTreeMap::from([(2, 100)]))]);
+        Bonds::<Test>::insert(netuid_index, 0, vec![(2, 300)]);
+
+        SubtensorModule::deposit_consensus_telemetry(netuid, &bonds_before);
+
+        let telemetry = System::digest()
+            .logs
+            .iter()
+            .find_map(|item| match item {
+                sp_runtime::DigestItem::Other(data) => {
+                    <([u8; 4], ConsensusTelemetry)>::decode(&mut &data[..]).ok()
+                }
+                _ => None,
+            })
+            .filter(|(id, _)| *id == CONSENSUS_TELEMETRY_DIGEST_ID)
+            .map(|(_, telemetry)| telemetry)
+            .unwrap();
+
+        assert_eq!(telemetry.netuid, netuid);
+        assert_eq!(telemetry.block, 100);
+        assert_abs_diff_eq!(telemetry.weights_stake_share, u16::MAX / 2, epsilon = 1);
+        assert_eq!(telemetry.mean_weight_age, 10);
+        assert_eq!(telemetry.validators, 2);
+        assert_eq!(telemetry.miners, 1);
+        assert_abs_diff_eq!(telemetry.bond_turnover, u16::MAX / 2, epsilon = 1);
+    });
+}
+
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::coinbase::test_drain_base_with_subnet_with_single_staker_not_registered --exact --show-output --nocapture
 #[test]
 fn test_drain_base_with_subnet_with_single_staker_not_registered() {
@@ -2876,3 +2961,400 @@ fn test_incentive_goes_to_hotkey_when_no_autostake_destination() {
         );
     });
 }
+
+#[test]
+fn test_miner_emission_share_scales_incentives() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = add_dynamic_network(&U256::from(1), &U256::from(0));
+        let miner_hk = U256::from(10);
+        let validator_hk = U256::from(11);
+        let emission = AlphaCurrency::from(1_000_000);
+        let zero = AlphaCurrency::ZERO;
+        let hotkey_emission = vec![(miner_hk, emission, zero), (validator_hk, zero, emission)];
+
+        // The default split is even and leaves the epoch emission unchanged
+        assert_eq!(
+            SubtensorModule::scale_miner_incentives(netuid, hotkey_emission.clone()),
+            hotkey_emission
+        );
+        assert_eq!(
+            SubtensorModule::get_validator_emission_fraction(netuid),
+            U96F32::from_num(0.5)
+        );
+
+        // Out of the bounds set by root
+        assert_eq!(
+            SubtensorModule::set_miner_emission_share(netuid, Percent::from_percent(61)),
+            Err(Error::<Test>::MinerEmissionShareOutOfBounds)
+        );
+
+        // 60/40 raises the incentives and leaves the dividends to be scaled to the validator share
+        assert_ok!(SubtensorModule::set_miner_emission_share(
+            netuid,
+            Percent::from_percent(60)
+        ));
+        System::assert_last_event(
+            Event::MinerEmissionShareSet {
+                netuid,
+                share: Percent::from_percent(60),
+            }
+            .into(),
+        );
+        assert_eq!(
+            SubtensorModule::scale_miner_incentives(netuid, hotkey_emission),
+            vec![
+                (miner_hk, AlphaCurrency::from(1_200_000), zero),
+                (validator_hk, zero, emission),
+            ]
+        );
+        assert_eq!(
+            SubtensorModule::get_validator_emission_share(netuid),
+            Percent::from_percent(40)
+        );
+
+        // The share is dropped with the subnet
+        SubtensorModule::remove_network(netuid);
+        assert_eq!(
+            SubtensorModule::get_miner_emission_share(netuid),
+            Percent::from_percent(50)
+        );
+    });
+}
+
+#[test]
+fn test_pause_subnet_emission() {
+    new_test_ext(1).execute_with(|| {
+        let netuid1 = NetUid::from(1);
+        let netuid2 = NetUid::from(2);
+        let emission = TaoCurrency::from(1_000_000);
+        add_network(netuid1, 1, 0);
+        add_network(netuid2, 1, 0);
+
+        assert_eq!(
+            SubtensorModule::pause_subnet_emission(RuntimeOrigin::signed(U256::from(1)), netuid1),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            SubtensorModule::resume_subnet_emission(RuntimeOrigin::root(), netuid1),
+            Err(Error::<Test>::SubnetEmissionNotPaused.into())
+        );
+        assert_ok!(SubtensorModule::pause_subnet_emission(
+            RuntimeOrigin::root(),
+            netuid1
+        ));
+        System::assert_last_event(Event::SubnetEmissionPaused { netuid: netuid1 }.into());
+        assert_eq!(
+            SubtensorModule::pause_subnet_emission(RuntimeOrigin::root(), netuid1),
+            Err(Error::<Test>::SubnetEmissionAlreadyPaused.into())
+        );
+
+        // The paused subnet gets nothing, the other one the whole emission
+        SubtensorModule::run_coinbase(U96F32::from_num(emission));
+        assert_eq!(SubnetTAO::<Test>::get(netuid1), TaoCurrency::ZERO);
+        assert_eq!(PendingEmission::<Test>::get(netuid1), AlphaCurrency::ZERO);
+        assert_eq!(SubnetTAO::<Test>::get(netuid2), emission);
+
+        // The subnet emits again once resumed
+        assert_ok!(SubtensorModule::resume_subnet_emission(
+            RuntimeOrigin::root(),
+            netuid1
+        ));
+        System::assert_last_event(Event::SubnetEmissionResumed { netuid: netuid1 }.into());
+        SubtensorModule::run_coinbase(U96F32::from_num(emission));
+        assert!(SubnetTAO::<Test>::get(netuid1) > TaoCurrency::ZERO);
+    });
+}
+
+#[test]
+fn test_owner_cut_vesting() {
+    new_test_ext(1).execute_with(|| {
+        let owner_ck = U256::from(0);
+        let owner_hk = U256::from(1);
+        let netuid = add_dynamic_network(&owner_hk, &owner_ck);
+        let owner_stake = || {
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &owner_hk, &owner_ck, netuid,
+            )
+        };
+        let distribute_owner_cut = |owner_cut: u64| {
+            SubtensorModule::distribute_dividends_and_incentives(
+                netuid,
+                owner_cut.into(),
+                BTreeMap::new(),
+                BTreeMap::new(),
+                BTreeMap::new(),
+            )
+        };
+        assert_ok!(SubtensorModule::set_owner_cut_vesting_blocks(netuid, 100));
+
+        // The owner cut is locked when emitted
+        System::set_block_number(1_000);
+        let stake_before = owner_stake();
+        distribute_owner_cut(1_000_000);
+        assert_eq!(owner_stake(), stake_before);
+        assert_eq!(
+            OwnerCutVestings::<Test>::get(netuid, owner_ck).map(|vesting| vesting.locked),
+            Some(AlphaCurrency::from(1_000_000))
+        );
+
+        // and vests linearly
+        System::set_block_number(1_050);
+        distribute_owner_cut(0);
+        close(
+            u64::from(owner_stake()),
+            u64::from(stake_before) + 500_000,
+            10,
+        );
+
+        // The owner cut accruing extends the vesting of the alpha still locked
+        distribute_owner_cut(500_000);
+        assert_eq!(
+            OwnerCutVestings::<Test>::get(netuid, owner_ck),
+            Some(OwnerCutVesting {
+                hotkey: owner_hk,
+                locked: 1_000_000.into(),
+                start_block: 1_050,
+                end_block: 1_150,
+            })
+        );
+        System::set_block_number(1_150);
+        distribute_owner_cut(0);
+        close(
+            u64::from(owner_stake()),
+            u64::from(stake_before) + 1_500_000,
+            10,
+        );
+        assert!(OwnerCutVestings::<Test>::get(netuid, owner_ck).is_none());
+    });
+}
+
+#[test]
+fn test_emission_telemetry() {
+    new_test_ext(1).execute_with(|| {
+        let netuid1 = NetUid::from(1);
+        let netuid2 = NetUid::from(2);
+        let emission = U96F32::from_num(1_000_000);
+        add_network(netuid1, 1, 0);
+        add_network(netuid2, 1, 0);
+
+        System::set_block_number(1);
+        SubtensorModule::run_coinbase(emission);
+        let telemetry = SubtensorModule::get_emission_telemetry();
+        assert_eq!(telemetry.len(), 1);
+        let summary = &telemetry[0];
+        assert_eq!(summary.block, 1);
+        assert_eq!(
+            summary.subnets.iter().map(|s| s.netuid).collect::<Vec<_>>(),
+            vec![netuid1, netuid2]
+        );
+        for subnet in summary.subnets.iter() {
+            assert_eq!(subnet.tao_in, SubnetTAO::<Test>::get(subnet.netuid));
+            assert_eq!(
+                subnet.alpha_in,
+                SubnetAlphaInEmission::<Test>::get(subnet.netuid)
+            );
+            assert_eq!(
+                subnet.alpha_out,
+                SubnetAlphaOutEmission::<Test>::get(subnet.netuid)
+            );
+        }
+        assert_eq!(
+            summary.tao_injected,
+            SubnetTAO::<Test>::get(netuid1).saturating_add(SubnetTAO::<Test>::get(netuid2))
+        );
+
+        // Summaries are kept oldest first for the last blocks only
+        System::set_block_number(2);
+        SubtensorModule::run_coinbase(emission);
+        System::set_block_number(1 + EMISSION_TELEMETRY_LEN);
+        SubtensorModule::run_coinbase(emission);
+        let blocks: Vec<u64> = SubtensorModule::get_emission_telemetry()
+            .iter()
+            .map(|summary| summary.block)
+            .collect();
+        assert_eq!(blocks, vec![2, 1 + EMISSION_TELEMETRY_LEN]);
+        assert_eq!(EmissionTelemetryLog::<Test>::iter().count(), 2);
+    });
+}
+
+#[test]
+fn test_issuance_schedule() {
+    new_test_ext(1).execute_with(|| {
+        System::set_block_number(100);
+        TotalIssuance::<Test>::put(TaoCurrency::from(1_000_000_000_000_000));
+        let schedule = SubtensorModule::get_issuance_schedule();
+        assert_eq!(schedule.block, 100u64.into());
+        assert_eq!(
+            schedule.block_emission,
+            TaoCurrency::from(1_000_000_000).into()
+        );
+        assert_eq!(
+            schedule.remaining_supply,
+            TaoCurrency::from(20_000_000_000_000_000).into()
+        );
+        assert_eq!(
+            schedule.next_halving_issuance,
+            Some(TaoCurrency::from(10_500_000_000_000_000).into())
+        );
+        assert_eq!(schedule.next_halving_block, Some(9_500_100u64.into()));
+
+        // Past the first halving the emission is halved and the next one is at three quarters
+        TotalIssuance::<Test>::put(TaoCurrency::from(10_500_001_000_000_000));
+        let schedule = SubtensorModule::get_issuance_schedule();
+        assert_eq!(
+            schedule.block_emission,
+            TaoCurrency::from(500_000_000).into()
+        );
+        assert_eq!(
+            schedule.next_halving_issuance,
+            Some(TaoCurrency::from(15_750_000_000_000_000).into())
+        );
+        assert_eq!(schedule.next_halving_block, Some(10_500_098u64.into()));
+
+        // Nothing is left to issue at the cap
+        TotalIssuance::<Test>::put(TaoCurrency::from(21_000_000_000_000_000));
+        let schedule = SubtensorModule::get_issuance_schedule();
+        assert_eq!(schedule.block_emission, TaoCurrency::ZERO.into());
+        assert_eq!(schedule.remaining_supply, TaoCurrency::ZERO.into());
+        assert_eq!(schedule.next_halving_issuance, None);
+        assert_eq!(schedule.next_halving_block, None);
+    });
+}
+
+#[test]
+fn test_max_subnet_emission_share() {
+    new_test_ext(1).execute_with(|| {
+        let emission = U96F32::from_num(1_000);
+        let moving_prices: BTreeMap<NetUid, U96F32> = [(1, 6), (2, 2), (3, 1), (4, 1)]
+            .into_iter()
+            .map(|(netuid, price)| (NetUid::from(netuid), U96F32::from_num(price)))
+            .collect();
+        let emissions = |prices: &BTreeMap<NetUid, U96F32>| -> Vec<u64> {
+            SubtensorModule::get_subnet_tao_emissions(emission, prices)
+                .values()
+                .map(|emission| emission.to_num::<u64>())
+                .collect()
+        };
+
+        // Without a cap the emission follows the moving prices
+        assert_eq!(emissions(&moving_prices), vec![600, 200, 100, 100]);
+
+        // The excess of a capped subnet is redistributed pro-rata
+        assert_ok!(SubtensorModule::set_max_subnet_emission_share(
+            Percent::from_percent(40)
+        ));
+        assert_eq!(emissions(&moving_prices), vec![400, 300, 150, 150]);
+
+        // Emission is left over once every subnet is capped
+        assert_ok!(SubtensorModule::set_max_subnet_emission_share(
+            Percent::from_percent(20)
+        ));
+        assert_eq!(emissions(&moving_prices), vec![200, 200, 200, 200]);
+    });
+}
+
+#[test]
+fn test_network_treasury() {
+    new_test_ext(1).execute_with(|| {
+        let beneficiary = U256::from(7);
+        let issuance = SubtensorModule::get_total_issuance();
+
+        // Without a share everything is burned
+        assert_eq!(
+            SubtensorModule::keep_network_treasury_share(TaoCurrency::from(1_000)),
+            TaoCurrency::from(1_000)
+        );
+        assert_eq!(SubtensorModule::get_network_treasury(), TaoCurrency::ZERO);
+
+        // The treasury keeps its share, which stays in the issuance
+        SubtensorModule::set_network_treasury_share(u16::MAX / 4);
+        let burned = SubtensorModule::keep_network_treasury_share(TaoCurrency::from(1_000));
+        assert_eq!(burned, TaoCurrency::from(751));
+        assert_eq!(
+            SubtensorModule::get_network_treasury(),
+            TaoCurrency::from(249)
+        );
+        assert_eq!(SubtensorModule::get_total_issuance(), issuance);
+
+        // Only root spends the treasury, within its balance
+        assert_eq!(
+            SubtensorModule::spend_network_treasury(
+                RuntimeOrigin::signed(beneficiary),
+                beneficiary,
+                TaoCurrency::from(100)
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            SubtensorModule::spend_network_treasury(
+                RuntimeOrigin::root(),
+                beneficiary,
+                TaoCurrency::from(250)
+            ),
+            Err(Error::<Test>::InsufficientNetworkTreasury.into())
+        );
+        assert_ok!(SubtensorModule::spend_network_treasury(
+            RuntimeOrigin::root(),
+            beneficiary,
+            TaoCurrency::from(100)
+        ));
+        assert_eq!(
+            SubtensorModule::get_network_treasury(),
+            TaoCurrency::from(149)
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&beneficiary), 100);
+    });
+}
+
+#[test]
+fn test_neuron_lifetime_emission() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_ck = U256::from(0);
+        let subnet_owner_hk = U256::from(1);
+        let miner_ck = U256::from(2);
+        let miner_hk = U256::from(3);
+        let netuid = add_dynamic_network(&subnet_owner_hk, &subnet_owner_ck);
+        register_ok_neuron(netuid, miner_hk, miner_ck, 0);
+        let miner_uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &miner_hk).unwrap();
+
+        let distribute = || {
+            let mut incentives: BTreeMap<U256, AlphaCurrency> = BTreeMap::new();
+            incentives.insert(miner_hk, 1_000.into());
+            incentives.insert(subnet_owner_hk, 1_000.into());
+            let mut alpha_dividends: BTreeMap<U256, U96F32> = BTreeMap::new();
+            alpha_dividends.insert(miner_hk, U96F32::from_num(500));
+            SubtensorModule::distribute_dividends_and_incentives(
+                netuid,
+                AlphaCurrency::ZERO,
+                incentives,
+                alpha_dividends,
+                BTreeMap::new(),
+            );
+        };
+
+        // Incentive and dividends add up over epochs
+        distribute();
+        distribute();
+        assert_eq!(
+            SubtensorModule::get_neuron_lifetime_emission(netuid, miner_uid),
+            3_000.into()
+        );
+
+        // The burned incentive of the owner hotkey is not earned
+        let owner_uid =
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &subnet_owner_hk).unwrap();
+        assert_eq!(
+            SubtensorModule::get_neuron_lifetime_emission(netuid, owner_uid),
+            0.into()
+        );
+        let emissions = SubtensorModule::get_neuron_lifetime_emissions(netuid);
+        assert_eq!(emissions.get(usize::from(miner_uid)), Some(&3_000.into()));
+
+        // The lifetime emission restarts with the next neuron under the uid
+        SubtensorModule::replace_neuron(netuid, miner_uid, &U256::from(4), 10);
+        assert_eq!(
+            SubtensorModule::get_neuron_lifetime_emission(netuid, miner_uid),
+            0.into()
+        );
+    });
+}
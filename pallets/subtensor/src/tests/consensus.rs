@@ -543,3 +543,68 @@ fn map_consensus_guarantees() {
     }
     println!("]");
 }
+
+#[test]
+fn test_consensus_engines() {
+    use crate::epoch::consensus::ConsensusEngineKind;
+
+    // A majority validator weighs uid 0, two minority validators weigh uid 1
+    let stake = vec![fixed(0.6), fixed(0.2), fixed(0.2)];
+    let weights = vec![
+        vec![(0, fixed(1.0))],
+        vec![(1, fixed(1.0))],
+        vec![(1, fixed(1.0))],
+    ];
+    let dense_weights = vec![
+        vec![fixed(1.0), fixed(0.0)],
+        vec![fixed(0.0), fixed(1.0)],
+        vec![fixed(0.0), fixed(1.0)],
+    ];
+    let kappa = fixed(0.5);
+
+    for (engine, expected) in [
+        (ConsensusEngineKind::Yuma, [1.0, 0.0]),
+        (ConsensusEngineKind::StakeWeightedAverage, [0.6, 0.4]),
+        (ConsensusEngineKind::TrustedMedian, [0.0, 1.0]),
+    ] {
+        for consensus in [
+            engine.consensus_sparse(&stake, &weights, 2, kappa),
+            engine.consensus_dense(&stake, &dense_weights, kappa),
+        ] {
+            assert_eq!(consensus.len(), 2);
+            for (value, expected) in consensus.iter().zip(expected) {
+                assert!(
+                    (value.to_num::<f64>() - expected).abs() < 1e-6,
+                    "{engine:?}: {consensus:?}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_consensus_engine_selected_per_subnet() {
+    use crate::epoch::consensus::ConsensusEngineKind;
+
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        add_network(netuid, 10, 0);
+        assert_eq!(
+            SubtensorModule::get_consensus_engine(netuid),
+            ConsensusEngineKind::Yuma
+        );
+
+        SubtensorModule::set_consensus_engine(netuid, ConsensusEngineKind::TrustedMedian);
+        assert_eq!(
+            SubtensorModule::get_consensus_engine(netuid),
+            ConsensusEngineKind::TrustedMedian
+        );
+        assert_eq!(
+            SubtensorModule::get_consensus_engine(NetUid::from(2)),
+            ConsensusEngineKind::Yuma
+        );
+
+        SubtensorModule::remove_network(netuid);
+        assert!(!SubnetConsensusEngine::<Test>::contains_key(netuid));
+    });
+}
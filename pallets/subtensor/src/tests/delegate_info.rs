@@ -1,5 +1,8 @@
+#![allow(clippy::unwrap_used)]
+
 use super::mock::*;
 
+use crate::Delegates;
 use codec::Compact;
 use frame_support::assert_ok;
 use scale_info::prelude::collections::HashMap;
@@ -164,3 +167,52 @@ fn test_get_delegated() {
         }
     });
 }
+
+#[test]
+fn test_trailing_delegate_emission() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let hotkey = U256::from(1);
+        add_network(netuid, 1, 0);
+
+        // Dividends of the current window are all counted
+        System::set_block_number(100);
+        SubtensorModule::record_delegate_emission(netuid, &hotkey, 1_000.into());
+        SubtensorModule::record_delegate_emission(netuid, &hotkey, 500.into());
+        assert_eq!(
+            SubtensorModule::get_trailing_delegate_emission(netuid, &hotkey),
+            1_500.into()
+        );
+
+        // A quarter into the next window, three quarters of the previous one are counted
+        System::set_block_number(7_200 + 1_800);
+        SubtensorModule::record_delegate_emission(netuid, &hotkey, 200.into());
+        assert_eq!(
+            SubtensorModule::get_trailing_delegate_emission(netuid, &hotkey),
+            (200 + 1_125).into()
+        );
+
+        // Nothing is left once both windows are over
+        System::set_block_number(3 * 7_200);
+        assert_eq!(
+            SubtensorModule::get_trailing_delegate_emission(netuid, &hotkey),
+            0.into()
+        );
+
+        // The delegate reports the trailing emission of the subnets it earned on
+        Delegates::<Test>::insert(hotkey, 1_000);
+        SubtensorModule::record_delegate_emission(netuid, &hotkey, 300.into());
+        let performance = SubtensorModule::get_delegates_performance();
+        assert_eq!(performance.len(), 1);
+        let delegate = performance.first().unwrap();
+        assert_eq!(delegate.take, 1_000.into());
+        assert_eq!(
+            delegate
+                .subnets
+                .iter()
+                .map(|subnet| (subnet.netuid, subnet.trailing_emission))
+                .collect::<Vec<_>>(),
+            vec![(netuid.into(), 300.into())]
+        );
+    });
+}
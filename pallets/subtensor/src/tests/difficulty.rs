@@ -1,9 +1,11 @@
 #![allow(clippy::unwrap_used)]
 
 use sp_core::U256;
+use substrate_fixed::types::I32F32;
 use subtensor_runtime_common::NetUid;
 
 use super::mock::*;
+use crate::coinbase::difficulty_controller::DifficultyControllerGains;
 
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::difficulty::test_registration_difficulty_adjustment --exact --show-output --nocapture
 #[test]
@@ -171,3 +173,95 @@ fn test_registration_difficulty_adjustment() {
         assert_eq!(SubtensorModule::get_difficulty_as_u64(netuid), 5833); // Difficulty unchanged
     });
 }
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::difficulty::test_difficulty_controller --exact --show-output --nocapture
+#[test]
+fn test_difficulty_controller() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        add_network(netuid, 1, 0);
+        SubtensorModule::set_min_difficulty(netuid, 1_000);
+        SubtensorModule::set_max_difficulty(netuid, 1_000_000);
+        SubtensorModule::set_difficulty(netuid, 10_000);
+
+        // Without gains the step-wise adjustment is used.
+        assert!(!SubtensorModule::run_difficulty_controller(
+            netuid, 10_000, 6, 2
+        ));
+        assert_eq!(SubtensorModule::get_difficulty_as_u64(netuid), 10_000);
+
+        SubtensorModule::set_difficulty_controller_gains(
+            netuid,
+            DifficultyControllerGains {
+                kp: 500_000,
+                ki: 0,
+                kd: 0,
+            },
+        );
+
+        // Registrations at three times the target double the difficulty: 1 + 0.5 * 2 = 2.
+        assert!(SubtensorModule::run_difficulty_controller(
+            netuid, 10_000, 6, 2
+        ));
+        assert_eq!(SubtensorModule::get_difficulty_as_u64(netuid), 20_000);
+        let state = crate::DifficultyControllerStates::<Test>::get(netuid);
+        assert_eq!(state.integral, I32F32::from_num(2));
+        assert_eq!(state.last_error, I32F32::from_num(2));
+
+        // On target the difficulty holds, the integral keeps the past errors.
+        assert!(SubtensorModule::run_difficulty_controller(
+            netuid, 20_000, 2, 2
+        ));
+        assert_eq!(SubtensorModule::get_difficulty_as_u64(netuid), 20_000);
+        let state = crate::DifficultyControllerStates::<Test>::get(netuid);
+        assert_eq!(state.integral, I32F32::from_num(2));
+        assert_eq!(state.last_error, I32F32::from_num(0));
+
+        // A single adjustment at most doubles the difficulty, within the max difficulty.
+        SubtensorModule::set_max_difficulty(netuid, 30_000);
+        assert!(SubtensorModule::run_difficulty_controller(
+            netuid, 20_000, 20, 2
+        ));
+        assert_eq!(SubtensorModule::get_difficulty_as_u64(netuid), 30_000);
+
+        // No registrations halve it: 1 + 0.5 * -1 = 0.5.
+        assert!(SubtensorModule::run_difficulty_controller(
+            netuid, 30_000, 0, 2
+        ));
+        assert_eq!(SubtensorModule::get_difficulty_as_u64(netuid), 15_000);
+
+        // The integral is bounded against windup.
+        SubtensorModule::set_difficulty_controller_gains(
+            netuid,
+            DifficultyControllerGains {
+                kp: 0,
+                ki: 1_000,
+                kd: 0,
+            },
+        );
+        for _ in 0..10 {
+            SubtensorModule::run_difficulty_controller(netuid, 15_000, 10, 2);
+        }
+        assert_eq!(
+            crate::DifficultyControllerStates::<Test>::get(netuid).integral,
+            I32F32::from_num(16)
+        );
+
+        // Zero gains remove the controller and its state.
+        SubtensorModule::set_difficulty_controller_gains(
+            netuid,
+            DifficultyControllerGains::default(),
+        );
+        assert_eq!(
+            SubtensorModule::get_difficulty_controller_gains(netuid),
+            None
+        );
+        assert!(!crate::DifficultyControllerStates::<Test>::contains_key(
+            netuid
+        ));
+        assert!(!SubtensorModule::run_difficulty_controller(
+            netuid, 15_000, 6, 2
+        ));
+        assert_eq!(SubtensorModule::get_difficulty_as_u64(netuid), 15_000);
+    });
+}
@@ -109,7 +109,7 @@ fn ensure_owner_or_root_with_limits_checks_rl_and_freeze() {
             &[Hyperparameter::Kappa.into()],
         )
         .expect("should pass");
-        assert_eq!(res, Some(owner));
+        assert_eq!(res.owner, Some(owner));
 
         // Simulate previous update at current block -> next call should fail due to rate limit
         let now = crate::Pallet::<Test>::get_current_block_as_u64();
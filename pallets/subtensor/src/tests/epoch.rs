@@ -16,6 +16,7 @@ use subtensor_swap_interface::SwapHandler;
 
 use super::mock::*;
 use crate::epoch::math::{fixed, u16_proportion_to_fixed};
+use crate::subnets::weight_row::WeightRow;
 use crate::tests::math::{assert_mat_compare, vec_to_fixed, vec_to_mat_fixed};
 use crate::*;
 
@@ -2500,7 +2501,7 @@ fn test_can_set_self_weight_as_subnet_owner() {
         Weights::<Test>::insert(
             NetUidStorageIndex::from(netuid),
             0,
-            vec![(0, fifty_percent), (1, fifty_percent)],
+            WeightRow::from(vec![(0, fifty_percent), (1, fifty_percent)]),
         );
 
         step_block(1);
@@ -2529,6 +2530,199 @@ fn test_can_set_self_weight_as_subnet_owner() {
     });
 }
 
+#[test]
+fn test_self_weights_excluded_masks_subnet_owner() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey: U256 = U256::from(1);
+        let subnet_owner_hotkey: U256 = U256::from(1 + 456);
+        let other_hotkey: U256 = U256::from(2);
+        let stake = 5_000_000_000_000; // 5k TAO
+        let to_emit: u64 = 1_000_000_000; // 1 TAO
+
+        let netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        register_ok_neuron(netuid, other_hotkey, subnet_owner_coldkey, 0);
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &subnet_owner_hotkey,
+            &subnet_owner_coldkey,
+            netuid,
+            stake.into(),
+        );
+        ValidatorPermit::<Test>::insert(netuid, vec![true, false]);
+
+        // The owner hotkey puts half of its weight on itself
+        let fifty_percent: u16 = u16::MAX / 2;
+        Weights::<Test>::insert(
+            NetUidStorageIndex::from(netuid),
+            0,
+            WeightRow::from(vec![(0, fifty_percent), (1, fifty_percent)]),
+        );
+        step_block(1);
+        LastUpdate::<Test>::insert(NetUidStorageIndex::from(netuid), vec![2, 0]);
+
+        SubtensorModule::set_self_weights_excluded(netuid, true);
+        let hotkey_emission = SubtensorModule::epoch(netuid, to_emit.into());
+
+        // Only the other hotkey earns incentive
+        let incentive = |hotkey: U256| {
+            hotkey_emission
+                .iter()
+                .find(|(hk, _, _)| *hk == hotkey)
+                .map(|(_, incentive, _)| *incentive)
+                .unwrap()
+        };
+        assert_eq!(incentive(subnet_owner_hotkey), AlphaCurrency::ZERO);
+        assert!(incentive(other_hotkey) > AlphaCurrency::ZERO);
+    });
+}
+
+#[test]
+fn test_expired_weights_ignored_by_epoch() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey: U256 = U256::from(1);
+        let subnet_owner_hotkey: U256 = U256::from(1 + 456);
+        let other_hotkey: U256 = U256::from(2);
+        let stake = 5_000_000_000_000; // 5k TAO
+        let to_emit: u64 = 1_000_000_000; // 1 TAO
+
+        let netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        register_ok_neuron(netuid, other_hotkey, subnet_owner_coldkey, 0);
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &subnet_owner_hotkey,
+            &subnet_owner_coldkey,
+            netuid,
+            stake.into(),
+        );
+        ValidatorPermit::<Test>::insert(netuid, vec![true, false]);
+        Weights::<Test>::insert(
+            NetUidStorageIndex::from(netuid),
+            0,
+            WeightRow::from(vec![(1, u16::MAX)]),
+        );
+        step_block(1);
+        let updated = SubtensorModule::get_current_block_as_u64();
+        LastUpdate::<Test>::insert(NetUidStorageIndex::from(netuid), vec![updated, 0]);
+        SubtensorModule::set_weight_expiry_blocks(netuid, 10);
+
+        let incentive_at = |block: u64| {
+            System::set_block_number(block);
+            SubtensorModule::epoch(netuid, to_emit.into())
+                .into_iter()
+                .find(|(hotkey, _, _)| *hotkey == other_hotkey)
+                .map(|(_, incentive, _)| incentive)
+                .unwrap()
+        };
+
+        // Weights count until the expiry has passed since their last update
+        assert!(incentive_at(updated + 10) > AlphaCurrency::ZERO);
+        assert_eq!(incentive_at(updated + 11), AlphaCurrency::ZERO);
+
+        // Without an expiry they never stop counting
+        SubtensorModule::set_weight_expiry_blocks(netuid, 0);
+        assert!(incentive_at(updated + 11) > AlphaCurrency::ZERO);
+    });
+}
+
+#[test]
+fn test_validator_permit_min_stake() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey: U256 = U256::from(1);
+        let subnet_owner_hotkey: U256 = U256::from(1 + 456);
+        let other_hotkey: U256 = U256::from(2);
+        let to_emit: u64 = 1_000_000_000; // 1 TAO
+
+        let netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        register_ok_neuron(netuid, other_hotkey, subnet_owner_coldkey, 0);
+        for (hotkey, stake) in [
+            (subnet_owner_hotkey, 5_000_000_000_000u64), // 5k TAO
+            (other_hotkey, 1_000_000_000_000u64),        // 1k TAO
+        ] {
+            SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey,
+                &subnet_owner_coldkey,
+                netuid,
+                stake.into(),
+            );
+        }
+        ValidatorPermit::<Test>::insert(netuid, vec![false, true]);
+
+        // Only the neuron meeting the minimum stake holds a permit
+        SubtensorModule::set_validator_permit_min_stake(netuid, 2_000_000_000_000);
+        System::reset_events();
+        SubtensorModule::epoch(netuid, to_emit.into());
+        assert_eq!(
+            SubtensorModule::get_validator_permit(netuid),
+            vec![true, false]
+        );
+        System::assert_has_event(Event::ValidatorPermitGranted { netuid, uid: 0 }.into());
+        System::assert_has_event(Event::ValidatorPermitRevoked { netuid, uid: 1 }.into());
+
+        // Without a minimum the permits go to the largest stakes, up to the validator bound
+        SubtensorModule::set_validator_permit_min_stake(netuid, 0);
+        SubtensorModule::epoch(netuid, to_emit.into());
+        assert_eq!(
+            SubtensorModule::get_validator_permit(netuid),
+            vec![true, true]
+        );
+        System::assert_last_event(Event::ValidatorPermitGranted { netuid, uid: 1 }.into());
+
+        SubtensorModule::set_max_allowed_validators(netuid, 1);
+        SubtensorModule::epoch(netuid, to_emit.into());
+        assert_eq!(
+            SubtensorModule::get_validator_permit(netuid),
+            vec![true, false]
+        );
+        System::assert_last_event(Event::ValidatorPermitRevoked { netuid, uid: 1 }.into());
+    });
+}
+
+#[test]
+fn test_adaptive_max_weight_limit() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey: U256 = U256::from(1);
+        let subnet_owner_hotkey: U256 = U256::from(1 + 456);
+        let other_hotkey: U256 = U256::from(2);
+        let to_emit: u64 = 1_000_000_000; // 1 TAO
+
+        let netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        register_ok_neuron(netuid, other_hotkey, subnet_owner_coldkey, 0);
+        for hotkey in [subnet_owner_hotkey, other_hotkey] {
+            SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey,
+                &subnet_owner_coldkey,
+                netuid,
+                1_000_000_000_000u64.into(),
+            );
+        }
+        SubtensorModule::set_max_weight_limit(netuid, 1_000);
+
+        // A fixed max weight limit is left as is
+        SubtensorModule::epoch(netuid, to_emit.into());
+        assert_eq!(SubtensorModule::get_max_weight_limit(netuid), 1_000);
+
+        // Both uids are active, each may get at most the uniform weight
+        SubtensorModule::set_adaptive_max_weight_limit(netuid, 1);
+        SubtensorModule::epoch(netuid, to_emit.into());
+        assert_eq!(SubtensorModule::get_active(netuid), vec![true, true]);
+        assert_eq!(SubtensorModule::get_max_weight_limit(netuid), u16::MAX / 2);
+        System::assert_has_event(Event::MaxWeightLimitSet(netuid, u16::MAX / 2).into());
+
+        // The limit grows as uids turn inactive
+        SubtensorModule::set_activity_cutoff(netuid, 10);
+        System::set_block_number(100);
+        LastUpdate::<Test>::insert(NetUidStorageIndex::from(netuid), vec![100, 0]);
+        SubtensorModule::epoch(netuid, to_emit.into());
+        assert_eq!(SubtensorModule::get_active(netuid), vec![true, false]);
+        assert_eq!(SubtensorModule::get_max_weight_limit(netuid), u16::MAX);
+
+        // A larger multiple is capped at a weight of one
+        SubtensorModule::set_adaptive_max_weight_limit(netuid, 3);
+        SubtensorModule::set_max_weight_limit(netuid, 1_000);
+        LastUpdate::<Test>::insert(NetUidStorageIndex::from(netuid), vec![100, 100]);
+        SubtensorModule::epoch(netuid, to_emit.into());
+        assert_eq!(SubtensorModule::get_max_weight_limit(netuid), u16::MAX);
+    });
+}
+
 #[test]
 fn test_epoch_outputs_single_staker_registered_no_weights() {
     new_test_ext(1).execute_with(|| {
@@ -3883,3 +4077,64 @@ fn test_last_update_size_mismatch() {
         assert_eq!(SubtensorModule::get_dividends_for_uid(netuid, uid), 0);
     });
 }
+
+#[test]
+fn test_validator_heartbeat_counts_as_activity() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let netuid_index = NetUidStorageIndex::from(netuid);
+        let validator = U256::from(1);
+        let miner = U256::from(2);
+        add_network(netuid, 10, 0);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+        SubtensorModule::set_activity_cutoff(netuid, 50);
+        register_ok_neuron(netuid, validator, U256::from(101), 0);
+        register_ok_neuron(netuid, miner, U256::from(102), 0);
+
+        assert_err!(
+            SubtensorModule::heartbeat(RuntimeOrigin::signed(validator), netuid),
+            Error::<Test>::NeuronNoValidatorPermit
+        );
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+
+        // Both neurons are past the activity cutoff of their registration
+        System::set_block_number(100);
+        let last_update = SubtensorModule::get_last_update(netuid_index);
+        assert_eq!(
+            SubtensorModule::get_last_activity(netuid, &last_update),
+            last_update
+        );
+
+        assert_ok!(SubtensorModule::heartbeat(
+            RuntimeOrigin::signed(validator),
+            netuid
+        ));
+        assert_eq!(
+            SubtensorModule::get_last_activity(netuid, &last_update),
+            vec![100, last_update[1]]
+        );
+        assert_eq!(SubtensorModule::get_last_update(netuid_index), last_update);
+
+        // One heartbeat per tempo
+        assert_err!(
+            SubtensorModule::heartbeat(RuntimeOrigin::signed(validator), netuid),
+            Error::<Test>::HeartbeatTooFast
+        );
+        System::set_block_number(110);
+        assert_ok!(SubtensorModule::heartbeat(
+            RuntimeOrigin::signed(validator),
+            netuid
+        ));
+
+        SubtensorModule::epoch(netuid, AlphaCurrency::from(1_000_000_000));
+        assert_eq!(Active::<Test>::get(netuid), vec![true, false]);
+
+        // Heartbeats are only accepted on commit-reveal subnets
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, false);
+        System::set_block_number(120);
+        assert_err!(
+            SubtensorModule::heartbeat(RuntimeOrigin::signed(validator), netuid),
+            Error::<Test>::CommitRevealDisabled
+        );
+    });
+}
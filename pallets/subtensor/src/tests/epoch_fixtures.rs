@@ -0,0 +1,154 @@
+#![allow(
+    clippy::arithmetic_side_effects,
+    clippy::indexing_slicing,
+    clippy::unwrap_used
+)]
+
+use std::collections::VecDeque;
+
+use codec::Decode;
+use frame_support::assert_ok;
+use sp_core::{H256, U256};
+use subtensor_runtime_common::{AlphaCurrency, MechId};
+
+use super::mock::*;
+use crate::epoch::fixture::{EPOCH_FIXTURE_VERSION, EpochFixture};
+use crate::subnets::weight_row::WeightRow;
+use crate::*;
+
+/// Loads an epoch fixture into the test externalities and moves to the block it was captured at,
+/// after which `SubtensorModule::epoch` replays the captured epoch. Hotkeys of fixtures captured
+/// from a live chain are 32 bytes and decode as `U256`.
+pub fn load_epoch_fixture(blob: &[u8]) -> EpochFixture<U256> {
+    let fixture = EpochFixture::<U256>::decode(&mut &blob[..]).expect("invalid epoch fixture");
+    assert_eq!(fixture.version, EPOCH_FIXTURE_VERSION);
+    let netuid = fixture.netuid;
+    let params = &fixture.params;
+
+    System::set_block_number(fixture.block);
+    NetworksAdded::<Test>::insert(netuid, true);
+    Tempo::<Test>::insert(netuid, params.tempo);
+    ActivityCutoff::<Test>::insert(netuid, params.activity_cutoff);
+    MaxAllowedValidators::<Test>::insert(netuid, params.max_allowed_validators);
+    Kappa::<Test>::insert(netuid, params.kappa);
+    Rho::<Test>::insert(netuid, params.rho);
+    BondsPenalty::<Test>::insert(netuid, params.bonds_penalty);
+    BondsMovingAverage::<Test>::insert(netuid, params.bonds_moving_average);
+    LiquidAlphaOn::<Test>::insert(netuid, params.liquid_alpha_enabled);
+    AlphaValues::<Test>::insert(netuid, params.alpha_values);
+    AlphaSigmoidSteepness::<Test>::insert(netuid, params.alpha_sigmoid_steepness);
+    Yuma3On::<Test>::insert(netuid, params.yuma3_enabled);
+    CommitRevealWeightsEnabled::<Test>::insert(netuid, params.commit_reveal_weights_enabled);
+    RevealPeriodEpochs::<Test>::insert(netuid, params.reveal_period);
+    StakeThreshold::<Test>::put(params.stake_threshold);
+    TaoWeight::<Test>::put(params.tao_weight);
+    SubnetConsensusEngine::<Test>::insert(netuid, params.consensus_engine);
+    SelfWeightsExcluded::<Test>::insert(netuid, params.self_weights_excluded);
+    WeightExpiryBlocks::<Test>::insert(netuid, params.weight_expiry_blocks);
+    ValidatorPermitMinStake::<Test>::insert(netuid, params.validator_permit_min_stake);
+
+    // Stake is loaded as owned by the hotkeys, which gives the captured stake without child keys
+    SubnetworkN::<Test>::insert(netuid, fixture.neurons.len() as u16);
+    for (uid, neuron) in fixture.neurons.iter().enumerate() {
+        let uid = uid as u16;
+        Keys::<Test>::insert(netuid, uid, neuron.hotkey);
+        Uids::<Test>::insert(netuid, neuron.hotkey, uid);
+        BlockAtRegistration::<Test>::insert(netuid, uid, neuron.block_at_registration);
+        TotalHotkeyAlpha::<Test>::insert(
+            neuron.hotkey,
+            netuid,
+            AlphaCurrency::from(neuron.alpha_stake),
+        );
+        TotalHotkeyAlpha::<Test>::insert(
+            neuron.hotkey,
+            NetUid::ROOT,
+            AlphaCurrency::from(neuron.tao_stake),
+        );
+    }
+    ValidatorPermit::<Test>::insert(
+        netuid,
+        fixture
+            .neurons
+            .iter()
+            .map(|neuron| neuron.validator_permit)
+            .collect::<Vec<_>>(),
+    );
+    for (uid, heartbeat) in fixture.heartbeats.iter() {
+        LastHeartbeat::<Test>::insert(netuid, uid, heartbeat);
+    }
+    if let Some(owner_uid) = fixture.owner_uid {
+        SubnetOwnerHotkey::<Test>::insert(netuid, fixture.neurons[owner_uid as usize].hotkey);
+    }
+
+    MechanismCountCurrent::<Test>::insert(netuid, MechId::from(fixture.mechanisms.len() as u8));
+    for mechanism in fixture.mechanisms.iter() {
+        let netuid_index = SubtensorModule::get_mechanism_storage_index(netuid, mechanism.mecid);
+        LastUpdate::<Test>::insert(netuid_index, mechanism.last_update.clone());
+        for (uid, row) in mechanism.weights.iter().enumerate() {
+            Weights::<Test>::insert(netuid_index, uid as u16, WeightRow::from(row.clone()));
+        }
+        for (uid, row) in mechanism.bonds.iter().enumerate() {
+            Bonds::<Test>::insert(netuid_index, uid as u16, row.clone());
+        }
+        // The epoch only reads the commit blocks
+        for (uid, commit_block) in mechanism.commit_blocks.iter() {
+            WeightCommits::<Test>::insert(
+                netuid_index,
+                fixture.neurons[*uid as usize].hotkey,
+                VecDeque::from([(H256::zero(), *commit_block, 0, 0)]),
+            );
+        }
+    }
+
+    fixture
+}
+
+#[test]
+fn test_epoch_fixture_round_trip() {
+    let netuid = NetUid::from(1);
+    let emission = AlphaCurrency::from(1_000_000_000);
+
+    let (blob, expected) = new_test_ext(1).execute_with(|| {
+        add_network_disable_commit_reveal(netuid, 10, 0);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_max_allowed_validators(netuid, 2);
+        for key in 1..=4u64 {
+            let hotkey = U256::from(key);
+            register_ok_neuron(netuid, hotkey, U256::from(key + 100), key * 10_000);
+            SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey,
+                &U256::from(key + 100),
+                netuid,
+                AlphaCurrency::from((5 - key) * 1_000_000_000),
+            );
+        }
+        // Issue validator permits to the two largest stakes
+        SubtensorModule::epoch(netuid, emission);
+        next_block_no_epoch(netuid);
+
+        for key in 1..=2u64 {
+            assert_ok!(SubtensorModule::set_weights(
+                RuntimeOrigin::signed(U256::from(key)),
+                netuid,
+                vec![2, 3],
+                vec![u16::MAX, u16::MAX / 2],
+                0
+            ));
+        }
+        next_block_no_epoch(netuid);
+
+        let blob = SubtensorModule::get_epoch_fixture_blob(netuid).unwrap();
+        assert_eq!(
+            SubtensorModule::get_epoch_fixture_blob(netuid),
+            Some(blob.clone())
+        );
+        (blob, SubtensorModule::epoch(netuid, emission))
+    });
+
+    new_test_ext(1).execute_with(|| {
+        let fixture = load_epoch_fixture(&blob);
+        assert_eq!(fixture.neurons.len(), 4);
+        assert_eq!(SubtensorModule::get_epoch_fixture_blob(netuid), Some(blob));
+        assert_eq!(SubtensorModule::epoch(netuid, emission), expected);
+    });
+}
@@ -5,9 +5,10 @@
 )]
 
 use super::mock::*;
+use crate::utils::evm_logs::MAX_EVM_LOGS_PER_BLOCK;
 use crate::*;
 use frame_support::testing_prelude::*;
-use sp_core::{H160, Pair, U256, blake2_256, ecdsa, keccak_256};
+use sp_core::{H160, H256, Pair, U256, blake2_256, ecdsa, keccak_256};
 
 fn public_to_evm_key(pubkey: &ecdsa::Public) -> H160 {
     use libsecp256k1::PublicKey;
@@ -343,3 +344,95 @@ fn test_associate_evm_key_uid_not_found() {
         );
     });
 }
+
+#[test]
+fn test_stake_added_evm_log() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(533453);
+        let coldkey = U256::from(55453);
+        let amount = DefaultMinStake::<Test>::get().to_u64() * 10;
+        let evm_key = H160::from_low_u64_be(0xbeef);
+
+        let netuid = add_dynamic_network(&hotkey, &coldkey);
+        setup_reserves(
+            netuid,
+            (amount * 1_000_000).into(),
+            (amount * 10_000_000).into(),
+        );
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, amount * 2);
+
+        // No log while the hotkey has no associated address.
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            amount.into()
+        ));
+        assert!(PendingEvmLogs::<Test>::get().is_empty());
+
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+        AssociatedEvmAddress::<Test>::insert(netuid, uid, (evm_key, 0));
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            amount.into()
+        ));
+
+        // The logs of the block are handed over on finalize.
+        assert_eq!(PendingEvmLogs::<Test>::get().len(), 1);
+        assert!(EVM_LOGS.with_borrow(|logs| logs.is_empty()));
+        step_block(1);
+        assert!(PendingEvmLogs::<Test>::get().is_empty());
+
+        let (topics, data) = EVM_LOGS.with_borrow(|logs| logs.last().cloned()).unwrap();
+        assert_eq!(
+            topics,
+            vec![
+                H256(keccak_256(
+                    b"StakeAdded(address,uint16,bytes32,uint256,uint256)"
+                )),
+                H256::from(evm_key),
+                H256::from_low_u64_be(u16::from(netuid).into()),
+            ]
+        );
+        assert_eq!(data.get(..32), Some(coldkey.encode().as_slice()));
+        assert_eq!(
+            data.get(32..64),
+            Some(H256::from_low_u64_be(amount).as_bytes())
+        );
+    });
+}
+
+#[test]
+fn test_evm_logs_past_the_block_bound_are_dropped() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey = U256::from(533453);
+        let coldkey = U256::from(55453);
+        let amount = DefaultMinStake::<Test>::get().to_u64() * 10;
+
+        let netuid = add_dynamic_network(&hotkey, &coldkey);
+        setup_reserves(
+            netuid,
+            (amount * 1_000_000).into(),
+            (amount * 10_000_000).into(),
+        );
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, amount * 2);
+        let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+        AssociatedEvmAddress::<Test>::insert(netuid, uid, (H160::from_low_u64_be(0xbeef), 0));
+
+        let full = vec![(Vec::new(), Vec::new()); MAX_EVM_LOGS_PER_BLOCK as usize];
+        PendingEvmLogs::<Test>::put(BoundedVec::truncate_from(full));
+
+        // The stake goes through, its log is dropped.
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            amount.into()
+        ));
+        let pending = PendingEvmLogs::<Test>::get();
+        assert_eq!(pending.len(), MAX_EVM_LOGS_PER_BLOCK as usize);
+        assert!(pending.iter().all(|(topics, _)| topics.is_empty()));
+    });
+}
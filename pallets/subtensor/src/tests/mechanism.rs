@@ -38,6 +38,7 @@
 use super::mock::*;
 use crate::coinbase::reveal_commits::WeightsTlockPayload;
 use crate::subnets::mechanism::{GLOBAL_MAX_SUBNET_COUNT, MAX_MECHANISM_COUNT_PER_SUBNET};
+use crate::subnets::weight_row::WeightRow;
 use crate::*;
 use alloc::collections::BTreeMap;
 use approx::assert_abs_diff_eq;
@@ -299,7 +300,7 @@ fn update_mechanism_counts_decreases_and_cleans() {
         let idx_keep = SubtensorModule::get_mechanism_storage_index(netuid, MechId::from(1u8));
         let idx_rm3 = SubtensorModule::get_mechanism_storage_index(netuid, MechId::from(2u8));
 
-        Weights::<Test>::insert(idx_keep, 0u16, vec![(1u16, 1u16)]);
+        Weights::<Test>::insert(idx_keep, 0u16, WeightRow::from(vec![(1u16, 1u16)]));
         Incentive::<Test>::insert(idx_keep, vec![1u16]);
         LastUpdate::<Test>::insert(idx_keep, vec![123u64]);
         Bonds::<Test>::insert(idx_keep, 0u16, vec![(1u16, 2u16)]);
@@ -314,7 +315,7 @@ fn update_mechanism_counts_decreases_and_cleans() {
             VecDeque::from([(hotkey, 1u64, Default::default(), Default::default())]),
         );
 
-        Weights::<Test>::insert(idx_rm3, 0u16, vec![(9u16, 9u16)]);
+        Weights::<Test>::insert(idx_rm3, 0u16, WeightRow::from(vec![(9u16, 9u16)]));
         Incentive::<Test>::insert(idx_rm3, vec![9u16]);
         LastUpdate::<Test>::insert(idx_rm3, vec![999u64]);
         Bonds::<Test>::insert(idx_rm3, 0u16, vec![(9u16, 9u16)]);
@@ -478,10 +479,26 @@ pub fn mock_epoch_state(netuid: NetUid, ck0: U256, hk0: U256, ck1: U256, hk1: U2
     ValidatorPermit::<Test>::insert(netuid, vec![true, true]);
 
     // Simple weights, setting for each other on both mechanisms
-    Weights::<Test>::insert(idx0, 0, vec![(0u16, 0xFFFF), (1u16, 0xFFFF)]);
-    Weights::<Test>::insert(idx0, 1, vec![(0u16, 0xFFFF), (1u16, 0xFFFF)]);
-    Weights::<Test>::insert(idx1, 0, vec![(0u16, 0xFFFF), (1u16, 0xFFFF)]);
-    Weights::<Test>::insert(idx1, 1, vec![(0u16, 0xFFFF), (1u16, 0xFFFF)]);
+    Weights::<Test>::insert(
+        idx0,
+        0,
+        WeightRow::from(vec![(0u16, 0xFFFF), (1u16, 0xFFFF)]),
+    );
+    Weights::<Test>::insert(
+        idx0,
+        1,
+        WeightRow::from(vec![(0u16, 0xFFFF), (1u16, 0xFFFF)]),
+    );
+    Weights::<Test>::insert(
+        idx1,
+        0,
+        WeightRow::from(vec![(0u16, 0xFFFF), (1u16, 0xFFFF)]),
+    );
+    Weights::<Test>::insert(
+        idx1,
+        1,
+        WeightRow::from(vec![(0u16, 0xFFFF), (1u16, 0xFFFF)]),
+    );
 
     // Keep weight masking off for simplicity.
     CommitRevealWeightsEnabled::<Test>::insert(netuid, false);
@@ -539,8 +556,12 @@ fn epoch_with_mechanisms_updates_bonds() {
         mock_epoch_state(netuid, ck0, hk0, ck1, hk1);
 
         // Cause bonds to be asymmetric on diff mechanisms
-        Weights::<Test>::insert(idx1, 0, vec![(0u16, 0xFFFF), (1u16, 0)]);
-        Weights::<Test>::insert(idx1, 1, vec![(0u16, 0xFFFF), (1u16, 0xFFFF)]);
+        Weights::<Test>::insert(idx1, 0, WeightRow::from(vec![(0u16, 0xFFFF), (1u16, 0)]));
+        Weights::<Test>::insert(
+            idx1,
+            1,
+            WeightRow::from(vec![(0u16, 0xFFFF), (1u16, 0xFFFF)]),
+        );
 
         SubtensorModule::epoch_with_mechanisms(netuid, emission);
 
@@ -579,8 +600,16 @@ fn epoch_with_mechanisms_incentives_proportional_to_weights() {
         ValidatorPermit::<Test>::insert(netuid, vec![true, false, false]);
 
         // Set greater weight to uid1 on sub-subnet 0 and to uid2 on mechanism 1
-        Weights::<Test>::insert(idx0, 0, vec![(1u16, 0xFFFF / 5 * 4), (2u16, 0xFFFF / 5)]);
-        Weights::<Test>::insert(idx1, 0, vec![(1u16, 0xFFFF / 5), (2u16, 0xFFFF / 5 * 4)]);
+        Weights::<Test>::insert(
+            idx0,
+            0,
+            WeightRow::from(vec![(1u16, 0xFFFF / 5 * 4), (2u16, 0xFFFF / 5)]),
+        );
+        Weights::<Test>::insert(
+            idx1,
+            0,
+            WeightRow::from(vec![(1u16, 0xFFFF / 5), (2u16, 0xFFFF / 5 * 4)]),
+        );
 
         SubtensorModule::epoch_with_mechanisms(netuid, emission);
 
@@ -646,10 +675,14 @@ fn epoch_with_mechanisms_persists_and_aggregates_all_terms() {
         Weights::<Test>::insert(
             idx0,
             0,
-            vec![(1u16, 0xFFFF / 5 * 3), (2u16, 0xFFFF / 5 * 2)],
+            WeightRow::from(vec![(1u16, 0xFFFF / 5 * 3), (2u16, 0xFFFF / 5 * 2)]),
         );
         // sub 1: uid1 light, uid2 heavy
-        Weights::<Test>::insert(idx1, 0, vec![(1u16, 0xFFFF / 5), (2u16, 0xFFFF / 5 * 4)]);
+        Weights::<Test>::insert(
+            idx1,
+            0,
+            WeightRow::from(vec![(1u16, 0xFFFF / 5), (2u16, 0xFFFF / 5 * 4)]),
+        );
 
         // Per-sub emissions (and weights used for aggregation)
         let mechanism_emissions = SubtensorModule::split_emissions(netuid, emission);
@@ -818,8 +851,8 @@ fn epoch_with_mechanisms_no_weight_no_incentive() {
         ValidatorPermit::<Test>::insert(netuid, vec![true, false, false]);
 
         // Set no weight to uid2 on sub-subnet 0 and 1
-        Weights::<Test>::insert(idx0, 0, vec![(1u16, 1), (2u16, 0)]);
-        Weights::<Test>::insert(idx1, 0, vec![(1u16, 1), (2u16, 0)]);
+        Weights::<Test>::insert(idx0, 0, WeightRow::from(vec![(1u16, 1), (2u16, 0)]));
+        Weights::<Test>::insert(idx1, 0, WeightRow::from(vec![(1u16, 1), (2u16, 0)]));
 
         SubtensorModule::epoch_with_mechanisms(netuid, emission);
 
@@ -866,11 +899,15 @@ fn neuron_dereg_cleans_weights_across_subids() {
             Incentive::<Test>::insert(idx, vec![10u16, 20u16, 30u16]);
 
             // Row set BY neuron_uid (to be removed)
-            Weights::<Test>::insert(idx, neuron_uid, vec![(0u16, 5u16)]);
+            Weights::<Test>::insert(idx, neuron_uid, WeightRow::from(vec![(0u16, 5u16)]));
             Bonds::<Test>::insert(idx, neuron_uid, vec![(0u16, 6u16)]);
 
             // Rows FOR neuron_uid inside other validators' vecs => value should be set to 0 (not removed)
-            Weights::<Test>::insert(idx, 0u16, vec![(neuron_uid, 7u16), (42u16, 3u16)]);
+            Weights::<Test>::insert(
+                idx,
+                0u16,
+                WeightRow::from(vec![(neuron_uid, 7u16), (42u16, 3u16)]),
+            );
             Bonds::<Test>::insert(idx, 0u16, vec![(neuron_uid, 8u16), (42u16, 4u16)]);
         }
 
@@ -906,8 +943,8 @@ fn neuron_dereg_cleans_weights_across_subids() {
 
             // In other rows, entries FOR neuron_uid are zeroed, others unchanged
             let w0 = Weights::<Test>::get(idx, 0u16);
-            assert!(w0.iter().any(|&(u, w)| u == neuron_uid && w == 0));
-            assert!(w0.iter().any(|&(u, w)| u == 42 && w == 3));
+            assert!(w0.iter().any(|(u, w)| u == neuron_uid && w == 0));
+            assert!(w0.iter().any(|(u, w)| u == 42 && w == 3));
         }
     });
 }
@@ -991,7 +1028,7 @@ fn test_set_mechanism_weights_happy_path_sets_row_under_subid() {
         // Verify row exists under the chosen mecid and not under a different mecid
         let idx1 = SubtensorModule::get_mechanism_storage_index(netuid, mecid);
         assert_eq!(
-            Weights::<Test>::get(idx1, uid1),
+            Weights::<Test>::get(idx1, uid1).to_vec(),
             vec![(uid2, 88u16), (uid3, 0xFFFF)]
         );
 
@@ -1129,7 +1166,7 @@ fn test_commit_reveal_mechanism_weights_ok() {
 
         // Verify weights stored under the chosen mecid (normalized keeps max=0xFFFF here)
         assert_eq!(
-            Weights::<Test>::get(idx1, uid1),
+            Weights::<Test>::get(idx1, uid1).to_vec(),
             vec![(uid2, 88u16), (uid3, 0xFFFFu16)]
         );
 
@@ -7,10 +7,13 @@ use approx::assert_abs_diff_eq;
 use codec::{Decode, Encode};
 use frame_support::{
     StorageHasher, Twox64Concat, assert_ok,
-    storage::unhashed::{get, get_raw, put, put_raw},
+    storage::{
+        StoragePrefixedMap,
+        unhashed::{get, get_raw, put, put_raw},
+    },
     storage_alias,
     traits::{StorageInstance, StoredMap},
-    weights::Weight,
+    weights::{Weight, WeightMeter},
 };
 
 use crate::migrations::migrate_storage;
@@ -2165,3 +2168,201 @@ fn test_migrate_network_lock_cost_2500_sets_price_and_decay() {
         );
     });
 }
+
+#[test]
+fn test_migrate_subnets_owned_by() {
+    new_test_ext(1).execute_with(|| {
+        const MIGRATION_NAME: &[u8] = b"migrate_subnets_owned_by";
+        let owner1 = U256::from(1);
+        let owner2 = U256::from(2);
+
+        // Ownership written before the index existed
+        SubnetOwner::<Test>::insert(NetUid::from(3), owner1);
+        SubnetOwner::<Test>::insert(NetUid::from(1), owner1);
+        SubnetOwner::<Test>::insert(NetUid::from(2), owner2);
+        assert!(SubtensorModule::get_subnets_owned_by(&owner1).is_empty());
+
+        let weight =
+            crate::migrations::migrate_subnets_owned_by::migrate_subnets_owned_by::<Test>();
+        assert!(!weight.is_zero());
+        assert!(HasMigrationRun::<Test>::get(MIGRATION_NAME.to_vec()));
+
+        assert_eq!(
+            SubtensorModule::get_subnets_owned_by(&owner1),
+            vec![NetUid::from(1), NetUid::from(3)]
+        );
+        assert_eq!(
+            SubtensorModule::get_subnets_owned_by(&owner2),
+            vec![NetUid::from(2)]
+        );
+
+        // A second run leaves the index alone
+        SubnetOwner::<Test>::insert(NetUid::from(4), owner2);
+        crate::migrations::migrate_subnets_owned_by::migrate_subnets_owned_by::<Test>();
+        assert_eq!(
+            SubtensorModule::get_subnets_owned_by(&owner2),
+            vec![NetUid::from(2)]
+        );
+    });
+}
+
+#[test]
+fn test_migrate_compress_weights() {
+    use crate::migrations::migrate_compress_weights::CompressWeights;
+    use crate::migrations::stepped::{SteppedMigration, step_migration};
+    use crate::subnets::weight_row::{WEIGHT_ROW_V1, WeightRow};
+    use subtensor_runtime_common::MechId;
+
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let netuid_index = NetUidStorageIndex::from(netuid);
+        let legacy_row: Vec<(u16, u16)> = vec![(40, u16::MAX), (3, 7), (41, 0)];
+        let key = |uid: u16| Weights::<Test>::hashed_key_for(netuid_index, uid);
+
+        // Rows written as (uid, weight) pairs before the compression
+        put(&key(0), &legacy_row);
+        put(&key(1), &Vec::<(u16, u16)>::new());
+        Weights::<Test>::insert(netuid_index, 2, WeightRow::from(vec![(5, 9)]));
+
+        // Legacy rows read as the same pairs before they are migrated
+        assert_eq!(
+            Weights::<Test>::get(netuid_index, 0).to_vec(),
+            vec![(3, 7), (40, u16::MAX), (41, 0)]
+        );
+        assert_eq!(
+            SubtensorModule::get_weight_rows(netuid, MechId::MAIN),
+            vec![
+                (0, vec![(3, 7), (40, u16::MAX), (41, 0)]),
+                (1, vec![]),
+                (2, vec![(5, 9)]),
+            ]
+        );
+
+        // A step migrates as many rows as the meter has the weight for
+        let db_weight = <Test as frame_system::Config>::DbWeight::get();
+        let mut meter = WeightMeter::with_limit(db_weight.reads_writes(2, 2).saturating_add(
+            CompressWeights::<Test>::row_weight(legacy_row.encode().len()),
+        ));
+        assert!(step_migration::<Test, CompressWeights<Test>>(&mut meter));
+        assert_eq!(get_raw(&key(0)).unwrap().first(), Some(&WEIGHT_ROW_V1));
+        assert_eq!(get_raw(&key(1)).unwrap(), vec![0]);
+
+        let mut meter = WeightMeter::with_limit(Weight::MAX);
+        assert!(!step_migration::<Test, CompressWeights<Test>>(&mut meter));
+        assert!(HasMigrationRun::<Test>::get(
+            CompressWeights::<Test>::NAME.to_vec()
+        ));
+        for uid in 0..3 {
+            assert_eq!(get_raw(&key(uid)).unwrap().first(), Some(&WEIGHT_ROW_V1));
+        }
+        assert_eq!(
+            Weights::<Test>::get(netuid_index, 0).to_vec(),
+            vec![(3, 7), (40, u16::MAX), (41, 0)]
+        );
+        assert!(Weights::<Test>::contains_key(netuid_index, 1));
+        assert!(Weights::<Test>::get(netuid_index, 1).is_empty());
+        assert_eq!(Weights::<Test>::get(netuid_index, 2).to_vec(), vec![(5, 9)]);
+    });
+}
+
+/// Doubles the tempo of every subnet, as many subnets per step as the meter allows.
+struct DoubleTempos;
+
+impl DoubleTempos {
+    fn entry_weight() -> Weight {
+        <Test as frame_system::Config>::DbWeight::get().reads_writes(1, 1)
+    }
+}
+
+impl crate::migrations::stepped::SteppedMigration<Test> for DoubleTempos {
+    const NAME: &'static [u8] = b"test_double_tempos";
+
+    fn step(cursor: Option<Vec<u8>>, meter: &mut WeightMeter) -> Option<Vec<u8>> {
+        let mut cursor = cursor.unwrap_or_else(|| Tempo::<Test>::final_prefix().to_vec());
+        let mut tempos = Tempo::<Test>::iter_from(cursor.clone());
+        while meter.try_consume(Self::entry_weight()).is_ok() {
+            let Some((netuid, tempo)) = tempos.next() else {
+                return None;
+            };
+            Tempo::<Test>::insert(netuid, tempo.saturating_mul(2));
+            cursor = tempos.last_raw_key().to_vec();
+        }
+        Some(cursor)
+    }
+}
+
+#[test]
+fn test_stepped_migration() {
+    use crate::migrations::stepped::{SteppedMigration, SteppedMigrations, step_migration};
+
+    new_test_ext(1).execute_with(|| {
+        let _ = Tempo::<Test>::clear(u32::MAX, None);
+        for netuid in 1..=5_u16 {
+            Tempo::<Test>::insert(NetUid::from(netuid), netuid);
+        }
+
+        // The bookkeeping of a step and two subnets fit in the meter, the fifth subnet is
+        // migrated in the third step
+        let db_weight = <Test as frame_system::Config>::DbWeight::get();
+        let step_limit = db_weight
+            .reads_writes(2, 2)
+            .saturating_add(DoubleTempos::entry_weight().saturating_mul(2));
+        for _ in 0..2 {
+            let mut meter = WeightMeter::with_limit(step_limit);
+            assert!(step_migration::<Test, DoubleTempos>(&mut meter));
+            assert_eq!(meter.consumed(), step_limit);
+            assert!(SteppedMigrationCursor::<Test>::contains_key(
+                DoubleTempos::NAME.to_vec()
+            ));
+            assert!(!HasMigrationRun::<Test>::get(DoubleTempos::NAME.to_vec()));
+        }
+        assert_eq!(Tempo::<Test>::get(NetUid::from(4)), 8);
+        assert_eq!(Tempo::<Test>::get(NetUid::from(5)), 5);
+
+        // A meter without the weight for an entry keeps the cursor
+        let mut meter = WeightMeter::with_limit(db_weight.reads_writes(2, 2));
+        assert!(<(DoubleTempos,) as SteppedMigrations<Test>>::step(
+            &mut meter
+        ));
+        assert_eq!(Tempo::<Test>::get(NetUid::from(5)), 5);
+
+        let mut meter = WeightMeter::with_limit(step_limit);
+        assert!(!<(DoubleTempos,) as SteppedMigrations<Test>>::step(
+            &mut meter
+        ));
+        assert!(!SteppedMigrationCursor::<Test>::contains_key(
+            DoubleTempos::NAME.to_vec()
+        ));
+        assert!(HasMigrationRun::<Test>::get(DoubleTempos::NAME.to_vec()));
+        for netuid in 1..=5_u16 {
+            assert_eq!(
+                Tempo::<Test>::get(NetUid::from(netuid)),
+                netuid.saturating_mul(2)
+            );
+        }
+
+        // A migration that has run makes no more steps
+        let mut meter = WeightMeter::with_limit(step_limit);
+        assert!(!<(DoubleTempos,) as SteppedMigrations<Test>>::step(
+            &mut meter
+        ));
+        assert_eq!(meter.consumed(), db_weight.reads(1));
+        assert_eq!(Tempo::<Test>::get(NetUid::from(1)), 2);
+    });
+}
+
+#[test]
+fn test_stepped_migrations_fit_in_the_remaining_block_weight() {
+    use crate::migrations::stepped::run_stepped_migrations;
+    use frame_support::dispatch::DispatchClass;
+
+    new_test_ext(1).execute_with(|| {
+        // A block without weight left makes no step, not even to read the migration status
+        let max_block = <Test as frame_system::Config>::BlockWeights::get().max_block;
+        frame_system::Pallet::<Test>::register_extra_weight_unchecked(
+            max_block,
+            DispatchClass::Mandatory,
+        );
+        assert_eq!(run_stepped_migrations::<Test>(), Weight::zero());
+    });
+}
@@ -226,6 +226,7 @@ parameter_types! {
     pub const MaxContributorsPerLeaseToRemove: u32 = 3;
     pub const LeaseDividendsDistributionInterval: u32 = 100;
     pub const MaxImmuneUidsPercentage: Percent = Percent::from_percent(80);
+    pub const SlotDuration: u64 = 12_000;
     pub const EvmKeyAssociateRateLimit: u64 = 10;
 }
 
@@ -461,7 +462,9 @@ impl crate::Config for Test {
     type LeaseDividendsDistributionInterval = LeaseDividendsDistributionInterval;
     type GetCommitments = ();
     type MaxImmuneUidsPercentage = MaxImmuneUidsPercentage;
+    type SlotDuration = SlotDuration;
     type CommitmentsInterface = CommitmentsI;
+    type EvmLogInterface = FakeEvmLogs;
     type EvmKeyAssociateRateLimit = EvmKeyAssociateRateLimit;
 }
 
@@ -472,6 +475,9 @@ parameter_types! {
     pub const SwapMaxPositions: u32 = 100;
     pub const SwapMinimumLiquidity: u64 = 1_000;
     pub const SwapMinimumReserve: NonZeroU64 = NonZeroU64::new(100).unwrap();
+    pub const SwapMaxCandles: u32 = 100;
+    pub const SwapMaxPriceObservations: u32 = 100;
+    pub const SwapMaxLimitOrders: u32 = 100;
 }
 
 impl pallet_subtensor_swap::Config for Test {
@@ -482,6 +488,9 @@ impl pallet_subtensor_swap::Config for Test {
     type MaxPositions = SwapMaxPositions;
     type MinimumLiquidity = SwapMinimumLiquidity;
     type MinimumReserve = SwapMinimumReserve;
+    type MaxCandles = SwapMaxCandles;
+    type MaxPriceObservations = SwapMaxPriceObservations;
+    type MaxLimitOrders = SwapMaxLimitOrders;
     type WeightInfo = ();
 }
 
@@ -498,6 +507,17 @@ impl CommitmentsInterface for CommitmentsI {
     fn purge_netuid(_netuid: NetUid) {}
 }
 
+thread_local! {
+    pub static EVM_LOGS: RefCell<Vec<(Vec<H256>, Vec<u8>)>> = const { RefCell::new(Vec::new()) };
+}
+
+pub struct FakeEvmLogs;
+impl EvmLogInterface for FakeEvmLogs {
+    fn deposit_logs(logs: Vec<(Vec<H256>, Vec<u8>)>) {
+        EVM_LOGS.with_borrow_mut(|deposited| deposited.extend(logs));
+    }
+}
+
 parameter_types! {
     pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
         BlockWeights::get().max_block;
@@ -8,6 +8,7 @@ mod difficulty;
 mod emission;
 mod ensure;
 mod epoch;
+mod epoch_fixtures;
 mod evm;
 mod leasing;
 mod math;
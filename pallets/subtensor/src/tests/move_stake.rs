@@ -9,6 +9,7 @@ use subtensor_swap_interface::SwapHandler;
 
 use super::mock;
 use super::mock::*;
+use crate::staking::stake_origin::StakeOriginTag;
 use crate::*;
 
 // 1. test_do_move_success
@@ -1928,3 +1929,122 @@ fn test_swap_stake_limits_destination_netuid() {
         )));
     });
 }
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::move_stake::test_stake_origin_tags --exact --nocapture
+#[test]
+fn test_stake_origin_tags() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1001);
+        let subnet_owner_hotkey = U256::from(1002);
+        let netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        let coldkey = U256::from(1);
+        let own_hotkey = U256::from(2);
+        let delegate_coldkey = U256::from(3);
+        let delegate_hotkey = U256::from(4);
+        let other_delegate_hotkey = U256::from(5);
+        let institution = U256::from(6);
+        let recipient = U256::from(7);
+        let stake_amount = DefaultMinStake::<Test>::get() * 10.into();
+
+        SubtensorModule::create_account_if_non_existent(&coldkey, &own_hotkey);
+        SubtensorModule::create_account_if_non_existent(&delegate_coldkey, &delegate_hotkey);
+        SubtensorModule::create_account_if_non_existent(&delegate_coldkey, &other_delegate_hotkey);
+        let stake = |hotkey: &U256, coldkey: &U256| {
+            SubtensorModule::stake_into_subnet(
+                hotkey,
+                coldkey,
+                netuid,
+                stake_amount,
+                <Test as Config>::SwapInterface::max_price().into(),
+                false,
+                false,
+            )
+            .unwrap()
+        };
+        let tag =
+            |hotkey: U256, coldkey: U256| StakeOrigins::<Test>::get((netuid, hotkey, coldkey));
+
+        // Tags are set at stake time
+        stake(&own_hotkey, &coldkey);
+        stake(&delegate_hotkey, &coldkey);
+        assert_eq!(tag(own_hotkey, coldkey), Some(StakeOriginTag::SelfStake));
+        assert_eq!(
+            tag(delegate_hotkey, coldkey),
+            Some(StakeOriginTag::Delegated)
+        );
+
+        assert_noop!(
+            SubtensorModule::set_institutional_attestation(
+                RuntimeOrigin::signed(institution),
+                institution,
+                true
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(SubtensorModule::set_institutional_attestation(
+            RuntimeOrigin::root(),
+            institution,
+            true
+        ));
+        stake(&delegate_hotkey, &institution);
+        assert_eq!(
+            tag(delegate_hotkey, institution),
+            Some(StakeOriginTag::InstitutionalAttested)
+        );
+
+        // Moving self stake to a delegate keeps the origin, the emptied position loses its tag
+        let alpha = SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &own_hotkey,
+            &coldkey,
+            netuid,
+        );
+        assert_ok!(SubtensorModule::do_move_stake(
+            RuntimeOrigin::signed(coldkey),
+            own_hotkey,
+            other_delegate_hotkey,
+            netuid,
+            netuid,
+            alpha,
+        ));
+        assert_eq!(tag(own_hotkey, coldkey), None);
+        assert_eq!(
+            tag(other_delegate_hotkey, coldkey),
+            Some(StakeOriginTag::SelfStake)
+        );
+
+        // Stake transferred to another coldkey is tagged for its new owner
+        let alpha = SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &delegate_hotkey,
+            &institution,
+            netuid,
+        );
+        assert_ok!(SubtensorModule::do_transfer_stake(
+            RuntimeOrigin::signed(institution),
+            recipient,
+            delegate_hotkey,
+            netuid,
+            netuid,
+            alpha,
+        ));
+        assert_eq!(tag(delegate_hotkey, institution), None);
+        assert_eq!(
+            tag(delegate_hotkey, recipient),
+            Some(StakeOriginTag::Delegated)
+        );
+
+        let mut origins: Vec<(U256, U256, StakeOriginTag)> =
+            SubtensorModule::get_stake_origins(netuid)
+                .into_iter()
+                .map(|info| (info.hotkey, info.coldkey, info.origin))
+                .collect();
+        origins.sort_by_key(|(hotkey, coldkey, _)| (*hotkey, *coldkey));
+        assert_eq!(
+            origins,
+            vec![
+                (delegate_hotkey, coldkey, StakeOriginTag::Delegated),
+                (delegate_hotkey, recipient, StakeOriginTag::Delegated),
+                (other_delegate_hotkey, coldkey, StakeOriginTag::SelfStake),
+            ]
+        );
+    });
+}
@@ -1,5 +1,7 @@
 use super::mock::*;
 use crate::migrations::migrate_network_immunity_period;
+use crate::staking::dissolution_payout::DissolutionPayout;
+use crate::subnets::weight_row::WeightRow;
 use crate::*;
 use frame_support::{assert_err, assert_ok};
 use frame_system::Config;
@@ -346,7 +348,11 @@ fn dissolve_clears_all_per_subnet_storages() {
         // Prefix / double-map collections
         Keys::<Test>::insert(net, 0u16, owner_hot);
         Bonds::<Test>::insert(NetUidStorageIndex::from(net), 0u16, vec![(0u16, 1u16)]);
-        Weights::<Test>::insert(NetUidStorageIndex::from(net), 0u16, vec![(1u16, 1u16)]);
+        Weights::<Test>::insert(
+            NetUidStorageIndex::from(net),
+            0u16,
+            WeightRow::from(vec![(1u16, 1u16)]),
+        );
 
         // Membership entry for the SAME hotkey as Keys
         IsNetworkMember::<Test>::insert(owner_hot, net, true);
@@ -400,6 +406,10 @@ fn dissolve_clears_all_per_subnet_storages() {
         RegistrationsThisBlock::<Test>::insert(net, 1u16);
         EMAPriceHalvingBlocks::<Test>::insert(net, 1u64);
         RAORecycledForRegistration::<Test>::insert(net, TaoCurrency::from(1));
+        RegistrationBurnedTao::<Test>::insert(net, TaoCurrency::from(1));
+        RecycledEmission::<Test>::insert(net, AlphaCurrency::from(1));
+        RegistrationDepositShare::<Test>::insert(net, 1u16);
+        RegistrationDepositEpochs::<Test>::insert(net, 1u16);
 
         // Feature toggles
         LiquidAlphaOn::<Test>::insert(net, true);
@@ -558,6 +568,10 @@ fn dissolve_clears_all_per_subnet_storages() {
         assert!(!RegistrationsThisBlock::<Test>::contains_key(net));
         assert!(!EMAPriceHalvingBlocks::<Test>::contains_key(net));
         assert!(!RAORecycledForRegistration::<Test>::contains_key(net));
+        assert!(!RegistrationBurnedTao::<Test>::contains_key(net));
+        assert!(!RecycledEmission::<Test>::contains_key(net));
+        assert!(!RegistrationDepositShare::<Test>::contains_key(net));
+        assert!(!RegistrationDepositEpochs::<Test>::contains_key(net));
 
         // Feature toggles
         assert!(!LiquidAlphaOn::<Test>::contains_key(net));
@@ -1734,6 +1748,35 @@ fn test_register_subnet_high_lock_cost() {
     })
 }
 
+#[test]
+fn test_network_lock_cost_at() {
+    new_test_ext(1).execute_with(|| {
+        NetworkMinLockCost::<Test>::set(TaoCurrency::from(100));
+        SubtensorModule::set_network_last_lock(TaoCurrency::from(1_000));
+        SubtensorModule::set_network_last_lock_block(100);
+        SubtensorModule::set_lock_reduction_interval(100);
+        System::set_block_number(120);
+
+        // The lock cost decays linearly from twice the last lock down to the minimum
+        assert_eq!(
+            SubtensorModule::get_network_lock_cost_at(100),
+            TaoCurrency::from(2_000)
+        );
+        assert_eq!(
+            SubtensorModule::get_network_lock_cost_at(150),
+            TaoCurrency::from(1_500)
+        );
+        assert_eq!(
+            SubtensorModule::get_network_lock_cost_at(300),
+            TaoCurrency::from(100)
+        );
+        assert_eq!(
+            SubtensorModule::get_network_lock_cost_at(120),
+            SubtensorModule::get_network_lock_cost()
+        );
+    })
+}
+
 #[test]
 fn test_tempo_greater_than_weight_set_rate_limit() {
     new_test_ext(1).execute_with(|| {
@@ -2201,3 +2244,168 @@ fn massive_dissolve_refund_and_reregistration_flow_is_lossless_and_cleans_state(
         );
     });
 }
+
+#[test]
+fn test_subnets_owned_by_follows_subnet_owner() {
+    new_test_ext(0).execute_with(|| {
+        let cold = U256::from(1);
+        let new_cold = U256::from(3);
+        let net_a = add_dynamic_network(&U256::from(2), &cold);
+        let net_b = add_dynamic_network(&U256::from(4), &cold);
+        assert_eq!(
+            SubtensorModule::get_subnets_owned_by(&cold),
+            vec![net_a, net_b]
+        );
+
+        // A coldkey swap moves every owned subnet to the new coldkey
+        let mut weight = frame_support::weights::Weight::zero();
+        assert_ok!(SubtensorModule::perform_swap_coldkey(
+            &cold,
+            &new_cold,
+            &mut weight
+        ));
+        assert!(SubtensorModule::get_subnets_owned_by(&cold).is_empty());
+        assert!(!SubnetsOwnedBy::<Test>::contains_key(cold));
+        assert_eq!(
+            SubtensorModule::get_subnets_owned_by(&new_cold),
+            vec![net_a, net_b]
+        );
+        assert_eq!(SubnetOwner::<Test>::get(net_a), new_cold);
+
+        // Dissolving a subnet drops it from the index
+        assert_ok!(SubtensorModule::do_dissolve_network(net_a));
+        assert_eq!(
+            SubtensorModule::get_subnets_owned_by(&new_cold),
+            vec![net_b]
+        );
+    });
+}
+
+#[test]
+fn test_replay_dissolution_settlement_matches_dissolution() {
+    new_test_ext(1).execute_with(|| {
+        let owner_cold = U256::from(50);
+        let net = add_dynamic_network(&U256::from(51), &owner_cold);
+
+        // Legacy subnet, so the owner is refunded the lock cost
+        let reg_at = NetworkRegisteredAt::<Test>::get(net);
+        NetworkRegistrationStartBlock::<Test>::put(reg_at.saturating_add(1));
+        SubtensorModule::set_subnet_locked_balance(net, 5_000.into());
+
+        let (s1_hot, s1_cold) = (U256::from(201), U256::from(301));
+        let (s2_hot, s2_cold) = (U256::from(202), U256::from(302));
+        Alpha::<Test>::insert((s1_hot, s1_cold, net), U64F64::from_num(333u128));
+        Alpha::<Test>::insert((s2_hot, s2_cold, net), U64F64::from_num(667u128));
+        Alpha::<Test>::insert((s2_hot, owner_cold, net), U64F64::from_num(0u128));
+        SubnetTAO::<Test>::insert(net, TaoCurrency::from(10_001));
+
+        let balances = |coldkeys: &[U256]| -> Vec<u64> {
+            coldkeys
+                .iter()
+                .map(SubtensorModule::get_coldkey_balance)
+                .collect()
+        };
+        let coldkeys = [s1_cold, s2_cold, owner_cold];
+        let before = balances(&coldkeys);
+        let block = System::block_number();
+
+        let settlement = SubtensorModule::replay_dissolution_settlement(net, 100).unwrap();
+        assert_eq!(settlement.pot, TaoCurrency::from(10_001));
+        assert_eq!(settlement.owner, owner_cold);
+        assert_eq!(settlement.owner_refund, TaoCurrency::from(5_000));
+        assert_eq!(settlement.stakes.len(), 3);
+        let credits = settlement.credits();
+        assert_eq!(
+            credits
+                .values()
+                .fold(0u64, |total, tao| total + tao.to_u64()),
+            15_001
+        );
+
+        // The replay leaves the state as it was
+        assert_eq!(balances(&coldkeys), before);
+        assert_eq!(SubnetTAO::<Test>::get(net), TaoCurrency::from(10_001));
+        assert_eq!(
+            Alpha::<Test>::iter()
+                .filter(|((_, _, n), _)| *n == net)
+                .count(),
+            3
+        );
+        assert_eq!(System::block_number(), block);
+
+        // The dissolution pays out exactly the replayed settlement
+        assert_ok!(SubtensorModule::do_dissolve_network(net));
+        for (coldkey, before) in coldkeys.iter().zip(before) {
+            let credit = credits.get(coldkey).map(|tao| tao.to_u64()).unwrap_or(0);
+            assert_eq!(
+                SubtensorModule::get_coldkey_balance(coldkey),
+                before + credit
+            );
+        }
+
+        // A subnet that no longer exists cannot be replayed
+        assert!(SubtensorModule::replay_dissolution_settlement(net, 100).is_err());
+    });
+}
+
+#[test]
+fn test_dissolution_payout_restaked_into_root() {
+    new_test_ext(1).execute_with(|| {
+        let net = add_dynamic_network(&U256::from(51), &U256::from(50));
+        SubtensorModule::set_subnet_locked_balance(net, 0.into());
+
+        let (s1_hot, s1_cold) = (U256::from(201), U256::from(301));
+        let (s2_hot, s2_cold) = (U256::from(202), U256::from(302));
+        let root_hot = U256::from(401);
+        Alpha::<Test>::insert((s1_hot, s1_cold, net), U64F64::from_num(1_000u128));
+        Alpha::<Test>::insert((s2_hot, s2_cold, net), U64F64::from_num(3_000u128));
+        SubnetTAO::<Test>::insert(net, TaoCurrency::from(10_000));
+
+        // The payouts can only be restaked on an existing hotkey
+        assert_err!(
+            SubtensorModule::set_dissolution_payout(
+                RuntimeOrigin::signed(s2_cold),
+                DissolutionPayout::RootStake(root_hot)
+            ),
+            Error::<Test>::HotKeyAccountNotExists
+        );
+        SubtensorModule::create_account_if_non_existent(&U256::from(402), &root_hot);
+        assert_ok!(SubtensorModule::set_dissolution_payout(
+            RuntimeOrigin::signed(s2_cold),
+            DissolutionPayout::RootStake(root_hot)
+        ));
+        assert_eq!(
+            DissolutionPayoutPreference::<Test>::get(s2_cold),
+            DissolutionPayout::RootStake(root_hot)
+        );
+
+        // The replay accounts for the restaked payouts
+        assert_ok!(SubtensorModule::replay_dissolution_settlement(net, 100));
+
+        let s1_before = SubtensorModule::get_coldkey_balance(&s1_cold);
+        let s2_before = SubtensorModule::get_coldkey_balance(&s2_cold);
+        assert_ok!(SubtensorModule::do_dissolve_network(net));
+
+        // The default payout goes to the free balance, the preferred one into root stake
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&s1_cold),
+            s1_before + 2_500
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&s2_cold), s2_before);
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &root_hot,
+                &s2_cold,
+                NetUid::ROOT
+            ),
+            AlphaCurrency::from(7_500)
+        );
+
+        // Going back to the free balance clears the preference
+        assert_ok!(SubtensorModule::set_dissolution_payout(
+            RuntimeOrigin::signed(s2_cold),
+            DissolutionPayout::Balance
+        ));
+        assert!(!DissolutionPayoutPreference::<Test>::contains_key(s2_cold));
+    });
+}
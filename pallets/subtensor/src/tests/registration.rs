@@ -13,7 +13,13 @@ use subtensor_runtime_common::{AlphaCurrency, Currency as CurrencyT, NetUid, Net
 
 use super::mock;
 use super::mock::*;
+use crate::subnets::bootstrap::MAX_BOOTSTRAP_NEURONS;
+use crate::subnets::registration_access::{RegistrationAccess, RegistrationAccount};
+use crate::subnets::registration_deposit::RegistrationDeposit;
+use crate::subnets::registration_queue::REGISTRATION_QUEUE_TIMEOUT;
+use crate::subnets::weight_row::WeightRow;
 use crate::transaction_extension::SubtensorTransactionExtension;
+use crate::utils::reserved_accounts::ReservedAccountKind;
 use crate::{AxonInfoOf, CustomTransactionError, Error};
 
 /********************************************
@@ -1336,6 +1342,60 @@ fn test_registration_get_uid_to_prune_none_in_immunity_period() {
     });
 }
 
+#[test]
+fn test_registration_get_uid_to_prune_immunity_override() {
+    new_test_ext(1).execute_with(|| {
+        System::set_block_number(0);
+        let netuid = NetUid::from(1);
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, U256::from(0), U256::from(0), 39420842);
+        register_ok_neuron(netuid, U256::from(1), U256::from(1), 12412392);
+        SubtensorModule::set_pruning_score_for_uid(netuid, 0, 100);
+        SubtensorModule::set_pruning_score_for_uid(netuid, 1, 110);
+        SubtensorModule::set_immunity_period(netuid, 2);
+        step_block(3);
+
+        // The uid with the lowest pruning score is kept while its immunity is extended
+        assert_eq!(SubtensorModule::find_neuron_to_prune(netuid), Some(0));
+        assert_ok!(SubtensorModule::set_neuron_immunity_override(
+            netuid,
+            0,
+            Some(10)
+        ));
+        assert!(SubtensorModule::get_neuron_is_immune(netuid, 0));
+        assert_eq!(SubtensorModule::find_neuron_to_prune(netuid), Some(1));
+        assert_noop!(
+            SubtensorModule::set_neuron_immunity_override(netuid, 1, Some(2)),
+            Error::<Test>::InvalidValue
+        );
+
+        // The number of uids with an override is bounded
+        let max_overrides = MaxNeuronImmunityOverrides::<Test>::get();
+        for uid in 1..max_overrides {
+            assert_ok!(SubtensorModule::set_neuron_immunity_override(
+                netuid,
+                uid as u16,
+                Some(10)
+            ));
+        }
+        assert_noop!(
+            SubtensorModule::set_neuron_immunity_override(netuid, max_overrides as u16, Some(10)),
+            Error::<Test>::TooManyNeuronImmunityOverrides
+        );
+        assert_ok!(SubtensorModule::set_neuron_immunity_override(
+            netuid,
+            0,
+            Some(20)
+        ));
+
+        // The extension ends with the immunity period, and when the uid is replaced
+        step_block(20);
+        assert!(!SubtensorModule::get_neuron_is_immune(netuid, 0));
+        SubtensorModule::replace_neuron(netuid, 0, &U256::from(2), 20);
+        assert!(!NeuronImmunityOverride::<Test>::contains_key(netuid, 0));
+    });
+}
+
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::registration::test_registration_get_uid_to_prune_owner_immortality --exact --show-output --nocapture
 #[test]
 fn test_registration_get_uid_to_prune_owner_immortality() {
@@ -2338,3 +2398,915 @@ fn test_last_update_correctness() {
 //         );
 //     });
 // }
+
+/********************************************
+    reserved account registry tests
+*********************************************/
+
+#[test]
+fn test_burned_registration_rejects_reserved_hotkey() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(667);
+        let burn_cost: u64 = 1000;
+        let reserve: u64 = 1_000_000_000_000;
+        SubtensorModule::set_burn(netuid, burn_cost.into());
+        add_network(netuid, 13, 0);
+        mock::setup_reserves(netuid, reserve.into(), reserve.into());
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10000);
+
+        assert_ok!(SubtensorModule::set_reserved_account(
+            RawOrigin::Root.into(),
+            hotkey,
+            Some(ReservedAccountKind::Treasury),
+        ));
+        assert_eq!(
+            SubtensorModule::get_reserved_accounts(),
+            vec![(hotkey, ReservedAccountKind::Treasury)]
+        );
+
+        assert_noop!(
+            SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                hotkey
+            ),
+            Error::<Test>::AccountIsReserved
+        );
+
+        // Once released, the account can be registered again.
+        assert_ok!(SubtensorModule::set_reserved_account(
+            RawOrigin::Root.into(),
+            hotkey,
+            None,
+        ));
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            hotkey
+        ));
+    });
+}
+
+#[test]
+fn test_set_reserved_account_checks() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+
+        assert_noop!(
+            SubtensorModule::set_reserved_account(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                U256::from(3),
+                Some(ReservedAccountKind::Escrow),
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            SubtensorModule::set_reserved_account(
+                RawOrigin::Root.into(),
+                hotkey,
+                Some(ReservedAccountKind::Escrow),
+            ),
+            Error::<Test>::ReservedAccountInUse
+        );
+        assert_noop!(
+            SubtensorModule::set_reserved_account(RawOrigin::Root.into(), U256::from(3), None),
+            Error::<Test>::AccountNotReserved
+        );
+    });
+}
+
+#[test]
+fn test_swap_hotkey_rejects_reserved_destination() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, old_hotkey, coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000_000_000);
+
+        assert_ok!(SubtensorModule::set_reserved_account(
+            RawOrigin::Root.into(),
+            new_hotkey,
+            Some(ReservedAccountKind::Claims),
+        ));
+        assert_noop!(
+            SubtensorModule::swap_hotkey(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                old_hotkey,
+                new_hotkey,
+                None
+            ),
+            Error::<Test>::AccountIsReserved
+        );
+    });
+}
+
+/********************************************
+    registration churn tests
+*********************************************/
+
+#[test]
+fn test_burned_registration_churn_escalates_burn() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let coldkey = U256::from(667);
+        let burn_cost: u64 = 1000;
+        let reserve: u64 = 1_000_000_000_000;
+        SubtensorModule::set_burn(netuid, burn_cost.into());
+        add_network(netuid, 13, 0);
+        mock::setup_reserves(netuid, reserve.into(), reserve.into());
+        SubtensorModule::set_max_registrations_per_block(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        SubtensorModule::set_churn_burn_params(netuid, 1, 100);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000);
+
+        // Registers a fresh hotkey of the coldkey and deregisters it again.
+        let churn = |i: u64| -> u64 {
+            let hotkey = U256::from(i);
+            let balance_before = SubtensorModule::get_coldkey_balance(&coldkey);
+            assert_ok!(SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                hotkey
+            ));
+            let uid = SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey).unwrap();
+            SubtensorModule::replace_neuron(netuid, uid, &U256::from(i + 1000), 1);
+            balance_before - SubtensorModule::get_coldkey_balance(&coldkey)
+        };
+
+        // The first registration is not preceded by a deregistration.
+        assert_eq!(churn(1), burn_cost);
+        // The first re-registration is within the threshold.
+        assert_eq!(churn(2), burn_cost);
+        // The second one exceeds it and escalates the burn.
+        assert_eq!(churn(3), burn_cost);
+        assert_eq!(
+            SubtensorModule::get_churn_burn_exponent(netuid, &coldkey),
+            1
+        );
+        assert_eq!(churn(4), burn_cost * 2);
+        assert_eq!(
+            SubtensorModule::get_churn_burn_exponent(netuid, &coldkey),
+            2
+        );
+        assert_eq!(
+            SubtensorModule::get_burn_for_coldkey(netuid, &coldkey),
+            (burn_cost * 4).into()
+        );
+
+        // Other coldkeys pay the regular burn.
+        assert_eq!(
+            SubtensorModule::get_burn_for_coldkey(netuid, &U256::from(668)),
+            burn_cost.into()
+        );
+
+        // The escalation decays by one step per elapsed window.
+        System::set_block_number(System::block_number() + 100);
+        assert_eq!(
+            SubtensorModule::get_churn_burn_exponent(netuid, &coldkey),
+            1
+        );
+        System::set_block_number(System::block_number() + 100);
+        assert_eq!(
+            SubtensorModule::get_churn_burn_exponent(netuid, &coldkey),
+            0
+        );
+        assert_eq!(
+            SubtensorModule::get_burn_for_coldkey(netuid, &coldkey),
+            burn_cost.into()
+        );
+    });
+}
+
+#[test]
+fn test_hotkey_blacklist() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        let coldkey = U256::from(667);
+        let hotkey = U256::from(668);
+        let validator = U256::from(669);
+        let burn_cost: u64 = 1000;
+        SubtensorModule::set_burn(netuid, burn_cost.into());
+        add_network_disable_commit_reveal(netuid, 13, 0);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        mock::setup_reserves(netuid, 1_000_000_000_000.into(), 1_000_000_000_000.into());
+        SubtensorModule::set_max_registrations_per_block(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000);
+        register_ok_neuron(netuid, validator, coldkey, 0);
+
+        assert_noop!(
+            SubtensorModule::blacklist_hotkey(RuntimeOrigin::signed(coldkey), netuid, hotkey, 100),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            SubtensorModule::blacklist_hotkey(RuntimeOrigin::signed(owner), netuid, hotkey, 0),
+            Error::<Test>::InvalidBlacklistDuration
+        );
+        assert_ok!(SubtensorModule::blacklist_hotkey(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            hotkey,
+            100
+        ));
+        assert_ok!(SubtensorModule::blacklist_hotkey(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            validator,
+            10
+        ));
+
+        // Blacklisted hotkeys can neither register nor set weights.
+        assert_noop!(
+            SubtensorModule::burned_register(RuntimeOrigin::signed(coldkey), netuid, hotkey),
+            Error::<Test>::HotkeyBlacklisted
+        );
+        assert_noop!(
+            SubtensorModule::set_weights(
+                RuntimeOrigin::signed(validator),
+                netuid,
+                vec![0],
+                vec![1],
+                0
+            ),
+            Error::<Test>::HotkeyBlacklisted
+        );
+
+        // Entries expire.
+        System::set_block_number(System::block_number() + 10);
+        assert!(!SubtensorModule::is_hotkey_blacklisted(netuid, &validator));
+        assert!(SubtensorModule::is_hotkey_blacklisted(netuid, &hotkey));
+
+        // Only governance or the owner strike entries.
+        assert_noop!(
+            SubtensorModule::strike_blacklisted_hotkey(
+                RuntimeOrigin::signed(coldkey),
+                netuid,
+                hotkey
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(SubtensorModule::strike_blacklisted_hotkey(
+            RuntimeOrigin::root(),
+            netuid,
+            hotkey
+        ));
+        assert_noop!(
+            SubtensorModule::strike_blacklisted_hotkey(RuntimeOrigin::root(), netuid, hotkey),
+            Error::<Test>::HotkeyNotBlacklisted
+        );
+        assert_ok!(SubtensorModule::burned_register(
+            RuntimeOrigin::signed(coldkey),
+            netuid,
+            hotkey
+        ));
+    });
+}
+
+#[test]
+fn test_registration_access_lists() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        let coldkey = U256::from(667);
+        let other_coldkey = U256::from(670);
+        let hotkey = U256::from(668);
+        let other_hotkey = U256::from(669);
+        let burn_cost: u64 = 1000;
+        SubtensorModule::set_burn(netuid, burn_cost.into());
+        add_network(netuid, 13, 0);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        mock::setup_reserves(netuid, 1_000_000_000_000.into(), 1_000_000_000_000.into());
+        SubtensorModule::set_max_registrations_per_block(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000);
+        SubtensorModule::add_balance_to_coldkey_account(&other_coldkey, 1_000_000);
+
+        assert_noop!(
+            SubtensorModule::set_registration_access(
+                RuntimeOrigin::signed(coldkey),
+                netuid,
+                RegistrationAccount::Coldkey(coldkey),
+                Some(RegistrationAccess::Allowed)
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            SubtensorModule::set_registration_access(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                RegistrationAccount::Coldkey(coldkey),
+                None
+            ),
+            Error::<Test>::RegistrationAccessEntryNotFound
+        );
+
+        // Only allowlisted coldkeys register while the allowlist is not empty.
+        assert_ok!(SubtensorModule::set_registration_access(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            RegistrationAccount::Coldkey(coldkey),
+            Some(RegistrationAccess::Allowed)
+        ));
+        assert_eq!(RegistrationAllowlistSize::<Test>::get(netuid), 1);
+        assert_noop!(
+            SubtensorModule::burned_register(
+                RuntimeOrigin::signed(other_coldkey),
+                netuid,
+                other_hotkey
+            ),
+            Error::<Test>::RegistrationNotAllowlisted
+        );
+
+        // Denied hotkeys do not register, even for an allowlisted coldkey.
+        assert_ok!(SubtensorModule::set_registration_access(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            RegistrationAccount::Hotkey(hotkey),
+            Some(RegistrationAccess::Denied)
+        ));
+        assert_noop!(
+            SubtensorModule::burned_register(RuntimeOrigin::signed(coldkey), netuid, hotkey),
+            Error::<Test>::RegistrationDenied
+        );
+        assert_ok!(SubtensorModule::set_registration_access(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            RegistrationAccount::Hotkey(hotkey),
+            None
+        ));
+        assert_ok!(SubtensorModule::burned_register(
+            RuntimeOrigin::signed(coldkey),
+            netuid,
+            hotkey
+        ));
+
+        // Emptying the allowlist opens registrations again.
+        assert_ok!(SubtensorModule::set_registration_access(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            RegistrationAccount::Coldkey(coldkey),
+            Some(RegistrationAccess::Denied)
+        ));
+        assert_eq!(RegistrationAllowlistSize::<Test>::get(netuid), 0);
+        System::assert_last_event(
+            Event::RegistrationAccessSet {
+                netuid,
+                account: RegistrationAccount::Coldkey(coldkey),
+                access: Some(RegistrationAccess::Denied),
+            }
+            .into(),
+        );
+        assert_ok!(SubtensorModule::burned_register(
+            RuntimeOrigin::signed(other_coldkey),
+            netuid,
+            other_hotkey
+        ));
+    });
+}
+
+#[test]
+fn test_coldkey_registration_quota() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let coldkey = U256::from(667);
+        let other_coldkey = U256::from(668);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, 1000.into());
+        SubtensorModule::set_max_registrations_per_block(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        SubtensorModule::set_adjustment_interval(netuid, 100);
+        SubtensorModule::set_max_registrations_per_coldkey_per_interval(netuid, 2);
+        mock::setup_reserves(netuid, 1_000_000_000_000.into(), 1_000_000_000_000.into());
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000);
+        SubtensorModule::add_balance_to_coldkey_account(&other_coldkey, 1_000_000);
+
+        // Burned and PoW registrations both count against the quota of the coldkey.
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            U256::from(1)
+        ));
+        let (nonce, work) =
+            SubtensorModule::create_work_for_block_number(netuid, 0, 3942084, &U256::from(2));
+        assert_ok!(SubtensorModule::register(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(2)),
+            netuid,
+            0,
+            nonce,
+            work,
+            U256::from(2),
+            coldkey
+        ));
+        assert_eq!(
+            ColdkeyRegistrationsThisInterval::<Test>::get(netuid, coldkey),
+            2
+        );
+        assert_noop!(
+            SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                U256::from(3)
+            ),
+            Error::<Test>::TooManyColdkeyRegistrationsThisInterval
+        );
+
+        // Other coldkeys still register.
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(other_coldkey),
+            netuid,
+            U256::from(4)
+        ));
+
+        // The quota is drained with the other counters of the interval.
+        SubtensorModule::set_adjustment_interval(netuid, 1);
+        step_block(1);
+        assert_eq!(
+            ColdkeyRegistrationsThisInterval::<Test>::get(netuid, coldkey),
+            0
+        );
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            U256::from(3)
+        ));
+
+        // Without a quota, registrations are not counted.
+        SubtensorModule::set_max_registrations_per_coldkey_per_interval(netuid, 0);
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            U256::from(5)
+        ));
+        assert_eq!(
+            ColdkeyRegistrationsThisInterval::<Test>::get(netuid, coldkey),
+            1
+        );
+    });
+}
+
+#[test]
+fn test_bootstrap_register() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        add_network_without_emission_block(netuid, 13, 0);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_max_allowed_uids(netuid, 128);
+        let neurons: Vec<(U256, U256)> = (0..3)
+            .map(|i| (U256::from(100 + i), U256::from(200 + i)))
+            .collect();
+
+        // Only the owner can bootstrap the subnet.
+        assert_noop!(
+            SubtensorModule::bootstrap_register(
+                RuntimeOrigin::signed(U256::from(2)),
+                netuid,
+                neurons.clone()
+            ),
+            DispatchError::BadOrigin
+        );
+
+        // Hotkeys owned by another coldkey are rejected.
+        SubtensorModule::create_account_if_non_existent(&U256::from(300), &U256::from(400));
+        assert_noop!(
+            SubtensorModule::bootstrap_register(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                vec![(U256::from(400), U256::from(500))]
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+
+        // The neurons register without burning or counting against the registration limits.
+        assert_ok!(SubtensorModule::bootstrap_register(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            neurons.clone()
+        ));
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 3);
+        for (uid, (hotkey, coldkey)) in neurons.iter().enumerate() {
+            assert_eq!(
+                SubtensorModule::get_uid_for_net_and_hotkey(netuid, hotkey),
+                Ok(uid as u16)
+            );
+            assert_eq!(
+                SubtensorModule::get_owning_coldkey_for_hotkey(hotkey),
+                *coldkey
+            );
+        }
+        assert_eq!(SubtensorModule::get_registrations_this_block(netuid), 0);
+        assert_noop!(
+            SubtensorModule::bootstrap_register(RuntimeOrigin::signed(owner), netuid, neurons),
+            Error::<Test>::HotKeyAlreadyRegisteredInSubNet
+        );
+
+        // At most `MAX_BOOTSTRAP_NEURONS` neurons can be bootstrapped.
+        let too_many: Vec<(U256, U256)> = (0..MAX_BOOTSTRAP_NEURONS)
+            .map(|i| (U256::from(1_000 + i), U256::from(2_000 + i)))
+            .collect();
+        assert_noop!(
+            SubtensorModule::bootstrap_register(RuntimeOrigin::signed(owner), netuid, too_many),
+            Error::<Test>::TooManyBootstrapNeurons
+        );
+
+        // Once the subnet emits the bootstrap is closed.
+        FirstEmissionBlockNumber::<Test>::insert(netuid, 1);
+        assert_noop!(
+            SubtensorModule::bootstrap_register(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                vec![(U256::from(600), U256::from(700))]
+            ),
+            Error::<Test>::SubnetBootstrapClosed
+        );
+    });
+}
+
+#[test]
+fn test_burn_auction() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let burn_cost = 1000;
+        SubtensorModule::set_burn(netuid, burn_cost.into());
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 1);
+        mock::setup_reserves(netuid, 1_000_000_000_000.into(), 1_000_000_000_000.into());
+
+        let bidders = [
+            (U256::from(667), U256::from(1)),
+            (U256::from(668), U256::from(2)),
+            (U256::from(669), U256::from(3)),
+        ];
+        for (coldkey, _) in bidders {
+            SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+        }
+        let bid = |(coldkey, hotkey): (U256, U256), amount: u64| {
+            SubtensorModule::bid_burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                hotkey,
+                amount.into(),
+            )
+        };
+
+        assert_noop!(bid(bidders[0], 2_000), Error::<Test>::BurnAuctionDisabled);
+        SubtensorModule::set_burn_auction_enabled(netuid, true);
+
+        // Burned registrations go through the auction only
+        assert_noop!(
+            SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(bidders[0].0),
+                netuid,
+                bidders[0].1
+            ),
+            Error::<Test>::RegistrationByAuctionOnly
+        );
+        assert_noop!(
+            bid(bidders[0], burn_cost - 1),
+            Error::<Test>::RegistrationBidTooLow
+        );
+
+        assert_ok!(bid(bidders[0], 2_000));
+        assert_ok!(bid(bidders[1], 3_000));
+        assert_ok!(bid(bidders[2], 1_500));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&bidders[1].0),
+            10_000 - 3_000
+        );
+
+        // A bid can only be raised
+        assert_noop!(bid(bidders[0], 2_000), Error::<Test>::RegistrationBidTooLow);
+        assert_ok!(bid(bidders[0], 2_500));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&bidders[0].0),
+            10_000 - 2_500
+        );
+
+        // The highest bid wins the single registration of the interval
+        SubtensorModule::settle_registration_auction(netuid);
+        assert!(SubtensorModule::get_uid_for_net_and_hotkey(netuid, &bidders[1].1).is_ok());
+        assert!(SubtensorModule::get_uid_for_net_and_hotkey(netuid, &bidders[0].1).is_err());
+        assert!(SubtensorModule::get_uid_for_net_and_hotkey(netuid, &bidders[2].1).is_err());
+        assert_eq!(
+            SubtensorModule::get_burn_registrations_this_interval(netuid),
+            1
+        );
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&bidders[1].0),
+            10_000 - 3_000
+        );
+        for (coldkey, hotkey) in [bidders[0], bidders[2]] {
+            assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 10_000);
+            assert!(!RegistrationBids::<Test>::contains_key(netuid, hotkey));
+        }
+        System::assert_has_event(
+            Event::RegistrationBidRefunded {
+                netuid,
+                coldkey: bidders[0].0,
+                hotkey: bidders[0].1,
+                amount: 2_500.into(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_registration_queue() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let burn_cost = 1000;
+        SubtensorModule::set_burn(netuid, burn_cost.into());
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, 2);
+        SubtensorModule::set_max_registrations_per_block(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        mock::setup_reserves(netuid, 1_000_000_000_000.into(), 1_000_000_000_000.into());
+        SubtensorModule::set_registration_queue_enabled(netuid, true);
+
+        let coldkey = U256::from(667);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 100_000);
+        let register = |hotkey: u64| {
+            SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                U256::from(hotkey),
+            )
+        };
+
+        // Registrations on a subnet with free slots are not queued
+        assert_ok!(register(1));
+        assert_ok!(register(2));
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 2);
+        let balance = SubtensorModule::get_coldkey_balance(&coldkey);
+
+        // On the full subnet, the burn is escrowed in the queue and no neuron is pruned
+        let burn = SubtensorModule::get_burn(netuid);
+        assert_ok!(register(3));
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 2);
+        assert!(SubtensorModule::get_uid_for_net_and_hotkey(netuid, &U256::from(3)).is_err());
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            balance - u64::from(burn)
+        );
+        System::assert_has_event(
+            Event::RegistrationQueued {
+                netuid,
+                coldkey,
+                hotkey: U256::from(3),
+                escrow: burn,
+                position: 1,
+            }
+            .into(),
+        );
+        assert_noop!(register(3), Error::<Test>::HotKeyAlreadyQueued);
+
+        // The queued registration takes the slot of a pruned neuron after the epoch
+        SubtensorModule::admit_queued_registrations(netuid);
+        assert!(SubtensorModule::get_uid_for_net_and_hotkey(netuid, &U256::from(3)).is_ok());
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 2);
+        assert!(RegistrationQueue::<Test>::get(netuid).is_empty());
+
+        // A registration waiting past the timeout is refunded
+        let balance = SubtensorModule::get_coldkey_balance(&coldkey);
+        assert_ok!(register(4));
+        System::set_block_number(System::block_number() + REGISTRATION_QUEUE_TIMEOUT);
+        SubtensorModule::admit_queued_registrations(netuid);
+        assert!(SubtensorModule::get_uid_for_net_and_hotkey(netuid, &U256::from(4)).is_err());
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), balance);
+
+        // Every queued registration is refunded once the queue is disabled
+        assert_ok!(register(5));
+        SubtensorModule::set_registration_queue_enabled(netuid, false);
+        SubtensorModule::admit_queued_registrations(netuid);
+        assert!(SubtensorModule::get_uid_for_net_and_hotkey(netuid, &U256::from(5)).is_err());
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), balance);
+        assert!(RegistrationQueue::<Test>::get(netuid).is_empty());
+    });
+}
+
+#[test]
+fn test_subnet_token_accounting() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let coldkey = U256::from(667);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, 1000.into());
+        mock::setup_reserves(netuid, 1_000_000_000_000.into(), 1_000_000_000_000.into());
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000);
+
+        let accounting = SubtensorModule::get_subnet_token_accounting(netuid).unwrap();
+        assert!(accounting.registration_burned_tao.is_zero());
+        assert!(accounting.recycled_emission.is_zero());
+
+        // Burned registrations add their burn, PoW registrations burn nothing.
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            U256::from(1)
+        ));
+        let (nonce, work) =
+            SubtensorModule::create_work_for_block_number(netuid, 0, 3942084, &U256::from(2));
+        assert_ok!(SubtensorModule::register(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(2)),
+            netuid,
+            0,
+            nonce,
+            work,
+            U256::from(2),
+            coldkey
+        ));
+
+        // Recycled emission is counted, recycling alpha directly is not.
+        SubtensorModule::recycle_subnet_emission(netuid, AlphaCurrency::from(500));
+        SubtensorModule::recycle_subnet_alpha(netuid, AlphaCurrency::from(200));
+
+        let accounting = SubtensorModule::get_subnet_token_accounting(netuid).unwrap();
+        assert_eq!(accounting.registration_burned_tao, 1000.into());
+        assert_eq!(accounting.recycled_emission, AlphaCurrency::from(500));
+
+        assert_eq!(
+            SubtensorModule::get_subnet_token_accounting(NetUid::from(2)),
+            None
+        );
+    });
+}
+
+#[test]
+fn test_registration_deposit_refunded_on_maturity() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let coldkey = U256::from(667);
+        let hotkey = U256::from(1);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, 1000.into());
+        assert_ok!(SubtensorModule::set_registration_deposit(netuid, 16384, 2));
+        mock::setup_reserves(netuid, 1_000_000_000_000.into(), 1_000_000_000_000.into());
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000);
+
+        // A quarter of the burn is held for the neuron, the rest is burned.
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            hotkey
+        ));
+        let matures_at = SubtensorModule::get_current_block_as_u64() + 2 * 14;
+        assert_eq!(
+            RegistrationDeposits::<Test>::get(netuid, 0),
+            Some(RegistrationDeposit {
+                coldkey,
+                amount: 250.into(),
+                matures_at,
+            })
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 999_000);
+        assert_eq!(RegistrationBurnedTao::<Test>::get(netuid), 750.into());
+
+        // The deposit is held until it matures.
+        System::set_block_number(matures_at - 1);
+        SubtensorModule::release_matured_registration_deposits(netuid);
+        assert!(RegistrationDeposits::<Test>::contains_key(netuid, 0));
+
+        System::set_block_number(matures_at);
+        SubtensorModule::release_matured_registration_deposits(netuid);
+        assert!(!RegistrationDeposits::<Test>::contains_key(netuid, 0));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 999_250);
+    });
+}
+
+#[test]
+fn test_registration_deposit_slashed_on_inactive_prune() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let coldkey = U256::from(667);
+        let other_coldkey = U256::from(668);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, 1000.into());
+        SubtensorModule::set_max_registrations_per_block(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        SubtensorModule::set_max_allowed_uids(netuid, 1);
+        SubtensorModule::set_immunity_period(netuid, 0);
+        assert_ok!(SubtensorModule::set_registration_deposit(netuid, 16384, 10));
+        mock::setup_reserves(netuid, 1_000_000_000_000.into(), 1_000_000_000_000.into());
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000);
+        SubtensorModule::add_balance_to_coldkey_account(&other_coldkey, 1_000_000);
+
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            U256::from(1)
+        ));
+
+        // A neuron pruned while active gets its deposit back.
+        SubtensorModule::set_active_for_uid(netuid, 0, true);
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(other_coldkey),
+            netuid,
+            U256::from(2)
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 999_250);
+        assert_eq!(
+            RegistrationDeposits::<Test>::get(netuid, 0).map(|deposit| deposit.coldkey),
+            Some(other_coldkey)
+        );
+
+        // A neuron pruned while inactive loses it.
+        SubtensorModule::set_active_for_uid(netuid, 0, false);
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            U256::from(3)
+        ));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&other_coldkey),
+            999_000
+        );
+        assert_eq!(
+            RegistrationDeposits::<Test>::get(netuid, 0).map(|deposit| deposit.coldkey),
+            Some(coldkey)
+        );
+        assert_eq!(
+            RegistrationBurnedTao::<Test>::get(netuid),
+            (3 * 750 + 250).into()
+        );
+    });
+}
+
+#[test]
+fn test_deregister_uid() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let netuid_index = NetUidStorageIndex::from(netuid);
+        let coldkey = U256::from(667);
+        let hotkeys = [U256::from(1), U256::from(2), U256::from(3)];
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_burn(netuid, 1000.into());
+        SubtensorModule::set_max_registrations_per_block(netuid, 10);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 10);
+        assert_ok!(SubtensorModule::set_registration_deposit(netuid, 16384, 10));
+        mock::setup_reserves(netuid, 1_000_000_000_000.into(), 1_000_000_000_000.into());
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000);
+
+        for hotkey in hotkeys {
+            assert_ok!(SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                hotkey
+            ));
+        }
+        Weights::<Test>::insert(
+            netuid_index,
+            1,
+            WeightRow::from(vec![(0, 100), (1, 200), (2, 300)]),
+        );
+        Bonds::<Test>::insert(netuid_index, 1, vec![(0, 100), (2, 300)]);
+
+        // Only the coldkey of the neuron can deregister it.
+        assert_noop!(
+            SubtensorModule::deregister_uid(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(668)),
+                netuid,
+                0
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+        assert_noop!(
+            SubtensorModule::deregister_uid(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                netuid,
+                3
+            ),
+            Error::<Test>::HotKeyNotRegisteredInSubNet
+        );
+
+        // The deposit is refunded and the last neuron takes the uid.
+        assert_ok!(SubtensorModule::deregister_uid(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            0
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 997_250);
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 2);
+        assert!(!Uids::<Test>::contains_key(netuid, hotkeys[0]));
+        assert_eq!(Uids::<Test>::get(netuid, hotkeys[2]), Some(0));
+        assert_eq!(Keys::<Test>::get(netuid, 0), hotkeys[2]);
+        assert!(!Keys::<Test>::contains_key(netuid, 2));
+        assert!(RegistrationDeposits::<Test>::contains_key(netuid, 0));
+        assert!(!RegistrationDeposits::<Test>::contains_key(netuid, 2));
+        assert_eq!(
+            Weights::<Test>::get(netuid_index, 1).to_vec(),
+            vec![(0, 300), (1, 200)]
+        );
+        assert_eq!(Bonds::<Test>::get(netuid_index, 1), vec![(0, 300)]);
+    });
+}
@@ -893,3 +893,69 @@ fn test_adjust_senate_events() {
         );
     });
 }
+
+#[test]
+fn test_senate_updated_from_root_stake() {
+    new_test_ext().execute_with(|| {
+        migrations::migrate_create_root_network::migrate_create_root_network::<Test>();
+        let coldkey = U256::from(667);
+        let max_members = SenateMaxMembers::get() as u64;
+
+        // One more delegate on root than there are seats, the first with the least stake
+        let hotkeys: Vec<U256> = (0..=max_members).map(|i| U256::from(100 + i)).collect();
+        for (i, hotkey) in hotkeys.iter().enumerate() {
+            SubtensorModule::append_neuron(NetUid::ROOT, hotkey, 0);
+            Delegates::<Test>::insert(hotkey, u16::MAX / 10);
+            SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                hotkey,
+                &coldkey,
+                NetUid::ROOT,
+                AlphaCurrency::from(1_000 * (i as u64 + 1)),
+            );
+        }
+
+        // Members not registered on root leave, the delegates with the most stake join
+        SubtensorModule::update_senate();
+        assert!(!Senate::is_member(&U256::from(1)));
+        assert!(
+            System::events().contains(&record(RuntimeEvent::SubtensorModule(
+                SubtensorEvent::SenateMemberRemoved {
+                    member: U256::from(1)
+                }
+            )))
+        );
+        assert_eq!(Senate::members().len(), max_members as usize);
+        assert!(!Senate::is_member(&hotkeys[0]));
+        assert!(hotkeys.iter().skip(1).all(Senate::is_member));
+
+        // A delegate within the hysteresis of the least staked member does not replace it
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkeys[0],
+            &coldkey,
+            NetUid::ROOT,
+            AlphaCurrency::from(1_050),
+        );
+        SubtensorModule::update_senate();
+        assert!(!Senate::is_member(&hotkeys[0]));
+
+        // Above it, the delegate replaces the member
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkeys[0],
+            &coldkey,
+            NetUid::ROOT,
+            AlphaCurrency::from(150),
+        );
+        System::reset_events();
+        SubtensorModule::update_senate();
+        assert!(Senate::is_member(&hotkeys[0]));
+        assert!(!Senate::is_member(&hotkeys[1]));
+        assert!(
+            System::events().contains(&record(RuntimeEvent::SubtensorModule(
+                SubtensorEvent::SenateAdjusted {
+                    old_member: Some(hotkeys[1]),
+                    new_member: hotkeys[0]
+                }
+            )))
+        );
+    });
+}
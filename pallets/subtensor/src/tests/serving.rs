@@ -1444,3 +1444,65 @@ fn test_serve_axon_validate() {
         assert_ok!(result_ok);
     });
 }
+
+#[test]
+fn test_free_extrinsic_min_balance_filters_spam() {
+    new_test_ext(0).execute_with(|| {
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let netuid = NetUid::from(1);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+
+        let serve_axon = RuntimeCall::SubtensorModule(SubtensorCall::serve_axon {
+            netuid,
+            version: 2,
+            ip: 1676056785,
+            port: 128,
+            ip_type: 4,
+            protocol: 0,
+            placeholder1: 0,
+            placeholder2: 0,
+        });
+        let set_weights = RuntimeCall::SubtensorModule(SubtensorCall::set_weights {
+            netuid,
+            dests: vec![0],
+            weights: vec![u16::MAX],
+            version_key: 0,
+        });
+        let info: DispatchInfo =
+            DispatchInfoOf::<<Test as frame_system::Config>::RuntimeCall>::default();
+        let extension = SubtensorTransactionExtension::<Test>::new();
+        let validate = |call: &RuntimeCall| {
+            extension.validate(
+                RawOrigin::Signed(hotkey).into(),
+                call,
+                &info,
+                10,
+                (),
+                &TxBaseImplication(()),
+                TransactionSource::External,
+            )
+        };
+
+        // Without a minimum balance the zero balance coldkey passes.
+        assert_ok!(validate(&serve_axon));
+        assert_ok!(validate(&set_weights));
+
+        // With a minimum balance both are rejected.
+        SubtensorModule::set_free_extrinsic_min_balance(TaoCurrency::from(1_000_000));
+        assert_eq!(
+            validate(&serve_axon).unwrap_err(),
+            CustomTransactionError::BalanceTooLow.into()
+        );
+        assert_eq!(
+            validate(&set_weights).unwrap_err(),
+            CustomTransactionError::BalanceTooLow.into()
+        );
+
+        // Funding the coldkey lets them through.
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 2_000_000);
+        assert_ok!(validate(&serve_axon));
+        assert_ok!(validate(&set_weights));
+    });
+}
@@ -20,6 +20,7 @@ use subtensor_swap_interface::{OrderType, SwapHandler};
 
 use super::mock;
 use super::mock::*;
+use crate::subnets::weight_row::WeightRow;
 use crate::*;
 
 /***********************************************************
@@ -2442,8 +2443,16 @@ fn test_mining_emission_distribution_validator_valiminer_miner() {
         ));
 
         // Setup YUMA so that it creates emissions
-        Weights::<Test>::insert(NetUidStorageIndex::from(netuid), 0, vec![(1, 0xFFFF)]);
-        Weights::<Test>::insert(NetUidStorageIndex::from(netuid), 1, vec![(2, 0xFFFF)]);
+        Weights::<Test>::insert(
+            NetUidStorageIndex::from(netuid),
+            0,
+            WeightRow::from(vec![(1, 0xFFFF)]),
+        );
+        Weights::<Test>::insert(
+            NetUidStorageIndex::from(netuid),
+            1,
+            WeightRow::from(vec![(2, 0xFFFF)]),
+        );
         BlockAtRegistration::<Test>::set(netuid, 0, 1);
         BlockAtRegistration::<Test>::set(netuid, 1, 1);
         BlockAtRegistration::<Test>::set(netuid, 2, 1);
@@ -5554,3 +5563,870 @@ fn test_remove_root_updates_counters() {
         );
     });
 }
+
+#[test]
+fn test_unstake_timelock_blocks_remove_stake_until_unlocked() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1);
+        let subnet_owner_hotkey = U256::from(2);
+        let coldkey_account_id = U256::from(4343);
+        let hotkey_account_id = U256::from(4968585);
+        let amount = DefaultMinStake::<Test>::get() * 10.into();
+        let netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        register_ok_neuron(netuid, hotkey_account_id, coldkey_account_id, 192213123);
+
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey_account_id,
+            &coldkey_account_id,
+            netuid,
+            amount.to_u64().into(),
+        );
+        let (amount_tao, fee) = mock::swap_alpha_to_tao(netuid, amount.to_u64().into());
+        SubnetTAO::<Test>::mutate(netuid, |v| *v += amount_tao + fee.into());
+        TotalStake::<Test>::mutate(|v| *v += amount_tao + fee.into());
+
+        assert_ok!(SubtensorModule::set_unstake_timelock(
+            RuntimeOrigin::signed(coldkey_account_id),
+            100
+        ));
+        assert_noop!(
+            SubtensorModule::remove_stake(
+                RuntimeOrigin::signed(coldkey_account_id),
+                hotkey_account_id,
+                netuid,
+                amount.to_u64().into()
+            ),
+            Error::<Test>::UnstakeTimelocked
+        );
+        assert_noop!(
+            SubtensorModule::transfer_stake(
+                RuntimeOrigin::signed(coldkey_account_id),
+                U256::from(5),
+                hotkey_account_id,
+                netuid,
+                netuid,
+                amount.to_u64().into()
+            ),
+            Error::<Test>::UnstakeTimelocked
+        );
+
+        // The unlock only matures once the timelock has elapsed
+        System::set_block_number(10);
+        assert_ok!(SubtensorModule::request_unstake_unlock(
+            RuntimeOrigin::signed(coldkey_account_id)
+        ));
+        System::set_block_number(109);
+        assert_noop!(
+            SubtensorModule::remove_stake(
+                RuntimeOrigin::signed(coldkey_account_id),
+                hotkey_account_id,
+                netuid,
+                amount.to_u64().into()
+            ),
+            Error::<Test>::UnstakeTimelocked
+        );
+
+        System::set_block_number(110);
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(coldkey_account_id),
+            hotkey_account_id,
+            netuid,
+            amount.to_u64().into()
+        ));
+    });
+}
+
+#[test]
+fn test_unstake_timelock_decrease_waits_out_current_value() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(4343);
+
+        assert_noop!(
+            SubtensorModule::request_unstake_unlock(RuntimeOrigin::signed(coldkey)),
+            Error::<Test>::UnstakeTimelockNotSet
+        );
+        assert_noop!(
+            SubtensorModule::set_unstake_timelock(
+                RuntimeOrigin::signed(coldkey),
+                crate::staking::unstake_timelock::MAX_UNSTAKE_TIMELOCK + 1
+            ),
+            Error::<Test>::UnstakeTimelockTooLong
+        );
+
+        System::set_block_number(1);
+        assert_ok!(SubtensorModule::set_unstake_timelock(
+            RuntimeOrigin::signed(coldkey),
+            100
+        ));
+        assert_ok!(SubtensorModule::request_unstake_unlock(
+            RuntimeOrigin::signed(coldkey)
+        ));
+
+        // Lowering the timelock is delayed by the current value
+        assert_ok!(SubtensorModule::set_unstake_timelock(
+            RuntimeOrigin::signed(coldkey),
+            0
+        ));
+        assert_eq!(SubtensorModule::get_unstake_timelock(&coldkey), 100);
+        System::set_block_number(100);
+        assert_eq!(SubtensorModule::get_unstake_timelock(&coldkey), 100);
+        System::set_block_number(101);
+        assert_eq!(SubtensorModule::get_unstake_timelock(&coldkey), 0);
+        assert_ok!(SubtensorModule::ensure_unstake_unlocked(&coldkey));
+
+        // Raising the timelock applies immediately and re-locks the coldkey
+        assert_ok!(SubtensorModule::set_unstake_timelock(
+            RuntimeOrigin::signed(coldkey),
+            50
+        ));
+        assert_eq!(SubtensorModule::get_unstake_timelock(&coldkey), 50);
+        assert!(UnstakeUnlockedAt::<Test>::get(coldkey).is_none());
+        assert_noop!(
+            SubtensorModule::ensure_unstake_unlocked(&coldkey),
+            Error::<Test>::UnstakeTimelocked
+        );
+    });
+}
+
+#[test]
+fn test_unstake_timelock_blocks_liquidity_positions() {
+    new_test_ext(1).execute_with(|| {
+        let owner_hotkey = U256::from(1);
+        let owner_coldkey = U256::from(2);
+        let dest_coldkey = U256::from(4);
+        let amount = 1_000_000_000;
+
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+        SubtensorModule::add_balance_to_coldkey_account(&owner_coldkey, amount * 10);
+        pallet_subtensor_swap::EnabledUserLiquidity::<Test>::insert(NetUid::from(netuid), true);
+        pallet_subtensor_swap::PositionTransfersEnabled::<Test>::insert(NetUid::from(netuid), true);
+        mock::setup_reserves(
+            netuid,
+            TaoCurrency::from(100_000_000_000),
+            AlphaCurrency::from(400_000_000_000),
+        );
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(owner_coldkey),
+            owner_hotkey,
+            netuid,
+            amount.into(),
+        ));
+
+        // Alpha-only range above the current price, so providing it debits stake
+        let current_price =
+            <Test as pallet::Config>::SwapInterface::current_alpha_price(netuid.into())
+                .to_num::<f64>()
+                + 0.0001;
+        let tick_low = price_to_tick(current_price);
+        let tick_high = price_to_tick(current_price + 0.001);
+        let (position_id, _, _) = <Test as pallet::Config>::SwapInterface::do_add_liquidity(
+            NetUid::from(netuid),
+            &owner_coldkey,
+            &owner_hotkey,
+            tick_low,
+            tick_high,
+            amount,
+        )
+        .unwrap();
+
+        assert_ok!(SubtensorModule::set_unstake_timelock(
+            RuntimeOrigin::signed(owner_coldkey),
+            100
+        ));
+
+        // Neither a new position nor a transfer moves the locked stake out
+        assert_noop!(
+            pallet_subtensor_swap::Pallet::<Test>::add_liquidity(
+                RuntimeOrigin::signed(owner_coldkey),
+                owner_hotkey,
+                netuid,
+                tick_low,
+                tick_high,
+                amount,
+            ),
+            Error::<Test>::UnstakeTimelocked
+        );
+        assert_noop!(
+            pallet_subtensor_swap::Pallet::<Test>::transfer_position(
+                RuntimeOrigin::signed(owner_coldkey),
+                netuid,
+                position_id,
+                dest_coldkey,
+            ),
+            Error::<Test>::UnstakeTimelocked
+        );
+
+        System::set_block_number(10);
+        assert_ok!(SubtensorModule::request_unstake_unlock(
+            RuntimeOrigin::signed(owner_coldkey)
+        ));
+        System::set_block_number(110);
+        assert_ok!(pallet_subtensor_swap::Pallet::<Test>::transfer_position(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid,
+            position_id,
+            dest_coldkey,
+        ));
+    });
+}
+
+#[test]
+fn test_add_liquidity_single_sided_stakes_through_the_pool() {
+    new_test_ext(1).execute_with(|| {
+        let owner_hotkey = U256::from(1);
+        let owner_coldkey = U256::from(2);
+        let amount = 10_000_000_000_u64;
+
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+        SubtensorModule::add_balance_to_coldkey_account(&owner_coldkey, amount * 10);
+        pallet_subtensor_swap::EnabledUserLiquidity::<Test>::insert(NetUid::from(netuid), true);
+        mock::setup_reserves(
+            netuid,
+            TaoCurrency::from(100_000_000_000),
+            AlphaCurrency::from(400_000_000_000),
+        );
+
+        // A range around the current price takes both tokens
+        let current_price =
+            <Test as pallet::Config>::SwapInterface::current_alpha_price(netuid.into())
+                .to_num::<f64>();
+        let tick_low = price_to_tick(current_price / 2.0);
+        let tick_high = price_to_tick(current_price * 2.0);
+        let total_stake_before = TotalStake::<Test>::get();
+        let volume_before = SubnetVolume::<Test>::get(netuid);
+
+        assert_ok!(
+            pallet_subtensor_swap::Pallet::<Test>::add_liquidity_single_sided(
+                RuntimeOrigin::signed(owner_coldkey),
+                owner_hotkey,
+                netuid,
+                tick_low,
+                tick_high,
+                amount,
+                true,
+                0,
+            )
+        );
+
+        // The swapped TAO is accounted for like a stake
+        assert!(TotalStake::<Test>::get() > total_stake_before);
+        assert!(SubnetVolume::<Test>::get(netuid) > volume_before);
+        assert!(StakingHotkeys::<Test>::get(owner_coldkey).contains(&owner_hotkey));
+    });
+}
+
+#[test]
+fn test_price_floor_defense_stakes_through_the_pool() {
+    new_test_ext(1).execute_with(|| {
+        let owner_hotkey = U256::from(1);
+        let owner_coldkey = U256::from(2);
+        let amount = 20_000_000_000_u64;
+
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+        SubtensorModule::add_balance_to_coldkey_account(&owner_coldkey, amount * 10);
+        mock::setup_reserves(
+            netuid,
+            TaoCurrency::from(100_000_000_000),
+            AlphaCurrency::from(100_000_000_000),
+        );
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(owner_coldkey),
+            owner_hotkey,
+            netuid,
+            amount.into(),
+        ));
+        assert_ok!(pallet_subtensor_swap::Pallet::<Test>::set_price_floor(
+            RuntimeOrigin::signed(owner_coldkey),
+            owner_hotkey,
+            netuid,
+            1_200_000_000,
+            TaoCurrency::from(amount / 4),
+        ));
+
+        // Selling below the floor buys back through the pool
+        remove_stake_rate_limit_for_tests(&owner_hotkey, &owner_coldkey, netuid);
+        let alpha = SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+            &owner_hotkey,
+            &owner_coldkey,
+            netuid,
+        );
+        let total_stake_before = TotalStake::<Test>::get();
+        let balance_before = SubtensorModule::get_coldkey_balance(&owner_coldkey);
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(owner_coldkey),
+            owner_hotkey,
+            netuid,
+            alpha,
+        ));
+        let tao_out = SubtensorModule::get_coldkey_balance(&owner_coldkey) - balance_before;
+
+        // The buy is accounted for like a stake
+        let floor = pallet_subtensor_swap::PriceFloors::<Test>::get(netuid).unwrap();
+        assert!(!floor.tao_spent.is_zero());
+        assert_eq!(
+            TotalStake::<Test>::get(),
+            total_stake_before - tao_out.into() + floor.tao_spent
+        );
+    });
+}
+
+#[test]
+fn test_remove_stake_sliced_executes_over_blocks() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1);
+        let subnet_owner_hotkey = U256::from(2);
+        let coldkey_account_id = U256::from(4343);
+        let hotkey_account_id = U256::from(4968585);
+        let netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        register_ok_neuron(netuid, hotkey_account_id, coldkey_account_id, 192213123);
+
+        let reserve = 1_000_000_000_000_u64;
+        mock::setup_reserves(netuid, reserve.into(), reserve.into());
+        let amount = AlphaCurrency::from(100_000_000_000);
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey_account_id,
+            &coldkey_account_id,
+            netuid,
+            amount,
+        );
+
+        // About 1% of the alpha reserve by default
+        let threshold = SubtensorModule::get_sliced_unstake_threshold(netuid);
+        assert_abs_diff_eq!(threshold.to_u64(), 9_994_659_342, epsilon = 1_000);
+        assert_noop!(
+            SubtensorModule::remove_stake_sliced(
+                RuntimeOrigin::signed(coldkey_account_id),
+                hotkey_account_id,
+                netuid,
+                threshold,
+                TaoCurrency::ZERO,
+                5
+            ),
+            Error::<Test>::UnstakeBelowSlicingThreshold
+        );
+        assert_noop!(
+            SubtensorModule::remove_stake_sliced(
+                RuntimeOrigin::signed(coldkey_account_id),
+                hotkey_account_id,
+                netuid,
+                amount,
+                TaoCurrency::ZERO,
+                0
+            ),
+            Error::<Test>::InvalidSlicedUnstakeDuration
+        );
+
+        assert_ok!(SubtensorModule::remove_stake_sliced(
+            RuntimeOrigin::signed(coldkey_account_id),
+            hotkey_account_id,
+            netuid,
+            amount,
+            TaoCurrency::ZERO,
+            5
+        ));
+        let order = SlicedUnstakes::<Test>::get(0).unwrap();
+        assert_eq!(order.slice, AlphaCurrency::from(20_000_000_000));
+        assert_eq!(order.expires_at, System::block_number() + 5);
+
+        // One slice is sold per block, and charged to it
+        let balance_before = SubtensorModule::get_coldkey_balance(&coldkey_account_id);
+        let weight = SubtensorModule::execute_sliced_unstakes();
+        assert!(weight.ref_time() >= 196_800_000);
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey_account_id,
+                &coldkey_account_id,
+                netuid
+            ),
+            AlphaCurrency::from(80_000_000_000)
+        );
+        assert!(SubtensorModule::get_coldkey_balance(&coldkey_account_id) > balance_before);
+
+        for block in 1..5 {
+            System::set_block_number(System::block_number() + 1);
+            SubtensorModule::execute_sliced_unstakes();
+            assert_eq!(SlicedUnstakes::<Test>::contains_key(0), block < 4);
+        }
+        assert!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey_account_id,
+                &coldkey_account_id,
+                netuid
+            )
+            .is_zero()
+        );
+    });
+}
+
+#[test]
+fn test_cancel_sliced_unstake() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1);
+        let subnet_owner_hotkey = U256::from(2);
+        let coldkey_account_id = U256::from(4343);
+        let hotkey_account_id = U256::from(4968585);
+        let netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        register_ok_neuron(netuid, hotkey_account_id, coldkey_account_id, 192213123);
+
+        let reserve = 1_000_000_000_000_u64;
+        mock::setup_reserves(netuid, reserve.into(), reserve.into());
+        let amount = AlphaCurrency::from(100_000_000_000);
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey_account_id,
+            &coldkey_account_id,
+            netuid,
+            amount,
+        );
+
+        assert_ok!(SubtensorModule::remove_stake_sliced(
+            RuntimeOrigin::signed(coldkey_account_id),
+            hotkey_account_id,
+            netuid,
+            amount,
+            TaoCurrency::ZERO,
+            100
+        ));
+        assert_noop!(
+            SubtensorModule::cancel_sliced_unstake(RuntimeOrigin::signed(U256::from(5)), 0),
+            Error::<Test>::SlicedUnstakeNotFound
+        );
+        assert_ok!(SubtensorModule::cancel_sliced_unstake(
+            RuntimeOrigin::signed(coldkey_account_id),
+            0
+        ));
+        assert!(!SlicedUnstakes::<Test>::contains_key(0));
+
+        // Nothing has been unstaked
+        SubtensorModule::execute_sliced_unstakes();
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey_account_id,
+                &coldkey_account_id,
+                netuid
+            ),
+            amount
+        );
+    });
+}
+
+#[test]
+fn test_add_stake_with_allowance() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1);
+        let subnet_owner_hotkey = U256::from(2);
+        let coldkey_account_id = U256::from(4343);
+        let hotkey_account_id = U256::from(4968585);
+        let manager = U256::from(777);
+        let netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        register_ok_neuron(netuid, hotkey_account_id, coldkey_account_id, 192213123);
+
+        let reserve = 1_000_000_000_000_u64;
+        mock::setup_reserves(netuid, reserve.into(), reserve.into());
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey_account_id, 10_000_000_000);
+
+        let allowance = TaoCurrency::from(3_000_000_000);
+        let amount = TaoCurrency::from(2_000_000_000);
+        let limit_price = TaoCurrency::from(2_000_000_000);
+
+        // No allowance yet
+        assert_noop!(
+            SubtensorModule::add_stake_with_allowance(
+                RuntimeOrigin::signed(manager),
+                coldkey_account_id,
+                hotkey_account_id,
+                netuid,
+                amount,
+                limit_price
+            ),
+            Error::<Test>::InsufficientStakeAllowance
+        );
+
+        assert_ok!(SubtensorModule::approve_stake_allowance(
+            RuntimeOrigin::signed(coldkey_account_id),
+            manager,
+            netuid,
+            allowance
+        ));
+        assert_ok!(SubtensorModule::add_stake_with_allowance(
+            RuntimeOrigin::signed(manager),
+            coldkey_account_id,
+            hotkey_account_id,
+            netuid,
+            amount,
+            limit_price
+        ));
+
+        // The stake belongs to the coldkey and the allowance is consumed
+        assert!(
+            !SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey_account_id,
+                &coldkey_account_id,
+                netuid
+            )
+            .is_zero()
+        );
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey_account_id),
+            10_000_000_000 - u64::from(amount)
+        );
+        assert_eq!(
+            StakeAllowance::<Test>::get((netuid, coldkey_account_id, manager)),
+            allowance - amount
+        );
+
+        // The remaining allowance cannot be exceeded
+        assert_noop!(
+            SubtensorModule::add_stake_with_allowance(
+                RuntimeOrigin::signed(manager),
+                coldkey_account_id,
+                hotkey_account_id,
+                netuid,
+                amount,
+                limit_price
+            ),
+            Error::<Test>::InsufficientStakeAllowance
+        );
+
+        assert_ok!(SubtensorModule::revoke_stake_allowance(
+            RuntimeOrigin::signed(coldkey_account_id),
+            manager,
+            netuid
+        ));
+        assert!(!StakeAllowance::<Test>::contains_key((
+            netuid,
+            coldkey_account_id,
+            manager
+        )));
+        assert_noop!(
+            SubtensorModule::revoke_stake_allowance(
+                RuntimeOrigin::signed(coldkey_account_id),
+                manager,
+                netuid
+            ),
+            Error::<Test>::StakeAllowanceNotFound
+        );
+    });
+}
+
+#[test]
+fn test_max_scheduled_operations() {
+    new_test_ext(1).execute_with(|| {
+        let subnet_owner_coldkey = U256::from(1);
+        let subnet_owner_hotkey = U256::from(2);
+        let coldkey_account_id = U256::from(4343);
+        let hotkey_account_id = U256::from(4968585);
+        let netuid = add_dynamic_network(&subnet_owner_hotkey, &subnet_owner_coldkey);
+        register_ok_neuron(netuid, hotkey_account_id, coldkey_account_id, 192213123);
+
+        let reserve = 1_000_000_000_000_u64;
+        mock::setup_reserves(netuid, reserve.into(), reserve.into());
+        let amount = AlphaCurrency::from(100_000_000_000);
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey_account_id,
+            &coldkey_account_id,
+            netuid,
+            amount,
+        );
+        MaxScheduledOperations::<Test>::put(2);
+
+        for _ in 0..2 {
+            assert_ok!(SubtensorModule::remove_stake_sliced(
+                RuntimeOrigin::signed(coldkey_account_id),
+                hotkey_account_id,
+                netuid,
+                amount,
+                TaoCurrency::ZERO,
+                100
+            ));
+        }
+        assert_noop!(
+            SubtensorModule::remove_stake_sliced(
+                RuntimeOrigin::signed(coldkey_account_id),
+                hotkey_account_id,
+                netuid,
+                amount,
+                TaoCurrency::ZERO,
+                100
+            ),
+            Error::<Test>::TooManyScheduledOperations
+        );
+
+        // Raising the timelock applies immediately, lowering it is scheduled
+        assert_ok!(SubtensorModule::set_unstake_timelock(
+            RuntimeOrigin::signed(coldkey_account_id),
+            100
+        ));
+        assert_noop!(
+            SubtensorModule::set_unstake_timelock(RuntimeOrigin::signed(coldkey_account_id), 10),
+            Error::<Test>::TooManyScheduledOperations
+        );
+
+        assert_ok!(SubtensorModule::cancel_sliced_unstake(
+            RuntimeOrigin::signed(coldkey_account_id),
+            0
+        ));
+        assert_ok!(SubtensorModule::set_unstake_timelock(
+            RuntimeOrigin::signed(coldkey_account_id),
+            10
+        ));
+        // Replacing the pending decrease does not count again
+        assert_ok!(SubtensorModule::set_unstake_timelock(
+            RuntimeOrigin::signed(coldkey_account_id),
+            5
+        ));
+
+        let operations = SubtensorModule::get_scheduled_operations(coldkey_account_id);
+        assert_eq!(operations.len(), 2);
+        assert!(operations.contains(
+            &crate::utils::scheduled_operations::ScheduledOperation::UnstakeTimelockDecrease {
+                blocks: 5,
+                applies_at: SubtensorModule::get_current_block_as_u64() + 100,
+            }
+        ));
+        assert!(SubtensorModule::get_scheduled_operations(U256::from(5)).is_empty());
+    });
+}
+
+#[test]
+fn test_remove_stake_subnet_tao_insolvent() {
+    new_test_ext(0).execute_with(|| {
+        let hotkey_account_id = U256::from(561337);
+        let coldkey_account_id = U256::from(61337);
+        add_network(NetUid::ROOT, 10, 0);
+        assert_ok!(SubtensorModule::root_register(
+            RuntimeOrigin::signed(coldkey_account_id).clone(),
+            hotkey_account_id,
+        ));
+        let stake_amount = 1_000_000_000;
+
+        // The reserve only backs half of the stake
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &hotkey_account_id,
+            &coldkey_account_id,
+            NetUid::ROOT,
+            stake_amount.into(),
+        );
+        TotalStake::<Test>::set(TaoCurrency::from(stake_amount));
+        SubnetTAO::<Test>::insert(NetUid::ROOT, TaoCurrency::from(stake_amount / 2));
+        SubnetAlphaOut::<Test>::insert(NetUid::ROOT, AlphaCurrency::from(stake_amount));
+
+        assert_noop!(
+            SubtensorModule::remove_stake(
+                RuntimeOrigin::signed(coldkey_account_id),
+                hotkey_account_id,
+                NetUid::ROOT,
+                stake_amount.into()
+            ),
+            Error::<Test>::SubnetTaoInsolvent
+        );
+
+        // Unstaking the part the reserve covers would leave the rest of the alpha unbacked
+        assert_noop!(
+            SubtensorModule::remove_stake(
+                RuntimeOrigin::signed(coldkey_account_id),
+                hotkey_account_id,
+                NetUid::ROOT,
+                (stake_amount / 2).into()
+            ),
+            Error::<Test>::SubnetTaoInsolvent
+        );
+
+        // Once the reserve backs all of the stake it can be unstaked
+        SubnetTAO::<Test>::insert(NetUid::ROOT, TaoCurrency::from(stake_amount));
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(coldkey_account_id),
+            hotkey_account_id,
+            NetUid::ROOT,
+            stake_amount.into()
+        ));
+        assert_eq!(SubnetTAO::<Test>::get(NetUid::ROOT), TaoCurrency::ZERO);
+    });
+}
+
+#[test]
+fn test_alpha_sale_bounded_by_alpha_value() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = add_dynamic_network(&U256::from(1), &U256::from(2));
+        SubnetTAO::<Test>::insert(netuid, TaoCurrency::from(1_000_000_000_000));
+        let price = U96F32::from_num(0.5);
+        let sale = |tao: u64| subtensor_swap_interface::SwapResult {
+            amount_paid_in: 1_000,
+            amount_paid_out: tao,
+            fee_paid: 0,
+            tao_reserve_delta: -(tao as i64),
+            alpha_reserve_delta: 1_000,
+        };
+
+        assert_ok!(SubtensorModule::ensure_alpha_sale_solvent(
+            netuid,
+            price,
+            &sale(500)
+        ));
+        assert_eq!(
+            SubtensorModule::ensure_alpha_sale_solvent(netuid, price, &sale(501)),
+            Err(Error::<Test>::SubnetTaoInsolvent)
+        );
+    });
+}
+
+#[test]
+fn test_subnet_tao_backs_outstanding_alpha_at_current_tick() {
+    new_test_ext(1).execute_with(|| {
+        let owner_hotkey = U256::from(1);
+        let owner_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let coldkey = U256::from(4);
+        let netuid = add_dynamic_network(&owner_hotkey, &owner_coldkey);
+        let reserve = 1_000_000_000_000_u64;
+        mock::setup_reserves(netuid, reserve.into(), reserve.into());
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+
+        // Buy real alpha, which also moves the pool off its initial tick
+        let amount = 10_000_000_000_u64;
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, amount + 1_000_000);
+        assert_ok!(SubtensorModule::add_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            amount.into()
+        ));
+        remove_stake_rate_limit_for_tests(&hotkey, &coldkey, netuid);
+
+        // The outstanding alpha is worth what the pool pays for it from the current tick
+        let alpha_out = SubnetAlphaOut::<Test>::get(netuid);
+        let alpha_value = SubtensorModule::get_outstanding_alpha_value(netuid).unwrap();
+        let sale = <Test as pallet::Config>::SwapInterface::sim_swap(
+            netuid.into(),
+            OrderType::Sell,
+            alpha_out.into(),
+        )
+        .unwrap();
+        assert_eq!(alpha_value, sale.amount_paid_out.into());
+        assert!(!alpha_value.is_zero());
+
+        // Only the reserve above that value can be debited
+        let headroom = SubtensorModule::get_subnet_tao_reserve(netuid).saturating_sub(alpha_value);
+        assert_ok!(SubtensorModule::ensure_subnet_tao_solvent(netuid, headroom));
+        assert_eq!(
+            SubtensorModule::ensure_subnet_tao_solvent(netuid, headroom + 1.into()),
+            Err(Error::<Test>::SubnetTaoInsolvent)
+        );
+
+        // A reserve below the value of the outstanding alpha rejects unstakes and is reported on
+        // the epoch
+        let alpha =
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(&hotkey, &coldkey, netuid);
+        let low_reserve = TaoCurrency::from(alpha_value.to_u64() / 2);
+        SubnetTAO::<Test>::insert(netuid, low_reserve);
+        assert_noop!(
+            SubtensorModule::remove_stake(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                netuid,
+                alpha / 10.into()
+            ),
+            Error::<Test>::SubnetTaoInsolvent
+        );
+        SubtensorModule::check_subnet_tao_solvency(netuid);
+        System::assert_last_event(
+            Event::SubnetTaoInsolvencyDetected {
+                netuid,
+                reserve: low_reserve,
+                alpha_value,
+            }
+            .into(),
+        );
+
+        // A hook selling into the short reserve reports the rolled back debit
+        let err = SubtensorModule::swap_alpha_for_tao(
+            netuid,
+            alpha / 10.into(),
+            <Test as Config>::SwapInterface::min_price().into(),
+            true,
+        )
+        .unwrap_err();
+        assert_eq!(err, Error::<Test>::SubnetTaoInsolvent.into());
+        SubtensorModule::report_rejected_tao_debit(netuid, &err);
+        System::assert_last_event(Event::SubnetTaoDebitRejected { netuid }.into());
+        SubtensorModule::report_rejected_tao_debit(netuid, &Error::<Test>::NotEnoughStake.into());
+        assert_eq!(
+            System::events()
+                .iter()
+                .filter(|record| record.event
+                    == RuntimeEvent::SubtensorModule(Event::SubnetTaoDebitRejected { netuid }))
+                .count(),
+            1
+        );
+
+        // Restored, the reserve pays the unstake out again
+        SubnetTAO::<Test>::insert(netuid, TaoCurrency::from(reserve));
+        assert_ok!(SubtensorModule::remove_stake(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            alpha / 10.into()
+        ));
+    });
+}
+
+#[test]
+fn test_get_stake_valuation() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = add_dynamic_network(&U256::from(1), &U256::from(2));
+        let reserve = 1_000_000_000_000_u64;
+        mock::setup_reserves(netuid, reserve.into(), reserve.into());
+        let alpha = AlphaCurrency::from(reserve / 10);
+
+        // The position is worth its alpha at the current price, less the fee and price impact
+        // of unstaking it at once
+        let (tao_value, exit_fee, exit_slippage) =
+            SubtensorModule::get_stake_valuation(netuid, alpha);
+        assert_abs_diff_eq!(tao_value.to_u64(), reserve / 10, epsilon = reserve / 10_000);
+        assert!(!exit_fee.is_zero());
+        assert!(!exit_slippage.is_zero());
+        let (received, _) = mock::swap_alpha_to_tao(netuid, alpha);
+        assert_abs_diff_eq!(
+            tao_value
+                .saturating_sub(exit_fee)
+                .saturating_sub(exit_slippage)
+                .to_u64(),
+            received.to_u64(),
+            epsilon = 10
+        );
+
+        // Root stake is valued one to one
+        assert_eq!(
+            SubtensorModule::get_stake_valuation(NetUid::ROOT, alpha),
+            (
+                TaoCurrency::from(reserve / 10),
+                TaoCurrency::ZERO,
+                TaoCurrency::ZERO
+            )
+        );
+    });
+}
+
+#[test]
+fn test_trailing_subnet_volume() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+
+        System::set_block_number(1);
+        SubtensorModule::increase_subnet_volume(netuid, 100);
+        assert_eq!(SubtensorModule::get_trailing_subnet_volume(netuid), 100);
+
+        // The previous window is counted for the blocks still within the trailing window
+        System::set_block_number(7_201);
+        assert_eq!(SubtensorModule::get_trailing_subnet_volume(netuid), 99);
+        SubtensorModule::increase_subnet_volume(netuid, 50);
+        assert_eq!(SubtensorModule::get_trailing_subnet_volume(netuid), 149);
+
+        System::set_block_number(10_800);
+        assert_eq!(SubtensorModule::get_trailing_subnet_volume(netuid), 100);
+
+        // Volume older than two windows is dropped, the cumulative volume is kept
+        System::set_block_number(21_601);
+        assert_eq!(SubtensorModule::get_trailing_subnet_volume(netuid), 0);
+        assert_eq!(SubnetVolume::<Test>::get(netuid), 150);
+    });
+}
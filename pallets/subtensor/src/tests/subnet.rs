@@ -1,11 +1,15 @@
 #![allow(clippy::unwrap_used)]
 use super::mock::*;
+use crate::subnets::clock::SubnetClock;
+use crate::subnets::hyperparameter_vote::HyperparameterChange;
+use crate::subnets::staged_hyperparams::StagedHyperparameter;
 use crate::subnets::symbols::{DEFAULT_SYMBOL, SYMBOLS};
+use crate::utils::rate_limiting::Hyperparameter;
 use crate::*;
 use frame_support::{assert_err, assert_noop, assert_ok};
 use frame_system::Config;
 use sp_core::U256;
-use subtensor_runtime_common::{AlphaCurrency, TaoCurrency};
+use subtensor_runtime_common::{AlphaCurrency, MechId, NetUidStorageIndex, TaoCurrency};
 
 use super::mock;
 
@@ -891,3 +895,992 @@ fn test_update_symbol_fails_if_symbol_already_in_use() {
         );
     });
 }
+
+/***************************
+  pub fn estimate_validator_yield() tests
+*****************************/
+
+#[test]
+fn test_estimate_validator_yield_scales_with_stake() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1u16);
+        let ck0 = U256::from(1);
+        let hk0 = U256::from(2);
+        let ck1 = U256::from(3);
+        let hk1 = U256::from(4);
+
+        super::mechanism::mock_epoch_state(netuid, ck0, hk0, ck1, hk1);
+        Uids::<Test>::insert(netuid, hk0, 0u16);
+        Uids::<Test>::insert(netuid, hk1, 1u16);
+        SubnetAlphaOutEmission::<Test>::insert(netuid, AlphaCurrency::from(1_000_000_000));
+
+        let stake_before = TotalHotkeyAlpha::<Test>::get(hk0, netuid);
+        let bonds_before = Bonds::<Test>::get(NetUidStorageIndex::from(netuid), 0u16);
+
+        let low = SubtensorModule::estimate_validator_yield(
+            netuid,
+            hk0,
+            AlphaCurrency::from(1_000_000_000),
+        )
+        .unwrap();
+        let high = SubtensorModule::estimate_validator_yield(
+            netuid,
+            hk0,
+            AlphaCurrency::from(9_000_000_000),
+        )
+        .unwrap();
+
+        assert_eq!(low.hotkey, hk0);
+        assert_eq!(low.blocks_per_epoch, 2u64.into());
+        assert!(low.epoch_emission.0 > AlphaCurrency::ZERO);
+        assert!(high.dividends.0 > low.dividends.0);
+        assert!(high.dividends.0 <= high.epoch_emission.0);
+
+        // The simulation must not leave any trace in state.
+        assert_eq!(TotalHotkeyAlpha::<Test>::get(hk0, netuid), stake_before);
+        assert_eq!(
+            Bonds::<Test>::get(NetUidStorageIndex::from(netuid), 0u16),
+            bonds_before
+        );
+    });
+}
+
+#[test]
+fn test_estimate_validator_yield_unknown_hotkey_or_subnet() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1u16);
+        let ck0 = U256::from(1);
+        let hk0 = U256::from(2);
+        let ck1 = U256::from(3);
+        let hk1 = U256::from(4);
+
+        super::mechanism::mock_epoch_state(netuid, ck0, hk0, ck1, hk1);
+
+        assert!(
+            SubtensorModule::estimate_validator_yield(netuid, U256::from(99), 1.into()).is_none()
+        );
+        assert!(
+            SubtensorModule::estimate_validator_yield(NetUid::from(7u16), hk0, 1.into()).is_none()
+        );
+        assert!(SubtensorModule::estimate_validator_yield(NetUid::ROOT, hk0, 1.into()).is_none());
+    });
+}
+
+#[test]
+fn test_simulate_epoch_leaves_state_untouched() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1u16);
+        let ck0 = U256::from(1);
+        let hk0 = U256::from(2);
+        let ck1 = U256::from(3);
+        let hk1 = U256::from(4);
+
+        super::mechanism::mock_epoch_state(netuid, ck0, hk0, ck1, hk1);
+        Uids::<Test>::insert(netuid, hk0, 0u16);
+        Uids::<Test>::insert(netuid, hk1, 1u16);
+        SubnetAlphaOutEmission::<Test>::insert(netuid, AlphaCurrency::from(1_000_000_000));
+
+        let bonds_before = Bonds::<Test>::get(NetUidStorageIndex::from(netuid), 0u16);
+        let dividends_before = Dividends::<Test>::get(netuid);
+
+        let simulation = SubtensorModule::simulate_epoch(netuid).unwrap();
+        assert_eq!(
+            simulation.epoch_emission.0,
+            SubtensorModule::get_projected_epoch_emission(netuid)
+        );
+        assert_eq!(simulation.miner_emission.len(), 2);
+        assert_eq!(simulation.validator_emission.len(), 2);
+        let distributed = simulation
+            .miner_emission
+            .iter()
+            .chain(simulation.validator_emission.iter())
+            .fold(AlphaCurrency::ZERO, |acc, emission| {
+                acc.saturating_add(emission.0)
+            });
+        assert!(distributed > AlphaCurrency::ZERO);
+        assert!(distributed <= simulation.epoch_emission.0);
+
+        // The simulation must not leave any trace in state.
+        assert_eq!(
+            Bonds::<Test>::get(NetUidStorageIndex::from(netuid), 0u16),
+            bonds_before
+        );
+        assert_eq!(Dividends::<Test>::get(netuid), dividends_before);
+
+        assert!(SubtensorModule::simulate_epoch(NetUid::from(7u16)).is_none());
+        assert!(SubtensorModule::simulate_epoch(NetUid::ROOT).is_none());
+    });
+}
+
+/***************************
+  staged hyperparameter bundle tests
+*****************************/
+
+#[test]
+fn test_staged_hyperparameters_shadow_epoch_then_commit() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1u16);
+        let ck0 = U256::from(1);
+        let hk0 = U256::from(2);
+        let ck1 = U256::from(3);
+        let hk1 = U256::from(4);
+
+        super::mechanism::mock_epoch_state(netuid, ck0, hk0, ck1, hk1);
+        Uids::<Test>::insert(netuid, hk0, 0u16);
+        Uids::<Test>::insert(netuid, hk1, 1u16);
+        SubnetOwner::<Test>::insert(netuid, ck0);
+        AdminFreezeWindow::<Test>::set(0);
+
+        let kappa_before = SubtensorModule::get_kappa(netuid);
+        let new_kappa: u16 = 100;
+        assert_ne!(kappa_before, new_kappa);
+        assert_ok!(SubtensorModule::stage_hyperparameters(
+            <Test as Config>::RuntimeOrigin::signed(ck0),
+            netuid,
+            vec![
+                StagedHyperparameter::Kappa(new_kappa),
+                StagedHyperparameter::Yuma3Enabled(true),
+            ],
+        ));
+
+        // Cannot commit before the bundle has been evaluated.
+        assert_noop!(
+            SubtensorModule::commit_staged_hyperparameters(
+                <Test as Config>::RuntimeOrigin::signed(ck0),
+                netuid,
+            ),
+            Error::<Test>::StagedHyperparametersNotEvaluated
+        );
+
+        let bonds_before = Bonds::<Test>::get(NetUidStorageIndex::from(netuid), 0u16);
+        SubtensorModule::maybe_run_shadow_epoch(netuid, AlphaCurrency::from(1_000_000_000));
+
+        // The shadow epoch records a preview without touching live state.
+        let bundle = SubtensorModule::get_staged_hyperparameters(netuid).unwrap();
+        let preview = bundle.preview.unwrap();
+        assert_eq!(preview.validator_permit.len(), 2);
+        assert_eq!(SubtensorModule::get_kappa(netuid), kappa_before);
+        assert!(!SubtensorModule::get_yuma3_enabled(netuid));
+        assert_eq!(
+            Bonds::<Test>::get(NetUidStorageIndex::from(netuid), 0u16),
+            bonds_before
+        );
+
+        assert_ok!(SubtensorModule::commit_staged_hyperparameters(
+            <Test as Config>::RuntimeOrigin::signed(ck0),
+            netuid,
+        ));
+        assert_eq!(SubtensorModule::get_kappa(netuid), new_kappa);
+        assert!(SubtensorModule::get_yuma3_enabled(netuid));
+        assert!(SubtensorModule::get_staged_hyperparameters(netuid).is_none());
+    });
+}
+
+#[test]
+fn test_staged_hyperparameters_rollback() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1u16);
+        let owner = U256::from(1);
+        let other = U256::from(2);
+        add_network(netuid, 10, 0);
+        SubnetOwner::<Test>::insert(netuid, owner);
+
+        let rho_before = SubtensorModule::get_rho(netuid);
+        assert_ne!(rho_before, 42);
+        assert_ok!(SubtensorModule::stage_hyperparameters(
+            <Test as Config>::RuntimeOrigin::signed(owner),
+            netuid,
+            vec![StagedHyperparameter::Rho(42)],
+        ));
+
+        assert_noop!(
+            SubtensorModule::rollback_staged_hyperparameters(
+                <Test as Config>::RuntimeOrigin::signed(other),
+                netuid,
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(SubtensorModule::rollback_staged_hyperparameters(
+            <Test as Config>::RuntimeOrigin::signed(owner),
+            netuid,
+        ));
+        assert!(SubtensorModule::get_staged_hyperparameters(netuid).is_none());
+        assert_eq!(SubtensorModule::get_rho(netuid), rho_before);
+
+        assert_noop!(
+            SubtensorModule::rollback_staged_hyperparameters(
+                <Test as Config>::RuntimeOrigin::signed(owner),
+                netuid,
+            ),
+            Error::<Test>::NoStagedHyperparameters
+        );
+    });
+}
+
+#[test]
+fn test_stage_hyperparameters_validates_changes() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1u16);
+        let owner = U256::from(1);
+        add_network(netuid, 10, 0);
+        SubnetOwner::<Test>::insert(netuid, owner);
+
+        assert_noop!(
+            SubtensorModule::stage_hyperparameters(
+                <Test as Config>::RuntimeOrigin::signed(owner),
+                netuid,
+                vec![],
+            ),
+            Error::<Test>::InvalidValue
+        );
+        assert_noop!(
+            SubtensorModule::stage_hyperparameters(
+                <Test as Config>::RuntimeOrigin::signed(owner),
+                netuid,
+                vec![StagedHyperparameter::AlphaValues(u16::MAX, 1)],
+            ),
+            Error::<Test>::AlphaHighTooLow
+        );
+
+        // The owner stages no value its setter rejects, root does
+        assert_noop!(
+            SubtensorModule::stage_hyperparameters(
+                <Test as Config>::RuntimeOrigin::signed(owner),
+                netuid,
+                vec![StagedHyperparameter::BondsMovingAverage(975_001)],
+            ),
+            Error::<Test>::BondsMovingAverageMaxReached
+        );
+        assert_noop!(
+            SubtensorModule::stage_hyperparameters(
+                <Test as Config>::RuntimeOrigin::signed(owner),
+                netuid,
+                vec![StagedHyperparameter::AlphaSigmoidSteepness(-1)],
+            ),
+            Error::<Test>::NegativeSigmoidSteepness
+        );
+        assert_ok!(SubtensorModule::stage_hyperparameters(
+            <Test as Config>::RuntimeOrigin::root(),
+            netuid,
+            vec![
+                StagedHyperparameter::BondsMovingAverage(975_001),
+                StagedHyperparameter::AlphaSigmoidSteepness(-1),
+            ],
+        ));
+    });
+}
+
+#[test]
+fn test_tempo_seconds_follow_slot_duration() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1u16);
+        add_network(netuid, 10, 0);
+        // 12 second slots in the mock
+        assert_eq!(SubtensorModule::tempo_seconds_to_blocks(3_600), 300);
+        assert_eq!(SubtensorModule::tempo_seconds_to_blocks(17), 1);
+        assert_eq!(SubtensorModule::tempo_seconds_to_blocks(1), 1);
+        assert_eq!(SubtensorModule::tempo_seconds_to_blocks(u64::MAX), u16::MAX);
+
+        assert_noop!(
+            SubtensorModule::set_tempo_seconds(netuid, Some(0)),
+            Error::<Test>::InvalidTempoSeconds
+        );
+        assert_ok!(SubtensorModule::set_tempo_seconds(netuid, Some(3_600)));
+        assert_eq!(SubtensorModule::get_tempo(netuid), 300);
+        assert_eq!(
+            SubtensorModule::get_subnet_clock(netuid),
+            Some(SubnetClock {
+                tempo_blocks: 300,
+                tempo_seconds: 3_600,
+                target_tempo_seconds: Some(3_600),
+                slot_duration: 12_000,
+            })
+        );
+
+        // The tempo was converted at 250 ms slots before the slot duration changed
+        TempoSlotDuration::<Test>::put(250);
+        SubtensorModule::set_tempo(netuid, 14_400);
+        step_block(1);
+        assert_eq!(SubtensorModule::get_tempo(netuid), 300);
+        assert_eq!(TempoSlotDuration::<Test>::get(), 12_000);
+
+        // Subnets with a tempo in blocks keep it
+        assert_ok!(SubtensorModule::set_tempo_seconds(netuid, None));
+        SubtensorModule::set_tempo(netuid, 100);
+        TempoSlotDuration::<Test>::put(250);
+        step_block(1);
+        assert_eq!(SubtensorModule::get_tempo(netuid), 100);
+        assert_eq!(
+            SubtensorModule::get_subnet_clock(netuid)
+                .unwrap()
+                .target_tempo_seconds,
+            None
+        );
+        assert_eq!(SubtensorModule::get_subnet_clock(NetUid::from(2u16)), None);
+    });
+}
+
+#[test]
+fn test_subnet_attestations() {
+    use crate::subnets::attestations::{
+        MAX_SUBNET_ATTESTATION_AGE, SubnetAttestationKind, SubnetAttestationPayload,
+    };
+    use codec::Encode;
+    use sp_core::{H256, Pair, sr25519};
+    use sp_runtime::traits::ValidateUnsigned;
+    use sp_runtime::transaction_validity::{InvalidTransaction, TransactionSource};
+
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        add_network(netuid, 10, 0);
+        step_block(10);
+
+        let oracle = sr25519::Pair::from_seed(&[1; 32]);
+        let payload = SubnetAttestationPayload {
+            netuid,
+            oracle: oracle.public(),
+            kind: SubnetAttestationKind::ExternalAudit {
+                report: H256::repeat_byte(7),
+            },
+            block_number: SubtensorModule::get_current_block_as_u64(),
+        };
+        let signature = oracle.sign(&payload.encode());
+        let call = crate::Call::<Test>::submit_subnet_attestation {
+            payload: payload.clone(),
+            signature,
+        };
+
+        assert_eq!(
+            SubtensorModule::validate_unsigned(TransactionSource::External, &call),
+            InvalidTransaction::BadSigner.into()
+        );
+        assert_ok!(SubtensorModule::set_subnet_attestation_oracle(
+            RuntimeOrigin::root(),
+            oracle.public(),
+            true
+        ));
+        assert!(SubtensorModule::validate_unsigned(TransactionSource::External, &call).is_ok());
+
+        // Signatures over another payload are rejected
+        let forged = SubnetAttestationPayload {
+            kind: SubnetAttestationKind::ExternalAudit {
+                report: H256::repeat_byte(8),
+            },
+            ..payload.clone()
+        };
+        assert_noop!(
+            SubtensorModule::submit_subnet_attestation(RuntimeOrigin::none(), forged, signature),
+            Error::<Test>::InvalidSubnetAttestationSignature
+        );
+        assert_noop!(
+            SubtensorModule::submit_subnet_attestation(
+                RuntimeOrigin::signed(U256::from(1)),
+                payload.clone(),
+                signature
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(SubtensorModule::submit_subnet_attestation(
+            RuntimeOrigin::none(),
+            payload.clone(),
+            signature
+        ));
+        let attestations = SubtensorModule::get_subnet_attestations(netuid);
+        assert_eq!(attestations.len(), 1);
+        assert_eq!(attestations[0].kind, payload.kind);
+        assert_noop!(
+            SubtensorModule::submit_subnet_attestation(
+                RuntimeOrigin::none(),
+                payload.clone(),
+                signature
+            ),
+            Error::<Test>::DuplicateSubnetAttestation
+        );
+
+        // Old payloads can no longer be submitted
+        let payload = SubnetAttestationPayload {
+            kind: SubnetAttestationKind::MechanismDeployed {
+                mecid: subtensor_runtime_common::MechId::from(0u8),
+                version: 2,
+            },
+            ..payload
+        };
+        let signature = oracle.sign(&payload.encode());
+        step_block(MAX_SUBNET_ATTESTATION_AGE as u16 + 1);
+        assert_noop!(
+            SubtensorModule::submit_subnet_attestation(RuntimeOrigin::none(), payload, signature),
+            Error::<Test>::StaleSubnetAttestation
+        );
+    });
+}
+
+#[test]
+fn test_reset_bonds() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(1);
+        add_network(netuid, 10, 0);
+        SubnetOwner::<Test>::insert(netuid, owner);
+        AdminFreezeWindow::<Test>::set(0);
+        MechanismCountCurrent::<Test>::insert(netuid, MechId::from(2u8));
+        let seed_bonds = || {
+            for mecid in 0..2u8 {
+                let netuid_index =
+                    SubtensorModule::get_mechanism_storage_index(netuid, MechId::from(mecid));
+                Bonds::<Test>::insert(netuid_index, 0u16, vec![(1u16, 100u16)]);
+                Bonds::<Test>::insert(netuid_index, 1u16, vec![(0u16, 200u16)]);
+            }
+        };
+        let bonds_cleared = || {
+            (0..2u8).all(|mecid| {
+                let netuid_index =
+                    SubtensorModule::get_mechanism_storage_index(netuid, MechId::from(mecid));
+                Bonds::<Test>::iter_prefix(netuid_index).next().is_none()
+            })
+        };
+        seed_bonds();
+
+        assert_noop!(
+            SubtensorModule::reset_bonds(RuntimeOrigin::signed(U256::from(2)), netuid),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            SubtensorModule::reset_bonds(RuntimeOrigin::root(), NetUid::from(2)),
+            Error::<Test>::SubnetNotExists
+        );
+
+        assert_ok!(SubtensorModule::reset_bonds(
+            RuntimeOrigin::signed(owner),
+            netuid
+        ));
+        assert!(bonds_cleared());
+        System::assert_last_event(Event::SubnetBondsReset { netuid }.into());
+
+        // The owner is rate limited, root is not
+        seed_bonds();
+        assert_noop!(
+            SubtensorModule::reset_bonds(RuntimeOrigin::signed(owner), netuid),
+            Error::<Test>::TxRateLimitExceeded
+        );
+        assert_ok!(SubtensorModule::reset_bonds(RuntimeOrigin::root(), netuid));
+        assert!(bonds_cleared());
+    });
+}
+
+#[cfg(not(feature = "dev-mode"))]
+#[test]
+fn test_dev_mode_disabled() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        add_network(netuid, 10, 0);
+
+        assert_noop!(
+            SubtensorModule::dev_faucet(RuntimeOrigin::signed(U256::from(1)), 1.into()),
+            Error::<Test>::DevModeDisabled
+        );
+        assert_noop!(
+            SubtensorModule::dev_enable_instant_registration(RuntimeOrigin::root(), netuid),
+            Error::<Test>::DevModeDisabled
+        );
+        assert_noop!(
+            SubtensorModule::dev_disable_rate_limits(RuntimeOrigin::root(), netuid),
+            Error::<Test>::DevModeDisabled
+        );
+        assert_noop!(
+            SubtensorModule::dev_advance_tempo(RuntimeOrigin::root(), netuid),
+            Error::<Test>::DevModeDisabled
+        );
+    });
+}
+
+#[cfg(feature = "dev-mode")]
+#[test]
+fn test_dev_mode() {
+    use crate::utils::dev_mode::DEV_FAUCET_MAX_AMOUNT;
+
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let owner = U256::from(0);
+        let coldkey = U256::from(1);
+        add_network(netuid, 10, 0);
+
+        assert_noop!(
+            SubtensorModule::dev_faucet(
+                RuntimeOrigin::signed(coldkey),
+                (DEV_FAUCET_MAX_AMOUNT + 1).into()
+            ),
+            Error::<Test>::DevFaucetAmountTooHigh
+        );
+        assert_ok!(SubtensorModule::dev_faucet(
+            RuntimeOrigin::signed(coldkey),
+            DEV_FAUCET_MAX_AMOUNT.into()
+        ));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey),
+            DEV_FAUCET_MAX_AMOUNT
+        );
+
+        // Registrations are no longer limited per block
+        SubtensorModule::set_max_registrations_per_block(netuid, 1);
+        assert_ok!(SubtensorModule::dev_enable_instant_registration(
+            RuntimeOrigin::signed(owner),
+            netuid
+        ));
+        for key in 10..13u64 {
+            assert_ok!(SubtensorModule::burned_register(
+                RuntimeOrigin::signed(coldkey),
+                netuid,
+                U256::from(key)
+            ));
+        }
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 3);
+
+        // Only root can lift the rate limits of the chain
+        assert_noop!(
+            SubtensorModule::dev_disable_rate_limits(RuntimeOrigin::signed(owner), netuid),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(SubtensorModule::dev_disable_rate_limits(
+            RuntimeOrigin::root(),
+            netuid
+        ));
+        assert_eq!(SubtensorModule::get_tx_rate_limit(), 0);
+        assert_eq!(SubtensorModule::get_weights_set_rate_limit(netuid), 0);
+
+        // The epoch runs without waiting for the tempo
+        System::set_block_number(SubtensorModule::get_current_block_as_u64() + 1);
+        assert!(!SubtensorModule::should_run_epoch(
+            netuid,
+            SubtensorModule::get_current_block_as_u64()
+        ));
+        assert_ok!(SubtensorModule::dev_advance_tempo(
+            RuntimeOrigin::signed(owner),
+            netuid
+        ));
+        assert_eq!(
+            LastMechansimStepBlock::<Test>::get(netuid),
+            SubtensorModule::get_current_block_as_u64()
+        );
+        System::assert_last_event(Event::DevTempoAdvanced { netuid }.into());
+    });
+}
+
+#[test]
+fn test_subnet_treasury() {
+    new_test_ext(1).execute_with(|| {
+        let owner_ck = U256::from(0);
+        let owner_hk = U256::from(1);
+        let validator_ck = U256::from(2);
+        let validator_hk = U256::from(3);
+        let beneficiary = U256::from(4);
+        let beneficiary_hk = U256::from(5);
+        let netuid = add_dynamic_network(&owner_hk, &owner_ck);
+        SubtensorModule::create_account_if_non_existent(&beneficiary, &beneficiary_hk);
+
+        // The whole owner cut is redirected into the treasury
+        assert_ok!(SubtensorModule::set_subnet_treasury(netuid, u16::MAX, 0));
+        SubtensorModule::distribute_dividends_and_incentives(
+            netuid,
+            1_000_000.into(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        );
+        assert_eq!(
+            SubnetTreasury::<Test>::get(netuid),
+            AlphaCurrency::from(1_000_000)
+        );
+
+        // Only the owner spends, at most the treasury
+        assert_noop!(
+            SubtensorModule::spend_subnet_treasury(
+                RuntimeOrigin::signed(beneficiary),
+                netuid,
+                beneficiary_hk,
+                beneficiary,
+                400_000.into()
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            SubtensorModule::spend_subnet_treasury(
+                RuntimeOrigin::signed(owner_ck),
+                netuid,
+                beneficiary_hk,
+                beneficiary,
+                2_000_000.into()
+            ),
+            Error::<Test>::InsufficientSubnetTreasury
+        );
+
+        // Without approval threshold the spend is made right away
+        assert_ok!(SubtensorModule::spend_subnet_treasury(
+            RuntimeOrigin::signed(owner_ck),
+            netuid,
+            beneficiary_hk,
+            beneficiary,
+            400_000.into()
+        ));
+        assert_eq!(
+            SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &beneficiary_hk,
+                &beneficiary,
+                netuid
+            ),
+            AlphaCurrency::from(400_000)
+        );
+        assert_eq!(
+            SubnetTreasury::<Test>::get(netuid),
+            AlphaCurrency::from(600_000)
+        );
+
+        // With a threshold, it waits for the approval of half the alpha of the subnet
+        assert_ok!(SubtensorModule::set_subnet_treasury(
+            netuid,
+            u16::MAX,
+            u16::MAX / 2
+        ));
+        SubnetAlphaOut::<Test>::insert(netuid, AlphaCurrency::from(1_000_000_000));
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &validator_hk,
+            &validator_ck,
+            netuid,
+            600_000_000.into(),
+        );
+        assert_ok!(SubtensorModule::spend_subnet_treasury(
+            RuntimeOrigin::signed(owner_ck),
+            netuid,
+            beneficiary_hk,
+            beneficiary,
+            100_000.into()
+        ));
+        assert_eq!(
+            SubnetTreasury::<Test>::get(netuid),
+            AlphaCurrency::from(600_000)
+        );
+        assert_noop!(
+            SubtensorModule::approve_subnet_treasury_spend(
+                RuntimeOrigin::signed(validator_hk),
+                netuid,
+                0
+            ),
+            Error::<Test>::NoSubnetTreasurySpend
+        );
+        assert_noop!(
+            SubtensorModule::approve_subnet_treasury_spend(
+                RuntimeOrigin::signed(beneficiary),
+                netuid,
+                1
+            ),
+            Error::<Test>::NotEnoughStakeToApprove
+        );
+        assert_ok!(SubtensorModule::approve_subnet_treasury_spend(
+            RuntimeOrigin::signed(validator_hk),
+            netuid,
+            1
+        ));
+        assert_eq!(
+            SubnetTreasury::<Test>::get(netuid),
+            AlphaCurrency::from(500_000)
+        );
+        assert!(SubnetTreasurySpends::<Test>::get(netuid).is_none());
+    });
+}
+
+#[test]
+fn test_hyperparameter_voting() {
+    use codec::Encode;
+
+    new_test_ext(1).execute_with(|| {
+        let owner_ck = U256::from(0);
+        let owner_hk = U256::from(1);
+        let aye_ck = U256::from(2);
+        let aye_hk = U256::from(3);
+        let nay_ck = U256::from(4);
+        let nay_hk = U256::from(5);
+        let netuid = add_dynamic_network(&owner_hk, &owner_ck);
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &aye_hk,
+            &aye_ck,
+            netuid,
+            600_000.into(),
+        );
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &nay_hk,
+            &nay_ck,
+            netuid,
+            400_000.into(),
+        );
+        let propose = |immunity_period: u16| {
+            SubtensorModule::propose_hyperparameter_change(
+                RuntimeOrigin::signed(owner_ck),
+                netuid,
+                HyperparameterChange::ImmunityPeriod(immunity_period),
+            )
+        };
+
+        // Changes are only voted on once the subnet opts in
+        assert_noop!(propose(100), Error::<Test>::HyperparameterVotingDisabled);
+        assert_ok!(SubtensorModule::set_hyperparameter_voting_period(
+            netuid, 100
+        ));
+        assert_noop!(
+            SubtensorModule::set_hyperparameter_voting_period(netuid, 50),
+            Error::<Test>::HyperparameterVotingPeriodTooShort
+        );
+        assert!(SubtensorModule::is_hyperparameter_voted(
+            netuid,
+            &Hyperparameter::ImmunityPeriod
+        ));
+        assert!(!SubtensorModule::is_hyperparameter_voted(
+            netuid,
+            &Hyperparameter::Kappa
+        ));
+
+        // Only the owner proposes, one change at a time
+        assert_noop!(
+            SubtensorModule::propose_hyperparameter_change(
+                RuntimeOrigin::signed(aye_ck),
+                netuid,
+                HyperparameterChange::ImmunityPeriod(100),
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(propose(100));
+        assert_noop!(propose(200), Error::<Test>::HyperparameterProposalPending);
+
+        // Hotkeys with the stake to set weights vote until the end of the voting period
+        assert_noop!(
+            SubtensorModule::vote_hyperparameter_change(
+                RuntimeOrigin::signed(U256::from(6)),
+                netuid,
+                0,
+                true
+            ),
+            Error::<Test>::NotEnoughStakeToVote
+        );
+        SubtensorModule::set_stake_threshold(100_000);
+        SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+            &U256::from(6),
+            &U256::from(7),
+            netuid,
+            1.into(),
+        );
+        assert_noop!(
+            SubtensorModule::vote_hyperparameter_change(
+                RuntimeOrigin::signed(U256::from(6)),
+                netuid,
+                0,
+                true
+            ),
+            Error::<Test>::NotEnoughStakeToVote
+        );
+        assert_ok!(SubtensorModule::vote_hyperparameter_change(
+            RuntimeOrigin::signed(aye_hk),
+            netuid,
+            0,
+            false
+        ));
+        assert_ok!(SubtensorModule::vote_hyperparameter_change(
+            RuntimeOrigin::signed(aye_hk),
+            netuid,
+            0,
+            true
+        ));
+        assert_noop!(
+            SubtensorModule::vote_hyperparameter_change(
+                RuntimeOrigin::signed(aye_hk),
+                netuid,
+                0,
+                true
+            ),
+            Error::<Test>::HyperparameterAlreadyVoted
+        );
+        assert_ok!(SubtensorModule::vote_hyperparameter_change(
+            RuntimeOrigin::signed(nay_hk),
+            netuid,
+            0,
+            false
+        ));
+        assert_noop!(
+            SubtensorModule::enact_hyperparameter_change(RuntimeOrigin::signed(nay_ck), netuid, 0),
+            Error::<Test>::HyperparameterVotingOpen
+        );
+
+        // The change is made once the voting period is over, the ayes holding more alpha
+        let immunity_period = SubtensorModule::get_immunity_period(netuid);
+        System::set_block_number(System::block_number() + 101);
+        assert_noop!(
+            SubtensorModule::vote_hyperparameter_change(
+                RuntimeOrigin::signed(nay_hk),
+                netuid,
+                0,
+                true
+            ),
+            Error::<Test>::HyperparameterVotingClosed
+        );
+        let post_info =
+            SubtensorModule::enact_hyperparameter_change(RuntimeOrigin::signed(nay_ck), netuid, 0)
+                .unwrap();
+        assert_eq!(
+            post_info.actual_weight,
+            Some(SubtensorModule::enact_hyperparameter_change_weight(2))
+        );
+        assert_eq!(SubtensorModule::get_immunity_period(netuid), 100);
+        System::assert_has_event(
+            Event::HyperparameterChanged {
+                netuid,
+                param: Hyperparameter::ImmunityPeriod,
+                old: immunity_period.encode(),
+                new: 100_u16.encode(),
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::HyperparameterChangeEnacted {
+                netuid,
+                id: 0,
+                ayes: 600_000.into(),
+                nays: 400_000.into(),
+            }
+            .into(),
+        );
+        assert!(SubtensorModule::get_hyperparameter_proposal(netuid).is_none());
+        assert_eq!(HyperparameterVotes::<Test>::iter_prefix(netuid).count(), 0);
+
+        // A change the nays hold more alpha against is rejected
+        assert_ok!(propose(200));
+        assert_ok!(SubtensorModule::vote_hyperparameter_change(
+            RuntimeOrigin::signed(nay_hk),
+            netuid,
+            1,
+            false
+        ));
+        System::set_block_number(System::block_number() + 101);
+        assert_ok!(SubtensorModule::enact_hyperparameter_change(
+            RuntimeOrigin::signed(nay_ck),
+            netuid,
+            1
+        ));
+        assert_eq!(SubtensorModule::get_immunity_period(netuid), 100);
+        System::assert_last_event(
+            Event::HyperparameterChangeRejected {
+                netuid,
+                id: 1,
+                ayes: AlphaCurrency::ZERO,
+                nays: 400_000.into(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_hyperparameter_voters_are_bounded() {
+    use crate::subnets::hyperparameter_vote::MAX_HYPERPARAMETER_VOTERS;
+
+    new_test_ext(1).execute_with(|| {
+        let owner_ck = U256::from(0);
+        let owner_hk = U256::from(1);
+        let netuid = add_dynamic_network(&owner_hk, &owner_ck);
+        assert_ok!(SubtensorModule::set_hyperparameter_voting_period(
+            netuid, 100
+        ));
+
+        // The tempo is only changed by a vote once the subnet opts in
+        assert!(SubtensorModule::is_hyperparameter_voted(
+            netuid,
+            &Hyperparameter::Tempo
+        ));
+        assert_ok!(SubtensorModule::propose_hyperparameter_change(
+            RuntimeOrigin::signed(owner_ck),
+            netuid,
+            HyperparameterChange::Tempo(42),
+        ));
+
+        SubtensorModule::set_stake_threshold(0);
+        for voter in 0..=MAX_HYPERPARAMETER_VOTERS {
+            SubtensorModule::increase_stake_for_hotkey_and_coldkey_on_subnet(
+                &U256::from(1_000 + voter),
+                &U256::from(100_000 + voter),
+                netuid,
+                1_000.into(),
+            );
+        }
+        let vote = |voter: u32| {
+            SubtensorModule::vote_hyperparameter_change(
+                RuntimeOrigin::signed(U256::from(1_000 + voter)),
+                netuid,
+                0,
+                true,
+            )
+        };
+        for voter in 0..MAX_HYPERPARAMETER_VOTERS {
+            assert_ok!(vote(voter));
+        }
+        assert_noop!(
+            vote(MAX_HYPERPARAMETER_VOTERS),
+            Error::<Test>::TooManyHyperparameterVoters
+        );
+
+        // A voter changing its vote takes no new place
+        assert_ok!(SubtensorModule::vote_hyperparameter_change(
+            RuntimeOrigin::signed(U256::from(1_000)),
+            netuid,
+            0,
+            false
+        ));
+        assert_eq!(
+            SubtensorModule::get_hyperparameter_proposal(netuid)
+                .unwrap()
+                .voters,
+            MAX_HYPERPARAMETER_VOTERS
+        );
+
+        System::set_block_number(System::block_number() + 101);
+        assert_ok!(SubtensorModule::enact_hyperparameter_change(
+            RuntimeOrigin::signed(owner_ck),
+            netuid,
+            0
+        ));
+        assert_eq!(SubtensorModule::get_tempo(netuid), 42);
+        assert_eq!(HyperparameterVotes::<Test>::iter_prefix(netuid).count(), 0);
+    });
+}
+
+#[test]
+fn test_get_metagraph_page() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1u16);
+        add_network(netuid, 10, 0);
+        for uid in 0..5u16 {
+            let hotkey = U256::from(100 + uid);
+            SubtensorModule::create_account_if_non_existent(&U256::from(200 + uid), &hotkey);
+            SubtensorModule::append_neuron(netuid, &hotkey, 0);
+        }
+        Rank::<Test>::insert(netuid, vec![10, 11, 12, 13, 14]);
+
+        let page = SubtensorModule::get_metagraph_page(netuid, 1, 2).unwrap();
+        assert_eq!(page.num_uids, 5.into());
+        assert_eq!(page.start_uid, 1.into());
+        assert_eq!(page.hotkeys, vec![U256::from(101), U256::from(102)]);
+        assert_eq!(page.coldkeys, vec![U256::from(201), U256::from(202)]);
+        assert_eq!(page.rank, vec![11.into(), 12.into()]);
+        assert_eq!(page.axons.len(), 2);
+        assert_eq!(page.total_stake.len(), 2);
+
+        // The last page is cut at the last UID, and pages past it are empty
+        let page = SubtensorModule::get_metagraph_page(netuid, 4, 10).unwrap();
+        assert_eq!(page.hotkeys, vec![U256::from(104)]);
+        assert_eq!(page.rank, vec![14.into()]);
+        let page = SubtensorModule::get_metagraph_page(netuid, 5, 10).unwrap();
+        assert!(page.hotkeys.is_empty() && page.rank.is_empty());
+
+        assert_eq!(
+            SubtensorModule::get_metagraph_page(NetUid::from(2u16), 0, 10),
+            None
+        );
+    });
+}
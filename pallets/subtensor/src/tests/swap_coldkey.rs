@@ -62,7 +62,7 @@ fn test_swap_subnet_owner() {
         let netuid = NetUid::from(1u16);
 
         add_network(netuid, 1, 0);
-        SubnetOwner::<Test>::insert(netuid, old_coldkey);
+        SubtensorModule::set_subnet_owner(netuid, &old_coldkey);
 
         let mut weight = Weight::zero();
         assert_ok!(SubtensorModule::perform_swap_coldkey(
@@ -256,8 +256,8 @@ fn test_swap_with_multiple_subnets() {
 
         add_network(netuid1, 1, 0);
         add_network(netuid2, 1, 0);
-        SubnetOwner::<Test>::insert(netuid1, old_coldkey);
-        SubnetOwner::<Test>::insert(netuid2, old_coldkey);
+        SubtensorModule::set_subnet_owner(netuid1, &old_coldkey);
+        SubtensorModule::set_subnet_owner(netuid2, &old_coldkey);
 
         let mut weight = Weight::zero();
         assert_ok!(SubtensorModule::perform_swap_coldkey(
@@ -1227,8 +1227,8 @@ fn test_swap_subnet_owner_for_coldkey() {
         // Initialize SubnetOwner for old_coldkey
         add_network(netuid1, 13, 0);
         add_network(netuid2, 14, 0);
-        SubnetOwner::<Test>::insert(netuid1, old_coldkey);
-        SubnetOwner::<Test>::insert(netuid2, old_coldkey);
+        SubtensorModule::set_subnet_owner(netuid1, &old_coldkey);
+        SubtensorModule::set_subnet_owner(netuid2, &old_coldkey);
 
         // Set up TotalNetworks
         TotalNetworks::<Test>::put(3);
@@ -1261,7 +1261,7 @@ fn test_do_swap_coldkey_with_subnet_ownership() {
         crate::TotalNetworks::<Test>::set(1);
 
         SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, stake_amount + swap_cost);
-        SubnetOwner::<Test>::insert(netuid, old_coldkey);
+        SubtensorModule::set_subnet_owner(netuid, &old_coldkey);
 
         // Populate OwnedHotkeys map
         OwnedHotkeys::<Test>::insert(old_coldkey, vec![hotkey]);
@@ -2653,3 +2653,196 @@ fn test_swap_auto_stake_destination_coldkeys() {
         );
     });
 }
+
+#[test]
+fn test_cancel_scheduled_coldkey_swap() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let other_coldkey = U256::from(3);
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1_000_000_000_000);
+
+        // Nothing to cancel
+        assert_noop!(
+            SubtensorModule::cancel_scheduled_coldkey_swap(
+                <<Test as Config>::RuntimeOrigin>::signed(old_coldkey)
+            ),
+            Error::<Test>::NoColdkeySwapScheduled
+        );
+
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            new_coldkey
+        ));
+        let when = System::block_number() + ColdkeySwapScheduleDuration::<Test>::get();
+        assert!(ColdkeySwapScheduledTask::<Test>::contains_key(old_coldkey));
+
+        // Only the scheduling coldkey can cancel its swap
+        assert_noop!(
+            SubtensorModule::cancel_scheduled_coldkey_swap(
+                <<Test as Config>::RuntimeOrigin>::signed(other_coldkey)
+            ),
+            Error::<Test>::NoColdkeySwapScheduled
+        );
+
+        // The cancel call passes the transaction extension of a coldkey in swap schedule
+        let info: DispatchInfo =
+            DispatchInfoOf::<<Test as frame_system::Config>::RuntimeCall>::default();
+        let extension = SubtensorTransactionExtension::<Test>::new();
+        let call = RuntimeCall::SubtensorModule(SubtensorCall::cancel_scheduled_coldkey_swap {});
+        assert_ok!(extension.validate(
+            RawOrigin::Signed(old_coldkey).into(),
+            &call,
+            &info,
+            10,
+            (),
+            &TxBaseImplication(()),
+            TransactionSource::External,
+        ));
+
+        run_to_block(when - 1);
+        assert_ok!(SubtensorModule::cancel_scheduled_coldkey_swap(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey)
+        ));
+        assert!(!ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+        assert!(!ColdkeySwapScheduledTask::<Test>::contains_key(old_coldkey));
+        System::assert_last_event(
+            Event::ColdkeySwapCancelled {
+                old_coldkey,
+                new_coldkey,
+            }
+            .into(),
+        );
+
+        // The swap does not execute
+        run_to_block(when + 1);
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&old_coldkey),
+            1_000_000_000_000
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&new_coldkey), 0);
+
+        // A swap cannot be cancelled once its execution block is reached
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            new_coldkey
+        ));
+        let (when, _) = ColdkeySwapScheduled::<Test>::get(old_coldkey);
+        System::set_block_number(when);
+        assert_noop!(
+            SubtensorModule::cancel_scheduled_coldkey_swap(
+                <<Test as Config>::RuntimeOrigin>::signed(old_coldkey)
+            ),
+            Error::<Test>::ColdkeySwapNotCancellable
+        );
+    });
+}
+
+#[test]
+fn test_coldkey_guardians_approve_swap() {
+    new_test_ext(1).execute_with(|| {
+        let old_coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let guardians = vec![U256::from(10), U256::from(11), U256::from(12)];
+        SubtensorModule::add_balance_to_coldkey_account(&old_coldkey, 1_000_000_000_000);
+
+        // The threshold must be within the guardians, which must be unique
+        assert_noop!(
+            SubtensorModule::set_coldkey_guardians(
+                <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+                guardians.clone(),
+                4
+            ),
+            Error::<Test>::InvalidColdkeyGuardians
+        );
+        assert_noop!(
+            SubtensorModule::set_coldkey_guardians(
+                <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+                vec![U256::from(10), U256::from(10)],
+                1
+            ),
+            Error::<Test>::InvalidColdkeyGuardians
+        );
+        assert_ok!(SubtensorModule::set_coldkey_guardians(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            guardians.clone(),
+            2
+        ));
+        assert_noop!(
+            SubtensorModule::set_coldkey_guardians(
+                <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+                vec![U256::from(13)],
+                1
+            ),
+            Error::<Test>::ColdkeyGuardiansAlreadySet
+        );
+
+        // A swap approved by a single guardian does not execute
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            new_coldkey
+        ));
+        let (when, _) = ColdkeySwapScheduled::<Test>::get(old_coldkey);
+        assert_noop!(
+            SubtensorModule::approve_coldkey_swap(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(13)),
+                old_coldkey,
+                new_coldkey
+            ),
+            Error::<Test>::NotColdkeyGuardian
+        );
+        assert_noop!(
+            SubtensorModule::approve_coldkey_swap(
+                <<Test as Config>::RuntimeOrigin>::signed(guardians[0]),
+                old_coldkey,
+                U256::from(3)
+            ),
+            Error::<Test>::ColdkeySwapTargetMismatch
+        );
+        assert_ok!(SubtensorModule::approve_coldkey_swap(
+            <<Test as Config>::RuntimeOrigin>::signed(guardians[0]),
+            old_coldkey,
+            new_coldkey
+        ));
+        assert_noop!(
+            SubtensorModule::approve_coldkey_swap(
+                <<Test as Config>::RuntimeOrigin>::signed(guardians[0]),
+                old_coldkey,
+                new_coldkey
+            ),
+            Error::<Test>::ColdkeySwapAlreadyApproved
+        );
+        run_to_block(when + 1);
+        assert!(ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&new_coldkey), 0);
+        assert_noop!(
+            SubtensorModule::approve_coldkey_swap(
+                <<Test as Config>::RuntimeOrigin>::signed(guardians[1]),
+                old_coldkey,
+                new_coldkey
+            ),
+            Error::<Test>::ColdkeySwapApprovalClosed
+        );
+
+        // Rescheduling clears the approvals, the swap executes with the threshold of approvals
+        run_to_block(when + ColdkeySwapRescheduleDuration::<Test>::get());
+        assert_ok!(SubtensorModule::schedule_swap_coldkey(
+            <<Test as Config>::RuntimeOrigin>::signed(old_coldkey),
+            new_coldkey
+        ));
+        assert!(ColdkeySwapApprovals::<Test>::get(old_coldkey).is_empty());
+        let (when, _) = ColdkeySwapScheduled::<Test>::get(old_coldkey);
+        for guardian in guardians.iter().skip(1) {
+            assert_ok!(SubtensorModule::approve_coldkey_swap(
+                <<Test as Config>::RuntimeOrigin>::signed(*guardian),
+                old_coldkey,
+                new_coldkey
+            ));
+        }
+        run_to_block(when + 1);
+        assert!(!ColdkeySwapScheduled::<Test>::contains_key(old_coldkey));
+        assert!(SubtensorModule::get_coldkey_balance(&new_coldkey) > 0);
+        assert!(!ColdkeyGuardians::<Test>::contains_key(old_coldkey));
+        assert!(!ColdkeySwapApprovals::<Test>::contains_key(old_coldkey));
+    });
+}
@@ -13,6 +13,7 @@ use subtensor_swap_interface::SwapHandler;
 
 use super::mock;
 use super::mock::*;
+use crate::swap::key_rotation_log::{KeyRotation, KeyRotationRecord, MAX_KEY_ROTATION_LOG_LEN};
 use crate::*;
 
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey -- test_swap_owner --exact --nocapture
@@ -1501,3 +1502,63 @@ fn test_swap_auto_stake_destination_coldkeys() {
         );
     });
 }
+
+#[test]
+fn test_key_rotation_log() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+
+        add_network(netuid, 10, 0);
+        register_ok_neuron(netuid, old_hotkey, coldkey, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 2_000_000_000);
+        step_block(10);
+
+        assert_ok!(SubtensorModule::do_swap_hotkey(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            &old_hotkey,
+            &new_hotkey,
+            None
+        ));
+
+        // The swap is recorded for both hotkeys and the coldkey
+        let record = KeyRotationRecord {
+            block: SubtensorModule::get_current_block_as_u64(),
+            rotation: KeyRotation::HotkeySwap {
+                coldkey,
+                old_hotkey,
+                new_hotkey,
+                netuid: None,
+            },
+        };
+        for account in [old_hotkey, new_hotkey, coldkey] {
+            assert_eq!(
+                SubtensorModule::get_key_rotation_log(account),
+                vec![record.clone()]
+            );
+        }
+
+        // The log keeps the last records only
+        for i in 0..MAX_KEY_ROTATION_LOG_LEN as u64 {
+            SubtensorModule::record_key_rotation(
+                &[&coldkey],
+                KeyRotation::ColdkeySwap {
+                    old_coldkey: coldkey,
+                    new_coldkey: U256::from(100 + i),
+                },
+            );
+        }
+        let log = SubtensorModule::get_key_rotation_log(coldkey);
+        assert_eq!(log.len(), MAX_KEY_ROTATION_LOG_LEN);
+        assert!(!log.contains(&record));
+        assert_eq!(
+            log.last().map(|record| record.rotation.clone()),
+            Some(KeyRotation::ColdkeySwap {
+                old_coldkey: coldkey,
+                new_coldkey: U256::from(100 + MAX_KEY_ROTATION_LOG_LEN as u64 - 1),
+            })
+        );
+    });
+}
@@ -1585,3 +1585,63 @@ fn test_swap_hotkey_registered_on_other_subnet() {
         );
     });
 }
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --test swap_hotkey_with_subnet -- test_swap_hotkey_on_subnet_extrinsic --exact --nocapture
+#[test]
+fn test_swap_hotkey_on_subnet_extrinsic() {
+    new_test_ext(1).execute_with(|| {
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(2);
+        let coldkey = U256::from(3);
+        let operator = U256::from(4);
+        let netuid = add_dynamic_network(&old_hotkey, &coldkey);
+        let other_netuid = add_dynamic_network(&old_hotkey, &coldkey);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, u64::MAX);
+
+        ChildkeyTake::<Test>::insert(old_hotkey, netuid, 100);
+        ChildkeyTake::<Test>::insert(old_hotkey, other_netuid, 200);
+        WeightSetterDelegate::<Test>::insert(netuid, operator, old_hotkey);
+        WeightSetterDelegate::<Test>::insert(other_netuid, operator, old_hotkey);
+        let uid = Uids::<Test>::get(netuid, old_hotkey).unwrap();
+
+        System::set_block_number(System::block_number() + HotkeySwapOnSubnetInterval::get());
+
+        // As with `swap_hotkey`, the new hotkey must not be registered on any subnet
+        let registered_hotkey = U256::from(5);
+        register_ok_neuron(other_netuid, registered_hotkey, U256::from(6), 0);
+        assert_noop!(
+            SubtensorModule::swap_hotkey_on_subnet(
+                RuntimeOrigin::signed(coldkey),
+                old_hotkey,
+                registered_hotkey,
+                netuid
+            ),
+            Error::<Test>::HotKeyAlreadyRegisteredInSubNet
+        );
+
+        assert_ok!(SubtensorModule::swap_hotkey_on_subnet(
+            RuntimeOrigin::signed(coldkey),
+            old_hotkey,
+            new_hotkey,
+            netuid
+        ));
+
+        // The neuron, its childkey take and its operators move on the subnet only.
+        assert_eq!(Uids::<Test>::get(netuid, new_hotkey), Some(uid));
+        assert_eq!(Keys::<Test>::get(netuid, uid), new_hotkey);
+        assert!(Uids::<Test>::get(netuid, old_hotkey).is_none());
+        assert_eq!(ChildkeyTake::<Test>::get(new_hotkey, netuid), 100);
+        assert!(!ChildkeyTake::<Test>::contains_key(old_hotkey, netuid));
+        assert_eq!(
+            WeightSetterDelegate::<Test>::get(netuid, operator),
+            Some(new_hotkey)
+        );
+
+        assert!(Uids::<Test>::get(other_netuid, old_hotkey).is_some());
+        assert_eq!(ChildkeyTake::<Test>::get(old_hotkey, other_netuid), 200);
+        assert_eq!(
+            WeightSetterDelegate::<Test>::get(other_netuid, operator),
+            Some(old_hotkey)
+        );
+    });
+}
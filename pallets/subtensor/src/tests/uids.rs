@@ -1,6 +1,7 @@
 #![allow(clippy::unwrap_used)]
 
 use super::mock::*;
+use crate::subnets::neuron_status::NeuronStatus;
 use crate::*;
 use frame_support::{assert_err, assert_ok};
 use frame_system::Config;
@@ -569,3 +570,78 @@ fn test_get_neuron_to_prune_owner_pruned_if_not_in_sn_owner_hotkey_map() {
         );
     });
 }
+
+#[test]
+fn test_neuron_status_lifecycle() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        add_network(netuid, 1, 0);
+        SubtensorModule::set_max_registrations_per_block(netuid, 100);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 100);
+        SubtensorModule::set_max_allowed_uids(netuid, 3);
+        SubtensorModule::set_immunity_period(netuid, 2);
+
+        let hotkeys = [U256::from(1), U256::from(2), U256::from(3)];
+        for (i, hotkey) in hotkeys.iter().enumerate() {
+            register_ok_neuron(
+                netuid,
+                *hotkey,
+                U256::from(100 + i as u64),
+                i as u64 * 100_000,
+            );
+        }
+
+        // Registered neurons are immune
+        for uid in 0..3 {
+            assert_eq!(
+                SubtensorModule::get_neuron_status_for_uid(netuid, uid),
+                NeuronStatus::Immune
+            );
+        }
+
+        // Once immunity has passed, the full subnet has a neuron pending prune
+        System::set_block_number(100);
+        SubtensorModule::set_pruning_score_for_uid(netuid, 0, 5);
+        SubtensorModule::set_pruning_score_for_uid(netuid, 1, 5);
+        SubtensorModule::set_pruning_score_for_uid(netuid, 2, 0);
+        SubtensorModule::update_neuron_statuses(netuid, &[true, false, false]);
+        assert_eq!(
+            NeuronStatuses::<Test>::get(netuid),
+            vec![
+                NeuronStatus::Active,
+                NeuronStatus::Inactive,
+                NeuronStatus::PendingPrune
+            ]
+        );
+        // Finding the neuron to prune does not mark it
+        assert_eq!(SubtensorModule::get_pruning_score_for_uid(netuid, 2), 0);
+        assert!(System::events().iter().any(|e| {
+            e.event
+                == RuntimeEvent::SubtensorModule(Event::NeuronStatusChanged {
+                    netuid,
+                    uid: 1,
+                    hotkey: hotkeys[1],
+                    from: NeuronStatus::Immune,
+                    to: NeuronStatus::Inactive,
+                })
+        }));
+
+        // The replaced neuron is pruned and its successor is immune
+        let new_hotkey = U256::from(4);
+        SubtensorModule::replace_neuron(netuid, 2, &new_hotkey, 100);
+        assert!(System::events().iter().any(|e| {
+            e.event
+                == RuntimeEvent::SubtensorModule(Event::NeuronStatusChanged {
+                    netuid,
+                    uid: 2,
+                    hotkey: hotkeys[2],
+                    from: NeuronStatus::PendingPrune,
+                    to: NeuronStatus::Pruned,
+                })
+        }));
+        assert_eq!(
+            SubtensorModule::get_neuron_status_for_uid(netuid, 2),
+            NeuronStatus::Immune
+        );
+    });
+}
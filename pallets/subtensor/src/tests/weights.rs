@@ -2,9 +2,10 @@
 
 use ark_serialize::CanonicalDeserialize;
 use ark_serialize::CanonicalSerialize;
+use codec::Decode;
 use frame_support::dispatch::DispatchInfo;
 use frame_support::{
-    assert_err, assert_ok,
+    assert_err, assert_noop, assert_ok,
     dispatch::{DispatchClass, DispatchResult, GetDispatchInfo, Pays},
 };
 use frame_system::RawOrigin;
@@ -21,7 +22,7 @@ use sp_runtime::{
 };
 use sp_std::collections::vec_deque::VecDeque;
 use substrate_fixed::types::I32F32;
-use subtensor_runtime_common::{NetUidStorageIndex, TaoCurrency};
+use subtensor_runtime_common::{MechId, NetUidStorageIndex, TaoCurrency};
 use subtensor_swap_interface::SwapHandler;
 use tle::{
     curves::drand::TinyBLS381,
@@ -34,6 +35,8 @@ use w3f_bls::EngineBLS;
 use super::mock;
 use super::mock::*;
 use crate::coinbase::reveal_commits::{LegacyWeightsTlockPayload, WeightsTlockPayload};
+use crate::subnets::weight_row::WeightRow;
+use crate::subnets::weights::WeightsReveal;
 use crate::transaction_extension::SubtensorTransactionExtension;
 use crate::*;
 /***************************
@@ -971,8 +974,161 @@ fn test_weights_err_setting_weights_too_fast() {
     });
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_weights_set_rate_limit_override --exact --show-output --nocapture
+// Test ensures that the rate limit override of a uid is used instead of the subnet's.
+#[test]
+fn test_weights_set_rate_limit_override() {
+    new_test_ext(0).execute_with(|| {
+        let hotkey_account_id = U256::from(55);
+        let netuid = NetUid::from(1);
+        add_network_disable_commit_reveal(netuid, 13, 0);
+        SubtensorModule::set_min_allowed_weights(netuid, 0);
+        SubtensorModule::set_max_weight_limit(netuid, u16::MAX);
+        register_ok_neuron(netuid, hotkey_account_id, U256::from(66), 0);
+        register_ok_neuron(netuid, U256::from(1), U256::from(1), 65555);
+
+        let neuron_uid: u16 =
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey_account_id)
+                .expect("Not registered.");
+        SubtensorModule::set_validator_permit_for_uid(netuid, neuron_uid, true);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 10);
+        SubtensorModule::set_weights_set_rate_limit_override(netuid, neuron_uid, Some(1));
+        assert_eq!(
+            SubtensorModule::get_weights_set_rate_limit_for_uid(netuid, neuron_uid),
+            1
+        );
+
+        let set_weights = || {
+            SubtensorModule::set_weights(
+                RuntimeOrigin::signed(hotkey_account_id),
+                netuid,
+                vec![1],
+                vec![1],
+                0,
+            )
+        };
+
+        // The uid may set weights every block
+        for block in 1..5 {
+            run_to_block(block);
+            assert_ok!(set_weights());
+        }
+
+        // Without the override it follows the rate limit of the subnet again
+        SubtensorModule::set_weights_set_rate_limit_override(netuid, neuron_uid, None);
+        run_to_block(5);
+        assert_eq!(
+            set_weights(),
+            Err(Error::<Test>::SettingWeightsTooFast.into())
+        );
+
+        // A replaced neuron does not inherit the override of its uid
+        SubtensorModule::set_weights_set_rate_limit_override(netuid, neuron_uid, Some(1));
+        SubtensorModule::replace_neuron(netuid, neuron_uid, &U256::from(77), 5);
+        assert!(!WeightsSetRateLimitOverride::<Test>::contains_key(
+            netuid, neuron_uid
+        ));
+    });
+}
+
+#[test]
+fn test_set_no_weights() {
+    new_test_ext(0).execute_with(|| {
+        let hotkey_account_id = U256::from(55);
+        let netuid = NetUid::from(1);
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, U256::from(66), 0);
+        MechanismCountCurrent::<Test>::insert(netuid, MechId::from(2));
+        SubtensorModule::set_weights_set_rate_limit(netuid, 10);
+
+        let neuron_uid: u16 =
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey_account_id)
+                .expect("Not registered.");
+        let netuid_indices = [MechId::MAIN, MechId::from(1)]
+            .map(|mecid| SubtensorModule::get_mechanism_storage_index(netuid, mecid));
+        LastUpdate::<Test>::insert(netuid_indices[1], vec![0]);
+
+        // Commit-reveal subnets take heartbeats instead
+        assert_noop!(
+            SubtensorModule::set_no_weights(RuntimeOrigin::signed(hotkey_account_id), netuid),
+            Error::<Test>::CommitRevealEnabled
+        );
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, false);
+        assert_noop!(
+            SubtensorModule::set_no_weights(RuntimeOrigin::signed(U256::from(77)), netuid),
+            Error::<Test>::HotKeyNotRegisteredInSubNet
+        );
+
+        run_to_block(20);
+        for netuid_index in netuid_indices {
+            Weights::<Test>::insert(
+                netuid_index,
+                neuron_uid,
+                WeightRow::from(vec![(0, u16::MAX)]),
+            );
+        }
+        assert_ok!(SubtensorModule::set_no_weights(
+            RuntimeOrigin::signed(hotkey_account_id),
+            netuid
+        ));
+
+        // The weights are emptied and count as an update on every mechanism
+        for netuid_index in netuid_indices {
+            assert!(Weights::<Test>::get(netuid_index, neuron_uid).is_empty());
+            assert_eq!(
+                SubtensorModule::get_last_update_for_uid(netuid_index, neuron_uid),
+                20
+            );
+            System::assert_has_event(Event::WeightsSet(netuid_index, neuron_uid).into());
+        }
+
+        // The weights set rate limit applies
+        run_to_block(25);
+        assert_noop!(
+            SubtensorModule::set_no_weights(RuntimeOrigin::signed(hotkey_account_id), netuid),
+            Error::<Test>::SettingWeightsTooFast
+        );
+        run_to_block(30);
+        assert_ok!(SubtensorModule::set_no_weights(
+            RuntimeOrigin::signed(hotkey_account_id),
+            netuid
+        ));
+    });
+}
+
+#[test]
+fn test_weight_row_compression() {
+    // A validator weighting 5% of 4096 uids
+    let weights: Vec<(u16, u16)> = (0..4096u16)
+        .step_by(20)
+        .map(|uid| (uid, uid.saturating_mul(13)))
+        .collect();
+    let row = WeightRow::from(weights.clone());
+    assert_eq!(row.len(), weights.len());
+    assert_eq!(row.to_vec(), weights);
+    assert_eq!(WeightRow::decode(&mut &row.encode()[..]).unwrap(), row);
+    // One byte per uid instead of two, for a version byte
+    assert_eq!(
+        weights.encoded_size() - row.encoded_size(),
+        weights.len() - 1
+    );
+
+    // Rows stored as pairs before the compression decode to the same row
+    assert_eq!(WeightRow::decode(&mut &weights.encode()[..]).unwrap(), row);
+    assert_eq!(
+        WeightRow::decode(&mut &Vec::<(u16, u16)>::new().encode()[..]).unwrap(),
+        WeightRow::default()
+    );
+
+    // Rows are sorted by uid, a uid listed twice keeps its first weight
+    let row = WeightRow::from(vec![(9, 1), (u16::MAX, 2), (0, 3), (9, 4)]);
+    assert_eq!(row.to_vec(), vec![(0, 3), (9, 1), (u16::MAX, 2)]);
+    assert!(WeightRow::default().is_empty());
+}
+
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_weights_err_weights_vec_not_equal_size --exact --show-output --nocapture
 // Test ensures that uids -- weights must have the same size.
+
 #[test]
 fn test_weights_err_weights_vec_not_equal_size() {
     new_test_ext(0).execute_with(|| {
@@ -1896,6 +2052,550 @@ fn test_commit_reveal_weights_ok() {
     });
 }
 
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_commit_reveal_window --exact --show-output --nocapture
+#[test]
+fn test_commit_reveal_window() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let uids: Vec<u16> = vec![0, 1];
+        let weight_values: Vec<u16> = vec![10, 10];
+        let salt: Vec<u16> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let version_key: u64 = 0;
+        let hotkey: U256 = U256::from(1);
+
+        let commit_hash: H256 = BlakeTwo256::hash_of(&(
+            hotkey,
+            netuid,
+            uids.clone(),
+            weight_values.clone(),
+            salt.clone(),
+            version_key,
+        ));
+
+        System::set_block_number(0);
+
+        let tempo: u16 = 5;
+        add_network(netuid, tempo, 0);
+
+        register_ok_neuron(netuid, U256::from(3), U256::from(4), 300_000);
+        register_ok_neuron(netuid, U256::from(1), U256::from(2), 100_000);
+        SubtensorModule::set_stake_threshold(0);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 5);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+        assert_ok!(SubtensorModule::set_commit_reveal_bounds(1, 100, 1));
+
+        // Reveals are only accepted in the first 2 blocks of the reveal epoch
+        assert_ok!(SubtensorModule::set_reveal_window(netuid, 2));
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hash
+        ));
+        let (first_reveal_block, last_reveal_block) = SubtensorModule::get_reveal_blocks(netuid, 0);
+        assert_eq!(last_reveal_block, first_reveal_block.saturating_add(1));
+
+        step_epochs(1, netuid);
+        step_block(2);
+        assert_noop!(
+            SubtensorModule::reveal_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                uids.clone(),
+                weight_values.clone(),
+                salt.clone(),
+                version_key,
+            ),
+            Error::<Test>::RevealWindowClosed
+        );
+
+        // With the whole epoch as window, the commit can still be revealed
+        assert_ok!(SubtensorModule::set_reveal_window(netuid, 0));
+        assert_ok!(SubtensorModule::reveal_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            uids,
+            weight_values,
+            salt,
+            version_key,
+        ));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_settle_weight_commits --exact --show-output --nocapture
+#[test]
+fn test_settle_weight_commits() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let netuid_index = NetUidStorageIndex::from(netuid);
+        let hotkey: U256 = U256::from(1);
+        let salt: Vec<u16> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let missed = (vec![0], vec![10], salt.clone(), 0);
+        let pending = (vec![0, 1], vec![10, 10], salt.clone(), 0);
+        let hash_of = |(uids, values, salt, version_key): &WeightsReveal| {
+            SubtensorModule::get_commit_hash(
+                &hotkey,
+                netuid_index,
+                uids,
+                values,
+                salt,
+                *version_key,
+            )
+        };
+
+        System::set_block_number(0);
+        add_network(netuid, 5, 0);
+        register_ok_neuron(netuid, U256::from(3), U256::from(4), 300_000);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100_000);
+        SubtensorModule::set_stake_threshold(0);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+
+        // The first commit is never revealed in its reveal epoch
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            hash_of(&missed)
+        ));
+        step_epochs(1, netuid);
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            hash_of(&pending)
+        ));
+        step_epochs(1, netuid);
+
+        assert_noop!(
+            SubtensorModule::settle_weight_commits(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                MechId::MAIN,
+                vec![(vec![0], vec![20], salt.clone(), 0)],
+            ),
+            Error::<Test>::InvalidRevealCommitHashNotMatch
+        );
+        assert_noop!(
+            SubtensorModule::settle_weight_commits(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                MechId::MAIN,
+                vec![],
+            ),
+            Error::<Test>::InvalidNumberOfReveals
+        );
+
+        // The missed commit is dropped and the pending one revealed
+        assert_ok!(SubtensorModule::settle_weight_commits(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            MechId::MAIN,
+            vec![missed.clone(), pending.clone()],
+        ));
+        assert_eq!(Weights::<Test>::get(netuid_index, 1).len(), 2);
+        assert!(WeightCommits::<Test>::get(netuid_index, hotkey).is_none());
+        System::assert_last_event(
+            Event::WeightCommitsSettled {
+                hotkey,
+                netuid: netuid_index,
+                revealed_hashes: vec![hash_of(&pending)],
+                expired_hashes: vec![hash_of(&missed)],
+            }
+            .into(),
+        );
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_weight_setter_delegate --exact --show-output --nocapture
+#[test]
+fn test_weight_setter_delegate() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let netuid_index = NetUidStorageIndex::from(netuid);
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let miner = U256::from(3);
+        let operator = U256::from(10);
+
+        add_network_disable_commit_reveal(netuid, 5, 0);
+        register_ok_neuron(netuid, miner, U256::from(4), 300_000);
+        register_ok_neuron(netuid, hotkey, coldkey, 100_000);
+        SubtensorModule::set_stake_threshold(0);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+
+        assert_noop!(
+            SubtensorModule::set_weights(
+                RuntimeOrigin::signed(operator),
+                netuid,
+                vec![0, 1],
+                vec![10, 10],
+                0
+            ),
+            Error::<Test>::HotKeyNotRegisteredInSubNet
+        );
+
+        // Only the owner of the hotkey authorizes operators that are not on the subnet
+        assert_noop!(
+            SubtensorModule::set_weight_setter_delegate(
+                RuntimeOrigin::signed(U256::from(4)),
+                hotkey,
+                netuid,
+                operator
+            ),
+            Error::<Test>::NonAssociatedColdKey
+        );
+        assert_noop!(
+            SubtensorModule::set_weight_setter_delegate(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                netuid,
+                miner
+            ),
+            Error::<Test>::InvalidWeightSetterDelegate
+        );
+        assert_ok!(SubtensorModule::set_weight_setter_delegate(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            operator
+        ));
+
+        // Weights set by the operator are the weights of the hotkey
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(operator),
+            netuid,
+            vec![0, 1],
+            vec![10, 10],
+            0
+        ));
+        assert_eq!(Weights::<Test>::get(netuid_index, 1).len(), 2);
+        assert!(Weights::<Test>::get(netuid_index, 0).is_empty());
+
+        // So are commits, hashed with the hotkey
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+        let commit_hash =
+            SubtensorModule::get_commit_hash(&hotkey, netuid_index, &[0], &[10], &[1, 2], 0);
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(operator),
+            netuid,
+            commit_hash
+        ));
+        assert!(WeightCommits::<Test>::get(netuid_index, hotkey).is_some());
+        assert!(WeightCommits::<Test>::get(netuid_index, operator).is_none());
+
+        assert_ok!(SubtensorModule::remove_weight_setter_delegate(
+            RuntimeOrigin::signed(coldkey),
+            netuid,
+            operator
+        ));
+        assert_noop!(
+            SubtensorModule::remove_weight_setter_delegate(
+                RuntimeOrigin::signed(operator),
+                netuid,
+                operator
+            ),
+            Error::<Test>::WeightSetterDelegateNotFound
+        );
+        assert_noop!(
+            SubtensorModule::commit_weights(RuntimeOrigin::signed(operator), netuid, commit_hash),
+            Error::<Test>::HotKeyNotRegisteredInSubNet
+        );
+    });
+}
+
+#[test]
+fn test_hotkey_lease() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        let miner = U256::from(3);
+        let renter = U256::from(5);
+        let operator = U256::from(10);
+
+        add_network_disable_commit_reveal(netuid, 5, 0);
+        register_ok_neuron(netuid, miner, U256::from(4), 300_000);
+        register_ok_neuron(netuid, hotkey, coldkey, 100_000);
+        SubtensorModule::set_stake_threshold(0);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+
+        // Only the renter the lease is offered to accepts it
+        assert_noop!(
+            SubtensorModule::offer_hotkey_lease(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                netuid,
+                renter,
+                u16::MAX / 2,
+                0
+            ),
+            Error::<Test>::InvalidHotkeyLease
+        );
+        assert_ok!(SubtensorModule::offer_hotkey_lease(
+            RuntimeOrigin::signed(coldkey),
+            hotkey,
+            netuid,
+            renter,
+            u16::MAX / 2,
+            100
+        ));
+        assert_noop!(
+            SubtensorModule::accept_hotkey_lease(
+                RuntimeOrigin::signed(U256::from(6)),
+                hotkey,
+                netuid,
+                operator
+            ),
+            Error::<Test>::NoHotkeyLeaseOffer
+        );
+        assert_ok!(SubtensorModule::accept_hotkey_lease(
+            RuntimeOrigin::signed(renter),
+            hotkey,
+            netuid,
+            operator
+        ));
+        let end_block = SubtensorModule::get_current_block_as_u64() + 100;
+        assert_eq!(
+            HotkeyLeases::<Test>::get(netuid, hotkey).map(|lease| lease.end_block),
+            Some(end_block)
+        );
+
+        // Only the operator of the renter sets weights for the hotkey
+        assert_noop!(
+            SubtensorModule::set_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                vec![0, 1],
+                vec![10, 10],
+                0
+            ),
+            Error::<Test>::HotkeyLeased
+        );
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(operator),
+            netuid,
+            vec![0, 1],
+            vec![10, 10],
+            0
+        ));
+        assert_noop!(
+            SubtensorModule::remove_weight_setter_delegate(
+                RuntimeOrigin::signed(coldkey),
+                netuid,
+                operator
+            ),
+            Error::<Test>::HotkeyLeased
+        );
+        assert_noop!(
+            SubtensorModule::set_weight_setter_delegate(
+                RuntimeOrigin::signed(coldkey),
+                hotkey,
+                netuid,
+                U256::from(11)
+            ),
+            Error::<Test>::HotkeyLeased
+        );
+
+        // The renter is paid its share of the take of the hotkey
+        let owner_take = SubtensorModule::pay_hotkey_lease_share(netuid, &hotkey, 1_000.into());
+        assert_eq!(u64::from(owner_take), 501);
+        assert_eq!(
+            u64::from(SubtensorModule::get_stake_for_hotkey_and_coldkey_on_subnet(
+                &hotkey, &renter, netuid
+            )),
+            499
+        );
+
+        // The lease expires on its own and the owner sets weights again
+        System::set_block_number(end_block + 1);
+        SubtensorModule::expire_hotkey_leases(netuid);
+        assert!(!HotkeyLeases::<Test>::contains_key(netuid, hotkey));
+        assert_eq!(WeightSetterDelegate::<Test>::get(netuid, operator), None);
+        assert_eq!(
+            u64::from(SubtensorModule::pay_hotkey_lease_share(
+                netuid,
+                &hotkey,
+                1_000.into()
+            )),
+            1_000
+        );
+        assert_ok!(SubtensorModule::set_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            vec![0, 1],
+            vec![10, 10],
+            0
+        ));
+    });
+}
+
+// SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_merkle_weights --exact --show-output --nocapture
+#[test]
+fn test_merkle_weights() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let netuid_index = NetUidStorageIndex::from(netuid);
+        let mecid = MechId::from(0);
+        let hotkey = U256::from(1);
+
+        add_network_disable_commit_reveal(netuid, 5, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100_000);
+        register_ok_neuron(netuid, U256::from(3), U256::from(4), 200_000);
+        register_ok_neuron(netuid, U256::from(5), U256::from(6), 300_000);
+        SubtensorModule::set_stake_threshold(0);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+
+        let weights = vec![0, 100, 200];
+        let root = SubtensorModule::merkle_weights_root(&weights);
+        for (uid, weight) in weights.iter().enumerate() {
+            let proof = SubtensorModule::merkle_weights_proof(&weights, uid as u16);
+            assert_eq!(proof.len(), 2);
+            assert!(SubtensorModule::verify_merkle_weight(
+                root, 3, uid as u16, *weight, &proof
+            ));
+            assert!(!SubtensorModule::verify_merkle_weight(
+                root,
+                3,
+                uid as u16,
+                weight + 1,
+                &proof
+            ));
+        }
+        let entry = |uid: u16| {
+            (
+                uid,
+                weights[uid as usize],
+                SubtensorModule::merkle_weights_proof(&weights, uid),
+            )
+        };
+
+        assert_noop!(
+            SubtensorModule::commit_merkle_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                mecid,
+                root,
+                2,
+                0
+            ),
+            Error::<Test>::MerkleWeightsDisabled
+        );
+        SubtensorModule::set_merkle_weights_enabled(netuid, true);
+        assert_noop!(
+            SubtensorModule::commit_merkle_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                mecid,
+                root,
+                4,
+                0
+            ),
+            Error::<Test>::InvalidMerkleWeightsEntries
+        );
+        assert_noop!(
+            SubtensorModule::submit_merkle_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                mecid,
+                vec![entry(1)]
+            ),
+            Error::<Test>::NoMerkleWeightsCommit
+        );
+        assert_ok!(SubtensorModule::commit_merkle_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            mecid,
+            root,
+            2,
+            0
+        ));
+
+        // Zero weights and tampered entries are rejected
+        assert_noop!(
+            SubtensorModule::submit_merkle_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                mecid,
+                vec![entry(0)]
+            ),
+            Error::<Test>::InvalidMerkleWeightProof
+        );
+        let (uid, _, proof) = entry(1);
+        assert_noop!(
+            SubtensorModule::submit_merkle_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                mecid,
+                vec![(uid, 200, proof)]
+            ),
+            Error::<Test>::InvalidMerkleWeightProof
+        );
+
+        assert_ok!(SubtensorModule::submit_merkle_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            mecid,
+            vec![entry(1)]
+        ));
+        assert!(Weights::<Test>::get(netuid_index, 0).is_empty());
+        assert_noop!(
+            SubtensorModule::submit_merkle_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                mecid,
+                vec![entry(1)]
+            ),
+            Error::<Test>::DuplicateUids
+        );
+
+        // The last entry sets the weights
+        assert_ok!(SubtensorModule::submit_merkle_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            mecid,
+            vec![entry(2)]
+        ));
+        let row = Weights::<Test>::get(netuid_index, 0);
+        assert_eq!(
+            row.iter().map(|(uid, _)| uid).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert!(MerkleWeightsUploads::<Test>::get(netuid_index, hotkey).is_none());
+
+        // Commits are held to the weights set rate limit
+        SubtensorModule::set_weights_set_rate_limit(netuid, 10);
+        assert_noop!(
+            SubtensorModule::commit_merkle_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                mecid,
+                root,
+                2,
+                0
+            ),
+            Error::<Test>::SettingWeightsTooFast
+        );
+
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+        assert_noop!(
+            SubtensorModule::commit_merkle_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                mecid,
+                root,
+                2,
+                0
+            ),
+            Error::<Test>::CommitRevealEnabled
+        );
+    });
+}
+
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::test_commit_reveal_tempo_interval --exact --show-output --nocapture
 #[test]
 fn test_commit_reveal_tempo_interval() {
@@ -4835,6 +5535,41 @@ fn test_commit_weights_rate_limit() {
     });
 }
 
+#[test]
+fn test_timelocked_weights_only() {
+    new_test_ext(1).execute_with(|| {
+        let netuid = NetUid::from(1);
+        let hotkey = U256::from(1);
+        let commit_hash = H256::repeat_byte(1);
+
+        add_network(netuid, 5, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100_000);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_timelocked_weights_only(netuid, true);
+
+        // Hash commits would need a reveal transaction
+        assert_noop!(
+            SubtensorModule::commit_weights(RuntimeOrigin::signed(hotkey), netuid, commit_hash),
+            Error::<Test>::TimelockedWeightsRequired
+        );
+        assert_ok!(SubtensorModule::do_commit_timelocked_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            vec![1; 32].try_into().unwrap(),
+            1000,
+            SubtensorModule::get_commit_reveal_weights_version()
+        ));
+
+        SubtensorModule::set_timelocked_weights_only(netuid, false);
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hash
+        ));
+    });
+}
+
 // SKIP_WASM_BUILD=1 RUST_LOG=debug cargo test --package pallet-subtensor --lib -- tests::weights::tlock_encrypt_decrypt_drand_quicknet_works --exact --show-output --nocapture
 #[test]
 pub fn tlock_encrypt_decrypt_drand_quicknet_works() {
@@ -45,8 +45,21 @@ where
         }
     }
 
+    /// Checks the stake of the hotkey `who` sets weights for, itself or the validator it operates
+    /// for.
     pub fn check_weights_min_stake(who: &T::AccountId, netuid: NetUid) -> bool {
-        Pallet::<T>::check_weights_min_stake(who, netuid)
+        Pallet::<T>::check_weights_min_stake(
+            &Pallet::<T>::get_weight_setter_hotkey(netuid, who),
+            netuid,
+        )
+    }
+
+    /// Checks the coldkey of the hotkey `who` acts for holds the minimum balance or stake for
+    /// free extrinsics.
+    pub fn check_free_extrinsic_min_balance(who: &T::AccountId, netuid: NetUid) -> bool {
+        Pallet::<T>::check_free_extrinsic_min_balance(&Pallet::<T>::get_weight_setter_hotkey(
+            netuid, who,
+        ))
     }
 
     pub fn result_to_validity(result: Result<(), Error<T>>, priority: u64) -> TransactionValidity {
@@ -124,7 +137,8 @@ where
         // Verify ColdkeySwapScheduled map for coldkey
         match call.is_sub_type() {
             // Whitelist
-            Some(Call::schedule_swap_coldkey { .. }) => {}
+            Some(Call::schedule_swap_coldkey { .. })
+            | Some(Call::cancel_scheduled_coldkey_swap { .. }) => {}
             _ => {
                 if ColdkeySwapScheduled::<T>::contains_key(who) {
                     return Err(CustomTransactionError::ColdkeyInSwapSchedule.into());
@@ -148,7 +162,7 @@ where
             }) => {
                 if Self::check_weights_min_stake(who, *netuid) {
                     let provided_hash = Pallet::<T>::get_commit_hash(
-                        who,
+                        &Pallet::<T>::get_weight_setter_hotkey(*netuid, who),
                         NetUidStorageIndex::from(*netuid),
                         uids,
                         values,
@@ -177,6 +191,7 @@ where
                 version_keys,
             }) => {
                 if Self::check_weights_min_stake(who, *netuid) {
+                    let hotkey = Pallet::<T>::get_weight_setter_hotkey(*netuid, who);
                     let num_reveals = uids_list.len();
                     if num_reveals == values_list.len()
                         && num_reveals == salts_list.len()
@@ -185,7 +200,7 @@ where
                         let provided_hashes = (0..num_reveals)
                             .map(|i| {
                                 Pallet::<T>::get_commit_hash(
-                                    who,
+                                    &hotkey,
                                     NetUidStorageIndex::from(*netuid),
                                     uids_list.get(i).unwrap_or(&Vec::new()),
                                     values_list.get(i).unwrap_or(&Vec::new()),
@@ -218,6 +233,22 @@ where
                 }
             }
             Some(Call::set_weights { netuid, .. }) => {
+                if !Self::check_free_extrinsic_min_balance(who, *netuid) {
+                    return Err(CustomTransactionError::BalanceTooLow.into());
+                }
+                if Self::check_weights_min_stake(who, *netuid) {
+                    Ok((Default::default(), Some(who.clone()), origin))
+                } else {
+                    Err(CustomTransactionError::StakeAmountTooLow.into())
+                }
+            }
+            Some(
+                Call::settle_weight_commits { netuid, .. }
+                | Call::commit_merkle_weights { netuid, .. }
+                | Call::submit_merkle_weights { netuid, .. }
+                | Call::heartbeat { netuid }
+                | Call::set_no_weights { netuid },
+            ) => {
                 if Self::check_weights_min_stake(who, *netuid) {
                     Ok((Default::default(), Some(who.clone()), origin))
                 } else {
@@ -261,6 +292,9 @@ where
                 placeholder1,
                 placeholder2,
             }) => {
+                if !Pallet::<T>::check_free_extrinsic_min_balance(who) {
+                    return Err(CustomTransactionError::BalanceTooLow.into());
+                }
                 // Fully validate the user input
                 Self::result_to_validity(
                     Pallet::<T>::validate_serve_axon(
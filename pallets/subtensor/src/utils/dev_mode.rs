@@ -0,0 +1,110 @@
+//! Developer mode.
+//!
+//! Devnet and localnet runtimes are built with the `dev-mode` feature, which enables a set of
+//! extrinsics that take the waiting out of local subnet development: a faucet without proof of
+//! work, instant registration on a subnet, zero rate limits, and running the epoch of a subnet
+//! without waiting for its tempo. Without the feature these extrinsics fail with
+//! `DevModeDisabled`, so they can never change the state of mainnet.
+//!
+//! The helpers only write the hyperparameters a sudo or owner call would write, and the epoch
+//! runs the same path as the coinbase, so a subnet developed against them behaves the same on a
+//! chain without them.
+
+use super::*;
+use frame_system::{ensure_root, ensure_signed};
+use subtensor_runtime_common::{NetUid, TaoCurrency};
+
+/// The most TAO the developer faucet gives out in one call.
+pub const DEV_FAUCET_MAX_AMOUNT: u64 = 1_000_000_000_000;
+
+impl<T: Config> Pallet<T> {
+    /// Whether the runtime is built with the developer extrinsics.
+    pub fn is_dev_mode_enabled() -> bool {
+        cfg!(feature = "dev-mode")
+    }
+
+    fn ensure_dev_mode() -> DispatchResult {
+        ensure!(Self::is_dev_mode_enabled(), Error::<T>::DevModeDisabled);
+        Ok(())
+    }
+
+    /// Mints `amount` to the coldkey `origin`.
+    pub fn do_dev_faucet(origin: T::RuntimeOrigin, amount: TaoCurrency) -> DispatchResult {
+        Self::ensure_dev_mode()?;
+        let coldkey = ensure_signed(origin)?;
+        ensure!(
+            u64::from(amount) <= DEV_FAUCET_MAX_AMOUNT,
+            Error::<T>::DevFaucetAmountTooHigh
+        );
+
+        Self::increase_issuance(amount);
+        Self::add_balance_to_coldkey_account(&coldkey, amount.into());
+        Self::deposit_event(Event::Faucet(coldkey, amount.into()));
+        Ok(())
+    }
+
+    /// Lets any number of neurons register on `netuid` in every block, at its minimum burn and
+    /// difficulty.
+    pub fn do_dev_enable_instant_registration(
+        origin: T::RuntimeOrigin,
+        netuid: NetUid,
+    ) -> DispatchResult {
+        Self::ensure_dev_mode()?;
+        Self::ensure_subnet_owner_or_root(origin, netuid)?;
+        ensure!(
+            !netuid.is_root() && Self::if_subnet_exist(netuid),
+            Error::<T>::SubnetNotExists
+        );
+
+        Self::set_network_registration_allowed(netuid, true);
+        Self::set_network_pow_registration_allowed(netuid, true);
+        Self::set_max_registrations_per_block(netuid, u16::MAX);
+        Self::set_target_registrations_per_interval(netuid, u16::MAX);
+        RegistrationsThisBlock::<T>::remove(netuid);
+        RegistrationsThisInterval::<T>::remove(netuid);
+
+        // Pinning the maximum burn to the minimum keeps the burn adjustment from raising it
+        let min_burn = Self::get_min_burn(netuid);
+        Self::set_burn(netuid, min_burn);
+        Self::set_max_burn(netuid, min_burn);
+        Self::set_difficulty(netuid, Self::get_min_difficulty(netuid));
+
+        Self::deposit_event(Event::DevInstantRegistrationEnabled { netuid });
+        Ok(())
+    }
+
+    /// Sets every rate limit of the chain, and those of `netuid`, to zero.
+    pub fn do_dev_disable_rate_limits(origin: T::RuntimeOrigin, netuid: NetUid) -> DispatchResult {
+        Self::ensure_dev_mode()?;
+        ensure_root(origin)?;
+        ensure!(Self::if_subnet_exist(netuid), Error::<T>::SubnetNotExists);
+
+        Self::set_tx_rate_limit(0);
+        Self::set_tx_delegate_take_rate_limit(0);
+        Self::set_tx_childkey_take_rate_limit(0);
+        Self::set_network_rate_limit(0);
+        Self::set_owner_hyperparam_rate_limit(0);
+        Self::set_admin_freeze_window(0);
+        WeightsVersionKeyRateLimit::<T>::put(0);
+        Self::set_weights_set_rate_limit(netuid, 0);
+        Self::set_serving_rate_limit(netuid, 0);
+
+        Self::deposit_event(Event::DevRateLimitsDisabled { netuid });
+        Ok(())
+    }
+
+    /// Runs the epoch of `netuid` in the current block, as if its tempo had elapsed.
+    pub fn do_dev_advance_tempo(origin: T::RuntimeOrigin, netuid: NetUid) -> DispatchResult {
+        Self::ensure_dev_mode()?;
+        Self::ensure_subnet_owner_or_root(origin, netuid)?;
+        ensure!(
+            !netuid.is_root() && Self::if_subnet_exist(netuid),
+            Error::<T>::SubnetNotExists
+        );
+
+        Self::run_subnet_step(netuid, Self::get_current_block_as_u64());
+
+        Self::deposit_event(Event::DevTempoAdvanced { netuid });
+        Ok(())
+    }
+}
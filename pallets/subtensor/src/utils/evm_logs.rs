@@ -0,0 +1,179 @@
+//! EVM logs of native events.
+//!
+//! Contracts and indexers on the EVM side only see the logs of EVM transactions, so they cannot
+//! react to stake moved, subnets dissolved or neurons registered by extrinsics. Alongside the
+//! native events, the pallet collects a log for the hotkeys that have an EVM address associated
+//! with `associate_evm_key` in `PendingEvmLogs`, and hands the logs of the block to
+//! `Config::EvmLogInterface` on finalize, for the runtime to deposit them as EVM log events. At
+//! most `MAX_EVM_LOGS_PER_BLOCK` logs are kept per block, the logs past it are dropped:
+//!
+//! ```text
+//! StakeAdded(address indexed evmAddress, uint16 indexed netuid, bytes32 coldkey, uint256 tao, uint256 alpha)
+//! StakeRemoved(address indexed evmAddress, uint16 indexed netuid, bytes32 coldkey, uint256 tao, uint256 alpha)
+//! DissolvePayout(address indexed evmAddress, uint16 indexed netuid, bytes32 coldkey, uint256 tao)
+//! NeuronRegistered(address indexed evmAddress, uint16 indexed netuid, uint16 uid)
+//! ```
+//!
+//! The address of a hotkey is the one associated on the subnet of the event. A neuron has no
+//! associated address on the subnet it registers on: `associate_evm_key` needs the uid the
+//! registration assigns, and the address associated with a replaced uid is removed with the
+//! neuron it belonged to. Its registration is logged for the addresses the hotkey has associated
+//! on the other subnets it is registered on instead.
+
+use super::*;
+use crate::subnets::settlement::DissolutionSettlement;
+use sp_core::{H160, H256, hashing::keccak_256};
+use sp_std::vec::Vec;
+use subtensor_runtime_common::{AlphaCurrency, NetUid, TaoCurrency};
+
+/// The maximum number of logs of native events deposited in a block.
+pub const MAX_EVM_LOGS_PER_BLOCK: u32 = 1024;
+
+const STAKE_ADDED: &str = "StakeAdded(address,uint16,bytes32,uint256,uint256)";
+const STAKE_REMOVED: &str = "StakeRemoved(address,uint16,bytes32,uint256,uint256)";
+const DISSOLVE_PAYOUT: &str = "DissolvePayout(address,uint16,bytes32,uint256)";
+const NEURON_REGISTERED: &str = "NeuronRegistered(address,uint16,uint16)";
+
+/// The topic of the event with the solidity `signature`.
+pub fn evm_log_topic(signature: &str) -> H256 {
+    H256(keccak_256(signature.as_bytes()))
+}
+
+/// The ABI word of `value`, right aligned.
+fn abi_word(value: u64) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    if let Some(tail) = word.get_mut(24..) {
+        tail.copy_from_slice(&value.to_be_bytes());
+    }
+    word
+}
+
+impl<T: Config> Pallet<T> {
+    /// The EVM address associated with `hotkey` on `netuid`, if any.
+    pub fn get_associated_evm_address(netuid: NetUid, hotkey: &T::AccountId) -> Option<H160> {
+        let uid = Uids::<T>::get(netuid, hotkey)?;
+        AssociatedEvmAddress::<T>::get(netuid, uid).map(|(evm_address, _)| evm_address)
+    }
+
+    /// The ABI word of `account`, its encoding cut or padded to 32 bytes.
+    fn account_word(account: &T::AccountId) -> [u8; 32] {
+        let mut word = [0u8; 32];
+        for (byte, encoded) in word.iter_mut().zip(account.encode()) {
+            *byte = encoded;
+        }
+        word
+    }
+
+    fn deposit_evm_log(signature: &str, evm_address: H160, netuid: NetUid, words: &[[u8; 32]]) {
+        let topics = Vec::from([
+            evm_log_topic(signature),
+            H256::from(evm_address),
+            H256(abi_word(u16::from(netuid).into())),
+        ]);
+        if PendingEvmLogs::<T>::try_append((topics, words.concat())).is_err() {
+            log::debug!("EVM log {signature} dropped, the block has {MAX_EVM_LOGS_PER_BLOCK} logs");
+        }
+    }
+
+    fn deposit_stake_log(
+        signature: &str,
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        netuid: NetUid,
+        tao: TaoCurrency,
+        alpha: AlphaCurrency,
+    ) {
+        if let Some(evm_address) = Self::get_associated_evm_address(netuid, hotkey) {
+            Self::deposit_evm_log(
+                signature,
+                evm_address,
+                netuid,
+                &[
+                    Self::account_word(coldkey),
+                    abi_word(tao.into()),
+                    abi_word(alpha.into()),
+                ],
+            );
+        }
+    }
+
+    /// Logs `StakeAdded` for the address associated with `hotkey` on `netuid`.
+    pub(crate) fn deposit_stake_added_log(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        netuid: NetUid,
+        tao: TaoCurrency,
+        alpha: AlphaCurrency,
+    ) {
+        Self::deposit_stake_log(STAKE_ADDED, coldkey, hotkey, netuid, tao, alpha);
+    }
+
+    /// Logs `StakeRemoved` for the address associated with `hotkey` on `netuid`.
+    pub(crate) fn deposit_stake_removed_log(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        netuid: NetUid,
+        tao: TaoCurrency,
+        alpha: AlphaCurrency,
+    ) {
+        Self::deposit_stake_log(STAKE_REMOVED, coldkey, hotkey, netuid, tao, alpha);
+    }
+
+    /// Logs `DissolvePayout` with the total payout of every coldkey of `settlement`, for the
+    /// addresses associated with the hotkeys it staked to. Must be called before the stakes and
+    /// neurons of the subnet are removed.
+    pub(crate) fn deposit_dissolve_payout_logs(settlement: &DissolutionSettlement<T::AccountId>) {
+        let netuid = settlement.netuid;
+        let mut logged: Vec<(H160, &T::AccountId)> = Vec::new();
+        for (hotkey, coldkey) in settlement.stakes.iter() {
+            let Some(evm_address) = Self::get_associated_evm_address(netuid, hotkey) else {
+                continue;
+            };
+            if logged.contains(&(evm_address, coldkey)) {
+                continue;
+            }
+            logged.push((evm_address, coldkey));
+
+            let tao = settlement
+                .payouts
+                .iter()
+                .filter(|(payee, _)| payee == coldkey)
+                .fold(TaoCurrency::ZERO, |total, (_, share)| {
+                    total.saturating_add(*share)
+                });
+            if tao.is_zero() {
+                continue;
+            }
+            Self::deposit_evm_log(
+                DISSOLVE_PAYOUT,
+                evm_address,
+                netuid,
+                &[Self::account_word(coldkey), abi_word(tao.into())],
+            );
+        }
+    }
+
+    /// Logs `NeuronRegistered` for the addresses `hotkey` associated on the other subnets it is
+    /// registered on, as it has none associated on `netuid` yet.
+    pub(crate) fn deposit_neuron_registered_log(netuid: NetUid, uid: u16, hotkey: &T::AccountId) {
+        let mut logged: Vec<H160> = Vec::new();
+        for (other_netuid, is_member) in IsNetworkMember::<T>::iter_prefix(hotkey) {
+            if !is_member || other_netuid == netuid {
+                continue;
+            }
+            let Some(evm_address) = Self::get_associated_evm_address(other_netuid, hotkey) else {
+                continue;
+            };
+            if logged.contains(&evm_address) {
+                continue;
+            }
+            logged.push(evm_address);
+            Self::deposit_evm_log(
+                NEURON_REGISTERED,
+                evm_address,
+                netuid,
+                &[abi_word(uid.into())],
+            );
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Audit of subnet hyperparameter changes.
+//!
+//! Owner updates of a hyperparameter are rate limited per hyperparameter and subnet, but the
+//! changes themselves are only visible to stakers by diffing storage. Every update of a rate
+//! limited hyperparameter, by the owner, root or an enacted stakeholder vote, deposits a
+//! `HyperparameterChanged` event with the SCALE encoded value of the hyperparameter before and
+//! after the update.
+//!
+//! The value before the update is read when the caller passes the owner checks and handed back
+//! to the call in the `HyperparameterUpdate` it passes to `record_owner_rl`, where the event is
+//! deposited. Updates that leave the value unchanged deposit no event.
+
+use super::*;
+use crate::utils::rate_limiting::{Hyperparameter, TransactionType};
+use subtensor_runtime_common::NetUid;
+
+/// An update of subnet hyperparameters that passed the owner checks.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct HyperparameterUpdate<AccountId> {
+    /// The subnet owner making the update, `None` for root.
+    pub owner: Option<AccountId>,
+    /// The value of the updated hyperparameters before the update.
+    pub(crate) before: Vec<(Hyperparameter, Vec<u8>)>,
+}
+
+impl<T: Config> Pallet<T> {
+    /// The SCALE encoded value of `hyperparameter` on `netuid`.
+    pub fn get_hyperparameter_value(netuid: NetUid, hyperparameter: &Hyperparameter) -> Vec<u8> {
+        match hyperparameter {
+            Hyperparameter::Unknown => Vec::new(),
+            Hyperparameter::ServingRateLimit => ServingRateLimit::<T>::get(netuid).encode(),
+            Hyperparameter::MaxDifficulty => MaxDifficulty::<T>::get(netuid).encode(),
+            Hyperparameter::AdjustmentAlpha => AdjustmentAlpha::<T>::get(netuid).encode(),
+            Hyperparameter::MaxWeightLimit => MaxWeightsLimit::<T>::get(netuid).encode(),
+            Hyperparameter::ImmunityPeriod => ImmunityPeriod::<T>::get(netuid).encode(),
+            Hyperparameter::MinAllowedWeights => MinAllowedWeights::<T>::get(netuid).encode(),
+            Hyperparameter::Kappa => Kappa::<T>::get(netuid).encode(),
+            Hyperparameter::Rho => Rho::<T>::get(netuid).encode(),
+            Hyperparameter::ActivityCutoff => ActivityCutoff::<T>::get(netuid).encode(),
+            Hyperparameter::PowRegistrationAllowed => {
+                NetworkPowRegistrationAllowed::<T>::get(netuid).encode()
+            }
+            Hyperparameter::MinBurn => MinBurn::<T>::get(netuid).encode(),
+            Hyperparameter::MaxBurn => MaxBurn::<T>::get(netuid).encode(),
+            Hyperparameter::BondsMovingAverage => BondsMovingAverage::<T>::get(netuid).encode(),
+            Hyperparameter::BondsPenalty => BondsPenalty::<T>::get(netuid).encode(),
+            Hyperparameter::CommitRevealEnabled => {
+                CommitRevealWeightsEnabled::<T>::get(netuid).encode()
+            }
+            Hyperparameter::LiquidAlphaEnabled => LiquidAlphaOn::<T>::get(netuid).encode(),
+            Hyperparameter::AlphaValues => AlphaValues::<T>::get(netuid).encode(),
+            Hyperparameter::WeightCommitInterval => RevealPeriodEpochs::<T>::get(netuid).encode(),
+            Hyperparameter::TransferEnabled => TransferToggle::<T>::get(netuid).encode(),
+            Hyperparameter::AlphaSigmoidSteepness => {
+                AlphaSigmoidSteepness::<T>::get(netuid).encode()
+            }
+            Hyperparameter::Yuma3Enabled => Yuma3On::<T>::get(netuid).encode(),
+            Hyperparameter::BondsResetEnabled => BondsResetOn::<T>::get(netuid).encode(),
+            Hyperparameter::ImmuneNeuronLimit => ImmuneOwnerUidsLimit::<T>::get(netuid).encode(),
+            Hyperparameter::RecycleOrBurn => RecycleOrBurn::<T>::get(netuid).encode(),
+            Hyperparameter::MaxAllowedUids => MaxAllowedUids::<T>::get(netuid).encode(),
+            Hyperparameter::ChurnBurn => (
+                ChurnBurnThreshold::<T>::get(netuid),
+                ChurnBurnWindow::<T>::get(netuid),
+            )
+                .encode(),
+            Hyperparameter::SubnetFeatures => Self::get_subnet_features(netuid).encode(),
+            Hyperparameter::TimelockedWeightsOnly => {
+                TimelockedWeightsOnly::<T>::get(netuid).encode()
+            }
+            Hyperparameter::RevealWindow => RevealWindow::<T>::get(netuid).encode(),
+            Hyperparameter::MerkleWeights => MerkleWeightsEnabled::<T>::get(netuid).encode(),
+            Hyperparameter::SelfWeightsExcluded => SelfWeightsExcluded::<T>::get(netuid).encode(),
+            Hyperparameter::WeightExpiry => WeightExpiryBlocks::<T>::get(netuid).encode(),
+            Hyperparameter::MaxAllowedValidators => MaxAllowedValidators::<T>::get(netuid).encode(),
+            Hyperparameter::ValidatorPermitMinStake => {
+                ValidatorPermitMinStake::<T>::get(netuid).encode()
+            }
+            Hyperparameter::WeightsSetRateLimitOverride => {
+                WeightsSetRateLimitOverride::<T>::iter_prefix(netuid)
+                    .collect::<Vec<(u16, u64)>>()
+                    .encode()
+            }
+            Hyperparameter::AdaptiveMaxWeightLimit => {
+                AdaptiveMaxWeightsLimit::<T>::get(netuid).encode()
+            }
+            Hyperparameter::BurnAuction => BurnAuctionEnabled::<T>::get(netuid).encode(),
+            Hyperparameter::ColdkeyRegistrationQuota => {
+                MaxRegistrationsPerColdkeyPerInterval::<T>::get(netuid).encode()
+            }
+            Hyperparameter::RegistrationQueue => {
+                RegistrationQueueEnabled::<T>::get(netuid).encode()
+            }
+            Hyperparameter::NeuronImmunityOverride => {
+                NeuronImmunityOverride::<T>::iter_prefix(netuid)
+                    .collect::<Vec<(u16, u64)>>()
+                    .encode()
+            }
+            Hyperparameter::RegistrationDeposit => (
+                RegistrationDepositShare::<T>::get(netuid),
+                RegistrationDepositEpochs::<T>::get(netuid),
+            )
+                .encode(),
+            Hyperparameter::MinerEmissionShare => MinerEmissionShare::<T>::get(netuid).encode(),
+            Hyperparameter::OwnerCutVesting => OwnerCutVestingBlocks::<T>::get(netuid).encode(),
+            Hyperparameter::SubnetTreasury => (
+                SubnetTreasuryShare::<T>::get(netuid),
+                SubnetTreasuryApprovalThreshold::<T>::get(netuid),
+            )
+                .encode(),
+            Hyperparameter::HyperparameterVoting => {
+                HyperparameterVotingPeriod::<T>::get(netuid).encode()
+            }
+            Hyperparameter::Tempo => Tempo::<T>::get(netuid).encode(),
+        }
+    }
+
+    /// The value of the hyperparameters updated by `txs` on `netuid`, before the update.
+    pub(crate) fn snapshot_hyperparameters(
+        netuid: NetUid,
+        txs: &[TransactionType],
+    ) -> Vec<(Hyperparameter, Vec<u8>)> {
+        txs.iter()
+            .filter_map(|tx| match tx {
+                TransactionType::OwnerHyperparamUpdate(hyperparameter) => Some((
+                    *hyperparameter,
+                    Self::get_hyperparameter_value(netuid, hyperparameter),
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Deposits a `HyperparameterChanged` event for every hyperparameter of `before` on `netuid`
+    /// whose value differs from the one it had before the update.
+    pub(crate) fn deposit_hyperparameter_changes(
+        netuid: NetUid,
+        before: Vec<(Hyperparameter, Vec<u8>)>,
+    ) {
+        for (hyperparameter, old) in before {
+            let new = Self::get_hyperparameter_value(netuid, &hyperparameter);
+            if old != new {
+                Self::deposit_event(Event::HyperparameterChanged {
+                    netuid,
+                    param: hyperparameter,
+                    old,
+                    new,
+                });
+            }
+        }
+    }
+}
@@ -3,6 +3,7 @@ use crate::Error;
 use crate::system::{
     ensure_root, ensure_signed, ensure_signed_or_root, pallet_prelude::BlockNumberFor,
 };
+use crate::utils::hyperparameter_audit::HyperparameterUpdate;
 use safe_math::*;
 use sp_core::Get;
 use sp_core::U256;
@@ -10,6 +11,9 @@ use sp_runtime::Saturating;
 use substrate_fixed::types::{I32F32, U96F32};
 use subtensor_runtime_common::{AlphaCurrency, NetUid, NetUidStorageIndex, TaoCurrency};
 
+/// The largest bonds moving average a subnet owner may set, root may set any.
+pub const MAX_OWNER_BONDS_MOVING_AVERAGE: u64 = 975_000;
+
 impl<T: Config> Pallet<T> {
     pub fn ensure_subnet_owner_or_root(
         o: T::RuntimeOrigin,
@@ -51,15 +55,19 @@ impl<T: Config> Pallet<T> {
     /// - Root: only freeze window is enforced; no TransactionType checks.
     /// - Owner (Signed): freeze window plus all rate checks in `limits` using signer extracted from
     ///   origin.
+    ///
+    /// Returns the update, with the value of the updated hyperparameters before it, to pass to
+    /// `record_owner_rl` once it is made.
     pub fn ensure_sn_owner_or_root_with_limits(
         o: T::RuntimeOrigin,
         netuid: NetUid,
         limits: &[crate::utils::rate_limiting::TransactionType],
-    ) -> Result<Option<T::AccountId>, DispatchError> {
+    ) -> Result<HyperparameterUpdate<T::AccountId>, DispatchError> {
         let maybe_who = Self::ensure_subnet_owner_or_root(o, netuid)?;
         let now = Self::get_current_block_as_u64();
         Self::ensure_not_in_admin_freeze_window(netuid, now)?;
         if let Some(who) = maybe_who.as_ref() {
+            Self::ensure_not_voted(netuid, limits)?;
             for tx in limits.iter() {
                 ensure!(
                     tx.passes_rate_limit_on_subnet::<T>(who, netuid),
@@ -67,7 +75,10 @@ impl<T: Config> Pallet<T> {
                 );
             }
         }
-        Ok(maybe_who)
+        Ok(HyperparameterUpdate {
+            owner: maybe_who,
+            before: Self::snapshot_hyperparameters(netuid, limits),
+        })
     }
 
     /// Ensure the caller is the subnet owner and passes all provided rate limits.
@@ -81,6 +92,7 @@ impl<T: Config> Pallet<T> {
         let who = Self::ensure_subnet_owner(o, netuid)?;
         let now = Self::get_current_block_as_u64();
         Self::ensure_not_in_admin_freeze_window(netuid, now)?;
+        Self::ensure_not_voted(netuid, limits)?;
         for tx in limits.iter() {
             ensure!(
                 tx.passes_rate_limit_on_subnet::<T>(&who, netuid),
@@ -90,6 +102,26 @@ impl<T: Config> Pallet<T> {
         Ok(who)
     }
 
+    /// Ensures none of the hyperparameters updated by `limits` is only changed by a vote of the
+    /// stakeholders of `netuid`.
+    fn ensure_not_voted(
+        netuid: NetUid,
+        limits: &[crate::utils::rate_limiting::TransactionType],
+    ) -> Result<(), Error<T>> {
+        for tx in limits.iter() {
+            if let crate::utils::rate_limiting::TransactionType::OwnerHyperparamUpdate(
+                hyperparameter,
+            ) = tx
+            {
+                ensure!(
+                    !Self::is_hyperparameter_voted(netuid, hyperparameter),
+                    Error::<T>::HyperparameterRequiresVote
+                );
+            }
+        }
+        Ok(())
+    }
+
     /// Returns true if the current block is within the terminal freeze window of the tempo for the
     /// given subnet. During this window, admin ops are prohibited to avoid interference with
     /// validator weight submissions.
@@ -121,13 +153,16 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::OwnerHyperparamRateLimitSet(epochs));
     }
 
-    /// If owner is `Some`, record last-blocks for the provided `TransactionType`s.
+    /// If the update is made by the owner, record last-blocks for the provided
+    /// `TransactionType`s. Deposits a `HyperparameterChanged` event for every hyperparameter the
+    /// update changed.
     pub fn record_owner_rl(
-        maybe_owner: Option<<T as frame_system::Config>::AccountId>,
+        update: HyperparameterUpdate<<T as frame_system::Config>::AccountId>,
         netuid: NetUid,
         txs: &[TransactionType],
     ) {
-        if let Some(who) = maybe_owner {
+        Self::deposit_hyperparameter_changes(netuid, update.before);
+        if let Some(who) = update.owner {
             let now = Self::get_current_block_as_u64();
             for tx in txs {
                 tx.set_last_block_on_subnet::<T>(&who, netuid, now);
@@ -327,6 +362,13 @@ impl<T: Config> Pallet<T> {
     pub fn get_stake_threshold() -> u64 {
         StakeThreshold::<T>::get()
     }
+    pub fn get_free_extrinsic_min_balance() -> TaoCurrency {
+        FreeExtrinsicMinBalance::<T>::get()
+    }
+    pub fn set_free_extrinsic_min_balance(min_balance: TaoCurrency) {
+        FreeExtrinsicMinBalance::<T>::put(min_balance);
+        Self::deposit_event(Event::FreeExtrinsicMinBalanceSet(min_balance));
+    }
 
     // ============================
     // ==== Subnetwork Getters ====
@@ -519,6 +561,15 @@ impl<T: Config> Pallet<T> {
             weights_set_rate_limit,
         ));
     }
+    /// The weights set rate limit of `uid`, its override if it has one, or else the subnet's.
+    pub fn get_weights_set_rate_limit_for_uid(netuid: NetUid, uid: u16) -> u64 {
+        WeightsSetRateLimitOverride::<T>::get(netuid, uid)
+            .unwrap_or_else(|| Self::get_weights_set_rate_limit(netuid))
+    }
+    pub fn set_weights_set_rate_limit_override(netuid: NetUid, uid: u16, limit: Option<u64>) {
+        WeightsSetRateLimitOverride::<T>::set(netuid, uid, limit);
+        Self::deposit_event(Event::WeightsSetRateLimitOverrideSet { netuid, uid, limit });
+    }
 
     pub fn get_adjustment_interval(netuid: NetUid) -> u16 {
         AdjustmentInterval::<T>::get(netuid)
@@ -556,6 +607,20 @@ impl<T: Config> Pallet<T> {
         MaxWeightsLimit::<T>::insert(netuid, max_weight_limit);
         Self::deposit_event(Event::MaxWeightLimitSet(netuid, max_weight_limit));
     }
+    pub fn get_burn_auction_enabled(netuid: NetUid) -> bool {
+        BurnAuctionEnabled::<T>::get(netuid)
+    }
+    pub fn set_burn_auction_enabled(netuid: NetUid, enabled: bool) {
+        BurnAuctionEnabled::<T>::insert(netuid, enabled);
+        Self::deposit_event(Event::BurnAuctionEnabledSet(netuid, enabled));
+    }
+    pub fn get_adaptive_max_weight_limit(netuid: NetUid) -> u16 {
+        AdaptiveMaxWeightsLimit::<T>::get(netuid)
+    }
+    pub fn set_adaptive_max_weight_limit(netuid: NetUid, multiple: u16) {
+        AdaptiveMaxWeightsLimit::<T>::insert(netuid, multiple);
+        Self::deposit_event(Event::AdaptiveMaxWeightLimitSet(netuid, multiple));
+    }
 
     pub fn get_immunity_period(netuid: NetUid) -> u16 {
         ImmunityPeriod::<T>::get(netuid)
@@ -564,12 +629,48 @@ impl<T: Config> Pallet<T> {
         ImmunityPeriod::<T>::insert(netuid, immunity_period);
         Self::deposit_event(Event::ImmunityPeriodSet(netuid, immunity_period));
     }
+    /// The immunity period of `uid`, its override if it has a longer one, or else the subnet's.
+    pub fn get_immunity_period_for_uid(netuid: NetUid, uid: u16) -> u64 {
+        let immunity_period = u64::from(Self::get_immunity_period(netuid));
+        NeuronImmunityOverride::<T>::get(netuid, uid)
+            .map_or(immunity_period, |period| period.max(immunity_period))
+    }
+    /// Overrides the immunity period of `uid` with a longer one, or removes the override with
+    /// `None`. At most `MaxNeuronImmunityOverrides` uids of a subnet have an override.
+    pub fn set_neuron_immunity_override(
+        netuid: NetUid,
+        uid: u16,
+        immunity_period: Option<u64>,
+    ) -> DispatchResult {
+        if let Some(period) = immunity_period {
+            ensure!(
+                period > u64::from(Self::get_immunity_period(netuid)),
+                Error::<T>::InvalidValue
+            );
+            let overrides = NeuronImmunityOverride::<T>::iter_key_prefix(netuid)
+                .filter(|other| *other != uid)
+                .count();
+            ensure!(
+                u32::try_from(overrides).unwrap_or(u32::MAX)
+                    < MaxNeuronImmunityOverrides::<T>::get(),
+                Error::<T>::TooManyNeuronImmunityOverrides
+            );
+        }
+
+        NeuronImmunityOverride::<T>::set(netuid, uid, immunity_period);
+        Self::deposit_event(Event::NeuronImmunityOverrideSet {
+            netuid,
+            uid,
+            immunity_period,
+        });
+        Ok(())
+    }
     /// Check if a neuron is in immunity based on the current block
     pub fn get_neuron_is_immune(netuid: NetUid, uid: u16) -> bool {
         let registered_at = Self::get_neuron_block_at_registration(netuid, uid);
         let current_block = Self::get_current_block_as_u64();
-        let immunity_period = Self::get_immunity_period(netuid);
-        current_block.saturating_sub(registered_at) < u64::from(immunity_period)
+        let immunity_period = Self::get_immunity_period_for_uid(netuid, uid);
+        current_block.saturating_sub(registered_at) < immunity_period
     }
 
     pub fn get_min_allowed_weights(netuid: NetUid) -> u16 {
@@ -610,6 +711,34 @@ impl<T: Config> Pallet<T> {
         CommitRevealWeightsEnabled::<T>::set(netuid, enabled);
         Self::deposit_event(Event::CommitRevealEnabled(netuid, enabled));
     }
+    pub fn get_timelocked_weights_only(netuid: NetUid) -> bool {
+        TimelockedWeightsOnly::<T>::get(netuid)
+    }
+    pub fn set_timelocked_weights_only(netuid: NetUid, enabled: bool) {
+        TimelockedWeightsOnly::<T>::set(netuid, enabled);
+        Self::deposit_event(Event::TimelockedWeightsOnlySet(netuid, enabled));
+    }
+    pub fn get_merkle_weights_enabled(netuid: NetUid) -> bool {
+        MerkleWeightsEnabled::<T>::get(netuid)
+    }
+    pub fn set_merkle_weights_enabled(netuid: NetUid, enabled: bool) {
+        MerkleWeightsEnabled::<T>::set(netuid, enabled);
+        Self::deposit_event(Event::MerkleWeightsEnabledSet(netuid, enabled));
+    }
+    pub fn get_self_weights_excluded(netuid: NetUid) -> bool {
+        SelfWeightsExcluded::<T>::get(netuid)
+    }
+    pub fn set_self_weights_excluded(netuid: NetUid, excluded: bool) {
+        SelfWeightsExcluded::<T>::set(netuid, excluded);
+        Self::deposit_event(Event::SelfWeightsExcludedSet(netuid, excluded));
+    }
+    pub fn get_weight_expiry_blocks(netuid: NetUid) -> u64 {
+        WeightExpiryBlocks::<T>::get(netuid)
+    }
+    pub fn set_weight_expiry_blocks(netuid: NetUid, blocks: u64) {
+        WeightExpiryBlocks::<T>::set(netuid, blocks);
+        Self::deposit_event(Event::WeightExpiryBlocksSet(netuid, blocks));
+    }
     pub fn get_commit_reveal_weights_version() -> u16 {
         CommitRevealWeightsVersion::<T>::get()
     }
@@ -704,6 +833,13 @@ impl<T: Config> Pallet<T> {
             max_allowed_validators,
         ));
     }
+    pub fn get_validator_permit_min_stake(netuid: NetUid) -> u64 {
+        ValidatorPermitMinStake::<T>::get(netuid)
+    }
+    pub fn set_validator_permit_min_stake(netuid: NetUid, min_stake: u64) {
+        ValidatorPermitMinStake::<T>::insert(netuid, min_stake);
+        Self::deposit_event(Event::ValidatorPermitMinStakeSet(netuid, min_stake));
+    }
 
     pub fn get_bonds_moving_average(netuid: NetUid) -> u64 {
         BondsMovingAverage::<T>::get(netuid)
@@ -743,6 +879,35 @@ impl<T: Config> Pallet<T> {
     pub fn get_subnet_owner(netuid: NetUid) -> T::AccountId {
         SubnetOwner::<T>::get(netuid)
     }
+    /// The subnets owned by `coldkey`, in the order it came to own them.
+    pub fn get_subnets_owned_by(coldkey: &T::AccountId) -> Vec<NetUid> {
+        SubnetsOwnedBy::<T>::get(coldkey)
+    }
+    /// Sets the owner coldkey of `netuid`, moving the subnet between the `SubnetsOwnedBy` lists
+    /// of its previous and new owner.
+    pub fn set_subnet_owner(netuid: NetUid, coldkey: &T::AccountId) {
+        Self::remove_subnet_owner(netuid);
+        SubnetOwner::<T>::insert(netuid, coldkey.clone());
+        SubnetsOwnedBy::<T>::mutate(coldkey, |netuids| {
+            if !netuids.contains(&netuid) {
+                netuids.push(netuid);
+            }
+        });
+    }
+    /// Removes the owner of `netuid`, and the subnet from the `SubnetsOwnedBy` list of the
+    /// owner. Returns the removed owner.
+    pub fn remove_subnet_owner(netuid: NetUid) -> T::AccountId {
+        let owner = SubnetOwner::<T>::take(netuid);
+        SubnetsOwnedBy::<T>::mutate_exists(&owner, |maybe_netuids| {
+            if let Some(netuids) = maybe_netuids {
+                netuids.retain(|owned| *owned != netuid);
+                if netuids.is_empty() {
+                    *maybe_netuids = None;
+                }
+            }
+        });
+        owner
+    }
     pub fn get_subnet_owner_cut() -> u16 {
         SubnetOwnerCut::<T>::get()
     }
@@ -830,6 +995,42 @@ impl<T: Config> Pallet<T> {
         AlphaValues::<T>::get(netuid)
     }
 
+    /// Sets the liquid alpha values of `netuid`, which must lie within `LiquidAlphaBounds`.
+    pub fn set_alpha_values(netuid: NetUid, alpha_low: u16, alpha_high: u16) -> DispatchResult {
+        Self::ensure_alpha_values_in_bounds(alpha_low, alpha_high)?;
+        AlphaValues::<T>::insert(netuid, (alpha_low, alpha_high));
+        Self::deposit_event(Event::AlphaValuesSet(netuid, alpha_low, alpha_high));
+        Ok(())
+    }
+
+    pub fn ensure_alpha_values_in_bounds(alpha_low: u16, alpha_high: u16) -> DispatchResult {
+        let (min_alpha, max_alpha) = LiquidAlphaBounds::<T>::get();
+        ensure!(alpha_high >= min_alpha, Error::<T>::AlphaHighTooLow);
+        ensure!(alpha_high <= max_alpha, Error::<T>::AlphaHighTooHigh);
+        ensure!(
+            alpha_low >= min_alpha && alpha_low <= alpha_high,
+            Error::<T>::AlphaLowOutOfRange
+        );
+        Ok(())
+    }
+
+    /// Sets the bounds within which subnet owners set their liquid alpha values. Values already
+    /// set outside of them are kept.
+    pub fn set_liquid_alpha_bounds(min_alpha: u16, max_alpha: u16) -> DispatchResult {
+        ensure!(
+            min_alpha > 0 && min_alpha <= max_alpha,
+            Error::<T>::InvalidLiquidAlphaBounds
+        );
+
+        LiquidAlphaBounds::<T>::put((min_alpha, max_alpha));
+
+        Self::deposit_event(Event::LiquidAlphaBoundsSet {
+            min_alpha,
+            max_alpha,
+        });
+        Ok(())
+    }
+
     pub fn set_alpha_values_32(netuid: NetUid, low: I32F32, high: I32F32) {
         let low =
             (low.saturating_mul(I32F32::saturating_from_num(u16::MAX))).saturating_to_num::<u16>();
@@ -858,6 +1059,7 @@ impl<T: Config> Pallet<T> {
 
     pub fn set_liquid_alpha_enabled(netuid: NetUid, enabled: bool) {
         LiquidAlphaOn::<T>::set(netuid, enabled);
+        Self::deposit_event(Event::LiquidAlphaEnabledSet(netuid, enabled));
     }
 
     pub fn get_liquid_alpha_enabled(netuid: NetUid) -> bool {
@@ -1,7 +1,13 @@
 use super::*;
+pub mod dev_mode;
 pub mod evm;
+pub mod evm_logs;
+pub mod hyperparameter_audit;
 pub mod identity;
 pub mod misc;
 pub mod rate_limiting;
+pub mod reserved_accounts;
+pub mod scheduled_operations;
+pub mod trailing_window;
 #[cfg(feature = "try-runtime")]
 pub mod try_state;
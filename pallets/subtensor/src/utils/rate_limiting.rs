@@ -1,3 +1,4 @@
+use codec::DecodeWithMemTracking;
 use subtensor_runtime_common::NetUid;
 
 use super::*;
@@ -16,6 +17,7 @@ pub enum TransactionType {
     MechanismCountUpdate,
     MechanismEmission,
     MaxUidsTrimming,
+    BondsReset,
 }
 
 impl TransactionType {
@@ -38,8 +40,15 @@ impl TransactionType {
         match self {
             Self::SetWeightsVersionKey => (Tempo::<T>::get(netuid) as u64)
                 .saturating_mul(WeightsVersionKeyRateLimit::<T>::get()),
-            // Owner hyperparameter updates are rate-limited by N tempos on the subnet (sudo configurable)
-            Self::OwnerHyperparamUpdate(_) => {
+            // Owner hyperparameter updates are rate-limited by N tempos on the subnet, per group of
+            // hyperparameters (sudo configurable)
+            Self::OwnerHyperparamUpdate(hparam) => {
+                let epochs =
+                    Pallet::<T>::get_owner_hyperparam_group_rate_limit(netuid, hparam.group())
+                        as u64;
+                (Tempo::<T>::get(netuid) as u64).saturating_mul(epochs)
+            }
+            Self::BondsReset => {
                 let epochs = OwnerHyperparamRateLimit::<T>::get() as u64;
                 (Tempo::<T>::get(netuid) as u64).saturating_mul(epochs)
             }
@@ -141,6 +150,7 @@ impl From<TransactionType> for u16 {
             TransactionType::MechanismCountUpdate => 7,
             TransactionType::MechanismEmission => 8,
             TransactionType::MaxUidsTrimming => 9,
+            TransactionType::BondsReset => 10,
         }
     }
 }
@@ -158,6 +168,7 @@ impl From<u16> for TransactionType {
             7 => TransactionType::MechanismCountUpdate,
             8 => TransactionType::MechanismEmission,
             9 => TransactionType::MaxUidsTrimming,
+            10 => TransactionType::BondsReset,
             _ => TransactionType::Unknown,
         }
     }
@@ -169,7 +180,7 @@ impl From<Hyperparameter> for TransactionType {
     }
 }
 
-#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo)]
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, Debug, TypeInfo)]
 #[non_exhaustive]
 pub enum Hyperparameter {
     Unknown = 0,
@@ -198,6 +209,88 @@ pub enum Hyperparameter {
     ImmuneNeuronLimit = 23,
     RecycleOrBurn = 24,
     MaxAllowedUids = 25,
+    ChurnBurn = 26,
+    SubnetFeatures = 27,
+    TimelockedWeightsOnly = 28,
+    RevealWindow = 29,
+    MerkleWeights = 30,
+    SelfWeightsExcluded = 31,
+    WeightExpiry = 32,
+    MaxAllowedValidators = 33,
+    ValidatorPermitMinStake = 34,
+    WeightsSetRateLimitOverride = 35,
+    AdaptiveMaxWeightLimit = 36,
+    BurnAuction = 37,
+    ColdkeyRegistrationQuota = 38,
+    RegistrationQueue = 39,
+    NeuronImmunityOverride = 40,
+    RegistrationDeposit = 41,
+    MinerEmissionShare = 42,
+    OwnerCutVesting = 43,
+    SubnetTreasury = 44,
+    HyperparameterVoting = 45,
+    Tempo = 46,
+}
+
+impl Hyperparameter {
+    /// The group the owner rate limit window of the hyperparameter is configured by.
+    pub fn group(&self) -> HyperparameterGroup {
+        match self {
+            Self::PowRegistrationAllowed
+            | Self::MinBurn
+            | Self::MaxBurn
+            | Self::MaxDifficulty
+            | Self::AdjustmentAlpha
+            | Self::ImmunityPeriod
+            | Self::ImmuneNeuronLimit
+            | Self::MaxAllowedUids
+            | Self::ChurnBurn
+            | Self::BurnAuction
+            | Self::ColdkeyRegistrationQuota
+            | Self::RegistrationQueue
+            | Self::NeuronImmunityOverride
+            | Self::RegistrationDeposit => HyperparameterGroup::Registration,
+            Self::Kappa
+            | Self::Rho
+            | Self::ActivityCutoff
+            | Self::Yuma3Enabled
+            | Self::SelfWeightsExcluded
+            | Self::MaxAllowedValidators
+            | Self::ValidatorPermitMinStake => HyperparameterGroup::Consensus,
+            Self::BondsMovingAverage
+            | Self::BondsPenalty
+            | Self::BondsResetEnabled
+            | Self::LiquidAlphaEnabled
+            | Self::AlphaValues
+            | Self::AlphaSigmoidSteepness => HyperparameterGroup::Bonds,
+            Self::MaxWeightLimit
+            | Self::MinAllowedWeights
+            | Self::CommitRevealEnabled
+            | Self::WeightCommitInterval
+            | Self::TimelockedWeightsOnly
+            | Self::RevealWindow
+            | Self::MerkleWeights
+            | Self::WeightExpiry
+            | Self::WeightsSetRateLimitOverride
+            | Self::AdaptiveMaxWeightLimit => HyperparameterGroup::Weights,
+            _ => HyperparameterGroup::Subnet,
+        }
+    }
+}
+
+/// Groups of owner hyperparameters, each with its own owner rate limit window.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, Debug, TypeInfo)]
+pub enum HyperparameterGroup {
+    /// Registration cost and neuron slots.
+    Registration,
+    /// Consensus of the epoch.
+    Consensus,
+    /// Bonds and liquid alpha.
+    Bonds,
+    /// Weight setting and commit reveal.
+    Weights,
+    /// Everything else, such as serving, transfers and subnet features.
+    Subnet,
 }
 
 impl<T: Config> Pallet<T> {
@@ -205,6 +298,31 @@ impl<T: Config> Pallet<T> {
     // ==== Rate Limiting =====
     // ========================
 
+    /// The number of tempos owner updates of a hyperparameter of `group` on `netuid` are rate
+    /// limited by, `OwnerHyperparamRateLimit` unless root has set the group apart.
+    pub fn get_owner_hyperparam_group_rate_limit(
+        netuid: NetUid,
+        group: HyperparameterGroup,
+    ) -> u16 {
+        OwnerHyperparamGroupRateLimit::<T>::get(netuid, group)
+            .unwrap_or_else(OwnerHyperparamRateLimit::<T>::get)
+    }
+
+    /// Sets the owner rate limit of the hyperparameters of `group` on `netuid` in tempos, or lets
+    /// it follow `OwnerHyperparamRateLimit` again with `None`.
+    pub fn set_owner_hyperparam_group_rate_limit(
+        netuid: NetUid,
+        group: HyperparameterGroup,
+        epochs: Option<u16>,
+    ) {
+        OwnerHyperparamGroupRateLimit::<T>::set(netuid, group, epochs);
+        Self::deposit_event(Event::OwnerHyperparamGroupRateLimitSet {
+            netuid,
+            group,
+            epochs,
+        });
+    }
+
     pub fn remove_last_tx_block(key: &T::AccountId) {
         Self::remove_rate_limited_last_block(&RateLimitKey::LastTxBlock(key.clone()))
     }
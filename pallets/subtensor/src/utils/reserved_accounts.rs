@@ -0,0 +1,73 @@
+//! Registry of accounts reserved for the protocol.
+//!
+//! Protocol pots such as the treasury, escrow accounts and claims accounts must never be used as
+//! neuron hotkeys, subnet owners or key swap destinations, otherwise they can end up receiving
+//! emissions. Accounts in the registry are rejected by all of those entry points.
+
+use super::*;
+use codec::DecodeWithMemTracking;
+use frame_system::ensure_root;
+
+/// What a reserved account is used for by the protocol.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, Debug, TypeInfo)]
+pub enum ReservedAccountKind {
+    /// Treasury account.
+    Treasury,
+    /// Account holding funds in escrow.
+    Escrow,
+    /// Account holding funds to be claimed.
+    Claims,
+    /// Any other protocol-owned account.
+    Other,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Adds `account` to the reserved account registry, or removes it if `kind` is `None`.
+    ///
+    /// An account that is already registered as a hotkey or owns a subnet cannot be reserved.
+    pub fn do_set_reserved_account(
+        origin: T::RuntimeOrigin,
+        account: T::AccountId,
+        kind: Option<ReservedAccountKind>,
+    ) -> DispatchResult {
+        ensure_root(origin)?;
+
+        match kind {
+            Some(kind) => {
+                ensure!(
+                    !Self::hotkey_account_exists(&account)
+                        && !SubnetOwner::<T>::iter_values().any(|owner| owner == account),
+                    Error::<T>::ReservedAccountInUse
+                );
+                ReservedAccounts::<T>::insert(&account, kind);
+                Self::deposit_event(Event::ReservedAccountAdded { account, kind });
+            }
+            None => {
+                ensure!(
+                    ReservedAccounts::<T>::contains_key(&account),
+                    Error::<T>::AccountNotReserved
+                );
+                ReservedAccounts::<T>::remove(&account);
+                Self::deposit_event(Event::ReservedAccountRemoved { account });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn is_reserved_account(account: &T::AccountId) -> bool {
+        ReservedAccounts::<T>::contains_key(account)
+    }
+
+    pub fn ensure_not_reserved_account(account: &T::AccountId) -> DispatchResult {
+        ensure!(
+            !Self::is_reserved_account(account),
+            Error::<T>::AccountIsReserved
+        );
+        Ok(())
+    }
+
+    pub fn get_reserved_accounts() -> Vec<(T::AccountId, ReservedAccountKind)> {
+        ReservedAccounts::<T>::iter().collect()
+    }
+}
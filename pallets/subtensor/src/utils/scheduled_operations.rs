@@ -0,0 +1,87 @@
+//! Per-account cap on scheduled operations.
+//!
+//! Coldkey swaps, sliced unstakes and unstake timelock decreases are executed or applied at a
+//! later block. Every coldkey may only have `MaxScheduledOperations` of them pending at a time,
+//! so that a single account cannot grow the scheduler agenda or the sliced unstake queue without
+//! bound.
+
+use super::*;
+use crate::staking::sliced_unstake::SlicedUnstakeId;
+use sp_runtime::SaturatedConversion;
+use subtensor_runtime_common::{AlphaCurrency, NetUid};
+
+/// An operation scheduled by a coldkey.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum ScheduledOperation<AccountId> {
+    /// A swap of the coldkey to `new_coldkey`.
+    ColdkeySwap {
+        new_coldkey: AccountId,
+        execution_block: u64,
+    },
+    /// A queued sliced unstake.
+    SlicedUnstake {
+        id: SlicedUnstakeId,
+        hotkey: AccountId,
+        netuid: NetUid,
+        remaining: AlphaCurrency,
+        expires_at: u64,
+    },
+    /// A decrease of the unstake timelock of the coldkey to `blocks`.
+    UnstakeTimelockDecrease { blocks: u64, applies_at: u64 },
+}
+
+impl<T: Config> Pallet<T> {
+    /// The operations `coldkey` has scheduled and that are still pending.
+    pub fn get_scheduled_operations(
+        coldkey: T::AccountId,
+    ) -> Vec<ScheduledOperation<T::AccountId>> {
+        let current_block = Self::get_current_block_as_u64();
+        let mut operations = Vec::new();
+
+        if ColdkeySwapScheduled::<T>::contains_key(&coldkey) {
+            let (execution_block, new_coldkey) = ColdkeySwapScheduled::<T>::get(&coldkey);
+            let execution_block: u64 = execution_block.saturated_into();
+            if execution_block >= current_block {
+                operations.push(ScheduledOperation::ColdkeySwap {
+                    new_coldkey,
+                    execution_block,
+                });
+            }
+        }
+
+        operations.extend(
+            SlicedUnstakes::<T>::iter()
+                .filter(|(_, order)| order.coldkey == coldkey)
+                .map(|(id, order)| ScheduledOperation::SlicedUnstake {
+                    id,
+                    hotkey: order.hotkey,
+                    netuid: order.netuid,
+                    remaining: order.remaining,
+                    expires_at: order.expires_at,
+                }),
+        );
+
+        if let Some((blocks, applies_at)) = PendingUnstakeTimelock::<T>::get(&coldkey) {
+            if applies_at > current_block {
+                operations.push(ScheduledOperation::UnstakeTimelockDecrease { blocks, applies_at });
+            }
+        }
+
+        operations
+    }
+
+    /// Fails if `coldkey` already has the maximum number of scheduled operations pending.
+    pub fn ensure_can_schedule_operation(coldkey: &T::AccountId) -> Result<(), Error<T>> {
+        let scheduled = Self::get_scheduled_operations(coldkey.clone()).len();
+        ensure!(
+            scheduled < MaxScheduledOperations::<T>::get() as usize,
+            Error::<T>::TooManyScheduledOperations
+        );
+        Ok(())
+    }
+
+    pub fn set_max_scheduled_operations(max: u32) {
+        MaxScheduledOperations::<T>::put(max);
+        Self::deposit_event(Event::MaxScheduledOperationsSet(max));
+    }
+}
@@ -0,0 +1,41 @@
+//! Sums over a trailing window of blocks.
+//!
+//! A sum over the last `TRAILING_WINDOW_BLOCKS` blocks is kept as `(start, current, previous)`:
+//! the sum over the window starting at `start` and the sum over the window before it. The
+//! trailing sum is the current window plus the part of the previous window still within the
+//! trailing window, assuming the previous window was summed evenly over its blocks.
+
+use safe_math::*;
+
+/// The number of blocks trailing sums are kept over, a day.
+pub const TRAILING_WINDOW_BLOCKS: u64 = 7200;
+
+/// Moves the windows `(start, current, previous)` forward to the window of `now`.
+pub fn roll_trailing_windows<V: Default>(
+    (start, current, previous): (u64, V, V),
+    now: u64,
+) -> (u64, V, V) {
+    let elapsed = now.saturating_sub(start);
+    if elapsed < TRAILING_WINDOW_BLOCKS {
+        (start, current, previous)
+    } else if elapsed < TRAILING_WINDOW_BLOCKS.saturating_mul(2) {
+        (
+            start.saturating_add(TRAILING_WINDOW_BLOCKS),
+            V::default(),
+            current,
+        )
+    } else {
+        let start = now.saturating_sub(elapsed.checked_rem(TRAILING_WINDOW_BLOCKS).unwrap_or(0));
+        (start, V::default(), V::default())
+    }
+}
+
+/// The sum over the trailing window at `now` of windows rolled to `now`.
+pub fn trailing_window_sum(start: u64, current: u128, previous: u128, now: u64) -> u128 {
+    let remaining = TRAILING_WINDOW_BLOCKS.saturating_sub(now.saturating_sub(start));
+    current.saturating_add(
+        previous
+            .saturating_mul(u128::from(remaining))
+            .safe_div(u128::from(TRAILING_WINDOW_BLOCKS)),
+    )
+}
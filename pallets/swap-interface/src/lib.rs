@@ -1,10 +1,26 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use frame_support::pallet_prelude::*;
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
 use substrate_fixed::types::U96F32;
 use subtensor_runtime_common::{AlphaCurrency, NetUid, TaoCurrency};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    TypeInfo,
+    MaxEncodedLen,
+    Serialize,
+    Deserialize,
+)]
 pub enum OrderType {
     Sell,
     Buy,
@@ -24,8 +40,25 @@ pub trait SwapHandler<AccountId> {
         order_t: OrderType,
         amount: u64,
     ) -> Result<SwapResult, DispatchError>;
+    /// Swaps `amount` Alpha of `origin_netuid` for Alpha of `destination_netuid`, routing
+    /// through TAO in one atomic operation. Fails unless at least `min_amount_out` destination
+    /// Alpha is received. As with `swap`, the caller settles the reserves and stake of both legs.
+    fn swap_alpha_for_alpha(
+        origin_netuid: NetUid,
+        destination_netuid: NetUid,
+        amount: u64,
+        min_amount_out: u64,
+        drop_fees: bool,
+        should_rollback: bool,
+    ) -> Result<MultiHopSwapResult, DispatchError>;
     fn approx_fee_amount(netuid: NetUid, amount: u64) -> u64;
+    /// Worst-case weight a swap that lowers the price adds for the price floor defense of its
+    /// subnet
+    fn price_floor_defense_weight() -> Weight;
     fn current_alpha_price(netuid: NetUid) -> U96F32;
+    /// Time-weighted average Alpha price over the last `window` blocks, `None` if the price
+    /// history is shorter than `window`
+    fn twap_alpha_price(netuid: NetUid, window: u64) -> Option<U96F32>;
     fn max_price() -> u64;
     fn min_price() -> u64;
     fn adjust_protocol_liquidity(
@@ -37,6 +70,9 @@ pub trait SwapHandler<AccountId> {
     fn dissolve_all_liquidity_providers(netuid: NetUid) -> DispatchResult;
     fn toggle_user_liquidity(netuid: NetUid, enabled: bool);
     fn clear_protocol_liquidity(netuid: NetUid) -> DispatchResult;
+    /// Liquidity of the positions of `netuid` locked by its owner and the block the earliest of
+    /// the locks ends at, zero without active locks
+    fn locked_liquidity(netuid: NetUid) -> (u64, u64);
 }
 
 #[derive(Debug, PartialEq)]
@@ -48,3 +84,14 @@ pub struct SwapResult {
     pub tao_reserve_delta: i64,
     pub alpha_reserve_delta: i64,
 }
+
+/// Result of an Alpha to Alpha swap routed through TAO
+#[derive(Debug, PartialEq)]
+pub struct MultiHopSwapResult {
+    /// Alpha sold for TAO on the origin subnet
+    pub sell: SwapResult,
+    /// TAO spent on Alpha on the destination subnet
+    pub buy: SwapResult,
+    /// Fees of both legs, valued in TAO
+    pub fee_paid_tao: u64,
+}
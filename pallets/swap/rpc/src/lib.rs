@@ -12,6 +12,7 @@ use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
 use sp_runtime::traits::Block as BlockT;
 use subtensor_runtime_common::{AlphaCurrency, NetUid, TaoCurrency};
+use subtensor_swap_interface::OrderType;
 
 pub use pallet_subtensor_swap_runtime_api::SwapRuntimeApi;
 
@@ -33,6 +34,23 @@ pub trait SwapRpcApi<BlockHash> {
         alpha: AlphaCurrency,
         at: Option<BlockHash>,
     ) -> RpcResult<Vec<u8>>;
+    #[method(name = "swap_priceCandles")]
+    fn price_candles(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "swap_twapAlphaPrice")]
+    fn twap_alpha_price(
+        &self,
+        netuid: NetUid,
+        window: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<u64>>;
+    #[method(name = "swap_simSwaps")]
+    fn sim_swaps(
+        &self,
+        swaps: Vec<(NetUid, OrderType, u64)>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "swap_getPoolInfo")]
+    fn get_pool_info(&self, netuid: NetUid, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
 }
 
 /// Error type of this RPC api.
@@ -127,4 +145,61 @@ where
             .into()),
         }
     }
+
+    fn price_candles(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.price_candles(at, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => {
+                Err(Error::RuntimeError(format!("Unable to get price candles: {e:?}")).into())
+            }
+        }
+    }
+
+    fn twap_alpha_price(
+        &self,
+        netuid: NetUid,
+        window: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<u64>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.twap_alpha_price(at, netuid, window).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get TWAP alpha price: {e:?}")).into()
+        })
+    }
+
+    fn sim_swaps(
+        &self,
+        swaps: Vec<(NetUid, OrderType, u64)>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.sim_swaps(at, swaps) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!("Unable to simulate swaps: {e:?}")).into()),
+        }
+    }
+    fn get_pool_info(
+        &self,
+        netuid: NetUid,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        match api.get_pool_info(at, netuid) {
+            Ok(result) => Ok(result.encode()),
+            Err(e) => Err(Error::RuntimeError(format!("Unable to get pool info: {e:?}")).into()),
+        }
+    }
 }
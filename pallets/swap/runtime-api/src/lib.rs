@@ -1,8 +1,10 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::pallet_prelude::*;
+use sp_std::vec::Vec;
 use subtensor_macros::freeze_struct;
 use subtensor_runtime_common::{AlphaCurrency, NetUid, TaoCurrency};
+use subtensor_swap_interface::OrderType;
 
 #[freeze_struct("3a4fd213b5de5eb6")]
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
@@ -13,10 +15,55 @@ pub struct SimSwapResult {
     pub alpha_fee: AlphaCurrency,
 }
 
+/// Quote of one leg of a batched swap simulation. Amounts are in the currency paid in and out by
+/// the leg, the price is in rao per one 10^9 Alpha units.
+#[freeze_struct("5edc343dbf0d604")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct SimSwapQuote {
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub fee: u64,
+    pub price_after: u64,
+}
+
+#[freeze_struct("17e8012c31e05807")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct PriceCandle {
+    pub start_block: u64,
+    pub open: u64,
+    pub high: u64,
+    pub low: u64,
+    pub close: u64,
+}
+
+/// State of the pool of a subnet. The price is in rao per one 10^9 Alpha units, the square root
+/// price and the global fees are the bits of `U64F64` values, and the volume is the TAO bought
+/// and sold over the last day.
+#[freeze_struct("1f370512c220716f")]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo)]
+pub struct PoolInfo {
+    pub tao_reserve: TaoCurrency,
+    pub alpha_in: AlphaCurrency,
+    pub alpha_out: AlphaCurrency,
+    pub price: u64,
+    pub v3_initialized: bool,
+    pub current_tick: i32,
+    pub sqrt_price: u128,
+    pub liquidity: u64,
+    pub fee_rate: u16,
+    pub fee_global_tao: u128,
+    pub fee_global_alpha: u128,
+    pub volume_24h: u128,
+}
+
 sp_api::decl_runtime_apis! {
     pub trait SwapRuntimeApi {
         fn current_alpha_price(netuid: NetUid) -> u64;
         fn sim_swap_tao_for_alpha(netuid: NetUid, tao: TaoCurrency) -> SimSwapResult;
         fn sim_swap_alpha_for_tao(netuid: NetUid, alpha: AlphaCurrency) -> SimSwapResult;
+        fn price_candles(netuid: NetUid) -> Vec<PriceCandle>;
+        fn twap_alpha_price(netuid: NetUid, window: u64) -> Option<u64>;
+        fn sim_swaps(swaps: Vec<(NetUid, OrderType, u64)>) -> Vec<Option<SimSwapQuote>>;
+        fn get_pool_info(netuid: NetUid) -> Option<PoolInfo>;
     }
 }
@@ -8,14 +8,20 @@ use frame_benchmarking::v2::*;
 use frame_support::traits::Get;
 use frame_system::RawOrigin;
 use substrate_fixed::types::{I64F64, U64F64};
-use subtensor_runtime_common::NetUid;
+use subtensor_runtime_common::{AlphaCurrency, Currency, NetUid, SubnetInfo, TaoCurrency};
+use subtensor_swap_interface::OrderType;
 
 use crate::{
+    limit_order::LimitOrder,
     pallet::{
-        AlphaSqrtPrice, Call, Config, CurrentLiquidity, CurrentTick, EnabledUserLiquidity, Pallet,
-        Positions, SwapV3Initialized,
+        AlphaSqrtPrice, Call, CandleInterval, Config, CurrentLiquidity, CurrentTick,
+        EnabledUserLiquidity, LimitOrderCount, LimitOrders, LiquidityIncentives,
+        LiquidityProviderWhitelist, Pallet, PositionTransfersEnabled, Positions, PriceFloors,
+        ProtocolFeeRecipient, ProtocolFeesTao, SwapV3Initialized, TickSpacing,
     },
     position::{Position, PositionId},
+    price_floor::PriceFloor,
+    protocol_fee::ProtocolFeeDestination,
     tick::TickIndex,
 };
 
@@ -131,6 +137,16 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn set_candle_interval() {
+        let netuid = NetUid::from(1);
+
+        #[extrinsic_call]
+        set_candle_interval(RawOrigin::Root, netuid.into(), 100);
+
+        assert_eq!(CandleInterval::<T>::get(netuid), 100);
+    }
+
     #[benchmark]
     fn toggle_user_liquidity() {
         let netuid = NetUid::from(101);
@@ -143,5 +159,392 @@ mod benchmarks {
         assert!(EnabledUserLiquidity::<T>::get(netuid));
     }
 
+    #[benchmark]
+    fn collect_fees() {
+        let netuid = NetUid::from(1);
+
+        if !SwapV3Initialized::<T>::get(netuid) {
+            SwapV3Initialized::<T>::insert(netuid, true);
+            AlphaSqrtPrice::<T>::insert(netuid, U64F64::from_num(1));
+            CurrentTick::<T>::insert(netuid, TickIndex::new(0).unwrap());
+            CurrentLiquidity::<T>::insert(netuid, T::MinimumLiquidity::get());
+        }
+
+        let caller: T::AccountId = whitelisted_caller();
+        let hotkey: T::AccountId = account("hotkey", 0, 0);
+        let id = PositionId::from(1u128);
+
+        Positions::<T>::insert(
+            (netuid, caller.clone(), id),
+            Position {
+                id,
+                netuid,
+                tick_low: TickIndex::new(-10000).unwrap(),
+                tick_high: TickIndex::new(10000).unwrap(),
+                liquidity: 10000,
+                fees_tao: I64F64::from_num(0),
+                fees_alpha: I64F64::from_num(0),
+                _phantom: PhantomData,
+            },
+        );
+
+        #[extrinsic_call]
+        collect_fees(RawOrigin::Signed(caller), hotkey, netuid.into(), id.into());
+    }
+
+    #[benchmark]
+    fn decrease_liquidity() {
+        let netuid = NetUid::from(1);
+
+        if !SwapV3Initialized::<T>::get(netuid) {
+            SwapV3Initialized::<T>::insert(netuid, true);
+            AlphaSqrtPrice::<T>::insert(netuid, U64F64::from_num(1));
+            CurrentTick::<T>::insert(netuid, TickIndex::new(0).unwrap());
+            CurrentLiquidity::<T>::insert(netuid, T::MinimumLiquidity::get());
+        }
+
+        let caller: T::AccountId = whitelisted_caller();
+        let hotkey: T::AccountId = account("hotkey", 0, 0);
+        let id = PositionId::from(1u128);
+
+        Positions::<T>::insert(
+            (netuid, caller.clone(), id),
+            Position {
+                id,
+                netuid,
+                tick_low: TickIndex::new(-10000).unwrap(),
+                tick_high: TickIndex::new(10000).unwrap(),
+                liquidity: 10000,
+                fees_tao: I64F64::from_num(0),
+                fees_alpha: I64F64::from_num(0),
+                _phantom: PhantomData,
+            },
+        );
+
+        #[extrinsic_call]
+        decrease_liquidity(
+            RawOrigin::Signed(caller),
+            hotkey,
+            netuid.into(),
+            id.into(),
+            5000,
+        );
+    }
+
+    #[benchmark]
+    fn toggle_position_transfers() {
+        let netuid = NetUid::from(101);
+
+        #[extrinsic_call]
+        toggle_position_transfers(RawOrigin::Root, netuid.into(), false);
+
+        assert!(!PositionTransfersEnabled::<T>::get(netuid));
+    }
+
+    #[benchmark]
+    fn transfer_position() {
+        let netuid = NetUid::from(1);
+
+        let caller: T::AccountId = whitelisted_caller();
+        let dest: T::AccountId = account("dest", 0, 0);
+        let id = PositionId::from(1u128);
+
+        Positions::<T>::insert(
+            (netuid, caller.clone(), id),
+            Position {
+                id,
+                netuid,
+                tick_low: TickIndex::new(-10000).unwrap(),
+                tick_high: TickIndex::new(10000).unwrap(),
+                liquidity: 10000,
+                fees_tao: I64F64::from_num(0),
+                fees_alpha: I64F64::from_num(0),
+                _phantom: PhantomData,
+            },
+        );
+
+        #[extrinsic_call]
+        transfer_position(
+            RawOrigin::Signed(caller),
+            netuid.into(),
+            id.into(),
+            dest.clone(),
+        );
+
+        assert!(Positions::<T>::contains_key((netuid, dest, id)));
+    }
+
+    #[benchmark]
+    fn place_limit_order() {
+        let netuid = NetUid::from(1);
+
+        if !SwapV3Initialized::<T>::get(netuid) {
+            SwapV3Initialized::<T>::insert(netuid, true);
+            AlphaSqrtPrice::<T>::insert(netuid, U64F64::from_num(1));
+            CurrentTick::<T>::insert(netuid, TickIndex::new(0).unwrap());
+            CurrentLiquidity::<T>::insert(netuid, T::MinimumLiquidity::get());
+        }
+        EnabledUserLiquidity::<T>::insert(netuid, true);
+
+        let caller: T::AccountId = whitelisted_caller();
+        let hotkey: T::AccountId = account("hotkey", 0, 0);
+
+        #[extrinsic_call]
+        place_limit_order(
+            RawOrigin::Signed(caller),
+            hotkey,
+            netuid,
+            OrderType::Sell,
+            1000,
+            TickIndex::new_unchecked(100),
+        );
+
+        assert_eq!(LimitOrderCount::<T>::get(netuid), 1);
+    }
+
+    #[benchmark]
+    fn cancel_order() {
+        let netuid = NetUid::from(1);
+
+        if !SwapV3Initialized::<T>::get(netuid) {
+            SwapV3Initialized::<T>::insert(netuid, true);
+            AlphaSqrtPrice::<T>::insert(netuid, U64F64::from_num(1));
+            CurrentTick::<T>::insert(netuid, TickIndex::new(0).unwrap());
+            CurrentLiquidity::<T>::insert(netuid, T::MinimumLiquidity::get());
+        }
+
+        let caller: T::AccountId = whitelisted_caller();
+        let hotkey: T::AccountId = account("hotkey", 0, 0);
+        let id = PositionId::from(1u128);
+        let tick = TickIndex::new(100).unwrap();
+
+        Positions::<T>::insert(
+            (netuid, caller.clone(), id),
+            Position {
+                id,
+                netuid,
+                tick_low: tick,
+                tick_high: tick.next().unwrap(),
+                liquidity: 10000,
+                fees_tao: I64F64::from_num(0),
+                fees_alpha: I64F64::from_num(0),
+                _phantom: PhantomData,
+            },
+        );
+        LimitOrders::<T>::insert(
+            netuid,
+            id,
+            LimitOrder {
+                coldkey: caller.clone(),
+                hotkey,
+                side: OrderType::Sell,
+                tick,
+            },
+        );
+        LimitOrderCount::<T>::insert(netuid, 1);
+
+        #[extrinsic_call]
+        cancel_order(RawOrigin::Signed(caller), netuid, id);
+
+        assert_eq!(LimitOrderCount::<T>::get(netuid), 0);
+    }
+
+    #[benchmark]
+    fn set_protocol_fee() {
+        #[extrinsic_call]
+        set_protocol_fee(
+            RawOrigin::Root,
+            6554,
+            0,
+            u16::MAX,
+            ProtocolFeeDestination::SubnetOwner,
+        );
+    }
+
+    #[benchmark]
+    fn set_subnet_protocol_fee_rate() {
+        let netuid = NetUid::from(1);
+
+        #[extrinsic_call]
+        set_subnet_protocol_fee_rate(RawOrigin::Root, netuid, Some(6554));
+    }
+
+    #[benchmark]
+    fn claim_protocol_fees() {
+        let netuid = NetUid::from(1);
+        let caller: T::AccountId = whitelisted_caller();
+        let hotkey: T::AccountId = account("hotkey", 0, 0);
+
+        ProtocolFeeRecipient::<T>::put(ProtocolFeeDestination::Account {
+            coldkey: caller.clone(),
+            hotkey,
+        });
+        ProtocolFeesTao::<T>::insert(netuid, TaoCurrency::from(1_000_000));
+
+        #[extrinsic_call]
+        claim_protocol_fees(RawOrigin::Signed(caller), netuid);
+
+        assert_eq!(ProtocolFeesTao::<T>::get(netuid), TaoCurrency::ZERO);
+    }
+
+    #[benchmark]
+    fn set_global_fee_rate() {
+        #[extrinsic_call]
+        set_global_fee_rate(RawOrigin::Root, 100, 33, 655);
+    }
+
+    #[benchmark]
+    fn set_tick_spacing() {
+        let netuid = NetUid::from(1);
+
+        #[extrinsic_call]
+        set_tick_spacing(RawOrigin::Root, netuid, 10);
+
+        assert_eq!(TickSpacing::<T>::get(netuid), 10);
+    }
+
+    #[benchmark]
+    fn set_liquidity_provider_whitelisted() {
+        let netuid = NetUid::from(1);
+        let coldkey: T::AccountId = account("coldkey", 0, 0);
+
+        #[extrinsic_call]
+        set_liquidity_provider_whitelisted(RawOrigin::Root, netuid, coldkey.clone(), true);
+
+        assert!(LiquidityProviderWhitelist::<T>::contains_key(netuid, coldkey));
+    }
+
+    #[benchmark]
+    fn fund_liquidity_incentive() {
+        let netuid = NetUid::from(1);
+        EnabledUserLiquidity::<T>::insert(netuid, true);
+
+        let caller = T::SubnetInfo::owner_coldkey(netuid).unwrap_or_else(whitelisted_caller);
+        let hotkey = T::SubnetInfo::owner_hotkey(netuid).unwrap_or_else(|| account("hotkey", 0, 0));
+
+        #[extrinsic_call]
+        fund_liquidity_incentive(
+            RawOrigin::Signed(caller),
+            hotkey,
+            netuid,
+            TaoCurrency::from(1_000_000),
+            AlphaCurrency::from(1_000_000),
+            7200,
+        );
+
+        assert!(LiquidityIncentives::<T>::contains_key(netuid));
+    }
+
+    #[benchmark]
+    fn add_liquidity_single_sided() {
+        let netuid = NetUid::from(1);
+        EnabledUserLiquidity::<T>::insert(netuid, true);
+
+        if !SwapV3Initialized::<T>::get(netuid) {
+            SwapV3Initialized::<T>::insert(netuid, true);
+            AlphaSqrtPrice::<T>::insert(netuid, U64F64::from_num(1));
+            CurrentTick::<T>::insert(netuid, TickIndex::new(0).unwrap());
+            CurrentLiquidity::<T>::insert(netuid, 1_000_000_000_000_u64);
+        }
+
+        let caller: T::AccountId = whitelisted_caller();
+        let hotkey: T::AccountId = account("hotkey", 0, 0);
+        let tick_low = TickIndex::new_unchecked(-1000);
+        let tick_high = TickIndex::new_unchecked(1000);
+        // Large enough for the split search to run all of its steps
+        let amount = 1_u64 << 40;
+
+        #[extrinsic_call]
+        add_liquidity_single_sided(
+            RawOrigin::Signed(caller),
+            hotkey,
+            netuid,
+            tick_low,
+            tick_high,
+            amount,
+            true,
+            0,
+        );
+
+        assert!(AlphaSqrtPrice::<T>::get(netuid) > U64F64::from_num(1));
+    }
+
+    #[benchmark]
+    fn set_price_floor() {
+        let netuid = NetUid::from(1);
+
+        if !SwapV3Initialized::<T>::get(netuid) {
+            SwapV3Initialized::<T>::insert(netuid, true);
+            AlphaSqrtPrice::<T>::insert(netuid, U64F64::from_num(1));
+            CurrentTick::<T>::insert(netuid, TickIndex::new(0).unwrap());
+            CurrentLiquidity::<T>::insert(netuid, 1_000_000_000_000_u64);
+        }
+
+        let caller = T::SubnetInfo::owner_coldkey(netuid).unwrap_or_else(whitelisted_caller);
+        let hotkey = T::SubnetInfo::owner_hotkey(netuid).unwrap_or_else(|| account("hotkey", 0, 0));
+
+        // A floor above the current price is defended right away
+        #[extrinsic_call]
+        set_price_floor(
+            RawOrigin::Signed(caller),
+            hotkey,
+            netuid,
+            2_000_000_000,
+            TaoCurrency::from(1_000_000),
+        );
+
+        assert!(AlphaSqrtPrice::<T>::get(netuid) > U64F64::from_num(1));
+    }
+
+    #[benchmark]
+    fn remove_price_floor() {
+        let netuid = NetUid::from(1);
+        let caller: T::AccountId = whitelisted_caller();
+
+        PriceFloors::<T>::insert(
+            netuid,
+            PriceFloor {
+                coldkey: caller.clone(),
+                hotkey: account("hotkey", 0, 0),
+                price: 1_000_000_000,
+                reserve: TaoCurrency::from(1_000_000),
+                tao_spent: TaoCurrency::ZERO,
+                alpha_bought: AlphaCurrency::ZERO,
+                paused: false,
+            },
+        );
+
+        #[extrinsic_call]
+        remove_price_floor(RawOrigin::Root, netuid);
+
+        assert!(!PriceFloors::<T>::contains_key(netuid));
+    }
+
+    #[benchmark]
+    fn lock_liquidity() {
+        let netuid = NetUid::from(1);
+        let caller = T::SubnetInfo::owner_coldkey(netuid).unwrap_or_else(whitelisted_caller);
+        let id = PositionId::from(1u128);
+
+        Positions::<T>::insert(
+            (netuid, caller.clone(), id),
+            Position {
+                id,
+                netuid,
+                tick_low: TickIndex::new(-10000).unwrap(),
+                tick_high: TickIndex::new(10000).unwrap(),
+                liquidity: 10000,
+                fees_tao: I64F64::from_num(0),
+                fees_alpha: I64F64::from_num(0),
+                _phantom: PhantomData,
+            },
+        );
+
+        #[extrinsic_call]
+        lock_liquidity(RawOrigin::Signed(caller), netuid, id, 7200);
+
+        assert!(LiquidityLocks::<T>::contains_key(netuid, id));
+    }
+
     impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -0,0 +1,44 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::*;
+use subtensor_macros::freeze_struct;
+
+/// Candle designates the open, high, low and close Alpha price of a subnet over one candle
+/// interval.
+///
+/// Prices are expressed in rao units per one 10^9 Alpha units. For example, price 1_000_000 is
+/// equal to 0.001 TAO per Alpha.
+#[freeze_struct("f4fd0a1fd04f0c1a")]
+#[derive(
+    Clone, Copy, Decode, Default, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo,
+)]
+pub struct Candle {
+    /// First block of the candle interval
+    pub start_block: u64,
+    /// Price before the first swap in the interval
+    pub open: u64,
+    /// Highest price in the interval
+    pub high: u64,
+    /// Lowest price in the interval
+    pub low: u64,
+    /// Price after the last swap in the interval
+    pub close: u64,
+}
+
+impl Candle {
+    pub fn new(start_block: u64, open: u64) -> Self {
+        Self {
+            start_block,
+            open,
+            high: open,
+            low: open,
+            close: open,
+        }
+    }
+
+    /// Include a new price into the candle
+    pub fn update(&mut self, price: u64) {
+        self.high = self.high.max(price);
+        self.low = self.low.min(price);
+        self.close = price;
+    }
+}
@@ -0,0 +1,27 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::*;
+use subtensor_macros::freeze_struct;
+use subtensor_runtime_common::{AlphaCurrency, TaoCurrency};
+
+/// Maximum duration over which a liquidity incentive is streamed, about 30 days.
+pub const MAX_LIQUIDITY_INCENTIVE_DURATION: u64 = 216_000;
+
+/// LiquidityIncentive designates TAO and Alpha deposited by a subnet owner that is streamed to
+/// in-range liquidity once per block.
+///
+/// Every block, the remaining amounts divided by the number of blocks left are added to the global
+/// fee growth of the subnet, so positions earn them pro-rata to their in-range liquidity and
+/// collect them together with swap fees. Blocks without in-range liquidity stream nothing, and
+/// whatever is left at `ends_at` is streamed in the next block with in-range liquidity.
+#[freeze_struct("d9aa83bab2708cdc")]
+#[derive(
+    Clone, Copy, Decode, Default, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo,
+)]
+pub struct LiquidityIncentive {
+    /// TAO left to stream
+    pub tao: TaoCurrency,
+    /// Alpha left to stream
+    pub alpha: AlphaCurrency,
+    /// Last block over which the incentive is streamed
+    pub ends_at: u64,
+}
@@ -3,8 +3,15 @@
 use substrate_fixed::types::U64F64;
 use subtensor_swap_interface::OrderType;
 
+pub mod candle;
+pub mod incentive;
+pub mod limit_order;
+pub mod liquidity_lock;
+pub mod oracle;
 pub mod pallet;
 pub mod position;
+pub mod price_floor;
+pub mod protocol_fee;
 pub mod tick;
 pub mod weights;
 
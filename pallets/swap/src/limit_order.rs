@@ -0,0 +1,43 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::*;
+use subtensor_macros::freeze_struct;
+use subtensor_swap_interface::OrderType;
+
+use crate::SqrtPrice;
+use crate::tick::TickIndex;
+
+/// LimitOrder designates a liquidity position spanning a single tick spacing, which converts
+/// fully from one token to the other once the price crosses the position.
+///
+/// A sell order holds Alpha in a position above the current price and is filled once the price
+/// rises above the position. A buy order holds TAO in a position below the current price and is
+/// filled once the price falls below the position.
+#[freeze_struct("7314ca797905a789")]
+#[derive(Clone, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct LimitOrder<AccountId> {
+    /// The coldkey account that owns the order position
+    pub coldkey: AccountId,
+    /// The hotkey account where Alpha comes from or goes to
+    pub hotkey: AccountId,
+    /// Whether the order sells or buys Alpha
+    pub side: OrderType,
+    /// The lower tick of the order position
+    pub tick: TickIndex,
+}
+
+impl<AccountId> LimitOrder<AccountId> {
+    /// Whether the price has crossed the whole order position
+    pub fn is_filled(&self, current_sqrt_price: SqrtPrice, tick_spacing: u16) -> bool {
+        match self.side {
+            OrderType::Sell => self
+                .tick
+                .checked_add(i32::from(tick_spacing))
+                .and_then(|tick_high| tick_high.try_to_sqrt_price())
+                .is_ok_and(|sqrt_price_high| current_sqrt_price >= sqrt_price_high),
+            OrderType::Buy => self
+                .tick
+                .try_to_sqrt_price()
+                .is_ok_and(|sqrt_price_low| current_sqrt_price <= sqrt_price_low),
+        }
+    }
+}
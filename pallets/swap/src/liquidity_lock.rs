@@ -0,0 +1,30 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::*;
+use subtensor_macros::freeze_struct;
+
+/// Maximum number of blocks a liquidity position can be locked for, about a year.
+pub const MAX_LIQUIDITY_LOCK_DURATION: u64 = 2_628_000;
+
+/// LiquidityLock designates a liquidity position that the subnet owner has committed to keep in
+/// the pool until a declared block.
+///
+/// While the lock is active the liquidity of the position cannot be removed, decreased or
+/// transferred. Fees can still be collected and liquidity added. A lock can only be extended, and
+/// it is dropped together with the position once the position is removed.
+#[freeze_struct("15ed28fd8c059711")]
+#[derive(Clone, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct LiquidityLock<AccountId> {
+    /// The coldkey account that owns the locked position
+    pub coldkey: AccountId,
+    /// Block at which the position was first locked
+    pub locked_at: u64,
+    /// First block at which the liquidity can be withdrawn again
+    pub unlocks_at: u64,
+}
+
+impl<AccountId> LiquidityLock<AccountId> {
+    /// Whether the lock still holds the liquidity at `block`
+    pub fn is_active(&self, block: u64) -> bool {
+        block < self.unlocks_at
+    }
+}
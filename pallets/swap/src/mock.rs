@@ -16,7 +16,7 @@ use sp_runtime::{
 };
 use subtensor_runtime_common::{AlphaCurrency, BalanceOps, NetUid, SubnetInfo, TaoCurrency};
 
-use crate::pallet::EnabledUserLiquidity;
+use crate::{OrderType, pallet::EnabledUserLiquidity, tick::TickIndex};
 
 construct_runtime!(
     pub enum Test {
@@ -81,6 +81,9 @@ parameter_types! {
     pub const MaxPositions: u32 = 100;
     pub const MinimumLiquidity: u64 = 1_000;
     pub const MinimumReserves: NonZeroU64 = NonZeroU64::new(1).unwrap();
+    pub const MaxCandles: u32 = 3;
+    pub const MaxPriceObservations: u32 = 4;
+    pub const MaxLimitOrders: u32 = 8;
 }
 
 // Mock implementor of SubnetInfo trait
@@ -140,6 +143,14 @@ impl SubnetInfo<AccountId> for MockLiquidityProvider {
     fn hotkey_of_uid(_netuid: NetUid, uid: u16) -> Option<AccountId> {
         Some(uid as AccountId)
     }
+
+    fn owner_coldkey(_netuid: NetUid) -> Option<AccountId> {
+        Some(OK_COLDKEY_ACCOUNT_ID)
+    }
+
+    fn owner_hotkey(_netuid: NetUid) -> Option<AccountId> {
+        Some(OK_HOTKEY_ACCOUNT_ID)
+    }
 }
 
 pub struct MockBalanceOps;
@@ -198,10 +209,51 @@ impl BalanceOps<AccountId> for MockBalanceOps {
         Ok(alpha)
     }
 
+    fn ensure_unstake_unlocked(_coldkey: &AccountId) -> DispatchResult {
+        Ok(())
+    }
+
+    fn stake(
+        _coldkey: &AccountId,
+        _hotkey: &AccountId,
+        netuid: NetUid,
+        tao: TaoCurrency,
+    ) -> Result<AlphaCurrency, DispatchError> {
+        let result = Swap::do_swap(
+            netuid,
+            OrderType::Buy,
+            tao.into(),
+            TickIndex::max_sqrt_price(),
+            false,
+            false,
+        )?;
+        Ok(result.amount_paid_out.into())
+    }
+
+    fn unstake(
+        _coldkey: &AccountId,
+        _hotkey: &AccountId,
+        netuid: NetUid,
+        alpha: AlphaCurrency,
+    ) -> Result<TaoCurrency, DispatchError> {
+        let result = Swap::do_swap(
+            netuid,
+            OrderType::Sell,
+            alpha.into(),
+            TickIndex::min_sqrt_price(),
+            false,
+            false,
+        )?;
+        Ok(result.amount_paid_out.into())
+    }
+
     fn increase_provided_tao_reserve(_netuid: NetUid, _tao: TaoCurrency) {}
-    fn decrease_provided_tao_reserve(_netuid: NetUid, _tao: TaoCurrency) {}
+    fn decrease_provided_tao_reserve(_netuid: NetUid, _tao: TaoCurrency) -> DispatchResult {
+        Ok(())
+    }
     fn increase_provided_alpha_reserve(_netuid: NetUid, _alpha: AlphaCurrency) {}
     fn decrease_provided_alpha_reserve(_netuid: NetUid, _alpha: AlphaCurrency) {}
+    fn increase_network_treasury(_tao: TaoCurrency) {}
 }
 
 impl crate::pallet::Config for Test {
@@ -212,6 +264,9 @@ impl crate::pallet::Config for Test {
     type MaxPositions = MaxPositions;
     type MinimumLiquidity = MinimumLiquidity;
     type MinimumReserve = MinimumReserves;
+    type MaxCandles = MaxCandles;
+    type MaxPriceObservations = MaxPriceObservations;
+    type MaxLimitOrders = MaxLimitOrders;
     type WeightInfo = ();
 }
 
@@ -0,0 +1,20 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::*;
+use subtensor_macros::freeze_struct;
+
+/// PriceObservation designates one sample of the cumulative Alpha price of a subnet.
+///
+/// The cumulative price is the sum of the price over every block up to `block`, with the price
+/// expressed in rao units per one 10^9 Alpha units. The time-weighted average price between two
+/// observations is the difference of their cumulative prices divided by the number of blocks
+/// between them.
+#[freeze_struct("44dd80a80d2aa723")]
+#[derive(
+    Clone, Copy, Decode, Default, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo,
+)]
+pub struct PriceObservation {
+    /// Block at which the observation was taken
+    pub block: u64,
+    /// Cumulative price at `block`
+    pub cumulative_price: u128,
+}
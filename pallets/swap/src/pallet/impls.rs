@@ -2,25 +2,40 @@ use core::marker::PhantomData;
 use core::ops::Neg;
 
 use frame_support::storage::{TransactionOutcome, transactional};
-use frame_support::{ensure, pallet_prelude::DispatchError, traits::Get};
+use frame_support::{
+    ensure,
+    pallet_prelude::DispatchError,
+    traits::Get,
+    weights::{Weight, WeightMeter},
+};
 use safe_math::*;
 use sp_arithmetic::helpers_128bit;
-use sp_runtime::{DispatchResult, Vec, traits::AccountIdConversion};
+use sp_runtime::{DispatchResult, SaturatedConversion, Vec, traits::AccountIdConversion};
 use substrate_fixed::types::{I64F64, U64F64, U96F32};
 use subtensor_runtime_common::{
     AlphaCurrency, BalanceOps, Currency, NetUid, SubnetInfo, TaoCurrency,
 };
-use subtensor_swap_interface::{SwapHandler, SwapResult};
+use subtensor_swap_interface::{MultiHopSwapResult, SwapHandler, SwapResult};
 
 use super::pallet::*;
 use crate::{
     OrderType, SqrtPrice,
+    candle::Candle,
+    incentive::{LiquidityIncentive, MAX_LIQUIDITY_INCENTIVE_DURATION},
+    limit_order::LimitOrder,
+    liquidity_lock::{LiquidityLock, MAX_LIQUIDITY_LOCK_DURATION},
+    oracle::PriceObservation,
     position::{Position, PositionId},
+    price_floor::PriceFloor,
     tick::{ActiveTickIndexManager, Tick, TickIndex},
+    weights::WeightInfo,
 };
 
 const MAX_SWAP_ITERATIONS: u16 = 1000;
 
+/// Number of bisection steps used to split a single-sided liquidity deposit
+const SINGLE_SIDED_SEARCH_STEPS: u8 = 32;
+
 #[derive(Debug, PartialEq)]
 pub struct UpdateLiquidityResult {
     pub tao: TaoCurrency,
@@ -310,6 +325,220 @@ impl<T: Config> Pallet<T> {
         AlphaSqrtPrice::<T>::get(netuid)
     }
 
+    /// Current price in rao units per one 10^9 Alpha units
+    pub(super) fn current_price_rao(netuid: NetUid) -> u64 {
+        Self::current_price(netuid)
+            .saturating_mul(U96F32::saturating_from_num(1_000_000_000))
+            .saturating_to_num()
+    }
+
+    /// Simulates a swap without a price limit like `sim_swap`, returning the result together with
+    /// the price in rao the swap would leave the pool at
+    pub fn sim_swap_with_price(
+        netuid: NetUid,
+        order_t: OrderType,
+        amount: u64,
+    ) -> Result<(SwapResult, u64), DispatchError> {
+        if T::SubnetInfo::mechanism(netuid) != 1 {
+            let result = <Self as SwapHandler<T::AccountId>>::sim_swap(netuid, order_t, amount)?;
+            return Ok((result, Self::current_price_rao(netuid)));
+        }
+
+        let price_limit = match order_t {
+            OrderType::Buy => <Self as SwapHandler<T::AccountId>>::max_price(),
+            OrderType::Sell => <Self as SwapHandler<T::AccountId>>::min_price(),
+        };
+        transactional::with_transaction(|| {
+            let result = <Self as SwapHandler<T::AccountId>>::swap(
+                netuid,
+                order_t,
+                amount,
+                price_limit,
+                false,
+                false,
+            )
+            .map(|result| (result, Self::current_price_rao(netuid)));
+            TransactionOutcome::Rollback(result)
+        })
+    }
+
+    /// Includes the current price into the candle of the current interval, opening a new candle
+    /// at `open_price` if the interval has just started
+    fn record_price_candle(netuid: NetUid, open_price: u64) {
+        let current_block: u64 = frame_system::Pallet::<T>::block_number().saturated_into();
+        let interval = CandleInterval::<T>::get(netuid).max(1);
+        let start_block =
+            current_block.saturating_sub(current_block.checked_rem(interval).unwrap_or_default());
+        let price = Self::current_price_rao(netuid);
+
+        let head = CandleHead::<T>::get(netuid);
+        let slot = match Candles::<T>::get(netuid, head) {
+            Some(mut candle) if candle.start_block == start_block => {
+                candle.update(price);
+                Candles::<T>::insert(netuid, head, candle);
+                return;
+            }
+            Some(_) => head
+                .saturating_add(1)
+                .checked_rem(T::MaxCandles::get().max(1))
+                .unwrap_or_default(),
+            None => head,
+        };
+
+        let mut candle = Candle::new(start_block, open_price);
+        candle.update(price);
+        Candles::<T>::insert(netuid, slot, candle);
+        CandleHead::<T>::insert(netuid, slot);
+    }
+
+    /// Price candles of a subnet, oldest first
+    pub fn price_candles(netuid: NetUid) -> Vec<Candle> {
+        // The buffer is full once the slot after the head is taken, otherwise it was filled from
+        // slot 0
+        let max_candles = T::MaxCandles::get().max(1);
+        let head = CandleHead::<T>::get(netuid);
+        let next = head
+            .saturating_add(1)
+            .checked_rem(max_candles)
+            .unwrap_or_default();
+        let mut slot = if next != head && Candles::<T>::contains_key(netuid, next) {
+            next
+        } else {
+            0
+        };
+
+        let mut candles = Vec::new();
+        while let Some(candle) = Candles::<T>::get(netuid, slot) {
+            candles.push(candle);
+            if slot == head {
+                break;
+            }
+            slot = slot
+                .saturating_add(1)
+                .checked_rem(max_candles)
+                .unwrap_or_default();
+        }
+        candles
+    }
+
+    /// Accumulates the current price into the price oracle of a subnet
+    ///
+    /// Must be called before anything that can move the price. The price up to this point has
+    /// been constant since the latest observation, so only the first call in a block records a
+    /// new observation.
+    pub(super) fn update_price_oracle(netuid: NetUid) {
+        let current_block: u64 = frame_system::Pallet::<T>::block_number().saturated_into();
+        let head = PriceObservationHead::<T>::get(netuid);
+
+        let (slot, cumulative_price) = match PriceObservations::<T>::get(netuid, head) {
+            Some(latest) if latest.block >= current_block => return,
+            Some(latest) => (
+                head.saturating_add(1)
+                    .checked_rem(T::MaxPriceObservations::get().max(1))
+                    .unwrap_or_default(),
+                Self::cumulative_price_at(&latest, current_block, Self::current_price_rao(netuid)),
+            ),
+            None => (head, 0),
+        };
+
+        PriceObservations::<T>::insert(
+            netuid,
+            slot,
+            PriceObservation {
+                block: current_block,
+                cumulative_price,
+            },
+        );
+        PriceObservationHead::<T>::insert(netuid, slot);
+    }
+
+    /// Time-weighted average price of a subnet over the last `window` blocks, in rao units per
+    /// one 10^9 Alpha units
+    ///
+    /// Returns `None` if the oracle holds less than `window` blocks of history.
+    pub fn twap_price(netuid: NetUid, window: u64) -> Option<u64> {
+        let price = Self::current_price_rao(netuid);
+        if window == 0 {
+            return Some(price);
+        }
+
+        let current_block: u64 = frame_system::Pallet::<T>::block_number().saturated_into();
+        let target_block = current_block.checked_sub(window)?;
+        let head = PriceObservationHead::<T>::get(netuid);
+        let latest = PriceObservations::<T>::get(netuid, head)?;
+
+        // Slots are ordered oldest first starting at `oldest`. The buffer is full once the slot
+        // after the head is taken, otherwise it was filled from slot 0.
+        let max_observations = T::MaxPriceObservations::get().max(1);
+        let next = head
+            .saturating_add(1)
+            .checked_rem(max_observations)
+            .unwrap_or_default();
+        let (oldest, count) = if next != head && PriceObservations::<T>::contains_key(netuid, next)
+        {
+            (next, max_observations)
+        } else {
+            (0, head.saturating_add(1))
+        };
+        let observation_at = |index: u32| {
+            let slot = oldest
+                .saturating_add(index)
+                .checked_rem(max_observations)
+                .unwrap_or_default();
+            PriceObservations::<T>::get(netuid, slot)
+        };
+
+        // Binary search for the newest observation taken at or before `target_block`
+        let first = observation_at(0)?;
+        if first.block > target_block {
+            return None;
+        }
+        let (mut low, mut high) = (0u32, count.saturating_sub(1));
+        while low < high {
+            let mid = low.saturating_add(high.saturating_sub(low).saturating_add(1).safe_div(2));
+            match observation_at(mid) {
+                Some(observation) if observation.block <= target_block => low = mid,
+                _ => high = mid.saturating_sub(1),
+            }
+        }
+        let observation = observation_at(low)?;
+
+        // The price between two observations is constant, and equal to the current price after
+        // the latest one
+        let next_observation = if low.saturating_add(1) < count {
+            observation_at(low.saturating_add(1))
+        } else {
+            None
+        };
+        let price_after = match next_observation {
+            Some(next) => next
+                .cumulative_price
+                .saturating_sub(observation.cumulative_price)
+                .checked_div(u128::from(next.block.saturating_sub(observation.block)))
+                .unwrap_or_default()
+                .saturated_into(),
+            None => price,
+        };
+
+        let cumulative_now = Self::cumulative_price_at(&latest, current_block, price);
+        let cumulative_then = Self::cumulative_price_at(&observation, target_block, price_after);
+
+        Some(
+            cumulative_now
+                .saturating_sub(cumulative_then)
+                .checked_div(u128::from(window))
+                .unwrap_or_default()
+                .saturated_into(),
+        )
+    }
+
+    /// Cumulative price at `block`, given that the price has been `price` since `observation`
+    fn cumulative_price_at(observation: &PriceObservation, block: u64, price: u64) -> u128 {
+        observation.cumulative_price.saturating_add(
+            u128::from(price).saturating_mul(u128::from(block.saturating_sub(observation.block))),
+        )
+    }
+
     // initializes V3 swap for a subnet if needed
     pub(super) fn maybe_initialize_v3(netuid: NetUid) -> Result<(), Error<T>> {
         if SwapV3Initialized::<T>::get(netuid) {
@@ -361,6 +590,8 @@ impl<T: Config> Pallet<T> {
         tao_delta: TaoCurrency,
         alpha_delta: AlphaCurrency,
     ) {
+        Self::update_price_oracle(netuid);
+
         // Update protocol position with new liquidity
         let protocol_account_id = Self::protocol_account_id();
         let mut positions =
@@ -440,6 +671,8 @@ impl<T: Config> Pallet<T> {
             // Read alpha and tao reserves before transaction
             let tao_reserve = T::SubnetInfo::tao_reserve(netuid.into());
             let alpha_reserve = T::SubnetInfo::alpha_reserve(netuid.into());
+            let price_before = Self::current_price_rao(netuid);
+            Self::update_price_oracle(netuid);
 
             let mut result =
                 Self::swap_inner(netuid, order_type, amount, limit_sqrt_price, drop_fees)
@@ -463,6 +696,12 @@ impl<T: Config> Pallet<T> {
                     }
                 }
 
+                if result.is_ok() {
+                    Self::defend_price_floor(netuid);
+                    Self::record_price_candle(netuid, price_before);
+                    Self::mark_limit_orders_for_settlement(netuid);
+                }
+
                 TransactionOutcome::Commit(result)
             }
         })
@@ -569,6 +808,96 @@ impl<T: Config> Pallet<T> {
         })
     }
 
+    /// Swaps Alpha of one subnet for Alpha of another, routing through TAO
+    ///
+    /// Both legs run in a single storage transaction: the TAO received for the origin Alpha is
+    /// spent on destination Alpha in full, and the whole route is rolled back unless at least
+    /// `min_amount_out` destination Alpha is received. The individual legs are not price
+    /// limited, `min_amount_out` is the only slippage bound.
+    ///
+    /// Fees are charged on both legs. The returned `fee_paid_tao` values the origin leg fee at
+    /// the effective rate of that leg and adds the destination leg fee.
+    pub fn do_swap_alpha_for_alpha(
+        origin_netuid: NetUid,
+        destination_netuid: NetUid,
+        amount: u64,
+        min_amount_out: u64,
+        drop_fees: bool,
+        simulate: bool,
+    ) -> Result<MultiHopSwapResult, DispatchError> {
+        ensure!(
+            origin_netuid != destination_netuid,
+            Error::<T>::InvalidSwapRoute
+        );
+        ensure!(
+            T::SubnetInfo::mechanism(origin_netuid) == 1
+                && T::SubnetInfo::mechanism(destination_netuid) == 1,
+            Error::<T>::InvalidSwapRoute
+        );
+
+        transactional::with_transaction(|| {
+            let result = Self::swap_alpha_for_alpha_inner(
+                origin_netuid,
+                destination_netuid,
+                amount,
+                min_amount_out,
+                drop_fees,
+            );
+
+            if simulate || result.is_err() {
+                TransactionOutcome::Rollback(result)
+            } else {
+                TransactionOutcome::Commit(result)
+            }
+        })
+    }
+
+    fn swap_alpha_for_alpha_inner(
+        origin_netuid: NetUid,
+        destination_netuid: NetUid,
+        amount: u64,
+        min_amount_out: u64,
+        drop_fees: bool,
+    ) -> Result<MultiHopSwapResult, DispatchError> {
+        let sell = Self::do_swap(
+            origin_netuid,
+            OrderType::Sell,
+            amount,
+            TickIndex::min_sqrt_price(),
+            drop_fees,
+            false,
+        )?;
+        ensure!(
+            sell.amount_paid_out > 0,
+            Error::<T>::InsufficientInputAmount
+        );
+
+        let buy = Self::do_swap(
+            destination_netuid,
+            OrderType::Buy,
+            sell.amount_paid_out,
+            TickIndex::max_sqrt_price(),
+            drop_fees,
+            false,
+        )?;
+        ensure!(
+            buy.amount_paid_out >= min_amount_out,
+            Error::<T>::SwapOutputTooLow
+        );
+
+        let sell_fee_tao = U64F64::saturating_from_num(sell.fee_paid)
+            .saturating_mul(U64F64::saturating_from_num(sell.amount_paid_out))
+            .safe_div(U64F64::saturating_from_num(sell.amount_paid_in))
+            .saturating_to_num::<u64>();
+        let fee_paid_tao = sell_fee_tao.saturating_add(buy.fee_paid);
+
+        Ok(MultiHopSwapResult {
+            sell,
+            buy,
+            fee_paid_tao,
+        })
+    }
+
     /// Get the tick at the current tick edge for the given direction (order type) If
     /// order type is Buy, then edge tick is the high tick, otherwise it is the low
     /// tick.
@@ -621,8 +950,36 @@ impl<T: Config> Pallet<T> {
         }
     }
 
-    /// Add fees to the global fee counters
+    /// The share of swap fees diverted to the protocol on a subnet
+    ///
+    /// Normalized value between 0 and u16::MAX, clamped to the bounds in case they were narrowed
+    /// after the subnet override was set.
+    pub fn protocol_fee_rate(netuid: NetUid) -> u16 {
+        let (min_rate, max_rate) = ProtocolFeeRateBounds::<T>::get();
+        SubnetProtocolFeeRate::<T>::get(netuid)
+            .unwrap_or_else(ProtocolFeeRate::<T>::get)
+            .clamp(min_rate, max_rate.max(min_rate))
+    }
+
+    /// Add fees to the global fee counters, holding back the protocol share
     fn add_fees(netuid: NetUid, order_type: OrderType, fee: u64) {
+        let protocol_fee_rate = U64F64::saturating_from_num(Self::protocol_fee_rate(netuid))
+            .safe_div(U64F64::saturating_from_num(u16::MAX));
+        let protocol_fee = U64F64::saturating_from_num(fee)
+            .saturating_mul(protocol_fee_rate)
+            .saturating_to_num::<u64>();
+        if protocol_fee > 0 {
+            match order_type {
+                OrderType::Sell => ProtocolFeesAlpha::<T>::mutate(netuid, |total| {
+                    *total = total.saturating_add(protocol_fee.into())
+                }),
+                OrderType::Buy => ProtocolFeesTao::<T>::mutate(netuid, |total| {
+                    *total = total.saturating_add(protocol_fee.into())
+                }),
+            }
+        }
+        let fee = fee.saturating_sub(protocol_fee);
+
         let liquidity_curr = Self::current_liquidity_safe(netuid);
 
         if liquidity_curr == 0 {
@@ -649,6 +1006,321 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Takes TAO and Alpha from the subnet owner and adds them to the liquidity incentive of a
+    /// subnet, returning the updated incentive
+    pub(super) fn do_fund_liquidity_incentive(
+        netuid: NetUid,
+        coldkey_account_id: &T::AccountId,
+        hotkey_account_id: &T::AccountId,
+        tao: TaoCurrency,
+        alpha: AlphaCurrency,
+        duration: u64,
+    ) -> Result<LiquidityIncentive, DispatchError> {
+        ensure!(
+            (1..=MAX_LIQUIDITY_INCENTIVE_DURATION).contains(&duration),
+            Error::<T>::InvalidIncentiveDuration
+        );
+        ensure!(
+            !tao.is_zero() || !alpha.is_zero(),
+            Error::<T>::ZeroLiquidityIncentive
+        );
+        ensure!(
+            EnabledUserLiquidity::<T>::get(netuid),
+            Error::<T>::UserLiquidityDisabled
+        );
+
+        // Remove TAO and Alpha balances or fail transaction if they can't be removed exactly
+        if !tao.is_zero() {
+            let tao_provided = T::BalanceOps::decrease_balance(coldkey_account_id, tao)?;
+            ensure!(tao_provided == tao, Error::<T>::InsufficientBalance);
+        }
+        if !alpha.is_zero() {
+            let alpha_provided = T::BalanceOps::decrease_stake(
+                coldkey_account_id,
+                hotkey_account_id,
+                netuid,
+                alpha,
+            )?;
+            ensure!(alpha_provided == alpha, Error::<T>::InsufficientBalance);
+        }
+
+        let current_block: u64 = frame_system::Pallet::<T>::block_number().saturated_into();
+        let incentive = LiquidityIncentives::<T>::mutate(netuid, |incentive| {
+            let incentive = incentive.get_or_insert_with(Default::default);
+            incentive.tao = incentive.tao.saturating_add(tao);
+            incentive.alpha = incentive.alpha.saturating_add(alpha);
+            incentive.ends_at = incentive
+                .ends_at
+                .max(current_block.saturating_add(duration));
+            *incentive
+        });
+
+        Ok(incentive)
+    }
+
+    pub(super) fn do_set_price_floor(
+        netuid: NetUid,
+        coldkey_account_id: &T::AccountId,
+        hotkey_account_id: &T::AccountId,
+        price: u64,
+        tao: TaoCurrency,
+    ) -> Result<PriceFloor<T::AccountId>, DispatchError> {
+        ensure!(price > 0, Error::<T>::InvalidPriceFloor);
+
+        // Remove TAO balance or fail transaction if it can't be removed exactly
+        if !tao.is_zero() {
+            let tao_provided = T::BalanceOps::decrease_balance(coldkey_account_id, tao)?;
+            ensure!(tao_provided == tao, Error::<T>::InsufficientBalance);
+        }
+
+        // The reserve of a floor funded by a previous owner goes back to it
+        let reserve = match PriceFloors::<T>::get(netuid) {
+            Some(floor) if floor.coldkey == *coldkey_account_id => floor.reserve,
+            Some(floor) => {
+                T::BalanceOps::increase_balance(&floor.coldkey, floor.reserve);
+                TaoCurrency::ZERO
+            }
+            None => TaoCurrency::ZERO,
+        }
+        .saturating_add(tao);
+
+        let floor = PriceFloors::<T>::mutate(netuid, |floor| {
+            let (tao_spent, alpha_bought) = floor
+                .as_ref()
+                .filter(|floor| floor.coldkey == *coldkey_account_id)
+                .map(|floor| (floor.tao_spent, floor.alpha_bought))
+                .unwrap_or_default();
+            floor
+                .insert(PriceFloor {
+                    coldkey: coldkey_account_id.clone(),
+                    hotkey: hotkey_account_id.clone(),
+                    price,
+                    reserve,
+                    tao_spent,
+                    alpha_bought,
+                    paused: reserve.is_zero(),
+                })
+                .clone()
+        });
+
+        Ok(floor)
+    }
+
+    /// Removes the price floor of a subnet and refunds its reserve to the coldkey that funded it
+    pub(super) fn do_remove_price_floor(
+        netuid: NetUid,
+    ) -> Result<PriceFloor<T::AccountId>, Error<T>> {
+        let floor = PriceFloors::<T>::take(netuid).ok_or(Error::<T>::PriceFloorNotFound)?;
+        T::BalanceOps::increase_balance(&floor.coldkey, floor.reserve);
+        Ok(floor)
+    }
+
+    /// Locks the liquidity of a position of `coldkey_account_id` for `duration` blocks, or
+    /// extends its active lock to end `duration` blocks from now
+    pub(super) fn do_lock_liquidity(
+        netuid: NetUid,
+        coldkey_account_id: &T::AccountId,
+        position_id: PositionId,
+        duration: u64,
+    ) -> Result<(LiquidityLock<T::AccountId>, u64), Error<T>> {
+        ensure!(
+            (1..=MAX_LIQUIDITY_LOCK_DURATION).contains(&duration),
+            Error::<T>::InvalidLockDuration
+        );
+        let position = Positions::<T>::get((netuid, coldkey_account_id, position_id))
+            .ok_or(Error::<T>::LiquidityNotFound)?;
+        ensure!(
+            !LimitOrders::<T>::contains_key(netuid, position_id),
+            Error::<T>::LimitOrderNotLockable
+        );
+
+        let current_block: u64 = frame_system::Pallet::<T>::block_number().saturated_into();
+        let unlocks_at = current_block.saturating_add(duration);
+        let locked_at = match LiquidityLocks::<T>::get(netuid, position_id) {
+            Some(lock) if lock.is_active(current_block) => {
+                ensure!(
+                    unlocks_at >= lock.unlocks_at,
+                    Error::<T>::InvalidLockDuration
+                );
+                lock.locked_at
+            }
+            _ => current_block,
+        };
+
+        let lock = LiquidityLock {
+            coldkey: coldkey_account_id.clone(),
+            locked_at,
+            unlocks_at,
+        };
+        LiquidityLocks::<T>::insert(netuid, position_id, lock.clone());
+
+        Ok((lock, position.liquidity))
+    }
+
+    /// Ensures the liquidity of a position is not held by an active lock
+    pub(super) fn ensure_liquidity_unlocked(
+        netuid: NetUid,
+        position_id: PositionId,
+    ) -> Result<(), Error<T>> {
+        let current_block: u64 = frame_system::Pallet::<T>::block_number().saturated_into();
+        match LiquidityLocks::<T>::get(netuid, position_id) {
+            Some(lock) if lock.is_active(current_block) => Err(Error::<T>::LiquidityLocked),
+            _ => Ok(()),
+        }
+    }
+
+    /// The liquidity held by the active locks of a subnet and the block the earliest of them
+    /// unlocks at, zero without active locks
+    pub fn locked_liquidity(netuid: NetUid) -> (u64, u64) {
+        let current_block: u64 = frame_system::Pallet::<T>::block_number().saturated_into();
+        LiquidityLocks::<T>::iter_prefix(netuid)
+            .filter(|(_, lock)| lock.is_active(current_block))
+            .filter_map(|(position_id, lock)| {
+                Positions::<T>::get((netuid, &lock.coldkey, position_id))
+                    .map(|position| (position.liquidity, lock.unlocks_at))
+            })
+            .fold((0, 0), |(liquidity, earliest), (locked, unlocks_at)| {
+                let earliest = if earliest == 0 {
+                    unlocks_at
+                } else {
+                    earliest.min(unlocks_at)
+                };
+                (liquidity.saturating_add(locked), earliest)
+            })
+    }
+
+    /// Buys Alpha with the price floor reserve of a subnet until the price is back at the floor
+    ///
+    /// The buy is staked to the floor hotkey like any other stake, so it is charged to the swaps
+    /// that can trigger it through `price_floor_defense_weight`. A failing buy is rolled back on
+    /// its own, so that it never fails the swap that moved the price. The floor is paused once
+    /// its reserve cannot lift the price back to the floor.
+    pub(super) fn defend_price_floor(netuid: NetUid) {
+        let Some(mut floor) = PriceFloors::<T>::get(netuid) else {
+            return;
+        };
+        if floor.paused {
+            return;
+        }
+        let Some(floor_sqrt_price) = floor.sqrt_price() else {
+            return;
+        };
+        if Self::current_price_sqrt(netuid) >= floor_sqrt_price {
+            return;
+        }
+
+        // Size the buy on the pool alone, it stops at the floor or once the reserve is spent
+        let tao = transactional::with_transaction(|| {
+            let result = Self::swap_inner(
+                netuid,
+                OrderType::Buy,
+                floor.reserve.to_u64(),
+                floor_sqrt_price,
+                false,
+            )
+            .map(|swap| TaoCurrency::from(swap.amount_paid_in.saturating_add(swap.fee_paid)))
+            .map_err(DispatchError::from);
+            TransactionOutcome::Rollback(result)
+        });
+
+        // Keep the swap of the buy itself from defending the floor again
+        PriceFloors::<T>::insert(
+            netuid,
+            PriceFloor {
+                paused: true,
+                ..floor.clone()
+            },
+        );
+        let result = transactional::with_transaction(|| {
+            let result = tao.and_then(|tao| {
+                T::BalanceOps::increase_balance(&floor.coldkey, tao);
+                let alpha = T::BalanceOps::stake(&floor.coldkey, &floor.hotkey, netuid, tao)?;
+                Ok((tao, alpha))
+            });
+            if result.is_ok() {
+                TransactionOutcome::Commit(result)
+            } else {
+                TransactionOutcome::Rollback(result)
+            }
+        });
+
+        // A buy too small to be staked is retried on the next swap, unless it is all the
+        // reserve has left
+        let depleted = match result {
+            Ok((tao, alpha)) => {
+                floor.reserve = floor.reserve.saturating_sub(tao);
+                floor.tao_spent = floor.tao_spent.saturating_add(tao);
+                floor.alpha_bought = floor.alpha_bought.saturating_add(alpha);
+                Self::deposit_event(Event::PriceFloorDefended {
+                    netuid,
+                    tao,
+                    alpha,
+                    reserve: floor.reserve,
+                });
+                floor.reserve.is_zero() || Self::current_price_sqrt(netuid) < floor_sqrt_price
+            }
+            Err(e) => {
+                log::warn!(
+                    "defend_price_floor: failed to defend the floor on netuid={netuid:?}: {e:?}"
+                );
+                tao.is_ok_and(|tao| tao >= floor.reserve)
+            }
+        };
+
+        // Whatever stopped the buy short of the floor, the reserve cannot hold it
+        if depleted {
+            floor.paused = true;
+            Self::deposit_event(Event::PriceFloorPaused { netuid });
+        }
+        PriceFloors::<T>::insert(netuid, floor);
+    }
+
+    /// Streams one block of every liquidity incentive into the global fee growth of its subnet
+    ///
+    /// Returns the weight consumed.
+    pub(super) fn stream_liquidity_incentives() -> Weight {
+        let mut weight = T::DbWeight::get().reads(1);
+        let current_block: u64 = frame_system::Pallet::<T>::block_number().saturated_into();
+        let incentives = LiquidityIncentives::<T>::iter().collect::<Vec<_>>();
+
+        for (netuid, mut incentive) in incentives {
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(3, 3));
+
+            let liquidity_curr = Self::current_liquidity_safe(netuid);
+            if liquidity_curr == 0 {
+                continue;
+            }
+
+            // Round up so that the whole incentive is streamed by the last block
+            let blocks_left = incentive
+                .ends_at
+                .saturating_sub(current_block)
+                .saturating_add(1);
+            let tao = incentive.tao.to_u64().div_ceil(blocks_left);
+            let alpha = incentive.alpha.to_u64().div_ceil(blocks_left);
+
+            FeeGlobalTao::<T>::mutate(netuid, |fee_global| {
+                *fee_global = fee_global
+                    .saturating_add(U64F64::saturating_from_num(tao).safe_div(liquidity_curr))
+            });
+            FeeGlobalAlpha::<T>::mutate(netuid, |fee_global| {
+                *fee_global = fee_global
+                    .saturating_add(U64F64::saturating_from_num(alpha).safe_div(liquidity_curr))
+            });
+
+            incentive.tao = incentive.tao.saturating_sub(tao.into());
+            incentive.alpha = incentive.alpha.saturating_sub(alpha.into());
+            if incentive.tao.is_zero() && incentive.alpha.is_zero() {
+                LiquidityIncentives::<T>::remove(netuid);
+                Self::deposit_event(Event::LiquidityIncentiveFinished { netuid });
+            } else {
+                LiquidityIncentives::<T>::insert(netuid, incentive);
+            }
+        }
+
+        weight
+    }
+
     /// Convert input amount (delta_in) to output amount (delta_out)
     ///
     /// This is the core method of uniswap V3 that tells how much output token is given for an
@@ -839,9 +1511,13 @@ impl<T: Config> Pallet<T> {
         liquidity: u64,
     ) -> Result<(PositionId, u64, u64), Error<T>> {
         ensure!(
-            EnabledUserLiquidity::<T>::get(netuid),
+            Self::is_user_liquidity_allowed(netuid, coldkey_account_id),
             Error::<T>::UserLiquidityDisabled
         );
+        ensure!(
+            Self::is_tick_aligned(netuid, tick_low) && Self::is_tick_aligned(netuid, tick_high),
+            Error::<T>::TickNotAligned
+        );
 
         let (position, tao, alpha) = Self::add_liquidity_not_insert(
             netuid,
@@ -873,6 +1549,143 @@ impl<T: Config> Pallet<T> {
         Ok((position_id, tao, alpha))
     }
 
+    /// Whether positions on `netuid` can start or end at `tick`
+    ///
+    /// Ticks must be multiples of the tick spacing of the subnet. The bounds of the full price
+    /// range are always allowed.
+    pub fn is_tick_aligned(netuid: NetUid, tick: TickIndex) -> bool {
+        tick == TickIndex::MIN
+            || tick == TickIndex::MAX
+            || tick
+                .get()
+                .checked_rem(i32::from(TickSpacing::<T>::get(netuid)))
+                .is_some_and(|rem| rem == 0)
+    }
+
+    /// Whether `netuid` has liquidity positions other than the protocol position
+    pub fn has_user_positions(netuid: NetUid) -> bool {
+        let protocol_account_id = Self::protocol_account_id();
+        Positions::<T>::iter_prefix((netuid,))
+            .any(|((coldkey, _), _)| coldkey != protocol_account_id)
+    }
+
+    /// Whether `coldkey` may provide liquidity on `netuid`, either because user liquidity is
+    /// enabled or because the coldkey is whitelisted by the subnet owner
+    pub fn is_user_liquidity_allowed(netuid: NetUid, coldkey: &T::AccountId) -> bool {
+        EnabledUserLiquidity::<T>::get(netuid)
+            || LiquidityProviderWhitelist::<T>::contains_key(netuid, coldkey)
+    }
+
+    /// Amount of a single-sided deposit of `amount` TAO (or Alpha if `tao_input` is false) to
+    /// swap before the rest and the swap output are deposited into `[tick_low, tick_high]`.
+    ///
+    /// The split is found by at most `SINGLE_SIDED_SEARCH_STEPS` bisection steps on swaps of the
+    /// pool alone that are rolled back, so the price impact of the swap itself is taken into
+    /// account. Whatever of the two sides the position cannot take stays with the caller.
+    pub fn single_sided_swap_amount(
+        netuid: NetUid,
+        tick_low: TickIndex,
+        tick_high: TickIndex,
+        amount: u64,
+        tao_input: bool,
+    ) -> Result<u64, DispatchError> {
+        let (order_type, limit_sqrt_price) = if tao_input {
+            (OrderType::Buy, TickIndex::max_sqrt_price())
+        } else {
+            (OrderType::Sell, TickIndex::min_sqrt_price())
+        };
+
+        let (mut low, mut high) = (0_u64, amount);
+        for _ in 0..SINGLE_SIDED_SEARCH_STEPS {
+            if low >= high {
+                break;
+            }
+            let mid = low.saturating_add(high.saturating_sub(low).safe_div(2));
+
+            let (left, received, sqrt_price) = if mid == 0 {
+                (amount, 0, Self::current_price_sqrt(netuid))
+            } else {
+                // Only the pool moves, none of the side effects of a committed swap run
+                transactional::with_transaction(|| {
+                    let result = Self::swap_inner(netuid, order_type, mid, limit_sqrt_price, false)
+                        .map(|result| {
+                            let paid = result.amount_paid_in.saturating_add(result.fee_paid);
+                            (
+                                amount.saturating_sub(paid),
+                                result.amount_paid_out,
+                                Self::current_price_sqrt(netuid),
+                            )
+                        })
+                        .map_err(DispatchError::from);
+                    TransactionOutcome::Rollback(result)
+                })?
+            };
+
+            let (tao, alpha) = if tao_input {
+                (left, received)
+            } else {
+                (received, left)
+            };
+            let (_, tao_binding) =
+                Self::liquidity_for_amounts(sqrt_price, tick_low, tick_high, tao, alpha)?;
+
+            // Swap more while the swapped-into side is what limits the position
+            if tao_binding != tao_input {
+                low = mid.saturating_add(1);
+            } else {
+                high = mid;
+            }
+        }
+
+        Ok(low)
+    }
+
+    /// The largest liquidity a position in `[tick_low, tick_high]` can be minted with at
+    /// `sqrt_price` from `tao` and `alpha`, and whether TAO is the side limiting it
+    pub fn liquidity_for_amounts(
+        sqrt_price: SqrtPrice,
+        tick_low: TickIndex,
+        tick_high: TickIndex,
+        tao: u64,
+        alpha: u64,
+    ) -> Result<(u64, bool), Error<T>> {
+        let one = U64F64::saturating_from_num(1);
+        let sqrt_price_low = tick_low
+            .try_to_sqrt_price()
+            .map_err(|_| Error::<T>::InvalidTickRange)?;
+        let sqrt_price_high = tick_high
+            .try_to_sqrt_price()
+            .map_err(|_| Error::<T>::InvalidTickRange)?;
+        ensure!(
+            sqrt_price_high > sqrt_price_low,
+            Error::<T>::InvalidTickRange
+        );
+
+        // A side the position does not need at this price does not limit it
+        let liquidity_tao = (sqrt_price > sqrt_price_low).then(|| {
+            U64F64::saturating_from_num(tao).safe_div(
+                sqrt_price
+                    .min(sqrt_price_high)
+                    .saturating_sub(sqrt_price_low),
+            )
+        });
+        let liquidity_alpha = (sqrt_price < sqrt_price_high).then(|| {
+            U64F64::saturating_from_num(alpha).safe_div(
+                one.safe_div(sqrt_price.max(sqrt_price_low))
+                    .saturating_sub(one.safe_div(sqrt_price_high)),
+            )
+        });
+
+        Ok(match (liquidity_tao, liquidity_alpha) {
+            (Some(by_tao), Some(by_alpha)) if by_tao < by_alpha => {
+                (by_tao.saturating_to_num(), true)
+            }
+            (_, Some(by_alpha)) => (by_alpha.saturating_to_num(), false),
+            (Some(by_tao), None) => (by_tao.saturating_to_num(), true),
+            (None, None) => (0, false),
+        })
+    }
+
     // add liquidity without inserting position into storage (used privately for v3 intiialization).
     // unlike Self::add_liquidity it also doesn't perform account's balance check.
     //
@@ -947,6 +1760,10 @@ impl<T: Config> Pallet<T> {
 
         // Remove user position
         Positions::<T>::remove((netuid, coldkey_account_id, position_id));
+        LiquidityLocks::<T>::remove(netuid, position_id);
+        if LimitOrders::<T>::take(netuid, position_id).is_some() {
+            LimitOrderCount::<T>::mutate(netuid, |count| *count = count.saturating_sub(1));
+        }
 
         Ok(RemoveLiquidityResult {
             tao: tao.into(),
@@ -959,6 +1776,363 @@ impl<T: Config> Pallet<T> {
         })
     }
 
+    /// Collects the fees accrued by a position, leaving its liquidity and ticks untouched.
+    pub fn do_collect_fees(
+        netuid: NetUid,
+        coldkey_account_id: &T::AccountId,
+        position_id: PositionId,
+    ) -> Result<(TaoCurrency, AlphaCurrency), Error<T>> {
+        let Some(mut position) = Positions::<T>::get((netuid, coldkey_account_id, position_id))
+        else {
+            return Err(Error::<T>::LiquidityNotFound);
+        };
+
+        let (fee_tao, fee_alpha) = position.collect_fees();
+        Positions::<T>::insert(&(netuid, coldkey_account_id, position.id), position);
+
+        Ok((fee_tao.into(), fee_alpha.into()))
+    }
+
+    /// Adds `liquidity_delta` to an existing position. Fees accrued so far stay in the position.
+    ///
+    /// Returns the TAO and Alpha amounts the account has to provide.
+    pub fn do_increase_liquidity(
+        netuid: NetUid,
+        coldkey_account_id: &T::AccountId,
+        hotkey_account_id: &T::AccountId,
+        position_id: PositionId,
+        liquidity_delta: u64,
+    ) -> Result<UpdateLiquidityResult, Error<T>> {
+        ensure!(
+            Self::is_user_liquidity_allowed(netuid, coldkey_account_id),
+            Error::<T>::UserLiquidityDisabled
+        );
+
+        Self::resize_position(
+            netuid,
+            coldkey_account_id,
+            hotkey_account_id,
+            position_id,
+            liquidity_delta,
+            true,
+        )
+    }
+
+    /// Removes `liquidity_delta` from an existing position. Fees accrued so far stay in the
+    /// position, and the position is never closed: the remaining liquidity must not go below
+    /// `MinimumLiquidity`.
+    ///
+    /// Returns the TAO and Alpha amounts to credit back to the account.
+    pub fn do_decrease_liquidity(
+        netuid: NetUid,
+        coldkey_account_id: &T::AccountId,
+        hotkey_account_id: &T::AccountId,
+        position_id: PositionId,
+        liquidity_delta: u64,
+    ) -> Result<UpdateLiquidityResult, Error<T>> {
+        Self::resize_position(
+            netuid,
+            coldkey_account_id,
+            hotkey_account_id,
+            position_id,
+            liquidity_delta,
+            false,
+        )
+    }
+
+    fn resize_position(
+        netuid: NetUid,
+        coldkey_account_id: &T::AccountId,
+        hotkey_account_id: &T::AccountId,
+        position_id: PositionId,
+        liquidity_delta: u64,
+        increase: bool,
+    ) -> Result<UpdateLiquidityResult, Error<T>> {
+        let Some(mut position) = Positions::<T>::get((netuid, coldkey_account_id, position_id))
+        else {
+            return Err(Error::<T>::LiquidityNotFound);
+        };
+
+        // Small delta is not allowed
+        ensure!(
+            liquidity_delta >= T::MinimumLiquidity::get(),
+            Error::<T>::InvalidLiquidityValue
+        );
+
+        // Token amounts backing the delta at the current price
+        let delta_position = Position::<T> {
+            liquidity: liquidity_delta,
+            ..position.clone()
+        };
+        let current_price_sqrt = Pallet::<T>::current_price_sqrt(netuid);
+        let (tao, alpha) = delta_position.to_token_amounts(current_price_sqrt)?;
+
+        if increase {
+            ensure!(
+                T::BalanceOps::tao_balance(coldkey_account_id) >= TaoCurrency::from(tao)
+                    && T::BalanceOps::alpha_balance(netuid, coldkey_account_id, hotkey_account_id)
+                        >= AlphaCurrency::from(alpha),
+                Error::<T>::InsufficientBalance
+            );
+
+            Self::add_liquidity_at_index(netuid, position.tick_low, liquidity_delta, false);
+            Self::add_liquidity_at_index(netuid, position.tick_high, liquidity_delta, true);
+            Self::update_liquidity_if_needed(
+                netuid,
+                position.tick_low,
+                position.tick_high,
+                liquidity_delta as i128,
+            );
+
+            position.set_liquidity(position.liquidity.saturating_add(liquidity_delta));
+        } else {
+            ensure!(
+                position.liquidity.saturating_sub(liquidity_delta) >= T::MinimumLiquidity::get(),
+                Error::<T>::InsufficientLiquidity
+            );
+
+            Self::remove_liquidity_at_index(netuid, position.tick_low, liquidity_delta, false);
+            Self::remove_liquidity_at_index(netuid, position.tick_high, liquidity_delta, true);
+            Self::update_liquidity_if_needed(
+                netuid,
+                position.tick_low,
+                position.tick_high,
+                (liquidity_delta as i128).neg(),
+            );
+
+            position.set_liquidity(position.liquidity.saturating_sub(liquidity_delta));
+        }
+
+        let (tick_low, tick_high) = (position.tick_low, position.tick_high);
+        Positions::<T>::insert(&(netuid, coldkey_account_id, position.id), position);
+
+        Ok(UpdateLiquidityResult {
+            tao: tao.into(),
+            alpha: alpha.into(),
+            fee_tao: TaoCurrency::ZERO,
+            fee_alpha: AlphaCurrency::ZERO,
+            removed: false,
+            tick_low,
+            tick_high,
+        })
+    }
+
+    /// Moves a position from `coldkey_account_id` to `dest_account_id`, keeping its liquidity
+    /// and accrued fees.
+    pub fn do_transfer_position(
+        netuid: NetUid,
+        coldkey_account_id: &T::AccountId,
+        dest_account_id: &T::AccountId,
+        position_id: PositionId,
+    ) -> Result<(), Error<T>> {
+        ensure!(
+            PositionTransfersEnabled::<T>::get(netuid),
+            Error::<T>::PositionTransfersDisabled
+        );
+        ensure!(
+            coldkey_account_id != dest_account_id
+                && *dest_account_id != Self::protocol_account_id(),
+            Error::<T>::InvalidTransferDestination
+        );
+
+        let Some(position) = Positions::<T>::get((netuid, coldkey_account_id, position_id)) else {
+            return Err(Error::<T>::LiquidityNotFound);
+        };
+        ensure!(
+            !LimitOrders::<T>::contains_key(netuid, position_id),
+            Error::<T>::LimitOrderNotTransferable
+        );
+
+        ensure!(
+            Self::count_positions(netuid, dest_account_id) < T::MaxPositions::get() as usize,
+            Error::<T>::MaxPositionsExceeded
+        );
+
+        Positions::<T>::remove((netuid, coldkey_account_id, position_id));
+        Positions::<T>::insert(&(netuid, dest_account_id, position_id), position);
+
+        Ok(())
+    }
+
+    /// Adds a liquidity position spanning a single tick spacing for a limit order
+    ///
+    /// The liquidity is chosen so that the position holds `amount` Alpha for a sell order, or
+    /// `amount` TAO for a buy order. Returns the position ID and the (TAO, Alpha) amounts to be
+    /// debited from the user.
+    pub fn do_place_limit_order(
+        netuid: NetUid,
+        coldkey_account_id: &T::AccountId,
+        hotkey_account_id: &T::AccountId,
+        side: OrderType,
+        amount: u64,
+        tick: TickIndex,
+    ) -> Result<(PositionId, u64, u64), Error<T>> {
+        ensure!(
+            Self::is_user_liquidity_allowed(netuid, coldkey_account_id),
+            Error::<T>::UserLiquidityDisabled
+        );
+        ensure!(
+            LimitOrderCount::<T>::get(netuid) < T::MaxLimitOrders::get(),
+            Error::<T>::MaxLimitOrdersExceeded
+        );
+
+        let tick_high = tick
+            .checked_add(i32::from(TickSpacing::<T>::get(netuid)))
+            .map_err(|_| Error::<T>::InvalidTickRange)?;
+        let sqrt_price_low = tick
+            .try_to_sqrt_price()
+            .map_err(|_| Error::<T>::InvalidTickRange)?;
+        let sqrt_price_high = tick_high
+            .try_to_sqrt_price()
+            .map_err(|_| Error::<T>::InvalidTickRange)?;
+        let current_sqrt_price = Self::current_price_sqrt(netuid);
+        let amount = U64F64::saturating_from_num(amount);
+
+        // The whole tick must hold the token being sold, see `Position::to_token_amounts`
+        let liquidity = match side {
+            OrderType::Sell => {
+                ensure!(
+                    current_sqrt_price <= sqrt_price_low,
+                    Error::<T>::InvalidLimitOrderTick
+                );
+                let one = U64F64::saturating_from_num(1);
+                amount.safe_div(
+                    one.safe_div(sqrt_price_low)
+                        .saturating_sub(one.safe_div(sqrt_price_high)),
+                )
+            }
+            OrderType::Buy => {
+                ensure!(
+                    current_sqrt_price >= sqrt_price_high,
+                    Error::<T>::InvalidLimitOrderTick
+                );
+                amount.safe_div(sqrt_price_high.saturating_sub(sqrt_price_low))
+            }
+        }
+        .saturating_to_num::<u64>();
+
+        let (position_id, tao, alpha) = Self::do_add_liquidity(
+            netuid,
+            coldkey_account_id,
+            hotkey_account_id,
+            tick,
+            tick_high,
+            liquidity,
+        )?;
+
+        LimitOrders::<T>::insert(
+            netuid,
+            position_id,
+            LimitOrder {
+                coldkey: coldkey_account_id.clone(),
+                hotkey: hotkey_account_id.clone(),
+                side,
+                tick,
+            },
+        );
+        LimitOrderCount::<T>::mutate(netuid, |count| *count = count.saturating_add(1));
+
+        Ok((position_id, tao, alpha))
+    }
+
+    /// Removes the position of a limit order and credits its tokens and fees to the owner
+    ///
+    /// Returns the credited (TAO, Alpha) amounts.
+    pub(super) fn close_limit_order(
+        netuid: NetUid,
+        position_id: PositionId,
+        order: &LimitOrder<T::AccountId>,
+    ) -> Result<(TaoCurrency, AlphaCurrency), DispatchError> {
+        let result = Self::do_remove_liquidity(netuid, &order.coldkey, position_id)?;
+        let tao = result.tao.saturating_add(result.fee_tao);
+        let alpha = result.alpha.saturating_add(result.fee_alpha);
+
+        T::BalanceOps::increase_balance(&order.coldkey, tao);
+        T::BalanceOps::increase_stake(&order.coldkey, &order.hotkey, netuid, alpha)?;
+
+        // Remove withdrawn liquidity from user-provided reserves
+        T::BalanceOps::decrease_provided_tao_reserve(netuid, result.tao)?;
+        T::BalanceOps::decrease_provided_alpha_reserve(netuid, result.alpha);
+
+        Ok((tao, alpha))
+    }
+
+    /// Queues the limit orders of a subnet for settlement once the block has weight to spare
+    fn mark_limit_orders_for_settlement(netuid: NetUid) {
+        if LimitOrderCount::<T>::get(netuid) > 0 {
+            PendingLimitOrderSettlement::<T>::insert(netuid, true);
+        }
+    }
+
+    /// Settles the limit orders that the price has crossed on the subnets marked by swaps
+    ///
+    /// Runs within `limit`. A subnet whose orders do not all fit stays marked and is picked up
+    /// again in a later block. Returns the weight consumed.
+    pub(super) fn settle_pending_limit_orders(limit: Weight) -> Weight {
+        let mut meter = WeightMeter::with_limit(limit);
+        let settle_weight = T::WeightInfo::cancel_order();
+
+        for netuid in PendingLimitOrderSettlement::<T>::iter_keys().collect::<Vec<_>>() {
+            // Scan of the open orders, the price and the tick spacing, then the unmarking
+            let scan_weight = T::DbWeight::get().reads_writes(
+                u64::from(LimitOrderCount::<T>::get(netuid)).saturating_add(4),
+                1,
+            );
+            if meter.try_consume(scan_weight).is_err() {
+                break;
+            }
+
+            if !Self::settle_limit_orders(netuid, &mut meter, settle_weight) {
+                break;
+            }
+            PendingLimitOrderSettlement::<T>::remove(netuid);
+        }
+
+        meter.consumed()
+    }
+
+    /// Settles the limit orders of a subnet that the current price has crossed
+    ///
+    /// An order that fails to settle is left open and rolled back on its own. Returns false if
+    /// the meter ran out before all crossed orders were settled.
+    fn settle_limit_orders(netuid: NetUid, meter: &mut WeightMeter, settle_weight: Weight) -> bool {
+        let current_sqrt_price = Self::current_price_sqrt(netuid);
+        let tick_spacing = TickSpacing::<T>::get(netuid);
+        let filled = LimitOrders::<T>::iter_prefix(netuid)
+            .filter(|(_, order)| order.is_filled(current_sqrt_price, tick_spacing))
+            .collect::<Vec<_>>();
+
+        for (position_id, order) in filled {
+            if meter.try_consume(settle_weight).is_err() {
+                return false;
+            }
+
+            let result = transactional::with_transaction(|| {
+                let result = Self::close_limit_order(netuid, position_id, &order);
+                if result.is_ok() {
+                    TransactionOutcome::Commit(result)
+                } else {
+                    TransactionOutcome::Rollback(result)
+                }
+            });
+
+            match result {
+                Ok((tao, alpha)) => Self::deposit_event(Event::LimitOrderFilled {
+                    coldkey: order.coldkey,
+                    netuid,
+                    position_id,
+                    tao,
+                    alpha,
+                }),
+                Err(e) => log::warn!(
+                    "settle_limit_orders: failed to settle order {position_id:?} on netuid={netuid:?}: {e:?}"
+                ),
+            }
+        }
+
+        true
+    }
+
     pub fn do_modify_position(
         netuid: NetUid,
         coldkey_account_id: &T::AccountId,
@@ -967,7 +2141,7 @@ impl<T: Config> Pallet<T> {
         liquidity_delta: i64,
     ) -> Result<UpdateLiquidityResult, Error<T>> {
         ensure!(
-            EnabledUserLiquidity::<T>::get(netuid),
+            Self::is_user_liquidity_allowed(netuid, coldkey_account_id),
             Error::<T>::UserLiquidityDisabled
         );
 
@@ -1269,7 +2443,7 @@ impl<T: Config> Pallet<T> {
                         if rm.tao > TaoCurrency::ZERO {
                             T::BalanceOps::increase_balance(&owner, rm.tao);
                             user_refunded_tao = user_refunded_tao.saturating_add(rm.tao);
-                            T::BalanceOps::decrease_provided_tao_reserve(netuid, rm.tao);
+                            T::BalanceOps::decrease_provided_tao_reserve(netuid, rm.tao)?;
                         }
 
                         // 2) Stake ALL withdrawn α (principal + fees) to the best permitted validator.
@@ -1397,7 +2571,24 @@ impl<T: Config> Pallet<T> {
 
         let _ = TickIndexBitmapWords::<T>::clear_prefix((netuid,), u32::MAX, None);
         FeeRate::<T>::remove(netuid);
+        TickSpacing::<T>::remove(netuid);
+        LiquidityIncentives::<T>::remove(netuid);
+        let _ = Self::do_remove_price_floor(netuid);
+        SubnetProtocolFeeRate::<T>::remove(netuid);
+        ProtocolFeesTao::<T>::remove(netuid);
+        ProtocolFeesAlpha::<T>::remove(netuid);
         EnabledUserLiquidity::<T>::remove(netuid);
+        let _ = LiquidityProviderWhitelist::<T>::clear_prefix(netuid, u32::MAX, None);
+        CandleInterval::<T>::remove(netuid);
+        let _ = Candles::<T>::clear_prefix(netuid, u32::MAX, None);
+        CandleHead::<T>::remove(netuid);
+        let _ = PriceObservations::<T>::clear_prefix(netuid, u32::MAX, None);
+        PriceObservationHead::<T>::remove(netuid);
+        PositionTransfersEnabled::<T>::remove(netuid);
+        let _ = LimitOrders::<T>::clear_prefix(netuid, u32::MAX, None);
+        LimitOrderCount::<T>::remove(netuid);
+        PendingLimitOrderSettlement::<T>::remove(netuid);
+        let _ = LiquidityLocks::<T>::clear_prefix(netuid, u32::MAX, None);
 
         log::debug!(
             "clear_protocol_liquidity: netuid={netuid:?}, protocol_burned: τ={burned_tao:?}, α={burned_alpha:?}; state cleared"
@@ -1463,14 +2654,42 @@ impl<T: Config> SwapHandler<T::AccountId> for Pallet<T> {
         }
     }
 
+    fn swap_alpha_for_alpha(
+        origin_netuid: NetUid,
+        destination_netuid: NetUid,
+        amount: u64,
+        min_amount_out: u64,
+        drop_fees: bool,
+        should_rollback: bool,
+    ) -> Result<MultiHopSwapResult, DispatchError> {
+        Self::do_swap_alpha_for_alpha(
+            origin_netuid,
+            destination_netuid,
+            amount,
+            min_amount_out,
+            drop_fees,
+            should_rollback,
+        )
+    }
+
     fn approx_fee_amount(netuid: NetUid, amount: u64) -> u64 {
         Self::calculate_fee_amount(netuid.into(), amount, false)
     }
 
+    fn price_floor_defense_weight() -> Weight {
+        T::WeightInfo::defend_price_floor()
+    }
+
     fn current_alpha_price(netuid: NetUid) -> U96F32 {
         Self::current_price(netuid.into())
     }
 
+    fn twap_alpha_price(netuid: NetUid, window: u64) -> Option<U96F32> {
+        Self::twap_price(netuid, window).map(|price| {
+            U96F32::saturating_from_num(price).safe_div(U96F32::saturating_from_num(1_000_000_000))
+        })
+    }
+
     fn min_price() -> u64 {
         TickIndex::min_sqrt_price()
             .saturating_mul(TickIndex::min_sqrt_price())
@@ -1506,6 +2725,9 @@ impl<T: Config> SwapHandler<T::AccountId> for Pallet<T> {
     fn clear_protocol_liquidity(netuid: NetUid) -> DispatchResult {
         Self::do_clear_protocol_liquidity(netuid)
     }
+    fn locked_liquidity(netuid: NetUid) -> (u64, u64) {
+        Self::locked_liquidity(netuid)
+    }
 }
 
 #[derive(Debug, PartialEq)]
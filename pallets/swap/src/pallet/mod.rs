@@ -7,9 +7,17 @@ use substrate_fixed::types::U64F64;
 use subtensor_runtime_common::{
     AlphaCurrency, BalanceOps, Currency, NetUid, SubnetInfo, TaoCurrency,
 };
+use subtensor_swap_interface::OrderType;
 
 use crate::{
+    candle::Candle,
+    incentive::LiquidityIncentive,
+    limit_order::LimitOrder,
+    liquidity_lock::LiquidityLock,
+    oracle::PriceObservation,
     position::{Position, PositionId},
+    price_floor::PriceFloor,
+    protocol_fee::ProtocolFeeDestination,
     tick::{LayerLevel, Tick, TickIndex},
     weights::WeightInfo,
 };
@@ -60,19 +68,87 @@ mod pallet {
         #[pallet::constant]
         type MinimumReserve: Get<NonZeroU64>;
 
+        /// The maximum number of price candles kept per subnet
+        #[pallet::constant]
+        type MaxCandles: Get<u32>;
+
+        /// The maximum number of price oracle observations kept per subnet
+        #[pallet::constant]
+        type MaxPriceObservations: Get<u32>;
+
+        /// The maximum number of open limit orders per subnet. Settlement checks all open orders
+        /// of a subnet whose price a swap has moved.
+        #[pallet::constant]
+        type MaxLimitOrders: Get<u32>;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
 
-    /// Default fee rate if not set
+    /// Default global fee rate if not set
     #[pallet::type_value]
-    pub fn DefaultFeeRate() -> u16 {
+    pub fn DefaultGlobalFeeRate() -> u16 {
         33 // ~0.05 %
     }
 
+    /// The fee rate applied to swaps on subnets without their own fee rate, normalized value
+    /// between 0 and u16::MAX
+    #[pallet::storage]
+    pub type GlobalFeeRate<T> = StorageValue<_, u16, ValueQuery, DefaultGlobalFeeRate>;
+
+    /// Default bounds of the subnet owner fee rate if not set
+    #[pallet::type_value]
+    pub fn DefaultFeeRateBounds() -> (u16, u16) {
+        (33, 655) // ~0.05 % to ~1 %
+    }
+
+    /// The bounds within which subnet owners can set the fee rate of their subnet
+    #[pallet::storage]
+    pub type FeeRateBounds<T> = StorageValue<_, (u16, u16), ValueQuery, DefaultFeeRateBounds>;
+
+    /// Default fee rate of a subnet if not set
+    #[pallet::type_value]
+    pub fn DefaultFeeRate<T: Config>() -> u16 {
+        GlobalFeeRate::<T>::get()
+    }
+
     /// The fee rate applied to swaps per subnet, normalized value between 0 and u16::MAX
     #[pallet::storage]
-    pub type FeeRate<T> = StorageMap<_, Twox64Concat, NetUid, u16, ValueQuery, DefaultFeeRate>;
+    pub type FeeRate<T: Config> =
+        StorageMap<_, Twox64Concat, NetUid, u16, ValueQuery, DefaultFeeRate<T>>;
+
+    /// Default bounds of the per-subnet protocol fee rate if not set
+    #[pallet::type_value]
+    pub fn DefaultProtocolFeeRateBounds() -> (u16, u16) {
+        (0, u16::MAX)
+    }
+
+    /// The share of swap fees diverted from liquidity providers to the protocol, normalized value
+    /// between 0 and u16::MAX. Applies to subnets without an override.
+    #[pallet::storage]
+    pub type ProtocolFeeRate<T> = StorageValue<_, u16, ValueQuery>;
+
+    /// The bounds within which subnet owners can override the protocol fee rate
+    #[pallet::storage]
+    pub type ProtocolFeeRateBounds<T> =
+        StorageValue<_, (u16, u16), ValueQuery, DefaultProtocolFeeRateBounds>;
+
+    /// The per-subnet override of the protocol fee rate
+    #[pallet::storage]
+    pub type SubnetProtocolFeeRate<T> = StorageMap<_, Twox64Concat, NetUid, u16, OptionQuery>;
+
+    /// The account the protocol share of swap fees is paid to
+    #[pallet::storage]
+    pub type ProtocolFeeRecipient<T: Config> =
+        StorageValue<_, ProtocolFeeDestination<T::AccountId>, ValueQuery>;
+
+    /// Protocol fees in tao accrued and not yet claimed per subnet
+    #[pallet::storage]
+    pub type ProtocolFeesTao<T> = StorageMap<_, Twox64Concat, NetUid, TaoCurrency, ValueQuery>;
+
+    /// Protocol fees in alpha accrued and not yet claimed per subnet
+    #[pallet::storage]
+    pub type ProtocolFeesAlpha<T> = StorageMap<_, Twox64Concat, NetUid, AlphaCurrency, ValueQuery>;
 
     // Global accrued fees in tao per subnet
     #[pallet::storage]
@@ -102,12 +178,68 @@ mod pallet {
     #[pallet::storage]
     pub type CurrentLiquidity<T> = StorageMap<_, Twox64Concat, NetUid, u64, ValueQuery>;
 
+    /// Default tick spacing if not set
+    #[pallet::type_value]
+    pub fn DefaultTickSpacing() -> u16 {
+        1
+    }
+
+    /// The spacing of the ticks that positions can start and end at per subnet
+    #[pallet::storage]
+    pub type TickSpacing<T> =
+        StorageMap<_, Twox64Concat, NetUid, u16, ValueQuery, DefaultTickSpacing>;
+
     /// Indicates whether a subnet has been switched to V3 swap from V2.
     /// If `true`, the subnet is permanently on V3 swap mode allowing add/remove liquidity
     /// operations. Once set to `true` for a subnet, it cannot be changed back to `false`.
     #[pallet::storage]
     pub type EnabledUserLiquidity<T> = StorageMap<_, Twox64Concat, NetUid, bool, ValueQuery>;
 
+    /// Coldkeys allowed to provide liquidity on a subnet while user liquidity is disabled
+    #[pallet::storage]
+    pub type LiquidityProviderWhitelist<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, NetUid, Twox64Concat, T::AccountId, (), OptionQuery>;
+
+    /// Ring buffer of cumulative price observations per subnet, at most one per block
+    #[pallet::storage]
+    pub type PriceObservations<T> =
+        StorageDoubleMap<_, Twox64Concat, NetUid, Twox64Concat, u32, PriceObservation>;
+
+    /// Slot of the latest observation in the `PriceObservations` ring buffer of a subnet
+    #[pallet::storage]
+    pub type PriceObservationHead<T> = StorageMap<_, Twox64Concat, NetUid, u32, ValueQuery>;
+
+    /// Open limit orders by subnet and position
+    #[pallet::storage]
+    pub type LimitOrders<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        NetUid,
+        Twox64Concat,
+        PositionId,
+        LimitOrder<T::AccountId>,
+        OptionQuery,
+    >;
+
+    /// Number of open limit orders per subnet
+    #[pallet::storage]
+    pub type LimitOrderCount<T> = StorageMap<_, Twox64Concat, NetUid, u32, ValueQuery>;
+
+    /// Subnets whose price a swap has moved since their limit orders were last settled
+    #[pallet::storage]
+    pub type PendingLimitOrderSettlement<T> = StorageMap<_, Twox64Concat, NetUid, bool, ValueQuery>;
+
+    /// Default for position transfers if not set
+    #[pallet::type_value]
+    pub fn DefaultPositionTransfersEnabled() -> bool {
+        true
+    }
+
+    /// Indicates whether liquidity positions can be transferred between coldkeys on a subnet.
+    #[pallet::storage]
+    pub type PositionTransfersEnabled<T> =
+        StorageMap<_, Twox64Concat, NetUid, bool, ValueQuery, DefaultPositionTransfersEnabled>;
+
     /// Storage for user positions, using subnet ID and account ID as keys
     /// The value is a bounded vector of Position structs with details about the liquidity positions
     #[pallet::storage]
@@ -126,6 +258,50 @@ mod pallet {
     #[pallet::storage]
     pub type LastPositionId<T> = StorageValue<_, u128, ValueQuery>;
 
+    /// Default candle interval if not set
+    #[pallet::type_value]
+    pub fn DefaultCandleInterval() -> u64 {
+        360 // one default tempo
+    }
+
+    /// The length of one price candle in blocks per subnet
+    #[pallet::storage]
+    pub type CandleInterval<T> =
+        StorageMap<_, Twox64Concat, NetUid, u64, ValueQuery, DefaultCandleInterval>;
+
+    /// Ring buffer of the most recent price candles per subnet, of `MaxCandles` slots
+    /// The oldest candle is overwritten once every slot is taken
+    #[pallet::storage]
+    pub type Candles<T> = StorageDoubleMap<_, Twox64Concat, NetUid, Twox64Concat, u32, Candle>;
+
+    /// Slot of the latest candle in the `Candles` ring buffer of a subnet
+    #[pallet::storage]
+    pub type CandleHead<T> = StorageMap<_, Twox64Concat, NetUid, u32, ValueQuery>;
+
+    /// TAO and Alpha deposited by the subnet owner that is streamed to in-range liquidity per
+    /// subnet
+    #[pallet::storage]
+    pub type LiquidityIncentives<T> =
+        StorageMap<_, Twox64Concat, NetUid, LiquidityIncentive, OptionQuery>;
+
+    /// TAO locked by the subnet owner to buy Alpha whenever the price falls below a floor, per
+    /// subnet
+    #[pallet::storage]
+    pub type PriceFloors<T: Config> =
+        StorageMap<_, Twox64Concat, NetUid, PriceFloor<T::AccountId>, OptionQuery>;
+
+    /// Liquidity positions locked by the subnet owner, by subnet and position
+    #[pallet::storage]
+    pub type LiquidityLocks<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        NetUid,
+        Twox64Concat,
+        PositionId,
+        LiquidityLock<T::AccountId>,
+        OptionQuery,
+    >;
+
     /// Tick index bitmap words storage
     #[pallet::storage]
     pub type TickIndexBitmapWords<T: Config> = StorageNMap<
@@ -145,10 +321,136 @@ mod pallet {
         /// Event emitted when the fee rate has been updated for a subnet
         FeeRateSet { netuid: NetUid, rate: u16 },
 
+        /// Event emitted when the global fee rate and the subnet owner fee rate bounds have been
+        /// updated
+        GlobalFeeRateSet {
+            rate: u16,
+            min_rate: u16,
+            max_rate: u16,
+        },
+
+        /// Event emitted when the price candle interval has been updated for a subnet
+        CandleIntervalSet { netuid: NetUid, interval: u64 },
+
+        /// Event emitted when the tick spacing has been updated for a subnet
+        TickSpacingSet { netuid: NetUid, spacing: u16 },
+
+        /// Event emitted when the subnet owner has funded the liquidity incentive of a subnet
+        LiquidityIncentiveFunded {
+            /// The subnet identifier
+            netuid: NetUid,
+            /// The coldkey account that funded the incentive
+            coldkey: T::AccountId,
+            /// The amount of TAO deposited
+            tao: TaoCurrency,
+            /// The amount of Alpha deposited
+            alpha: AlphaCurrency,
+            /// Last block over which the incentive is streamed
+            ends_at: u64,
+        },
+
+        /// Event emitted when the liquidity incentive of a subnet has been fully streamed
+        LiquidityIncentiveFinished { netuid: NetUid },
+
+        /// Event emitted when the subnet owner has set or funded the price floor of a subnet
+        PriceFloorSet {
+            /// The subnet identifier
+            netuid: NetUid,
+            /// The coldkey account that funded the floor
+            coldkey: T::AccountId,
+            /// The floor price in rao per 10^9 Alpha units
+            price: u64,
+            /// The amount of TAO deposited
+            tao: TaoCurrency,
+            /// TAO left to defend the floor with
+            reserve: TaoCurrency,
+        },
+
+        /// Event emitted when the price floor of a subnet has bought Alpha
+        PriceFloorDefended {
+            /// The subnet identifier
+            netuid: NetUid,
+            /// The amount of TAO spent, including fees
+            tao: TaoCurrency,
+            /// The amount of Alpha bought
+            alpha: AlphaCurrency,
+            /// TAO left to defend the floor with
+            reserve: TaoCurrency,
+        },
+
+        /// Event emitted when the price floor of a subnet has been paused because its reserve
+        /// is depleted
+        PriceFloorPaused { netuid: NetUid },
+
+        /// Event emitted when the price floor of a subnet has been removed
+        PriceFloorRemoved {
+            /// The subnet identifier
+            netuid: NetUid,
+            /// The coldkey account the reserve is refunded to
+            coldkey: T::AccountId,
+            /// The amount of TAO refunded
+            refund: TaoCurrency,
+        },
+
+        /// Event emitted when the subnet owner has locked or extended the lock of a liquidity
+        /// position
+        LiquidityLocked {
+            /// The subnet identifier
+            netuid: NetUid,
+            /// The coldkey account that owns the position
+            coldkey: T::AccountId,
+            /// The locked position
+            position_id: PositionId,
+            /// The liquidity of the position
+            liquidity: u64,
+            /// First block at which the liquidity can be withdrawn again
+            unlocks_at: u64,
+        },
+
         /// Event emitted when user liquidity operations are enabled for a subnet.
         /// First enable even indicates a switch from V2 to V3 swap.
         UserLiquidityToggled { netuid: NetUid, enable: bool },
 
+        /// Event emitted when a coldkey is added to or removed from the liquidity provider
+        /// whitelist of a subnet.
+        LiquidityProviderWhitelistUpdated {
+            netuid: NetUid,
+            coldkey: T::AccountId,
+            whitelisted: bool,
+        },
+
+        /// Event emitted when part of a single-sided liquidity deposit is swapped to the other
+        /// token before the position is minted.
+        SingleSidedLiquiditySwapped {
+            /// The coldkey depositing the liquidity
+            coldkey: T::AccountId,
+            /// The hotkey the Alpha is staked to
+            hotkey: T::AccountId,
+            /// The subnet identifier
+            netuid: NetUid,
+            /// Whether the deposit is TAO rather than Alpha
+            tao_input: bool,
+            /// Amount of the deposit swapped, including the fee
+            amount_in: u64,
+            /// Amount of the other token received
+            amount_out: u64,
+        },
+
+        /// Event emitted when position transfers are enabled or disabled for a subnet.
+        PositionTransfersToggled { netuid: NetUid, enable: bool },
+
+        /// Event emitted when a liquidity position is transferred to another coldkey.
+        PositionTransferred {
+            /// The subnet identifier
+            netuid: NetUid,
+            /// Unique identifier for the liquidity position
+            position_id: PositionId,
+            /// The coldkey account that owned the position
+            from: T::AccountId,
+            /// The coldkey account that owns the position now
+            to: T::AccountId,
+        },
+
         /// Event emitted when a liquidity position is added to a subnet's liquidity pool.
         LiquidityAdded {
             /// The coldkey account that owns the position
@@ -223,6 +525,100 @@ mod pallet {
             /// the upper tick
             tick_high: TickIndex,
         },
+
+        /// Event emitted when a limit order is placed.
+        LimitOrderPlaced {
+            /// The coldkey account that owns the order
+            coldkey: T::AccountId,
+            /// The hotkey account where Alpha comes from or goes to
+            hotkey: T::AccountId,
+            /// The subnet identifier
+            netuid: NetUid,
+            /// Unique identifier for the order position
+            position_id: PositionId,
+            /// Whether the order sells or buys Alpha
+            side: OrderType,
+            /// The tick the order converts at
+            tick: TickIndex,
+            /// The amount of TAO tokens committed to the order
+            tao: TaoCurrency,
+            /// The amount of Alpha tokens committed to the order
+            alpha: AlphaCurrency,
+        },
+
+        /// Event emitted when a limit order is cancelled before being filled.
+        LimitOrderCancelled {
+            /// The coldkey account that owned the order
+            coldkey: T::AccountId,
+            /// The subnet identifier
+            netuid: NetUid,
+            /// Unique identifier for the order position
+            position_id: PositionId,
+            /// The amount of TAO tokens returned to the user, including fees
+            tao: TaoCurrency,
+            /// The amount of Alpha tokens returned to the user, including fees
+            alpha: AlphaCurrency,
+        },
+
+        /// Event emitted when a limit order is filled and its proceeds are credited.
+        LimitOrderFilled {
+            /// The coldkey account that owned the order
+            coldkey: T::AccountId,
+            /// The subnet identifier
+            netuid: NetUid,
+            /// Unique identifier for the order position
+            position_id: PositionId,
+            /// The amount of TAO tokens credited to the user, including fees
+            tao: TaoCurrency,
+            /// The amount of Alpha tokens credited to the user, including fees
+            alpha: AlphaCurrency,
+        },
+
+        /// Event emitted when the fees accrued by a liquidity position are collected without
+        /// changing its liquidity.
+        FeesCollected {
+            /// The coldkey account that owns the position
+            coldkey: T::AccountId,
+            /// The hotkey account where Alpha fees go to
+            hotkey: T::AccountId,
+            /// The subnet identifier
+            netuid: NetUid,
+            /// Unique identifier for the liquidity position
+            position_id: PositionId,
+            /// The amount of TAO fees collected from the position
+            fee_tao: TaoCurrency,
+            /// The amount of Alpha fees collected from the position
+            fee_alpha: AlphaCurrency,
+        },
+
+        /// Event emitted when the global protocol fee configuration has been updated
+        ProtocolFeeSet {
+            /// The default protocol fee rate
+            rate: u16,
+            /// The lowest protocol fee rate a subnet owner can set
+            min_rate: u16,
+            /// The highest protocol fee rate a subnet owner can set
+            max_rate: u16,
+            /// The account protocol fees are paid to
+            destination: ProtocolFeeDestination<T::AccountId>,
+        },
+
+        /// Event emitted when the protocol fee rate of a subnet has been overridden or reset
+        SubnetProtocolFeeRateSet { netuid: NetUid, rate: Option<u16> },
+
+        /// Event emitted when the accrued protocol fees of a subnet are paid out.
+        ProtocolFeesClaimed {
+            /// The subnet identifier
+            netuid: NetUid,
+            /// The coldkey the TAO fees are credited to, unless paid into the network treasury
+            coldkey: T::AccountId,
+            /// The hotkey the Alpha fees are staked to
+            hotkey: T::AccountId,
+            /// The amount of TAO fees paid out
+            fee_tao: TaoCurrency,
+            /// The amount of Alpha fees paid out
+            fee_alpha: AlphaCurrency,
+        },
     }
 
     #[pallet::error]
@@ -231,6 +627,9 @@ mod pallet {
         /// The fee rate is too high
         FeeRateTooHigh,
 
+        /// The fee rate is outside of the bounds subnet owners can set
+        FeeRateOutOfBounds,
+
         /// The provided amount is insufficient for the swap.
         InsufficientInputAmount,
 
@@ -269,6 +668,83 @@ mod pallet {
 
         /// The subnet does not have subtoken enabled
         SubtokenDisabled,
+
+        /// The candle interval must be at least one block
+        InvalidCandleInterval,
+
+        /// Position transfers are disabled for this subnet
+        PositionTransfersDisabled,
+
+        /// The position cannot be transferred to this account
+        InvalidTransferDestination,
+
+        /// The limit order tick is not entirely on the correct side of the current price
+        InvalidLimitOrderTick,
+
+        /// The subnet has reached the maximum number of open limit orders
+        MaxLimitOrdersExceeded,
+
+        /// The limit order does not exist or is owned by another account
+        LimitOrderNotFound,
+
+        /// Limit order positions cannot be transferred
+        LimitOrderNotTransferable,
+
+        /// Alpha can only be routed between two different dynamic subnets
+        InvalidSwapRoute,
+
+        /// The routed swap returns less than the requested minimum amount
+        SwapOutputTooLow,
+
+        /// The protocol fee rate is outside of the allowed bounds
+        ProtocolFeeRateOutOfBounds,
+
+        /// The subnet has no owner to pay protocol fees to
+        NoProtocolFeeRecipient,
+
+        /// The tick spacing must be at least one tick
+        InvalidTickSpacing,
+
+        /// The tick is not a multiple of the tick spacing of the subnet
+        TickNotAligned,
+
+        /// The tick spacing cannot be changed while the subnet has user positions
+        UserPositionsExist,
+
+        /// The liquidity incentive duration is zero or exceeds the maximum
+        InvalidIncentiveDuration,
+
+        /// The liquidity incentive deposits neither TAO nor Alpha
+        ZeroLiquidityIncentive,
+
+        /// The single-sided deposit mints less than the requested minimum liquidity
+        SingleSidedLiquidityTooLow,
+
+        /// The floor price must be above zero
+        InvalidPriceFloor,
+
+        /// The subnet has no price floor
+        PriceFloorNotFound,
+
+        /// The lock duration is zero, exceeds the maximum or would shorten an active lock
+        InvalidLockDuration,
+
+        /// The liquidity of the position is locked by the subnet owner
+        LiquidityLocked,
+
+        /// Limit orders settle on their own and cannot be locked
+        LimitOrderNotLockable,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            Self::stream_liquidity_incentives()
+        }
+
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::settle_pending_limit_orders(remaining_weight)
+        }
     }
 
     #[pallet::call]
@@ -276,7 +752,8 @@ mod pallet {
         /// Set the fee rate for swaps on a specific subnet (normalized value).
         /// For example, 0.3% is approximately 196.
         ///
-        /// Only callable by the admin origin
+        /// Only callable by the admin origin or the subnet owner. The subnet owner can only set
+        /// a rate within `FeeRateBounds`.
         #[pallet::call_index(0)]
         #[pallet::weight(<T as pallet::Config>::WeightInfo::set_fee_rate())]
         pub fn set_fee_rate(origin: OriginFor<T>, netuid: NetUid, rate: u16) -> DispatchResult {
@@ -286,6 +763,12 @@ mod pallet {
                     T::SubnetInfo::is_owner(&account_id, netuid.into()),
                     DispatchError::BadOrigin
                 );
+
+                let (min_rate, max_rate) = FeeRateBounds::<T>::get();
+                ensure!(
+                    (min_rate..=max_rate).contains(&rate),
+                    Error::<T>::FeeRateOutOfBounds
+                );
             }
 
             // Ensure that the subnet exists.
@@ -434,6 +917,8 @@ mod pallet {
                 Error::<T>::MechanismDoesNotExist
             );
 
+            Self::ensure_liquidity_unlocked(netuid, position_id)?;
+
             // Remove liquidity
             let result = Self::do_remove_liquidity(netuid, &coldkey, position_id)?;
 
@@ -447,7 +932,7 @@ mod pallet {
             )?;
 
             // Remove withdrawn liquidity from user-provided reserves
-            T::BalanceOps::decrease_provided_tao_reserve(netuid.into(), result.tao);
+            T::BalanceOps::decrease_provided_tao_reserve(netuid.into(), result.tao)?;
             T::BalanceOps::decrease_provided_alpha_reserve(netuid.into(), result.alpha);
 
             // Emit an event
@@ -499,6 +984,10 @@ mod pallet {
                 Error::<T>::SubtokenDisabled
             );
 
+            if liquidity_delta < 0 {
+                Self::ensure_liquidity_unlocked(netuid, position_id)?;
+            }
+
             // Add or remove liquidity
             let result =
                 Self::do_modify_position(netuid, &coldkey, &hotkey, position_id, liquidity_delta)?;
@@ -581,5 +1070,951 @@ mod pallet {
 
             Ok(())
         }
+
+        /// Collect the fees accrued by a liquidity position without removing or modifying its
+        /// liquidity.
+        ///
+        /// Parameters:
+        /// - origin: The origin of the transaction
+        /// - hotkey: The hotkey account where Alpha fees are staked to
+        /// - netuid: Subnet ID
+        /// - position_id: ID of the position to collect fees from
+        ///
+        /// Emits `Event::FeesCollected` on success
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::collect_fees())]
+        pub fn collect_fees(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: NetUid,
+            position_id: PositionId,
+        ) -> DispatchResult {
+            let coldkey = ensure_signed(origin)?;
+
+            // Ensure that the subnet exists.
+            ensure!(
+                T::SubnetInfo::exists(netuid.into()),
+                Error::<T>::MechanismDoesNotExist
+            );
+
+            ensure!(
+                T::SubnetInfo::is_subtoken_enabled(netuid.into()),
+                Error::<T>::SubtokenDisabled
+            );
+
+            let (fee_tao, fee_alpha) = Self::do_collect_fees(netuid, &coldkey, position_id)?;
+
+            // Credit accrued fees to user account
+            if fee_tao > TaoCurrency::ZERO {
+                T::BalanceOps::increase_balance(&coldkey, fee_tao);
+            }
+            if !fee_alpha.is_zero() {
+                T::BalanceOps::increase_stake(&coldkey, &hotkey, netuid.into(), fee_alpha)?;
+            }
+
+            Self::deposit_event(Event::FeesCollected {
+                coldkey,
+                hotkey,
+                netuid,
+                position_id,
+                fee_tao,
+                fee_alpha,
+            });
+
+            Ok(())
+        }
+
+        /// Add liquidity to an existing position.
+        ///
+        /// Unlike `modify_position`, fees accrued by the position are not collected.
+        ///
+        /// Parameters:
+        /// - origin: The origin of the transaction
+        /// - hotkey: The hotkey account where Alpha comes from
+        /// - netuid: Subnet ID
+        /// - position_id: ID of the position to resize
+        /// - liquidity_delta: Liquidity to add
+        ///
+        /// Emits `Event::LiquidityModified` on success
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::increase_liquidity())]
+        pub fn increase_liquidity(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: NetUid,
+            position_id: PositionId,
+            liquidity_delta: u64,
+        ) -> DispatchResult {
+            let coldkey = ensure_signed(origin)?;
+
+            // Ensure that the subnet exists.
+            ensure!(
+                T::SubnetInfo::exists(netuid.into()),
+                Error::<T>::MechanismDoesNotExist
+            );
+
+            ensure!(
+                T::SubnetInfo::is_subtoken_enabled(netuid.into()),
+                Error::<T>::SubtokenDisabled
+            );
+
+            let result = Self::do_increase_liquidity(
+                netuid,
+                &coldkey,
+                &hotkey,
+                position_id,
+                liquidity_delta,
+            )?;
+
+            // Remove TAO and Alpha balances or fail transaction if they can't be removed exactly
+            let tao_provided = T::BalanceOps::decrease_balance(&coldkey, result.tao)?;
+            ensure!(tao_provided == result.tao, Error::<T>::InsufficientBalance);
+
+            let alpha_provided =
+                T::BalanceOps::decrease_stake(&coldkey, &hotkey, netuid.into(), result.alpha)?;
+            ensure!(
+                alpha_provided == result.alpha,
+                Error::<T>::InsufficientBalance
+            );
+
+            Self::deposit_event(Event::LiquidityModified {
+                coldkey,
+                hotkey,
+                netuid,
+                position_id,
+                liquidity: liquidity_delta as i64,
+                tao: result.tao.to_u64() as i64,
+                alpha: result.alpha.to_u64() as i64,
+                fee_tao: result.fee_tao,
+                fee_alpha: result.fee_alpha,
+                tick_low: result.tick_low,
+                tick_high: result.tick_high,
+            });
+
+            Ok(())
+        }
+
+        /// Remove liquidity from an existing position without closing it.
+        ///
+        /// Unlike `modify_position`, fees accrued by the position are not collected, and the
+        /// remaining liquidity must stay above the minimum.
+        ///
+        /// Parameters:
+        /// - origin: The origin of the transaction
+        /// - hotkey: The hotkey account where Alpha goes to
+        /// - netuid: Subnet ID
+        /// - position_id: ID of the position to resize
+        /// - liquidity_delta: Liquidity to remove
+        ///
+        /// Emits `Event::LiquidityModified` on success
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::decrease_liquidity())]
+        pub fn decrease_liquidity(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: NetUid,
+            position_id: PositionId,
+            liquidity_delta: u64,
+        ) -> DispatchResult {
+            let coldkey = ensure_signed(origin)?;
+
+            // Ensure that the subnet exists.
+            ensure!(
+                T::SubnetInfo::exists(netuid.into()),
+                Error::<T>::MechanismDoesNotExist
+            );
+
+            ensure!(
+                T::SubnetInfo::is_subtoken_enabled(netuid.into()),
+                Error::<T>::SubtokenDisabled
+            );
+
+            Self::ensure_liquidity_unlocked(netuid, position_id)?;
+
+            let result = Self::do_decrease_liquidity(
+                netuid,
+                &coldkey,
+                &hotkey,
+                position_id,
+                liquidity_delta,
+            )?;
+
+            // Credit the returned tao and alpha to the account
+            T::BalanceOps::increase_balance(&coldkey, result.tao);
+            T::BalanceOps::increase_stake(&coldkey, &hotkey, netuid.into(), result.alpha)?;
+
+            Self::deposit_event(Event::LiquidityModified {
+                coldkey,
+                hotkey,
+                netuid,
+                position_id,
+                liquidity: (liquidity_delta as i64).neg(),
+                tao: (result.tao.to_u64() as i64).neg(),
+                alpha: (result.alpha.to_u64() as i64).neg(),
+                fee_tao: result.fee_tao,
+                fee_alpha: result.fee_alpha,
+                tick_low: result.tick_low,
+                tick_high: result.tick_high,
+            });
+
+            Ok(())
+        }
+
+        /// Set the length of price candles in blocks for a specific subnet.
+        ///
+        /// Candles recorded with the previous interval are discarded.
+        ///
+        /// Only callable by the admin origin or the subnet owner
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_candle_interval())]
+        pub fn set_candle_interval(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            interval: u64,
+        ) -> DispatchResult {
+            if ensure_root(origin.clone()).is_err() {
+                let account_id: T::AccountId = ensure_signed(origin)?;
+                ensure!(
+                    T::SubnetInfo::is_owner(&account_id, netuid.into()),
+                    DispatchError::BadOrigin
+                );
+            }
+
+            // Ensure that the subnet exists.
+            ensure!(
+                T::SubnetInfo::exists(netuid.into()),
+                Error::<T>::MechanismDoesNotExist
+            );
+
+            ensure!(interval > 0, Error::<T>::InvalidCandleInterval);
+
+            CandleInterval::<T>::insert(netuid, interval);
+            let _ = Candles::<T>::clear_prefix(netuid, u32::MAX, None);
+            CandleHead::<T>::remove(netuid);
+
+            Self::deposit_event(Event::CandleIntervalSet { netuid, interval });
+
+            Ok(())
+        }
+
+        /// Enable or disable transfers of liquidity positions for a specific subnet.
+        ///
+        /// Only callable by the admin origin or the subnet owner
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::toggle_position_transfers())]
+        pub fn toggle_position_transfers(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            enable: bool,
+        ) -> DispatchResult {
+            if ensure_root(origin.clone()).is_err() {
+                let account_id: T::AccountId = ensure_signed(origin)?;
+                ensure!(
+                    T::SubnetInfo::is_owner(&account_id, netuid.into()),
+                    DispatchError::BadOrigin
+                );
+            }
+
+            ensure!(
+                T::SubnetInfo::exists(netuid.into()),
+                Error::<T>::MechanismDoesNotExist
+            );
+
+            PositionTransfersEnabled::<T>::insert(netuid, enable);
+
+            Self::deposit_event(Event::PositionTransfersToggled { netuid, enable });
+
+            Ok(())
+        }
+
+        /// Transfer a liquidity position to another coldkey.
+        ///
+        /// The liquidity and the fees accrued by the position move with it. Fails while the unstake
+        /// timelock of the coldkey holds its stake.
+        ///
+        /// Parameters:
+        /// - origin: The origin of the transaction
+        /// - netuid: Subnet ID
+        /// - position_id: ID of the position to transfer
+        /// - dest: The coldkey account receiving the position
+        ///
+        /// Emits `Event::PositionTransferred` on success
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::transfer_position())]
+        pub fn transfer_position(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            position_id: PositionId,
+            dest: T::AccountId,
+        ) -> DispatchResult {
+            let coldkey = ensure_signed(origin)?;
+
+            // Ensure that the subnet exists.
+            ensure!(
+                T::SubnetInfo::exists(netuid.into()),
+                Error::<T>::MechanismDoesNotExist
+            );
+
+            Self::ensure_liquidity_unlocked(netuid, position_id)?;
+            T::BalanceOps::ensure_unstake_unlocked(&coldkey)?;
+
+            Self::do_transfer_position(netuid, &coldkey, &dest, position_id)?;
+
+            Self::deposit_event(Event::PositionTransferred {
+                netuid,
+                position_id,
+                from: coldkey,
+                to: dest,
+            });
+
+            Ok(())
+        }
+
+        /// Place a limit order spanning a single tick spacing.
+        ///
+        /// A sell order commits `amount` Alpha to a range above the current price, a buy order
+        /// commits `amount` TAO to a range below the current price. The order is settled
+        /// automatically once a swap moves the price across the whole range, and the proceeds are
+        /// credited to the coldkey and hotkey.
+        ///
+        /// Parameters:
+        /// - origin: The origin of the transaction
+        /// - hotkey: Hotkey account where Alpha comes from or goes to
+        /// - netuid: Subnet ID
+        /// - side: Whether the order sells or buys Alpha
+        /// - amount: Amount of Alpha to sell or TAO to spend
+        /// - tick: Lower tick of the order
+        ///
+        /// Emits `Event::LimitOrderPlaced` on success
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::place_limit_order())]
+        pub fn place_limit_order(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: NetUid,
+            side: OrderType,
+            amount: u64,
+            tick: TickIndex,
+        ) -> DispatchResult {
+            let coldkey = ensure_signed(origin)?;
+
+            // Ensure that the subnet exists.
+            ensure!(
+                T::SubnetInfo::exists(netuid.into()),
+                Error::<T>::MechanismDoesNotExist
+            );
+
+            ensure!(
+                T::SubnetInfo::is_subtoken_enabled(netuid.into()),
+                Error::<T>::SubtokenDisabled
+            );
+
+            let (position_id, tao, alpha) =
+                Self::do_place_limit_order(netuid, &coldkey, &hotkey, side, amount, tick)?;
+            let alpha = AlphaCurrency::from(alpha);
+            let tao = TaoCurrency::from(tao);
+
+            // Remove TAO and Alpha balances or fail transaction if they can't be removed exactly
+            let tao_provided = T::BalanceOps::decrease_balance(&coldkey, tao)?;
+            ensure!(tao_provided == tao, Error::<T>::InsufficientBalance);
+
+            let alpha_provided =
+                T::BalanceOps::decrease_stake(&coldkey, &hotkey, netuid.into(), alpha)?;
+            ensure!(alpha_provided == alpha, Error::<T>::InsufficientBalance);
+
+            // Add provided liquidity to user-provided reserves
+            T::BalanceOps::increase_provided_tao_reserve(netuid.into(), tao_provided);
+            T::BalanceOps::increase_provided_alpha_reserve(netuid.into(), alpha_provided);
+
+            Self::deposit_event(Event::LimitOrderPlaced {
+                coldkey,
+                hotkey,
+                netuid,
+                position_id,
+                side,
+                tick,
+                tao,
+                alpha,
+            });
+
+            Ok(())
+        }
+
+        /// Cancel an open limit order and return its tokens and accrued fees.
+        ///
+        /// Parameters:
+        /// - origin: The origin of the transaction
+        /// - netuid: Subnet ID
+        /// - position_id: ID of the order position
+        ///
+        /// Emits `Event::LimitOrderCancelled` on success
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_order())]
+        pub fn cancel_order(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            position_id: PositionId,
+        ) -> DispatchResult {
+            let coldkey = ensure_signed(origin)?;
+
+            let order = LimitOrders::<T>::get(netuid, position_id)
+                .filter(|order| order.coldkey == coldkey)
+                .ok_or(Error::<T>::LimitOrderNotFound)?;
+
+            let (tao, alpha) = Self::close_limit_order(netuid, position_id, &order)?;
+
+            Self::deposit_event(Event::LimitOrderCancelled {
+                coldkey,
+                netuid,
+                position_id,
+                tao,
+                alpha,
+            });
+
+            Ok(())
+        }
+
+        /// Set the fee rate of subnets without their own fee rate and the bounds within which
+        /// subnet owners can set the fee rate of their subnet (normalized values).
+        ///
+        /// Only callable by the admin origin
+        #[pallet::call_index(17)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_global_fee_rate())]
+        pub fn set_global_fee_rate(
+            origin: OriginFor<T>,
+            rate: u16,
+            min_rate: u16,
+            max_rate: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(max_rate <= T::MaxFeeRate::get(), Error::<T>::FeeRateTooHigh);
+            ensure!(
+                min_rate <= rate && rate <= max_rate,
+                Error::<T>::FeeRateOutOfBounds
+            );
+
+            GlobalFeeRate::<T>::put(rate);
+            FeeRateBounds::<T>::put((min_rate, max_rate));
+
+            Self::deposit_event(Event::GlobalFeeRateSet {
+                rate,
+                min_rate,
+                max_rate,
+            });
+
+            Ok(())
+        }
+
+        /// Set the tick spacing of a specific subnet. Positions can only start and end at ticks
+        /// that are multiples of the spacing.
+        ///
+        /// Only callable by the admin origin, and only while the subnet has no user positions
+        #[pallet::call_index(18)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_tick_spacing())]
+        pub fn set_tick_spacing(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            spacing: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            // Ensure that the subnet exists.
+            ensure!(
+                T::SubnetInfo::exists(netuid.into()),
+                Error::<T>::MechanismDoesNotExist
+            );
+
+            ensure!(spacing > 0, Error::<T>::InvalidTickSpacing);
+            ensure!(
+                !Self::has_user_positions(netuid),
+                Error::<T>::UserPositionsExist
+            );
+
+            TickSpacing::<T>::insert(netuid, spacing);
+
+            Self::deposit_event(Event::TickSpacingSet { netuid, spacing });
+
+            Ok(())
+        }
+
+        /// Deposit TAO and Alpha into the liquidity incentive of a subnet, streamed to in-range
+        /// liquidity positions over `duration` blocks. Streamed amounts are collected by the
+        /// positions together with their swap fees.
+        ///
+        /// Funding an active incentive adds to the remaining amounts and extends it if it would
+        /// end earlier.
+        ///
+        /// Only callable by the subnet owner
+        ///
+        /// Parameters:
+        /// - origin: The subnet owner coldkey, which the TAO is taken from
+        /// - hotkey: The hotkey account where Alpha comes from
+        /// - netuid: Subnet ID
+        /// - tao: Amount of TAO to deposit
+        /// - alpha: Amount of Alpha to deposit
+        /// - duration: Number of blocks over which the deposit is streamed
+        ///
+        /// Emits `Event::LiquidityIncentiveFunded` on success
+        #[pallet::call_index(19)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::fund_liquidity_incentive())]
+        pub fn fund_liquidity_incentive(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: NetUid,
+            tao: TaoCurrency,
+            alpha: AlphaCurrency,
+            duration: u64,
+        ) -> DispatchResult {
+            let coldkey = ensure_signed(origin)?;
+            ensure!(
+                T::SubnetInfo::is_owner(&coldkey, netuid.into()),
+                DispatchError::BadOrigin
+            );
+
+            // Ensure that the subnet exists.
+            ensure!(
+                T::SubnetInfo::exists(netuid.into()),
+                Error::<T>::MechanismDoesNotExist
+            );
+
+            ensure!(
+                T::SubnetInfo::is_subtoken_enabled(netuid.into()),
+                Error::<T>::SubtokenDisabled
+            );
+
+            let incentive =
+                Self::do_fund_liquidity_incentive(netuid, &coldkey, &hotkey, tao, alpha, duration)?;
+
+            Self::deposit_event(Event::LiquidityIncentiveFunded {
+                netuid,
+                coldkey,
+                tao,
+                alpha,
+                ends_at: incentive.ends_at,
+            });
+
+            Ok(())
+        }
+
+        /// Add a coldkey to or remove it from the liquidity provider whitelist of a subnet.
+        /// Whitelisted coldkeys may provide liquidity while user liquidity is disabled, e.g.
+        /// market-making partners of the subnet.
+        ///
+        /// Only callable by the admin origin or the subnet owner
+        ///
+        /// Parameters:
+        /// - origin: The admin origin or the subnet owner
+        /// - netuid: Subnet ID
+        /// - coldkey: The liquidity provider coldkey
+        /// - whitelisted: Whether the coldkey is whitelisted
+        ///
+        /// Emits `Event::LiquidityProviderWhitelistUpdated` on success
+        #[pallet::call_index(20)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_liquidity_provider_whitelisted())]
+        pub fn set_liquidity_provider_whitelisted(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            coldkey: T::AccountId,
+            whitelisted: bool,
+        ) -> DispatchResult {
+            if ensure_root(origin.clone()).is_err() {
+                let account_id: T::AccountId = ensure_signed(origin)?;
+                ensure!(
+                    T::SubnetInfo::is_owner(&account_id, netuid.into()),
+                    DispatchError::BadOrigin
+                );
+            }
+
+            ensure!(
+                T::SubnetInfo::exists(netuid.into()),
+                Error::<T>::MechanismDoesNotExist
+            );
+
+            if whitelisted {
+                LiquidityProviderWhitelist::<T>::insert(netuid, &coldkey, ());
+            } else {
+                LiquidityProviderWhitelist::<T>::remove(netuid, &coldkey);
+            }
+
+            Self::deposit_event(Event::LiquidityProviderWhitelistUpdated {
+                netuid,
+                coldkey,
+                whitelisted,
+            });
+
+            Ok(())
+        }
+
+        /// Add liquidity to a specific price range from TAO only or from Alpha only.
+        ///
+        /// The part of `amount` that has to be the other token is staked or unstaked first, with
+        /// the split chosen against the price the swap itself moves the pool to. The position is
+        /// then minted with the largest liquidity the swapped and remaining amounts allow.
+        /// Whatever the position cannot take stays with the caller.
+        ///
+        /// Parameters:
+        /// - origin: The origin of the transaction
+        /// - hotkey: The hotkey account the Alpha is staked to
+        /// - netuid: Subnet ID
+        /// - tick_low: Lower bound of the price range
+        /// - tick_high: Upper bound of the price range
+        /// - amount: Amount of TAO or Alpha to deposit
+        /// - tao_input: Whether `amount` is TAO rather than Alpha
+        /// - min_liquidity: Minimum liquidity of the minted position
+        ///
+        /// Emits `Event::SingleSidedLiquiditySwapped` if a swap was needed and
+        /// `Event::LiquidityAdded` with the exact amounts deposited on success
+        #[pallet::call_index(21)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::add_liquidity_single_sided())]
+        pub fn add_liquidity_single_sided(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: NetUid,
+            tick_low: TickIndex,
+            tick_high: TickIndex,
+            amount: u64,
+            tao_input: bool,
+            min_liquidity: u64,
+        ) -> DispatchResult {
+            let coldkey = ensure_signed(origin)?;
+
+            // Ensure that the subnet exists and has a pool to swap against.
+            ensure!(
+                T::SubnetInfo::exists(netuid.into()) && T::SubnetInfo::mechanism(netuid) == 1,
+                Error::<T>::MechanismDoesNotExist
+            );
+
+            ensure!(
+                T::SubnetInfo::is_subtoken_enabled(netuid.into()),
+                Error::<T>::SubtokenDisabled
+            );
+            ensure!(
+                Self::is_user_liquidity_allowed(netuid, &coldkey),
+                Error::<T>::UserLiquidityDisabled
+            );
+            ensure!(
+                Self::is_tick_aligned(netuid, tick_low) && Self::is_tick_aligned(netuid, tick_high),
+                Error::<T>::TickNotAligned
+            );
+            ensure!(tick_high > tick_low, Error::<T>::InvalidTickRange);
+
+            let balance = if tao_input {
+                T::BalanceOps::tao_balance(&coldkey).to_u64()
+            } else {
+                T::BalanceOps::alpha_balance(netuid, &coldkey, &hotkey).to_u64()
+            };
+            ensure!(balance >= amount, Error::<T>::InsufficientBalance);
+
+            Self::maybe_initialize_v3(netuid)?;
+            let swap_amount =
+                Self::single_sided_swap_amount(netuid, tick_low, tick_high, amount, tao_input)?;
+
+            let (mut tao, mut alpha) = if tao_input { (amount, 0) } else { (0, amount) };
+            if swap_amount > 0 {
+                // The swapped part is staked or unstaked like any other stake of the caller
+                let amount_out = if tao_input {
+                    T::BalanceOps::stake(&coldkey, &hotkey, netuid, swap_amount.into())?.to_u64()
+                } else {
+                    T::BalanceOps::unstake(&coldkey, &hotkey, netuid, swap_amount.into())?.to_u64()
+                };
+                let amount_left = amount.saturating_sub(swap_amount);
+                (tao, alpha) = if tao_input {
+                    (amount_left, amount_out)
+                } else {
+                    (amount_out, amount_left)
+                };
+
+                Self::deposit_event(Event::SingleSidedLiquiditySwapped {
+                    coldkey: coldkey.clone(),
+                    hotkey: hotkey.clone(),
+                    netuid,
+                    tao_input,
+                    amount_in: swap_amount,
+                    amount_out,
+                });
+            }
+
+            let (liquidity, _) = Self::liquidity_for_amounts(
+                Self::current_price_sqrt(netuid),
+                tick_low,
+                tick_high,
+                tao,
+                alpha,
+            )?;
+            ensure!(
+                liquidity >= min_liquidity,
+                Error::<T>::SingleSidedLiquidityTooLow
+            );
+
+            Self::add_liquidity(
+                frame_system::RawOrigin::Signed(coldkey).into(),
+                hotkey,
+                netuid,
+                tick_low,
+                tick_high,
+                liquidity,
+            )
+        }
+
+        /// Set the price floor of a subnet and lock TAO into its reserve. Whenever a swap leaves
+        /// the price below the floor, the reserve buys Alpha until the price is back at the floor
+        /// and stakes it to `hotkey`. A depleted floor is paused until it is funded again.
+        ///
+        /// Setting the floor again changes the price and hotkey and adds to the reserve. The
+        /// reserve of a floor funded by a previous owner is refunded to it.
+        ///
+        /// Only callable by the subnet owner
+        ///
+        /// Parameters:
+        /// - origin: The subnet owner coldkey, which the TAO is taken from
+        /// - hotkey: The hotkey account the bought Alpha is staked to
+        /// - netuid: Subnet ID
+        /// - price: The floor price in rao per 10^9 Alpha units
+        /// - tao: Amount of TAO to add to the reserve
+        ///
+        /// Emits `Event::PriceFloorSet` on success
+        #[pallet::call_index(22)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_price_floor())]
+        pub fn set_price_floor(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            netuid: NetUid,
+            price: u64,
+            tao: TaoCurrency,
+        ) -> DispatchResult {
+            let coldkey = ensure_signed(origin)?;
+            ensure!(
+                T::SubnetInfo::is_owner(&coldkey, netuid.into()),
+                DispatchError::BadOrigin
+            );
+
+            // Ensure that the subnet exists and has a pool to buy from.
+            ensure!(
+                T::SubnetInfo::exists(netuid.into()) && T::SubnetInfo::mechanism(netuid) == 1,
+                Error::<T>::MechanismDoesNotExist
+            );
+
+            ensure!(
+                T::SubnetInfo::is_subtoken_enabled(netuid.into()),
+                Error::<T>::SubtokenDisabled
+            );
+
+            let floor = Self::do_set_price_floor(netuid, &coldkey, &hotkey, price, tao)?;
+
+            Self::deposit_event(Event::PriceFloorSet {
+                netuid,
+                coldkey,
+                price,
+                tao,
+                reserve: floor.reserve,
+            });
+
+            // A floor above the current price is defended right away
+            Self::defend_price_floor(netuid);
+
+            Ok(())
+        }
+
+        /// Remove the price floor of a subnet and refund its reserve to the coldkey that funded
+        /// it.
+        ///
+        /// Only callable by the admin origin or the subnet owner
+        ///
+        /// Parameters:
+        /// - origin: The admin origin or the subnet owner
+        /// - netuid: Subnet ID
+        ///
+        /// Emits `Event::PriceFloorRemoved` on success
+        #[pallet::call_index(23)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_price_floor())]
+        pub fn remove_price_floor(origin: OriginFor<T>, netuid: NetUid) -> DispatchResult {
+            if ensure_root(origin.clone()).is_err() {
+                let account_id: T::AccountId = ensure_signed(origin)?;
+                ensure!(
+                    T::SubnetInfo::is_owner(&account_id, netuid.into()),
+                    DispatchError::BadOrigin
+                );
+            }
+
+            let floor = Self::do_remove_price_floor(netuid)?;
+
+            Self::deposit_event(Event::PriceFloorRemoved {
+                netuid,
+                coldkey: floor.coldkey,
+                refund: floor.reserve,
+            });
+
+            Ok(())
+        }
+
+        /// Lock the liquidity of a position of the subnet owner for `duration` blocks, so that it
+        /// cannot be removed, decreased or transferred before the lock ends. Locking an already
+        /// locked position extends its lock, which can never end earlier than it already does.
+        ///
+        /// Only callable by the subnet owner
+        ///
+        /// Parameters:
+        /// - origin: The subnet owner coldkey, which owns the position
+        /// - netuid: Subnet ID
+        /// - position_id: ID of the position to lock
+        /// - duration: Number of blocks from now the liquidity stays locked
+        ///
+        /// Emits `Event::LiquidityLocked` on success
+        #[pallet::call_index(24)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::lock_liquidity())]
+        pub fn lock_liquidity(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            position_id: PositionId,
+            duration: u64,
+        ) -> DispatchResult {
+            let coldkey = ensure_signed(origin)?;
+            ensure!(
+                T::SubnetInfo::is_owner(&coldkey, netuid.into()),
+                DispatchError::BadOrigin
+            );
+
+            // Ensure that the subnet exists.
+            ensure!(
+                T::SubnetInfo::exists(netuid.into()),
+                Error::<T>::MechanismDoesNotExist
+            );
+
+            let (lock, liquidity) =
+                Self::do_lock_liquidity(netuid, &coldkey, position_id, duration)?;
+
+            Self::deposit_event(Event::LiquidityLocked {
+                netuid,
+                coldkey,
+                position_id,
+                liquidity,
+                unlocks_at: lock.unlocks_at,
+            });
+
+            Ok(())
+        }
+
+        /// Set the share of swap fees diverted to the protocol, the bounds within which subnet
+        /// owners can override it and the account the protocol fees are paid to. Rates are
+        /// normalized values between 0 and u16::MAX.
+        ///
+        /// Only callable by the admin origin
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_protocol_fee())]
+        pub fn set_protocol_fee(
+            origin: OriginFor<T>,
+            rate: u16,
+            min_rate: u16,
+            max_rate: u16,
+            destination: ProtocolFeeDestination<T::AccountId>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(
+                min_rate <= rate && rate <= max_rate,
+                Error::<T>::ProtocolFeeRateOutOfBounds
+            );
+
+            ProtocolFeeRate::<T>::put(rate);
+            ProtocolFeeRateBounds::<T>::put((min_rate, max_rate));
+            ProtocolFeeRecipient::<T>::put(destination.clone());
+
+            Self::deposit_event(Event::ProtocolFeeSet {
+                rate,
+                min_rate,
+                max_rate,
+                destination,
+            });
+
+            Ok(())
+        }
+
+        /// Override the protocol fee rate of a subnet within the bounds set by the admin origin,
+        /// or reset it to the default rate with `None`.
+        ///
+        /// Only callable by the admin origin or the subnet owner
+        #[pallet::call_index(15)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_subnet_protocol_fee_rate())]
+        pub fn set_subnet_protocol_fee_rate(
+            origin: OriginFor<T>,
+            netuid: NetUid,
+            rate: Option<u16>,
+        ) -> DispatchResult {
+            if ensure_root(origin.clone()).is_err() {
+                let account_id: T::AccountId = ensure_signed(origin)?;
+                ensure!(
+                    T::SubnetInfo::is_owner(&account_id, netuid.into()),
+                    DispatchError::BadOrigin
+                );
+            }
+
+            // Ensure that the subnet exists.
+            ensure!(
+                T::SubnetInfo::exists(netuid.into()),
+                Error::<T>::MechanismDoesNotExist
+            );
+
+            match rate {
+                Some(rate) => {
+                    let (min_rate, max_rate) = ProtocolFeeRateBounds::<T>::get();
+                    ensure!(
+                        (min_rate..=max_rate).contains(&rate),
+                        Error::<T>::ProtocolFeeRateOutOfBounds
+                    );
+                    SubnetProtocolFeeRate::<T>::insert(netuid, rate);
+                }
+                None => SubnetProtocolFeeRate::<T>::remove(netuid),
+            }
+
+            Self::deposit_event(Event::SubnetProtocolFeeRateSet { netuid, rate });
+
+            Ok(())
+        }
+
+        /// Pay the protocol fees accrued on a subnet out to the protocol fee recipient.
+        ///
+        /// Callable by any signed origin
+        ///
+        /// Emits `Event::ProtocolFeesClaimed` on success
+        #[pallet::call_index(16)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::claim_protocol_fees())]
+        pub fn claim_protocol_fees(origin: OriginFor<T>, netuid: NetUid) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let destination = ProtocolFeeRecipient::<T>::get();
+            let (coldkey, hotkey) = match destination.clone() {
+                ProtocolFeeDestination::SubnetOwner | ProtocolFeeDestination::Treasury => {
+                    T::SubnetInfo::owner_coldkey(netuid)
+                        .zip(T::SubnetInfo::owner_hotkey(netuid))
+                        .ok_or(Error::<T>::NoProtocolFeeRecipient)?
+                }
+                ProtocolFeeDestination::Account { coldkey, hotkey } => (coldkey, hotkey),
+            };
+
+            let fee_tao = ProtocolFeesTao::<T>::take(netuid);
+            let fee_alpha = ProtocolFeesAlpha::<T>::take(netuid);
+
+            if fee_tao > TaoCurrency::ZERO {
+                if destination == ProtocolFeeDestination::Treasury {
+                    T::BalanceOps::increase_network_treasury(fee_tao);
+                } else {
+                    T::BalanceOps::increase_balance(&coldkey, fee_tao);
+                }
+            }
+            if !fee_alpha.is_zero() {
+                T::BalanceOps::increase_stake(&coldkey, &hotkey, netuid, fee_alpha)?;
+            }
+
+            Self::deposit_event(Event::ProtocolFeesClaimed {
+                netuid,
+                coldkey,
+                hotkey,
+                fee_tao,
+                fee_alpha,
+            });
+
+            Ok(())
+        }
     }
 }
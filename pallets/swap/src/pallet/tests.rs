@@ -92,7 +92,7 @@ mod dispatchables {
             // Check that fee rate was set correctly
             assert_eq!(FeeRate::<Test>::get(netuid), fee_rate);
 
-            let fee_rate = fee_rate * 2;
+            let fee_rate = 600;
             assert_ok!(Swap::set_fee_rate(
                 RuntimeOrigin::signed(1),
                 netuid,
@@ -100,6 +100,17 @@ mod dispatchables {
             ));
             assert_eq!(FeeRate::<Test>::get(netuid), fee_rate);
 
+            // The subnet owner can only set a rate within the bounds
+            let (min_rate, max_rate) = FeeRateBounds::<Test>::get();
+            assert_noop!(
+                Swap::set_fee_rate(RuntimeOrigin::signed(1), netuid, max_rate + 1),
+                Error::<Test>::FeeRateOutOfBounds
+            );
+            assert_noop!(
+                Swap::set_fee_rate(RuntimeOrigin::signed(1), netuid, min_rate - 1),
+                Error::<Test>::FeeRateOutOfBounds
+            );
+
             // Verify fee rate validation - should fail if too high
             let too_high_fee = MaxFeeRate::get() + 1;
             assert_noop!(
@@ -109,6 +120,93 @@ mod dispatchables {
         });
     }
 
+    #[test]
+    fn test_set_global_fee_rate() {
+        new_test_ext().execute_with(|| {
+            let netuid = NetUid::from(1);
+            let other_netuid = NetUid::from(2);
+
+            assert_noop!(
+                Swap::set_global_fee_rate(RuntimeOrigin::signed(1), 100, 10, 1000),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                Swap::set_global_fee_rate(RuntimeOrigin::root(), 5, 10, 1000),
+                Error::<Test>::FeeRateOutOfBounds
+            );
+            assert_noop!(
+                Swap::set_global_fee_rate(RuntimeOrigin::root(), 100, 10, MaxFeeRate::get() + 1),
+                Error::<Test>::FeeRateTooHigh
+            );
+
+            assert_ok!(Swap::set_fee_rate(RuntimeOrigin::root(), netuid, 500));
+            assert_ok!(Swap::set_global_fee_rate(
+                RuntimeOrigin::root(),
+                100,
+                10,
+                1000
+            ));
+
+            // Subnets without their own rate use the global rate
+            assert_eq!(GlobalFeeRate::<Test>::get(), 100);
+            assert_eq!(FeeRate::<Test>::get(other_netuid), 100);
+            assert_eq!(FeeRate::<Test>::get(netuid), 500);
+            assert_eq!(FeeRateBounds::<Test>::get(), (10, 1000));
+
+            // The subnet owner can use the new bounds
+            assert_ok!(Swap::set_fee_rate(RuntimeOrigin::signed(1), netuid, 1000));
+            assert_eq!(FeeRate::<Test>::get(netuid), 1000);
+        });
+    }
+
+    #[test]
+    fn test_set_tick_spacing() {
+        new_test_ext().execute_with(|| {
+            let netuid = NetUid::from(1);
+
+            assert_noop!(
+                Swap::set_tick_spacing(RuntimeOrigin::signed(1), netuid, 10),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                Swap::set_tick_spacing(RuntimeOrigin::root(), netuid, 0),
+                Error::<Test>::InvalidTickSpacing
+            );
+
+            // The protocol position does not block changing the spacing
+            assert_ok!(Pallet::<Test>::maybe_initialize_v3(netuid));
+            assert_ok!(Swap::set_tick_spacing(RuntimeOrigin::root(), netuid, 10));
+            assert_eq!(TickSpacing::<Test>::get(netuid), 10);
+
+            // Positions must start and end at multiples of the spacing
+            assert_noop!(
+                Pallet::<Test>::do_add_liquidity(
+                    netuid,
+                    &OK_COLDKEY_ACCOUNT_ID,
+                    &OK_HOTKEY_ACCOUNT_ID,
+                    TickIndex::new_unchecked(-105),
+                    TickIndex::new_unchecked(100),
+                    2_000_000_000,
+                ),
+                Error::<Test>::TickNotAligned
+            );
+            assert_ok!(Pallet::<Test>::do_add_liquidity(
+                netuid,
+                &OK_COLDKEY_ACCOUNT_ID,
+                &OK_HOTKEY_ACCOUNT_ID,
+                TickIndex::new_unchecked(-100),
+                TickIndex::new_unchecked(100),
+                2_000_000_000,
+            ));
+
+            // The spacing is locked once user positions exist
+            assert_noop!(
+                Swap::set_tick_spacing(RuntimeOrigin::root(), netuid, 20),
+                Error::<Test>::UserPositionsExist
+            );
+        });
+    }
+
     #[test]
     fn test_toggle_user_liquidity() {
         new_test_ext().execute_with(|| {
@@ -145,6 +243,73 @@ mod dispatchables {
             );
         });
     }
+
+    #[test]
+    fn test_liquidity_provider_whitelist() {
+        new_test_ext().execute_with(|| {
+            // User liquidity is disabled above netuid 100 in the mock
+            let netuid = NetUid::from(101);
+            let add_liquidity = |coldkey, hotkey| {
+                Pallet::<Test>::do_add_liquidity(
+                    netuid,
+                    &coldkey,
+                    &hotkey,
+                    TickIndex::new_unchecked(-1000),
+                    TickIndex::new_unchecked(1000),
+                    1_000_000_000,
+                )
+            };
+
+            assert_noop!(
+                Swap::set_liquidity_provider_whitelisted(
+                    RuntimeOrigin::signed(NOT_SUBNET_OWNER),
+                    netuid,
+                    OK_COLDKEY_ACCOUNT_ID,
+                    true
+                ),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                Swap::set_liquidity_provider_whitelisted(
+                    RuntimeOrigin::root(),
+                    NON_EXISTENT_NETUID.into(),
+                    OK_COLDKEY_ACCOUNT_ID,
+                    true
+                ),
+                Error::<Test>::MechanismDoesNotExist
+            );
+
+            assert_ok!(Swap::set_liquidity_provider_whitelisted(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+                netuid,
+                OK_COLDKEY_ACCOUNT_ID,
+                true
+            ));
+            assert!(LiquidityProviderWhitelist::<Test>::contains_key(
+                netuid,
+                OK_COLDKEY_ACCOUNT_ID
+            ));
+
+            // Only whitelisted coldkeys may provide liquidity while the pool is closed
+            assert!(!EnabledUserLiquidity::<Test>::get(netuid));
+            assert_ok!(add_liquidity(OK_COLDKEY_ACCOUNT_ID, OK_HOTKEY_ACCOUNT_ID));
+            assert_noop!(
+                add_liquidity(OK_COLDKEY_ACCOUNT_ID_2, OK_HOTKEY_ACCOUNT_ID_2),
+                Error::<Test>::UserLiquidityDisabled
+            );
+
+            assert_ok!(Swap::set_liquidity_provider_whitelisted(
+                RuntimeOrigin::root(),
+                netuid,
+                OK_COLDKEY_ACCOUNT_ID,
+                false
+            ));
+            assert_noop!(
+                add_liquidity(OK_COLDKEY_ACCOUNT_ID, OK_HOTKEY_ACCOUNT_ID),
+                Error::<Test>::UserLiquidityDisabled
+            );
+        });
+    }
 }
 
 #[test]
@@ -1736,6 +1901,106 @@ fn test_new_lp_doesnt_get_old_fees() {
     });
 }
 
+// cargo test --package pallet-subtensor-swap --lib -- pallet::tests::test_collect_fees_keeps_liquidity --exact --show-output
+#[test]
+fn test_collect_fees_keeps_liquidity() {
+    new_test_ext().execute_with(|| {
+        let min_price = tick_to_price(TickIndex::MIN);
+        let max_price = tick_to_price(TickIndex::MAX);
+        let netuid = NetUid::from(1);
+        let liquidity = 2_000_000_000_000_u64;
+
+        assert_ok!(Pallet::<Test>::maybe_initialize_v3(netuid));
+
+        let tick_low = price_to_tick(min_price);
+        let tick_high = price_to_tick(max_price);
+        let (position_id, _, _) = Pallet::<Test>::do_add_liquidity(
+            netuid,
+            &OK_COLDKEY_ACCOUNT_ID,
+            &OK_HOTKEY_ACCOUNT_ID,
+            tick_low,
+            tick_high,
+            liquidity,
+        )
+        .unwrap();
+
+        // Swap both ways to accrue TAO and Alpha fees on the position
+        Pallet::<Test>::do_swap(
+            netuid,
+            OrderType::Buy,
+            liquidity / 10,
+            u64::MAX.into(),
+            false,
+            false,
+        )
+        .unwrap();
+        Pallet::<Test>::do_swap(
+            netuid,
+            OrderType::Sell,
+            liquidity / 10,
+            0_u64.into(),
+            false,
+            false,
+        )
+        .unwrap();
+
+        let current_liquidity_before = CurrentLiquidity::<Test>::get(netuid);
+        let tick_low_info_before = Ticks::<Test>::get(netuid, tick_low).unwrap();
+
+        let (fee_tao, fee_alpha) =
+            Pallet::<Test>::do_collect_fees(netuid, &OK_COLDKEY_ACCOUNT_ID, position_id).unwrap();
+        assert!(fee_tao > TaoCurrency::ZERO);
+        assert!(fee_alpha > AlphaCurrency::ZERO);
+
+        // Liquidity stays in range and untouched
+        let position =
+            Positions::<Test>::get((netuid, OK_COLDKEY_ACCOUNT_ID, position_id)).unwrap();
+        assert_eq!(position.liquidity, liquidity);
+        assert_eq!(position.tick_low, tick_low);
+        assert_eq!(position.tick_high, tick_high);
+        assert_eq!(
+            CurrentLiquidity::<Test>::get(netuid),
+            current_liquidity_before
+        );
+        assert_eq!(
+            Ticks::<Test>::get(netuid, tick_low).unwrap(),
+            tick_low_info_before
+        );
+
+        // Fees can't be collected twice
+        assert_ok!(Swap::collect_fees(
+            RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+            OK_HOTKEY_ACCOUNT_ID,
+            netuid,
+            position_id,
+        ));
+        let (fee_tao, fee_alpha) =
+            Pallet::<Test>::do_collect_fees(netuid, &OK_COLDKEY_ACCOUNT_ID, position_id).unwrap();
+        assert_eq!(fee_tao, TaoCurrency::ZERO);
+        assert_eq!(fee_alpha, AlphaCurrency::ZERO);
+
+        // Unknown positions and subnets are rejected
+        assert_noop!(
+            Swap::collect_fees(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID_2),
+                OK_HOTKEY_ACCOUNT_ID_2,
+                netuid,
+                position_id,
+            ),
+            Error::<Test>::LiquidityNotFound
+        );
+        assert_noop!(
+            Swap::collect_fees(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+                OK_HOTKEY_ACCOUNT_ID,
+                NON_EXISTENT_NETUID.into(),
+                position_id,
+            ),
+            Error::<Test>::MechanismDoesNotExist
+        );
+    });
+}
+
 fn bbox(t: U64F64, a: U64F64, b: U64F64) -> U64F64 {
     if t < a {
         a
@@ -2789,3 +3054,1178 @@ fn test_clear_protocol_liquidity_green_path() {
         assert!(!SwapV3Initialized::<Test>::contains_key(netuid));
     });
 }
+
+#[test]
+fn test_resize_position_keeps_accrued_fees() {
+    new_test_ext().execute_with(|| {
+        let min_price = tick_to_price(TickIndex::MIN);
+        let max_price = tick_to_price(TickIndex::MAX);
+        let netuid = NetUid::from(1);
+        let liquidity = 2_000_000_000_000_u64;
+        let delta = 1_000_000_000_000_u64;
+
+        assert_ok!(Pallet::<Test>::maybe_initialize_v3(netuid));
+
+        let tick_low = price_to_tick(min_price);
+        let tick_high = price_to_tick(max_price);
+        let (position_id, _, _) = Pallet::<Test>::do_add_liquidity(
+            netuid,
+            &OK_COLDKEY_ACCOUNT_ID,
+            &OK_HOTKEY_ACCOUNT_ID,
+            tick_low,
+            tick_high,
+            liquidity,
+        )
+        .unwrap();
+
+        // Swap both ways to accrue TAO and Alpha fees on the position
+        Pallet::<Test>::do_swap(
+            netuid,
+            OrderType::Buy,
+            liquidity / 10,
+            u64::MAX.into(),
+            false,
+            false,
+        )
+        .unwrap();
+        Pallet::<Test>::do_swap(
+            netuid,
+            OrderType::Sell,
+            liquidity / 10,
+            0_u64.into(),
+            false,
+            false,
+        )
+        .unwrap();
+
+        let accrued_fees = Positions::<Test>::get((netuid, OK_COLDKEY_ACCOUNT_ID, position_id))
+            .unwrap()
+            .collect_fees();
+        assert!(accrued_fees.0 > 0);
+        assert!(accrued_fees.1 > 0);
+
+        let current_liquidity_before = CurrentLiquidity::<Test>::get(netuid);
+        assert_ok!(Swap::increase_liquidity(
+            RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+            OK_HOTKEY_ACCOUNT_ID,
+            netuid,
+            position_id,
+            delta,
+        ));
+
+        let mut position =
+            Positions::<Test>::get((netuid, OK_COLDKEY_ACCOUNT_ID, position_id)).unwrap();
+        assert_eq!(position.liquidity, liquidity + delta);
+        assert_eq!(
+            CurrentLiquidity::<Test>::get(netuid),
+            current_liquidity_before + delta
+        );
+
+        // Fees accrued before the resize are still collectable
+        let fees_after_increase = position.clone().collect_fees();
+        assert_abs_diff_eq!(fees_after_increase.0, accrued_fees.0, epsilon = 1);
+        assert_abs_diff_eq!(fees_after_increase.1, accrued_fees.1, epsilon = 1);
+
+        assert_ok!(Swap::decrease_liquidity(
+            RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+            OK_HOTKEY_ACCOUNT_ID,
+            netuid,
+            position_id,
+            delta,
+        ));
+
+        position = Positions::<Test>::get((netuid, OK_COLDKEY_ACCOUNT_ID, position_id)).unwrap();
+        assert_eq!(position.liquidity, liquidity);
+        assert_eq!(
+            CurrentLiquidity::<Test>::get(netuid),
+            current_liquidity_before
+        );
+        let fees_after_decrease = position.collect_fees();
+        assert_abs_diff_eq!(fees_after_decrease.0, accrued_fees.0, epsilon = 1);
+        assert_abs_diff_eq!(fees_after_decrease.1, accrued_fees.1, epsilon = 1);
+
+        // Decreasing never closes the position
+        assert_noop!(
+            Swap::decrease_liquidity(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+                OK_HOTKEY_ACCOUNT_ID,
+                netuid,
+                position_id,
+                liquidity,
+            ),
+            Error::<Test>::InsufficientLiquidity
+        );
+    });
+}
+
+#[test]
+fn test_price_candles() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let amount = 1_000_000_000_u64;
+
+        assert_ok!(Pallet::<Test>::maybe_initialize_v3(netuid));
+        assert_ok!(Swap::set_candle_interval(RuntimeOrigin::root(), netuid, 10));
+        assert_noop!(
+            Swap::set_candle_interval(RuntimeOrigin::root(), netuid, 0),
+            Error::<Test>::InvalidCandleInterval
+        );
+
+        // Simulated swaps are not recorded
+        let price_open = Pallet::<Test>::current_price_rao(netuid);
+        Pallet::<Test>::do_swap(netuid, OrderType::Buy, amount, u64::MAX.into(), false, true)
+            .unwrap();
+        assert!(Pallet::<Test>::price_candles(netuid).is_empty());
+
+        // Buy then sell within one interval updates a single candle
+        Pallet::<Test>::do_swap(
+            netuid,
+            OrderType::Buy,
+            amount,
+            u64::MAX.into(),
+            false,
+            false,
+        )
+        .unwrap();
+        let price_high = Pallet::<Test>::current_price_rao(netuid);
+        Pallet::<Test>::do_swap(
+            netuid,
+            OrderType::Sell,
+            amount * 8,
+            0_u64.into(),
+            false,
+            false,
+        )
+        .unwrap();
+        let price_close = Pallet::<Test>::current_price_rao(netuid);
+
+        let candles = Pallet::<Test>::price_candles(netuid);
+        assert_eq!(candles.len(), 1);
+        assert_eq!(candles[0].start_block, 0);
+        assert_eq!(candles[0].open, price_open);
+        assert_eq!(candles[0].high, price_high);
+        assert_eq!(candles[0].low, price_close);
+        assert_eq!(candles[0].close, price_close);
+        assert!(price_high > price_open && price_close < price_open);
+
+        // Later intervals open new candles and the oldest ones are dropped
+        for block in [15, 25, 35] {
+            System::set_block_number(block);
+            Pallet::<Test>::do_swap(
+                netuid,
+                OrderType::Buy,
+                amount,
+                u64::MAX.into(),
+                false,
+                false,
+            )
+            .unwrap();
+        }
+
+        let candles = Pallet::<Test>::price_candles(netuid);
+        assert_eq!(candles.len(), 3);
+        assert_eq!(
+            candles.iter().map(|c| c.start_block).collect::<Vec<_>>(),
+            vec![10, 20, 30]
+        );
+        assert_eq!(candles[0].open, price_close);
+
+        // Changing the interval discards the history
+        assert_ok!(Swap::set_candle_interval(RuntimeOrigin::root(), netuid, 20));
+        assert!(Pallet::<Test>::price_candles(netuid).is_empty());
+    });
+}
+
+#[test]
+fn test_twap_price() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let amount = 1_000_000_000_u64;
+        let swap = |order_type: OrderType, simulate: bool| {
+            let limit = match order_type {
+                OrderType::Buy => u64::MAX,
+                OrderType::Sell => 0,
+            };
+            Pallet::<Test>::do_swap(netuid, order_type, amount, limit.into(), false, simulate)
+                .unwrap();
+        };
+
+        assert_ok!(Pallet::<Test>::maybe_initialize_v3(netuid));
+        System::set_block_number(10);
+        assert_eq!(Pallet::<Test>::twap_price(netuid, 5), None);
+        assert_eq!(
+            Pallet::<Test>::twap_price(netuid, 0),
+            Some(Pallet::<Test>::current_price_rao(netuid))
+        );
+
+        // Simulated swaps are not observed
+        swap(OrderType::Buy, true);
+        assert!(PriceObservations::<Test>::get(netuid, 0).is_none());
+
+        swap(OrderType::Buy, false);
+        let price_1 = Pallet::<Test>::current_price_rao(netuid);
+
+        System::set_block_number(20);
+        assert_eq!(Pallet::<Test>::twap_price(netuid, 10), Some(price_1));
+        assert_eq!(Pallet::<Test>::twap_price(netuid, 11), None);
+
+        swap(OrderType::Sell, false);
+        let price_2 = Pallet::<Test>::current_price_rao(netuid);
+        assert_ne!(price_1, price_2);
+
+        // The average is weighted by the number of blocks each price was in effect
+        System::set_block_number(30);
+        assert_eq!(
+            Pallet::<Test>::twap_price(netuid, 20),
+            Some((price_1 + price_2) / 2)
+        );
+        assert_eq!(Pallet::<Test>::twap_price(netuid, 5), Some(price_2));
+
+        // Only the latest `MaxPriceObservations` observations are kept
+        for block in [40, 50, 60] {
+            System::set_block_number(block);
+            swap(OrderType::Buy, false);
+        }
+        assert_eq!(Pallet::<Test>::twap_price(netuid, 50), None);
+        assert!(Pallet::<Test>::twap_price(netuid, 40).is_some());
+    });
+}
+
+#[test]
+fn test_sim_swap_with_price() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let amount = 1_000_000_000_u64;
+        assert_ok!(Pallet::<Test>::maybe_initialize_v3(netuid));
+
+        for order_type in [OrderType::Buy, OrderType::Sell] {
+            let price_before = Pallet::<Test>::current_price_rao(netuid);
+            let (result, price_after) =
+                Pallet::<Test>::sim_swap_with_price(netuid, order_type, amount).unwrap();
+
+            // The simulation is not persisted
+            assert_eq!(Pallet::<Test>::current_price_rao(netuid), price_before);
+            assert_ne!(price_after, price_before);
+
+            let limit = match order_type {
+                OrderType::Buy => u64::MAX,
+                OrderType::Sell => 0,
+            };
+            let swapped =
+                Pallet::<Test>::do_swap(netuid, order_type, amount, limit.into(), false, false)
+                    .unwrap();
+            assert_eq!(result, swapped);
+            assert_eq!(Pallet::<Test>::current_price_rao(netuid), price_after);
+        }
+    });
+}
+
+#[test]
+fn test_transfer_position() {
+    new_test_ext().execute_with(|| {
+        let min_price = tick_to_price(TickIndex::MIN);
+        let max_price = tick_to_price(TickIndex::MAX);
+        let netuid = NetUid::from(1);
+        let liquidity = 2_000_000_000_000_u64;
+
+        assert_ok!(Pallet::<Test>::maybe_initialize_v3(netuid));
+
+        let (position_id, _, _) = Pallet::<Test>::do_add_liquidity(
+            netuid,
+            &OK_COLDKEY_ACCOUNT_ID,
+            &OK_HOTKEY_ACCOUNT_ID,
+            price_to_tick(min_price),
+            price_to_tick(max_price),
+            liquidity,
+        )
+        .unwrap();
+        let position =
+            Positions::<Test>::get((netuid, OK_COLDKEY_ACCOUNT_ID, position_id)).unwrap();
+
+        // Only the owner can transfer the position, and not to itself
+        assert_noop!(
+            Swap::transfer_position(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID_2),
+                netuid,
+                position_id,
+                OK_COLDKEY_ACCOUNT_ID_2,
+            ),
+            Error::<Test>::InvalidTransferDestination
+        );
+        assert_noop!(
+            Swap::transfer_position(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID_2),
+                netuid,
+                position_id,
+                OK_COLDKEY_ACCOUNT_ID,
+            ),
+            Error::<Test>::LiquidityNotFound
+        );
+
+        // Transfers can be disabled per subnet
+        assert_ok!(Swap::toggle_position_transfers(
+            RuntimeOrigin::root(),
+            netuid,
+            false
+        ));
+        assert_noop!(
+            Swap::transfer_position(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+                netuid,
+                position_id,
+                OK_COLDKEY_ACCOUNT_ID_2,
+            ),
+            Error::<Test>::PositionTransfersDisabled
+        );
+        assert_ok!(Swap::toggle_position_transfers(
+            RuntimeOrigin::root(),
+            netuid,
+            true
+        ));
+
+        let current_liquidity_before = CurrentLiquidity::<Test>::get(netuid);
+        assert_ok!(Swap::transfer_position(
+            RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+            netuid,
+            position_id,
+            OK_COLDKEY_ACCOUNT_ID_2,
+        ));
+
+        // The position moved as is, pool liquidity is untouched
+        assert!(!Positions::<Test>::contains_key((
+            netuid,
+            OK_COLDKEY_ACCOUNT_ID,
+            position_id
+        )));
+        assert_eq!(
+            Positions::<Test>::get((netuid, OK_COLDKEY_ACCOUNT_ID_2, position_id)),
+            Some(position)
+        );
+        assert_eq!(
+            CurrentLiquidity::<Test>::get(netuid),
+            current_liquidity_before
+        );
+
+        // The new owner can remove the liquidity
+        assert_ok!(Swap::remove_liquidity(
+            RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID_2),
+            OK_HOTKEY_ACCOUNT_ID_2,
+            netuid,
+            position_id,
+        ));
+    });
+}
+
+#[test]
+fn test_limit_orders() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let amount = 1_000_000_000_u64;
+
+        assert_ok!(Pallet::<Test>::maybe_initialize_v3(netuid));
+        let current_tick = CurrentTick::<Test>::get(netuid);
+        let sell_tick = current_tick.checked_add(10).unwrap();
+        let buy_tick = current_tick.checked_sub(10).unwrap();
+
+        // Orders must sit entirely on the side of the price they convert towards
+        assert_noop!(
+            Swap::place_limit_order(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+                OK_HOTKEY_ACCOUNT_ID,
+                netuid,
+                OrderType::Sell,
+                amount,
+                buy_tick,
+            ),
+            Error::<Test>::InvalidLimitOrderTick
+        );
+        assert_noop!(
+            Swap::place_limit_order(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+                OK_HOTKEY_ACCOUNT_ID,
+                netuid,
+                OrderType::Buy,
+                amount,
+                sell_tick,
+            ),
+            Error::<Test>::InvalidLimitOrderTick
+        );
+
+        assert_ok!(Swap::place_limit_order(
+            RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+            OK_HOTKEY_ACCOUNT_ID,
+            netuid,
+            OrderType::Sell,
+            amount,
+            sell_tick,
+        ));
+        assert_ok!(Swap::place_limit_order(
+            RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+            OK_HOTKEY_ACCOUNT_ID,
+            netuid,
+            OrderType::Buy,
+            amount,
+            buy_tick,
+        ));
+        assert_eq!(LimitOrderCount::<Test>::get(netuid), 2);
+
+        let order_id = |side: OrderType| {
+            LimitOrders::<Test>::iter_prefix(netuid)
+                .find(|(_, order)| order.side == side)
+                .map(|(position_id, _)| position_id)
+                .unwrap()
+        };
+        let sell_id = order_id(OrderType::Sell);
+        let buy_id = order_id(OrderType::Buy);
+
+        // The sell order holds Alpha only, the buy order TAO only
+        let sell_position =
+            Positions::<Test>::get((netuid, OK_COLDKEY_ACCOUNT_ID, sell_id)).unwrap();
+        let (tao, alpha) = sell_position
+            .to_token_amounts(Pallet::<Test>::current_price_sqrt(netuid))
+            .unwrap();
+        assert_eq!(tao, 0);
+        assert_abs_diff_eq!(alpha, amount, epsilon = amount / 1000);
+        let buy_position = Positions::<Test>::get((netuid, OK_COLDKEY_ACCOUNT_ID, buy_id)).unwrap();
+        let (tao, alpha) = buy_position
+            .to_token_amounts(Pallet::<Test>::current_price_sqrt(netuid))
+            .unwrap();
+        assert_eq!(alpha, 0);
+        assert_abs_diff_eq!(tao, amount, epsilon = amount / 1000);
+
+        // Order positions cannot be transferred, and only the owner can cancel
+        assert_noop!(
+            Swap::transfer_position(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+                netuid,
+                sell_id,
+                OK_COLDKEY_ACCOUNT_ID_2,
+            ),
+            Error::<Test>::LimitOrderNotTransferable
+        );
+        assert_noop!(
+            Swap::cancel_order(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID_2),
+                netuid,
+                sell_id
+            ),
+            Error::<Test>::LimitOrderNotFound
+        );
+
+        // A swap that moves the price across the sell tick queues the subnet for settlement
+        Pallet::<Test>::do_swap(
+            netuid,
+            OrderType::Buy,
+            amount * 10,
+            u64::MAX.into(),
+            false,
+            false,
+        )
+        .unwrap();
+        assert!(CurrentTick::<Test>::get(netuid) > sell_tick);
+        assert!(PendingLimitOrderSettlement::<Test>::get(netuid));
+
+        // Settlement waits for spare block weight
+        assert_eq!(Pallet::<Test>::on_idle(1, Weight::zero()), Weight::zero());
+        assert!(LimitOrders::<Test>::contains_key(netuid, sell_id));
+        assert!(PendingLimitOrderSettlement::<Test>::get(netuid));
+
+        // The sell order settles only
+        assert!(!Pallet::<Test>::on_idle(1, Weight::MAX).is_zero());
+        assert!(!PendingLimitOrderSettlement::<Test>::get(netuid));
+        assert!(!LimitOrders::<Test>::contains_key(netuid, sell_id));
+        assert!(!Positions::<Test>::contains_key((
+            netuid,
+            OK_COLDKEY_ACCOUNT_ID,
+            sell_id
+        )));
+        assert!(LimitOrders::<Test>::contains_key(netuid, buy_id));
+        assert_eq!(LimitOrderCount::<Test>::get(netuid), 1);
+
+        // Cancelling removes the order and its position
+        assert_ok!(Swap::cancel_order(
+            RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+            netuid,
+            buy_id
+        ));
+        assert!(!Positions::<Test>::contains_key((
+            netuid,
+            OK_COLDKEY_ACCOUNT_ID,
+            buy_id
+        )));
+        assert_eq!(LimitOrderCount::<Test>::get(netuid), 0);
+    });
+}
+
+#[test]
+fn test_swap_alpha_for_alpha() {
+    new_test_ext().execute_with(|| {
+        let origin_netuid = NetUid::from(1);
+        let destination_netuid = NetUid::from(2);
+        let amount = 1_000_000_000_u64;
+
+        assert_ok!(Pallet::<Test>::maybe_initialize_v3(origin_netuid));
+        assert_ok!(Pallet::<Test>::maybe_initialize_v3(destination_netuid));
+        let origin_price = AlphaSqrtPrice::<Test>::get(origin_netuid);
+        let destination_price = AlphaSqrtPrice::<Test>::get(destination_netuid);
+
+        // Routes must connect two different dynamic subnets
+        assert_noop!(
+            Pallet::<Test>::do_swap_alpha_for_alpha(
+                origin_netuid,
+                origin_netuid,
+                amount,
+                0,
+                false,
+                false
+            ),
+            Error::<Test>::InvalidSwapRoute
+        );
+        assert_noop!(
+            Pallet::<Test>::do_swap_alpha_for_alpha(
+                NetUid::ROOT,
+                destination_netuid,
+                amount,
+                0,
+                false,
+                false
+            ),
+            Error::<Test>::InvalidSwapRoute
+        );
+
+        // Simulation reports the route without touching either pool
+        let simulated = Pallet::<Test>::do_swap_alpha_for_alpha(
+            origin_netuid,
+            destination_netuid,
+            amount,
+            0,
+            false,
+            true,
+        )
+        .unwrap();
+        assert_eq!(AlphaSqrtPrice::<Test>::get(origin_netuid), origin_price);
+        assert_eq!(
+            AlphaSqrtPrice::<Test>::get(destination_netuid),
+            destination_price
+        );
+
+        // Both pools have the same price, so the route loses only fees and slippage
+        assert_eq!(
+            simulated.buy.amount_paid_in + simulated.buy.fee_paid,
+            simulated.sell.amount_paid_out
+        );
+        assert!(simulated.buy.amount_paid_out < amount);
+        assert_abs_diff_eq!(
+            simulated.buy.amount_paid_out,
+            amount,
+            epsilon = amount / 100
+        );
+        assert!(simulated.fee_paid_tao > 0);
+        assert!(simulated.fee_paid_tao > simulated.buy.fee_paid);
+
+        // A single bound covers the whole route, and failing it leaves no trace
+        assert_noop!(
+            Pallet::<Test>::do_swap_alpha_for_alpha(
+                origin_netuid,
+                destination_netuid,
+                amount,
+                simulated.buy.amount_paid_out + 1,
+                false,
+                false,
+            ),
+            Error::<Test>::SwapOutputTooLow
+        );
+
+        let result = Pallet::<Test>::do_swap_alpha_for_alpha(
+            origin_netuid,
+            destination_netuid,
+            amount,
+            simulated.buy.amount_paid_out,
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(result, simulated);
+        assert!(AlphaSqrtPrice::<Test>::get(origin_netuid) < origin_price);
+        assert!(AlphaSqrtPrice::<Test>::get(destination_netuid) > destination_price);
+    });
+}
+
+#[test]
+fn test_protocol_fee_switch() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let treasury = OK_COLDKEY_ACCOUNT_ID_2;
+        let treasury_hotkey = OK_HOTKEY_ACCOUNT_ID_2;
+        let destination = ProtocolFeeDestination::Account {
+            coldkey: treasury,
+            hotkey: treasury_hotkey,
+        };
+        assert_ok!(Pallet::<Test>::maybe_initialize_v3(netuid));
+
+        // Fees go to liquidity providers only by default
+        Pallet::<Test>::do_swap(
+            netuid,
+            OrderType::Buy,
+            1_000_000_000,
+            TickIndex::max_sqrt_price(),
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(ProtocolFeesTao::<Test>::get(netuid), TaoCurrency::ZERO);
+
+        assert_noop!(
+            Swap::set_protocol_fee(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+                u16::MAX / 2,
+                0,
+                u16::MAX,
+                destination.clone()
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Swap::set_protocol_fee(
+                RuntimeOrigin::root(),
+                u16::MAX / 2,
+                0,
+                u16::MAX / 4,
+                destination.clone()
+            ),
+            Error::<Test>::ProtocolFeeRateOutOfBounds
+        );
+        assert_ok!(Swap::set_protocol_fee(
+            RuntimeOrigin::root(),
+            u16::MAX / 2,
+            u16::MAX / 4,
+            u16::MAX / 2,
+            destination.clone()
+        ));
+
+        // Subnet owners can only override the rate within the bounds
+        assert_noop!(
+            Swap::set_subnet_protocol_fee_rate(
+                RuntimeOrigin::signed(NOT_SUBNET_OWNER),
+                netuid,
+                Some(u16::MAX / 4)
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Swap::set_subnet_protocol_fee_rate(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+                netuid,
+                Some(0)
+            ),
+            Error::<Test>::ProtocolFeeRateOutOfBounds
+        );
+        assert_ok!(Swap::set_subnet_protocol_fee_rate(
+            RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+            netuid,
+            Some(u16::MAX / 4)
+        ));
+        assert_eq!(Pallet::<Test>::protocol_fee_rate(netuid), u16::MAX / 4);
+        assert_eq!(
+            Pallet::<Test>::protocol_fee_rate(NetUid::from(2)),
+            u16::MAX / 2
+        );
+        assert_ok!(Swap::set_subnet_protocol_fee_rate(
+            RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+            netuid,
+            None
+        ));
+        assert_eq!(Pallet::<Test>::protocol_fee_rate(netuid), u16::MAX / 2);
+
+        // Half of the fees of both swap directions is held back for the protocol
+        let buy = Pallet::<Test>::do_swap(
+            netuid,
+            OrderType::Buy,
+            1_000_000_000,
+            TickIndex::max_sqrt_price(),
+            false,
+            false,
+        )
+        .unwrap();
+        let sell = Pallet::<Test>::do_swap(
+            netuid,
+            OrderType::Sell,
+            1_000_000_000,
+            TickIndex::min_sqrt_price(),
+            false,
+            false,
+        )
+        .unwrap();
+        assert_abs_diff_eq!(
+            ProtocolFeesTao::<Test>::get(netuid).to_u64(),
+            buy.fee_paid / 2,
+            epsilon = 10
+        );
+        assert_abs_diff_eq!(
+            ProtocolFeesAlpha::<Test>::get(netuid).to_u64(),
+            sell.fee_paid / 2,
+            epsilon = 10
+        );
+
+        let fee_tao = ProtocolFeesTao::<Test>::get(netuid);
+        let fee_alpha = ProtocolFeesAlpha::<Test>::get(netuid);
+        assert_ok!(Swap::claim_protocol_fees(
+            RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+            netuid
+        ));
+        assert_eq!(ProtocolFeesTao::<Test>::get(netuid), TaoCurrency::ZERO);
+        assert_eq!(ProtocolFeesAlpha::<Test>::get(netuid), AlphaCurrency::ZERO);
+        System::assert_last_event(
+            Event::<Test>::ProtocolFeesClaimed {
+                netuid,
+                coldkey: treasury,
+                hotkey: treasury_hotkey,
+                fee_tao,
+                fee_alpha,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_liquidity_incentive() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let tao = TaoCurrency::from(1_000_000_000);
+        let alpha = AlphaCurrency::from(2_000_000_000);
+        assert_ok!(Pallet::<Test>::maybe_initialize_v3(netuid));
+
+        assert_noop!(
+            Swap::fund_liquidity_incentive(
+                RuntimeOrigin::signed(NOT_SUBNET_OWNER),
+                OK_HOTKEY_ACCOUNT_ID,
+                netuid,
+                tao,
+                alpha,
+                10
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Swap::fund_liquidity_incentive(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+                OK_HOTKEY_ACCOUNT_ID,
+                netuid,
+                tao,
+                alpha,
+                0
+            ),
+            Error::<Test>::InvalidIncentiveDuration
+        );
+        assert_noop!(
+            Swap::fund_liquidity_incentive(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+                OK_HOTKEY_ACCOUNT_ID,
+                netuid,
+                TaoCurrency::ZERO,
+                AlphaCurrency::ZERO,
+                10
+            ),
+            Error::<Test>::ZeroLiquidityIncentive
+        );
+
+        // A user position with the same liquidity as the protocol position
+        let liquidity = CurrentLiquidity::<Test>::get(netuid);
+        let (position_id, _, _) = Pallet::<Test>::do_add_liquidity(
+            netuid,
+            &OK_COLDKEY_ACCOUNT_ID,
+            &OK_HOTKEY_ACCOUNT_ID,
+            TickIndex::MIN,
+            TickIndex::MAX,
+            liquidity,
+        )
+        .unwrap();
+
+        assert_ok!(Swap::fund_liquidity_incentive(
+            RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+            OK_HOTKEY_ACCOUNT_ID,
+            netuid,
+            tao,
+            alpha,
+            10
+        ));
+        let ends_at = LiquidityIncentives::<Test>::get(netuid).unwrap().ends_at;
+        assert_eq!(ends_at, System::block_number() + 10);
+
+        // The incentive is streamed once per block from the next block until it ends
+        let mut block = System::block_number();
+        while LiquidityIncentives::<Test>::contains_key(netuid) {
+            block += 1;
+            System::set_block_number(block);
+            Pallet::<Test>::stream_liquidity_incentives();
+        }
+        assert_eq!(block, ends_at);
+
+        // The user position earns its share of the incentive
+        let share = liquidity as f64
+            / (CurrentLiquidity::<Test>::get(netuid) - MinimumLiquidity::get()) as f64;
+        let (fee_tao, fee_alpha) =
+            Pallet::<Test>::do_collect_fees(netuid, &OK_COLDKEY_ACCOUNT_ID, position_id).unwrap();
+        assert_abs_diff_eq!(
+            fee_tao.to_u64() as f64,
+            tao.to_u64() as f64 * share,
+            epsilon = 1_000.
+        );
+        assert_abs_diff_eq!(
+            fee_alpha.to_u64() as f64,
+            alpha.to_u64() as f64 * share,
+            epsilon = 1_000.
+        );
+    });
+}
+
+#[test]
+fn test_add_liquidity_single_sided() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let amount = 1_000_000_000_u64;
+        assert_ok!(Pallet::<Test>::maybe_initialize_v3(netuid));
+
+        let deposited = || {
+            System::events()
+                .into_iter()
+                .rev()
+                .find_map(|record| match record.event {
+                    RuntimeEvent::Swap(Event::LiquidityAdded { tao, alpha, .. }) => {
+                        Some((tao.to_u64(), alpha.to_u64()))
+                    }
+                    _ => None,
+                })
+                .unwrap()
+        };
+        let swapped = || {
+            System::events()
+                .into_iter()
+                .rev()
+                .find_map(|record| match record.event {
+                    RuntimeEvent::Swap(Event::SingleSidedLiquiditySwapped {
+                        amount_in,
+                        amount_out,
+                        ..
+                    }) => Some((amount_in, amount_out)),
+                    _ => None,
+                })
+        };
+
+        // A range below the current price only takes TAO, nothing is swapped
+        assert_ok!(Swap::add_liquidity_single_sided(
+            RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+            OK_HOTKEY_ACCOUNT_ID,
+            netuid,
+            price_to_tick(0.12),
+            price_to_tick(0.13),
+            amount,
+            true,
+            0,
+        ));
+        assert_eq!(swapped(), None);
+        let (tao, alpha) = deposited();
+        assert_eq!(alpha, 0);
+        assert!(amount - tao <= 10);
+
+        // A range around the current price takes both, only dust is left over
+        let (tick_low, tick_high) = (price_to_tick(0.125), price_to_tick(0.5));
+        for tao_input in [true, false] {
+            System::reset_events();
+            assert_ok!(Swap::add_liquidity_single_sided(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+                OK_HOTKEY_ACCOUNT_ID,
+                netuid,
+                tick_low,
+                tick_high,
+                amount,
+                tao_input,
+                0,
+            ));
+            let (amount_in, amount_out) = swapped().unwrap();
+            let (tao, alpha) = deposited();
+            let (input_left, output_left) = if tao_input {
+                (amount - amount_in - tao, amount_out - alpha)
+            } else {
+                (amount - amount_in - alpha, amount_out - tao)
+            };
+            assert!(amount_in > 0 && amount_in < amount);
+            assert!(input_left < amount / 10_000);
+            assert!(output_left < amount_out / 10_000);
+        }
+
+        // The deposit is bounded by the minimum liquidity
+        assert_err!(
+            Swap::add_liquidity_single_sided(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+                OK_HOTKEY_ACCOUNT_ID,
+                netuid,
+                tick_low,
+                tick_high,
+                amount,
+                true,
+                u64::MAX,
+            ),
+            Error::<Test>::SingleSidedLiquidityTooLow
+        );
+    });
+}
+
+#[test]
+fn test_price_floor() {
+    new_test_ext().execute_with(|| {
+        let netuid = NetUid::from(1);
+        let floor_price = 200_000_000_u64;
+        let reserve = TaoCurrency::from(1_000_000_000);
+        assert_ok!(Pallet::<Test>::maybe_initialize_v3(netuid));
+
+        let sell = |amount| {
+            assert_ok!(Pallet::<Test>::do_swap(
+                netuid,
+                OrderType::Sell,
+                amount,
+                TickIndex::min_sqrt_price(),
+                false,
+                false,
+            ));
+        };
+
+        assert_noop!(
+            Swap::set_price_floor(
+                RuntimeOrigin::signed(NOT_SUBNET_OWNER),
+                OK_HOTKEY_ACCOUNT_ID,
+                netuid,
+                floor_price,
+                reserve
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Swap::set_price_floor(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+                OK_HOTKEY_ACCOUNT_ID,
+                netuid,
+                0,
+                reserve
+            ),
+            Error::<Test>::InvalidPriceFloor
+        );
+
+        // A floor below the current price does nothing until the price falls below it
+        assert_ok!(Swap::set_price_floor(
+            RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+            OK_HOTKEY_ACCOUNT_ID,
+            netuid,
+            floor_price,
+            reserve
+        ));
+        assert_eq!(PriceFloors::<Test>::get(netuid).unwrap().reserve, reserve);
+
+        // Selling far below the floor is bought back up to the floor
+        sell(1_000_000_000);
+        let floor = PriceFloors::<Test>::get(netuid).unwrap();
+        assert!(!floor.paused);
+        assert!(!floor.alpha_bought.is_zero());
+        assert_eq!(floor.reserve, reserve - floor.tao_spent);
+        assert_abs_diff_eq!(
+            Pallet::<Test>::current_price(netuid).to_num::<f64>(),
+            0.2,
+            epsilon = 0.000_001
+        );
+        System::assert_has_event(
+            Event::<Test>::PriceFloorDefended {
+                netuid,
+                tao: floor.tao_spent,
+                alpha: floor.alpha_bought,
+                reserve: floor.reserve,
+            }
+            .into(),
+        );
+
+        // The reserve is refunded on removal
+        assert_noop!(
+            Swap::remove_price_floor(RuntimeOrigin::signed(NOT_SUBNET_OWNER), netuid),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Swap::remove_price_floor(RuntimeOrigin::root(), netuid));
+        System::assert_last_event(
+            Event::<Test>::PriceFloorRemoved {
+                netuid,
+                coldkey: OK_COLDKEY_ACCOUNT_ID,
+                refund: floor.reserve,
+            }
+            .into(),
+        );
+        assert_noop!(
+            Swap::remove_price_floor(RuntimeOrigin::root(), netuid),
+            Error::<Test>::PriceFloorNotFound
+        );
+
+        // A reserve that cannot hold the floor pauses it
+        assert_ok!(Swap::set_price_floor(
+            RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+            OK_HOTKEY_ACCOUNT_ID,
+            netuid,
+            floor_price,
+            TaoCurrency::from(1_000)
+        ));
+        sell(1_000_000_000);
+        assert!(PriceFloors::<Test>::get(netuid).unwrap().paused);
+        System::assert_has_event(Event::<Test>::PriceFloorPaused { netuid }.into());
+
+        let price = AlphaSqrtPrice::<Test>::get(netuid);
+        let floor = PriceFloors::<Test>::get(netuid).unwrap();
+        sell(1_000_000);
+        assert!(AlphaSqrtPrice::<Test>::get(netuid) < price);
+        assert_eq!(PriceFloors::<Test>::get(netuid).unwrap(), floor);
+    });
+}
+
+#[test]
+fn test_lock_liquidity() {
+    new_test_ext().execute_with(|| {
+        let min_price = tick_to_price(TickIndex::MIN);
+        let max_price = tick_to_price(TickIndex::MAX);
+        let netuid = NetUid::from(1);
+        let liquidity = 2_000_000_000_000_u64;
+
+        assert_ok!(Pallet::<Test>::maybe_initialize_v3(netuid));
+        System::set_block_number(10);
+
+        let (position_id, _, _) = Pallet::<Test>::do_add_liquidity(
+            netuid,
+            &OK_COLDKEY_ACCOUNT_ID,
+            &OK_HOTKEY_ACCOUNT_ID,
+            price_to_tick(min_price),
+            price_to_tick(max_price),
+            liquidity,
+        )
+        .unwrap();
+
+        assert_noop!(
+            Swap::lock_liquidity(
+                RuntimeOrigin::signed(NOT_SUBNET_OWNER),
+                netuid,
+                position_id,
+                100
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Swap::lock_liquidity(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+                netuid,
+                position_id,
+                0
+            ),
+            Error::<Test>::InvalidLockDuration
+        );
+        assert_noop!(
+            Swap::lock_liquidity(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID_2),
+                netuid,
+                position_id,
+                100
+            ),
+            Error::<Test>::LiquidityNotFound
+        );
+
+        assert_ok!(Swap::lock_liquidity(
+            RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+            netuid,
+            position_id,
+            100
+        ));
+        System::assert_last_event(
+            Event::<Test>::LiquidityLocked {
+                netuid,
+                coldkey: OK_COLDKEY_ACCOUNT_ID,
+                position_id,
+                liquidity,
+                unlocks_at: 110,
+            }
+            .into(),
+        );
+        assert_eq!(Pallet::<Test>::locked_liquidity(netuid), (liquidity, 110));
+
+        // The liquidity cannot leave the pool while locked, but can still be added to
+        assert_noop!(
+            Swap::remove_liquidity(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+                OK_HOTKEY_ACCOUNT_ID,
+                netuid,
+                position_id,
+            ),
+            Error::<Test>::LiquidityLocked
+        );
+        assert_noop!(
+            Swap::decrease_liquidity(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+                OK_HOTKEY_ACCOUNT_ID,
+                netuid,
+                position_id,
+                1_000,
+            ),
+            Error::<Test>::LiquidityLocked
+        );
+        assert_noop!(
+            Swap::modify_position(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+                OK_HOTKEY_ACCOUNT_ID,
+                netuid,
+                position_id,
+                -1_000,
+            ),
+            Error::<Test>::LiquidityLocked
+        );
+        assert_noop!(
+            Swap::transfer_position(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+                netuid,
+                position_id,
+                OK_COLDKEY_ACCOUNT_ID_2,
+            ),
+            Error::<Test>::LiquidityLocked
+        );
+        assert_ok!(Swap::modify_position(
+            RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+            OK_HOTKEY_ACCOUNT_ID,
+            netuid,
+            position_id,
+            1_000,
+        ));
+
+        // A lock can only be extended
+        System::set_block_number(50);
+        assert_noop!(
+            Swap::lock_liquidity(
+                RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+                netuid,
+                position_id,
+                10
+            ),
+            Error::<Test>::InvalidLockDuration
+        );
+        assert_ok!(Swap::lock_liquidity(
+            RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+            netuid,
+            position_id,
+            100
+        ));
+        let lock = LiquidityLocks::<Test>::get(netuid, position_id).unwrap();
+        assert_eq!(lock.locked_at, 10);
+        assert_eq!(lock.unlocks_at, 150);
+
+        // The liquidity can be withdrawn once unlocked, which drops the lock
+        System::set_block_number(150);
+        assert_eq!(Pallet::<Test>::locked_liquidity(netuid), (0, 0));
+        assert_ok!(Swap::remove_liquidity(
+            RuntimeOrigin::signed(OK_COLDKEY_ACCOUNT_ID),
+            OK_HOTKEY_ACCOUNT_ID,
+            netuid,
+            position_id,
+        ));
+        assert!(!LiquidityLocks::<Test>::contains_key(netuid, position_id));
+    });
+}
@@ -145,6 +145,27 @@ impl<T: Config> Position<T> {
         )
     }
 
+    /// Set the position liquidity without collecting fees
+    ///
+    /// Fee checkpoints are rebased so that the fees accrued with the previous liquidity remain
+    /// collectable, and fees accrue with the new liquidity from here on.
+    pub fn set_liquidity(&mut self, liquidity: u64) {
+        let liquidity_old = I64F64::saturating_from_num(self.liquidity);
+        let liquidity_new = I64F64::saturating_from_num(liquidity);
+
+        let fee_tao_agg = self.fees_in_range(true);
+        let fee_alpha_agg = self.fees_in_range(false);
+
+        // Accrued fees are `liquidity * (agg - checkpoint)`, keep them constant
+        let accrued_tao = liquidity_old.saturating_mul(fee_tao_agg.saturating_sub(self.fees_tao));
+        let accrued_alpha =
+            liquidity_old.saturating_mul(fee_alpha_agg.saturating_sub(self.fees_alpha));
+
+        self.fees_tao = fee_tao_agg.saturating_sub(accrued_tao.safe_div(liquidity_new));
+        self.fees_alpha = fee_alpha_agg.saturating_sub(accrued_alpha.safe_div(liquidity_new));
+        self.liquidity = liquidity;
+    }
+
     /// Get fees in a position's range
     ///
     /// If quote flag is true, Tao is returned, otherwise alpha.
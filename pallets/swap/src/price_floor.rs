@@ -0,0 +1,42 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::*;
+use safe_math::*;
+use substrate_fixed::types::U64F64;
+use subtensor_macros::freeze_struct;
+use subtensor_runtime_common::{AlphaCurrency, TaoCurrency};
+
+use crate::SqrtPrice;
+
+/// PriceFloor designates TAO locked by a subnet owner that the pool spends on Alpha whenever the
+/// price falls below a declared floor price.
+///
+/// After every swap that leaves the price below the floor, the reserve buys Alpha until the price
+/// is back at the floor. The bought Alpha is staked to the owner's hotkey. Once the reserve cannot
+/// lift the price back to the floor, the floor is paused until it is funded again.
+#[freeze_struct("cdb69d0d0a3f516b")]
+#[derive(Clone, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PriceFloor<AccountId> {
+    /// The coldkey account that funded the floor and gets the reserve back
+    pub coldkey: AccountId,
+    /// The hotkey account the bought Alpha is staked to
+    pub hotkey: AccountId,
+    /// The floor price in rao per 10^9 Alpha units
+    pub price: u64,
+    /// TAO left to defend the floor with
+    pub reserve: TaoCurrency,
+    /// TAO spent on defending the floor so far, including fees
+    pub tao_spent: TaoCurrency,
+    /// Alpha bought while defending the floor so far
+    pub alpha_bought: AlphaCurrency,
+    /// Whether the reserve has been depleted
+    pub paused: bool,
+}
+
+impl<AccountId> PriceFloor<AccountId> {
+    /// The square root of the floor price
+    pub fn sqrt_price(&self) -> Option<SqrtPrice> {
+        U64F64::saturating_from_num(self.price)
+            .safe_div(U64F64::saturating_from_num(1_000_000_000))
+            .checked_sqrt(U64F64::saturating_from_num(0.0000000001))
+    }
+}
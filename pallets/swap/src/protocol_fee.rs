@@ -0,0 +1,30 @@
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::*;
+
+/// ProtocolFeeDestination designates the account the protocol share of swap fees is paid to.
+///
+/// TAO fees are credited to the coldkey balance, Alpha fees are staked to the hotkey.
+#[derive(
+    Clone,
+    Decode,
+    DecodeWithMemTracking,
+    Default,
+    Encode,
+    Eq,
+    MaxEncodedLen,
+    PartialEq,
+    RuntimeDebug,
+    TypeInfo,
+)]
+pub enum ProtocolFeeDestination<AccountId> {
+    /// The owner coldkey and owner hotkey of the subnet the fees accrue on
+    #[default]
+    SubnetOwner,
+    /// A fixed account
+    Account {
+        coldkey: AccountId,
+        hotkey: AccountId,
+    },
+    /// The network treasury, Alpha fees are paid to the subnet owner
+    Treasury,
+}
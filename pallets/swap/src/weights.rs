@@ -19,15 +19,35 @@ pub trait WeightInfo {
     fn remove_liquidity() -> Weight;
     fn modify_position() -> Weight;
     fn toggle_user_liquidity() -> Weight;
+    fn collect_fees() -> Weight;
+    fn increase_liquidity() -> Weight;
+    fn decrease_liquidity() -> Weight;
+    fn set_candle_interval() -> Weight;
+    fn toggle_position_transfers() -> Weight;
+    fn transfer_position() -> Weight;
+    fn place_limit_order() -> Weight;
+    fn cancel_order() -> Weight;
+    fn set_protocol_fee() -> Weight;
+    fn set_subnet_protocol_fee_rate() -> Weight;
+    fn claim_protocol_fees() -> Weight;
+    fn set_global_fee_rate() -> Weight;
+    fn set_tick_spacing() -> Weight;
+    fn fund_liquidity_incentive() -> Weight;
+    fn set_liquidity_provider_whitelisted() -> Weight;
+    fn add_liquidity_single_sided() -> Weight;
+    fn set_price_floor() -> Weight;
+    fn remove_price_floor() -> Weight;
+    fn lock_liquidity() -> Weight;
+    fn defend_price_floor() -> Weight;
 }
 
 /// Default weights for pallet_subtensor_swap.
 pub struct DefaultWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for DefaultWeight<T> {
     fn set_fee_rate() -> Weight {
-        // Conservative weight estimate: one read and one write
+        // Conservative weight estimate: two reads and one write
         Weight::from_parts(10_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads(2))
             .saturating_add(T::DbWeight::get().writes(1))
     }
 
@@ -58,13 +78,151 @@ impl<T: frame_system::Config> WeightInfo for DefaultWeight<T> {
             .saturating_add(T::DbWeight::get().reads(1))
             .saturating_add(T::DbWeight::get().writes(1))
     }
+
+    fn collect_fees() -> Weight {
+        // Conservative weight estimate for collect_fees
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    fn increase_liquidity() -> Weight {
+        // Conservative weight estimate for increase_liquidity
+        Weight::from_parts(50_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+
+    fn decrease_liquidity() -> Weight {
+        // Conservative weight estimate for decrease_liquidity
+        Weight::from_parts(50_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+
+    fn set_candle_interval() -> Weight {
+        // Conservative weight estimate: two reads and two writes
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    fn toggle_position_transfers() -> Weight {
+        // Conservative weight estimate: one read and one write
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    fn transfer_position() -> Weight {
+        // Conservative weight estimate for transfer_position
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    fn place_limit_order() -> Weight {
+        // Conservative weight estimate for place_limit_order
+        Weight::from_parts(50_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().writes(6))
+    }
+
+    fn cancel_order() -> Weight {
+        // Conservative weight estimate for cancel_order
+        Weight::from_parts(50_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(6))
+    }
+
+    fn set_protocol_fee() -> Weight {
+        // Conservative weight estimate: three writes
+        Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    fn set_subnet_protocol_fee_rate() -> Weight {
+        // Conservative weight estimate: three reads and one write
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    fn claim_protocol_fees() -> Weight {
+        // Conservative weight estimate for claim_protocol_fees
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+
+    fn set_global_fee_rate() -> Weight {
+        // Conservative weight estimate: two writes
+        Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    fn set_tick_spacing() -> Weight {
+        // Conservative weight estimate: three reads and one write
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    fn fund_liquidity_incentive() -> Weight {
+        // Conservative weight estimate for fund_liquidity_incentive
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+
+    fn set_liquidity_provider_whitelisted() -> Weight {
+        // Conservative weight estimate: two reads and one write
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    fn add_liquidity_single_sided() -> Weight {
+        // Conservative weight estimate for add_liquidity_single_sided, 32 simulated swaps to
+        // find the split, one stake or unstake and add_liquidity
+        Weight::from_parts(2_600_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(411))
+            .saturating_add(T::DbWeight::get().writes(342))
+    }
+
+    fn set_price_floor() -> Weight {
+        // Conservative weight estimate for set_price_floor, including a swap defending the floor
+        Weight::from_parts(100_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(16))
+            .saturating_add(T::DbWeight::get().writes(14))
+    }
+
+    fn remove_price_floor() -> Weight {
+        // Conservative weight estimate: three reads and two writes
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    fn lock_liquidity() -> Weight {
+        // Conservative weight estimate: four reads and one write
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    fn defend_price_floor() -> Weight {
+        // Conservative weight estimate for defending a price floor after a swap, a simulated
+        // swap and a stake
+        Weight::from_parts(400_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(36))
+            .saturating_add(T::DbWeight::get().writes(17))
+    }
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
     fn set_fee_rate() -> Weight {
         Weight::from_parts(10_000_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().reads(2))
             .saturating_add(RocksDbWeight::get().writes(1))
     }
 
@@ -91,4 +249,120 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(1))
             .saturating_add(RocksDbWeight::get().writes(1))
     }
+
+    fn collect_fees() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    fn increase_liquidity() -> Weight {
+        Weight::from_parts(50_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+
+    fn decrease_liquidity() -> Weight {
+        Weight::from_parts(50_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+
+    fn set_candle_interval() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    fn toggle_position_transfers() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn transfer_position() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    fn place_limit_order() -> Weight {
+        Weight::from_parts(50_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(7))
+            .saturating_add(RocksDbWeight::get().writes(6))
+    }
+
+    fn cancel_order() -> Weight {
+        Weight::from_parts(50_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(6))
+    }
+
+    fn set_protocol_fee() -> Weight {
+        Weight::from_parts(10_000_000, 0).saturating_add(RocksDbWeight::get().writes(3))
+    }
+
+    fn set_subnet_protocol_fee_rate() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn claim_protocol_fees() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+
+    fn set_global_fee_rate() -> Weight {
+        Weight::from_parts(10_000_000, 0).saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    fn set_tick_spacing() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn fund_liquidity_incentive() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(7))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+
+    fn set_liquidity_provider_whitelisted() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn add_liquidity_single_sided() -> Weight {
+        Weight::from_parts(2_600_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(411))
+            .saturating_add(RocksDbWeight::get().writes(342))
+    }
+
+    fn set_price_floor() -> Weight {
+        Weight::from_parts(100_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(16))
+            .saturating_add(RocksDbWeight::get().writes(14))
+    }
+
+    fn remove_price_floor() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    fn lock_liquidity() -> Weight {
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn defend_price_floor() -> Weight {
+        Weight::from_parts(400_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(36))
+            .saturating_add(RocksDbWeight::get().writes(17))
+    }
 }
@@ -75,7 +75,7 @@ pub trait AlphaFeeHandler<T: frame_system::Config> {
 }
 
 /// Deduct the transaction fee from the Subtensor Pallet TotalIssuance when charging the transaction
-/// fee.
+/// fee, except for the share kept by the network treasury.
 pub struct TransactionFeeHandler<T>(core::marker::PhantomData<T>);
 impl<T> Default for TransactionFeeHandler<T> {
     fn default() -> Self {
@@ -103,10 +103,10 @@ where
             IncreaseIssuance<AccountIdOf<T>, pallet_balances::Pallet<T>>,
         >,
     ) {
+        let burned =
+            pallet_subtensor::Pallet::<T>::keep_network_treasury_share(imbalance.peek().into());
         let ti_before = pallet_subtensor::TotalIssuance::<T>::get();
-        pallet_subtensor::TotalIssuance::<T>::put(
-            ti_before.saturating_sub(imbalance.peek().into()),
-        );
+        pallet_subtensor::TotalIssuance::<T>::put(ti_before.saturating_sub(burned));
         drop(imbalance);
     }
 }
@@ -135,6 +135,14 @@ where
         Ok(U256::from(swap_result.amount_paid_out))
     }
 
+    #[precompile::public("getSubnetExists(uint16)")]
+    #[precompile::view]
+    fn get_subnet_exists(_handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<bool> {
+        Ok(pallet_subtensor::Pallet::<R>::if_subnet_exist(
+            netuid.into(),
+        ))
+    }
+
     #[precompile::public("getSubnetMechanism(uint16)")]
     #[precompile::view]
     fn get_subnet_mechanism(_handle: &mut impl PrecompileHandle, netuid: u16) -> EvmResult<u16> {
@@ -38,6 +38,7 @@ use crate::sr25519::*;
 use crate::staking::*;
 use crate::storage_query::*;
 use crate::subnet::*;
+use crate::swap::*;
 use crate::uid_lookup::*;
 
 mod alpha;
@@ -52,6 +53,7 @@ mod sr25519;
 mod staking;
 mod storage_query;
 mod subnet;
+mod swap;
 mod uid_lookup;
 pub struct Precompiles<R>(PhantomData<R>);
 
@@ -71,6 +73,7 @@ where
         + From<pallet_balances::Call<R>>
         + From<pallet_admin_utils::Call<R>>
         + From<pallet_crowdloan::Call<R>>
+        + From<pallet_subtensor_swap::Call<R>>
         + GetDispatchInfo
         + Dispatchable<PostInfo = PostDispatchInfo>,
     <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
@@ -98,6 +101,7 @@ where
         + From<pallet_balances::Call<R>>
         + From<pallet_admin_utils::Call<R>>
         + From<pallet_crowdloan::Call<R>>
+        + From<pallet_subtensor_swap::Call<R>>
         + GetDispatchInfo
         + Dispatchable<PostInfo = PostDispatchInfo>,
     <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
@@ -108,7 +112,7 @@ where
         Self(Default::default())
     }
 
-    pub fn used_addresses() -> [H160; 24] {
+    pub fn used_addresses() -> [H160; 25] {
         [
             hash(1),
             hash(2),
@@ -134,6 +138,7 @@ where
             hash(AlphaPrecompile::<R>::INDEX),
             hash(CrowdloanPrecompile::<R>::INDEX),
             hash(LeasingPrecompile::<R>::INDEX),
+            hash(SwapPrecompile::<R>::INDEX),
         ]
     }
 }
@@ -153,6 +158,7 @@ where
         + From<pallet_balances::Call<R>>
         + From<pallet_admin_utils::Call<R>>
         + From<pallet_crowdloan::Call<R>>
+        + From<pallet_subtensor_swap::Call<R>>
         + GetDispatchInfo
         + Dispatchable<PostInfo = PostDispatchInfo>
         + Decode,
@@ -220,6 +226,9 @@ where
             a if a == hash(LeasingPrecompile::<R>::INDEX) => {
                 LeasingPrecompile::<R>::try_execute::<R>(handle, PrecompileEnum::Leasing)
             }
+            a if a == hash(SwapPrecompile::<R>::INDEX) => {
+                SwapPrecompile::<R>::try_execute::<R>(handle, PrecompileEnum::Swap)
+            }
             _ => None,
         }
     }
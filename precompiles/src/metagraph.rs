@@ -44,6 +44,20 @@ where
         Ok(pallet_subtensor::Pallet::<R>::get_total_stake_for_hotkey(&hotkey).to_u64())
     }
 
+    #[precompile::public("getAlphaStake(uint16,uint16)")]
+    #[precompile::view]
+    fn get_alpha_stake(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u64> {
+        let hotkey = pallet_subtensor::Pallet::<R>::get_hotkey_for_net_and_uid(netuid.into(), uid)
+            .map_err(|_| PrecompileFailure::Error {
+                exit_status: ExitError::InvalidRange,
+            })?;
+
+        Ok(
+            pallet_subtensor::Pallet::<R>::get_stake_for_hotkey_on_subnet(&hotkey, netuid.into())
+                .to_u64(),
+        )
+    }
+
     #[precompile::public("getRank(uint16,uint16)")]
     #[precompile::view]
     fn get_rank(_: &mut impl PrecompileHandle, netuid: u16, uid: u16) -> EvmResult<u16> {
@@ -55,6 +55,11 @@ interface IAlpha {
         uint64 alpha
     ) external view returns (uint256);
 
+    /// @dev Returns whether a subnet exists.
+    /// @param netuid The subnet identifier.
+    /// @return Whether the subnet exists.
+    function getSubnetExists(uint16 netuid) external view returns (bool);
+
     /// @dev Returns the mechanism type for a subnet (0 for Stable, 1 for Dynamic).
     /// @param netuid The subnet identifier.
     /// @return The subnet mechanism type.
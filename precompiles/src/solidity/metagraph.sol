@@ -28,6 +28,14 @@ interface IMetagraph {
    */
   function getStake(uint16 netuid, uint16 uid) external view returns (uint64);
 
+  /**
+   * @dev Retrieves the alpha staked to a node on its subnet with a given network identifier (netuid) and unique identifier (uid).
+   * @param netuid The network identifier for which to retrieve the alpha stake.
+   * @param uid The unique identifier for which to retrieve the alpha stake.
+   * @return The alpha staked to the node with the specified netuid and uid.
+   */
+  function getAlphaStake(uint16 netuid, uint16 uid) external view returns (uint64);
+
   /**
    * @dev Retrieves the rank of a node with a given network identifier (netuid) and unique identifier (uid).
    * @param netuid The network identifier for which to retrieve the rank.
@@ -288,4 +288,48 @@ interface IStaking {
         uint256 netuid,
         uint256 limitPrice
     ) external;
+
+    /**
+     * @dev Swaps a subtensor stake `amount` of the specified `hotkey` from one subnet to another.
+     *
+     * This function allows external accounts and contracts to move alpha between subnets, which
+     * effectively calls `swap_stake` on the subtensor pallet with coldkey being the hashed address
+     * mapping of H160 sender address to Substrate ss58 address.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param origin_netuid The subnet to move stake from (uint256).
+     * @param destination_netuid The subnet to move stake to (uint256).
+     * @param amount The amount to move in alpha.
+     */
+    function swapStake(
+        bytes32 hotkey,
+        uint256 origin_netuid,
+        uint256 destination_netuid,
+        uint256 amount
+    ) external;
+
+    /**
+     * @dev Swaps a subtensor stake `amount` of the specified `hotkey` from one subnet to another
+     * within a price limit.
+     *
+     * This function allows external accounts and contracts to move alpha between subnets, which
+     * effectively calls `swap_stake_limit` on the subtensor pallet with coldkey being the hashed
+     * address mapping of H160 sender address to Substrate ss58 address.
+     *
+     * @param hotkey The hotkey public key (32 bytes).
+     * @param origin_netuid The subnet to move stake from (uint256).
+     * @param destination_netuid The subnet to move stake to (uint256).
+     * @param amount The amount to move in alpha.
+     * @param limit_price The limit on the price of the origin alpha in the destination alpha, in
+     *   rao.
+     * @param allow_partial Whether to allow a partial move.
+     */
+    function swapStakeLimit(
+        bytes32 hotkey,
+        uint256 origin_netuid,
+        uint256 destination_netuid,
+        uint256 amount,
+        uint256 limit_price,
+        bool allow_partial
+    ) external;
 }
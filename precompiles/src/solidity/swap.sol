@@ -0,0 +1,54 @@
+pragma solidity ^0.8.0;
+
+address constant ISWAP_ADDRESS = 0x000000000000000000000000000000000000080b;
+
+interface ISwap {
+    /**
+     * @dev Buys alpha of a subnet with TAO of the caller, staked to a hotkey.
+     * Reverts if the stake of the caller grows by less than `minAlphaOut`.
+     * @param hotkey The hotkey public key (32 bytes) the alpha is staked to.
+     * @param netuid The subnet to buy alpha of.
+     * @param tao The amount of TAO to spend, in rao.
+     * @param minAlphaOut The minimum amount of alpha to receive.
+     * @return The amount of alpha received.
+     */
+    function buyAlpha(
+        bytes32 hotkey,
+        uint16 netuid,
+        uint64 tao,
+        uint64 minAlphaOut
+    ) external returns (uint64);
+
+    /**
+     * @dev Sells alpha of a subnet staked to a hotkey by the caller for TAO.
+     * Reverts if the balance of the caller grows by less than `minTaoOut`.
+     * @param hotkey The hotkey public key (32 bytes) the alpha is staked to.
+     * @param netuid The subnet to sell alpha of.
+     * @param alpha The amount of alpha to sell.
+     * @param minTaoOut The minimum amount of TAO to receive, in rao.
+     * @return The amount of TAO received, in rao.
+     */
+    function sellAlpha(
+        bytes32 hotkey,
+        uint16 netuid,
+        uint64 alpha,
+        uint64 minTaoOut
+    ) external returns (uint64);
+
+    /**
+     * @dev Swaps alpha of one subnet staked to a hotkey by the caller for alpha of another
+     * subnet, staked to the same hotkey. Reverts if less than `minAlphaOut` is received.
+     * @param hotkey The hotkey public key (32 bytes) the alpha is staked to.
+     * @param originNetuid The subnet to sell alpha of.
+     * @param destinationNetuid The subnet to buy alpha of.
+     * @param alpha The amount of origin alpha to sell.
+     * @param minAlphaOut The minimum amount of destination alpha to receive.
+     */
+    function swapAlphaForAlpha(
+        bytes32 hotkey,
+        uint16 originNetuid,
+        uint16 destinationNetuid,
+        uint64 alpha,
+        uint64 minAlphaOut
+    ) external;
+}
@@ -0,0 +1,145 @@
+// The swap precompile lets EVM contracts buy and sell alpha with TAO and swap alpha of one subnet
+// for alpha of another, for the coldkey mapped from the caller address. Alpha is held as stake, so
+// every swap takes the hotkey the alpha is staked to.
+//
+// Each swap takes the minimum amount it must pay out. Every swap compares the stake or balance of
+// the caller after the swap with the one before, and reverts if the caller received less than the
+// minimum, so fees and the price moving before the call are accounted for. Swaps between subnets
+// move the stake through the subtensor pallet like any other stake swap.
+
+use core::marker::PhantomData;
+
+use fp_evm::{ExitError, PrecompileFailure};
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_system::RawOrigin;
+use pallet_evm::{AddressMapping, PrecompileHandle};
+use precompile_utils::EvmResult;
+use sp_core::{ByteArray, H256};
+use sp_runtime::traits::Dispatchable;
+use subtensor_runtime_common::NetUid;
+
+use crate::{PrecompileExt, PrecompileHandleExt};
+
+pub struct SwapPrecompile<R>(PhantomData<R>);
+
+impl<R> PrecompileExt<R::AccountId> for SwapPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
+    R::AccountId: From<[u8; 32]> + ByteArray,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    const INDEX: u64 = 2059;
+}
+
+#[precompile_utils::precompile]
+impl<R> SwapPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_subtensor::Config,
+    R::AccountId: From<[u8; 32]> + ByteArray,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_subtensor::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    #[precompile::public("buyAlpha(bytes32,uint16,uint64,uint64)")]
+    fn buy_alpha(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+        tao: u64,
+        min_alpha_out: u64,
+    ) -> EvmResult<u64> {
+        let account_id = handle.caller_account_id::<R>();
+        let hotkey = R::AccountId::from(hotkey.0);
+        let stake_before = Self::stake_of(&hotkey, &account_id, netuid);
+        let call = pallet_subtensor::Call::<R>::add_stake {
+            hotkey: hotkey.clone(),
+            netuid: netuid.into(),
+            amount_staked: tao.into(),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id.clone()))?;
+
+        let alpha_out = Self::stake_of(&hotkey, &account_id, netuid).saturating_sub(stake_before);
+        Self::ensure_min_out(alpha_out, min_alpha_out)
+    }
+
+    #[precompile::public("sellAlpha(bytes32,uint16,uint64,uint64)")]
+    fn sell_alpha(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        netuid: u16,
+        alpha: u64,
+        min_tao_out: u64,
+    ) -> EvmResult<u64> {
+        let account_id = handle.caller_account_id::<R>();
+        let balance_before = pallet_subtensor::Pallet::<R>::get_coldkey_balance(&account_id);
+        let call = pallet_subtensor::Call::<R>::remove_stake {
+            hotkey: R::AccountId::from(hotkey.0),
+            netuid: netuid.into(),
+            amount_unstaked: alpha.into(),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id.clone()))?;
+
+        let tao_out = pallet_subtensor::Pallet::<R>::get_coldkey_balance(&account_id)
+            .saturating_sub(balance_before);
+        Self::ensure_min_out(tao_out, min_tao_out)
+    }
+
+    #[precompile::public("swapAlphaForAlpha(bytes32,uint16,uint16,uint64,uint64)")]
+    fn swap_alpha_for_alpha(
+        handle: &mut impl PrecompileHandle,
+        hotkey: H256,
+        origin_netuid: u16,
+        destination_netuid: u16,
+        alpha: u64,
+        min_alpha_out: u64,
+    ) -> EvmResult<()> {
+        let account_id = handle.caller_account_id::<R>();
+        let hotkey = R::AccountId::from(hotkey.0);
+        let stake_before = Self::stake_of(&hotkey, &account_id, destination_netuid);
+        let call = pallet_subtensor::Call::<R>::swap_stake {
+            hotkey: hotkey.clone(),
+            origin_netuid: origin_netuid.into(),
+            destination_netuid: destination_netuid.into(),
+            alpha_amount: alpha.into(),
+        };
+
+        handle.try_dispatch_runtime_call::<R, _>(call, RawOrigin::Signed(account_id.clone()))?;
+
+        let alpha_out =
+            Self::stake_of(&hotkey, &account_id, destination_netuid).saturating_sub(stake_before);
+        Self::ensure_min_out(alpha_out, min_alpha_out).map(|_| ())
+    }
+}
+
+impl<R> SwapPrecompile<R>
+where
+    R: frame_system::Config + pallet_subtensor::Config,
+{
+    /// The alpha `coldkey` has staked to `hotkey` on `netuid`.
+    fn stake_of(hotkey: &R::AccountId, coldkey: &R::AccountId, netuid: u16) -> u64 {
+        pallet_subtensor::Pallet::<R>::get_stake_for_hotkey_and_coldkey_on_subnet(
+            hotkey,
+            coldkey,
+            NetUid::from(netuid),
+        )
+        .into()
+    }
+
+    /// Returns `amount_out`, the amount the caller received from a swap, failing if it is below
+    /// `min_out`. The failure reverts the swap along with the EVM call.
+    fn ensure_min_out(amount_out: u64, min_out: u64) -> EvmResult<u64> {
+        if amount_out < min_out {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("swap output below the minimum".into()),
+            });
+        }
+
+        Ok(amount_out)
+    }
+}
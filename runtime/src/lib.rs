@@ -11,6 +11,7 @@ include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 use core::num::NonZeroU64;
 
 pub mod check_nonce;
+pub mod metadata_compat;
 mod migrations;
 pub mod transaction_payment_wrapper;
 
@@ -29,18 +30,33 @@ use frame_system::{EnsureNever, EnsureRoot, EnsureRootWithSuccess, RawOrigin};
 use pallet_commitments::{CanCommit, OnMetadataCommitment};
 use pallet_grandpa::{AuthorityId as GrandpaId, fg_primitives};
 use pallet_registry::CanRegisterIdentity;
+use pallet_subtensor::coinbase::emission_telemetry::EmissionTelemetry;
 use pallet_subtensor::rpc_info::{
     delegate_info::DelegateInfo,
+    delegate_performance::DelegatePerformance,
     dynamic_info::DynamicInfo,
+    epoch_simulation::EpochSimulation,
+    issuance_schedule::IssuanceSchedule,
+    metadata_compat::PalletVariantsInfo,
     metagraph::{Metagraph, SelectiveMetagraph},
+    metagraph_page::MetagraphPage,
     neuron_info::{NeuronInfo, NeuronInfoLite},
     show_subnet::SubnetState,
     stake_info::StakeInfo,
     subnet_info::{SubnetHyperparams, SubnetHyperparamsV2, SubnetInfo, SubnetInfov2},
+    validator_yield::ValidatorYieldEstimate,
 };
+use pallet_subtensor::staking::stake_origin::StakeOriginInfo;
+use pallet_subtensor::subnets::attestations::SubnetAttestation;
+use pallet_subtensor::subnets::clock::SubnetClock;
+use pallet_subtensor::subnets::staged_hyperparams::StagedHyperparameterBundle;
+use pallet_subtensor::subnets::token_accounting::SubnetTokenAccounting;
+use pallet_subtensor::swap::key_rotation_log::KeyRotationRecord;
+use pallet_subtensor::utils::reserved_accounts::ReservedAccountKind;
+use pallet_subtensor::utils::scheduled_operations::ScheduledOperation;
 use pallet_subtensor_collective as pallet_collective;
 use pallet_subtensor_proxy as pallet_proxy;
-use pallet_subtensor_swap_runtime_api::SimSwapResult;
+use pallet_subtensor_swap_runtime_api::{PoolInfo, PriceCandle, SimSwapQuote, SimSwapResult};
 use pallet_subtensor_utility as pallet_utility;
 use runtime_common::prod_or_fast;
 use sp_api::impl_runtime_apis;
@@ -223,7 +239,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 326,
+    spec_version: 327,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
     transaction_version: 1,
@@ -425,6 +441,7 @@ impl Contains<RuntimeCall> for SafeModeWhitelistedCalls {
                 | RuntimeCall::Timestamp(_)
                 | RuntimeCall::SubtensorModule(
                     pallet_subtensor::Call::set_weights { .. }
+                        | pallet_subtensor::Call::set_no_weights { .. }
                         | pallet_subtensor::Call::serve_axon { .. }
                 )
                 | RuntimeCall::Commitments(pallet_commitments::Call::set_commitment { .. })
@@ -518,7 +535,7 @@ impl CanVote<AccountId> for CanVoteToTriumvirate {
 }
 
 use pallet_subtensor::{
-    CollectiveInterface, CommitmentsInterface, MemberManagement, ProxyInterface,
+    CollectiveInterface, CommitmentsInterface, EvmLogInterface, MemberManagement, ProxyInterface,
 };
 pub struct ManageSenateMembers;
 impl MemberManagement<AccountId> for ManageSenateMembers {
@@ -686,7 +703,13 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                     | RuntimeCall::SubtensorModule(
                         pallet_subtensor::Call::schedule_swap_coldkey { .. }
                     )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::cancel_scheduled_coldkey_swap { .. }
+                    )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::swap_coldkey { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::spend_subnet_treasury { .. }
+                    )
             ),
             ProxyType::NonFungibile => !matches!(
                 c,
@@ -700,6 +723,18 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                     | RuntimeCall::SubtensorModule(
                         pallet_subtensor::Call::remove_stake_full_limit { .. }
                     )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::remove_stake_sliced { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::cancel_sliced_unstake { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::approve_stake_allowance { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::add_stake_with_allowance { .. }
+                    )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::unstake_all { .. })
                     | RuntimeCall::SubtensorModule(
                         pallet_subtensor::Call::unstake_all_alpha { .. }
@@ -713,8 +748,17 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                     | RuntimeCall::SubtensorModule(
                         pallet_subtensor::Call::schedule_swap_coldkey { .. }
                     )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::cancel_scheduled_coldkey_swap { .. }
+                    )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::swap_coldkey { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::swap_hotkey { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::swap_hotkey_on_subnet { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::spend_subnet_treasury { .. }
+                    )
             ),
             ProxyType::Transfer => matches!(
                 c,
@@ -747,6 +791,18 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                         | RuntimeCall::SubtensorModule(
                             pallet_subtensor::Call::update_symbol { .. }
                         )
+                        | RuntimeCall::SubtensorModule(
+                            pallet_subtensor::Call::blacklist_hotkey { .. }
+                        )
+                        | RuntimeCall::SubtensorModule(
+                            pallet_subtensor::Call::strike_blacklisted_hotkey { .. }
+                        )
+                        | RuntimeCall::SubtensorModule(
+                            pallet_subtensor::Call::set_registration_access { .. }
+                        )
+                        | RuntimeCall::SubtensorModule(
+                            pallet_subtensor::Call::bootstrap_register { .. }
+                        )
                 ) && !matches!(
                     c,
                     RuntimeCall::AdminUtils(
@@ -791,6 +847,15 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                     | RuntimeCall::SubtensorModule(
                         pallet_subtensor::Call::remove_stake_full_limit { .. }
                     )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::remove_stake_sliced { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::cancel_sliced_unstake { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::add_stake_with_allowance { .. }
+                    )
             ),
             ProxyType::Registration => matches!(
                 c,
@@ -801,6 +866,9 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
             ProxyType::ChildKeys => matches!(
                 c,
                 RuntimeCall::SubtensorModule(pallet_subtensor::Call::set_children { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::set_children_batch { .. }
+                    )
                     | RuntimeCall::SubtensorModule(
                         pallet_subtensor::Call::set_childkey_take { .. }
                     )
@@ -819,6 +887,9 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
             ProxyType::SwapHotkey => matches!(
                 c,
                 RuntimeCall::SubtensorModule(pallet_subtensor::Call::swap_hotkey { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::swap_hotkey_on_subnet { .. }
+                    )
             ),
             ProxyType::SubnetLeaseBeneficiary => matches!(
                 c,
@@ -945,6 +1016,29 @@ impl CommitmentsInterface for CommitmentsI {
     }
 }
 
+/// The address the logs of native events are emitted from, next to the precompiles.
+pub const NATIVE_EVENT_LOG_ADDRESS: u64 = 2060;
+
+/// Deposits the logs of the native events of a block as `pallet_evm::Event::Log` events from
+/// `NATIVE_EVENT_LOG_ADDRESS`, the event EVM contracts deposit their logs with. They are not part
+/// of any Ethereum transaction, so they are read from the events of the block rather than from
+/// receipts or `eth_getLogs`.
+pub struct EvmLogs;
+impl EvmLogInterface for EvmLogs {
+    fn deposit_logs(logs: Vec<(Vec<H256>, Vec<u8>)>) {
+        let address = H160::from_low_u64_be(NATIVE_EVENT_LOG_ADDRESS);
+        for (topics, data) in logs {
+            System::deposit_event(RuntimeEvent::EVM(pallet_evm::Event::Log {
+                log: pallet_evm::Log {
+                    address,
+                    topics,
+                    data,
+                },
+            }));
+        }
+    }
+}
+
 parameter_types! {
     pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
         BlockWeights::get().max_block;
@@ -1288,7 +1382,9 @@ impl pallet_subtensor::Config for Runtime {
     type LeaseDividendsDistributionInterval = LeaseDividendsDistributionInterval;
     type GetCommitments = GetCommitmentsStruct;
     type MaxImmuneUidsPercentage = MaxImmuneUidsPercentage;
+    type SlotDuration = <Runtime as pallet_aura::Config>::SlotDuration;
     type CommitmentsInterface = CommitmentsI;
+    type EvmLogInterface = EvmLogs;
     type EvmKeyAssociateRateLimit = EvmKeyAssociateRateLimit;
 }
 
@@ -1299,6 +1395,9 @@ parameter_types! {
     pub const SwapMinimumLiquidity: u64 = 1_000;
     pub const SwapMinimumReserve: NonZeroU64 = NonZeroU64::new(1_000_000)
         .expect("1_000_000 fits NonZeroU64");
+    pub const SwapMaxCandles: u32 = 720; // ~36 days of candles at one per tempo
+    pub const SwapMaxPriceObservations: u32 = 7200; // ~1 day of blocks
+    pub const SwapMaxLimitOrders: u32 = 128;
 }
 
 impl pallet_subtensor_swap::Config for Runtime {
@@ -1309,6 +1408,9 @@ impl pallet_subtensor_swap::Config for Runtime {
     type MaxPositions = SwapMaxPositions;
     type MinimumLiquidity = SwapMinimumLiquidity;
     type MinimumReserve = SwapMinimumReserve;
+    type MaxCandles = SwapMaxCandles;
+    type MaxPriceObservations = SwapMaxPriceObservations;
+    type MaxLimitOrders = SwapMaxLimitOrders;
     // TODO: set measured weights when the pallet been benchmarked and the type is generated
     type WeightInfo = pallet_subtensor_swap::weights::DefaultWeight<Runtime>;
 }
@@ -1340,6 +1442,7 @@ impl pallet_admin_utils::Config for Runtime {
     type Aura = AuraPalletIntrf;
     type Grandpa = GrandpaInterfaceImpl;
     type Balance = Balance;
+    type WeightInfo = pallet_admin_utils::weights::SubstrateWeight<Runtime>;
 }
 
 /// Define the ChainId
@@ -2315,6 +2418,10 @@ impl_runtime_apis! {
         fn get_delegated(delegatee_account: AccountId32) -> Vec<(DelegateInfo<AccountId32>, (Compact<NetUid>, Compact<AlphaCurrency>))> {
             SubtensorModule::get_delegated(delegatee_account)
         }
+
+        fn get_delegates_performance() -> Vec<DelegatePerformance<AccountId32>> {
+            SubtensorModule::get_delegates_performance()
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::NeuronInfoRuntimeApi<Block> for Runtime {
@@ -2333,6 +2440,10 @@ impl_runtime_apis! {
         fn get_neuron(netuid: NetUid, uid: u16) -> Option<NeuronInfo<AccountId32>> {
             SubtensorModule::get_neuron(netuid, uid)
         }
+
+        fn get_neuron_lifetime_emissions(netuid: NetUid) -> Vec<Compact<AlphaCurrency>> {
+            SubtensorModule::get_neuron_lifetime_emissions(netuid)
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::SubnetInfoRuntimeApi<Block> for Runtime {
@@ -2402,6 +2513,50 @@ impl_runtime_apis! {
         fn get_selective_mechagraph(netuid: NetUid, mecid: MechId, metagraph_indexes: Vec<u16>) -> Option<SelectiveMetagraph<AccountId32>> {
             SubtensorModule::get_selective_mechagraph(netuid, mecid, metagraph_indexes)
         }
+
+        fn estimate_validator_yield(netuid: NetUid, hotkey: AccountId32, stake_amount: AlphaCurrency) -> Option<ValidatorYieldEstimate<AccountId32>> {
+            SubtensorModule::estimate_validator_yield(netuid, hotkey, stake_amount)
+        }
+
+        fn get_staged_hyperparameters(netuid: NetUid) -> Option<StagedHyperparameterBundle> {
+            SubtensorModule::get_staged_hyperparameters(netuid)
+        }
+
+        fn get_reserved_accounts() -> Vec<(AccountId32, ReservedAccountKind)> {
+            SubtensorModule::get_reserved_accounts()
+        }
+
+        fn get_subnet_features(netuid: NetUid) -> Option<u16> {
+            SubtensorModule::get_subnet_features(netuid)
+        }
+
+        fn get_subnet_clock(netuid: NetUid) -> Option<SubnetClock> {
+            SubtensorModule::get_subnet_clock(netuid)
+        }
+
+        fn get_subnet_token_accounting(netuid: NetUid) -> Option<SubnetTokenAccounting> {
+            SubtensorModule::get_subnet_token_accounting(netuid)
+        }
+
+        fn get_subnet_attestations(netuid: NetUid) -> Vec<SubnetAttestation> {
+            SubtensorModule::get_subnet_attestations(netuid)
+        }
+
+        fn simulate_epoch(netuid: NetUid) -> Option<EpochSimulation> {
+            SubtensorModule::simulate_epoch(netuid)
+        }
+
+        fn get_emission_telemetry() -> Vec<EmissionTelemetry> {
+            SubtensorModule::get_emission_telemetry()
+        }
+
+        fn get_metagraph_page(netuid: NetUid, start_uid: u16, page_size: u16) -> Option<MetagraphPage<AccountId32>> {
+            SubtensorModule::get_metagraph_page(netuid, start_uid, page_size)
+        }
+
+        fn get_weights(netuid: NetUid, mecid: MechId) -> Vec<(u16, Vec<(u16, u16)>)> {
+            SubtensorModule::get_weight_rows(netuid, mecid)
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::StakeInfoRuntimeApi<Block> for Runtime {
@@ -2420,12 +2575,56 @@ impl_runtime_apis! {
         fn get_stake_fee( origin: Option<(AccountId32, NetUid)>, origin_coldkey_account: AccountId32, destination: Option<(AccountId32, NetUid)>, destination_coldkey_account: AccountId32, amount: u64 ) -> u64 {
             SubtensorModule::get_stake_fee( origin, origin_coldkey_account, destination, destination_coldkey_account, amount )
         }
+
+        fn get_scheduled_operations( coldkey_account: AccountId32 ) -> Vec<ScheduledOperation<AccountId32>> {
+            SubtensorModule::get_scheduled_operations( coldkey_account )
+        }
+
+        fn get_key_rotation_log( account: AccountId32 ) -> Vec<KeyRotationRecord<AccountId32>> {
+            SubtensorModule::get_key_rotation_log( account )
+        }
+
+        fn get_stake_origins( netuid: NetUid ) -> Vec<StakeOriginInfo<AccountId32>> {
+            SubtensorModule::get_stake_origins( netuid )
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::SubnetRegistrationRuntimeApi<Block> for Runtime {
         fn get_network_registration_cost() -> TaoCurrency {
             SubtensorModule::get_network_lock_cost()
         }
+
+        fn get_lock_cost_at(block: u64) -> TaoCurrency {
+            SubtensorModule::get_network_lock_cost_at(block)
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::IssuanceRuntimeApi<Block> for Runtime {
+        fn get_issuance_schedule() -> IssuanceSchedule {
+            SubtensorModule::get_issuance_schedule()
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::MetadataCompatRuntimeApi<Block> for Runtime {
+        fn get_pallet_variants() -> Vec<PalletVariantsInfo> {
+            // Only dev builds expose the full listing; production runtimes return nothing.
+            if cfg!(feature = "metadata-compat-api") {
+                metadata_compat::pallet_variants()
+            } else {
+                Vec::new()
+            }
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::EpochFixtureRuntimeApi<Block> for Runtime {
+        fn get_epoch_fixture(netuid: NetUid) -> Option<Vec<u8>> {
+            // Fixtures are only exported by runtimes built for capturing them.
+            if cfg!(feature = "epoch-fixtures") {
+                SubtensorModule::get_epoch_fixture_blob(netuid)
+            } else {
+                None
+            }
+        }
     }
 
     impl sp_consensus_babe::BabeApi<Block> for Runtime {
@@ -2535,6 +2734,67 @@ impl_runtime_apis! {
                 },
             )
         }
+
+        fn price_candles(netuid: NetUid) -> Vec<PriceCandle> {
+            pallet_subtensor_swap::Pallet::<Runtime>::price_candles(netuid.into())
+                .into_iter()
+                .map(|candle| PriceCandle {
+                    start_block: candle.start_block,
+                    open:        candle.open,
+                    high:        candle.high,
+                    low:         candle.low,
+                    close:       candle.close,
+                })
+                .collect()
+        }
+
+        fn twap_alpha_price(netuid: NetUid, window: u64) -> Option<u64> {
+            pallet_subtensor_swap::Pallet::<Runtime>::twap_price(netuid.into(), window)
+        }
+
+        fn sim_swaps(swaps: Vec<(NetUid, OrderType, u64)>) -> Vec<Option<SimSwapQuote>> {
+            swaps
+                .into_iter()
+                .map(|(netuid, order_type, amount)| {
+                    pallet_subtensor_swap::Pallet::<Runtime>::sim_swap_with_price(
+                        netuid,
+                        order_type,
+                        amount,
+                    )
+                    .ok()
+                    .map(|(sr, price_after)| SimSwapQuote {
+                        amount_in:   sr.amount_paid_in,
+                        amount_out:  sr.amount_paid_out,
+                        fee:         sr.fee_paid,
+                        price_after,
+                    })
+                })
+                .collect()
+        }
+
+        fn get_pool_info(netuid: NetUid) -> Option<PoolInfo> {
+            use substrate_fixed::types::U96F32;
+
+            if !SubtensorModule::if_subnet_exist(netuid) {
+                return None;
+            }
+            Some(PoolInfo {
+                tao_reserve:      pallet_subtensor::SubnetTAO::<Runtime>::get(netuid),
+                alpha_in:         pallet_subtensor::SubnetAlphaIn::<Runtime>::get(netuid),
+                alpha_out:        pallet_subtensor::SubnetAlphaOut::<Runtime>::get(netuid),
+                price:            pallet_subtensor_swap::Pallet::<Runtime>::current_price(netuid)
+                    .saturating_mul(U96F32::from_num(1_000_000_000))
+                    .saturating_to_num(),
+                v3_initialized:   pallet_subtensor_swap::SwapV3Initialized::<Runtime>::get(netuid),
+                current_tick:     pallet_subtensor_swap::CurrentTick::<Runtime>::get(netuid).get(),
+                sqrt_price:       pallet_subtensor_swap::AlphaSqrtPrice::<Runtime>::get(netuid).to_bits(),
+                liquidity:        pallet_subtensor_swap::CurrentLiquidity::<Runtime>::get(netuid),
+                fee_rate:         pallet_subtensor_swap::FeeRate::<Runtime>::get(netuid),
+                fee_global_tao:   pallet_subtensor_swap::FeeGlobalTao::<Runtime>::get(netuid).to_bits(),
+                fee_global_alpha: pallet_subtensor_swap::FeeGlobalAlpha::<Runtime>::get(netuid).to_bits(),
+                volume_24h:       SubtensorModule::get_trailing_subnet_volume(netuid),
+            })
+        }
     }
 }
 
@@ -0,0 +1,83 @@
+//! Flattened view of every pallet's event and error variants, built from the runtime metadata.
+//!
+//! Downstream SDKs compare this against their generated decoders in CI so that a reordered
+//! variant or a changed field type is caught before it turns into a decoding failure on chain.
+
+use alloc::vec::Vec;
+use frame_metadata::{RuntimeMetadata, RuntimeMetadataPrefixed};
+use pallet_subtensor::rpc_info::metadata_compat::{
+    PalletVariantsInfo, VariantFieldInfo, VariantInfo,
+};
+use scale_info::{PortableRegistry, TypeDef};
+
+use crate::Runtime;
+
+/// Pallets whose events and errors the SDK decodes directly. Any change to the order or the
+/// fields of their variants must be coordinated with an SDK release.
+pub const SDK_STABLE_PALLETS: [&str; 6] = [
+    "SubtensorModule",
+    "AdminUtils",
+    "Commitments",
+    "Registry",
+    "Crowdloan",
+    "Swap",
+];
+
+/// Returns the event and error variants of every pallet in the runtime, ordered by pallet index.
+pub fn pallet_variants() -> Vec<PalletVariantsInfo> {
+    let RuntimeMetadataPrefixed(_, metadata) = Runtime::metadata();
+    let RuntimeMetadata::V14(metadata) = metadata else {
+        return Vec::new();
+    };
+
+    let mut pallets: Vec<PalletVariantsInfo> = metadata
+        .pallets
+        .iter()
+        .map(|pallet| PalletVariantsInfo {
+            pallet_index: pallet.index,
+            pallet_name: pallet.name.as_bytes().to_vec(),
+            sdk_stable: SDK_STABLE_PALLETS.contains(&pallet.name.as_str()),
+            events: pallet
+                .event
+                .as_ref()
+                .map(|event| variants_of(&metadata.types, event.ty.id))
+                .unwrap_or_default(),
+            errors: pallet
+                .error
+                .as_ref()
+                .map(|error| variants_of(&metadata.types, error.ty.id))
+                .unwrap_or_default(),
+        })
+        .collect();
+    pallets.sort_by_key(|pallet| pallet.pallet_index);
+    pallets
+}
+
+fn variants_of(types: &PortableRegistry, type_id: u32) -> Vec<VariantInfo> {
+    let Some(TypeDef::Variant(def)) = types.resolve(type_id).map(|ty| &ty.type_def) else {
+        return Vec::new();
+    };
+
+    let mut variants: Vec<VariantInfo> = def
+        .variants
+        .iter()
+        .map(|variant| VariantInfo {
+            index: variant.index,
+            name: variant.name.as_bytes().to_vec(),
+            fields: variant
+                .fields
+                .iter()
+                .map(|field| VariantFieldInfo {
+                    name: field.name.as_ref().map(|name| name.as_bytes().to_vec()),
+                    type_name: field
+                        .type_name
+                        .as_ref()
+                        .map(|name| name.as_bytes().to_vec()),
+                    type_id: field.ty.id.into(),
+                })
+                .collect(),
+        })
+        .collect();
+    variants.sort_by_key(|variant| variant.index);
+    variants
+}
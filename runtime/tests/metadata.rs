@@ -49,3 +49,56 @@ fn test_metadata() {
         }
     };
 }
+
+// the flattened variant listing must mirror the metadata and keep stable pallets annotated
+#[test]
+fn test_metadata_compat_pallet_variants() {
+    use node_subtensor_runtime::metadata_compat::{SDK_STABLE_PALLETS, pallet_variants};
+
+    let pallets = pallet_variants();
+    assert!(!pallets.is_empty());
+
+    // pallets are ordered by index and indices are unique
+    for pair in pallets.windows(2) {
+        assert!(pair[0].pallet_index < pair[1].pallet_index);
+    }
+
+    for pallet in pallets.iter() {
+        let name = String::from_utf8(pallet.pallet_name.clone()).unwrap();
+        assert_eq!(
+            pallet.sdk_stable,
+            SDK_STABLE_PALLETS.contains(&name.as_str())
+        );
+        for variants in [&pallet.events, &pallet.errors] {
+            for pair in variants.windows(2) {
+                assert!(pair[0].index < pair[1].index);
+            }
+        }
+    }
+
+    let subtensor = pallets
+        .iter()
+        .find(|pallet| pallet.pallet_name == b"SubtensorModule".to_vec())
+        .unwrap();
+    assert_eq!(subtensor.pallet_index, 7);
+    assert!(subtensor.sdk_stable);
+    assert!(!subtensor.errors.is_empty());
+
+    // every SDK-stable pallet must be present in the runtime
+    for stable in SDK_STABLE_PALLETS.iter() {
+        assert!(
+            pallets
+                .iter()
+                .any(|pallet| pallet.pallet_name == stable.as_bytes().to_vec())
+        );
+    }
+
+    // field type names come straight from the pallet source
+    let network_added = subtensor
+        .events
+        .iter()
+        .find(|variant| variant.name == b"NetworkAdded".to_vec())
+        .unwrap();
+    assert_eq!(network_added.fields.len(), 2);
+    assert_eq!(network_added.fields[0].type_name, Some(b"NetUid".to_vec()));
+}
@@ -5,7 +5,7 @@ PALLET_LIST=(subtensor admin_utils commitments drand)
 
 declare -A DISPATCH_PATHS=(
   [subtensor]="../pallets/subtensor/src/macros/dispatches.rs"
-  [admin_utils]="../pallets/admin-utils/src/lib.rs"
+  [admin_utils]="../pallets/admin-utils/src/weights.rs"
   [commitments]="../pallets/commitments/src/lib.rs"
   [drand]="../pallets/drand/src/lib.rs"
   [swap]="../pallets/swap/src/pallet/mod.rs"
@@ -35,6 +35,8 @@ patch_weight() {
   FN="$1" NEWV="$2" perl -0777 -i -pe '
     my $n=$ENV{NEWV}; my $hit=0;
     $hit+=s|(pub\s+fn\s+\Q$ENV{FN}\E\s*[^{}]*?Weight::from_parts\(\s*)[0-9A-Za-z_]+|$1$n|s;
+    # weights.rs: the first implementation of the WeightInfo function
+    $hit+=s|(\bfn\s+\Q$ENV{FN}\E\s*\([^)]*\)\s*->\s*Weight\s*\{\s*Weight::from_parts\(\s*)[0-9A-Za-z_]+|$1$n|s;
     # attribute replacement allowing intermediate attributes (e.g., call_index) before pub fn
     $hit+=s|(\#\s*\[pallet::weight[^\]]*?Weight::from_parts\(\s*)[0-9A-Za-z_]+(?=[^\]]*\](?:\s*#\[[^\]]*\])*\s*pub\s+fn\s+\Q$ENV{FN}\E\b)|$1$n|s;
     END{exit $hit?0:1}
@@ -60,6 +62,9 @@ patch_reads_writes() {
     # In-body: .reads(...) and .writes(...)
     $h+=s|(pub\s+fn\s+\Q$ENV{FN}\E\s*[^{}]*?\.reads\(\s*)([^)]+)|$1.($newr eq "" ? $2 : u64($newr))|sge;
     $h+=s|(pub\s+fn\s+\Q$ENV{FN}\E\s*[^{}]*?\.writes\(\s*)([^)]+)|$1.($neww eq "" ? $2 : u64($neww))|sge;
+    # weights.rs: the component free .reads(...) and .writes(...) of the first WeightInfo implementation
+    $h+=s|(\bfn\s+\Q$ENV{FN}\E\s*\([^)]*\)\s*->\s*Weight\s*\{[^{}]*?\.reads\(\s*)([^)]+)|$1.($newr eq "" ? $2 : u64($newr))|se;
+    $h+=s|(\bfn\s+\Q$ENV{FN}\E\s*\([^)]*\)\s*->\s*Weight\s*\{[^{}]*?\.writes\(\s*)([^)]+)|$1.($neww eq "" ? $2 : u64($neww))|se;
 
     # Attribute: reads_writes(...), tolerate other attributes between ] and pub fn
     $h+=s|(\#\s*\[pallet::weight[^\]]*?reads_writes\(\s*)([^,]+)(\s*,\s*)([^)]+)(?=[^\]]*\](?:\s*#\[[^\]]*\])*\s*pub\s+fn\s+\Q$ENV{FN}\E\b)|&$rw_sub($1,$2,$3,$4,"")|sge;
@@ -146,6 +151,13 @@ for pallet in "${PALLET_LIST[@]}"; do
 
       read -r code_w code_r code_wr < <(awk -v fn="$extr" '
         /^\s*#\[pallet::call_index/ { next }
+        # weights.rs: the weight is in the body of the first WeightInfo implementation,
+        # skipping the component terms that follow the base weight, reads and writes
+        $0 ~ ("fn[[:space:]]+"fn"\\(") && /->[[:space:]]*Weight[[:space:]]*\{/ { infn=1; w=""; r=""; wr=""; next }
+        infn && /^[[:space:]]*}/ { print w,r,wr; exit }
+        infn && w!="" && /Weight::from_parts/ { next }
+        infn && r!="" && /\.reads\(/ { next }
+        infn && wr!="" && /\.writes\(/ { next }
         /Weight::from_parts/ { lw=$0; sub(/.*Weight::from_parts\(/,"",lw); sub(/[^0-9_].*/,"",lw); w=lw }
         /reads_writes\(/ {
             lw=$0; sub(/.*reads_writes\(/,"",lw); sub(/\).*/,"",lw);
@@ -8,6 +8,17 @@ pallets=(
   "pallet_admin_utils"
 )
 
+# Pallets whose weights are a generated `weights.rs`, written in place with `--write`
+declare -A WEIGHT_FILES=(
+  [pallet_admin_utils]="pallets/admin-utils/src/weights.rs"
+  [pallet_commitments]="pallets/commitments/src/weights.rs"
+)
+
+WRITE_WEIGHTS=false
+if [[ "${1:-}" == "--write" ]]; then
+  WRITE_WEIGHTS=true
+fi
+
 RUNTIME_WASM=./target/production/wbuild/node-subtensor-runtime/node_subtensor_runtime.compact.compressed.wasm
 
 cargo build \
@@ -20,6 +31,14 @@ for pallet in "${pallets[@]}"; do
   echo " Benchmarking all extrinsics for $pallet..."
   echo "--------------------------------------------------------"
 
+  output_args=()
+  if [[ "$WRITE_WEIGHTS" == true && -n "${WEIGHT_FILES[$pallet]:-}" ]]; then
+    output_args=(
+      --output "${WEIGHT_FILES[$pallet]}"
+      --template ./.maintain/frame-weight-template.hbs
+    )
+  fi
+
   ./target/production/node-subtensor benchmark pallet \
     --runtime "$RUNTIME_WASM" \
     --genesis-builder=runtime \
@@ -28,5 +47,6 @@ for pallet in "${pallets[@]}"; do
     --pallet "$pallet" \
     --extrinsic "*" \
     --steps 50 \
-    --repeat 5
+    --repeat 5 \
+    "${output_args[@]}"
 done
\ No newline at end of file
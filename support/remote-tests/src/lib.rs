@@ -0,0 +1,116 @@
+//! Tests of heavy Subtensor operations against mainnet state.
+//!
+//! The mock runtime of the pallet tests has a handful of subnets and neurons, so it cannot tell
+//! whether the epoch of the biggest subnet, the dissolution of a large subnet or the pending
+//! migrations still fit in a block. These tests run them with the production runtime on a
+//! snapshot of the state of a live chain, check the invariants of the pallet afterwards, and fail
+//! when an operation takes longer than the weight it is given.
+//!
+//! The snapshot is read from `SUBTENSOR_SNAPSHOT`, `remote-tests.snap` by default, and downloaded
+//! from `SUBTENSOR_REMOTE_URI`, the finney archive node by default, when the file does not exist.
+//! Only the storage of the `SubtensorModule`, `Swap` and `System` pallets, and the total issuance,
+//! is downloaded.
+//! The tests need the `try-runtime` feature and are ignored by default:
+//!
+//! ```sh
+//! cargo test --release -p subtensor-remote-tests --features try-runtime -- --ignored
+//! ```
+//!
+//! Operations run natively, which is faster than the wasm runtime validators execute, so a test
+//! that fails here is over its weight on any validator.
+#![cfg(feature = "try-runtime")]
+
+use std::path::PathBuf;
+use std::time::Instant;
+
+use frame_remote_externalities::{
+    Builder, Mode, OfflineConfig, OnlineConfig, RemoteExternalities, SnapshotConfig,
+};
+use frame_support::storage::storage_prefix;
+use frame_support::traits::{Get, TryState, TryStateSelect};
+use frame_support::weights::Weight;
+use node_subtensor_runtime::{Runtime, SubtensorModule, opaque::Block};
+
+/// The environment variable with the path of the state snapshot.
+pub const SNAPSHOT_ENV: &str = "SUBTENSOR_SNAPSHOT";
+/// The environment variable with the uri of the node to download the snapshot from.
+pub const REMOTE_URI_ENV: &str = "SUBTENSOR_REMOTE_URI";
+
+const DEFAULT_SNAPSHOT: &str = "remote-tests.snap";
+const DEFAULT_REMOTE_URI: &str = "wss://archive.dev.opentensor.ai:443";
+/// The pallets whose state is downloaded. Of the other pallets, only the total issuance of
+/// `Balances` is downloaded, which the invariants check.
+const REMOTE_PALLETS: [&str; 3] = ["SubtensorModule", "Swap", "System"];
+
+/// The path of the state snapshot.
+pub fn snapshot_path() -> PathBuf {
+    std::env::var(SNAPSHOT_ENV)
+        .unwrap_or_else(|_| DEFAULT_SNAPSHOT.into())
+        .into()
+}
+
+/// The externalities of the state snapshot, downloaded first if it does not exist.
+pub async fn remote_externalities() -> RemoteExternalities<Block> {
+    let snapshot = snapshot_path();
+    let uri = std::env::var(REMOTE_URI_ENV).unwrap_or_else(|_| DEFAULT_REMOTE_URI.into());
+    println!("loading state snapshot {snapshot:?}, or downloading it from {uri}");
+
+    let state_snapshot = SnapshotConfig::new(snapshot);
+    Builder::<Block>::new()
+        .mode(Mode::OfflineOrElseOnline(
+            OfflineConfig {
+                state_snapshot: state_snapshot.clone(),
+            },
+            OnlineConfig {
+                transport: uri.into(),
+                state_snapshot: Some(state_snapshot),
+                pallets: REMOTE_PALLETS
+                    .iter()
+                    .map(|pallet| pallet.to_string())
+                    .collect(),
+                hashed_keys: vec![storage_prefix(b"Balances", b"TotalIssuance").to_vec()],
+                ..Default::default()
+            },
+        ))
+        .build()
+        .await
+        .expect("the state snapshot can be loaded or downloaded")
+}
+
+/// Runs `f` and returns its result with the time it took, as the ref time of a weight.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Weight) {
+    let start = Instant::now();
+    let result = f();
+    let picos = start.elapsed().as_nanos().saturating_mul(1_000);
+    (
+        result,
+        Weight::from_parts(u64::try_from(picos).unwrap_or(u64::MAX), 0),
+    )
+}
+
+/// Asserts the operation `name` took no longer than the ref time of `weight`.
+pub fn assert_within_weight(name: &str, elapsed: Weight, weight: Weight) {
+    println!(
+        "{name} took {} ms of its {} ms",
+        elapsed.ref_time().saturating_div(1_000_000_000),
+        weight.ref_time().saturating_div(1_000_000_000)
+    );
+    assert!(
+        elapsed.ref_time() <= weight.ref_time(),
+        "{name} took {} ps, over its weight of {} ps",
+        elapsed.ref_time(),
+        weight.ref_time()
+    );
+}
+
+/// The maximum weight of a block of the production runtime.
+pub fn max_block_weight() -> Weight {
+    <Runtime as frame_system::Config>::BlockWeights::get().max_block
+}
+
+/// Asserts the invariants the pallet checks in `try-runtime`.
+pub fn assert_try_state() {
+    let block = frame_system::Pallet::<Runtime>::block_number();
+    <SubtensorModule as TryState<_>>::try_state(block, TryStateSelect::All)
+        .expect("the subtensor invariants hold");
+}
@@ -0,0 +1,48 @@
+//! The dissolution of the largest subnet against a mainnet state snapshot.
+#![cfg(feature = "try-runtime")]
+
+use frame_support::dispatch::GetDispatchInfo;
+use node_subtensor_runtime::{Runtime, RuntimeCall, SubtensorModule};
+use subtensor_remote_tests::{
+    assert_try_state, assert_within_weight, measure, remote_externalities,
+};
+
+#[tokio::test(flavor = "multi_thread")]
+#[ignore = "needs a mainnet state snapshot"]
+async fn dissolution_of_the_largest_subnet_settles_within_its_weight() {
+    let mut ext = remote_externalities().await;
+    ext.execute_with(|| {
+        let (netuid, pot) = pallet_subtensor::SubnetTAO::<Runtime>::iter()
+            .filter(|(netuid, _)| !netuid.is_root())
+            .max_by_key(|(_, tao)| *tao)
+            .expect("the snapshot has subnets");
+        let block = frame_system::Pallet::<Runtime>::block_number();
+
+        // The dissolution pays out its settlement exactly
+        let settlement = SubtensorModule::replay_dissolution_settlement(netuid, block)
+            .expect("the dissolution pays out its settlement");
+        assert_eq!(settlement.pot, pot);
+
+        let call = RuntimeCall::SubtensorModule(pallet_subtensor::Call::dissolve_network {
+            coldkey: settlement.owner.clone(),
+            netuid,
+        });
+        let weight = call.get_dispatch_info().call_weight;
+        let (result, elapsed) = measure(|| SubtensorModule::do_dissolve_network(netuid));
+        result.expect("the subnet is dissolved");
+        assert_within_weight(
+            &format!(
+                "dissolution of netuid {netuid} with {} stakes",
+                settlement.stakes.len()
+            ),
+            elapsed,
+            weight,
+        );
+
+        assert!(!SubtensorModule::if_subnet_exist(netuid));
+        assert!(!pallet_subtensor::SubnetTAO::<Runtime>::contains_key(
+            netuid
+        ));
+        assert_try_state();
+    });
+}
@@ -0,0 +1,41 @@
+//! The epoch of the biggest subnet against a mainnet state snapshot.
+#![cfg(feature = "try-runtime")]
+
+use node_subtensor_runtime::{Runtime, SubtensorModule};
+use subtensor_remote_tests::{
+    assert_try_state, assert_within_weight, max_block_weight, measure, remote_externalities,
+};
+use subtensor_runtime_common::{AlphaCurrency, Currency};
+
+#[tokio::test(flavor = "multi_thread")]
+#[ignore = "needs a mainnet state snapshot"]
+async fn epoch_of_the_biggest_subnet_fits_in_a_block() {
+    let mut ext = remote_externalities().await;
+    ext.execute_with(|| {
+        let (netuid, n) = pallet_subtensor::SubnetworkN::<Runtime>::iter()
+            .filter(|(netuid, _)| !netuid.is_root())
+            .max_by_key(|(_, n)| *n)
+            .expect("the snapshot has subnets");
+        let emission = AlphaCurrency::from(1_000_000_000);
+
+        let (terms, elapsed) = measure(|| SubtensorModule::epoch_with_mechanisms(netuid, emission));
+        assert_within_weight(
+            &format!("epoch of netuid {netuid} with {n} neurons"),
+            elapsed,
+            max_block_weight(),
+        );
+
+        // Every neuron is paid from the emission of the epoch and no more
+        assert_eq!(terms.len(), usize::from(n));
+        let paid = terms.iter().fold(0u64, |total, (_, server, validator)| {
+            total
+                .saturating_add(server.to_u64())
+                .saturating_add(validator.to_u64())
+        });
+        assert!(
+            paid <= emission.to_u64(),
+            "the epoch paid {paid} of an emission of {emission}"
+        );
+        assert_try_state();
+    });
+}
@@ -0,0 +1,26 @@
+//! The pending migrations against a mainnet state snapshot.
+#![cfg(feature = "try-runtime")]
+
+use frame_try_runtime::UpgradeCheckSelect;
+use node_subtensor_runtime::Executive;
+use subtensor_remote_tests::{
+    assert_try_state, assert_within_weight, max_block_weight, measure, remote_externalities,
+};
+
+#[tokio::test(flavor = "multi_thread")]
+#[ignore = "needs a mainnet state snapshot"]
+async fn pending_migrations_fit_in_a_block() {
+    let mut ext = remote_externalities().await;
+    ext.execute_with(|| {
+        let (result, elapsed) = measure(|| Executive::try_runtime_upgrade(UpgradeCheckSelect::All));
+        let weight = result.expect("the migrations and their checks pass");
+
+        // The migrations take no longer than the weight they report, which fits in a block
+        assert_within_weight("runtime upgrade", elapsed, weight);
+        assert!(
+            weight.all_lte(max_block_weight()),
+            "the migrations weigh {weight:?}, over the maximum block weight"
+        );
+        assert_try_state();
+    });
+}
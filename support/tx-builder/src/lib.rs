@@ -0,0 +1,189 @@
+//! SCALE encoded Subtensor calls for offline signing.
+//!
+//! Air-gapped signers cannot fetch the runtime metadata to look up pallet and call indices. This
+//! crate builds the encoded `RuntimeCall` of the common staking, weights and registration calls
+//! from plain Rust types. The indices are pinned to the runtime `SPEC_VERSION` they were taken
+//! from and checked for duplicates at compile time, and the tests decode every call with the
+//! runtime, so a change of indices or arguments is caught when the runtime is upgraded.
+//!
+//! ```ignore
+//! let call = AddStake {
+//!     hotkey,
+//!     netuid: 1.into(),
+//!     amount_staked: 1_000_000_000.into(),
+//! }
+//! .encode_call();
+//! ```
+
+use codec::Encode;
+use sp_core::{H256, crypto::AccountId32};
+use subtensor_runtime_common::{AlphaCurrency, MechId, NetUid, TaoCurrency};
+
+/// The runtime spec version the call indices were taken from.
+pub const SPEC_VERSION: u32 = 326;
+
+/// Index of the Subtensor pallet in the runtime.
+pub const SUBTENSOR_PALLET_INDEX: u8 = 7;
+
+/// A call of the Subtensor pallet.
+pub trait SubtensorCall: Encode {
+    /// Index of the call in the Subtensor pallet.
+    const CALL_INDEX: u8;
+
+    /// The SCALE encoded `RuntimeCall`, as signed in the extrinsic payload.
+    fn encode_call(&self) -> Vec<u8> {
+        let mut call = vec![SUBTENSOR_PALLET_INDEX, Self::CALL_INDEX];
+        self.encode_to(&mut call);
+        call
+    }
+}
+
+macro_rules! subtensor_calls {
+    ($(
+        $(#[$meta:meta])*
+        $name:ident = $index:literal { $($field:ident: $ty:ty),* $(,)? }
+    )*) => {
+        $(
+            $(#[$meta])*
+            #[derive(Encode, Clone, PartialEq, Eq, Debug)]
+            pub struct $name {
+                $(pub $field: $ty,)*
+            }
+
+            impl SubtensorCall for $name {
+                const CALL_INDEX: u8 = $index;
+            }
+        )*
+
+        /// Indices of every call built by this crate.
+        pub const CALL_INDICES: &[u8] = &[$($index),*];
+    };
+}
+
+subtensor_calls! {
+    /// `set_weights`
+    SetWeights = 0 {
+        netuid: NetUid,
+        dests: Vec<u16>,
+        weights: Vec<u16>,
+        version_key: u64,
+    }
+    /// `set_mechanism_weights`
+    SetMechanismWeights = 119 {
+        netuid: NetUid,
+        mecid: MechId,
+        dests: Vec<u16>,
+        weights: Vec<u16>,
+        version_key: u64,
+    }
+    /// `commit_weights`
+    CommitWeights = 96 {
+        netuid: NetUid,
+        commit_hash: H256,
+    }
+    /// `reveal_weights`
+    RevealWeights = 97 {
+        netuid: NetUid,
+        uids: Vec<u16>,
+        values: Vec<u16>,
+        salt: Vec<u16>,
+        version_key: u64,
+    }
+    /// `add_stake`
+    AddStake = 2 {
+        hotkey: AccountId32,
+        netuid: NetUid,
+        amount_staked: TaoCurrency,
+    }
+    /// `remove_stake`
+    RemoveStake = 3 {
+        hotkey: AccountId32,
+        netuid: NetUid,
+        amount_unstaked: AlphaCurrency,
+    }
+    /// `add_stake_limit`
+    AddStakeLimit = 88 {
+        hotkey: AccountId32,
+        netuid: NetUid,
+        amount_staked: TaoCurrency,
+        limit_price: TaoCurrency,
+        allow_partial: bool,
+    }
+    /// `remove_stake_limit`
+    RemoveStakeLimit = 89 {
+        hotkey: AccountId32,
+        netuid: NetUid,
+        amount_unstaked: AlphaCurrency,
+        limit_price: TaoCurrency,
+        allow_partial: bool,
+    }
+    /// `move_stake`
+    MoveStake = 85 {
+        origin_hotkey: AccountId32,
+        destination_hotkey: AccountId32,
+        origin_netuid: NetUid,
+        destination_netuid: NetUid,
+        alpha_amount: AlphaCurrency,
+    }
+    /// `transfer_stake`
+    TransferStake = 86 {
+        destination_coldkey: AccountId32,
+        hotkey: AccountId32,
+        origin_netuid: NetUid,
+        destination_netuid: NetUid,
+        alpha_amount: AlphaCurrency,
+    }
+    /// `swap_stake`
+    SwapStake = 87 {
+        hotkey: AccountId32,
+        origin_netuid: NetUid,
+        destination_netuid: NetUid,
+        alpha_amount: AlphaCurrency,
+    }
+    /// `unstake_all`
+    UnstakeAll = 83 {
+        hotkey: AccountId32,
+    }
+    /// `unstake_all_alpha`
+    UnstakeAllAlpha = 84 {
+        hotkey: AccountId32,
+    }
+    /// `register`
+    Register = 6 {
+        netuid: NetUid,
+        block_number: u64,
+        nonce: u64,
+        work: Vec<u8>,
+        hotkey: AccountId32,
+        coldkey: AccountId32,
+    }
+    /// `burned_register`
+    BurnedRegister = 7 {
+        netuid: NetUid,
+        hotkey: AccountId32,
+    }
+    /// `root_register`
+    RootRegister = 62 {
+        hotkey: AccountId32,
+    }
+}
+
+const fn indices_unique(indices: &[u8]) -> bool {
+    let mut rest = indices;
+    while let [index, tail @ ..] = rest {
+        let mut others = tail;
+        while let [other, others_tail @ ..] = others {
+            if *index == *other {
+                return false;
+            }
+            others = others_tail;
+        }
+        rest = tail;
+    }
+    true
+}
+
+const _: () = assert!(indices_unique(CALL_INDICES), "duplicate call index");
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,251 @@
+#![allow(clippy::unwrap_used)]
+
+use codec::Decode;
+use node_subtensor_runtime::{Runtime, RuntimeCall};
+use pallet_subtensor::Call;
+
+use super::*;
+
+fn assert_decodes_as(call: impl SubtensorCall, expected: Call<Runtime>) {
+    let encoded = call.encode_call();
+    let decoded = RuntimeCall::decode(&mut encoded.as_slice()).unwrap();
+    assert_eq!(decoded, RuntimeCall::SubtensorModule(expected));
+}
+
+#[test]
+fn test_spec_version_pinned() {
+    // Bump `SPEC_VERSION` after checking the call table against the new runtime
+    assert_eq!(SPEC_VERSION, node_subtensor_runtime::VERSION.spec_version);
+}
+
+#[test]
+fn test_staking_calls() {
+    let hotkey = AccountId32::new([1; 32]);
+    let coldkey = AccountId32::new([2; 32]);
+    let netuid = NetUid::from(1);
+    let tao = TaoCurrency::from(1_000_000_000);
+    let alpha = AlphaCurrency::from(2_000_000_000);
+
+    assert_decodes_as(
+        AddStake {
+            hotkey: hotkey.clone(),
+            netuid,
+            amount_staked: tao,
+        },
+        Call::add_stake {
+            hotkey: hotkey.clone(),
+            netuid,
+            amount_staked: tao,
+        },
+    );
+    assert_decodes_as(
+        RemoveStake {
+            hotkey: hotkey.clone(),
+            netuid,
+            amount_unstaked: alpha,
+        },
+        Call::remove_stake {
+            hotkey: hotkey.clone(),
+            netuid,
+            amount_unstaked: alpha,
+        },
+    );
+    assert_decodes_as(
+        AddStakeLimit {
+            hotkey: hotkey.clone(),
+            netuid,
+            amount_staked: tao,
+            limit_price: tao,
+            allow_partial: true,
+        },
+        Call::add_stake_limit {
+            hotkey: hotkey.clone(),
+            netuid,
+            amount_staked: tao,
+            limit_price: tao,
+            allow_partial: true,
+        },
+    );
+    assert_decodes_as(
+        RemoveStakeLimit {
+            hotkey: hotkey.clone(),
+            netuid,
+            amount_unstaked: alpha,
+            limit_price: tao,
+            allow_partial: false,
+        },
+        Call::remove_stake_limit {
+            hotkey: hotkey.clone(),
+            netuid,
+            amount_unstaked: alpha,
+            limit_price: tao,
+            allow_partial: false,
+        },
+    );
+    assert_decodes_as(
+        MoveStake {
+            origin_hotkey: hotkey.clone(),
+            destination_hotkey: coldkey.clone(),
+            origin_netuid: netuid,
+            destination_netuid: netuid.next(),
+            alpha_amount: alpha,
+        },
+        Call::move_stake {
+            origin_hotkey: hotkey.clone(),
+            destination_hotkey: coldkey.clone(),
+            origin_netuid: netuid,
+            destination_netuid: netuid.next(),
+            alpha_amount: alpha,
+        },
+    );
+    assert_decodes_as(
+        TransferStake {
+            destination_coldkey: coldkey.clone(),
+            hotkey: hotkey.clone(),
+            origin_netuid: netuid,
+            destination_netuid: netuid.next(),
+            alpha_amount: alpha,
+        },
+        Call::transfer_stake {
+            destination_coldkey: coldkey.clone(),
+            hotkey: hotkey.clone(),
+            origin_netuid: netuid,
+            destination_netuid: netuid.next(),
+            alpha_amount: alpha,
+        },
+    );
+    assert_decodes_as(
+        SwapStake {
+            hotkey: hotkey.clone(),
+            origin_netuid: netuid,
+            destination_netuid: netuid.next(),
+            alpha_amount: alpha,
+        },
+        Call::swap_stake {
+            hotkey: hotkey.clone(),
+            origin_netuid: netuid,
+            destination_netuid: netuid.next(),
+            alpha_amount: alpha,
+        },
+    );
+    assert_decodes_as(
+        UnstakeAll {
+            hotkey: hotkey.clone(),
+        },
+        Call::unstake_all {
+            hotkey: hotkey.clone(),
+        },
+    );
+    assert_decodes_as(
+        UnstakeAllAlpha {
+            hotkey: hotkey.clone(),
+        },
+        Call::unstake_all_alpha { hotkey },
+    );
+}
+
+#[test]
+fn test_weights_calls() {
+    let netuid = NetUid::from(1);
+    let dests = vec![0, 1, 2];
+    let weights = vec![u16::MAX, 0, 1];
+
+    assert_decodes_as(
+        SetWeights {
+            netuid,
+            dests: dests.clone(),
+            weights: weights.clone(),
+            version_key: 7,
+        },
+        Call::set_weights {
+            netuid,
+            dests: dests.clone(),
+            weights: weights.clone(),
+            version_key: 7,
+        },
+    );
+    assert_decodes_as(
+        SetMechanismWeights {
+            netuid,
+            mecid: MechId::from(1),
+            dests: dests.clone(),
+            weights: weights.clone(),
+            version_key: 7,
+        },
+        Call::set_mechanism_weights {
+            netuid,
+            mecid: MechId::from(1),
+            dests: dests.clone(),
+            weights: weights.clone(),
+            version_key: 7,
+        },
+    );
+    assert_decodes_as(
+        CommitWeights {
+            netuid,
+            commit_hash: H256::repeat_byte(3),
+        },
+        Call::commit_weights {
+            netuid,
+            commit_hash: H256::repeat_byte(3),
+        },
+    );
+    assert_decodes_as(
+        RevealWeights {
+            netuid,
+            uids: dests.clone(),
+            values: weights.clone(),
+            salt: vec![4, 5],
+            version_key: 7,
+        },
+        Call::reveal_weights {
+            netuid,
+            uids: dests,
+            values: weights,
+            salt: vec![4, 5],
+            version_key: 7,
+        },
+    );
+}
+
+#[test]
+fn test_registration_calls() {
+    let hotkey = AccountId32::new([1; 32]);
+    let coldkey = AccountId32::new([2; 32]);
+    let netuid = NetUid::from(1);
+
+    assert_decodes_as(
+        Register {
+            netuid,
+            block_number: 100,
+            nonce: 42,
+            work: vec![9; 32],
+            hotkey: hotkey.clone(),
+            coldkey: coldkey.clone(),
+        },
+        Call::register {
+            netuid,
+            block_number: 100,
+            nonce: 42,
+            work: vec![9; 32],
+            hotkey: hotkey.clone(),
+            coldkey,
+        },
+    );
+    assert_decodes_as(
+        BurnedRegister {
+            netuid,
+            hotkey: hotkey.clone(),
+        },
+        Call::burned_register {
+            netuid,
+            hotkey: hotkey.clone(),
+        },
+    );
+    assert_decodes_as(
+        RootRegister {
+            hotkey: hotkey.clone(),
+        },
+        Call::root_register { hotkey },
+    );
+}